@@ -7,6 +7,7 @@ use directories::{BaseDirs, ProjectDirs};
 use once_cell::sync::Lazy;
 
 static DEFAULT_DB_NAME: &str = "cpt.sqlite3";
+static DEFAULT_CONFIG_NAME: &str = "config.toml";
 static ENV_DATA_DIR: &str = "CPT_DATA_DIR";
 
 static PROJECT_DIRS: Lazy<Option<ProjectDirs>> =
@@ -16,6 +17,7 @@ static PROJECT_DIRS: Lazy<Option<ProjectDirs>> =
 pub struct AppConfig {
     data_dir: PathBuf,
     db_path: PathBuf,
+    config_path: PathBuf,
 }
 
 impl AppConfig {
@@ -34,7 +36,12 @@ impl AppConfig {
     /// Construct [`AppConfig`] directly from a resolved data directory.
     pub fn from_data_dir(data_dir: PathBuf) -> Result<Self> {
         let db_path = data_dir.join(DEFAULT_DB_NAME);
-        Ok(Self { data_dir, db_path })
+        let config_path = data_dir.join(DEFAULT_CONFIG_NAME);
+        Ok(Self {
+            data_dir,
+            db_path,
+            config_path,
+        })
     }
 
     pub fn data_dir(&self) -> &Path {
@@ -44,6 +51,11 @@ impl AppConfig {
     pub fn db_path(&self) -> &Path {
         &self.db_path
     }
+
+    /// Optional user settings (theme, keys, …) that live next to the database.
+    pub fn config_path(&self) -> &Path {
+        &self.config_path
+    }
 }
 
 fn resolve_data_dir(data_dir_override: Option<PathBuf>) -> Result<PathBuf> {
@@ -1,16 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use anyhow::{anyhow, Context, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
 use rusqlite::{named_params, types::Value, Connection, Row, ToSql};
 
 use crate::capture::TaskInput;
 use crate::config::AppConfig;
 use crate::model::{
-    AddOutcome, DeleteResult, EnergyLevel, ListFilters, ListOutputItem, ListView, ProjectSummary,
-    StatusUpdate, Task, TaskStatus,
+    AddOutcome, BatchAction, BatchOperation, DeleteResult, EnergyLevel, FacetCount, Facets,
+    ListFilters, ListOutputItem, ListView, ProjectHealth, ProjectSummary, SavedView, StatusUpdate,
+    Task, TaskStatus, TimeEntry,
 };
 use crate::parser;
+use crate::repeat::RepeatRule;
 
 pub struct Database {
     conn: Connection,
@@ -31,92 +33,304 @@ impl Database {
 
     pub fn handle_add(&mut self, input: &TaskInput) -> Result<AddOutcome> {
         let (insertable, outcome) = parser::prepare_new_task(input)?;
-        self.insert_task(&insertable)?;
+        Self::insert_task(&self.conn, &insertable)?;
         Ok(outcome)
     }
 
+    /// Adds every input in one transaction; if any of them fails to parse, none is added.
+    pub fn handle_add_many(&mut self, inputs: &[TaskInput]) -> Result<Vec<AddOutcome>> {
+        let prepared = inputs
+            .iter()
+            .map(parser::prepare_new_task)
+            .collect::<Result<Vec<_>>>()?;
+        let tx = self.conn.transaction()?;
+        let mut outcomes = Vec::with_capacity(prepared.len());
+        for (insertable, outcome) in prepared {
+            Self::insert_task(&tx, &insertable)?;
+            outcomes.push(outcome);
+        }
+        tx.commit()?;
+        Ok(outcomes)
+    }
+
     pub fn fetch_tasks(&self, filters: &ListFilters) -> Result<Vec<ListOutputItem>> {
         if matches!(filters.view, Some(ListView::Projects)) {
             return self.fetch_projects(filters);
         }
 
-        let mut sql = String::from(
+        let (conditions, mut values) = task_conditions(filters);
+        let mut sql = format!(
             "SELECT id, title, notes, status, project, areas, contexts, tags, priority, energy, \
             time_estimate, due_at, defer_until, repeat, created_at, updated_at, completed_at, waiting_on, waiting_since \
-            FROM tasks WHERE 1=1",
+            FROM tasks WHERE 1=1{}",
+            conditions
         );
-        let mut values: Vec<Value> = Vec::new();
+        sql.push_str(&build_order_clause(filters));
 
-        if let Some(status) = filters.status {
-            sql.push_str(" AND status = ?");
-            values.push(Value::from(status.as_str().to_string()));
-        } else if !filters.include_done {
-            sql.push_str(" AND status NOT IN ('done','canceled')");
+        if filters.limit.is_some() || filters.offset > 0 {
+            sql.push_str(" LIMIT ? OFFSET ?");
+            values.push(Value::from(filters.limit.map_or(-1, |limit| limit as i64)));
+            values.push(Value::from(filters.offset as i64));
         }
 
-        if matches!(filters.view, Some(ListView::Scheduled)) {
-            sql.push_str(
-                " AND ((status = 'scheduled') OR ((status IN ('inbox','next')) AND (due_at IS NOT NULL OR defer_until IS NOT NULL)))",
-            );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let param_refs: Vec<&dyn ToSql> = values.iter().map(|v| v as &dyn ToSql).collect();
+        let mut rows = stmt.query(&param_refs[..])?;
+        let mut tasks = Vec::new();
+        while let Some(row) = rows.next()? {
+            tasks.push(ListOutputItem::Task(Box::new(self.map_task(row)?)));
         }
+        Ok(tasks)
+    }
 
-        if let Some(project) = &filters.project {
-            sql.push_str(" AND project = ?");
-            values.push(Value::from(project.clone()));
+    /// Number of tasks `fetch_tasks` would return for `filters`, ignoring sort and paging.
+    pub fn count_tasks(&self, filters: &ListFilters) -> Result<usize> {
+        if matches!(filters.view, Some(ListView::Projects)) {
+            return Ok(self.fetch_projects(filters)?.len());
         }
+        let (conditions, values) = task_conditions(filters);
+        let sql = format!("SELECT COUNT(*) FROM tasks WHERE 1=1{}", conditions);
+        let param_refs: Vec<&dyn ToSql> = values.iter().map(|v| v as &dyn ToSql).collect();
+        let count: i64 = self
+            .conn
+            .query_row(&sql, &param_refs[..], |row| row.get(0))?;
+        Ok(count as usize)
+    }
 
-        for ctx in &filters.contexts {
-            let needle = format!("\"{}\"", ctx);
-            sql.push_str(" AND instr(contexts, ?) > 0");
-            values.push(Value::from(needle));
+    /// Counts open (not done or canceled) tasks per project, context, tag, and area.
+    pub fn fetch_facets(&self) -> Result<Facets> {
+        let mut stmt = self.conn.prepare(
+            "SELECT project, contexts, tags, areas FROM tasks WHERE status NOT IN ('done','canceled')",
+        )?;
+        let mut rows = stmt.query([])?;
+        let mut projects = BTreeMap::new();
+        let mut contexts = BTreeMap::new();
+        let mut tags = BTreeMap::new();
+        let mut areas = BTreeMap::new();
+        while let Some(row) = rows.next()? {
+            if let Some(project) = row.get::<_, Option<String>>(0)? {
+                if !project.is_empty() {
+                    *projects.entry(project).or_insert(0) += 1;
+                }
+            }
+            for (column, counts) in [(1, &mut contexts), (2, &mut tags), (3, &mut areas)] {
+                for name in parse_string_list(row.get::<_, Option<String>>(column)?) {
+                    *counts.entry(name).or_insert(0) += 1;
+                }
+            }
         }
+        Ok(Facets {
+            projects: facet_counts(projects),
+            contexts: facet_counts(contexts),
+            tags: facet_counts(tags),
+            areas: facet_counts(areas),
+        })
+    }
 
-        for tag in &filters.tags {
-            let needle = format!("\"{}\"", tag);
-            sql.push_str(" AND instr(tags, ?) > 0");
-            values.push(Value::from(needle));
-        }
+    /// SQLite's `data_version`, which changes when another connection commits to the file.
+    pub fn data_version(&self) -> Result<i64> {
+        Ok(self
+            .conn
+            .query_row("PRAGMA data_version", [], |row| row.get(0))?)
+    }
 
-        if let Some(due_before) = filters.due_before {
-            sql.push_str(" AND due_at IS NOT NULL AND due_at <= ?");
-            values.push(Value::from(due_before.to_rfc3339()));
+    /// Saved filter presets ordered by slot.
+    pub fn fetch_saved_views(&self) -> Result<Vec<SavedView>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT slot, name, project, contexts, tags, priority_min FROM saved_views ORDER BY slot",
+        )?;
+        let mut rows = stmt.query([])?;
+        let mut views = Vec::new();
+        while let Some(row) = rows.next()? {
+            views.push(SavedView {
+                slot: row.get::<_, i64>(0)? as u8,
+                name: row.get(1)?,
+                project: row.get(2)?,
+                contexts: parse_string_list(row.get::<_, Option<String>>(3)?),
+                tags: parse_string_list(row.get::<_, Option<String>>(4)?),
+                priority_min: row.get::<_, Option<i64>>(5)?.map(|v| v as u8),
+            });
         }
+        Ok(views)
+    }
 
-        if let Some(defer_after) = filters.defer_after {
-            sql.push_str(" AND defer_until IS NOT NULL AND defer_until >= ?");
-            values.push(Value::from(defer_after.to_rfc3339()));
-        }
+    /// Stores a preset, replacing whatever occupied its slot.
+    pub fn save_view(&self, view: &SavedView) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO saved_views (slot, name, project, contexts, tags, priority_min) \
+            VALUES (:slot, :name, :project, :contexts, :tags, :priority_min)",
+            named_params![
+                ":slot": view.slot as i64,
+                ":name": view.name,
+                ":project": view.project,
+                ":contexts": serde_json::to_string(&view.contexts)?,
+                ":tags": serde_json::to_string(&view.tags)?,
+                ":priority_min": view.priority_min.map(|p| p as i64),
+            ],
+        )?;
+        Ok(())
+    }
 
-        if let Some(limit) = filters.time_max {
-            sql.push_str(" AND (time_estimate IS NULL OR time_estimate <= ?)");
-            values.push(Value::from(limit as i64));
+    /// The timer that is currently running, if any. At most one runs at a time.
+    pub fn running_timer(&self) -> Result<Option<TimeEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, task_id, started_at, ended_at FROM time_entries \
+             WHERE ended_at IS NULL ORDER BY started_at DESC LIMIT 1",
+        )?;
+        let mut rows = stmt.query([])?;
+        match rows.next()? {
+            Some(row) => Ok(Some(map_time_entry(row)?)),
+            None => Ok(None),
         }
+    }
 
-        if let Some(energy) = filters.energy {
-            sql.push_str(" AND (energy = ?)");
-            values.push(Value::from(energy.as_str().to_string()));
-        }
+    /// Starts timing `task_id`, stopping whichever timer was running first.
+    pub fn start_timer(&mut self, task_id: &str) -> Result<TimeEntry> {
+        let now = Utc::now();
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "UPDATE time_entries SET ended_at = :now WHERE ended_at IS NULL",
+            named_params![":now": now.to_rfc3339()],
+        )?;
+        tx.execute(
+            "INSERT INTO time_entries (task_id, started_at) VALUES (:task_id, :started)",
+            named_params![":task_id": task_id, ":started": now.to_rfc3339()],
+        )?;
+        let id = tx.last_insert_rowid();
+        tx.commit()?;
+        Ok(TimeEntry {
+            id,
+            task_id: task_id.to_string(),
+            started_at: now,
+            ended_at: None,
+        })
+    }
 
-        if let Some(priority) = filters.priority_min {
-            sql.push_str(" AND priority >= ?");
-            values.push(Value::from(priority as i64));
+    /// Stores `ids` as the manual order, first to last, for views sorted by `SortField::Manual`.
+    pub fn set_sort_order(&mut self, ids: &[String]) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        for (position, id) in ids.iter().enumerate() {
+            tx.execute(
+                "UPDATE tasks SET sort_order = :position WHERE id = :id",
+                named_params![":position": position as i64, ":id": id],
+            )?;
         }
+        tx.commit()?;
+        Ok(())
+    }
 
-        sql.push_str(&build_order_clause(filters));
+    /// Stops the running timer and returns the finished entry.
+    pub fn stop_timer(&self) -> Result<Option<TimeEntry>> {
+        let Some(mut entry) = self.running_timer()? else {
+            return Ok(None);
+        };
+        let now = Utc::now();
+        self.conn.execute(
+            "UPDATE time_entries SET ended_at = :now WHERE id = :id",
+            named_params![":now": now.to_rfc3339(), ":id": entry.id],
+        )?;
+        entry.ended_at = Some(now);
+        Ok(Some(entry))
+    }
 
-        let mut stmt = self.conn.prepare(&sql)?;
-        let param_refs: Vec<&dyn ToSql> = values.iter().map(|v| v as &dyn ToSql).collect();
-        let mut rows = stmt.query(&param_refs[..])?;
-        let mut tasks = Vec::new();
+    pub fn fetch_time_entries(&self, task_id: &str) -> Result<Vec<TimeEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, task_id, started_at, ended_at FROM time_entries \
+             WHERE task_id = ? ORDER BY started_at",
+        )?;
+        let mut rows = stmt.query([task_id])?;
+        let mut entries = Vec::new();
         while let Some(row) = rows.next()? {
-            tasks.push(ListOutputItem::Task(Box::new(self.map_task(row)?)));
+            entries.push(map_time_entry(row)?);
         }
-        Ok(tasks)
+        Ok(entries)
+    }
+
+    /// Every time entry across all tasks, oldest first.
+    pub fn fetch_all_time_entries(&self) -> Result<Vec<TimeEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, task_id, started_at, ended_at FROM time_entries ORDER BY started_at",
+        )?;
+        let mut rows = stmt.query([])?;
+        let mut entries = Vec::new();
+        while let Some(row) = rows.next()? {
+            entries.push(map_time_entry(row)?);
+        }
+        Ok(entries)
     }
 
+    /// Completes the tasks; each open one with a repeat rule gets its next occurrence.
     pub fn mark_done(&self, ids: &[String]) -> Result<Vec<StatusUpdate>> {
-        let now = Utc::now().to_rfc3339();
-        self.update_status(ids, TaskStatus::Done, Some(now))
+        let now = Utc::now();
+        let mut repeating = Vec::new();
+        for id in ids {
+            if let Some(task) = self.fetch_task(id)?.filter(|task| {
+                task.repeat.is_some()
+                    && !matches!(task.status, TaskStatus::Done | TaskStatus::Canceled)
+            }) {
+                repeating.push(task);
+            }
+        }
+        let results = self.update_status(ids, TaskStatus::Done, Some(now.to_rfc3339()))?;
+        for task in &repeating {
+            self.insert_next_occurrence(task, now)?;
+        }
+        Ok(results)
+    }
+
+    /// Adds the occurrence after `task` under its repeat rule: dates move forward past `now`
+    /// (an undated task is deferred to its next day), and the notes' checklist starts unticked.
+    /// A stored rule that no longer parses schedules nothing.
+    fn insert_next_occurrence(&self, task: &Task, now: DateTime<Utc>) -> Result<()> {
+        let Some(rule) = task
+            .repeat
+            .as_deref()
+            .and_then(|rule| rule.parse::<RepeatRule>().ok())
+        else {
+            return Ok(());
+        };
+        let advance = |from: DateTime<Utc>| {
+            let mut next = rule.next_after(from);
+            while next <= now {
+                next = rule.next_after(next);
+            }
+            next
+        };
+        let mut next = crate::model::NewTask::from(task);
+        match (task.due_at, task.defer_until) {
+            (Some(due_at), defer_until) => {
+                let next_due = advance(due_at);
+                next.due_at = Some(next_due);
+                next.defer_until = defer_until.map(|defer| defer + (next_due - due_at));
+            }
+            (None, Some(defer_until)) => next.defer_until = Some(advance(defer_until)),
+            (None, None) => next.defer_until = Some(advance(now)),
+        }
+        next.status = if next.defer_until.is_some_and(|defer| defer > now) {
+            TaskStatus::Scheduled
+        } else {
+            TaskStatus::Next
+        };
+        next.notes = task.notes_unchecked();
+        next.waiting_on = None;
+        next.waiting_since = None;
+        Self::insert_task(&self.conn, &next.into_insertable())
+    }
+
+    /// Sets or clears the repeat rule, stored in its canonical form.
+    pub fn set_repeat(&self, id: &str, rule: Option<&RepeatRule>) -> Result<Option<Task>> {
+        let updated = self.conn.execute(
+            "UPDATE tasks SET repeat = :repeat, updated_at = :updated WHERE id = :id",
+            named_params![
+                ":repeat": rule.map(|rule| rule.to_string()),
+                ":updated": Utc::now().to_rfc3339(),
+                ":id": id,
+            ],
+        )?;
+        if updated == 0 {
+            return Ok(None);
+        }
+        self.fetch_task(id)
     }
 
     pub fn mark_next(&self, ids: &[String]) -> Result<Vec<StatusUpdate>> {
@@ -131,6 +345,46 @@ impl Database {
         self.update_status(ids, TaskStatus::Inbox, None)
     }
 
+    pub fn set_status(&self, ids: &[String], status: TaskStatus) -> Result<Vec<StatusUpdate>> {
+        match status {
+            TaskStatus::Done => self.mark_done(ids),
+            other => self.update_status(ids, other, None),
+        }
+    }
+
+    /// Applies every operation in one transaction, so either all of them land or none does.
+    /// Returns one result per id and operation; `changed` is false for ids that do not exist.
+    pub fn apply_batch(&self, operations: &[BatchOperation]) -> Result<Vec<Vec<StatusUpdate>>> {
+        let tx = self.conn.unchecked_transaction()?;
+        let mut results = Vec::with_capacity(operations.len());
+        for operation in operations {
+            let ids = &operation.ids;
+            let updates = match operation.action {
+                BatchAction::Status(status) => self.set_status(ids, status)?,
+                BatchAction::Defer(until) => ids
+                    .iter()
+                    .map(|id| {
+                        Ok(StatusUpdate {
+                            id: id.clone(),
+                            changed: self.defer_task(id, Some(until))?.is_some(),
+                        })
+                    })
+                    .collect::<Result<_>>()?,
+                BatchAction::Delete => self
+                    .delete_tasks(ids)?
+                    .into_iter()
+                    .map(|result| StatusUpdate {
+                        id: result.id,
+                        changed: result.deleted,
+                    })
+                    .collect(),
+            };
+            results.push(updates);
+        }
+        tx.commit()?;
+        Ok(results)
+    }
+
     fn update_status(
         &self,
         ids: &[String],
@@ -158,9 +412,51 @@ impl Database {
         Ok(results)
     }
 
+    /// Sets the priority (clamped to 0–3) without rewriting the rest of the task.
+    pub fn set_priority(&self, ids: &[String], priority: u8) -> Result<Vec<StatusUpdate>> {
+        let updated_ts = Utc::now().to_rfc3339();
+        let mut results = Vec::new();
+        for id in ids {
+            let updated = self.conn.execute(
+                "UPDATE tasks SET priority = :priority, updated_at = :updated WHERE id = :id",
+                named_params![
+                    ":priority": priority.min(3) as i64,
+                    ":updated": updated_ts,
+                    ":id": id,
+                ],
+            )?;
+            results.push(StatusUpdate {
+                id: id.to_string(),
+                changed: updated > 0,
+            });
+        }
+        Ok(results)
+    }
+
+    /// Reassigns tasks to `project`, or clears the project when it is `None`.
+    pub fn set_project(&self, ids: &[String], project: Option<&str>) -> Result<Vec<StatusUpdate>> {
+        let updated_ts = Utc::now().to_rfc3339();
+        let mut results = Vec::new();
+        for id in ids {
+            let updated = self.conn.execute(
+                "UPDATE tasks SET project = :project, updated_at = :updated WHERE id = :id",
+                named_params![":project": project, ":updated": updated_ts, ":id": id],
+            )?;
+            results.push(StatusUpdate {
+                id: id.to_string(),
+                changed: updated > 0,
+            });
+        }
+        Ok(results)
+    }
+
     pub fn delete_tasks(&self, ids: &[String]) -> Result<Vec<DeleteResult>> {
         let mut results = Vec::new();
         for id in ids {
+            self.conn.execute(
+                "DELETE FROM time_entries WHERE task_id = :id",
+                named_params![":id": id],
+            )?;
             let affected = self
                 .conn
                 .execute("DELETE FROM tasks WHERE id = :id", named_params![":id": id])?;
@@ -187,6 +483,37 @@ impl Database {
         }
     }
 
+    /// Sets or clears `defer_until`; deferring an Inbox or Next task moves it to Scheduled.
+    pub fn defer_task(&self, id: &str, defer_until: Option<DateTime<Utc>>) -> Result<Option<Task>> {
+        let Some(task) = self.fetch_task(id)? else {
+            return Ok(None);
+        };
+
+        let mut updated = crate::model::NewTask::from(&task);
+        updated.defer_until = defer_until;
+        updated.status = match updated.status {
+            TaskStatus::Inbox | TaskStatus::Next if defer_until.is_some() => TaskStatus::Scheduled,
+            other => other,
+        };
+        self.update_task(id, &updated)
+    }
+
+    /// Sets or clears `due_at`, leaving status and the rest of the task untouched.
+    pub fn set_due(&self, id: &str, due_at: Option<DateTime<Utc>>) -> Result<Option<Task>> {
+        let updated = self.conn.execute(
+            "UPDATE tasks SET due_at = :due_at, updated_at = :updated WHERE id = :id",
+            named_params![
+                ":due_at": due_at.map(|dt| dt.to_rfc3339()),
+                ":updated": Utc::now().to_rfc3339(),
+                ":id": id,
+            ],
+        )?;
+        if updated == 0 {
+            return Ok(None);
+        }
+        self.fetch_task(id)
+    }
+
     pub fn update_task(&self, id: &str, updated: &crate::model::NewTask) -> Result<Option<Task>> {
         let existing = match self.fetch_task(id)? {
             Some(task) => task,
@@ -265,7 +592,7 @@ impl Database {
         self.fetch_task(id)
     }
 
-    fn insert_task(&self, insertable: &crate::model::InsertableTask) -> Result<()> {
+    fn insert_task(conn: &Connection, insertable: &crate::model::InsertableTask) -> Result<()> {
         let now = Utc::now();
         let data = &insertable.data;
         let areas_json = serde_json::to_string(&data.areas)?;
@@ -279,7 +606,7 @@ impl Database {
         let waiting_on = data.waiting_on.clone();
         let waiting_since = data.waiting_since.map(|dt| dt.to_rfc3339());
 
-        self.conn.execute(
+        conn.execute(
             "INSERT INTO tasks (
                 id, title, notes, status, project, areas, contexts, tags, priority, energy, time_estimate,
                 due_at, defer_until, repeat, created_at, updated_at, completed_at, waiting_on, waiting_since
@@ -356,6 +683,55 @@ impl Database {
         Ok(summaries.into_iter().map(ListOutputItem::Project).collect())
     }
 
+    /// Summarizes every project, sorted by name, with its open count, whether it is stalled, and
+    /// when one of its tasks last changed.
+    pub fn fetch_project_health(&self) -> Result<Vec<ProjectHealth>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT project, status, COUNT(*), MAX(updated_at) FROM tasks \
+             WHERE project IS NOT NULL AND project <> '' GROUP BY project, status",
+        )?;
+        let mut rows = stmt.query([])?;
+        let mut projects: BTreeMap<String, ProjectHealth> = BTreeMap::new();
+        while let Some(row) = rows.next()? {
+            let project: String = row.get(0)?;
+            let status: TaskStatus = row.get::<_, String>(1)?.parse()?;
+            let count = row.get::<_, i64>(2)? as usize;
+            let updated = parse_datetime_required(row.get::<_, String>(3)?)?;
+            let health = projects
+                .entry(project.clone())
+                .or_insert_with(|| ProjectHealth {
+                    summary: ProjectSummary {
+                        project,
+                        total: 0,
+                        next_actions: 0,
+                        waiting: 0,
+                        someday: 0,
+                    },
+                    open: 0,
+                    stalled: false,
+                    last_activity: updated,
+                });
+            health.summary.total += count;
+            match status {
+                TaskStatus::Next => health.summary.next_actions += count,
+                TaskStatus::Waiting => health.summary.waiting += count,
+                TaskStatus::Someday => health.summary.someday += count,
+                _ => {}
+            }
+            if !matches!(status, TaskStatus::Done | TaskStatus::Canceled) {
+                health.open += count;
+            }
+            health.last_activity = health.last_activity.max(updated);
+        }
+        Ok(projects
+            .into_values()
+            .map(|mut health| {
+                health.stalled = health.open > 0 && health.summary.next_actions == 0;
+                health
+            })
+            .collect())
+    }
+
     fn map_task(&self, row: &Row<'_>) -> Result<Task> {
         let energy: Option<String> = row.get(9)?;
         let energy = match energy {
@@ -408,15 +784,54 @@ impl Database {
                 updated_at TEXT NOT NULL,
                 completed_at TEXT,
                 waiting_on TEXT,
-                waiting_since TEXT
+                waiting_since TEXT,
+                sort_order INTEGER
              );
              CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks(status);
              CREATE INDEX IF NOT EXISTS idx_tasks_project ON tasks(project);
              CREATE INDEX IF NOT EXISTS idx_tasks_due ON tasks(due_at);
+             CREATE TABLE IF NOT EXISTS saved_views (
+                slot INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                project TEXT,
+                contexts TEXT DEFAULT '[]',
+                tags TEXT DEFAULT '[]',
+                priority_min INTEGER
+             );
+             CREATE TABLE IF NOT EXISTS time_entries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                task_id TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                ended_at TEXT
+             );
+             CREATE INDEX IF NOT EXISTS idx_time_entries_task ON time_entries(task_id);
             ",
         )?;
+        self.ensure_column("tasks", "sort_order", "INTEGER")?;
         Ok(())
     }
+
+    /// Adds a column that databases created before it existed are missing.
+    fn ensure_column(&self, table: &str, column: &str, definition: &str) -> Result<()> {
+        let mut stmt = self.conn.prepare(&format!("PRAGMA table_info({table})"))?;
+        let names = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        if !names.iter().any(|name| name == column) {
+            self.conn.execute(
+                &format!("ALTER TABLE {table} ADD COLUMN {column} {definition}"),
+                [],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn facet_counts(counts: BTreeMap<String, usize>) -> Vec<FacetCount> {
+    counts
+        .into_iter()
+        .map(|(name, count)| FacetCount { name, count })
+        .collect()
 }
 
 fn parse_string_list(raw: Option<String>) -> Vec<String> {
@@ -424,6 +839,15 @@ fn parse_string_list(raw: Option<String>) -> Vec<String> {
         .unwrap_or_default()
 }
 
+fn map_time_entry(row: &Row<'_>) -> Result<TimeEntry> {
+    Ok(TimeEntry {
+        id: row.get(0)?,
+        task_id: row.get(1)?,
+        started_at: parse_datetime_required(row.get(2)?)?,
+        ended_at: parse_datetime(row.get(3)?),
+    })
+}
+
 fn parse_datetime(raw: Option<String>) -> Option<DateTime<Utc>> {
     raw.and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
         .map(|dt| dt.with_timezone(&Utc))
@@ -435,6 +859,119 @@ fn parse_datetime_required(raw: String) -> Result<DateTime<Utc>> {
         .map_err(|e| anyhow!("Failed to parse timestamp '{}': {}", raw, e))
 }
 
+/// `AND ...` clauses selecting the tasks that match `filters`, with their bound values.
+fn task_conditions(filters: &ListFilters) -> (String, Vec<Value>) {
+    let mut sql = String::new();
+    let mut values: Vec<Value> = Vec::new();
+
+    if let Some(status) = filters.status {
+        sql.push_str(" AND status = ?");
+        values.push(Value::from(status.as_str().to_string()));
+    } else if !filters.include_done {
+        sql.push_str(" AND status NOT IN ('done','canceled')");
+    }
+
+    if matches!(filters.view, Some(ListView::Scheduled)) {
+        sql.push_str(
+            " AND ((status = 'scheduled') OR ((status IN ('inbox','next')) AND (due_at IS NOT NULL OR defer_until IS NOT NULL)))",
+        );
+    }
+
+    if matches!(filters.view, Some(ListView::Today)) {
+        let (start, end) = local_day_bounds(Local::now().date_naive());
+        sql.push_str(
+            " AND ((due_at IS NOT NULL AND due_at < ?) OR (defer_until IS NOT NULL AND defer_until >= ? AND defer_until < ?))",
+        );
+        values.push(Value::from(end.to_rfc3339()));
+        values.push(Value::from(start.to_rfc3339()));
+        values.push(Value::from(end.to_rfc3339()));
+    }
+
+    if let Some(project) = &filters.project {
+        sql.push_str(" AND project = ?");
+        values.push(Value::from(project.clone()));
+    }
+
+    for ctx in &filters.contexts {
+        let needle = format!("\"{}\"", ctx);
+        sql.push_str(" AND instr(contexts, ?) > 0");
+        values.push(Value::from(needle));
+    }
+
+    for tag in &filters.tags {
+        let needle = format!("\"{}\"", tag);
+        sql.push_str(" AND instr(tags, ?) > 0");
+        values.push(Value::from(needle));
+    }
+
+    if let Some(due_before) = filters.due_before {
+        sql.push_str(" AND due_at IS NOT NULL AND due_at <= ?");
+        values.push(Value::from(due_before.to_rfc3339()));
+    }
+
+    if let Some(defer_after) = filters.defer_after {
+        sql.push_str(" AND defer_until IS NOT NULL AND defer_until >= ?");
+        values.push(Value::from(defer_after.to_rfc3339()));
+    }
+
+    if let Some((start, end)) = filters.dated_between {
+        sql.push_str(
+            " AND COALESCE(due_at, defer_until) >= ? AND COALESCE(due_at, defer_until) < ?",
+        );
+        values.push(Value::from(start.to_rfc3339()));
+        values.push(Value::from(end.to_rfc3339()));
+    }
+
+    if let Some(limit) = filters.time_max {
+        sql.push_str(" AND (time_estimate IS NULL OR time_estimate <= ?)");
+        values.push(Value::from(limit as i64));
+    }
+
+    if let Some(energy) = filters.energy {
+        sql.push_str(" AND (energy = ?)");
+        values.push(Value::from(energy.as_str().to_string()));
+    }
+
+    if let Some(priority) = filters.priority_min {
+        sql.push_str(" AND priority >= ?");
+        values.push(Value::from(priority as i64));
+    }
+
+    for word in filters.text.iter().flat_map(|text| text.split_whitespace()) {
+        let pattern = format!("%{}%", escape_like(word));
+        sql.push_str(" AND (title LIKE ? ESCAPE '\\' OR notes LIKE ? ESCAPE '\\')");
+        values.push(Value::from(pattern.clone()));
+        values.push(Value::from(pattern));
+    }
+
+    (sql, values)
+}
+
+/// Escapes `LIKE` wildcards so `word` matches literally.
+fn escape_like(word: &str) -> String {
+    let mut escaped = String::with_capacity(word.len());
+    for c in word.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// UTC instants bracketing the given local calendar day.
+fn local_day_bounds(date: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    let to_utc = |day: NaiveDate| {
+        let midnight = day.and_hms_opt(0, 0, 0).expect("midnight");
+        Local
+            .from_local_datetime(&midnight)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+    };
+    (to_utc(date), to_utc(date + Duration::days(1)))
+}
+
 fn build_order_clause(filters: &ListFilters) -> String {
     match filters.sort {
         crate::model::SortField::Due => {
@@ -490,9 +1027,53 @@ fn build_order_clause(filters: &ListFilters) -> String {
                 " ORDER BY created_at ASC".into()
             }
         }
+        crate::model::SortField::Urgency => {
+            if filters.reverse {
+                format!(
+                    " ORDER BY {} ASC, due_at IS NULL DESC, due_at DESC, created_at DESC",
+                    URGENCY_SCORE_SQL
+                )
+            } else {
+                format!(
+                    " ORDER BY {} DESC, due_at IS NULL, due_at ASC, created_at ASC",
+                    URGENCY_SCORE_SQL
+                )
+            }
+        }
+        crate::model::SortField::Completed => {
+            if filters.reverse {
+                " ORDER BY completed_at IS NULL DESC, completed_at ASC, created_at ASC".into()
+            } else {
+                " ORDER BY completed_at IS NULL, completed_at DESC, created_at DESC".into()
+            }
+        }
+        crate::model::SortField::Manual => {
+            // Tasks never placed by hand follow the arranged ones in due-date order.
+            if filters.reverse {
+                " ORDER BY sort_order IS NULL DESC, sort_order DESC, due_at IS NULL DESC, due_at DESC, created_at DESC".into()
+            } else {
+                " ORDER BY sort_order IS NULL, sort_order ASC, due_at IS NULL, due_at ASC, priority DESC, created_at ASC".into()
+            }
+        }
+        crate::model::SortField::Title => {
+            if filters.reverse {
+                " ORDER BY title COLLATE NOCASE DESC, created_at DESC".into()
+            } else {
+                " ORDER BY title COLLATE NOCASE ASC, created_at ASC".into()
+            }
+        }
     }
 }
 
+/// Weighs priority against how close the due date is; overdue work scores highest.
+const URGENCY_SCORE_SQL: &str = "(priority * 2 + CASE \
+        WHEN due_at IS NULL THEN 0 \
+        WHEN julianday(due_at) <= julianday('now') THEN 6 \
+        WHEN julianday(due_at) - julianday('now') <= 1 THEN 4 \
+        WHEN julianday(due_at) - julianday('now') <= 7 THEN 2 \
+        ELSE 1 \
+    END)";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -518,12 +1099,16 @@ mod tests {
             tags: vec![],
             due_before: None,
             defer_after: None,
+            dated_between: None,
             time_max: None,
             energy: None,
             priority_min: None,
+            text: None,
             include_done: false,
             sort: crate::model::SortField::Due,
             reverse: true,
+            limit: None,
+            offset: 0,
         };
         let clause = build_order_clause(&filters);
         assert!(clause.contains("due_at DESC"));
@@ -587,6 +1172,210 @@ mod tests {
         assert_eq!(ids[3], inbox.id);
     }
 
+    #[test]
+    fn urgency_sort_puts_overdue_work_first() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("init db");
+
+        let add = |text: &[&str]| TaskInput {
+            text: text.iter().map(|s| s.to_string()).collect(),
+            notes: None,
+            project: None,
+            areas: vec![],
+            status: Some(TaskStatus::Next),
+            contexts: vec![],
+            tags: vec![],
+            due_at: None,
+            defer_until: None,
+            time_estimate: None,
+            energy: None,
+            priority: None,
+            waiting_on: None,
+            waiting_since: None,
+        };
+
+        let later = db
+            .handle_add(&add(&["Later", "p:1", "due:2999-01-01"]))
+            .expect("add later");
+        let important = db
+            .handle_add(&add(&["Important", "p:2"]))
+            .expect("add important");
+        let overdue = db
+            .handle_add(&add(&["Overdue", "due:2001-01-01"]))
+            .expect("add overdue");
+
+        let mut filters = ListFilters::for_view(Some(ListView::Next));
+        filters.sort = crate::model::SortField::Urgency;
+        let ids: Vec<String> = db
+            .fetch_tasks(&filters)
+            .expect("fetch next")
+            .into_iter()
+            .filter_map(|item| match item {
+                ListOutputItem::Task(task) => Some(task.id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ids, vec![overdue.id, important.id, later.id]);
+    }
+
+    #[test]
+    fn title_sort_ignores_case_and_reverses() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("init db");
+        for text in ["banana", "Apple", "cherry"] {
+            db.handle_add(&TaskInput {
+                text: vec![text.to_string()],
+                ..Default::default()
+            })
+            .expect("add task");
+        }
+
+        let mut filters = ListFilters::for_view(Some(ListView::Inbox));
+        filters.sort = crate::model::SortField::Title;
+        let titles = |db: &Database, filters: &ListFilters| -> Vec<String> {
+            db.fetch_tasks(filters)
+                .expect("fetch inbox")
+                .into_iter()
+                .filter_map(|item| match item {
+                    ListOutputItem::Task(task) => Some(task.title),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(titles(&db, &filters), vec!["Apple", "banana", "cherry"]);
+        filters.reverse = true;
+        assert_eq!(titles(&db, &filters), vec!["cherry", "banana", "Apple"]);
+    }
+
+    #[test]
+    fn today_view_collects_overdue_due_and_starting_tasks() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("init db");
+
+        let mut add = |text: &str| {
+            db.handle_add(&TaskInput {
+                text: text.split_whitespace().map(|s| s.to_string()).collect(),
+                ..Default::default()
+            })
+            .expect("add task")
+            .title
+        };
+        add("Overdue due:2001-01-01");
+        add("Due today due:today");
+        add("Starts today defer:today");
+        add("Far off due:2999-01-01");
+        add("Undated");
+
+        let filters = ListFilters::for_view(Some(ListView::Today));
+        let mut titles: Vec<String> = db
+            .fetch_tasks(&filters)
+            .expect("fetch today")
+            .into_iter()
+            .filter_map(|item| match item {
+                ListOutputItem::Task(task) => Some(task.title),
+                _ => None,
+            })
+            .collect();
+        titles.sort();
+        assert_eq!(titles, vec!["Due today", "Overdue", "Starts today"]);
+    }
+
+    #[test]
+    fn text_filter_matches_every_word_in_title_or_notes() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("init db");
+        for text in [
+            "Renew vendor contract",
+            "Call vendor about invoice",
+            "Plan 100% offsite",
+        ] {
+            db.handle_add(&TaskInput {
+                text: vec![text.to_string()],
+                ..Default::default()
+            })
+            .expect("add task");
+        }
+        db.handle_add(&TaskInput {
+            text: vec![String::from("Legal review")],
+            notes: Some(String::from("Check the CONTRACT terms")),
+            ..Default::default()
+        })
+        .expect("add task");
+
+        let titles = |text: &str| -> Vec<String> {
+            let mut filters = ListFilters::for_view(None);
+            filters.text = Some(text.to_string());
+            filters.sort = crate::model::SortField::Title;
+            let mut titles: Vec<String> = db
+                .fetch_tasks(&filters)
+                .expect("fetch")
+                .into_iter()
+                .filter_map(|item| match item {
+                    ListOutputItem::Task(task) => Some(task.title),
+                    _ => None,
+                })
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        assert_eq!(
+            titles("contract"),
+            ["Legal review", "Renew vendor contract"]
+        );
+        assert_eq!(titles("VENDOR invoice"), ["Call vendor about invoice"]);
+        assert_eq!(titles("100%"), ["Plan 100% offsite"]);
+        assert!(titles("10_%").is_empty());
+
+        let mut filters = ListFilters::for_view(None);
+        filters.text = Some(String::from("vendor"));
+        assert_eq!(db.count_tasks(&filters).expect("count"), 2);
+    }
+
+    #[test]
+    fn done_view_pages_with_limit_and_offset() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("init db");
+
+        let mut ids = Vec::new();
+        for idx in 0..5 {
+            let outcome = db
+                .handle_add(&TaskInput {
+                    text: vec![format!("Task {}", idx)],
+                    ..Default::default()
+                })
+                .expect("add task");
+            db.mark_done(std::slice::from_ref(&outcome.id))
+                .expect("mark done");
+            ids.push(outcome.id);
+        }
+
+        let fetch_page = |offset: usize| -> Vec<String> {
+            let mut filters = ListFilters::for_view(Some(ListView::Done));
+            filters.limit = Some(2);
+            filters.offset = offset;
+            db.fetch_tasks(&filters)
+                .expect("fetch done")
+                .into_iter()
+                .filter_map(|item| match item {
+                    ListOutputItem::Task(task) => Some(task.id),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let first = fetch_page(0);
+        let second = fetch_page(2);
+        let last = fetch_page(4);
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 2);
+        assert_eq!(last.len(), 1);
+        let mut all: Vec<String> = first.into_iter().chain(second).chain(last).collect();
+        all.sort();
+        ids.sort();
+        assert_eq!(all, ids);
+    }
+
     #[test]
     fn handle_add_and_fetch_roundtrip() {
         let (config, _dir) = temp_config();
@@ -702,6 +1491,30 @@ mod tests {
         assert_eq!(items.len(), 1);
     }
 
+    #[test]
+    fn set_status_moves_task_and_stamps_completion() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+
+        let args = TaskInput {
+            text: vec!["Ship".into(), "it".into()],
+            ..Default::default()
+        };
+        let outcome = db.handle_add(&args).expect("add task");
+        let ids = std::slice::from_ref(&outcome.id);
+
+        db.set_status(ids, TaskStatus::Waiting)
+            .expect("set waiting");
+        let task = db.fetch_task(&outcome.id).expect("fetch").expect("task");
+        assert_eq!(task.status, TaskStatus::Waiting);
+        assert!(task.completed_at.is_none());
+
+        db.set_status(ids, TaskStatus::Done).expect("set done");
+        let task = db.fetch_task(&outcome.id).expect("fetch").expect("task");
+        assert_eq!(task.status, TaskStatus::Done);
+        assert!(task.completed_at.is_some());
+    }
+
     #[test]
     fn mark_someday_and_inbox_cycle_status() {
         let (config, _dir) = temp_config();
@@ -941,4 +1754,223 @@ mod tests {
         assert_eq!(summary.waiting, 1);
         assert_eq!(summary.someday, 1);
     }
+
+    #[test]
+    fn facets_count_open_tasks_per_token() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+
+        let capture = |text: &str| TaskInput {
+            text: text.split_whitespace().map(String::from).collect(),
+            ..Default::default()
+        };
+        db.handle_add(&capture("Call plumber +Home @phone"))
+            .unwrap();
+        db.handle_add(&capture("Email Alice +Work @computer #q4"))
+            .unwrap();
+        let done = db
+            .handle_add(&capture("Old report +Work @computer"))
+            .unwrap();
+        db.mark_done(&[done.id]).unwrap();
+
+        let facets = db.fetch_facets().expect("facets");
+        let pairs = |counts: &[FacetCount]| {
+            counts
+                .iter()
+                .map(|facet| (facet.name.clone(), facet.count))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            pairs(&facets.projects),
+            vec![("Home".to_string(), 1), ("Work".to_string(), 1)]
+        );
+        assert_eq!(
+            pairs(&facets.contexts),
+            vec![("computer".to_string(), 1), ("phone".to_string(), 1)]
+        );
+        assert_eq!(pairs(&facets.tags), vec![("q4".to_string(), 1)]);
+    }
+
+    #[test]
+    fn saved_views_replace_by_slot() {
+        let (config, _dir) = temp_config();
+        let db = Database::initialize(&config).expect("initialize db");
+
+        let mut view = SavedView {
+            slot: 1,
+            name: "deepwork".into(),
+            project: None,
+            contexts: vec!["computer".into()],
+            tags: vec![],
+            priority_min: Some(2),
+        };
+        db.save_view(&view).unwrap();
+        view.name = "focus".into();
+        db.save_view(&view).unwrap();
+
+        let views = db.fetch_saved_views().unwrap();
+        assert_eq!(views, vec![view]);
+    }
+
+    #[test]
+    fn data_version_tracks_other_connections() {
+        let (config, _dir) = temp_config();
+        let watcher = Database::initialize(&config).expect("initialize db");
+        let mut writer = Database::initialize(&config).expect("second connection");
+
+        let before = watcher.data_version().unwrap();
+        writer
+            .handle_add(&TaskInput {
+                text: vec!["External task".into()],
+                ..Default::default()
+            })
+            .unwrap();
+        assert_ne!(watcher.data_version().unwrap(), before);
+    }
+
+    #[test]
+    fn set_project_moves_and_clears() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+        let added = db
+            .handle_add(&TaskInput {
+                text: vec!["Draft".into(), "outline".into(), "+Book".into()],
+                ..Default::default()
+            })
+            .unwrap();
+        let ids = [added.id.clone()];
+
+        let results = db.set_project(&ids, Some("Blog")).unwrap();
+        assert!(results[0].changed);
+        let task = db.fetch_task(&added.id).unwrap().unwrap();
+        assert_eq!(task.project.as_deref(), Some("Blog"));
+
+        db.set_project(&ids, None).unwrap();
+        let task = db.fetch_task(&added.id).unwrap().unwrap();
+        assert_eq!(task.project, None);
+        assert!(!db.set_project(&["missing".into()], None).unwrap()[0].changed);
+    }
+
+    #[test]
+    fn set_priority_clamps_to_three() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+        let added = db
+            .handle_add(&TaskInput {
+                text: vec!["Ship".into(), "release".into()],
+                ..Default::default()
+            })
+            .unwrap();
+
+        db.set_priority(std::slice::from_ref(&added.id), 9).unwrap();
+        let task = db.fetch_task(&added.id).unwrap().unwrap();
+        assert_eq!(task.priority, 3);
+    }
+
+    #[test]
+    fn set_due_sets_and_clears_without_touching_status() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+        let added = db
+            .handle_add(&TaskInput {
+                text: vec!["File".into(), "taxes".into()],
+                ..Default::default()
+            })
+            .unwrap();
+        let due = Utc.with_ymd_and_hms(2030, 4, 15, 9, 0, 0).unwrap();
+
+        let task = db.set_due(&added.id, Some(due)).unwrap().unwrap();
+        assert_eq!(task.due_at, Some(due));
+        assert_eq!(task.status, added.status);
+        let task = db.set_due(&added.id, None).unwrap().unwrap();
+        assert_eq!(task.due_at, None);
+        assert!(db.set_due("missing", None).unwrap().is_none());
+    }
+
+    #[test]
+    fn timers_run_one_at_a_time() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+        let add = |db: &mut Database, title: &str| {
+            db.handle_add(&TaskInput {
+                text: vec![title.into()],
+                ..Default::default()
+            })
+            .unwrap()
+            .id
+        };
+        let first = add(&mut db, "Write");
+        let second = add(&mut db, "Review");
+
+        db.start_timer(&first).unwrap();
+        let running = db.start_timer(&second).unwrap();
+        assert_eq!(db.running_timer().unwrap(), Some(running));
+        assert!(db.fetch_time_entries(&first).unwrap()[0].ended_at.is_some());
+
+        let stopped = db.stop_timer().unwrap().expect("stopped entry");
+        assert_eq!(stopped.task_id, second);
+        assert!(db.running_timer().unwrap().is_none());
+        assert!(db.stop_timer().unwrap().is_none());
+
+        db.delete_tasks(std::slice::from_ref(&first)).unwrap();
+        assert!(db.fetch_time_entries(&first).unwrap().is_empty());
+    }
+
+    #[test]
+    fn count_tasks_matches_fetch_without_paging() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+        for text in [
+            "Sort receipts due:2020-01-01",
+            "Email Alice",
+            "Plan trip +Travel",
+        ] {
+            db.handle_add(&TaskInput {
+                text: text.split_whitespace().map(String::from).collect(),
+                ..Default::default()
+            })
+            .unwrap();
+        }
+
+        let mut filters = ListFilters::for_view(Some(ListView::Inbox));
+        filters.limit = Some(1);
+        assert_eq!(db.count_tasks(&filters).unwrap(), 3);
+        filters.due_before = Some(Utc::now());
+        assert_eq!(db.count_tasks(&filters).unwrap(), 1);
+        filters.due_before = None;
+        filters.project = Some("Travel".into());
+        assert_eq!(db.count_tasks(&filters).unwrap(), 1);
+    }
+
+    #[test]
+    fn dated_between_places_tasks_by_due_then_defer_date() {
+        let (config, _dir) = temp_config();
+        let mut db = Database::initialize(&config).expect("initialize db");
+        for text in [
+            "Pay rent due:2030-03-01",
+            "Book flights defer:2030-03-10",
+            "Renew passport due:2030-04-02 defer:2030-03-05",
+            "Undated chore",
+        ] {
+            db.handle_add(&TaskInput {
+                text: text.split_whitespace().map(String::from).collect(),
+                ..Default::default()
+            })
+            .unwrap();
+        }
+
+        let march = |day: u32| Utc.with_ymd_and_hms(2030, 3, day, 0, 0, 0).unwrap();
+        let mut filters = ListFilters::for_view(Some(ListView::Scheduled));
+        filters.dated_between = Some((march(1) - Duration::days(1), march(31)));
+        let titles: Vec<String> = db
+            .fetch_tasks(&filters)
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                ListOutputItem::Task(task) => Some(task.title),
+                ListOutputItem::Project(_) => None,
+            })
+            .collect();
+        assert_eq!(titles, vec!["Pay rent", "Book flights"]);
+    }
 }
@@ -3,10 +3,12 @@ pub mod config;
 pub mod database;
 pub mod model;
 pub mod parser;
+pub mod repeat;
 pub mod services;
 
 pub use capture::TaskInput;
 pub use config::AppConfig;
 pub use database::Database;
 pub use model::*;
-pub use services::{TasksService, ViewSnapshot};
+pub use repeat::{RepeatRule, RepeatUnit};
+pub use services::{ReviewSession, StatsService, TasksService, ViewSnapshot};
@@ -126,11 +126,46 @@ impl FromStr for EnergyLevel {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SortField {
     Due,
     Priority,
     Created,
+    Urgency,
+    Completed,
+    Title,
+    /// The order arranged by hand, e.g. by dragging rows in the desktop Next view.
+    Manual,
+}
+
+impl SortField {
+    pub const ALL: [SortField; 7] = [
+        SortField::Due,
+        SortField::Priority,
+        SortField::Created,
+        SortField::Urgency,
+        SortField::Completed,
+        SortField::Title,
+        SortField::Manual,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortField::Due => "due",
+            SortField::Priority => "priority",
+            SortField::Created => "created",
+            SortField::Urgency => "urgency",
+            SortField::Completed => "completed",
+            SortField::Title => "title",
+            SortField::Manual => "manual",
+        }
+    }
+}
+
+impl fmt::Display for SortField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 impl FromStr for SortField {
@@ -141,8 +176,12 @@ impl FromStr for SortField {
             "due" => Ok(SortField::Due),
             "priority" => Ok(SortField::Priority),
             "created" | "created_at" | "created-at" => Ok(SortField::Created),
+            "urgency" | "urgent" => Ok(SortField::Urgency),
+            "completed" | "completed_at" | "completed-at" => Ok(SortField::Completed),
+            "title" => Ok(SortField::Title),
+            "manual" => Ok(SortField::Manual),
             other => Err(anyhow!(
-                "Unknown sort field '{}': expected due|priority|created",
+                "Unknown sort field '{}': expected due|priority|created|urgency|completed|title|manual",
                 other
             )),
         }
@@ -152,6 +191,7 @@ impl FromStr for SortField {
 #[derive(Debug, Clone, ValueEnum)]
 #[clap(rename_all = "kebab-case")]
 pub enum ListView {
+    Today,
     Inbox,
     Next,
     Waiting,
@@ -164,7 +204,7 @@ pub enum ListView {
 impl ListView {
     pub fn to_status(&self) -> Option<TaskStatus> {
         match self {
-            ListView::Projects => None,
+            ListView::Projects | ListView::Today => None,
             ListView::Inbox => Some(TaskStatus::Inbox),
             ListView::Next => Some(TaskStatus::Next),
             ListView::Waiting => Some(TaskStatus::Waiting),
@@ -204,6 +244,84 @@ pub struct Task {
     pub waiting_since: Option<DateTime<Utc>>,
 }
 
+/// Done and total items of the Markdown checklist (`- [ ]` / `- [x]` lines) in a task's notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ChecklistProgress {
+    pub done: usize,
+    pub total: usize,
+}
+
+impl ChecklistProgress {
+    /// Counts checklist lines in `notes`; `None` when there are none.
+    pub fn from_notes(notes: &str) -> Option<Self> {
+        let mut progress = Self { done: 0, total: 0 };
+        for line in notes.lines() {
+            let line = line.trim_start();
+            let Some(rest) = ["- [", "* [", "+ ["]
+                .iter()
+                .find_map(|bullet| line.strip_prefix(bullet))
+            else {
+                continue;
+            };
+            let mut chars = rest.chars();
+            let (Some(mark), Some(']')) = (chars.next(), chars.next()) else {
+                continue;
+            };
+            match mark {
+                ' ' => progress.total += 1,
+                'x' | 'X' => {
+                    progress.done += 1;
+                    progress.total += 1;
+                }
+                _ => {}
+            }
+        }
+        (progress.total > 0).then_some(progress)
+    }
+}
+
+impl fmt::Display for ChecklistProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.done, self.total)
+    }
+}
+
+impl Task {
+    pub fn checklist(&self) -> Option<ChecklistProgress> {
+        self.notes
+            .as_deref()
+            .and_then(ChecklistProgress::from_notes)
+    }
+
+    /// The notes with `line` added as a paragraph of its own at the end.
+    pub fn notes_with_line(&self, line: &str) -> String {
+        match self.notes.as_deref().map(str::trim_end) {
+            Some(notes) if !notes.is_empty() => format!("{notes}\n\n{line}"),
+            _ => line.to_string(),
+        }
+    }
+
+    /// The notes with every checklist item unticked, for the next occurrence of a repeat.
+    pub fn notes_unchecked(&self) -> Option<String> {
+        let notes = self.notes.as_deref()?;
+        let lines: Vec<String> = notes
+            .lines()
+            .map(|line| {
+                let indent = line.len() - line.trim_start().len();
+                let (lead, rest) = line.split_at(indent);
+                match ["- [x]", "- [X]", "* [x]", "* [X]", "+ [x]", "+ [X]"]
+                    .iter()
+                    .find_map(|done| rest.strip_prefix(done))
+                {
+                    Some(after) => format!("{lead}{} [ ]{after}", &rest[..1]),
+                    None => line.to_string(),
+                }
+            })
+            .collect();
+        Some(lines.join("\n"))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NewTask {
     pub title: String,
@@ -254,6 +372,39 @@ impl From<&Task> for NewTask {
     }
 }
 
+/// Fields to change on a task; `None` leaves a field as it is, and `Some(None)` clears it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskChanges {
+    pub title: Option<String>,
+    /// New notes; an empty string clears them.
+    pub notes: Option<String>,
+    pub project: Option<Option<String>>,
+    pub contexts: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+    pub priority: Option<u8>,
+    pub energy: Option<Option<EnergyLevel>>,
+    pub time_estimate: Option<Option<u32>>,
+    pub due_at: Option<Option<DateTime<Utc>>>,
+    pub defer_until: Option<Option<DateTime<Utc>>>,
+    /// Who the task waits on; setting it moves the task to Waiting, clearing it back to Next.
+    pub waiting_on: Option<Option<String>>,
+}
+
+/// One step of a batch: an action applied to every task in `ids`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchOperation {
+    pub ids: Vec<String>,
+    pub action: BatchAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchAction {
+    Status(TaskStatus),
+    /// Hides the tasks until the given moment, as `Database::defer_task` does.
+    Defer(DateTime<Utc>),
+    Delete,
+}
+
 pub struct InsertableTask {
     pub id: String,
     pub data: NewTask,
@@ -287,12 +438,19 @@ pub struct ListFilters {
     pub tags: Vec<String>,
     pub due_before: Option<DateTime<Utc>>,
     pub defer_after: Option<DateTime<Utc>>,
+    /// `[start, end)` window matched against the due date, or the defer date when there is no
+    /// due date; used by calendar views.
+    pub dated_between: Option<(DateTime<Utc>, DateTime<Utc>)>,
     pub time_max: Option<u32>,
     pub energy: Option<EnergyLevel>,
     pub priority_min: Option<u8>,
+    /// Words that must each appear, ignoring ASCII case, in the title or the notes.
+    pub text: Option<String>,
     pub include_done: bool,
     pub sort: SortField,
     pub reverse: bool,
+    pub limit: Option<usize>,
+    pub offset: usize,
 }
 
 impl ListFilters {
@@ -303,10 +461,12 @@ impl ListFilters {
         }
         let include_done = matches!(view, Some(ListView::Done));
         let sort = match view {
-            Some(ListView::Next) | Some(ListView::Scheduled) => SortField::Due,
+            Some(ListView::Today) | Some(ListView::Scheduled) => SortField::Due,
+            Some(ListView::Next) => SortField::Manual,
             Some(ListView::Someday) => SortField::Priority,
             Some(ListView::Inbox) | Some(ListView::Waiting) => SortField::Created,
-            Some(ListView::Projects) | Some(ListView::Done) => SortField::Created,
+            Some(ListView::Projects) => SortField::Created,
+            Some(ListView::Done) => SortField::Completed,
             None => SortField::Due,
         };
 
@@ -318,12 +478,16 @@ impl ListFilters {
             tags: Vec::new(),
             due_before: None,
             defer_after: None,
+            dated_between: None,
             time_max: None,
             energy: None,
             priority_min: None,
+            text: None,
             include_done,
             sort,
             reverse: false,
+            limit: None,
+            offset: 0,
         }
     }
 }
@@ -337,6 +501,55 @@ pub struct ProjectSummary {
     pub someday: usize,
 }
 
+/// A project's counts plus the signals a weekly review looks for.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectHealth {
+    #[serde(flatten)]
+    pub summary: ProjectSummary,
+    /// Tasks that are neither done nor canceled.
+    pub open: usize,
+    /// Open tasks but no Next action, so nothing moves the project forward.
+    pub stalled: bool,
+    /// Most recent change to any of the project's tasks.
+    pub last_activity: DateTime<Utc>,
+}
+
+/// A named filter combination stored in one of the numbered preset slots.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SavedView {
+    pub slot: u8,
+    pub name: String,
+    pub project: Option<String>,
+    pub contexts: Vec<String>,
+    pub tags: Vec<String>,
+    pub priority_min: Option<u8>,
+}
+
+/// A stretch of time tracked against a task; `ended_at` is `None` while the timer runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimeEntry {
+    pub id: i64,
+    pub task_id: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+/// How many open tasks carry a given project, context, tag, or area.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FacetCount {
+    pub name: String,
+    pub count: usize,
+}
+
+/// Every known project, context, tag, and area with open-task counts, sorted by name.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Facets {
+    pub projects: Vec<FacetCount>,
+    pub contexts: Vec<FacetCount>,
+    pub tags: Vec<FacetCount>,
+    pub areas: Vec<FacetCount>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum ListOutputItem {
@@ -352,17 +565,43 @@ mod tests {
     fn list_filters_for_view_sets_status_and_sort() {
         let next = ListFilters::for_view(Some(ListView::Next));
         assert_eq!(next.status, Some(TaskStatus::Next));
-        assert_eq!(next.sort, SortField::Due);
+        assert_eq!(next.sort, SortField::Manual);
 
         let waiting = ListFilters::for_view(Some(ListView::Waiting));
         assert_eq!(waiting.status, Some(TaskStatus::Waiting));
         assert_eq!(waiting.sort, SortField::Created);
 
+        let today = ListFilters::for_view(Some(ListView::Today));
+        assert!(today.status.is_none());
+        assert_eq!(today.sort, SortField::Due);
+
         let projects = ListFilters::for_view(Some(ListView::Projects));
         assert!(projects.status.is_none());
         assert_eq!(projects.include_done, false);
     }
 
+    #[test]
+    fn sort_field_round_trips_through_str() {
+        for field in SortField::ALL {
+            assert_eq!(field.as_str().parse::<SortField>().unwrap(), field);
+        }
+        assert!("bogus".parse::<SortField>().is_err());
+    }
+
+    #[test]
+    fn checklist_progress_counts_markdown_task_lines() {
+        let notes = "Steps:\n- [x] Draft\n  - [ ] Review\n* [X] Send\n- [?] not an item\n- plain";
+        assert_eq!(
+            ChecklistProgress::from_notes(notes),
+            Some(ChecklistProgress { done: 2, total: 3 })
+        );
+        assert_eq!(
+            ChecklistProgress::from_notes(notes).unwrap().to_string(),
+            "2/3"
+        );
+        assert_eq!(ChecklistProgress::from_notes("no list here"), None);
+    }
+
     #[test]
     fn default_for_waiting_matches_presence() {
         assert_eq!(TaskStatus::default_for_waiting(true), TaskStatus::Waiting);
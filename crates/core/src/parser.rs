@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::fmt;
 
 use anyhow::{anyhow, Context, Result};
 use chrono::{prelude::*, Duration, Months};
@@ -7,6 +8,7 @@ use regex::Regex;
 
 use crate::capture::TaskInput;
 use crate::model::{AddOutcome, EnergyLevel, InsertableTask, NewTask, TaskStatus};
+use crate::repeat::RepeatRule;
 
 #[derive(Debug, Clone)]
 pub struct ParsedTask {
@@ -27,10 +29,48 @@ struct InlineTokens {
     time_estimate: Option<u32>,
     energy: Option<EnergyLevel>,
     priority: Option<u8>,
+    repeat: Option<RepeatRule>,
     waiting_on: Option<String>,
     waiting_since: Option<DateTime<Utc>>,
 }
 
+/// An inline token whose value did not parse, e.g. `due:tomorow`. Returned inside the
+/// `anyhow::Error` from [`parse_capture`], so frontends can point at it with `downcast_ref`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenError {
+    /// The token as typed, without trailing punctuation.
+    pub token: String,
+    /// Byte offset of the token in the capture text (its words joined by single spaces).
+    pub start: usize,
+    pub message: String,
+    /// A corrected token that parses, such as `due:tomorrow`, when there is a likely one.
+    pub suggestion: Option<String>,
+}
+
+impl TokenError {
+    fn new(start: usize, prefix: &str, spec: &str, err: impl fmt::Display) -> Self {
+        Self {
+            token: format!("{prefix}{spec}"),
+            start,
+            message: err.to_string(),
+            suggestion: suggest_token(prefix, spec),
+        }
+    }
+
+    /// Byte range of the token in the capture text.
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start..self.start + self.token.len()
+    }
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for TokenError {}
+
 pub fn prepare_new_task(input: &TaskInput) -> Result<(InsertableTask, AddOutcome)> {
     let parsed = parse_capture(input)?;
     let insertable = parsed.task.clone().into_insertable();
@@ -126,7 +166,7 @@ pub fn parse_capture(input: &TaskInput) -> Result<ParsedTask> {
         time_estimate,
         due_at,
         defer_until,
-        repeat: None,
+        repeat: inline.repeat.map(|rule| rule.to_string()),
         waiting_on,
         waiting_since,
     };
@@ -143,6 +183,11 @@ fn parse_inline_tokens(text: &str) -> Result<InlineTokens> {
     let mut waiting_since_token: Option<DateTime<Utc>> = None;
 
     for raw_piece in text.split_whitespace() {
+        // `split_whitespace` yields slices of `text`, so this is the piece's byte offset.
+        let start = raw_piece.as_ptr() as usize - text.as_ptr() as usize;
+        let invalid = |prefix: &str, spec: &str, err: &dyn fmt::Display| {
+            anyhow::Error::new(TokenError::new(start, prefix, spec, err))
+        };
         let (piece, trailing) = strip_trailing_punctuation(raw_piece);
         if piece.starts_with('@') && piece.len() > 1 {
             result
@@ -170,35 +215,53 @@ fn parse_inline_tokens(text: &str) -> Result<InlineTokens> {
             continue;
         }
         if let Some(spec) = piece.strip_prefix("due:") {
-            result.due_at = Some(parse_date_spec(spec)?);
+            result.due_at = Some(parse_date_spec(spec).map_err(|err| invalid("due:", spec, &err))?);
             if let Some(rest) = trailing {
                 push_trailing(&mut result.title_words, rest);
             }
             continue;
         }
         if let Some(spec) = piece.strip_prefix("defer:") {
-            result.defer_until = Some(parse_date_spec(spec)?);
+            result.defer_until =
+                Some(parse_date_spec(spec).map_err(|err| invalid("defer:", spec, &err))?);
             if let Some(rest) = trailing {
                 push_trailing(&mut result.title_words, rest);
             }
             continue;
         }
         if let Some(spec) = piece.strip_prefix("t:") {
-            result.time_estimate = Some(parse_duration_minutes(spec)?);
+            result.time_estimate =
+                Some(parse_duration_minutes(spec).map_err(|err| invalid("t:", spec, &err))?);
             if let Some(rest) = trailing {
                 push_trailing(&mut result.title_words, rest);
             }
             continue;
         }
         if let Some(spec) = piece.strip_prefix("e:") {
-            result.energy = Some(spec.parse::<EnergyLevel>()?);
+            result.energy = Some(
+                spec.parse::<EnergyLevel>()
+                    .map_err(|err| invalid("e:", spec, &err))?,
+            );
             if let Some(rest) = trailing {
                 push_trailing(&mut result.title_words, rest);
             }
             continue;
         }
         if let Some(spec) = piece.strip_prefix("p:") {
-            result.priority = Some(spec.parse::<u8>()?.min(3));
+            let priority = spec
+                .parse::<u8>()
+                .map_err(|err| invalid("p:", spec, &err))?;
+            result.priority = Some(priority.min(3));
+            if let Some(rest) = trailing {
+                push_trailing(&mut result.title_words, rest);
+            }
+            continue;
+        }
+        if let Some(spec) = piece.strip_prefix("rep:") {
+            result.repeat = Some(
+                spec.parse::<RepeatRule>()
+                    .map_err(|err| invalid("rep:", spec, &err))?,
+            );
             if let Some(rest) = trailing {
                 push_trailing(&mut result.title_words, rest);
             }
@@ -214,7 +277,8 @@ fn parse_inline_tokens(text: &str) -> Result<InlineTokens> {
             continue;
         }
         if let Some(spec) = piece.strip_prefix("since:") {
-            waiting_since_token = Some(parse_date_spec(spec)?);
+            waiting_since_token =
+                Some(parse_date_spec(spec).map_err(|err| invalid("since:", spec, &err))?);
             if let Some(rest) = trailing {
                 push_trailing(&mut result.title_words, rest);
             }
@@ -254,8 +318,16 @@ fn merge_lists(mut primary: Vec<String>, secondary: Vec<String>) -> Vec<String>
     primary
 }
 
-fn parse_duration_minutes(spec: &str) -> Result<u32> {
+/// Minutes in a `t:` estimate such as `30`, `45m`, `2h`, or `1h30m`.
+pub fn parse_duration_minutes(spec: &str) -> Result<u32> {
     let spec = spec.trim().to_ascii_lowercase();
+    if let Some((hours, minutes)) = spec.split_once('h').filter(|(_, rest)| !rest.is_empty()) {
+        let minutes = minutes.strip_suffix('m').unwrap_or(minutes);
+        return Ok(hours
+            .parse::<u32>()?
+            .saturating_mul(60)
+            .saturating_add(minutes.parse::<u32>()?));
+    }
     if spec.ends_with("m") {
         let number = &spec[..spec.len() - 1];
         Ok(number.parse::<u32>()?)
@@ -416,6 +488,124 @@ fn parse_weekday(label: &str) -> Option<Weekday> {
     }
 }
 
+/// A likely intended token for a `prefix` value that did not parse, checked to parse itself.
+fn suggest_token(prefix: &str, spec: &str) -> Option<String> {
+    let lower = spec.trim().to_ascii_lowercase();
+    let fixed = match prefix {
+        "due:" | "defer:" | "since:" => suggest_date(&lower)?,
+        "t:" => suggest_duration(&lower).filter(|fixed| parse_duration_minutes(fixed).is_ok())?,
+        "e:" => closest_word(&lower, &["low", "med", "high"])?.to_string(),
+        "rep:" => closest_word(
+            &lower,
+            &["daily", "weekdays", "weekly", "monthly", "yearly"],
+        )?
+        .to_string(),
+        "p:" => match lower.trim_start_matches(['p', '!']) {
+            "low" => "1".to_string(),
+            "med" | "medium" | "normal" => "2".to_string(),
+            "high" | "urgent" => "3".to_string(),
+            digits => digits.parse::<u8>().ok()?.min(3).to_string(),
+        },
+        _ => return None,
+    };
+    Some(format!("{prefix}{fixed}"))
+}
+
+const DATE_WORDS: [&str; 17] = [
+    "today",
+    "tomorrow",
+    "now",
+    "mon",
+    "tue",
+    "wed",
+    "thu",
+    "fri",
+    "sat",
+    "sun",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+fn suggest_date(spec: &str) -> Option<String> {
+    // `3`, `3d`, `+3`: relative offsets missing the `+` or the unit, which most often means days.
+    let offset = spec.trim_start_matches('+');
+    let mut candidates = vec![format!("+{offset}d"), format!("+{offset}")];
+    if let Some(weeks) = offset.strip_suffix("wk") {
+        candidates.push(format!("+{weeks}w"));
+    }
+    // `2025/06/01`, `2025.6.1`: the date with dashes and padding.
+    let parts: Vec<&str> = spec.split(['/', '.', '-']).collect();
+    if let [year, month, day] = parts[..] {
+        if let Some(date) = year
+            .parse()
+            .ok()
+            .zip(month.parse().ok())
+            .zip(day.parse().ok())
+            .and_then(|((year, month), day)| NaiveDate::from_ymd_opt(year, month, day))
+        {
+            candidates.push(date.format("%Y-%m-%d").to_string());
+        }
+    }
+    candidates.extend(closest_word(spec, &DATE_WORDS).map(str::to_string));
+    candidates
+        .into_iter()
+        .find(|candidate| candidate != spec && parse_date_spec(candidate).is_ok())
+}
+
+fn suggest_duration(spec: &str) -> Option<String> {
+    let digits = spec.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let unit = match &spec[digits.len()..] {
+        "min" | "mins" | "minute" | "minutes" => "m",
+        "hr" | "hrs" | "hour" | "hours" => "h",
+        _ => {
+            // `1.5h` as `1h30m`.
+            let hours: f32 = spec.strip_suffix('h')?.parse().ok()?;
+            let minutes = (hours * 60.0).round() as u32;
+            return Some(format!("{}h{}m", minutes / 60, minutes % 60));
+        }
+    };
+    Some(format!("{digits}{unit}"))
+}
+
+/// The word in `words` within two edits of `value`, if exactly one is closest.
+fn closest_word<'a>(value: &str, words: &[&'a str]) -> Option<&'a str> {
+    let mut best: Option<(usize, &'a str)> = None;
+    let mut tied = false;
+    for word in words {
+        let distance = edit_distance(value, word);
+        match best {
+            Some((least, _)) if distance > least => {}
+            Some((least, _)) if distance == least => tied = true,
+            _ => {
+                best = Some((distance, word));
+                tied = false;
+            }
+        }
+    }
+    best.filter(|(distance, _)| *distance <= 2 && !tied)
+        .map(|(_, word)| word)
+}
+
+/// Levenshtein distance between `a` and `b`, by character.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(ca != *cb);
+            current.push(substitute.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,6 +651,10 @@ mod tests {
         assert_eq!(parse_duration_minutes("30").unwrap(), 30);
         assert_eq!(parse_duration_minutes("45m").unwrap(), 45);
         assert_eq!(parse_duration_minutes("2h").unwrap(), 120);
+        assert_eq!(parse_duration_minutes("1h30m").unwrap(), 90);
+        assert_eq!(parse_duration_minutes("1h15").unwrap(), 75);
+        assert!(parse_duration_minutes("soon").is_err());
+        assert!(parse_duration_minutes("1hm").is_err());
     }
 
     #[test]
@@ -533,4 +727,57 @@ mod tests {
         let date = task.data.due_at.expect("due date");
         assert_eq!(date.date_naive().to_string(), "2025-12-24");
     }
+
+    #[test]
+    fn invalid_tokens_point_at_the_token_with_a_fix() {
+        let input = TaskInput {
+            text: vec![
+                "Call".into(),
+                "Sam".into(),
+                "due:tomorow,".into(),
+                "@phone".into(),
+            ],
+            ..TaskInput::default()
+        };
+        let err = parse_capture(&input).unwrap_err();
+        let token = err.downcast_ref::<TokenError>().expect("token error");
+        assert_eq!(token.token, "due:tomorow");
+        assert_eq!(
+            &"Call Sam due:tomorow, @phone"[token.range()],
+            "due:tomorow"
+        );
+        assert_eq!(token.suggestion.as_deref(), Some("due:tomorrow"));
+        assert!(err.to_string().contains("Unrecognized date specification"));
+
+        let suggest = |text: &str| {
+            let input = TaskInput {
+                text: vec!["Task".into(), text.into()],
+                ..TaskInput::default()
+            };
+            let err = parse_capture(&input).unwrap_err();
+            err.downcast_ref::<TokenError>().unwrap().suggestion.clone()
+        };
+        assert_eq!(suggest("due:3d").as_deref(), Some("due:+3d"));
+        assert_eq!(
+            suggest("defer:2025/06/01").as_deref(),
+            Some("defer:2025-06-01")
+        );
+        assert_eq!(suggest("e:hgih").as_deref(), Some("e:high"));
+        assert_eq!(suggest("t:30min").as_deref(), Some("t:30m"));
+        assert_eq!(suggest("t:1.5h").as_deref(), Some("t:1h30m"));
+        assert_eq!(suggest("p:high").as_deref(), Some("p:3"));
+        assert_eq!(suggest("rep:wekly").as_deref(), Some("rep:weekly"));
+        assert_eq!(suggest("due:whenever"), None);
+    }
+
+    #[test]
+    fn repeat_token_stores_the_canonical_rule() {
+        let input = TaskInput {
+            text: vec!["Water".into(), "plants".into(), "rep:w:fri,mon.".into()],
+            ..TaskInput::default()
+        };
+        let parsed = parse_capture(&input).unwrap();
+        assert_eq!(parsed.title, "Water plants.");
+        assert_eq!(parsed.task.repeat.as_deref(), Some("1w:mon,fri"));
+    }
 }
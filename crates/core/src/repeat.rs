@@ -0,0 +1,303 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use chrono::{prelude::*, Duration, Months};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl RepeatUnit {
+    fn letter(self) -> char {
+        match self {
+            RepeatUnit::Day => 'd',
+            RepeatUnit::Week => 'w',
+            RepeatUnit::Month => 'm',
+            RepeatUnit::Year => 'y',
+        }
+    }
+
+    fn noun(self) -> &'static str {
+        match self {
+            RepeatUnit::Day => "day",
+            RepeatUnit::Week => "week",
+            RepeatUnit::Month => "month",
+            RepeatUnit::Year => "year",
+        }
+    }
+}
+
+/// How often a task comes back once it is done, as written after `rep:` and stored in the
+/// `repeat` column: `1d`, `2w`, `1w:mon,thu`, `1m`, `1y`. Parsing also takes `daily`, `weekly`,
+/// `monthly`, `yearly`, `weekdays`, and bare weekdays such as `fri`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatRule {
+    pub every: u32,
+    pub unit: RepeatUnit,
+    /// Days of the week a weekly rule falls on, Monday first; empty repeats on the same day.
+    pub weekdays: Vec<Weekday>,
+}
+
+impl RepeatRule {
+    pub fn new(every: u32, unit: RepeatUnit) -> Self {
+        Self {
+            every: every.max(1),
+            unit,
+            weekdays: Vec::new(),
+        }
+    }
+
+    /// A weekly rule on `weekdays`, kept in Monday-first order without repeats.
+    pub fn weekly_on(every: u32, weekdays: &[Weekday]) -> Self {
+        let mut rule = Self::new(every, RepeatUnit::Week);
+        rule.weekdays = weekdays.to_vec();
+        rule.weekdays.sort_by_key(|day| day.num_days_from_monday());
+        rule.weekdays.dedup();
+        rule
+    }
+
+    /// Plain-language form such as "every 2 weeks on Friday" or "every weekday".
+    pub fn summary(&self) -> String {
+        let workweek = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ];
+        if self.every == 1 && self.weekdays == workweek {
+            return "every weekday".to_string();
+        }
+        let mut summary = if self.every == 1 {
+            format!("every {}", self.unit.noun())
+        } else {
+            format!("every {} {}s", self.every, self.unit.noun())
+        };
+        if !self.weekdays.is_empty() {
+            let names: Vec<String> = self.weekdays.iter().map(weekday_name).collect();
+            summary.push_str(" on ");
+            summary.push_str(&join_names(&names));
+        }
+        summary
+    }
+
+    /// The first occurrence after `from`, at the same local time of day.
+    pub fn next_after(&self, from: DateTime<Utc>) -> DateTime<Utc> {
+        let local = from.with_timezone(&Local).naive_local();
+        let date = local.date();
+        let next = match self.unit {
+            RepeatUnit::Day => date + Duration::days(self.every.into()),
+            RepeatUnit::Week if self.weekdays.is_empty() => {
+                date + Duration::weeks(self.every.into())
+            }
+            RepeatUnit::Week => self.next_weekday(date),
+            RepeatUnit::Month => add_months(date, self.every),
+            RepeatUnit::Year => add_months(date, self.every.saturating_mul(12)),
+        };
+        let next = next.and_time(local.time());
+        Local
+            .from_local_datetime(&next)
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+            // The time does not exist that day (a DST gap), so keep the same UTC offset.
+            .unwrap_or_else(|| from + (next - local))
+    }
+
+    /// The next listed weekday later this week, otherwise the first one `every` weeks on.
+    fn next_weekday(&self, date: NaiveDate) -> NaiveDate {
+        let today = date.weekday().num_days_from_monday();
+        if let Some(day) = self
+            .weekdays
+            .iter()
+            .map(|day| day.num_days_from_monday())
+            .find(|day| *day > today)
+        {
+            return date + Duration::days((day - today).into());
+        }
+        let monday = date - Duration::days(today.into());
+        let first = self.weekdays[0].num_days_from_monday();
+        monday + Duration::weeks(self.every.into()) + Duration::days(first.into())
+    }
+}
+
+impl fmt::Display for RepeatRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.every, self.unit.letter())?;
+        if !self.weekdays.is_empty() {
+            let days: Vec<String> = self
+                .weekdays
+                .iter()
+                .map(|day| day.to_string().to_ascii_lowercase())
+                .collect();
+            write!(f, ":{}", days.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RepeatRule {
+    type Err = anyhow::Error;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let lower = spec.trim().to_ascii_lowercase();
+        match lower.as_str() {
+            "daily" | "day" => return Ok(Self::new(1, RepeatUnit::Day)),
+            "weekly" | "week" => return Ok(Self::new(1, RepeatUnit::Week)),
+            "monthly" | "month" => return Ok(Self::new(1, RepeatUnit::Month)),
+            "yearly" | "year" | "annually" => return Ok(Self::new(1, RepeatUnit::Year)),
+            "weekdays" | "weekday" => {
+                return Ok(Self::weekly_on(
+                    1,
+                    &[
+                        Weekday::Mon,
+                        Weekday::Tue,
+                        Weekday::Wed,
+                        Weekday::Thu,
+                        Weekday::Fri,
+                    ],
+                ))
+            }
+            _ => {}
+        }
+        if let Some(days) = parse_weekdays(&lower) {
+            return Ok(Self::weekly_on(1, &days));
+        }
+
+        let unknown = || {
+            anyhow!(
+                "Unrecognized repeat '{}'. Try daily, weekdays, weekly, 2w:fri, monthly, yearly",
+                spec.trim()
+            )
+        };
+        let (interval, days) = match lower.split_once(':') {
+            Some((interval, days)) => (interval, Some(days)),
+            None => (lower.as_str(), None),
+        };
+        let unit = match interval.chars().last() {
+            Some('d') => RepeatUnit::Day,
+            Some('w') => RepeatUnit::Week,
+            Some('m') => RepeatUnit::Month,
+            Some('y') => RepeatUnit::Year,
+            _ => return Err(unknown()),
+        };
+        let digits = &interval[..interval.len() - 1];
+        let every = if digits.is_empty() {
+            1
+        } else {
+            digits.parse::<u32>().map_err(|_| unknown())?
+        };
+        if every == 0 {
+            return Err(anyhow!("A repeat interval must be at least 1"));
+        }
+        match days {
+            None => Ok(Self::new(every, unit)),
+            Some(days) if unit == RepeatUnit::Week => parse_weekdays(days)
+                .map(|days| Self::weekly_on(every, &days))
+                .ok_or_else(unknown),
+            Some(_) => Err(anyhow!("Only weekly repeats take weekdays, as in 2w:fri")),
+        }
+    }
+}
+
+/// `mon,thu` as weekdays; `None` unless every entry names one.
+fn parse_weekdays(list: &str) -> Option<Vec<Weekday>> {
+    list.split(',')
+        .map(|day| day.trim().parse::<Weekday>().ok())
+        .collect::<Option<Vec<_>>>()
+        .filter(|days| !days.is_empty())
+}
+
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    // Days past the end of a shorter month land on its last day.
+    date.checked_add_months(Months::new(months)).unwrap_or(date)
+}
+
+fn weekday_name(day: &Weekday) -> String {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+    .to_string()
+}
+
+/// `Monday`, `Monday and Friday`, `Monday, Wednesday and Friday`.
+fn join_names(names: &[String]) -> String {
+    match names {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Local
+            .with_ymd_and_hms(y, m, d, h, 0, 0)
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn parses_aliases_and_intervals_into_canonical_rules() {
+        let cases = [
+            ("daily", "1d", "every day"),
+            ("weekdays", "1w:mon,tue,wed,thu,fri", "every weekday"),
+            ("2w:fri", "2w:fri", "every 2 weeks on Friday"),
+            (
+                "w:thu,mon",
+                "1w:mon,thu",
+                "every week on Monday and Thursday",
+            ),
+            ("fri", "1w:fri", "every week on Friday"),
+            ("3D", "3d", "every 3 days"),
+            ("monthly", "1m", "every month"),
+            ("2y", "2y", "every 2 years"),
+        ];
+        for (spec, canonical, summary) in cases {
+            let rule: RepeatRule = spec.parse().unwrap();
+            assert_eq!(rule.to_string(), canonical, "{spec}");
+            assert_eq!(rule.summary(), summary, "{spec}");
+            assert_eq!(canonical.parse::<RepeatRule>().unwrap(), rule);
+        }
+        for bad in ["", "often", "0d", "2m:fri", "w:funday"] {
+            assert!(bad.parse::<RepeatRule>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn next_after_keeps_the_time_and_follows_weekdays() {
+        // 2025-06-04 is a Wednesday.
+        let wednesday = local(2025, 6, 4, 9);
+        let rule = |spec: &str| spec.parse::<RepeatRule>().unwrap();
+        assert_eq!(rule("2d").next_after(wednesday), local(2025, 6, 6, 9));
+        assert_eq!(rule("1w").next_after(wednesday), local(2025, 6, 11, 9));
+        assert_eq!(
+            rule("w:mon,fri").next_after(wednesday),
+            local(2025, 6, 6, 9)
+        );
+        assert_eq!(rule("2w:mon").next_after(wednesday), local(2025, 6, 16, 9));
+        assert_eq!(
+            rule("weekdays").next_after(local(2025, 6, 6, 9)),
+            local(2025, 6, 9, 9)
+        );
+        assert_eq!(
+            rule("1m").next_after(local(2025, 1, 31, 9)),
+            local(2025, 2, 28, 9)
+        );
+        assert_eq!(rule("1y").next_after(wednesday), local(2026, 6, 4, 9));
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, Utc};
+
+use crate::model::{Task, TaskStatus};
+
+/// Output formats for [`export_tasks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Markdown,
+    /// iCalendar `VTODO` entries, for calendar and reminder apps.
+    Ics,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 4] = [
+        ExportFormat::Json,
+        ExportFormat::Csv,
+        ExportFormat::Markdown,
+        ExportFormat::Ics,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Ics => "ics",
+        }
+    }
+
+    /// Guesses the format from a file name's extension.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        extension.parse().ok()
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(ExportFormat::Json),
+            "csv" => Ok(ExportFormat::Csv),
+            "md" | "markdown" => Ok(ExportFormat::Markdown),
+            "ics" | "ical" => Ok(ExportFormat::Ics),
+            other => bail!(
+                "unknown export format '{}' (expected json, csv, md, or ics)",
+                other
+            ),
+        }
+    }
+}
+
+const CSV_HEADER: [&str; 13] = [
+    "id",
+    "title",
+    "status",
+    "project",
+    "contexts",
+    "tags",
+    "priority",
+    "due_at",
+    "defer_until",
+    "waiting_on",
+    "created_at",
+    "completed_at",
+    "notes",
+];
+
+/// Renders `tasks` in `format`, keeping their order.
+pub fn export_tasks(tasks: &[Task], format: ExportFormat) -> Result<String> {
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(tasks)?),
+        ExportFormat::Csv => Ok(tasks_to_csv(tasks)),
+        ExportFormat::Markdown => Ok(tasks_to_markdown(tasks)),
+        ExportFormat::Ics => Ok(tasks_to_ics(tasks)),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn tasks_to_csv(tasks: &[Task]) -> String {
+    let timestamp = |at: Option<DateTime<Utc>>| at.map(|at| at.to_rfc3339()).unwrap_or_default();
+    let mut out = CSV_HEADER.join(",");
+    out.push('\n');
+    for task in tasks {
+        let row = [
+            task.id.clone(),
+            task.title.clone(),
+            task.status.as_str().to_string(),
+            task.project.clone().unwrap_or_default(),
+            task.contexts.join(" "),
+            task.tags.join(" "),
+            task.priority.to_string(),
+            timestamp(task.due_at),
+            timestamp(task.defer_until),
+            task.waiting_on.clone().unwrap_or_default(),
+            task.created_at.to_rfc3339(),
+            timestamp(task.completed_at),
+            task.notes.clone().unwrap_or_default(),
+        ];
+        let fields: Vec<String> = row.iter().map(|value| csv_field(value)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// A checklist with the capture tokens inline and notes indented underneath.
+fn tasks_to_markdown(tasks: &[Task]) -> String {
+    let mut out = String::new();
+    for task in tasks {
+        let done = matches!(task.status, TaskStatus::Done | TaskStatus::Canceled);
+        out.push_str(if done { "- [x] " } else { "- [ ] " });
+        out.push_str(&task.title);
+        let mut meta: Vec<String> = Vec::new();
+        if let Some(project) = &task.project {
+            meta.push(format!("+{}", project));
+        }
+        meta.extend(task.contexts.iter().map(|context| format!("@{}", context)));
+        meta.extend(task.tags.iter().map(|tag| format!("#{}", tag)));
+        if let Some(due) = task.due_at {
+            meta.push(format!(
+                "due:{}",
+                due.with_timezone(&Local).format("%Y-%m-%d")
+            ));
+        }
+        if let Some(who) = &task.waiting_on {
+            meta.push(format!("wait:{}", who));
+        }
+        if !meta.is_empty() {
+            out.push_str(&format!(" `{}`", meta.join(" ")));
+        }
+        out.push('\n');
+        if let Some(notes) = task
+            .notes
+            .as_deref()
+            .filter(|notes| !notes.trim().is_empty())
+        {
+            for line in notes.lines() {
+                out.push_str("  ");
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+/// One `VTODO` per task; due and defer dates become `DUE` and `DTSTART`, the project and tags
+/// become categories.
+fn tasks_to_ics(tasks: &[Task]) -> String {
+    let stamp = |at: DateTime<Utc>| at.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//cpt.run//cpt.run//EN".to_string(),
+    ];
+    for task in tasks {
+        lines.push("BEGIN:VTODO".into());
+        lines.push(format!("UID:{}@cpt.run", task.id));
+        lines.push(format!("DTSTAMP:{}", stamp(task.updated_at)));
+        lines.push(format!("CREATED:{}", stamp(task.created_at)));
+        lines.push(format!("SUMMARY:{}", ics_text(&task.title)));
+        if let Some(defer) = task.defer_until {
+            lines.push(format!("DTSTART:{}", stamp(defer)));
+        }
+        if let Some(due) = task.due_at {
+            lines.push(format!("DUE:{}", stamp(due)));
+        }
+        let status = match task.status {
+            TaskStatus::Done => "COMPLETED",
+            TaskStatus::Canceled => "CANCELLED",
+            _ => "NEEDS-ACTION",
+        };
+        lines.push(format!("STATUS:{}", status));
+        if let Some(completed) = task.completed_at {
+            lines.push(format!("COMPLETED:{}", stamp(completed)));
+        }
+        let categories: Vec<String> = task
+            .project
+            .iter()
+            .chain(task.tags.iter())
+            .map(|category| ics_text(category))
+            .collect();
+        if !categories.is_empty() {
+            lines.push(format!("CATEGORIES:{}", categories.join(",")));
+        }
+        if let Some(notes) = task
+            .notes
+            .as_deref()
+            .filter(|notes| !notes.trim().is_empty())
+        {
+            lines.push(format!("DESCRIPTION:{}", ics_text(notes)));
+        }
+        lines.push("END:VTODO".into());
+    }
+    lines.push("END:VCALENDAR".into());
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold_ics_line(&line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Escapes an iCalendar TEXT value.
+fn ics_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line to 75 octets, continuing on lines that start with a space.
+fn fold_ics_line(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(ch);
+        width += ch.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture::TaskInput;
+    use crate::config::AppConfig;
+    use crate::database::Database;
+    use crate::model::{ListFilters, ListOutputItem};
+    use tempfile::TempDir;
+
+    #[test]
+    fn exports_tasks_as_json_csv_and_markdown() {
+        let dir = TempDir::new().unwrap();
+        let config = AppConfig::from_data_dir(dir.path().to_path_buf()).unwrap();
+        let mut db = Database::initialize(&config).unwrap();
+        for text in ["Call \"Sam\", maybe +Home @phone", "Plan trip #travel"] {
+            db.handle_add(&TaskInput {
+                text: text.split_whitespace().map(String::from).collect(),
+                ..Default::default()
+            })
+            .unwrap();
+        }
+        let tasks: Vec<Task> = db
+            .fetch_tasks(&ListFilters::for_view(None))
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                ListOutputItem::Task(task) => Some(*task),
+                ListOutputItem::Project(_) => None,
+            })
+            .collect();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&export_tasks(&tasks, ExportFormat::Json).unwrap()).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+
+        let csv = export_tasks(&tasks, ExportFormat::Csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), CSV_HEADER.join(","));
+        assert!(csv.contains("\"Call \"\"Sam\"\", maybe\""));
+        assert_eq!(csv.lines().count(), 3);
+
+        let markdown = export_tasks(&tasks, ExportFormat::Markdown).unwrap();
+        assert!(markdown.contains("- [ ] Plan trip `#travel`"));
+        assert!(markdown.contains("`+Home @phone`"));
+
+        let ics = export_tasks(&tasks, ExportFormat::Ics).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+        assert!(ics.contains("SUMMARY:Call \"Sam\"\\, maybe\r\n"));
+        assert!(ics.contains("CATEGORIES:Home\r\n"));
+        assert!(ics.contains("STATUS:NEEDS-ACTION"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+
+        assert_eq!(
+            ExportFormat::from_path("out/tasks.CSV"),
+            Some(ExportFormat::Csv)
+        );
+        assert_eq!("ical".parse::<ExportFormat>().unwrap(), ExportFormat::Ics);
+        assert!("xml".parse::<ExportFormat>().is_err());
+    }
+}
@@ -1,3 +1,9 @@
+pub mod export;
+pub mod review;
+pub mod stats;
 pub mod tasks;
 
+pub use export::{export_tasks, ExportFormat};
+pub use review::{Disposition, ReviewItem, ReviewSession, ReviewStage, ReviewSummary};
+pub use stats::{EstimateEntry, StatsService, StatsSnapshot, WaitingEntry};
 pub use tasks::{TasksService, ViewSnapshot};
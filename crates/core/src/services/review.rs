@@ -0,0 +1,212 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::database::Database;
+use crate::model::{ListFilters, ListOutputItem, ListView, Task, TaskStatus};
+
+/// Which part of the review an item belongs to: inbox triage first, then one stage per project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ReviewStage {
+    Inbox,
+    Project(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewItem {
+    pub stage: ReviewStage,
+    pub task: Task,
+}
+
+/// What to do with the item under review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disposition {
+    Next,
+    Someday,
+    Delete,
+    Defer(DateTime<Utc>),
+    /// Leave the task as it is.
+    Keep,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReviewSummary {
+    pub next: usize,
+    pub someday: usize,
+    pub deleted: usize,
+    pub deferred: usize,
+    pub kept: usize,
+}
+
+impl ReviewSummary {
+    pub fn reviewed(&self) -> usize {
+        self.next + self.someday + self.deleted + self.deferred + self.kept
+    }
+}
+
+/// A guided weekly review over a snapshot of the inbox and every project's open tasks.
+///
+/// The item list is fixed when the session starts, so tasks captured mid-review wait for the
+/// next one.
+#[derive(Debug, Clone)]
+pub struct ReviewSession {
+    items: Vec<ReviewItem>,
+    position: usize,
+    summary: ReviewSummary,
+}
+
+impl ReviewSession {
+    pub fn load(db: &Database) -> Result<Self> {
+        let fetch = |view: Option<ListView>| -> Result<Vec<Task>> {
+            Ok(db
+                .fetch_tasks(&ListFilters::for_view(view))?
+                .into_iter()
+                .filter_map(|item| match item {
+                    ListOutputItem::Task(task) => Some(*task),
+                    ListOutputItem::Project(_) => None,
+                })
+                .collect())
+        };
+
+        let mut items: Vec<ReviewItem> = fetch(Some(ListView::Inbox))?
+            .into_iter()
+            .map(|task| ReviewItem {
+                stage: ReviewStage::Inbox,
+                task,
+            })
+            .collect();
+        let mut project_items: Vec<ReviewItem> = fetch(None)?
+            .into_iter()
+            .filter(|task| !matches!(task.status, TaskStatus::Inbox | TaskStatus::Done))
+            .filter_map(|task| {
+                let project = task.project.clone()?;
+                Some(ReviewItem {
+                    stage: ReviewStage::Project(project),
+                    task,
+                })
+            })
+            .collect();
+        project_items.sort_by(|a, b| match (&a.stage, &b.stage) {
+            (ReviewStage::Project(a), ReviewStage::Project(b)) => {
+                a.to_lowercase().cmp(&b.to_lowercase())
+            }
+            _ => std::cmp::Ordering::Equal,
+        });
+        items.extend(project_items);
+        Ok(Self::from_items(items))
+    }
+
+    pub fn from_items(items: Vec<ReviewItem>) -> Self {
+        Self {
+            items,
+            position: 0,
+            summary: ReviewSummary::default(),
+        }
+    }
+
+    pub fn current(&self) -> Option<&ReviewItem> {
+        self.items.get(self.position)
+    }
+
+    /// Zero-based position and total item count.
+    pub fn progress(&self) -> (usize, usize) {
+        (self.position, self.items.len())
+    }
+
+    /// Items left in the current stage, including the current one.
+    pub fn remaining_in_stage(&self) -> usize {
+        let Some(current) = self.current() else {
+            return 0;
+        };
+        self.items[self.position..]
+            .iter()
+            .take_while(|item| item.stage == current.stage)
+            .count()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.items.len()
+    }
+
+    pub fn summary(&self) -> &ReviewSummary {
+        &self.summary
+    }
+
+    /// Applies `disposition` to the current item and moves on to the next one.
+    pub fn apply(&mut self, db: &Database, disposition: Disposition) -> Result<()> {
+        let Some(item) = self.current() else {
+            return Ok(());
+        };
+        let ids = [item.task.id.clone()];
+        match disposition {
+            Disposition::Next => {
+                db.mark_next(&ids)?;
+                self.summary.next += 1;
+            }
+            Disposition::Someday => {
+                db.mark_someday(&ids)?;
+                self.summary.someday += 1;
+            }
+            Disposition::Delete => {
+                db.delete_tasks(&ids)?;
+                self.summary.deleted += 1;
+            }
+            Disposition::Defer(until) => {
+                db.defer_task(&ids[0], Some(until))?;
+                self.summary.deferred += 1;
+            }
+            Disposition::Keep => self.summary.kept += 1,
+        }
+        self.position += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture::TaskInput;
+    use crate::config::AppConfig;
+    use tempfile::TempDir;
+
+    #[test]
+    fn review_walks_inbox_then_projects() {
+        let dir = TempDir::new().unwrap();
+        let config = AppConfig::from_data_dir(dir.path().to_path_buf()).unwrap();
+        let mut db = Database::initialize(&config).unwrap();
+        let mut add = |text: &str, status: Option<TaskStatus>| {
+            db.handle_add(&TaskInput {
+                text: text.split_whitespace().map(String::from).collect(),
+                status,
+                ..Default::default()
+            })
+            .unwrap();
+        };
+        add("Sort receipts", None);
+        add("Draft chapter +Book", Some(TaskStatus::Next));
+        add("Fix gutter +Home", Some(TaskStatus::Someday));
+        add("Loose end", Some(TaskStatus::Next));
+
+        let mut session = ReviewSession::load(&db).unwrap();
+        let stages: Vec<ReviewStage> = session
+            .items
+            .iter()
+            .map(|item| item.stage.clone())
+            .collect();
+        assert_eq!(
+            stages,
+            vec![
+                ReviewStage::Inbox,
+                ReviewStage::Project("Book".into()),
+                ReviewStage::Project("Home".into()),
+            ]
+        );
+
+        session.apply(&db, Disposition::Next).unwrap();
+        session.apply(&db, Disposition::Keep).unwrap();
+        session.apply(&db, Disposition::Delete).unwrap();
+        assert!(session.is_finished());
+        assert_eq!(session.summary().reviewed(), 3);
+        assert_eq!(session.summary().deleted, 1);
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local};
+use serde::Serialize;
+
+use crate::database::Database;
+use crate::model::{ListFilters, ListOutputItem, Task, TaskStatus, TimeEntry};
+
+/// Statuses reported by [`StatsSnapshot::by_status`], in workflow order.
+pub const STATUS_ORDER: [TaskStatus; 6] = [
+    TaskStatus::Inbox,
+    TaskStatus::Next,
+    TaskStatus::Waiting,
+    TaskStatus::Scheduled,
+    TaskStatus::Someday,
+    TaskStatus::Done,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WaitingEntry {
+    pub title: String,
+    pub waiting_on: Option<String>,
+    /// Seconds since the task started waiting (falls back to its creation time).
+    pub waited_secs: i64,
+}
+
+/// A completed task's time estimate next to the time tracked on it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EstimateEntry {
+    pub title: String,
+    pub estimate_secs: i64,
+    pub actual_secs: i64,
+}
+
+/// Point-in-time numbers for the stats screen; every figure comes from one pass over the tasks.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct StatsSnapshot {
+    /// Completions per local day, oldest first; the last entry is today.
+    pub completions: Vec<u64>,
+    pub by_status: Vec<(TaskStatus, usize)>,
+    /// Open tasks per project, largest first.
+    pub by_project: Vec<(String, usize)>,
+    pub inbox_count: usize,
+    pub average_inbox_age_secs: Option<i64>,
+    /// Waiting tasks, longest wait first.
+    pub waiting: Vec<WaitingEntry>,
+    /// Tasks completed in the history window that had an estimate and tracked time, most
+    /// recently completed first.
+    pub estimates: Vec<EstimateEntry>,
+}
+
+impl StatsSnapshot {
+    pub fn from_tasks(
+        tasks: &[Task],
+        entries: &[TimeEntry],
+        now: DateTime<Local>,
+        days: usize,
+    ) -> Self {
+        let today = now.date_naive();
+        let mut tracked: HashMap<&str, i64> = HashMap::new();
+        for entry in entries {
+            let ended = entry
+                .ended_at
+                .unwrap_or_else(|| now.with_timezone(&chrono::Utc));
+            *tracked.entry(entry.task_id.as_str()).or_default() +=
+                (ended - entry.started_at).num_seconds().max(0);
+        }
+        let mut estimates = Vec::new();
+        let mut completions = vec![0u64; days];
+        let mut statuses = [0usize; STATUS_ORDER.len()];
+        let mut projects: HashMap<&str, usize> = HashMap::new();
+        let mut inbox_ages = Vec::new();
+        let mut waiting = Vec::new();
+
+        for task in tasks {
+            if let Some(slot) = STATUS_ORDER.iter().position(|s| *s == task.status) {
+                statuses[slot] += 1;
+            }
+            let open = !matches!(task.status, TaskStatus::Done | TaskStatus::Canceled);
+            if let Some(completed) = task.completed_at.filter(|_| !open) {
+                let age = (today - completed.with_timezone(&Local).date_naive()).num_days();
+                if (0..days as i64).contains(&age) {
+                    completions[days - 1 - age as usize] += 1;
+                    let actual = tracked.get(task.id.as_str()).copied();
+                    if let (Some(estimate), Some(actual)) = (task.time_estimate, actual) {
+                        estimates.push((
+                            completed,
+                            EstimateEntry {
+                                title: task.title.clone(),
+                                estimate_secs: i64::from(estimate) * 60,
+                                actual_secs: actual,
+                            },
+                        ));
+                    }
+                }
+            }
+            if !open {
+                continue;
+            }
+            if let Some(project) = &task.project {
+                *projects.entry(project).or_default() += 1;
+            }
+            match task.status {
+                TaskStatus::Inbox => {
+                    inbox_ages.push((now.signed_duration_since(task.created_at)).num_seconds())
+                }
+                TaskStatus::Waiting => {
+                    let since = task.waiting_since.unwrap_or(task.created_at);
+                    waiting.push(WaitingEntry {
+                        title: task.title.clone(),
+                        waiting_on: task.waiting_on.clone(),
+                        waited_secs: (now.signed_duration_since(since)).num_seconds().max(0),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let mut by_project: Vec<(String, usize)> = projects
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect();
+        by_project.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        waiting.sort_by_key(|entry| std::cmp::Reverse(entry.waited_secs));
+        estimates.sort_by_key(|(completed, _)| std::cmp::Reverse(*completed));
+
+        Self {
+            completions,
+            by_status: STATUS_ORDER.into_iter().zip(statuses).collect(),
+            by_project,
+            inbox_count: inbox_ages.len(),
+            average_inbox_age_secs: (!inbox_ages.is_empty())
+                .then(|| inbox_ages.iter().sum::<i64>() / inbox_ages.len() as i64),
+            waiting,
+            estimates: estimates.into_iter().map(|(_, entry)| entry).collect(),
+        }
+    }
+
+    /// Completions summed per 7-day week, oldest first; the last week ends today.
+    pub fn weekly_completions(&self) -> Vec<u64> {
+        let mut weeks: Vec<u64> = self
+            .completions
+            .rchunks(7)
+            .map(|week| week.iter().sum())
+            .collect();
+        weeks.reverse();
+        weeks
+    }
+
+    pub fn completed_total(&self) -> u64 {
+        self.completions.iter().sum()
+    }
+
+    pub fn average_waiting_secs(&self) -> Option<i64> {
+        (!self.waiting.is_empty()).then(|| {
+            self.waiting
+                .iter()
+                .map(|entry| entry.waited_secs)
+                .sum::<i64>()
+                / self.waiting.len() as i64
+        })
+    }
+}
+
+/// Builds [`StatsSnapshot`]s from the task database.
+pub struct StatsService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> StatsService<'a> {
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Snapshot with `days` of completion history ending today.
+    pub fn snapshot(&self, days: usize) -> Result<StatsSnapshot> {
+        let filters = ListFilters {
+            include_done: true,
+            ..ListFilters::for_view(None)
+        };
+        let tasks: Vec<Task> = self
+            .db
+            .fetch_tasks(&filters)?
+            .into_iter()
+            .filter_map(|item| match item {
+                ListOutputItem::Task(task) => Some(*task),
+                ListOutputItem::Project(_) => None,
+            })
+            .collect();
+        let entries = self.db.fetch_all_time_entries()?;
+        Ok(StatsSnapshot::from_tasks(
+            &tasks,
+            &entries,
+            Local::now(),
+            days,
+        ))
+    }
+}
+
+/// Compact `3d`, `5h`, or `12m` label for a duration in seconds.
+pub fn format_age(secs: i64) -> String {
+    let age = Duration::seconds(secs.max(0));
+    if age.num_days() > 0 {
+        format!("{}d", age.num_days())
+    } else if age.num_hours() > 0 {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}m", age.num_minutes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture::TaskInput;
+    use crate::config::AppConfig;
+    use tempfile::TempDir;
+
+    #[test]
+    fn stats_count_statuses_projects_and_completions() {
+        let dir = TempDir::new().unwrap();
+        let config = AppConfig::from_data_dir(dir.path().to_path_buf()).unwrap();
+        let mut db = Database::initialize(&config).unwrap();
+        let mut add = |text: &str, status: Option<TaskStatus>| {
+            db.handle_add(&TaskInput {
+                text: text.split_whitespace().map(String::from).collect(),
+                status,
+                ..Default::default()
+            })
+            .unwrap()
+        };
+        add("Sort receipts", None);
+        add("Draft chapter +Book", Some(TaskStatus::Next));
+        add("Edit chapter +Book", Some(TaskStatus::Next));
+        add("Quote from Sam +Home", Some(TaskStatus::Waiting));
+        let done = add("Ship release t:30m", Some(TaskStatus::Next));
+        db.start_timer(&done.id).unwrap();
+        db.stop_timer().unwrap();
+        db.mark_done(std::slice::from_ref(&done.id)).unwrap();
+
+        let stats = StatsService::new(&db).snapshot(7).unwrap();
+        assert_eq!(stats.completions.len(), 7);
+        assert_eq!(stats.completions[6], 1);
+        assert_eq!(stats.completed_total(), 1);
+        assert_eq!(
+            stats.by_status,
+            vec![
+                (TaskStatus::Inbox, 1),
+                (TaskStatus::Next, 2),
+                (TaskStatus::Waiting, 1),
+                (TaskStatus::Scheduled, 0),
+                (TaskStatus::Someday, 0),
+                (TaskStatus::Done, 1),
+            ]
+        );
+        assert_eq!(
+            stats.by_project,
+            vec![("Book".to_string(), 2), ("Home".to_string(), 1)]
+        );
+        assert_eq!(stats.inbox_count, 1);
+        assert!(stats.average_inbox_age_secs.is_some());
+        assert_eq!(stats.waiting.len(), 1);
+        assert_eq!(stats.weekly_completions(), vec![1]);
+        assert_eq!(stats.estimates.len(), 1);
+        assert_eq!(stats.estimates[0].title, "Ship release");
+        assert_eq!(stats.estimates[0].estimate_secs, 30 * 60);
+        assert_eq!(format_age(3 * 86_400 + 5), "3d");
+        assert_eq!(format_age(90), "1m");
+    }
+}
@@ -1,15 +1,18 @@
 use std::collections::HashSet;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 
 use crate::capture::TaskInput;
 use crate::config::AppConfig;
 use crate::database::Database;
 use crate::model::{
-    AddOutcome, DeleteResult, ListFilters, ListOutputItem, ListView, ProjectSummary, StatusUpdate,
-    Task, TaskStatus,
+    AddOutcome, BatchOperation, DeleteResult, EnergyLevel, Facets, ListFilters, ListOutputItem,
+    ListView, ProjectHealth, ProjectSummary, SavedView, StatusUpdate, Task, TaskChanges,
+    TaskStatus, TimeEntry,
 };
+use crate::repeat::RepeatRule;
+use crate::services::stats::{StatsService, StatsSnapshot};
 
 #[derive(Debug, Clone)]
 pub struct ViewSnapshot {
@@ -62,12 +65,45 @@ impl TasksService {
         })
     }
 
+    /// Number of rows `list` would return for `filters` without its limit and offset.
+    pub fn count(&self, filters: &ListFilters) -> Result<usize> {
+        let db = self.open_database()?;
+        db.count_tasks(filters)
+    }
+
+    /// Stats with `days` of completion history ending today.
+    pub fn stats(&self, days: usize) -> Result<StatsSnapshot> {
+        let db = self.open_database()?;
+        StatsService::new(&db).snapshot(days)
+    }
+
+    /// Open-task counts per project, context, tag, and area.
+    pub fn facets(&self) -> Result<Facets> {
+        let db = self.open_database()?;
+        db.fetch_facets()
+    }
+
+    /// Every project with its counts, open total, stalled flag, and latest activity.
+    pub fn project_health(&self) -> Result<Vec<ProjectHealth>> {
+        let db = self.open_database()?;
+        db.fetch_project_health()
+    }
+
     pub fn capture(&self, input: TaskInput) -> Result<AddOutcome> {
         input.require_text()?;
         let mut db = self.open_database()?;
         db.handle_add(&input)
     }
 
+    /// Captures several tasks at once, all or nothing.
+    pub fn capture_many(&self, inputs: &[TaskInput]) -> Result<Vec<AddOutcome>> {
+        for input in inputs {
+            input.require_text()?;
+        }
+        let mut db = self.open_database()?;
+        db.handle_add_many(inputs)
+    }
+
     pub fn promote_to_next(&self, ids: &[String]) -> Result<Vec<StatusUpdate>> {
         let db = self.open_database()?;
         db.mark_next(ids)
@@ -88,6 +124,11 @@ impl TasksService {
         db.mark_someday(ids)
     }
 
+    pub fn set_status(&self, ids: &[String], status: TaskStatus) -> Result<Vec<StatusUpdate>> {
+        let db = self.open_database()?;
+        db.set_status(ids, status)
+    }
+
     pub fn delete_tasks(&self, ids: &[String]) -> Result<Vec<DeleteResult>> {
         let db = self.open_database()?;
         db.delete_tasks(ids)
@@ -99,18 +140,42 @@ impl TasksService {
         defer_until: Option<DateTime<Utc>>,
     ) -> Result<Option<Task>> {
         let db = self.open_database()?;
-        let existing = db.fetch_task(id)?;
-        let Some(task) = existing else {
-            return Ok(None);
-        };
+        db.defer_task(id, defer_until)
+    }
 
-        let mut updated = crate::model::NewTask::from(&task);
-        updated.defer_until = defer_until;
-        updated.status = match updated.status {
-            TaskStatus::Inbox | TaskStatus::Next if defer_until.is_some() => TaskStatus::Scheduled,
-            other => other,
-        };
-        db.update_task(id, &updated)
+    /// Saves `ids` as the hand-arranged order of the Next view, first to last.
+    pub fn reorder_tasks(&self, ids: &[String]) -> Result<()> {
+        let mut db = self.open_database()?;
+        db.set_sort_order(ids)
+    }
+
+    /// Runs a mixed batch of status changes, defers, and deletes in one transaction.
+    pub fn apply_batch(&self, operations: &[BatchOperation]) -> Result<Vec<Vec<StatusUpdate>>> {
+        let db = self.open_database()?;
+        db.apply_batch(operations)
+    }
+
+    /// Defers every task in `ids` to the same moment; missing ids are skipped.
+    pub fn defer_tasks(&self, ids: &[String], defer_until: DateTime<Utc>) -> Result<Vec<Task>> {
+        let db = self.open_database()?;
+        let mut deferred = Vec::new();
+        for id in ids {
+            if let Some(task) = db.defer_task(id, Some(defer_until))? {
+                deferred.push(task);
+            }
+        }
+        Ok(deferred)
+    }
+
+    /// Sets the rule a task repeats by, or stops it repeating with `None`.
+    pub fn update_repeat(&self, id: &str, rule: Option<&RepeatRule>) -> Result<Option<Task>> {
+        let db = self.open_database()?;
+        db.set_repeat(id, rule)
+    }
+
+    pub fn update_due(&self, id: &str, due_at: Option<DateTime<Utc>>) -> Result<Option<Task>> {
+        let db = self.open_database()?;
+        db.set_due(id, due_at)
     }
 
     pub fn rename_task(&self, id: &str, title: &str) -> Result<Option<Task>> {
@@ -180,6 +245,167 @@ impl TasksService {
         db.update_task(id, &updated)
     }
 
+    pub fn update_energy(&self, id: &str, energy: Option<EnergyLevel>) -> Result<Option<Task>> {
+        let db = self.open_database()?;
+        let Some(task) = db.fetch_task(id)? else {
+            return Ok(None);
+        };
+
+        let mut updated = crate::model::NewTask::from(&task);
+        updated.energy = energy;
+        db.update_task(id, &updated)
+    }
+
+    /// Sets the time estimate in minutes; `None` or zero clears it.
+    pub fn update_time_estimate(&self, id: &str, minutes: Option<u32>) -> Result<Option<Task>> {
+        let db = self.open_database()?;
+        let Some(task) = db.fetch_task(id)? else {
+            return Ok(None);
+        };
+
+        let mut updated = crate::model::NewTask::from(&task);
+        updated.time_estimate = minutes.filter(|minutes| *minutes > 0);
+        db.update_task(id, &updated)
+    }
+
+    pub fn update_notes(&self, id: &str, notes: String) -> Result<Option<Task>> {
+        let db = self.open_database()?;
+        let existing = db.fetch_task(id)?;
+        let Some(task) = existing else {
+            return Ok(None);
+        };
+
+        let mut updated = crate::model::NewTask::from(&task);
+        updated.notes = Some(notes);
+        db.update_task(id, &updated)
+    }
+
+    /// Applies `changes` on top of the stored task in one write, leaving other fields as they are.
+    pub fn update_task(&self, id: &str, changes: &TaskChanges) -> Result<Option<Task>> {
+        let db = self.open_database()?;
+        let Some(task) = db.fetch_task(id)? else {
+            return Ok(None);
+        };
+
+        let mut updated = crate::model::NewTask::from(&task);
+        if let Some(title) = changes.title.as_deref().map(str::trim) {
+            if title.is_empty() {
+                return Err(anyhow!("Task title cannot be empty"));
+            }
+            updated.title = title.to_string();
+        }
+        if let Some(notes) = &changes.notes {
+            updated.notes = Some(notes.clone());
+        }
+        if let Some(project) = &changes.project {
+            updated.project = project
+                .as_deref()
+                .map(str::trim)
+                .filter(|project| !project.is_empty())
+                .map(str::to_string);
+        }
+        if let Some(contexts) = &changes.contexts {
+            updated.contexts = normalize_list(contexts.clone());
+        }
+        if let Some(tags) = &changes.tags {
+            updated.tags = normalize_list(tags.clone());
+        }
+        if let Some(priority) = changes.priority {
+            updated.priority = priority.clamp(0, 3);
+        }
+        if let Some(energy) = changes.energy {
+            updated.energy = energy;
+        }
+        if let Some(minutes) = changes.time_estimate {
+            updated.time_estimate = minutes.filter(|minutes| *minutes > 0);
+        }
+        if let Some(due_at) = changes.due_at {
+            updated.due_at = due_at;
+        }
+        if let Some(defer_until) = changes.defer_until {
+            updated.defer_until = defer_until;
+        }
+        if let Some(waiting_on) = &changes.waiting_on {
+            match waiting_on
+                .as_deref()
+                .map(str::trim)
+                .filter(|who| !who.is_empty())
+            {
+                Some(who) => {
+                    updated.waiting_on = Some(who.to_string());
+                    updated.waiting_since = task.waiting_since.or_else(|| Some(Utc::now()));
+                    updated.status = TaskStatus::Waiting;
+                }
+                None => {
+                    updated.waiting_on = None;
+                    updated.waiting_since = None;
+                    if task.status == TaskStatus::Waiting {
+                        updated.status = TaskStatus::Next;
+                    }
+                }
+            }
+        }
+        db.update_task(id, &updated)
+    }
+
+    /// Adds `line` to the end of each task's notes, e.g. to log a follow-up.
+    pub fn append_note(&self, ids: &[String], line: &str) -> Result<Vec<Task>> {
+        let db = self.open_database()?;
+        let mut updated = Vec::new();
+        for id in ids {
+            let Some(task) = db.fetch_task(id)? else {
+                continue;
+            };
+            let mut changed = crate::model::NewTask::from(&task);
+            changed.notes = Some(task.notes_with_line(line));
+            if let Some(task) = db.update_task(id, &changed)? {
+                updated.push(task);
+            }
+        }
+        Ok(updated)
+    }
+
+    /// Writes every editable field of `task` back to its record, e.g. to undo a change.
+    pub fn restore_task(&self, task: &Task) -> Result<Option<Task>> {
+        let db = self.open_database()?;
+        let mut restored = crate::model::NewTask::from(task);
+        restored.notes = Some(task.notes.clone().unwrap_or_default());
+        db.update_task(&task.id, &restored)
+    }
+
+    /// Saved filter presets ordered by slot.
+    pub fn saved_views(&self) -> Result<Vec<SavedView>> {
+        self.open_database()?.fetch_saved_views()
+    }
+
+    /// Stores `view`, replacing whatever occupied its slot.
+    pub fn save_view(&self, view: &SavedView) -> Result<()> {
+        self.open_database()?.save_view(view)
+    }
+
+    /// The timer that is running, on whichever task.
+    pub fn running_timer(&self) -> Result<Option<TimeEntry>> {
+        self.open_database()?.running_timer()
+    }
+
+    /// Starts timing `id`, stopping any other timer first.
+    pub fn start_timer(&self, id: &str) -> Result<TimeEntry> {
+        self.open_database()?.start_timer(id)
+    }
+
+    pub fn stop_timer(&self) -> Result<Option<TimeEntry>> {
+        self.open_database()?.stop_timer()
+    }
+
+    /// Seconds tracked on `id` across its finished time entries.
+    pub fn tracked_secs(&self, id: &str) -> Result<i64> {
+        let entries = self.open_database()?.fetch_time_entries(id)?;
+        Ok(entries
+            .iter()
+            .filter_map(|entry| Some((entry.ended_at? - entry.started_at).num_seconds()))
+            .sum())
+    }
+
     pub fn fetch_task(&self, id: &str) -> Result<Option<Task>> {
         let db = self.open_database()?;
         db.fetch_task(id)
@@ -209,7 +435,7 @@ fn normalize_list(tokens: Vec<String>) -> Vec<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::TaskStatus;
+    use crate::model::{BatchAction, TaskStatus};
     use chrono::Utc;
     use tempfile::TempDir;
 
@@ -245,6 +471,34 @@ mod tests {
         assert_eq!(next_snapshot.tasks.len(), 1);
     }
 
+    #[test]
+    fn captures_many_tasks_or_none() {
+        let (service, _guard) = service_with_temp_dir();
+        let input = |line: &str| TaskInput {
+            text: line.split_whitespace().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        };
+
+        let outcomes = service
+            .capture_many(&[input("Buy milk @errands"), input("Call Sam +Home")])
+            .unwrap();
+        assert_eq!(outcomes.len(), 2);
+        let inbox = service
+            .list(&ListFilters::for_view(Some(ListView::Inbox)))
+            .unwrap();
+        assert_eq!(inbox.tasks.len(), 2);
+        let call = service.fetch_task(&outcomes[1].id).unwrap().unwrap();
+        assert_eq!(call.project.as_deref(), Some("Home"));
+
+        assert!(service
+            .capture_many(&[input("Write report"), input("  ")])
+            .is_err());
+        let inbox = service
+            .list(&ListFilters::for_view(Some(ListView::Inbox)))
+            .unwrap();
+        assert_eq!(inbox.tasks.len(), 2);
+    }
+
     #[test]
     fn promotes_and_completes_tasks() {
         let (service, _guard) = service_with_temp_dir();
@@ -273,6 +527,51 @@ mod tests {
         assert!(updated.defer_until.is_some());
     }
 
+    #[test]
+    fn next_view_follows_the_manual_order() {
+        let (service, _guard) = service_with_temp_dir();
+        let ids: Vec<String> = ["Call bank", "Book flights", "Write report"]
+            .iter()
+            .map(|title| capture_simple(&service, title))
+            .collect();
+        service.promote_to_next(&ids).unwrap();
+        let late = capture_simple(&service, "Water plants");
+        service
+            .promote_to_next(std::slice::from_ref(&late))
+            .unwrap();
+
+        let arranged = vec![ids[2].clone(), ids[0].clone(), ids[1].clone()];
+        service.reorder_tasks(&arranged).unwrap();
+
+        let filters = ListFilters::for_view(Some(ListView::Next));
+        let listed: Vec<String> = service
+            .list(&filters)
+            .unwrap()
+            .tasks
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+        // Tasks never arranged by hand come after the ones that were.
+        assert_eq!(listed, [arranged, vec![late]].concat());
+    }
+
+    #[test]
+    fn defers_several_tasks_at_once() {
+        let (service, _guard) = service_with_temp_dir();
+        let ids = vec![
+            capture_simple(&service, "Draft agenda"),
+            capture_simple(&service, "Send invites"),
+            "missing".to_string(),
+        ];
+        let defer_until = Utc::now() + chrono::Duration::days(5);
+        let deferred = service.defer_tasks(&ids, defer_until).unwrap();
+        assert_eq!(deferred.len(), 2);
+        assert!(deferred
+            .iter()
+            .all(|task| task.status == TaskStatus::Scheduled
+                && task.defer_until.map(|at| at.timestamp()) == Some(defer_until.timestamp())));
+    }
+
     #[test]
     fn update_project_trims_and_clears_value() {
         let (service, _guard) = service_with_temp_dir();
@@ -339,6 +638,46 @@ mod tests {
         assert_eq!(unchanged_low.priority, 0);
     }
 
+    #[test]
+    fn update_energy_and_time_estimate_set_and_clear() {
+        let (service, _guard) = service_with_temp_dir();
+        let id = capture_simple(&service, "Draft proposal");
+
+        let task = service
+            .update_energy(&id, Some(EnergyLevel::High))
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.energy, Some(EnergyLevel::High));
+        let task = service
+            .update_time_estimate(&id, Some(90))
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.time_estimate, Some(90));
+        assert_eq!(task.energy, Some(EnergyLevel::High));
+
+        let task = service.update_energy(&id, None).unwrap().unwrap();
+        assert_eq!(task.energy, None);
+        let task = service.update_time_estimate(&id, Some(0)).unwrap().unwrap();
+        assert_eq!(task.time_estimate, None);
+    }
+
+    #[test]
+    fn restore_task_reverts_status_and_fields() {
+        let (service, _guard) = service_with_temp_dir();
+        let id = capture_simple(&service, "Ship beta");
+        let before = service.fetch_task(&id).unwrap().unwrap();
+
+        service.mark_done(std::slice::from_ref(&id)).unwrap();
+        service.update_priority(&id, 3).unwrap();
+        service.update_notes(&id, "Draft".into()).unwrap();
+
+        let restored = service.restore_task(&before).unwrap().unwrap();
+        assert_eq!(restored.status, before.status);
+        assert_eq!(restored.priority, before.priority);
+        assert!(restored.completed_at.is_none());
+        assert!(restored.notes.unwrap_or_default().is_empty());
+    }
+
     #[test]
     fn delete_tasks_removes_records_and_reports_missing() {
         let (service, _guard) = service_with_temp_dir();
@@ -365,4 +704,205 @@ mod tests {
         assert!(service.fetch_task(&delete_id).unwrap().is_none());
         assert!(service.fetch_task(&keep_id).unwrap().is_some());
     }
+
+    #[test]
+    fn append_note_adds_a_paragraph_to_each_task() {
+        let (service, _guard) = service_with_temp_dir();
+        let bare = capture_simple(&service, "Invoice from Sam");
+        let noted = capture_simple(&service, "Contract from Sam");
+        service
+            .update_notes(&noted, "Sent on Monday\n".into())
+            .unwrap();
+
+        let updated = service
+            .append_note(&[bare.clone(), noted.clone(), "missing".into()], "Nudged")
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+        let notes = |id: &str| service.fetch_task(id).unwrap().unwrap().notes.unwrap();
+        assert_eq!(notes(&bare), "Nudged");
+        assert_eq!(notes(&noted), "Sent on Monday\n\nNudged");
+    }
+
+    #[test]
+    fn saved_views_round_trip_by_slot() {
+        let (service, _guard) = service_with_temp_dir();
+        assert!(service.saved_views().unwrap().is_empty());
+        let mut view = SavedView {
+            slot: 2,
+            name: "Office".into(),
+            contexts: vec!["office".into()],
+            ..Default::default()
+        };
+        service.save_view(&view).unwrap();
+        view.name = "Desk".into();
+        service.save_view(&view).unwrap();
+        assert_eq!(service.saved_views().unwrap(), vec![view]);
+    }
+
+    #[test]
+    fn timers_run_one_at_a_time_and_add_up() {
+        let (service, _guard) = service_with_temp_dir();
+        let first = capture_simple(&service, "Write report");
+        let second = capture_simple(&service, "Review slides");
+
+        service.start_timer(&first).unwrap();
+        assert_eq!(service.running_timer().unwrap().unwrap().task_id, first);
+        service.start_timer(&second).unwrap();
+        assert_eq!(service.running_timer().unwrap().unwrap().task_id, second);
+
+        let stopped = service.stop_timer().unwrap().unwrap();
+        assert!(stopped.ended_at.is_some());
+        assert!(service.running_timer().unwrap().is_none());
+        assert!(service.stop_timer().unwrap().is_none());
+        assert!(service.tracked_secs(&first).unwrap() >= 0);
+        assert_eq!(service.tracked_secs("missing").unwrap(), 0);
+    }
+
+    #[test]
+    fn update_task_changes_only_the_given_fields() {
+        let (service, _guard) = service_with_temp_dir();
+        let id = capture_simple(&service, "Call vendr +Office @phone p:2");
+
+        let changes = TaskChanges {
+            title: Some("Call vendor".into()),
+            tags: Some(vec!["contract".into(), "Contract".into()]),
+            time_estimate: Some(Some(15)),
+            waiting_on: Some(Some("Sam".into())),
+            ..Default::default()
+        };
+        let task = service.update_task(&id, &changes).unwrap().unwrap();
+        assert_eq!(task.title, "Call vendor");
+        assert_eq!(task.project.as_deref(), Some("Office"));
+        assert_eq!(task.contexts, vec!["phone".to_string()]);
+        assert_eq!(task.tags, vec!["contract".to_string()]);
+        assert_eq!((task.priority, task.time_estimate), (2, Some(15)));
+        assert_eq!(task.status, TaskStatus::Waiting);
+        assert!(task.waiting_since.is_some());
+
+        let changes = TaskChanges {
+            project: Some(None),
+            waiting_on: Some(None),
+            ..Default::default()
+        };
+        let task = service.update_task(&id, &changes).unwrap().unwrap();
+        assert_eq!(task.project, None);
+        assert_eq!((task.status, task.waiting_on), (TaskStatus::Next, None));
+
+        let blank = TaskChanges {
+            title: Some("  ".into()),
+            ..Default::default()
+        };
+        assert!(service.update_task(&id, &blank).is_err());
+        assert!(service
+            .update_task("missing", &TaskChanges::default())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn project_health_flags_projects_without_next_actions() {
+        let (service, _guard) = service_with_temp_dir();
+        let moving = capture_simple(&service, "Draft agenda +Offsite");
+        service.promote_to_next(&[moving]).unwrap();
+        capture_simple(&service, "Book venue +Offsite");
+        capture_simple(&service, "Renew contract +Legal");
+        let shipped = capture_simple(&service, "Ship it +Launch");
+        service.mark_done(&[shipped]).unwrap();
+
+        let health = service.project_health().unwrap();
+        let flags: Vec<(&str, usize, bool)> = health
+            .iter()
+            .map(|project| {
+                (
+                    project.summary.project.as_str(),
+                    project.open,
+                    project.stalled,
+                )
+            })
+            .collect();
+        assert_eq!(
+            flags,
+            vec![
+                ("Launch", 0, false),
+                ("Legal", 1, true),
+                ("Offsite", 2, false)
+            ]
+        );
+        assert_eq!(health[2].summary.next_actions, 1);
+        assert!(health[2].last_activity <= Utc::now());
+    }
+
+    #[test]
+    fn completing_a_repeating_task_schedules_the_next_occurrence() {
+        let (service, _guard) = service_with_temp_dir();
+        let id = capture_simple(&service, "Water plants rep:3d");
+        let due = Utc::now() - chrono::Duration::days(4);
+        service.update_due(&id, Some(due)).unwrap();
+        service
+            .update_notes(&id, "- [x] Ferns\n- [ ] Cactus".into())
+            .unwrap();
+
+        service.mark_done(std::slice::from_ref(&id)).unwrap();
+        let open = service
+            .list(&ListFilters::for_view(Some(ListView::Next)))
+            .unwrap()
+            .tasks;
+        assert_eq!(open.len(), 1);
+        let next = &open[0];
+        assert_ne!(next.id, id);
+        assert_eq!(next.title, "Water plants");
+        assert_eq!(next.repeat.as_deref(), Some("3d"));
+        assert_eq!(next.notes.as_deref(), Some("- [ ] Ferns\n- [ ] Cactus"));
+        // Four days overdue on a three-day rule lands two days ahead, not in the past.
+        assert_eq!(next.due_at.unwrap() - due, chrono::Duration::days(6));
+
+        // Completing it again from Done does not schedule another.
+        service.mark_done(std::slice::from_ref(&id)).unwrap();
+        let stopped = service.update_repeat(&next.id, None).unwrap().unwrap();
+        assert_eq!(stopped.repeat, None);
+        service.mark_done(std::slice::from_ref(&next.id)).unwrap();
+        assert!(service
+            .list(&ListFilters::for_view(None))
+            .unwrap()
+            .tasks
+            .is_empty());
+    }
+
+    #[test]
+    fn apply_batch_runs_mixed_operations_with_a_result_per_id() {
+        let (service, _guard) = service_with_temp_dir();
+        let done = capture_simple(&service, "Pay invoice");
+        let later = capture_simple(&service, "Plan offsite");
+        let junk = capture_simple(&service, "asdf");
+        let until = Utc::now() + chrono::Duration::days(3);
+
+        let results = service
+            .apply_batch(&[
+                BatchOperation {
+                    ids: vec![done.clone(), "missing".into()],
+                    action: BatchAction::Status(TaskStatus::Done),
+                },
+                BatchOperation {
+                    ids: vec![later.clone()],
+                    action: BatchAction::Defer(until),
+                },
+                BatchOperation {
+                    ids: vec![junk.clone()],
+                    action: BatchAction::Delete,
+                },
+            ])
+            .unwrap();
+        let changed: Vec<Vec<bool>> = results
+            .iter()
+            .map(|updates| updates.iter().map(|update| update.changed).collect())
+            .collect();
+        assert_eq!(changed, vec![vec![true, false], vec![true], vec![true]]);
+
+        let done = service.fetch_task(&done).unwrap().unwrap();
+        assert_eq!(done.status, TaskStatus::Done);
+        assert!(done.completed_at.is_some());
+        let later = service.fetch_task(&later).unwrap().unwrap();
+        assert_eq!(later.status, TaskStatus::Scheduled);
+        assert!(service.fetch_task(&junk).unwrap().is_none());
+    }
 }
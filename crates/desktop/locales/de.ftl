@@ -0,0 +1,502 @@
+# Deutsche Texte für die cpt.run Desktop-App; fehlende Einträge zeigen den englischen Text.
+
+locale-name = Deutsch
+
+## Ansichten
+
+view-all = Alle
+view-all-subtitle = Alle Aufgaben auf einen Blick
+view-inbox = Eingang
+view-inbox-subtitle = Alles sammeln, schnell sortieren
+view-next = Als Nächstes
+view-next-subtitle = Die nächsten Schritte, bereit zum Loslegen
+view-waiting = Wartet
+view-waiting-subtitle = Personen und Abhängigkeiten zum Nachfassen
+view-scheduled = Geplant
+view-scheduled-subtitle = Zurückgestellte oder terminierte Zusagen
+view-someday = Irgendwann
+view-someday-subtitle = Ideen für eine spätere Durchsicht
+view-projects = Projekte
+view-projects-subtitle = Projekte mit ihren nächsten Schritten
+view-done = Erledigt
+view-done-subtitle = Abgeschlossene Arbeit, neueste zuerst
+view-stats = Statistik
+view-stats-subtitle = Wochentrends für Rückblicke
+
+## Befehlspalette und Zeilenmenü
+
+action-open-capture = Hinzufügen
+action-open-capture-description = Aufgabe mit Inline-Tokens erfassen
+action-promote-next = Zu „Als Nächstes“
+action-promote-next-description = Ausgewählte Aufgaben als nächste Schritte markieren
+action-mark-done = Erledigen
+action-mark-done-description = Ausgewählte Aufgaben abschließen
+action-move-to-inbox = In den Eingang
+action-move-to-inbox-description = Ausgewählte Aufgaben zurück in den Eingang legen
+action-defer-tomorrow = Auf morgen verschieben
+action-defer-tomorrow-description = Ausgewählte Aufgabe um einen Tag zurückstellen
+action-defer-next-week = Auf nächste Woche verschieben
+action-defer-next-week-description = Ausgewählte Aufgabe um sieben Tage zurückstellen
+action-defer-pick = Zurückstellen bis…
+action-defer-pick-description = Vorgabe wählen oder Datum für die ausgewählten Aufgaben eingeben
+action-move-to-project = In Projekt verschieben
+action-move-to-project-description = Projekt für die ausgewählte Aufgabe wählen
+action-copy-task = Aufgabe kopieren
+action-copy-task-description = Ausgewählte Aufgabe als Erfassungszeile kopieren
+action-delete-task = Aufgabe löschen
+action-delete-task-description = Ausgewählte Aufgabe endgültig löschen
+action-toggle-details = Details ein/aus
+action-toggle-details-description = Detailbereich der Aufgabe zeigen oder ausblenden
+action-toggle-filters = Filter ein/aus
+action-toggle-filters-description = Nach Projekt, Kontext, Tag oder Priorität filtern
+action-refresh = Jetzt aktualisieren
+action-refresh-description = Aktive Ansicht neu laden
+action-export = Exportieren…
+action-export-description = Diese Ansicht oder alle Aufgaben als JSON, CSV, Markdown oder iCalendar speichern
+action-print = Drucken…
+action-print-description = Diese Ansicht drucken oder als PDF speichern
+action-focus = Auf Aufgabe konzentrieren
+action-focus-description = Nur die ausgewählte Aufgabe zeigen und die Zeit dafür messen
+action-new-window = In neuem Fenster öffnen
+action-new-window-description = Diese Ansicht in einem weiteren Fenster zeigen, um zwei Listen nebeneinander zu haben
+menu-defer = Zurückstellen
+palette-title = Befehlspalette
+palette-placeholder = Befehl eingeben
+palette-empty = Keine Treffer
+
+## Änderungen, wie sie in Meldungen heißen
+
+mutation-promote = Befördern
+mutation-complete = Abschließen
+mutation-move-to-inbox = In den Eingang legen
+mutation-delete = Löschen
+mutation-move = Verschieben
+mutation-defer = Zurückstellen
+mutation-rename = Umbenennen
+mutation-update-project = Projekt ändern
+mutation-update-contexts = Kontexte ändern
+mutation-update-tags = Tags ändern
+mutation-update-priority = Priorität ändern
+mutation-update-energy = Energie ändern
+mutation-update-estimate = Schätzung ändern
+mutation-update-notes = Notizen ändern
+mutation-update-due-date = Fälligkeit ändern
+mutation-update-defer-date = Zurückstellung ändern
+mutation-undo = Rückgängig
+mutation-reorder = Umsortieren
+mutation-nudge = Nachhaken
+
+## Meldungen
+
+toast-mutation-queued = { $action } eingereiht…
+toast-mutation-succeeded = { $action } erfolgreich
+toast-action-undo = Rückgängig
+toast-action-open-file = Datei öffnen
+toast-action-retry = Erneut versuchen
+toast-dismiss = Schließen
+toast-task-added = Aufgabe „{ $title }“ hinzugefügt.
+toast-tasks-added = { $count } Aufgaben hinzugefügt.
+toast-sample-data-loaded = Beispielaufgaben hinzugefügt; du kannst sie jederzeit löschen.
+toast-sample-data-failed = Beispieldaten konnten nicht hinzugefügt werden: { $error }
+toast-copied = „{ $line }“ kopiert
+toast-exported-one = 1 Aufgabe nach { $path } exportiert
+toast-exported-many = { $count } Aufgaben nach { $path } exportiert
+toast-print-opened = Druckansicht im Browser geöffnet ({ $path })
+toast-print-failed = Die Druckansicht konnte nicht erstellt werden: { $error }
+toast-print-nothing = In dieser Ansicht gibt es nichts zu drucken
+toast-focus-nothing = Wähle eine Aufgabe aus, um dich darauf zu konzentrieren
+toast-timer-failed = Der Timer konnte nicht aktualisiert werden: { $error }
+toast-view-saved = Ansicht „{ $name }“ gespeichert
+toast-view-save-failed = Die Ansicht konnte nicht gespeichert werden: { $error }
+toast-view-slots-full = Alle 9 Plätze für gespeicherte Ansichten sind belegt; bearbeite eine angeheftete Ansicht, um ihren Platz wiederzuverwenden
+toast-export-failed = Export fehlgeschlagen: { $error }
+toast-open-failed = { $target } konnte nicht geöffnet werden: { $error }
+toast-link-invalid = Der Link konnte nicht geöffnet werden: { $error }
+toast-link-task-missing = Keine Aufgabe { $id } vorhanden
+toast-editor-failed = Der externe Editor konnte nicht geöffnet werden: { $error }
+toast-zoom = Zoom { $percent } %
+toast-shortcut-bound = { $keys } führt jetzt { $action } aus.
+toast-shortcut-displaced = { $keys } führt jetzt { $action } aus; { $displaced } ist nicht mehr belegt.
+toast-shortcuts-save-failed = Tastenkürzel konnten nicht gespeichert werden: { $error }
+toast-layout-save-failed = Spaltenlayout konnte nicht gespeichert werden: { $error }
+toast-stats-load-failed = Statistik konnte nicht geladen werden: { $error }
+toast-filters-load-failed = Filter konnten nicht geladen werden: { $error }
+toast-title-empty = Der Titel darf nicht leer sein
+toast-priority-range = Die Priorität muss zwischen 0 (keine) und 3 (hoch) liegen.
+toast-estimate-invalid = Die Schätzung braucht Minuten oder Stunden, etwa 45m, 2h oder 1h30m.
+error-invalid-date = Ungültiges Datum: { $error }
+
+## Erfassen
+
+capture-bulk-title = { $count } Aufgaben anlegen, eine pro Zeile?
+capture-bulk-confirm = { $count } Aufgaben anlegen
+capture-bulk-single = Als eine Aufgabe behalten
+capture-bulk-invalid = Markierte Zeilen korrigieren oder als eine Aufgabe behalten.
+capture-recent = Zuletzt
+capture-recent-clear = Verlauf leeren
+capture-recent-hint = ↑ und ↓ blättern durch die letzten Erfassungen
+capture-empty = Der Aufgabentext darf nicht leer sein
+
+## Leere Ansichten
+
+empty-all = Alles erledigt
+empty-all-hint = Nichts ist offen. Halte fest, was dir durch den Kopf geht, und sortiere später.
+empty-inbox = Eingang ist leer
+empty-inbox-hint = Neue Aufgaben landen hier, bis du entscheidest, was sie brauchen.
+empty-next = Noch keine nächsten Schritte
+empty-next-hint = Übernimm Aufgaben aus dem Eingang, sobald der nächste Schritt klar ist.
+empty-waiting = Nichts ausstehend
+empty-waiting-hint = Mit wait:Name beim Erfassen behältst du im Blick, was andere dir schulden.
+empty-scheduled = Nichts geplant
+empty-scheduled-hint = Aufgaben mit due:- oder defer:-Datum erscheinen hier.
+empty-someday = Irgendwann ist leer
+empty-someday-hint = Parke hier Ideen, die warten können.
+empty-projects = Noch keine Projekte
+empty-projects-hint = Mit +Projektname beim Erfassen beginnst du ein Projekt.
+empty-done = Noch nichts erledigt
+empty-done-hint = Erledigte Aufgaben werden hier nach Tag aufgelistet.
+empty-search = Keine Aufgaben passen zu „{ $query }“
+empty-search-hint = Die Suche durchsucht Titel und Notizen in dieser Ansicht.
+empty-clear-search = Suche löschen
+empty-filtered = Keine Aufgaben passen zu den Filtern
+empty-filtered-hint = Das gewählte Projekt, der Kontext, das Schlagwort oder die Priorität lässt in dieser Ansicht nichts übrig.
+empty-clear-filters = Filter löschen
+empty-add-task = Aufgabe hinzufügen
+empty-open-inbox = Eingang öffnen
+empty-sample-data = Beispieldaten laden
+empty-data-dir = Deine Aufgaben liegen in { $path }
+error-view = { $view } konnte nicht geladen werden
+error-view-hint = Die Liste wird bei der nächsten Aktualisierung erneut geladen, oder jetzt erneut versuchen. Die letzten Ereignisse zeigen jede fehlgeschlagene Aktualisierung.
+error-retry = Erneut versuchen
+error-retrying = Wird erneut versucht…
+error-recent-events = Letzte Ereignisse…
+
+## Einstellungen
+
+settings-title = Einstellungen
+settings-appearance = Darstellung
+settings-appearance-system = System
+settings-appearance-light = Hell
+settings-appearance-dark = Dunkel
+settings-text-size = Textgröße
+settings-reset = Zurücksetzen
+settings-color-theme = Farbschema
+settings-themes-hint = Eigene Farbschemata als TOML-Dateien in { $dir } ablegen
+settings-language = Sprache
+settings-language-system = System ({ $language })
+settings-reduce-motion = Bewegung reduzieren
+settings-reduce-motion-hint = Meldungen bleiben stehen, bis sie geschlossen oder verdrängt werden, und die Aktualisierungszeit zählt nicht mehr mit.
+settings-refresh = Automatisch aktualisieren
+settings-refresh-seconds = { $seconds } s
+settings-refresh-paused = Automatische Aktualisierung anhalten
+settings-refresh-hint = Wie oft die offene Ansicht neu aus der Datenbank geladen wird. Jetzt aktualisieren (R) lädt jederzeit neu, auch angehalten.
+settings-telemetry = Telemetrie
+settings-telemetry-memory = Diese Sitzung
+settings-telemetry-file = Logdatei
+settings-telemetry-stdout = Stdout
+settings-telemetry-disabled = Aus
+settings-telemetry-events = Letzte Ereignisse…
+settings-telemetry-hint = Nutzungsereignisse bleiben auf diesem Computer und werden nirgendwohin gesendet.
+settings-telemetry-file-hint = Ereignisse werden zusätzlich an { $path } angehängt
+settings-telemetry-disabled-hint = Es wird nichts aufgezeichnet.
+settings-external-editor = Externer Editor
+settings-external-editor-placeholder = Standard-App des Systems
+settings-external-editor-hint = Befehl zum Bearbeiten von Notizen, die Datei wird zuletzt übergeben, z. B. code --wait oder gedit. Leer lassen, um die Standard-App für Markdown-Dateien zu verwenden.
+settings-shortcuts = Tastenkürzel…
+settings-close = Schließen
+
+## Werkzeugleiste und Statuszeile
+
+toolbar-add-task = Aufgabe hinzufügen
+toolbar-command-palette = Befehlspalette
+toolbar-search = Titel und Notizen durchsuchen
+toolbar-search-keys = Titel und Notizen durchsuchen ({ $keys })
+toolbar-search-matches = { $matches } von { $total }
+toolbar-search-clear = Leeren
+toolbar-applying = Änderungen werden übernommen…
+toolbar-show-details = Details einblenden
+toolbar-hide-details = Details ausblenden
+toolbar-filters = Filter
+toolbar-filters-count = Filter ({ $count })
+toolbar-theme = Darstellung: { $theme }
+toolbar-mode-table = Tabelle
+toolbar-mode-board = Board
+toolbar-mode-calendar = Kalender
+toolbar-settings = Einstellungen
+toolbar-export = Exportieren
+status-not-refreshed = Noch nicht aktualisiert
+status-refreshed-at = Aktualisiert um { $time }
+status-refreshed-ago = Zuletzt vor { $age } aktualisiert
+status-loading = Wird geladen…
+status-error = Fehler: { $error }
+status-refresh-paused = Automatische Aktualisierung angehalten
+status-refresh-pause = Anhalten
+status-refresh-resume = Fortsetzen
+
+## Aufgabentabelle
+
+column-title = Titel
+column-status = Status
+column-project = Projekt
+column-contexts = Kontexte
+column-tags = Tags
+column-due = Fällig
+column-defer = Zurückgestellt
+column-priority = Priorität
+column-energy = Energie
+column-estimate = Schätzung
+column-waiting-on = Wartet auf
+column-completed = Erledigt
+column-created = Erstellt
+column-total = Gesamt
+column-next = Als Nächstes
+column-waiting = Wartet
+column-someday = Irgendwann
+table-load-more = Mehr laden
+table-columns = Spalten
+table-reset-layout = Layout zurücksetzen
+table-drag-to-move = Zum Verschieben ziehen
+waiting-one-task = 1 Aufgabe
+waiting-tasks = { $count } Aufgaben
+waiting-for = wartet seit { $age }
+waiting-nudge-all = Bei allen nachhaken
+editor-title = Aufgabentitel
+editor-project = Projekt
+editor-select-project = Projekt wählen
+editor-contexts = Kontexte (durch Komma getrennt)
+editor-add-context = Kontext hinzufügen
+editor-tags = Tags (durch Komma getrennt)
+editor-add-tag = Tag hinzufügen
+editor-select-priority = Priorität wählen
+editor-select-energy = Energie wählen
+editor-date = morgen, +3d, JJJJ-MM-TT
+editor-clear-date = Datum entfernen
+date-picker-due = Fällig
+date-picker-defer = Zurückstellen bis
+date-picker-today = Heute
+date-picker-clear = Entfernen
+date-picker-previous = Vorheriger Monat
+date-picker-next = Nächster Monat
+date-picker-mo = Mo
+date-picker-tu = Di
+date-picker-we = Mi
+date-picker-th = Do
+date-picker-fr = Fr
+date-picker-sa = Sa
+date-picker-su = So
+task-due = Fällig { $date }
+
+## Aufgabendetails
+
+detail-title = Details
+detail-close = Schließen
+detail-empty = Eine Aufgabe auswählen, um ihre Details zu sehen
+detail-status = Status
+detail-project = Projekt
+detail-areas = Bereiche
+detail-contexts = Kontexte
+detail-tags = Tags
+detail-priority = Priorität
+detail-energy = Energie
+detail-estimate = Schätzung
+detail-due = Fällig
+detail-defer = Zurückgestellt
+detail-waiting-on = Wartet auf
+detail-repeat = Wiederholung
+detail-notes = Notizen
+detail-history = Verlauf
+history-captured = Erfasst
+history-waiting = Wartet seit
+history-updated = Zuletzt geändert
+history-completed = Erledigt
+notes-edit = Bearbeiten
+notes-preview = Vorschau
+notes-placeholder = Notizen, Links und Checklisten (- [ ] Punkt)
+notes-save = Notizen speichern
+notes-revert = Verwerfen
+notes-external-editor = Externer Editor
+notes-watching = { $file } wird auf Speichern überwacht
+notes-stop-watching = Beenden
+notes-empty = Noch keine Notizen
+
+## Erfassungshinweise
+
+capture-placeholder = Aufgabe mit Inline-Tokens hinzufügen
+capture-did-you-mean = Meintest du { $suggestion }?
+capture-remove-attachment = Anhang entfernen
+capture-hint-context = Kontext (@home, @phone)
+capture-hint-project = Projektname (+Website)
+capture-hint-tag = Tag (#ops)
+capture-hint-due = Fälligkeitsdatum (today, tomorrow, fri, 2025-01-20, +3d)
+capture-hint-defer = Startdatum (tomorrow, +1w)
+capture-hint-estimate = Zeitschätzung (Minuten oder 2h)
+capture-hint-energy = Energieniveau
+capture-hint-priority = Priorität (0=niedrig … 3=hoch)
+capture-hint-wait = Wartet auf Person/Kontakt
+capture-hint-since = Wartet seit (today, +2d)
+quick-capture-placeholder = Aufgabe erfassen (@kontext +projekt due:fri)
+quick-capture-hint = Enter legt sie im Eingang ab · Esc schließt
+
+## Dialoge
+
+dialog-cancel = Abbrechen
+defer-title-one = Aufgabe zurückstellen
+defer-title-many = { $count } Aufgaben zurückstellen
+defer-preset-tomorrow = Morgen
+defer-preset-this-weekend = Dieses Wochenende
+defer-preset-next-monday = Nächsten Montag
+defer-preset-in-two-weeks = In zwei Wochen
+defer-preset-next-month = Nächsten Monat
+defer-placeholder = Oder ein Datum: fri, +3d, 2025-06-01
+defer-confirm = Zurückstellen
+delete-title-one = „{ $title }“ löschen?
+delete-title-many = { $count } Aufgaben löschen?
+delete-hint = Das kann nicht rückgängig gemacht werden.
+delete-confirm = Löschen
+export-title = Exportieren
+export-format = Format
+export-include = Umfang
+export-scope-current-view = Aktuelle Ansicht
+export-scope-whole-database = Ganze Datenbank
+export-summary-view = { $count } Aufgaben aus { $view }, mit Suche und Filtern
+export-summary-everything = Alle Aufgaben, auch erledigte
+export-confirm = Exportieren…
+
+## Kalender und Board
+
+calendar-today = Heute
+calendar-previous = Zurück
+calendar-next = Weiter
+calendar-month = Monat
+calendar-week = Woche
+calendar-mon = Mo
+calendar-tue = Di
+calendar-wed = Mi
+calendar-thu = Do
+calendar-fri = Fr
+calendar-sat = Sa
+calendar-sun = So
+calendar-more = +{ $count } weitere
+calendar-nothing = Nichts geplant
+calendar-due = Fällig { $time }
+calendar-starts = Beginnt { $time }
+board-drop-here = Aufgaben hier ablegen
+sidebar-drop-project = Auf ein Projekt ziehen
+
+## Filter
+
+filters-loading = Filter werden geladen…
+filters-projects = Projekte
+filters-contexts = Kontexte
+filters-tags = Tags
+filters-priority = Priorität
+filters-priority-low = Niedrig+
+filters-priority-medium = Mittel+
+filters-priority-high = Hoch
+filters-filtered-by = Gefiltert nach
+filters-clear = Filter löschen
+filters-remove = Filter entfernen
+filters-pin = An Seitenleiste anheften
+filters-saved-views = Gespeicherte Ansichten
+
+## Projekte und Statistik
+
+project-back = ‹ Projekte
+project-open = { $count } offen
+project-loading = Wird geladen…
+project-load-failed = { $project } konnte nicht geladen werden: { $error }
+project-stalled = Festgefahren: Dieses Projekt hat keine nächste Aktion. Eine Aufgabe befördern oder unten eine erfassen.
+project-capture-placeholder = Aufgabe zu diesem Projekt hinzufügen
+stats-loading = Wird geladen…
+stats-completed = Erledigt
+stats-completed-caption = letzte { $weeks } Wochen
+stats-open = Offen
+stats-open-caption = über alle Status
+stats-inbox-age = Alter im Eingang
+stats-inbox-age-caption = Durchschnitt über { $count }
+stats-weekly = Erledigt pro Woche
+stats-by-status = Offen nach Status
+stats-estimates = Schätzung und tatsächlicher Aufwand
+stats-estimates-empty = Eine Aufgabe mit Schätzung und erfasster Zeit abschließen, um beides hier zu vergleichen.
+stats-this-week = Diese Woche
+stats-weeks-ago = -{ $weeks } W.
+
+## Tastenkürzel
+
+shortcuts-title = Tastenkürzel
+shortcuts-hint = Cmd steht unter Windows und Linux für Strg. / und Rücktaste öffnen immer die Palette und löschen.
+shortcuts-recording = Tasten drücken… (Esc bricht ab)
+shortcuts-unbound = Nicht belegt
+shortcuts-change = Ändern
+shortcuts-reset = Zurücksetzen
+shortcuts-reset-all = Alle zurücksetzen
+shortcut-add-task = Aufgabe hinzufügen
+shortcut-command-palette = Befehlspalette
+shortcut-search = Suchen
+shortcut-next-view = Nächste Ansicht
+shortcut-previous-view = Vorherige Ansicht
+shortcut-toggle-details = Details ein-/ausblenden
+shortcut-toggle-filters = Filter ein-/ausblenden
+shortcut-mark-done = Erledigen
+shortcut-promote-to-next = Zu „Als Nächstes“
+shortcut-move-to-inbox = In den Eingang
+shortcut-delete-task = Aufgabe löschen
+shortcut-refresh = Aktualisieren
+shortcut-print = Drucken
+shortcut-undo = Rückgängig
+shortcut-settings = Einstellungen
+shortcut-focus-next-area = Nächsten Bereich fokussieren
+shortcut-focus-previous-area = Vorherigen Bereich fokussieren
+
+## Letzte Ereignisse
+
+events-title = Letzte Ereignisse
+events-hint = Die letzten { $count } Ereignisse dieser Sitzung; Fehler sind hervorgehoben.
+events-empty = Noch keine Ereignisse.
+events-disabled = Die Telemetrie ist aus, daher werden keine Ereignisse aufbewahrt.
+
+## Drucken
+
+print-matching = Passend zu „{ $query }“
+print-stalled = Festgefahren: keine nächste Aktion
+
+## Fokus
+
+focus-timer-running = Zeitmessung läuft
+focus-timer-paused = Zeitmessung angehalten
+focus-start-timer = Zeitmessung starten
+focus-pause-timer = Zeitmessung anhalten
+focus-defer = Zurückstellen…
+focus-done = Erledigt
+focus-exit = Fokus beenden
+focus-hint = Esc beendet den Fokus
+
+## Angeheftete Ansichten
+
+sidebar-pinned = Angeheftet
+sidebar-drag-to-reorder = Zum Umsortieren ziehen
+pinned-edit = Bearbeiten…
+pinned-remove = Aus der Seitenleiste entfernen
+pin-title-new = An Seitenleiste anheften
+pin-title-edit = Ansicht bearbeiten
+pin-name = Name
+pin-use-current = Stattdessen die aktuellen Filter verwenden
+pin-save = Speichern
+
+## Tray und Erinnerungen
+
+tray-summary = { $inbox } im Eingang · { $overdue } überfällig
+tray-open = cpt.run öffnen
+tray-quick-capture = Schnell erfassen…
+tray-keep-running = Nach dem Schließen weiterlaufen
+tray-quit = cpt.run beenden
+reminder-due = Aufgabe fällig
+reminder-resurfaced = Zurückgestellte Aufgabe ist wieder da
+reminder-done = Erledigt
+reminder-snooze = 1 Std. zurückstellen
+
+## Ansichtsfenster
+
+window-due = fällig { $date }
+window-empty = Gerade nichts zu tun
+window-loading = Wird geladen…
@@ -0,0 +1,503 @@
+# English strings for the cpt.run desktop app. Other catalogs use the same ids; anything they
+# leave out falls back to the text here. `{ $name }` is filled in by the app.
+
+locale-name = English
+
+## Views (sidebar, board columns)
+
+view-all = All
+view-all-subtitle = Unified task inventory
+view-inbox = Inbox
+view-inbox-subtitle = Collect everything, triage quickly
+view-next = Next
+view-next-subtitle = High-signal next actions ready for focus
+view-waiting = Waiting
+view-waiting-subtitle = People & dependencies to follow up
+view-scheduled = Scheduled
+view-scheduled-subtitle = Deferred or time-specific commitments
+view-someday = Someday
+view-someday-subtitle = Ideas parked for a later review
+view-projects = Projects
+view-projects-subtitle = See projects with next steps
+view-done = Done
+view-done-subtitle = Completed work, newest first
+view-stats = Stats
+view-stats-subtitle = Weekly trends for retrospectives
+
+## Command palette and row menu
+
+action-open-capture = Add
+action-open-capture-description = Add a task with inline tokens
+action-promote-next = Promote to Next
+action-promote-next-description = Mark selected tasks as next actions
+action-mark-done = Mark done
+action-mark-done-description = Complete selected tasks
+action-move-to-inbox = Move to Inbox
+action-move-to-inbox-description = Send selected tasks back to inbox
+action-defer-tomorrow = Defer until tomorrow
+action-defer-tomorrow-description = Snooze selected task for 1 day
+action-defer-next-week = Defer until next week
+action-defer-next-week-description = Snooze selected task for 7 days
+action-defer-pick = Defer until…
+action-defer-pick-description = Pick a preset or type a date for the selected tasks
+action-move-to-project = Move to project
+action-move-to-project-description = Pick a project for the selected task
+action-copy-task = Copy task
+action-copy-task-description = Copy the selected task as a capture line
+action-delete-task = Delete task
+action-delete-task-description = Permanently delete the selected task
+action-toggle-details = Toggle details
+action-toggle-details-description = Show or hide the task detail panel
+action-toggle-filters = Toggle filters
+action-toggle-filters-description = Filter by project, context, tag, or priority
+action-refresh = Refresh now
+action-refresh-description = Reload active view
+action-export = Export…
+action-export-description = Save this view or every task as JSON, CSV, Markdown, or iCalendar
+action-print = Print…
+action-print-description = Print this view or save it as a PDF
+action-focus = Focus on task
+action-focus-description = Show only the selected task, timing the work on it
+action-new-window = Open in new window
+action-new-window-description = Show this view in another window, to keep two lists side by side
+menu-defer = Defer
+palette-title = Command palette
+palette-placeholder = Type a command
+palette-empty = No matches
+
+## Task changes, as named in toasts
+
+mutation-promote = promote
+mutation-complete = complete
+mutation-move-to-inbox = move to inbox
+mutation-delete = delete
+mutation-move = move
+mutation-defer = defer
+mutation-rename = rename
+mutation-update-project = update project
+mutation-update-contexts = update contexts
+mutation-update-tags = update tags
+mutation-update-priority = update priority
+mutation-update-energy = update energy
+mutation-update-estimate = update estimate
+mutation-update-notes = update notes
+mutation-update-due-date = update due date
+mutation-update-defer-date = update defer date
+mutation-undo = undo
+mutation-reorder = reorder
+mutation-nudge = nudge
+
+## Toasts
+
+toast-mutation-queued = Queued { $action }…
+toast-mutation-succeeded = { $action } succeeded
+toast-action-undo = Undo
+toast-action-open-file = Open file
+toast-action-retry = Retry
+toast-dismiss = Dismiss
+toast-task-added = Added task '{ $title }'.
+toast-tasks-added = Added { $count } tasks.
+toast-sample-data-loaded = Added sample tasks to explore; delete them whenever you like.
+toast-sample-data-failed = Failed to add sample data: { $error }
+toast-copied = Copied '{ $line }'
+toast-exported-one = Exported 1 task to { $path }
+toast-exported-many = Exported { $count } tasks to { $path }
+toast-print-opened = Opened the print layout in your browser ({ $path })
+toast-print-failed = Could not prepare the print layout: { $error }
+toast-print-nothing = Nothing to print in this view
+toast-focus-nothing = Select a task to focus on
+toast-timer-failed = Could not update the timer: { $error }
+toast-view-saved = Saved the view “{ $name }”
+toast-view-save-failed = Could not save the view: { $error }
+toast-view-slots-full = All 9 saved view slots are in use; edit a pinned view to reuse its slot
+toast-export-failed = Export failed: { $error }
+toast-open-failed = Could not open { $target }: { $error }
+toast-link-invalid = Could not open the link: { $error }
+toast-link-task-missing = No task { $id } to show
+toast-editor-failed = Could not open the external editor: { $error }
+toast-zoom = Zoom { $percent }%
+toast-shortcut-bound = { $keys } now runs { $action }.
+toast-shortcut-displaced = { $keys } now runs { $action }; { $displaced } is unbound.
+toast-shortcuts-save-failed = Failed to save shortcuts: { $error }
+toast-layout-save-failed = Failed to save column layout: { $error }
+toast-stats-load-failed = Failed to load stats: { $error }
+toast-filters-load-failed = Failed to load filters: { $error }
+toast-title-empty = Task title cannot be empty
+toast-priority-range = Priority must be between 0 (none) and 3 (high).
+toast-estimate-invalid = Estimate must be minutes or hours, like 45m, 2h, or 1h30m.
+error-invalid-date = Invalid date: { $error }
+
+## Capture
+
+capture-bulk-title = Add { $count } tasks, one per line?
+capture-bulk-confirm = Add { $count } tasks
+capture-bulk-single = Keep as one task
+capture-bulk-invalid = Fix the marked lines, or keep the paste as one task.
+capture-recent = Recent
+capture-recent-clear = Clear history
+capture-recent-hint = ↑ and ↓ step through recent captures
+capture-empty = Task text cannot be empty
+
+## Empty views
+
+empty-all = All clear
+empty-all-hint = Nothing is open. Capture what is on your mind and sort it out later.
+empty-inbox = Inbox is clear
+empty-inbox-hint = New tasks land here until you decide what they need.
+empty-next = No next actions yet
+empty-next-hint = Promote tasks from the Inbox once you know the next step.
+empty-waiting = Nothing pending
+empty-waiting-hint = Add wait:Name when capturing to track what others owe you.
+empty-scheduled = Nothing scheduled
+empty-scheduled-hint = Tasks with a due: or defer: date show up here.
+empty-someday = Someday is empty
+empty-someday-hint = Park ideas here when they can wait.
+empty-projects = No projects yet
+empty-projects-hint = Add +ProjectName when capturing to start a project.
+empty-done = No finished tasks yet
+empty-done-hint = Completed tasks are listed here by day.
+empty-search = No tasks match "{ $query }"
+empty-search-hint = Search looks at titles and notes in this view.
+empty-clear-search = Clear search
+empty-filtered = No tasks match the filters
+empty-filtered-hint = The picked project, context, tag, or priority leaves nothing in this view.
+empty-clear-filters = Clear filters
+empty-add-task = Add a task
+empty-open-inbox = Open Inbox
+empty-sample-data = Load sample data
+empty-data-dir = Your tasks are stored in { $path }
+error-view = { $view } could not be loaded
+error-view-hint = The list will load again on the next refresh, or retry now. Recent events show each failed refresh.
+error-retry = Retry
+error-retrying = Retrying…
+error-recent-events = Recent events…
+
+## Settings
+
+settings-title = Settings
+settings-appearance = Appearance
+settings-appearance-system = System
+settings-appearance-light = Light
+settings-appearance-dark = Dark
+settings-text-size = Text size
+settings-reset = Reset
+settings-color-theme = Color theme
+settings-themes-hint = Add your own themes as TOML files in { $dir }
+settings-language = Language
+settings-language-system = System ({ $language })
+settings-reduce-motion = Reduce motion
+settings-reduce-motion-hint = Toasts stay until dismissed or pushed out and the refresh time stops counting.
+settings-refresh = Auto-refresh
+settings-refresh-seconds = { $seconds }s
+settings-refresh-paused = Pause auto-refresh
+settings-refresh-hint = How often the open view reloads from the database. Refresh now (R) reloads at any time, paused or not.
+settings-telemetry = Telemetry
+settings-telemetry-memory = This session
+settings-telemetry-file = Log file
+settings-telemetry-stdout = Stdout
+settings-telemetry-disabled = Off
+settings-telemetry-events = Recent events…
+settings-telemetry-hint = Usage events stay on this computer and are never sent anywhere.
+settings-telemetry-file-hint = Events are also appended to { $path }
+settings-telemetry-disabled-hint = Nothing is recorded.
+settings-external-editor = External editor
+settings-external-editor-placeholder = System default app
+settings-external-editor-hint = Command that edits notes, given the file last, e.g. code --wait or gedit. Leave empty to use the default app for Markdown files.
+settings-shortcuts = Keyboard shortcuts…
+settings-close = Close
+
+## Toolbar and status bar
+
+toolbar-add-task = Add Task
+toolbar-command-palette = Command Palette
+toolbar-search = Search titles and notes
+toolbar-search-keys = Search titles and notes ({ $keys })
+toolbar-search-matches = { $matches } of { $total }
+toolbar-search-clear = Clear
+toolbar-applying = Applying changes…
+toolbar-show-details = Show details
+toolbar-hide-details = Hide details
+toolbar-filters = Filters
+toolbar-filters-count = Filters ({ $count })
+toolbar-theme = Theme: { $theme }
+toolbar-mode-table = Table
+toolbar-mode-board = Board
+toolbar-mode-calendar = Calendar
+toolbar-settings = Settings
+toolbar-export = Export
+status-not-refreshed = Not yet refreshed
+status-refreshed-at = Refreshed at { $time }
+status-refreshed-ago = Last refreshed { $age } ago
+status-loading = Loading…
+status-error = Error: { $error }
+status-refresh-paused = Auto-refresh paused
+status-refresh-pause = Pause
+status-refresh-resume = Resume
+
+## Task table
+
+column-title = Title
+column-status = Status
+column-project = Project
+column-contexts = Contexts
+column-tags = Tags
+column-due = Due
+column-defer = Defer
+column-priority = Priority
+column-energy = Energy
+column-estimate = Estimate
+column-waiting-on = Waiting On
+column-completed = Completed
+column-created = Created
+column-total = Total
+column-next = Next
+column-waiting = Waiting
+column-someday = Someday
+table-load-more = Load more
+table-columns = Columns
+table-reset-layout = Reset layout
+table-drag-to-move = Drag to move
+waiting-one-task = 1 task
+waiting-tasks = { $count } tasks
+waiting-for = waiting { $age }
+waiting-nudge-all = Nudge all
+editor-title = Task title
+editor-project = Project
+editor-select-project = Select project
+editor-contexts = Contexts (comma separated)
+editor-add-context = Add context
+editor-tags = Tags (comma separated)
+editor-add-tag = Add tag
+editor-select-priority = Select priority
+editor-select-energy = Select energy
+editor-date = tomorrow, +3d, YYYY-MM-DD
+editor-clear-date = Clear date
+date-picker-due = Due
+date-picker-defer = Defer until
+date-picker-today = Today
+date-picker-clear = Clear
+date-picker-previous = Previous month
+date-picker-next = Next month
+date-picker-mo = Mo
+date-picker-tu = Tu
+date-picker-we = We
+date-picker-th = Th
+date-picker-fr = Fr
+date-picker-sa = Sa
+date-picker-su = Su
+task-due = Due { $date }
+
+## Task details
+
+detail-title = Details
+detail-close = Close
+detail-empty = Select a task to see its details
+detail-status = Status
+detail-project = Project
+detail-areas = Areas
+detail-contexts = Contexts
+detail-tags = Tags
+detail-priority = Priority
+detail-energy = Energy
+detail-estimate = Estimate
+detail-due = Due
+detail-defer = Defer
+detail-waiting-on = Waiting on
+detail-repeat = Repeat
+detail-notes = Notes
+detail-history = History
+history-captured = Captured
+history-waiting = Started waiting
+history-updated = Last updated
+history-completed = Completed
+notes-edit = Edit
+notes-preview = Preview
+notes-placeholder = Notes, links, and checklists (- [ ] item)
+notes-save = Save notes
+notes-revert = Revert
+notes-external-editor = External editor
+notes-watching = Watching { $file } for saves
+notes-stop-watching = Stop
+notes-empty = No notes yet
+
+## Capture hints
+
+capture-placeholder = Add a task with inline tokens
+capture-did-you-mean = Did you mean { $suggestion }?
+capture-remove-attachment = Remove attachment
+capture-hint-context = Context label (@home, @phone)
+capture-hint-project = Project name (+Website)
+capture-hint-tag = Tag (#ops)
+capture-hint-due = Due date (today, tomorrow, fri, 2025-01-20, +3d)
+capture-hint-defer = Start date (tomorrow, +1w)
+capture-hint-estimate = Time estimate (minutes or 2h)
+capture-hint-energy = Energy level
+capture-hint-priority = Priority (0=low … 3=high)
+capture-hint-wait = Waiting on person/contact
+capture-hint-since = Waiting since (today, +2d)
+quick-capture-placeholder = Capture a task (@context +project due:fri)
+quick-capture-hint = Enter to add to Inbox · Esc to dismiss
+
+## Dialogs
+
+dialog-cancel = Cancel
+defer-title-one = Defer task
+defer-title-many = Defer { $count } tasks
+defer-preset-tomorrow = Tomorrow
+defer-preset-this-weekend = This weekend
+defer-preset-next-monday = Next Monday
+defer-preset-in-two-weeks = In two weeks
+defer-preset-next-month = Next month
+defer-placeholder = Or a date: fri, +3d, 2025-06-01
+defer-confirm = Defer
+delete-title-one = Delete "{ $title }"?
+delete-title-many = Delete { $count } tasks?
+delete-hint = This cannot be undone.
+delete-confirm = Delete
+export-title = Export
+export-format = Format
+export-include = Include
+export-scope-current-view = Current view
+export-scope-whole-database = Whole database
+export-summary-view = { $count } tasks from { $view }, with search and filters applied
+export-summary-everything = Every task, including completed ones
+export-confirm = Export…
+
+## Calendar and board
+
+calendar-today = Today
+calendar-previous = Previous
+calendar-next = Next
+calendar-month = Month
+calendar-week = Week
+calendar-mon = Mon
+calendar-tue = Tue
+calendar-wed = Wed
+calendar-thu = Thu
+calendar-fri = Fri
+calendar-sat = Sat
+calendar-sun = Sun
+calendar-more = +{ $count } more
+calendar-nothing = Nothing scheduled
+calendar-due = Due { $time }
+calendar-starts = Starts { $time }
+board-drop-here = Drop tasks here
+sidebar-drop-project = Drop on a project
+
+## Filters
+
+filters-loading = Loading filters…
+filters-projects = Projects
+filters-contexts = Contexts
+filters-tags = Tags
+filters-priority = Priority
+filters-priority-low = Low+
+filters-priority-medium = Medium+
+filters-priority-high = High
+filters-filtered-by = Filtered by
+filters-clear = Clear filters
+filters-remove = Remove filter
+filters-pin = Pin to sidebar
+filters-saved-views = Saved views
+
+## Projects and stats
+
+project-back = ‹ Projects
+project-open = { $count } open
+project-loading = Loading…
+project-load-failed = Failed to load { $project }: { $error }
+project-stalled = Stalled: this project has no Next action. Promote a task or capture one below.
+project-capture-placeholder = Add a task to this project
+stats-loading = Loading…
+stats-completed = Completed
+stats-completed-caption = last { $weeks } weeks
+stats-open = Open
+stats-open-caption = across all statuses
+stats-inbox-age = Inbox age
+stats-inbox-age-caption = average over { $count }
+stats-weekly = Completed per week
+stats-by-status = Open by status
+stats-estimates = Estimate vs actual
+stats-estimates-empty = Finish a task with an estimate and tracked time to compare them here.
+stats-this-week = This week
+stats-weeks-ago = -{ $weeks }w
+
+## Keyboard shortcuts
+
+shortcuts-title = Keyboard shortcuts
+shortcuts-hint = Cmd means Ctrl on Windows and Linux. / and Backspace always open the palette and delete.
+shortcuts-recording = Press keys… (Esc cancels)
+shortcuts-unbound = Unbound
+shortcuts-change = Change
+shortcuts-reset = Reset
+shortcuts-reset-all = Reset all
+shortcut-add-task = Add task
+shortcut-command-palette = Command palette
+shortcut-search = Search
+shortcut-next-view = Next view
+shortcut-previous-view = Previous view
+shortcut-toggle-details = Toggle details
+shortcut-toggle-filters = Toggle filters
+shortcut-mark-done = Mark done
+shortcut-promote-to-next = Promote to Next
+shortcut-move-to-inbox = Move to Inbox
+shortcut-delete-task = Delete task
+shortcut-refresh = Refresh
+shortcut-print = Print
+shortcut-undo = Undo
+shortcut-settings = Settings
+shortcut-focus-next-area = Focus next area
+shortcut-focus-previous-area = Focus previous area
+
+## Recent events
+
+events-title = Recent events
+events-hint = The last { $count } events from this session; failures are highlighted.
+events-empty = No events yet.
+events-disabled = Telemetry is off, so no events are kept.
+
+## Print
+
+print-matching = Matching “{ $query }”
+print-stalled = Stalled: no Next action
+
+## Focus
+
+focus-timer-running = Timer running
+focus-timer-paused = Timer paused
+focus-start-timer = Start timer
+focus-pause-timer = Pause timer
+focus-defer = Defer…
+focus-done = Done
+focus-exit = Exit focus
+focus-hint = Esc to leave focus
+
+## Pinned views
+
+sidebar-pinned = Pinned
+sidebar-drag-to-reorder = Drag to reorder
+pinned-edit = Edit…
+pinned-remove = Remove from sidebar
+pin-title-new = Pin to sidebar
+pin-title-edit = Edit view
+pin-name = Name
+pin-use-current = Use the current filters instead
+pin-save = Save
+
+## Tray and reminders
+
+tray-summary = { $inbox } in Inbox · { $overdue } overdue
+tray-open = Open cpt.run
+tray-quick-capture = Quick capture…
+tray-keep-running = Keep running when closed
+tray-quit = Quit cpt.run
+reminder-due = Task due
+reminder-resurfaced = Deferred task is back
+reminder-done = Done
+reminder-snooze = Snooze 1h
+
+## View windows
+
+window-due = due { $date }
+window-empty = Nothing here right now
+window-loading = Loading…
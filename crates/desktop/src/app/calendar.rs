@@ -0,0 +1,147 @@
+//! Month and week calendar over the Scheduled view: which days the grid shows, the day each task
+//! lands on, and where a task dragged to another day ends up.
+
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, TimeZone, Utc};
+use cpt_core::model::Task;
+use serde::{Deserialize, Serialize};
+
+use crate::app::state::ViewTab;
+use crate::app::window_state::ViewMode;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum CalendarSpan {
+    #[default]
+    Month,
+    Week,
+}
+
+impl CalendarSpan {
+    pub(crate) const ALL: [CalendarSpan; 2] = [CalendarSpan::Month, CalendarSpan::Week];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            CalendarSpan::Month => "Month",
+            CalendarSpan::Week => "Week",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CalendarState {
+    pub(crate) span: CalendarSpan,
+    /// Any day inside the month or week on screen.
+    pub(crate) anchor: NaiveDate,
+    pub(crate) selected_day: Option<NaiveDate>,
+}
+
+impl CalendarState {
+    pub(crate) fn new(span: CalendarSpan, today: NaiveDate) -> Self {
+        Self {
+            span,
+            anchor: today,
+            selected_day: Some(today),
+        }
+    }
+
+    /// First and one-past-last day of the grid; a month is padded out to whole Monday-first weeks.
+    pub(crate) fn bounds(&self) -> (NaiveDate, NaiveDate) {
+        match self.span {
+            CalendarSpan::Week => {
+                let start = week_start(self.anchor);
+                (start, start + Duration::days(7))
+            }
+            CalendarSpan::Month => {
+                let first = first_of_month(self.anchor);
+                let next = first + Months::new(1);
+                let end = week_start(next - Duration::days(1)) + Duration::days(7);
+                (week_start(first), end)
+            }
+        }
+    }
+
+    pub(crate) fn days(&self) -> Vec<NaiveDate> {
+        let (start, end) = self.bounds();
+        start.iter_days().take_while(|day| *day < end).collect()
+    }
+
+    /// The grid as UTC instants, for the date-range query.
+    pub(crate) fn range(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let (start, end) = self.bounds();
+        (local_midnight(start), local_midnight(end))
+    }
+
+    /// Whether `day` belongs to the month on screen rather than the padding around it.
+    pub(crate) fn in_focus(&self, day: NaiveDate) -> bool {
+        match self.span {
+            CalendarSpan::Week => true,
+            CalendarSpan::Month => {
+                day.year() == self.anchor.year() && day.month() == self.anchor.month()
+            }
+        }
+    }
+
+    /// Pages `steps` months or weeks forward (or back when negative).
+    pub(crate) fn shift(&mut self, steps: i32) {
+        let count = steps.unsigned_abs();
+        self.anchor = match (self.span, steps < 0) {
+            (CalendarSpan::Month, false) => first_of_month(self.anchor) + Months::new(count),
+            (CalendarSpan::Month, true) => first_of_month(self.anchor) - Months::new(count),
+            (CalendarSpan::Week, _) => self.anchor + Duration::weeks(i64::from(steps)),
+        };
+    }
+
+    pub(crate) fn title(&self) -> String {
+        match self.span {
+            CalendarSpan::Month => self.anchor.format("%B %Y").to_string(),
+            CalendarSpan::Week => {
+                let (start, end) = self.bounds();
+                let last = end - Duration::days(1);
+                format!("{} – {}", start.format("%b %d"), last.format("%b %d, %Y"))
+            }
+        }
+    }
+}
+
+/// Date window to load `tab` with; only the Scheduled view drawn as a calendar is limited.
+pub(crate) fn view_range(
+    calendar: &CalendarState,
+    tab: ViewTab,
+    mode: ViewMode,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    (tab == ViewTab::Scheduled && mode == ViewMode::Calendar).then(|| calendar.range())
+}
+
+/// Local day a task sits on: its due date, or its defer date when it has none.
+pub(crate) fn placement(task: &Task) -> Option<NaiveDate> {
+    task.due_at
+        .or(task.defer_until)
+        .map(|at| at.with_timezone(&Local).date_naive())
+}
+
+/// `at` moved to `day`, keeping its local time of day.
+pub(crate) fn move_to_day(at: DateTime<Utc>, day: NaiveDate) -> DateTime<Utc> {
+    let time = at.with_timezone(&Local).time();
+    Local
+        .from_local_datetime(&day.and_time(time))
+        .earliest()
+        .map(|moved| moved.with_timezone(&Utc))
+        .unwrap_or_else(|| local_midnight(day))
+}
+
+fn week_start(day: NaiveDate) -> NaiveDate {
+    day - Duration::days(i64::from(day.weekday().num_days_from_monday()))
+}
+
+fn first_of_month(day: NaiveDate) -> NaiveDate {
+    day.with_day(1).unwrap_or(day)
+}
+
+fn local_midnight(day: NaiveDate) -> DateTime<Utc> {
+    let midnight = day.and_hms_opt(0, 0, 0).expect("midnight");
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+/// Recent capture lines, newest first, in the data directory.
+pub(crate) const HISTORY_FILE: &str = "desktop-captures.json";
+
+/// How many captures are remembered.
+pub(crate) const HISTORY_LIMIT: usize = 20;
+
+/// The last [`HISTORY_LIMIT`] capture lines as typed, tokens included, plus the file they
+/// persist to.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CaptureHistory {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl CaptureHistory {
+    /// Reads the history file; a missing file starts empty.
+    pub(crate) fn load(path: PathBuf) -> Result<Self> {
+        let entries = if path.exists() {
+            let raw = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            serde_json::from_str(&raw)
+                .with_context(|| format!("invalid capture history in {}", path.display()))?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            entries,
+            path: Some(path),
+        })
+    }
+
+    /// Newest first.
+    pub(crate) fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Puts `line` first, dropping an earlier copy and the oldest entry past the limit, and
+    /// writes the file.
+    pub(crate) fn record(&mut self, line: &str) -> Result<()> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+        self.entries.retain(|entry| entry != line);
+        self.entries.insert(0, line.to_string());
+        self.entries.truncate(HISTORY_LIMIT);
+        self.save()
+    }
+
+    pub(crate) fn clear(&mut self) -> Result<()> {
+        self.entries.clear();
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let contents = serde_json::to_string_pretty(&self.entries)?;
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+}
@@ -0,0 +1,167 @@
+//! Per-view task table layouts (column order, visibility, widths) saved beside the database.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::app::state::ViewTab;
+
+pub(crate) const LAYOUT_FILE: &str = "desktop-columns.json";
+pub(crate) const MIN_COLUMN_WIDTH: f32 = 60.0;
+pub(crate) const MAX_COLUMN_WIDTH: f32 = 640.0;
+/// The title column always stays visible and absorbs whatever width is left.
+pub(crate) const TITLE_COLUMN: &str = "Title";
+
+/// One view's customizations; labels missing from `order` keep their default position after it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ColumnLayout {
+    pub(crate) order: Vec<String>,
+    pub(crate) hidden: BTreeSet<String>,
+    pub(crate) widths: BTreeMap<String, f32>,
+}
+
+impl ColumnLayout {
+    /// `defaults` rearranged by the saved order; unknown saved labels are ignored.
+    pub(crate) fn ordered_labels(&self, defaults: &[&'static str]) -> Vec<&'static str> {
+        let mut labels: Vec<&'static str> = self
+            .order
+            .iter()
+            .filter_map(|saved| defaults.iter().copied().find(|label| label == saved))
+            .collect();
+        for label in defaults {
+            if !labels.contains(label) {
+                labels.push(label);
+            }
+        }
+        labels
+    }
+
+    pub(crate) fn visible_labels(&self, defaults: &[&'static str]) -> Vec<&'static str> {
+        self.ordered_labels(defaults)
+            .into_iter()
+            .filter(|label| !self.is_hidden(label))
+            .collect()
+    }
+
+    pub(crate) fn is_hidden(&self, label: &str) -> bool {
+        label != TITLE_COLUMN && self.hidden.contains(label)
+    }
+
+    pub(crate) fn width(&self, label: &str) -> Option<f32> {
+        if label == TITLE_COLUMN {
+            return None;
+        }
+        self.widths.get(label).copied()
+    }
+
+    /// Swaps `label` with its neighbour `delta` places away in the full (hidden included) order.
+    pub(crate) fn move_column(&mut self, defaults: &[&'static str], label: &str, delta: i32) {
+        let mut labels = self.ordered_labels(defaults);
+        let Some(from) = labels.iter().position(|candidate| *candidate == label) else {
+            return;
+        };
+        let to = from as i64 + delta as i64;
+        if to < 0 || to >= labels.len() as i64 {
+            return;
+        }
+        labels.swap(from, to as usize);
+        self.order = labels.into_iter().map(String::from).collect();
+    }
+
+    pub(crate) fn toggle_hidden(&mut self, label: &str) {
+        if label == TITLE_COLUMN {
+            return;
+        }
+        if !self.hidden.remove(label) {
+            self.hidden.insert(label.to_string());
+        }
+    }
+
+    pub(crate) fn set_width(&mut self, label: &str, width: f32) {
+        if label == TITLE_COLUMN {
+            return;
+        }
+        self.widths.insert(
+            label.to_string(),
+            width.clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH),
+        );
+    }
+
+    fn is_default(&self) -> bool {
+        self.order.is_empty() && self.hidden.is_empty() && self.widths.is_empty()
+    }
+}
+
+/// Every view's layout plus the file it persists to; `version` bumps on each change so cached
+/// table widgets rebuild.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ColumnLayouts {
+    views: BTreeMap<String, ColumnLayout>,
+    path: Option<PathBuf>,
+    pub(crate) version: u64,
+}
+
+impl ColumnLayouts {
+    /// Reads the layout file; a missing file starts from the defaults.
+    pub(crate) fn load(path: PathBuf) -> Result<Self> {
+        let views = if path.exists() {
+            let raw = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            serde_json::from_str(&raw)
+                .with_context(|| format!("invalid column layout in {}", path.display()))?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            views,
+            path: Some(path),
+            version: 0,
+        })
+    }
+
+    pub(crate) fn get(&self, tab: ViewTab) -> ColumnLayout {
+        self.views.get(tab.title()).cloned().unwrap_or_default()
+    }
+
+    /// Applies `change` to `tab`'s layout and writes the file.
+    pub(crate) fn update(
+        &mut self,
+        tab: ViewTab,
+        change: impl FnOnce(&mut ColumnLayout),
+    ) -> Result<()> {
+        let layout = self.views.entry(tab.title().to_string()).or_default();
+        change(layout);
+        if layout.is_default() {
+            self.views.remove(tab.title());
+        }
+        self.version = self.version.wrapping_add(1);
+        self.save()
+    }
+
+    pub(crate) fn reset(&mut self, tab: ViewTab) -> Result<()> {
+        self.views.remove(tab.title());
+        self.version = self.version.wrapping_add(1);
+        self.save()
+    }
+
+    /// Sets a width without touching the file; used while a resize drag is in flight.
+    pub(crate) fn preview_width(&mut self, tab: ViewTab, label: &str, width: f32) {
+        self.views
+            .entry(tab.title().to_string())
+            .or_default()
+            .set_width(label, width);
+        self.version = self.version.wrapping_add(1);
+    }
+
+    pub(crate) fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let contents = serde_json::to_string_pretty(&self.views)?;
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+}
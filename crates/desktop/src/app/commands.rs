@@ -1,17 +1,60 @@
 //! Async adapters that map desktop intents into core task service calls.
 
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
 use cpt_core::capture::TaskInput;
-use cpt_core::model::{AddOutcome, ListFilters};
+use cpt_core::model::{AddOutcome, ListFilters, SavedView, TimeEntry};
+use cpt_core::services::{export_tasks, ExportFormat};
 use cpt_core::TasksService;
 
 use crate::app::message::{Effect, Message};
-use crate::app::state::{MutationKind, ViewTab};
+use crate::app::print::{print_sheet, PrintSheet};
+use crate::app::seeding::maybe_seed_sample_data;
+use crate::app::state::{
+    export_format_name, ActiveFilters, ColumnSort, MutationKind, ViewCounts, ViewTab, STATS_WEEKS,
+};
+
+/// Filters `tab` loads with. Paged views take the search too, since the rows a search must
+/// reach are not all loaded; the others narrow their loaded rows on screen.
+pub(crate) fn view_filters(
+    tab: ViewTab,
+    active: &ActiveFilters,
+    sort: Option<ColumnSort>,
+    range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    search: &str,
+) -> ListFilters {
+    let mut filters = ListFilters::for_view(tab.list_view());
+    active.apply_to(&mut filters);
+    if let Some(sort) = sort {
+        sort.apply_to(&mut filters);
+    }
+    filters.dated_between = range;
+    if tab.page_size().is_some() {
+        filters.text = search_text(search);
+    }
+    filters
+}
 
-pub(crate) fn load_view_command(service: TasksService, tab: ViewTab) -> Effect {
+/// The search as a `ListFilters::text`, `None` when nothing is typed.
+pub(crate) fn search_text(search: &str) -> Option<String> {
+    let search = search.trim();
+    (!search.is_empty()).then(|| search.to_string())
+}
+
+/// Loads the first `pages` pages of `tab`, or all of it for views without paging.
+pub(crate) fn load_view_command(
+    service: TasksService,
+    tab: ViewTab,
+    mut filters: ListFilters,
+    pages: usize,
+) -> Effect {
     Effect::perform(
         async move {
             tokio::task::spawn_blocking(move || {
-                let filters = ListFilters::for_view(tab.list_view());
+                filters.limit = tab.page_size().map(|size| size * pages.max(1));
                 service.list(&filters)
             })
             .await
@@ -22,6 +65,293 @@ pub(crate) fn load_view_command(service: TasksService, tab: ViewTab) -> Effect {
     )
 }
 
+/// Loads the page of a paged view that starts after its first `offset` rows.
+pub(crate) fn load_page_command(
+    service: TasksService,
+    tab: ViewTab,
+    mut filters: ListFilters,
+    offset: usize,
+) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                filters.limit = tab.page_size();
+                filters.offset = offset;
+                service.list(&filters)
+            })
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        move |result| Message::PageLoaded(tab, result),
+    )
+}
+
+/// Counts the rows a paged view holds in the database, loaded or not.
+pub(crate) fn view_counts_command(
+    service: TasksService,
+    tab: ViewTab,
+    filters: ListFilters,
+) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || count_view(&service, &filters))
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        move |result| Message::ViewCounted(tab, result),
+    )
+}
+
+/// Rows matching `filters`, and how many there are without its search.
+pub(crate) fn count_view(
+    service: &TasksService,
+    filters: &ListFilters,
+) -> anyhow::Result<ViewCounts> {
+    let matches = service.count(filters)?;
+    let total = match filters.text {
+        Some(_) => service.count(&ListFilters {
+            text: None,
+            ..filters.clone()
+        })?,
+        None => matches,
+    };
+    Ok(ViewCounts {
+        search: filters.text.clone(),
+        matches,
+        total,
+    })
+}
+
+/// Loads every row of the active view for printing, when a paged view has not loaded them all.
+pub(crate) fn print_view_command(service: TasksService, filters: ListFilters) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || service.list(&filters))
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::PrintViewLoaded,
+    )
+}
+
+pub(crate) fn facets_command(service: TasksService) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || service.facets())
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::FacetsLoaded,
+    )
+}
+
+/// Whether the database holds no tasks at all, done ones included.
+pub(crate) fn workspace_empty_command(service: TasksService) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                let mut filters = ListFilters::for_view(None);
+                filters.include_done = true;
+                filters.limit = Some(1);
+                service
+                    .list(&filters)
+                    .map(|snapshot| snapshot.tasks.is_empty())
+            })
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::WorkspaceChecked,
+    )
+}
+
+pub(crate) fn seed_sample_data_command(service: TasksService) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || maybe_seed_sample_data(&service))
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::SampleDataLoaded,
+    )
+}
+
+/// A single task by id, for `cpt://task/<id>` links.
+pub(crate) fn fetch_task_command(service: TasksService, id: String) -> Effect {
+    Effect::perform(
+        {
+            let id = id.clone();
+            async move {
+                tokio::task::spawn_blocking(move || service.fetch_task(&id))
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result.map_err(|err| err.to_string()))
+            }
+        },
+        move |result| Message::LinkedTaskLoaded(id.clone(), result),
+    )
+}
+
+/// Every open task regardless of the active view, for the tray badge and reminders.
+pub(crate) fn open_tasks_command(service: TasksService) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                service
+                    .list(&ListFilters::for_view(None))
+                    .map(|snapshot| snapshot.tasks)
+            })
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::OpenTasksLoaded,
+    )
+}
+
+/// Numbers for the Stats view, with `STATS_WEEKS` of completion history.
+pub(crate) fn stats_command(service: TasksService) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || service.stats(STATS_WEEKS * 7))
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::StatsLoaded,
+    )
+}
+
+/// Asks where to save with the native dialog, then writes the tasks matching `filters` there.
+pub(crate) fn export_command(
+    service: TasksService,
+    filters: ListFilters,
+    format: ExportFormat,
+    file_name: String,
+) -> Effect {
+    Effect::perform(
+        async move {
+            let Some(handle) = rfd::AsyncFileDialog::new()
+                .set_file_name(file_name)
+                .add_filter(export_format_name(format), &[format.as_str()])
+                .save_file()
+                .await
+            else {
+                return Ok(None);
+            };
+            let path = handle.path().to_path_buf();
+            tokio::task::spawn_blocking(move || {
+                write_export(&service, &filters, format, &path).map(|count| Some((path, count)))
+            })
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|result| result.map_err(|err| format!("{err:#}")))
+        },
+        Message::ExportFinished,
+    )
+}
+
+/// Renders the export and writes it to `path`, returning how many tasks it holds.
+pub(crate) fn write_export(
+    service: &TasksService,
+    filters: &ListFilters,
+    format: ExportFormat,
+    path: &Path,
+) -> anyhow::Result<usize> {
+    let tasks = service.list(filters)?.tasks;
+    let contents = export_tasks(&tasks, format)?;
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(tasks.len())
+}
+
+/// Writes the print layout and opens it in the browser, whose print dialog also saves PDFs.
+pub(crate) fn saved_views_command(service: TasksService) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || service.saved_views())
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::SavedViewsLoaded,
+    )
+}
+
+pub(crate) fn save_view_command(service: TasksService, view: SavedView) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || service.save_view(&view).map(|_| view))
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::SavedViewSaved,
+    )
+}
+
+/// Starts timing `id` (stopping any other timer) and reports what its finished entries add up to.
+pub(crate) fn start_timer_command(service: TasksService, id: String) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || -> anyhow::Result<(i64, TimeEntry)> {
+                let entry = service.start_timer(&id)?;
+                Ok((service.tracked_secs(&id)?, entry))
+            })
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::FocusTimerStarted,
+    )
+}
+
+pub(crate) fn stop_timer_command(service: TasksService) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || service.stop_timer())
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        Message::FocusTimerStopped,
+    )
+}
+
+pub(crate) fn print_command(sheet: PrintSheet) -> Effect {
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || print_sheet(&sheet))
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|result| result.map_err(|err| format!("{err:#}")))
+        },
+        Message::PrintFinished,
+    )
+}
+
+/// Open tasks filed under `project`, for its project page.
+pub(crate) fn project_tasks_command(service: TasksService, project: String) -> Effect {
+    let name = project.clone();
+    Effect::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                let mut filters = ListFilters::for_view(None);
+                filters.project = Some(project);
+                service.list(&filters).map(|snapshot| snapshot.tasks)
+            })
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|result| result.map_err(|err| err.to_string()))
+        },
+        move |result| Message::ProjectTasksLoaded(name.clone(), result),
+    )
+}
+
 pub(crate) fn capture_command(
     service: TasksService,
     input: TaskInput,
@@ -34,6 +364,16 @@ pub(crate) fn capture_command(
     }
 }
 
+pub(crate) async fn capture_many_command(
+    service: TasksService,
+    inputs: Vec<TaskInput>,
+) -> Result<Vec<AddOutcome>, String> {
+    tokio::task::spawn_blocking(move || service.capture_many(&inputs))
+        .await
+        .map_err(|err| err.to_string())
+        .and_then(|result| result.map_err(|err| err.to_string()))
+}
+
 pub(crate) fn mutation_command(
     service: TasksService,
     kind: MutationKind,
@@ -43,7 +383,9 @@ pub(crate) fn mutation_command(
             MutationKind::Promote(ids) => service.promote_to_next(ids).map(|_| ()),
             MutationKind::Complete(ids) => service.mark_done(ids).map(|_| ()),
             MutationKind::Inbox(ids) => service.move_to_inbox(ids).map(|_| ()),
-            MutationKind::Defer { id, until } => service.defer_until(id, Some(*until)).map(|_| ()),
+            MutationKind::Delete(ids) => service.delete_tasks(ids).map(|_| ()),
+            MutationKind::SetStatus { ids, status } => service.set_status(ids, *status).map(|_| ()),
+            MutationKind::Defer { ids, until } => service.defer_tasks(ids, *until).map(|_| ()),
             MutationKind::Rename { id, title } => service.rename_task(id, title).map(|_| ()),
             MutationKind::ChangeProject { id, project } => {
                 service.update_project(id, project.clone()).map(|_| ())
@@ -57,6 +399,22 @@ pub(crate) fn mutation_command(
             MutationKind::ChangePriority { id, priority } => {
                 service.update_priority(id, *priority).map(|_| ())
             }
+            MutationKind::ChangeEnergy { id, energy } => {
+                service.update_energy(id, *energy).map(|_| ())
+            }
+            MutationKind::ChangeEstimate { id, minutes } => {
+                service.update_time_estimate(id, *minutes).map(|_| ())
+            }
+            MutationKind::ChangeNotes { id, notes } => {
+                service.update_notes(id, notes.clone()).map(|_| ())
+            }
+            MutationKind::ChangeDue { id, due } => service.update_due(id, *due).map(|_| ()),
+            MutationKind::ChangeDefer { id, defer } => service.defer_until(id, *defer).map(|_| ()),
+            MutationKind::Reorder(ids) => service.reorder_tasks(ids),
+            MutationKind::AppendNote { ids, line } => service.append_note(ids, line).map(|_| ()),
+            MutationKind::Restore(tasks) => tasks
+                .iter()
+                .try_for_each(|task| service.restore_task(task).map(|_| ())),
         })
         .await
         .map_err(|err| err.to_string())
@@ -0,0 +1,236 @@
+//! `cpt://` links: `cpt://task/<id>` shows a task and `cpt://capture?text=...` opens capture
+//! pre-filled. A second launch with a link hands it to the running app over a loopback port.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+
+pub(crate) const SCHEME: &str = "cpt";
+
+/// Port of the running app's link listener, in the data directory.
+pub(crate) const LINK_PORT_FILE: &str = "desktop-links.port";
+
+/// Longest link read from another launch; capture text does not need more.
+const MAX_LINK_LEN: u64 = 16 * 1024;
+
+const HANDOFF_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum DeepLink {
+    Task(String),
+    Capture(String),
+}
+
+impl DeepLink {
+    /// Whether `url` uses the `cpt:` scheme, so it is opened in the app rather than a browser.
+    pub(crate) fn is_link(url: &str) -> bool {
+        url.trim()
+            .split_once("://")
+            .is_some_and(|(scheme, _)| scheme.eq_ignore_ascii_case(SCHEME))
+    }
+
+    pub(crate) fn parse(url: &str) -> Result<Self> {
+        let url = url.trim();
+        let rest = match url.split_once("://") {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case(SCHEME) => rest,
+            _ => bail!("`{url}` is not a {SCHEME}:// link"),
+        };
+        let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let path = path.trim_end_matches('/');
+        let (kind, target) = path.split_once('/').unwrap_or((path, ""));
+
+        match kind.to_ascii_lowercase().as_str() {
+            "task" => {
+                let id = percent_decode(target, false)?;
+                if id.trim().is_empty() {
+                    bail!("`{url}` does not name a task");
+                }
+                Ok(Self::Task(id))
+            }
+            "capture" => {
+                let mut text = String::new();
+                for pair in query.split('&') {
+                    let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    if name == "text" {
+                        text = percent_decode(value, true)?;
+                    }
+                }
+                Ok(Self::Capture(text))
+            }
+            _ => bail!("unknown {SCHEME}:// link `{url}`"),
+        }
+    }
+}
+
+/// Decodes `%XX` escapes, and `+` as a space in query values.
+fn percent_decode(value: &str, plus_as_space: bool) -> Result<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let byte = value
+                    .get(index + 1..index + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .with_context(|| format!("invalid escape in `{value}`"))?;
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+            b'+' if plus_as_space => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8(decoded).with_context(|| format!("`{value}` is not valid UTF-8"))
+}
+
+/// Links passed on by later launches. Anything on this machine can send one, which is why a
+/// link only ever selects a task or fills in the capture field without submitting it.
+pub(crate) struct LinkListener {
+    links: Receiver<String>,
+}
+
+impl LinkListener {
+    /// Listens on a loopback port and records it in `port_file` for [`forward`].
+    pub(crate) fn start(port_file: PathBuf) -> Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .context("failed to open the link listener")?;
+        let port = listener.local_addr()?.port();
+        fs::write(&port_file, port.to_string())
+            .with_context(|| format!("failed to write {}", port_file.display()))?;
+
+        let (sender, links) = mpsc::channel();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let _ = stream.set_read_timeout(Some(HANDOFF_TIMEOUT));
+                let mut line = String::new();
+                let read = BufReader::new(&stream)
+                    .take(MAX_LINK_LEN)
+                    .read_line(&mut line);
+                if read.is_err() || line.trim().is_empty() {
+                    continue;
+                }
+                let _ = stream.write_all(b"ok\n");
+                if sender.send(line.trim().to_string()).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Self { links })
+    }
+
+    pub(crate) fn poll(&self) -> Vec<String> {
+        self.links.try_iter().collect()
+    }
+}
+
+/// Hands `url` to the app listening on the port in `port_file`; `false` when none answered, so
+/// the caller starts the app itself.
+pub(crate) fn forward(port_file: &Path, url: &str) -> bool {
+    let Some(port) = fs::read_to_string(port_file)
+        .ok()
+        .and_then(|port| port.trim().parse::<u16>().ok())
+    else {
+        return false;
+    };
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let Ok(mut stream) = TcpStream::connect_timeout(&address, HANDOFF_TIMEOUT) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(HANDOFF_TIMEOUT));
+    if writeln!(stream, "{}", url.trim()).is_err() {
+        return false;
+    }
+    // A stale port may belong to some other program now; only our listener answers `ok`.
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply).is_ok() && reply.trim() == "ok"
+}
+
+/// Makes the desktop the handler for `cpt://` links for the current user; returns what was
+/// registered.
+pub fn register_url_scheme() -> Result<String> {
+    let exe = std::env::current_exe().context("failed to locate the cpt executable")?;
+    register(&exe)
+}
+
+#[cfg(target_os = "linux")]
+fn register(exe: &Path) -> Result<String> {
+    use std::process::Command;
+
+    const DESKTOP_FILE: &str = "cpt-url-handler.desktop";
+
+    let applications = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .context("neither XDG_DATA_HOME nor HOME is set")?
+        .join("applications");
+    fs::create_dir_all(&applications)
+        .with_context(|| format!("failed to create {}", applications.display()))?;
+    let entry = applications.join(DESKTOP_FILE);
+    let contents = format!(
+        "[Desktop Entry]\nType=Application\nName=cpt.run\nExec=\"{}\" desktop --open %u\n\
+         MimeType=x-scheme-handler/{SCHEME};\nNoDisplay=true\n",
+        exe.display()
+    );
+    fs::write(&entry, contents).with_context(|| format!("failed to write {}", entry.display()))?;
+
+    let status = Command::new("xdg-mime")
+        .args([
+            "default",
+            DESKTOP_FILE,
+            &format!("x-scheme-handler/{SCHEME}"),
+        ])
+        .status()
+        .context("failed to run xdg-mime")?;
+    if !status.success() {
+        bail!("xdg-mime exited with {status}");
+    }
+    Ok(format!(
+        "{SCHEME}:// links now open cpt.run ({})",
+        entry.display()
+    ))
+}
+
+#[cfg(target_os = "windows")]
+fn register(exe: &Path) -> Result<String> {
+    use std::process::Command;
+
+    let key = format!(r"HKCU\Software\Classes\{SCHEME}");
+    let command = format!("\"{}\" desktop --open \"%1\"", exe.display());
+    let entries = [
+        (key.clone(), None, "URL:cpt.run".to_string()),
+        (key.clone(), Some("URL Protocol"), String::new()),
+        (format!(r"{key}\shell\open\command"), None, command),
+    ];
+    for (path, value, data) in entries {
+        let mut reg = Command::new("reg");
+        reg.args(["add", &path]);
+        match value {
+            Some(name) => reg.args(["/v", name]),
+            None => reg.arg("/ve"),
+        };
+        let status = reg
+            .args(["/d", &data, "/f"])
+            .status()
+            .context("failed to run reg")?;
+        if !status.success() {
+            bail!("reg add {path} exited with {status}");
+        }
+    }
+    Ok(format!("{SCHEME}:// links now open cpt.run ({key})"))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn register(_exe: &Path) -> Result<String> {
+    // macOS delivers links to a running bundle as Apple events, which iced does not expose.
+    bail!("registering {SCHEME}:// links is only supported on Linux and Windows")
+}
@@ -5,62 +5,155 @@ use std::env;
 use std::io::Cursor;
 use std::time::{Duration, Instant};
 
+use chrono::{Local, Utc};
+use cpt_core::model::{Facets, SavedView, Task};
+use cpt_core::services::StatsSnapshot;
 use cpt_core::{AppConfig, TasksService};
 use iced::event::{self, Event};
+use iced::keyboard::{key::Named, Event as KeyboardEvent, Key};
+use iced::mouse;
 use iced::time;
 use iced::widget::Id;
 use iced::Subscription;
 use iced::{window, Size, Theme};
 
-use crate::app::commands::load_view_command;
-use crate::app::helpers::detect_theme;
+use crate::app::calendar::{view_range, CalendarSpan, CalendarState};
+use crate::app::capture_history::{CaptureHistory, HISTORY_FILE};
+use crate::app::columns::{ColumnLayouts, LAYOUT_FILE};
+use crate::app::commands::{load_view_command, saved_views_command, stats_command, view_filters};
+use crate::app::deep_link::{self, LinkListener, LINK_PORT_FILE};
+use crate::app::external_editor::ExternalEdit;
+use crate::app::hotkey::{CaptureHotkey, HOTKEY_SUPPORTED};
+use crate::app::i18n::Catalog;
 use crate::app::message::{Effect, Message};
 use crate::app::options::{DesktopFlags, DesktopOptions};
+use crate::app::reminders::{Notifier, ReminderTracker};
 use crate::app::seeding::maybe_seed_sample_data;
+use crate::app::shortcuts::{ShortcutAction, ShortcutMap, SHORTCUTS_FILE};
 use crate::app::state::{
-    CaptureState, CommandPaletteState, InlineEditState, LoadState, StatusToast, ViewStore, ViewTab,
+    ActiveFilters, CaptureState, ColumnResize, CommandPaletteState, ContextMenuState, DeferDialog,
+    DeleteConfirmation, DragState, ExportDialog, ExportedFile, FocusSession, InlineEditState,
+    KeyboardFocus, ListViewport, LoadState, MutationKind, NotesDraft, PinDialog, PinDrag,
+    ProjectPage, QuickCapture, Rollback, ToastAction, Toasts, UndoEntry, ViewStore, ViewTab,
+    ViewWindow,
 };
-use crate::app::theme::Palette;
+use crate::app::theme::{ColorThemes, Palette, USER_THEMES_DIR};
+use crate::app::tray::{Tray, TRAY_SUPPORTED};
 use crate::app::views;
+use crate::app::window_state::{
+    ThemeChoice, WindowChange, WindowState, WindowStateStore, WINDOW_STATE_FILE,
+};
 use crate::telemetry::{self, Event as TelemetryEvent};
 
+/// How often tray menu clicks, notification actions, the capture hotkey, links from other
+/// launches, and notes saved in an external editor are picked up.
+const TRAY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How often the focus screen's running timer is redrawn.
+const FOCUS_TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the OS appearance is checked while the theme follows the system.
+const SYSTEM_THEME_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long a finished mutation's toast offers to undo it.
+pub(crate) const UNDO_WINDOW: Duration = Duration::from_secs(6);
+
 pub fn run(options: DesktopOptions) -> iced::Result {
     let _ = tracing_subscriber::fmt::try_init();
 
+    // A link meant for an app that is already open is handed over instead of starting another.
+    if let Some(url) = options.open_url.as_deref() {
+        if let Ok(config) = AppConfig::discover(options.data_dir.clone()) {
+            if deep_link::forward(&config.data_dir().join(LINK_PORT_FILE), url) {
+                return Ok(());
+            }
+        }
+    }
+
     let boot_flags = DesktopFlags::from(options);
-    let window_settings = window::Settings {
-        size: Size::new(1140.0, 780.0),
-        min_size: Some(Size::new(960.0, 600.0)),
-        maximized: true,
-        icon: load_window_icon(),
-        ..window::Settings::default()
-    };
 
-    iced::application(
+    // A daemon so the quick-capture window can come and go beside the main one; the main
+    // window is opened from `CptDesktop::new`.
+    iced::daemon(
         move || CptDesktop::new(boot_flags.clone()),
         CptDesktop::react,
         views::compose_root,
     )
-    .window(window_settings)
     .title(app_title)
     .theme(app_theme)
+    .scale_factor(app_scale_factor)
     .subscription(app_subscription)
     .run()
 }
 
-fn app_title(_state: &CptDesktop) -> String {
+fn main_window_settings(saved: &WindowState) -> window::Settings {
+    window::Settings {
+        size: saved.size(),
+        position: saved
+            .position()
+            .map_or(window::Position::Default, window::Position::Specific),
+        min_size: Some(Size::new(960.0, 600.0)),
+        maximized: saved.maximized,
+        icon: load_window_icon(),
+        // Closing may only hide the window into the tray; see `close_window`.
+        exit_on_close_request: false,
+        ..window::Settings::default()
+    }
+}
+
+pub(crate) fn quick_capture_window_settings() -> window::Settings {
+    window::Settings {
+        size: Size::new(560.0, 180.0),
+        position: window::Position::Centered,
+        resizable: false,
+        decorations: false,
+        level: window::Level::AlwaysOnTop,
+        icon: load_window_icon(),
+        exit_on_close_request: false,
+        ..window::Settings::default()
+    }
+}
+
+/// A further window on one view; smaller than the main window, with no saved geometry.
+pub(crate) fn view_window_settings() -> window::Settings {
+    window::Settings {
+        size: Size::new(720.0, 640.0),
+        min_size: Some(Size::new(420.0, 320.0)),
+        icon: load_window_icon(),
+        exit_on_close_request: false,
+        ..window::Settings::default()
+    }
+}
+
+fn app_title(state: &CptDesktop, window: window::Id) -> String {
+    if state.is_quick_capture(window) {
+        return "Quick capture".into();
+    }
+    if let Some(view_window) = state.view_window(window) {
+        return format!("{} · cpt.run", state.i18n.view_title(view_window.tab));
+    }
     format!("cpt.run Desktop v{}", env!("CARGO_PKG_VERSION"))
 }
 
-fn app_theme(state: &CptDesktop) -> Option<Theme> {
+fn app_theme(state: &CptDesktop, _window: window::Id) -> Option<Theme> {
     Some(state.theme.clone())
 }
 
+fn app_scale_factor(state: &CptDesktop, _window: window::Id) -> f64 {
+    f64::from(state.window_state.state().zoom)
+}
+
 fn app_subscription(state: &CptDesktop) -> Subscription<Message> {
     state.subscription()
 }
 
 fn load_window_icon() -> Option<window::Icon> {
+    let (rgba, width, height) = icon_rgba()?;
+    window::icon::from_rgba(rgba, width, height).ok()
+}
+
+/// Decoded brand icon shared by the window chrome and the tray.
+pub(crate) fn icon_rgba() -> Option<(Vec<u8>, u32, u32)> {
     // Embed the desktop brand icon so the native chrome reflects the app identity.
     const ICON_BYTES: &[u8] = include_bytes!(concat!(
         env!("CARGO_MANIFEST_DIR"),
@@ -73,32 +166,112 @@ fn load_window_icon() -> Option<window::Icon> {
     let frame = reader.next_frame(&mut buf).ok()?;
     let bytes = &buf[..frame.buffer_size()];
 
-    window::icon::from_rgba(bytes.to_vec(), frame.width, frame.height).ok()
+    Some((bytes.to_vec(), frame.width, frame.height))
 }
 
 pub(crate) struct CptDesktop {
     pub(crate) service: Option<TasksService>,
     pub(crate) views: HashMap<ViewTab, ViewStore>,
     pub(crate) active: ViewTab,
+    /// Where the active view's list is scrolled, to build only the rows in sight.
+    pub(crate) list_viewport: ListViewport,
     pub(crate) theme: Theme,
     pub(crate) palette: Palette,
     pub(crate) telemetry: telemetry::Handle,
+    /// `--refresh-interval`; Settings can pick another, see [`Self::refresh_every`].
     pub(crate) refresh_interval: Duration,
-    pub(crate) status: Option<StatusToast>,
+    /// Stops the timed view refreshes; Refresh now still reloads.
+    pub(crate) auto_refresh_paused: bool,
+    /// Status messages stacked over the status bar.
+    pub(crate) toasts: Toasts,
     pub(crate) capture: CaptureState,
     pub(crate) capture_input_id: Id,
+    /// Recent capture lines, recalled with the arrow keys or the Recent list.
+    pub(crate) capture_history: CaptureHistory,
     pub(crate) command_palette: CommandPaletteState,
     pub(crate) command_palette_input_id: Id,
     pub(crate) selected_task: Option<String>,
     pub(crate) pending_mutations: usize,
+    /// Pre-mutation task copies waiting on their mutation to finish.
+    pub(crate) undo_pending: Vec<UndoEntry>,
+    /// The last successful mutation, reversible until `UNDO_WINDOW` passes.
+    pub(crate) undo: Option<UndoEntry>,
+    /// Rows as they were before each pending mutation's optimistic update.
+    pub(crate) rollbacks: Vec<Rollback>,
+    /// The last failed mutation, offered again from its toast.
+    pub(crate) retry: Option<MutationKind>,
     pub(crate) inline_edit: Option<InlineEditState>,
     pub(crate) last_title_click: Option<(String, Instant)>,
+    pub(crate) context_menu: Option<ContextMenuState>,
+    pub(crate) delete_confirmation: Option<DeleteConfirmation>,
+    pub(crate) drag: Option<DragState>,
+    pub(crate) detail_open: bool,
+    pub(crate) notes_draft: Option<NotesDraft>,
+    /// Notes open in an external editor, watched for saves.
+    pub(crate) external_edit: Option<ExternalEdit>,
+    /// The focus screen, shown instead of the views while it is open.
+    pub(crate) focus: Option<FocusSession>,
+    /// Toolbar search text; narrows every view to matching titles and notes.
+    pub(crate) search: String,
+    pub(crate) search_input_id: Id,
+    pub(crate) filters: ActiveFilters,
+    pub(crate) filter_panel_open: bool,
+    pub(crate) facets: Option<Facets>,
+    /// Filter presets from the database; the window state says which are pinned.
+    pub(crate) saved_views: Vec<SavedView>,
+    /// Open tasks from the last poll, counted against each pinned view.
+    pub(crate) open_tasks: Vec<Task>,
+    pub(crate) pin_dialog: Option<PinDialog>,
+    /// The pinned view whose right-click menu is open.
+    pub(crate) pinned_menu: Option<u8>,
+    pub(crate) pin_drag: Option<PinDrag>,
+    pub(crate) columns: ColumnLayouts,
+    pub(crate) column_menu_open: bool,
+    pub(crate) column_resize: Option<ColumnResize>,
+    pub(crate) tray: Option<Tray>,
+    /// Hide the window instead of quitting when it is closed (only with a tray icon).
+    pub(crate) close_to_tray: bool,
+    pub(crate) window_id: Option<window::Id>,
+    pub(crate) window_hidden: bool,
+    pub(crate) capture_hotkey: Option<String>,
+    pub(crate) hotkey: Option<CaptureHotkey>,
+    pub(crate) quick_capture: Option<QuickCapture>,
+    pub(crate) quick_capture_input_id: Id,
+    /// Further windows, each on a view of its own.
+    pub(crate) view_windows: Vec<ViewWindow>,
+    /// The window last focused, so keys pressed in a further window leave the main one alone.
+    pub(crate) focused_window: Option<window::Id>,
+    pub(crate) notifier: Option<Notifier>,
+    pub(crate) reminders: ReminderTracker,
+    /// Geometry, view, and theme restored on the next launch.
+    pub(crate) window_state: WindowStateStore,
+    pub(crate) color_themes: ColorThemes,
+    pub(crate) settings_open: bool,
+    pub(crate) shortcuts: ShortcutMap,
+    pub(crate) shortcuts_open: bool,
+    /// The action waiting for its new key press in the shortcuts dialog.
+    pub(crate) shortcut_recording: Option<ShortcutAction>,
+    /// Month or week shown when the Scheduled view is drawn as a calendar.
+    pub(crate) calendar: CalendarState,
+    /// Drill-down shown in place of the Projects table.
+    pub(crate) project_page: Option<ProjectPage>,
+    pub(crate) stats: Option<StatsSnapshot>,
+    pub(crate) export_dialog: Option<ExportDialog>,
+    pub(crate) exported: Option<ExportedFile>,
+    pub(crate) defer_dialog: Option<DeferDialog>,
+    /// UI strings in the picked (or system) language.
+    pub(crate) i18n: Catalog,
+    /// Set by F6 traversal; draws the focus ring until Esc.
+    pub(crate) keyboard_focus: Option<KeyboardFocus>,
+    pub(crate) telemetry_events_open: bool,
+    /// Receives `cpt://` links from later launches.
+    pub(crate) links: Option<LinkListener>,
+    /// The database holds no tasks at all, so empty views offer sample data.
+    pub(crate) workspace_empty: bool,
 }
 
 impl CptDesktop {
     pub(crate) fn new(flags: DesktopFlags) -> (Self, Effect) {
-        let theme = detect_theme();
-        let palette = Palette::for_theme(&theme);
         let telemetry = telemetry::Handle::new();
         let mut views = HashMap::new();
         for tab in ViewTab::ALL {
@@ -107,11 +280,58 @@ impl CptDesktop {
 
         let mut service_opt = None;
         let mut effect = Effect::none();
+        let mut columns = ColumnLayouts::default();
+        let mut window_state = WindowStateStore::default();
+        let mut active = ViewTab::Inbox;
+        let mut color_themes = ColorThemes::default();
+        let mut shortcuts = ShortcutMap::default();
+        let mut capture_history = CaptureHistory::default();
+        let mut calendar = CalendarState::new(CalendarSpan::default(), Local::now().date_naive());
+        let mut links = None;
 
         match AppConfig::discover(flags.data_dir.clone()) {
             Ok(config) => match TasksService::new(config.clone()) {
                 Ok(service) => {
+                    match ColumnLayouts::load(config.data_dir().join(LAYOUT_FILE)) {
+                        Ok(loaded) => columns = loaded,
+                        Err(err) => {
+                            tracing::warn!(error = %err, "failed to load desktop column layout")
+                        }
+                    }
+                    match WindowStateStore::load(config.data_dir().join(WINDOW_STATE_FILE)) {
+                        Ok(loaded) => window_state = loaded,
+                        Err(err) => {
+                            tracing::warn!(error = %err, "failed to load desktop window state")
+                        }
+                    }
+                    telemetry.configure(window_state.state().telemetry, Some(config.data_dir()));
                     telemetry.record(TelemetryEvent::AppStarted);
+                    match LinkListener::start(config.data_dir().join(LINK_PORT_FILE)) {
+                        Ok(listener) => links = Some(listener),
+                        Err(err) => {
+                            tracing::warn!(error = %err, "failed to listen for cpt:// links")
+                        }
+                    }
+                    match ShortcutMap::load(config.data_dir().join(SHORTCUTS_FILE)) {
+                        Ok(loaded) => shortcuts = loaded,
+                        Err(err) => {
+                            tracing::warn!(error = %err, "failed to load desktop shortcuts")
+                        }
+                    }
+                    match CaptureHistory::load(config.data_dir().join(HISTORY_FILE)) {
+                        Ok(loaded) => capture_history = loaded,
+                        Err(err) => {
+                            tracing::warn!(error = %err, "failed to load desktop capture history")
+                        }
+                    }
+                    let (loaded, errors) =
+                        ColorThemes::load(config.data_dir().join(USER_THEMES_DIR));
+                    for err in errors {
+                        tracing::warn!(error = %err, "skipped a desktop color theme");
+                    }
+                    color_themes = loaded;
+                    active = window_state.state().active_view().unwrap_or(ViewTab::Inbox);
+                    calendar.span = window_state.state().calendar_span;
                     if should_seed_sample_data(&flags, &config) {
                         match maybe_seed_sample_data(&service) {
                             Ok(true) => tracing::debug!("seeded desktop sample data"),
@@ -122,9 +342,16 @@ impl CptDesktop {
                         }
                     }
                     views
-                        .entry(ViewTab::Inbox)
+                        .entry(active)
                         .and_modify(|store| store.state = LoadState::Loading);
-                    effect = load_view_command(service.clone(), ViewTab::Inbox);
+                    effect = if active == ViewTab::Stats {
+                        stats_command(service.clone())
+                    } else {
+                        let range = view_range(&calendar, active, window_state.state().view_mode);
+                        let filters =
+                            view_filters(active, &ActiveFilters::default(), None, range, "");
+                        load_view_command(service.clone(), active, filters, 1)
+                    };
                     service_opt = Some(service);
                 }
                 Err(err) => {
@@ -140,24 +367,107 @@ impl CptDesktop {
             }
         }
 
+        let saved = window_state.state();
+        let (theme, palette) = color_themes
+            .get(saved.color_theme.as_deref())
+            .resolve(saved.theme.resolve());
+
+        let i18n = Catalog::new(saved.locale.as_deref());
+
+        let (main_window, open_main) = window::open(main_window_settings(window_state.state()));
+        let mut effects = vec![open_main.discard(), effect];
+        // The tray icon and global shortcut have to be created once the event loop is running.
+        if TRAY_SUPPORTED {
+            effects.push(Effect::done(Message::TrayRequested));
+        }
+        if HOTKEY_SUPPORTED && flags.capture_hotkey.is_some() {
+            effects.push(Effect::done(Message::HotkeyRequested));
+        }
+        if let Some(url) = flags.open_url.clone() {
+            effects.push(Effect::done(Message::LinkOpened(url)));
+        }
+        if let Some(service) = service_opt.clone() {
+            effects.push(saved_views_command(service));
+        }
+        let effect = Effect::batch(effects);
+
         (
             Self {
                 service: service_opt,
                 views,
-                active: ViewTab::Inbox,
+                active,
+                list_viewport: ListViewport::default(),
                 theme,
                 palette,
                 telemetry,
                 refresh_interval: flags.refresh_interval,
-                status: None,
+                auto_refresh_paused: false,
+                toasts: Toasts::default(),
                 capture: CaptureState::new(),
                 capture_input_id: Id::new("capture_input"),
+                capture_history,
                 command_palette: CommandPaletteState::new(),
                 command_palette_input_id: Id::new("command_palette_input"),
                 selected_task: None,
                 pending_mutations: 0,
+                undo_pending: Vec::new(),
+                undo: None,
+                rollbacks: Vec::new(),
+                retry: None,
                 inline_edit: None,
                 last_title_click: None,
+                context_menu: None,
+                delete_confirmation: None,
+                drag: None,
+                detail_open: false,
+                notes_draft: None,
+                external_edit: None,
+                focus: None,
+                search: String::new(),
+                search_input_id: Id::new("search_input"),
+                filters: ActiveFilters::default(),
+                filter_panel_open: false,
+                facets: None,
+                saved_views: Vec::new(),
+                open_tasks: Vec::new(),
+                pin_dialog: None,
+                pinned_menu: None,
+                pin_drag: None,
+                columns,
+                column_menu_open: false,
+                column_resize: None,
+                tray: None,
+                close_to_tray: flags.close_to_tray,
+                window_id: Some(main_window),
+                window_hidden: false,
+                capture_hotkey: flags.capture_hotkey.clone(),
+                hotkey: None,
+                quick_capture: None,
+                quick_capture_input_id: Id::new("quick_capture_input"),
+                view_windows: Vec::new(),
+                focused_window: None,
+                #[cfg(feature = "notifications")]
+                notifier: Some(Notifier::new()),
+                #[cfg(not(feature = "notifications"))]
+                notifier: None,
+                reminders: ReminderTracker::new(Utc::now()),
+                window_state,
+                color_themes,
+                settings_open: false,
+                shortcuts,
+                shortcuts_open: false,
+                shortcut_recording: None,
+                calendar,
+                project_page: None,
+                stats: None,
+                export_dialog: None,
+                exported: None,
+                defer_dialog: None,
+                i18n,
+                keyboard_focus: None,
+                telemetry_events_open: false,
+                links,
+                workspace_empty: false,
             },
             effect,
         )
@@ -165,6 +475,10 @@ impl CptDesktop {
 }
 
 fn should_seed_sample_data(flags: &DesktopFlags, config: &AppConfig) -> bool {
+    if let Some(seed) = flags.seed_sample_data {
+        return seed;
+    }
+
     if !cfg!(debug_assertions) {
         return false;
     }
@@ -182,28 +496,167 @@ fn should_seed_sample_data(flags: &DesktopFlags, config: &AppConfig) -> bool {
 impl CptDesktop {
     pub(crate) fn subscription(&self) -> Subscription<Message> {
         let refresh = if self.service.is_some() {
-            time::every(self.refresh_interval).map(|_| Message::RefreshTick)
+            time::every(self.refresh_every()).map(|_| Message::RefreshTick)
         } else {
             Subscription::none()
         };
 
-        let keyboard = event::listen_with(|event, _, _| match event {
+        let keyboard = event::listen_with(|event, status, id| match event {
+            // Typing into a focused input or the notes editor must not fire single-key shortcuts.
+            Event::Keyboard(KeyboardEvent::KeyPressed {
+                key:
+                    Key::Character(_)
+                    | Key::Named(
+                        Named::Space
+                        | Named::Delete
+                        | Named::Backspace
+                        | Named::Home
+                        | Named::End
+                        | Named::Insert
+                        | Named::PageUp
+                        | Named::PageDown,
+                    ),
+                modifiers,
+                ..
+            }) if status == event::Status::Captured && !modifiers.command() => None,
             Event::Keyboard(key_event) => Some(Message::Keyboard(key_event)),
+            // Drops are resolved against the hovered sidebar entry, so any release ends a drag.
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                Some(Message::DragReleased)
+            }
+            Event::Window(window::Event::Focused) => Some(Message::WindowFocused(id)),
+            Event::Window(window::Event::FileDropped(path)) => Some(Message::FileDropped(path)),
+            Event::Window(window::Event::Unfocused) => Some(Message::WindowUnfocused(id)),
+            Event::Window(window::Event::Resized(size)) => {
+                Some(Message::WindowChanged(id, WindowChange::Resized(size)))
+            }
+            Event::Window(window::Event::Moved(position)) => {
+                Some(Message::WindowChanged(id, WindowChange::Moved(position)))
+            }
             _ => None,
         });
 
-        Subscription::batch(vec![refresh, keyboard])
+        // Only follow the cursor while a column is being resized.
+        let resize = if self.column_resize.is_some() {
+            event::listen_with(|event, _, _| match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    Some(Message::ColumnResizeMoved(position.x))
+                }
+                _ => None,
+            })
+        } else {
+            Subscription::none()
+        };
+
+        let tray = if self.tray.is_some() {
+            time::every(TRAY_POLL_INTERVAL).map(|_| Message::TrayPolled)
+        } else {
+            Subscription::none()
+        };
+
+        let reminders = if self.notifier.is_some() {
+            time::every(TRAY_POLL_INTERVAL).map(|_| Message::RemindersPolled)
+        } else {
+            Subscription::none()
+        };
+
+        let system_theme = if self.window_state.state().theme == ThemeChoice::System {
+            time::every(SYSTEM_THEME_POLL_INTERVAL).map(|_| Message::SystemThemePolled)
+        } else {
+            Subscription::none()
+        };
+
+        let hotkey = if self.hotkey.is_some() {
+            time::every(TRAY_POLL_INTERVAL).map(|_| Message::HotkeyPolled)
+        } else {
+            Subscription::none()
+        };
+
+        let links = if self.links.is_some() {
+            time::every(TRAY_POLL_INTERVAL).map(|_| Message::LinksPolled)
+        } else {
+            Subscription::none()
+        };
+
+        let external_edit = if self.external_edit.is_some() {
+            time::every(TRAY_POLL_INTERVAL).map(|_| Message::ExternalEditPolled)
+        } else {
+            Subscription::none()
+        };
+
+        let focus_timer = if self
+            .focus
+            .as_ref()
+            .is_some_and(|focus| focus.timer.is_some())
+        {
+            time::every(FOCUS_TICK_INTERVAL).map(|_| Message::FocusTick)
+        } else {
+            Subscription::none()
+        };
+
+        // No window closes on its own; `close_window` decides between hiding, closing, and quitting.
+        let close = window::close_requests().map(Message::WindowCloseRequested);
+
+        Subscription::batch(vec![
+            refresh,
+            keyboard,
+            resize,
+            tray,
+            reminders,
+            hotkey,
+            system_theme,
+            links,
+            external_edit,
+            focus_timer,
+            close,
+        ])
+    }
+
+    pub(crate) fn is_quick_capture(&self, window: window::Id) -> bool {
+        self.quick_capture
+            .as_ref()
+            .is_some_and(|quick| quick.window == window)
+    }
+
+    pub(crate) fn view_window(&self, window: window::Id) -> Option<&ViewWindow> {
+        self.view_windows
+            .iter()
+            .find(|view_window| view_window.window == window)
+    }
+
+    /// How often views refresh: the interval picked in Settings, else the one given at launch.
+    pub(crate) fn refresh_every(&self) -> Duration {
+        self.window_state
+            .state()
+            .refresh_interval
+            .map(Duration::from_secs)
+            .unwrap_or(self.refresh_interval)
     }
 
     pub(super) fn ensure_view_entry(&mut self, tab: ViewTab) {
         self.views.entry(tab).or_insert_with(ViewStore::new);
     }
 
+    /// Expires toasts on their timers, along with the Undo and Open file offers they carry.
+    /// With reduced motion toasts stay until pushed out by newer ones (or Esc) rather than
+    /// vanishing on their own; Undo still lapses so it cannot revert a change made long ago,
+    /// unless the pointer is on its toast.
     pub(super) fn prune_toast(&mut self) {
-        if let Some(toast) = &self.status {
-            if toast.created_at.elapsed() > Duration::from_secs(6) {
-                self.status = None;
+        if !self.window_state.state().reduce_motion {
+            self.toasts.prune();
+        }
+        if let Some(undo) = &self.undo {
+            let lapsed =
+                undo.created_at.elapsed() > UNDO_WINDOW && !self.toasts.holds(ToastAction::Undo);
+            if lapsed || !self.toasts.offers(ToastAction::Undo) {
+                self.undo = None;
             }
         }
+        if !self.toasts.offers(ToastAction::OpenFile) {
+            self.exported = None;
+        }
+        if !self.toasts.offers(ToastAction::Retry) {
+            self.retry = None;
+        }
     }
 }
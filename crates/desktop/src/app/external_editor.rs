@@ -0,0 +1,115 @@
+//! Editing task notes in another program: the notes go to a temp file, the editor opens it, and
+//! every save is picked up by polling the file until the edit is stopped or the editor exits.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use anyhow::{anyhow, Context, Result};
+
+/// Notes open in an external editor. Dropping it removes the temp file.
+#[derive(Debug)]
+pub(crate) struct ExternalEdit {
+    pub(crate) task_id: String,
+    pub(crate) path: PathBuf,
+    /// The file contents last written or picked up, so only real saves are applied.
+    last: String,
+    /// Set once a configured editor command exits; `None` for the system's default app, whose
+    /// process cannot be followed.
+    exited: Option<Arc<AtomicBool>>,
+}
+
+impl ExternalEdit {
+    /// Writes `notes` to a temp file and opens it in `editor` (a command line such as
+    /// `code --wait`), or in the system's default app for Markdown files when that is `None`.
+    pub(crate) fn start(
+        task_id: String,
+        title: &str,
+        notes: &str,
+        editor: Option<&str>,
+    ) -> Result<Self> {
+        let path = write_temp_file(title, notes)?;
+        let mut edit = Self {
+            task_id,
+            path,
+            last: notes.to_string(),
+            exited: None,
+        };
+        // On failure `edit` is dropped here, taking the temp file with it.
+        edit.exited = launch(editor.filter(|cmd| !cmd.trim().is_empty()), &edit.path)?;
+        Ok(edit)
+    }
+
+    /// The notes as last saved in the editor, when that differs from what was seen before.
+    pub(crate) fn poll(&mut self) -> Option<String> {
+        let saved = fs::read_to_string(&self.path).ok()?;
+        if saved == self.last {
+            return None;
+        }
+        self.last = saved.clone();
+        Some(saved)
+    }
+
+    /// Whether the editor command has exited, so no more saves can come.
+    pub(crate) fn finished(&self) -> bool {
+        self.exited
+            .as_ref()
+            .is_some_and(|exited| exited.load(Ordering::Relaxed))
+    }
+}
+
+impl Drop for ExternalEdit {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Creates a new, uniquely named file for the notes, so an edit never shares its file with
+/// another one (whose drop would delete it) or follows a link planted in the temp directory.
+fn write_temp_file(title: &str, notes: &str) -> Result<PathBuf> {
+    let slug: String = title
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .take(12)
+        .collect();
+    let mut file = tempfile::Builder::new()
+        .prefix(&format!("cpt-notes-{slug}-"))
+        .suffix(".md")
+        .tempfile()
+        .context("failed to create a temp file for the notes")?;
+    file.write_all(notes.as_bytes())
+        .with_context(|| format!("failed to write {}", file.path().display()))?;
+    // The file outlives this handle, which some editors could not replace on Windows;
+    // `ExternalEdit` removes it when dropped.
+    let (_, path) = file.keep().context("failed to keep the notes file")?;
+    Ok(path)
+}
+
+fn launch(editor: Option<&str>, path: &Path) -> Result<Option<Arc<AtomicBool>>> {
+    let Some(editor) = editor else {
+        opener::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+        return Ok(None);
+    };
+    let mut parts = editor.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow!("no editor configured"))?;
+    let mut child = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .spawn()
+        .with_context(|| format!("failed to launch editor '{}'", program))?;
+
+    // Reap the editor in the background; the app keeps running while it is open.
+    let exited = Arc::new(AtomicBool::new(false));
+    let flag = exited.clone();
+    thread::spawn(move || {
+        let _ = child.wait();
+        flag.store(true, Ordering::Relaxed);
+    });
+    Ok(Some(exited))
+}
@@ -1,7 +1,11 @@
 //! Helper utilities for detecting environment defaults and previewing capture tokens.
 
-use chrono::{DateTime, Local, Utc};
+use std::path::Path;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
 use cpt_core::capture::TaskInput;
+use cpt_core::model::Task;
+use cpt_core::parser::TokenError;
 use dark_light::Mode as ThemePreference;
 use iced::Theme;
 
@@ -15,7 +19,8 @@ pub(crate) fn detect_theme() -> Theme {
     }
 }
 
-pub(crate) fn capture_preview(input: &str) -> Result<Option<CapturePreview>, String> {
+/// Chips for the tokens in `input`. A bad token comes back as a [`TokenError`] inside the error.
+pub(crate) fn capture_preview(input: &str) -> anyhow::Result<Option<CapturePreview>> {
     if input.trim().is_empty() {
         return Ok(None);
     }
@@ -78,10 +83,35 @@ pub(crate) fn capture_preview(input: &str) -> Result<Option<CapturePreview>, Str
                     kind: CaptureChipKind::Priority,
                 });
             }
-            Ok(Some(CapturePreview { chips }))
+            Ok(Some(CapturePreview {
+                title: parsed.task.title,
+                chips,
+            }))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Moves `error` from the single-spaced text the parser saw onto `input` as typed, so its
+/// range covers the token there.
+pub(crate) fn locate_token_error(mut error: TokenError, input: &str) -> TokenError {
+    let mut spaced = 0;
+    for word in input.split_whitespace() {
+        if spaced == error.start {
+            error.start = word.as_ptr() as usize - input.as_ptr() as usize;
+            break;
         }
-        Err(err) => Err(err.to_string()),
+        spaced += word.len() + 1;
     }
+    error
+}
+
+/// `input` with the bad token replaced by its suggested fix.
+pub(crate) fn apply_token_fix(input: &str, error: &TokenError) -> Option<String> {
+    let fix = error.suggestion.as_ref()?;
+    let range = error.range();
+    (input.get(range.clone()) == Some(error.token.as_str()))
+        .then(|| format!("{}{fix}{}", &input[..range.start], &input[range.end..]))
 }
 
 pub(crate) fn format_datetime(dt: DateTime<Utc>) -> String {
@@ -89,6 +119,137 @@ pub(crate) fn format_datetime(dt: DateTime<Utc>) -> String {
     local.format("%a %b %d %H:%M").to_string()
 }
 
+/// Heading for a completed task in the Done view, relative to `today`.
+pub(crate) fn completion_group(
+    completed_at: Option<DateTime<Utc>>,
+    today: NaiveDate,
+) -> &'static str {
+    let Some(completed) = completed_at.map(|dt| dt.with_timezone(&Local).date_naive()) else {
+        return "Earlier";
+    };
+    let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    if completed >= today {
+        "Today"
+    } else if completed == today - Duration::days(1) {
+        "Yesterday"
+    } else if completed >= week_start {
+        "This week"
+    } else {
+        "Earlier"
+    }
+}
+
+/// Who a Waiting task waits on, if anyone is named.
+pub(crate) fn waiting_person(task: &Task) -> Option<&str> {
+    task.waiting_on
+        .as_deref()
+        .map(str::trim)
+        .filter(|person| !person.is_empty())
+}
+
+/// Heading for a task in the Waiting view.
+pub(crate) fn waiting_group(task: &Task) -> String {
+    waiting_person(task).unwrap_or("No one named").to_string()
+}
+
+/// Orders Waiting tasks by who they wait on, people alphabetically and tasks naming no one last,
+/// keeping the order within each group.
+pub(crate) fn group_by_waiting_on(tasks: &mut [Task]) {
+    tasks.sort_by_cached_key(|task| {
+        let person = waiting_person(task);
+        (
+            person.is_none(),
+            person.map(str::to_lowercase),
+            person.map(str::to_string),
+        )
+    });
+}
+
+/// Rebuilds a task's capture line (`title +Project @ctx #tag`) for the clipboard.
+pub(crate) fn capture_line(task: &Task) -> String {
+    let mut parts = vec![task.title.clone()];
+    if let Some(project) = task.project.as_ref().filter(|project| !project.is_empty()) {
+        parts.push(format!("+{project}"));
+    }
+    parts.extend(task.contexts.iter().map(|ctx| format!("@{ctx}")));
+    parts.extend(task.tags.iter().map(|tag| format!("#{tag}")));
+    if task.priority > 0 {
+        parts.push(format!("p:{}", task.priority));
+    }
+    parts.join(" ")
+}
+
+/// A capture line for a file dropped on the window: its name without the extension, with
+/// underscores as spaces and nothing a capture token would take, so `+`, `@`, `#`, and `:`
+/// are dropped.
+pub(crate) fn dropped_file_title(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .or(path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    stem.replace('_', " ")
+        .split_whitespace()
+        .map(|word| word.trim_start_matches(['+', '@', '#']).replace(':', " "))
+        .filter(|word| !word.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A markdown link to a local file for task notes, e.g. `[report.pdf](file:///tmp/report.pdf)`.
+pub(crate) fn file_link(path: &Path) -> String {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    let location = path.display().to_string().replace('\\', "/");
+    let mut url = String::from(if location.starts_with('/') {
+        "file://"
+    } else {
+        "file:///"
+    });
+    for c in location.chars() {
+        match c {
+            ' ' => url.push_str("%20"),
+            '%' => url.push_str("%25"),
+            '(' => url.push_str("%28"),
+            ')' => url.push_str("%29"),
+            c => url.push(c),
+        }
+    }
+    format!("[{}]({url})", name.replace(['[', ']'], ""))
+}
+
+/// True when every word of `query` appears in the task's title or notes, ignoring case.
+pub(crate) fn matches_search(task: &Task, query: &str) -> bool {
+    let title = task.title.to_lowercase();
+    let notes = task.notes.as_deref().unwrap_or_default().to_lowercase();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .all(|word| title.contains(word) || notes.contains(word))
+}
+
+/// `45m`, `2h`, or `1h30m` for an estimate in minutes, the same shape `t:` accepts.
+pub(crate) fn format_minutes(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, rest) => format!("{rest}m"),
+        (hours, 0) => format!("{hours}h"),
+        (hours, rest) => format!("{hours}h{rest}m"),
+    }
+}
+
+/// `h:mm:ss`, or `mm:ss` under an hour, for a running timer.
+pub(crate) fn format_elapsed(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
 pub(crate) fn capitalize(text: &str) -> String {
     let mut chars = text.chars();
     match chars.next() {
@@ -0,0 +1,73 @@
+//! System-wide shortcut that pops the quick-capture window from anywhere.
+
+/// Used when the user does not pick their own combination.
+pub(crate) const DEFAULT_CAPTURE_HOTKEY: &str = "CmdOrCtrl+Shift+Space";
+
+/// Whether this build can register global shortcuts.
+pub(crate) const HOTKEY_SUPPORTED: bool = cfg!(feature = "hotkey");
+
+#[cfg(feature = "hotkey")]
+pub(crate) use native::CaptureHotkey;
+
+#[cfg(not(feature = "hotkey"))]
+pub(crate) use fallback::CaptureHotkey;
+
+#[cfg(feature = "hotkey")]
+mod native {
+    use anyhow::{anyhow, Context, Result};
+    use global_hotkey::hotkey::HotKey;
+    use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+
+    /// A registered shortcut; dropping it unregisters the key.
+    pub(crate) struct CaptureHotkey {
+        _manager: GlobalHotKeyManager,
+        hotkey: HotKey,
+    }
+
+    impl CaptureHotkey {
+        /// Parses `spec` (e.g. `CmdOrCtrl+Shift+Space`) and registers it. Must run on the main
+        /// thread once the event loop is up.
+        pub(crate) fn register(spec: &str) -> Result<Self> {
+            let hotkey: HotKey = spec
+                .parse()
+                .map_err(|err| anyhow!("invalid capture hotkey '{spec}': {err}"))?;
+            let manager = GlobalHotKeyManager::new().context("global shortcuts are unavailable")?;
+            manager
+                .register(hotkey)
+                .with_context(|| format!("failed to register capture hotkey '{spec}'"))?;
+            Ok(Self {
+                _manager: manager,
+                hotkey,
+            })
+        }
+
+        /// Whether the shortcut was pressed since the last poll.
+        pub(crate) fn pressed(&self) -> bool {
+            let mut pressed = false;
+            while let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
+                if event.id == self.hotkey.id() && event.state == HotKeyState::Pressed {
+                    pressed = true;
+                }
+            }
+            pressed
+        }
+    }
+}
+
+#[cfg(not(feature = "hotkey"))]
+mod fallback {
+    use anyhow::{bail, Result};
+
+    /// Stand-in for builds without global shortcuts; it can never be constructed.
+    pub(crate) enum CaptureHotkey {}
+
+    impl CaptureHotkey {
+        pub(crate) fn register(_spec: &str) -> Result<Self> {
+            bail!("global shortcuts are not supported in this build")
+        }
+
+        pub(crate) fn pressed(&self) -> bool {
+            match *self {}
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Translated UI strings: one Fluent-style catalog per locale (`id = text`, `{ $name }`
+//! placeholders), bundled from `locales/*.ftl`. Ids a catalog leaves out fall back to English.
+
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+
+use crate::app::shortcuts::ShortcutAction;
+use crate::app::state::{CommandActionId, ViewTab};
+
+/// English, the catalog every other locale falls back to.
+pub(crate) const DEFAULT_LOCALE: &str = "en";
+
+const BUNDLED: &[(&str, &str)] = &[
+    ("en", include_str!("../../locales/en.ftl")),
+    ("de", include_str!("../../locales/de.ftl")),
+];
+
+#[derive(Debug, Clone)]
+pub(crate) struct Catalog {
+    locale: &'static str,
+    messages: Arc<HashMap<String, String>>,
+    fallback: Arc<HashMap<String, String>>,
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Self::new(Some(DEFAULT_LOCALE))
+    }
+}
+
+impl Catalog {
+    /// The catalog for `requested`, or for the system language when that is `None` or not
+    /// bundled; English when neither matches.
+    pub(crate) fn new(requested: Option<&str>) -> Self {
+        let locale = requested
+            .and_then(bundled_code)
+            .or_else(system_locale)
+            .unwrap_or(DEFAULT_LOCALE);
+        let fallback = Arc::new(parse_catalog(source(DEFAULT_LOCALE)));
+        let messages = if locale == DEFAULT_LOCALE {
+            fallback.clone()
+        } else {
+            Arc::new(parse_catalog(source(locale)))
+        };
+        Self {
+            locale,
+            messages,
+            fallback,
+        }
+    }
+
+    pub(crate) fn locale(&self) -> &'static str {
+        self.locale
+    }
+
+    /// The text for `id`; unknown ids come back as-is so a missing entry is easy to spot.
+    pub(crate) fn tr(&self, id: &str) -> String {
+        self.messages
+            .get(id)
+            .or_else(|| self.fallback.get(id))
+            .cloned()
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Like [`Catalog::tr`], filling each `{ $name }` placeholder from `args`.
+    pub(crate) fn tr_args(&self, id: &str, args: &[(&str, String)]) -> String {
+        let mut text = self.tr(id);
+        for (name, value) in args {
+            text = text
+                .replace(&format!("{{ ${name} }}"), value)
+                .replace(&format!("{{${name}}}"), value);
+        }
+        text
+    }
+
+    pub(crate) fn view_title(&self, tab: ViewTab) -> String {
+        self.tr(&format!("view-{}", view_id(tab)))
+    }
+
+    pub(crate) fn view_subtitle(&self, tab: ViewTab) -> String {
+        self.tr(&format!("view-{}-subtitle", view_id(tab)))
+    }
+
+    pub(crate) fn action_label(&self, id: CommandActionId) -> String {
+        self.tr(&format!("action-{}", id.message_id()))
+    }
+
+    pub(crate) fn action_description(&self, id: CommandActionId) -> String {
+        self.tr(&format!("action-{}-description", id.message_id()))
+    }
+
+    /// A mutation's label (`MutationKind::label`) as shown in toasts.
+    pub(crate) fn mutation(&self, label: &str) -> String {
+        self.tr(&format!("mutation-{}", label.replace(' ', "-")))
+    }
+
+    /// A table column's header; columns are keyed by their English label.
+    pub(crate) fn column(&self, label: &str) -> String {
+        self.tr(&format!("column-{}", message_id(label)))
+    }
+
+    /// A rebindable action as listed under Keyboard shortcuts.
+    pub(crate) fn shortcut(&self, action: ShortcutAction) -> String {
+        self.tr(&format!("shortcut-{}", message_id(action.label())))
+    }
+}
+
+/// Bundled locales as `(code, name in that language)`, English first.
+pub(crate) fn locales() -> Vec<(&'static str, String)> {
+    BUNDLED
+        .iter()
+        .map(|(code, source)| {
+            let name = parse_catalog(source)
+                .remove("locale-name")
+                .unwrap_or_else(|| code.to_string());
+            (*code, name)
+        })
+        .collect()
+}
+
+fn view_id(tab: ViewTab) -> String {
+    tab.title().to_ascii_lowercase()
+}
+
+/// `"Waiting On"` as `waiting-on`.
+fn message_id(label: &str) -> String {
+    label.to_ascii_lowercase().replace(' ', "-")
+}
+
+fn bundled_code(requested: &str) -> Option<&'static str> {
+    BUNDLED
+        .iter()
+        .map(|(code, _)| *code)
+        .find(|code| code.eq_ignore_ascii_case(requested))
+}
+
+fn source(locale: &str) -> &'static str {
+    BUNDLED
+        .iter()
+        .find(|(code, _)| *code == locale)
+        .map(|(_, source)| *source)
+        .unwrap_or_default()
+}
+
+/// Language of `LC_ALL`, `LC_MESSAGES`, or `LANG` (e.g. `de_DE.UTF-8`), if it is bundled.
+fn system_locale() -> Option<&'static str> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| {
+            let language = value.split(['_', '.', '-', '@']).next().unwrap_or_default();
+            bundled_code(language)
+        })
+}
+
+/// Reads `id = text` entries, skipping blank lines and `#` comments; indented lines continue the
+/// previous entry on a new line.
+pub(crate) fn parse_catalog(source: &str) -> HashMap<String, String> {
+    let mut messages = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+    for line in source.lines() {
+        if line.starts_with([' ', '\t']) && !line.trim().is_empty() {
+            if let Some((_, text)) = current.as_mut() {
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((id, text)) = current.take() {
+            messages.insert(id, text);
+        }
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((id, text)) = line.split_once('=') {
+            current = Some((id.trim().to_string(), text.trim().to_string()));
+        }
+    }
+    if let Some((id, text)) = current {
+        messages.insert(id, text);
+    }
+    messages
+}
@@ -1,22 +1,105 @@
 //! Message definitions passed around the desktop update loop.
 
+use std::path::PathBuf;
 use std::result::Result;
 
-use cpt_core::model::AddOutcome;
+use chrono::NaiveDate;
+use cpt_core::model::{AddOutcome, Facets, SavedView, SortField, Task, TimeEntry};
+use cpt_core::services::{ExportFormat, StatsSnapshot};
 use cpt_core::ViewSnapshot;
 use iced::keyboard::Event as KeyboardEvent;
-use iced::Task;
+use iced::widget::text_editor;
+use iced::{window, Theme};
 
-use crate::app::state::{CommandActionId, MutationKind, ViewTab};
+use crate::app::calendar::CalendarSpan;
+use crate::app::shortcuts::ShortcutAction;
+use crate::app::state::{
+    CommandActionId, DropTarget, ExportScope, FilterChip, ListViewport, MutationKind, ViewCounts,
+    ViewTab,
+};
+use crate::app::window_state::{ThemeChoice, ViewMode, WindowChange};
+use crate::telemetry::Sink as TelemetrySink;
 
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
     ViewRequested(ViewTab),
     ViewLoaded(ViewTab, Result<ViewSnapshot, String>),
+    /// A further page of a paged view, to append to its rows.
+    PageLoaded(ViewTab, Result<ViewSnapshot, String>),
+    ViewCounted(ViewTab, Result<ViewCounts, String>),
     RefreshTick,
+    LoadMoreRequested,
+    TaskListScrolled(ListViewport),
     ToggleTheme,
+    SystemThemePolled,
+    SystemThemeDetected(Theme),
+    SettingsToggled,
+    AppearancePicked(ThemeChoice),
+    ColorThemePicked(String),
+    /// `None` follows the system language.
+    LocalePicked(Option<String>),
+    ReduceMotionToggled(bool),
+    /// Seconds between view refreshes, picked in Settings.
+    RefreshIntervalPicked(u64),
+    AutoRefreshToggled,
+    ExternalEditorChanged(String),
+    TelemetrySinkPicked(TelemetrySink),
+    /// Opens or closes the recent telemetry events list.
+    TelemetryEventsToggled,
+    ZoomChanged(f32),
+    ViewModePicked(ViewMode),
+    CalendarSpanPicked(CalendarSpan),
+    /// Pages the calendar by months or weeks; zero jumps back to today.
+    CalendarShifted(i32),
+    CalendarDaySelected(NaiveDate),
+    ShortcutsToggled,
+    /// Starts listening for the next key press to bind to the action.
+    ShortcutRecordStarted(ShortcutAction),
+    /// Restores one action's default binding, or all of them for `None`.
+    ShortcutReset(Option<ShortcutAction>),
+    FilterPanelToggled,
+    FacetsLoaded(Result<Facets, String>),
+    FilterToggled(FilterChip),
+    FiltersCleared,
+    SavedViewsLoaded(Result<Vec<SavedView>, String>),
+    /// Applies a saved view's filters.
+    SavedViewOpened(u8),
+    SavedViewPinToggled(u8),
+    /// Opens the pin dialog for a pinned view, or for the current filters when `None`.
+    PinDialogOpened(Option<u8>),
+    PinNameChanged(String),
+    PinUseCurrentToggled(bool),
+    PinConfirmed,
+    PinCancelled,
+    SavedViewSaved(Result<SavedView, String>),
+    /// Opens a pinned view's right-click menu, or closes it when `None`.
+    PinnedMenuOpened(Option<u8>),
+    PinDragStarted(u8),
+    PinDragEntered(u8),
+    ColumnSortPressed(SortField),
+    ColumnMenuToggled,
+    ColumnMoved(&'static str, i32),
+    ColumnVisibilityToggled(&'static str),
+    ColumnsReset,
+    ColumnResizeStarted(&'static str, f32),
+    ColumnResizeMoved(f32),
+    SearchChanged(String),
+    SearchCleared,
     CaptureToggled,
     CaptureTextChanged(String),
+    /// A paste into the capture bar, with the input's new text; the clipboard is read again
+    /// since the input drops line breaks.
+    CapturePasted(String),
+    CaptureClipboardRead(Option<String>),
+    BulkCaptureDismissed,
+    /// Shows or hides the recent captures under the capture bar.
+    CaptureRecentToggled,
+    CaptureRecentPicked(String),
+    CaptureHistoryCleared,
+    /// A file dropped on a window, captured as a task linking to it.
+    FileDropped(PathBuf),
+    CaptureAttachmentRemoved(usize),
+    BulkCaptureCompleted(Result<Vec<AddOutcome>, String>),
     CaptureSubmit,
     CaptureCompleted(Result<AddOutcome, String>),
     CommandPaletteToggled,
@@ -24,16 +107,116 @@ pub(crate) enum Message {
     CommandPaletteQueryChanged(String),
     CommandPaletteExecute(CommandActionId),
     MutationFinished(MutationKind, Result<(), String>),
+    UndoRequested,
+    /// Seconds already tracked on the task and the timer just started for it.
+    FocusTimerStarted(Result<(i64, TimeEntry), String>),
+    FocusTimerStopped(Result<Option<TimeEntry>, String>),
+    FocusTimerToggled,
+    /// Redraws the focus screen's running timer.
+    FocusTick,
+    FocusDone,
+    FocusDefer,
+    FocusExited,
+    /// Runs the last failed mutation again.
+    RetryRequested,
+    /// Logs a follow-up in the notes of every Waiting task on this person (`None`: no one named).
+    WaitingNudged(Option<String>),
+    /// The pointer entered (`true`) or left a toast, holding or resuming its timer.
+    ToastHovered(u64, bool),
+    ToastDismissed(u64),
+    DeleteConfirmed,
+    DeleteCancelled,
+    TrayRequested,
+    TrayPolled,
+    OpenTasksLoaded(Result<Vec<Task>, String>),
+    RemindersPolled,
+    WindowCloseRequested(window::Id),
+    WindowFocused(window::Id),
+    WindowUnfocused(window::Id),
+    WindowChanged(window::Id, WindowChange),
+    /// A main-window geometry change together with whether the window is maximized.
+    WindowChangeChecked(WindowChange, bool),
+    HotkeyRequested,
+    HotkeyPolled,
+    LinksPolled,
+    SampleDataRequested,
+    /// Whether sample tasks were added; they only go into an empty database.
+    SampleDataLoaded(Result<bool, String>),
+    WorkspaceChecked(Result<bool, String>),
+    /// A `cpt://` link from the command line, another launch, or a note.
+    LinkOpened(String),
+    LinkedTaskLoaded(String, Result<Option<Task>, String>),
+    /// A further window was opened; its view is loaded once it shows.
+    ViewWindowOpened(window::Id),
+    ViewWindowTabPicked(window::Id, ViewTab),
+    ViewWindowTaskDone(String),
+    /// Shows a task from a further window in the main window, with its details.
+    ViewWindowTaskShown(String),
+    QuickCaptureOpened,
+    QuickCaptureTextChanged(String),
+    QuickCaptureSubmit,
+    QuickCaptureCompleted(Result<AddOutcome, String>),
+    StatsLoaded(Result<StatsSnapshot, String>),
+    ExportRequested,
+    ExportFormatPicked(ExportFormat),
+    ExportScopePicked(ExportScope),
+    ExportCancelled,
+    ExportConfirmed,
+    /// The written file and how many tasks it holds; `None` when the save dialog was dismissed.
+    ExportFinished(Result<Option<(PathBuf, usize)>, String>),
+    ExportedFileOpened,
+    /// Where the print layout was written and opened.
+    PrintFinished(Result<PathBuf, String>),
+    /// Every row of a paged view, loaded for printing.
+    PrintViewLoaded(Result<ViewSnapshot, String>),
+    DeferDateChanged(String),
+    /// A preset from the Defer dialog, as a date spec.
+    DeferPresetPicked(&'static str),
+    DeferConfirmed,
+    DeferCancelled,
+    ProjectOpened(String),
+    ProjectClosed,
+    ProjectTasksLoaded(String, Result<Vec<Task>, String>),
+    ProjectCaptureChanged(String),
+    ProjectCaptureSubmit,
+    ProjectCaptureCompleted(Result<AddOutcome, String>),
     RowSelected(String),
+    ContextMenuOpened(String),
+    ContextMenuClosed,
+    ContextMenuDeferToggled,
+    ContextMenuAction(CommandActionId),
+    DragStarted(String),
+    DragTargetEntered(DropTarget),
+    DragTargetLeft(DropTarget),
+    DragReleased,
+    DetailToggled,
+    NotesEdited(text_editor::Action),
+    NotesSaved,
+    NotesReverted,
+    NotesPreviewToggled,
+    /// Ticks the checklist item on this line of the notes from the rendered preview.
+    NotesCheckboxToggled(usize),
+    /// Opens the selected task's notes in the external editor.
+    NotesExternalEditRequested,
+    NotesExternalEditStopped,
+    ExternalEditPolled,
+    NoteLinkClicked(String),
     TaskTitlePressed(String),
     TaskProjectPressed(String),
     TaskContextsPressed(String),
     TaskTagsPressed(String),
     TaskPriorityPressed(String),
+    TaskEnergyPressed(String),
+    TaskEstimatePressed(String),
+    TaskDuePressed(String),
+    TaskDeferPressed(String),
     InlineEditChanged(String),
     InlineEditSubmitted,
     InlineEditOptionSelected(String),
+    DatePickerMonthChanged(i32),
+    DatePicked(NaiveDate),
+    DateCleared,
     Keyboard(KeyboardEvent),
 }
 
-pub(crate) type Effect = Task<Message>;
+pub(crate) type Effect = iced::Task<Message>;
@@ -1,18 +1,32 @@
 //! Desktop application wiring that composes views, state, and services for the cpt.run experience.
 
+pub use self::deep_link::register_url_scheme;
 pub use self::desktop::run;
 pub use self::options::DesktopOptions;
 
+mod calendar;
+mod capture_history;
+mod columns;
 mod commands;
+mod deep_link;
 mod desktop;
+mod external_editor;
 mod helpers;
+mod hotkey;
+mod i18n;
 mod message;
+mod notes;
 mod options;
+mod print;
+mod reminders;
 mod seeding;
+mod shortcuts;
 mod state;
 mod theme;
+mod tray;
 mod update;
 mod views;
+mod window_state;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,189 @@
+//! The slice of markdown the detail panel renders for task notes: headings, bullet and numbered
+//! lists, `- [ ]` checklists, quotes, and inline bold, code, and links.
+
+/// One rendered line of a note; checklist items remember their line so they can be toggled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NoteBlock {
+    Heading {
+        level: u8,
+        spans: Vec<Inline>,
+    },
+    Bullet {
+        depth: usize,
+        spans: Vec<Inline>,
+    },
+    Numbered {
+        depth: usize,
+        number: String,
+        spans: Vec<Inline>,
+    },
+    Checkbox {
+        line: usize,
+        depth: usize,
+        checked: bool,
+        spans: Vec<Inline>,
+    },
+    Quote(Vec<Inline>),
+    Paragraph(Vec<Inline>),
+    Blank,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Inline {
+    Text(String),
+    Strong(String),
+    Code(String),
+    Link { label: String, url: String },
+}
+
+pub(crate) fn parse_notes(notes: &str) -> Vec<NoteBlock> {
+    notes
+        .lines()
+        .enumerate()
+        .map(|(line, raw)| parse_line(line, raw))
+        .collect()
+}
+
+/// `notes` with the checklist item on `line` ticked or unticked, or `None` if that line is not
+/// a checklist item.
+pub(crate) fn toggle_checkbox(notes: &str, line: usize) -> Option<String> {
+    let mut lines: Vec<String> = notes.lines().map(str::to_string).collect();
+    let target = lines.get_mut(line)?;
+    let indent = target.len() - target.trim_start().len();
+    let (marker, rest) = list_marker(&target[indent..])?;
+    let (checked, text) = checkbox(rest)?;
+    let mark = if checked { ' ' } else { 'x' };
+    *target = format!("{}{marker} [{mark}] {text}", &target[..indent]);
+    let mut toggled = lines.join("\n");
+    if notes.ends_with('\n') {
+        toggled.push('\n');
+    }
+    Some(toggled)
+}
+
+fn parse_line(line: usize, raw: &str) -> NoteBlock {
+    let trimmed = raw.trim_start();
+    if trimmed.is_empty() {
+        return NoteBlock::Blank;
+    }
+    // Two spaces (or a tab) of indentation per list level.
+    let indent = &raw[..raw.len() - trimmed.len()];
+    let depth = indent.replace('\t', "  ").len() / 2;
+
+    let hashes = trimmed.chars().take_while(|ch| *ch == '#').count();
+    if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+        return NoteBlock::Heading {
+            level: hashes as u8,
+            spans: parse_inline(trimmed[hashes..].trim()),
+        };
+    }
+    if let Some(quote) = trimmed.strip_prefix('>') {
+        return NoteBlock::Quote(parse_inline(quote.trim()));
+    }
+    if let Some((marker, rest)) = list_marker(trimmed) {
+        if matches!(marker, "-" | "*" | "+") {
+            if let Some((checked, text)) = checkbox(rest) {
+                return NoteBlock::Checkbox {
+                    line,
+                    depth,
+                    checked,
+                    spans: parse_inline(text),
+                };
+            }
+            return NoteBlock::Bullet {
+                depth,
+                spans: parse_inline(rest),
+            };
+        }
+        return NoteBlock::Numbered {
+            depth,
+            number: marker.to_string(),
+            spans: parse_inline(rest),
+        };
+    }
+    NoteBlock::Paragraph(parse_inline(trimmed))
+}
+
+/// Splits `- item`, `* item`, `+ item`, or `1. item` into the marker and the text after it.
+fn list_marker(line: &str) -> Option<(&str, &str)> {
+    let (marker, rest) = line.split_once(' ')?;
+    let bullet = matches!(marker, "-" | "*" | "+");
+    let numbered = marker.len() > 1
+        && marker.ends_with(['.', ')'])
+        && marker[..marker.len() - 1]
+            .chars()
+            .all(|ch| ch.is_ascii_digit());
+    (bullet || numbered).then_some((marker, rest.trim_start()))
+}
+
+/// Reads a `[ ]` or `[x]` box at the start of a list item.
+fn checkbox(item: &str) -> Option<(bool, &str)> {
+    let checked = match item.get(..3)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+    Some((checked, item[3..].trim_start()))
+}
+
+/// Splits a line into plain text, `**bold**`, `` `code` ``, `[label](url)`, and bare
+/// `http(s)://` links.
+fn parse_inline(text: &str) -> Vec<Inline> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        if let Some((span, after)) = inline_span(rest) {
+            if !plain.is_empty() {
+                spans.push(Inline::Text(std::mem::take(&mut plain)));
+            }
+            spans.push(span);
+            rest = after;
+            continue;
+        }
+        let mut chars = rest.chars();
+        if let Some(ch) = chars.next() {
+            plain.push(ch);
+        }
+        rest = chars.as_str();
+    }
+    if !plain.is_empty() {
+        spans.push(Inline::Text(plain));
+    }
+    spans
+}
+
+fn inline_span(text: &str) -> Option<(Inline, &str)> {
+    if let Some(rest) = text.strip_prefix("**") {
+        let end = rest.find("**").filter(|end| *end > 0)?;
+        return Some((Inline::Strong(rest[..end].to_string()), &rest[end + 2..]));
+    }
+    if let Some(rest) = text.strip_prefix('`') {
+        let end = rest.find('`')?;
+        return Some((Inline::Code(rest[..end].to_string()), &rest[end + 1..]));
+    }
+    if let Some(rest) = text.strip_prefix('[') {
+        let (label, after) = rest.split_once("](")?;
+        let (url, after) = after.split_once(')')?;
+        if label.contains(']') || url.contains(char::is_whitespace) {
+            return None;
+        }
+        let link = Inline::Link {
+            label: label.to_string(),
+            url: url.to_string(),
+        };
+        return Some((link, after));
+    }
+    if text.starts_with("http://") || text.starts_with("https://") {
+        let end = text.find(char::is_whitespace).unwrap_or(text.len());
+        // Leave closing punctuation outside the link.
+        let url = text[..end].trim_end_matches(['.', ',', ';', ':', ')', '!', '?']);
+        let link = Inline::Link {
+            label: url.to_string(),
+            url: url.to_string(),
+        };
+        return Some((link, &text[url.len()..]));
+    }
+    None
+}
@@ -3,10 +3,21 @@
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::app::hotkey::DEFAULT_CAPTURE_HOTKEY;
+
 #[derive(Debug, Clone)]
 pub struct DesktopOptions {
     pub data_dir: Option<PathBuf>,
     pub refresh_interval: Duration,
+    /// Keep running in the system tray when the window is closed, where a tray is available.
+    pub close_to_tray: bool,
+    /// System-wide shortcut for the quick-capture window; `None` leaves it unregistered.
+    pub capture_hotkey: Option<String>,
+    /// A `cpt://` link to open, in the running app when there is one.
+    pub open_url: Option<String>,
+    /// `Some(true)` fills an empty database with sample tasks at launch and `Some(false)` never
+    /// does; `None` only seeds the debug build's `dev-cpt` sandbox.
+    pub seed_sample_data: Option<bool>,
 }
 
 impl Default for DesktopOptions {
@@ -14,6 +25,10 @@ impl Default for DesktopOptions {
         Self {
             data_dir: None,
             refresh_interval: Duration::from_secs(5),
+            close_to_tray: true,
+            capture_hotkey: Some(DEFAULT_CAPTURE_HOTKEY.to_string()),
+            open_url: None,
+            seed_sample_data: None,
         }
     }
 }
@@ -22,6 +37,10 @@ impl Default for DesktopOptions {
 pub(crate) struct DesktopFlags {
     pub(crate) data_dir: Option<PathBuf>,
     pub(crate) refresh_interval: Duration,
+    pub(crate) close_to_tray: bool,
+    pub(crate) capture_hotkey: Option<String>,
+    pub(crate) open_url: Option<String>,
+    pub(crate) seed_sample_data: Option<bool>,
 }
 
 impl From<DesktopOptions> for DesktopFlags {
@@ -29,6 +48,10 @@ impl From<DesktopOptions> for DesktopFlags {
         Self {
             data_dir: options.data_dir,
             refresh_interval: options.refresh_interval,
+            close_to_tray: options.close_to_tray,
+            capture_hotkey: options.capture_hotkey,
+            open_url: options.open_url,
+            seed_sample_data: options.seed_sample_data,
         }
     }
 }
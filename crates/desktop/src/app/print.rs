@@ -0,0 +1,121 @@
+//! Printable copy of the current view: a self-contained HTML page laid out for paper that the
+//! browser opens in its print dialog, where it can also be saved as a PDF.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::Local;
+
+/// What a printed view shows: its columns and rows, split into sections where the view has
+/// headings (Done's days, a project page's statuses).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct PrintSheet {
+    pub(crate) title: String,
+    /// Line under the title, e.g. the search or filters that narrowed the view.
+    pub(crate) subtitle: Option<String>,
+    pub(crate) columns: Vec<String>,
+    /// Adds an empty box before each row to tick off on paper.
+    pub(crate) checkboxes: bool,
+    pub(crate) sections: Vec<PrintSection>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct PrintSection {
+    pub(crate) heading: Option<String>,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+impl PrintSheet {
+    pub(crate) fn row_count(&self) -> usize {
+        self.sections.iter().map(|section| section.rows.len()).sum()
+    }
+
+    /// The page as HTML; it asks the browser to print as soon as it loads.
+    pub(crate) fn to_html(&self) -> String {
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             <style>{PRINT_CSS}</style>\n</head>\n<body onload=\"window.print()\">\n<h1>{}</h1>\n",
+            escape(&self.title),
+            escape(&self.title),
+        );
+        let printed = Local::now().format("%a %-d %b %Y, %H:%M");
+        html.push_str(&format!(
+            "<p class=\"meta\">{}Printed {printed}</p>\n",
+            self.subtitle
+                .as_deref()
+                .map(|subtitle| format!("{} · ", escape(subtitle)))
+                .unwrap_or_default(),
+        ));
+
+        html.push_str("<table>\n<thead><tr>");
+        if self.checkboxes {
+            html.push_str("<th class=\"check\"></th>");
+        }
+        for column in &self.columns {
+            html.push_str(&format!("<th>{}</th>", escape(column)));
+        }
+        html.push_str("</tr></thead>\n");
+        let span = self.columns.len() + usize::from(self.checkboxes);
+        for section in &self.sections {
+            html.push_str("<tbody>\n");
+            if let Some(heading) = &section.heading {
+                html.push_str(&format!(
+                    "<tr class=\"group\"><th colspan=\"{span}\">{}</th></tr>\n",
+                    escape(heading)
+                ));
+            }
+            for row in &section.rows {
+                html.push_str("<tr>");
+                if self.checkboxes {
+                    html.push_str("<td class=\"check\">☐</td>");
+                }
+                for cell in row {
+                    html.push_str(&format!("<td>{}</td>", escape(cell)));
+                }
+                html.push_str("</tr>\n");
+            }
+            html.push_str("</tbody>\n");
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+}
+
+const PRINT_CSS: &str = "\
+@page { margin: 14mm; }
+body { font: 11pt -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; color: #000; }
+h1 { font-size: 18pt; margin: 0 0 2pt; }
+.meta { color: #555; font-size: 9pt; margin: 0 0 10pt; }
+table { width: 100%; border-collapse: collapse; }
+thead { display: table-header-group; }
+th, td { text-align: left; vertical-align: top; padding: 4pt 6pt; border-bottom: 0.5pt solid #bbb; }
+thead th { font-size: 9pt; text-transform: uppercase; color: #555; border-bottom: 1pt solid #000; }
+tr { page-break-inside: avoid; }
+tr.group th { font-size: 11pt; padding-top: 10pt; border-bottom: 1pt solid #000; }
+td.check, th.check { width: 14pt; font-size: 13pt; padding-right: 0; }
+";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Writes `sheet` to a temp file and opens it in the default browser, returning its path.
+pub(crate) fn print_sheet(sheet: &PrintSheet) -> Result<PathBuf> {
+    let slug: String = sheet
+        .title
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .take(24)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let path = env::temp_dir().join(format!("cpt-print-{slug}.html"));
+    fs::write(&path, sheet.to_html())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    opener::open(&path).with_context(|| format!("failed to open {}", path.display()))?;
+    Ok(path)
+}
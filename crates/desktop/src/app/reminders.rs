@@ -0,0 +1,169 @@
+//! OS notifications for tasks that fall due or come back from a defer while the app is running.
+
+use chrono::{DateTime, Utc};
+use cpt_core::model::{Task, TaskStatus};
+
+use crate::app::i18n::Catalog;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReminderKind {
+    Due,
+    Resurfaced,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Reminder {
+    pub(crate) task_id: String,
+    pub(crate) title: String,
+    pub(crate) kind: ReminderKind,
+}
+
+impl Reminder {
+    pub(crate) fn summary(&self, i18n: &Catalog) -> String {
+        i18n.tr(match self.kind {
+            ReminderKind::Due => "reminder-due",
+            ReminderKind::Resurfaced => "reminder-resurfaced",
+        })
+    }
+}
+
+/// What the user picked on a notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ReminderAction {
+    Done(String),
+    Snooze(String),
+}
+
+/// Remembers when tasks were last checked so each due or defer date is announced once.
+#[derive(Debug, Clone)]
+pub(crate) struct ReminderTracker {
+    last_checked: DateTime<Utc>,
+}
+
+impl ReminderTracker {
+    /// Starts counting from `now`; dates already in the past are never announced.
+    pub(crate) fn new(now: DateTime<Utc>) -> Self {
+        Self { last_checked: now }
+    }
+
+    /// Open tasks whose due or defer date passed since the previous check.
+    pub(crate) fn check(&mut self, tasks: &[Task], now: DateTime<Utc>) -> Vec<Reminder> {
+        let since = std::mem::replace(&mut self.last_checked, now);
+        let crossed = |at: Option<DateTime<Utc>>| at.is_some_and(|at| since < at && at <= now);
+        tasks
+            .iter()
+            .filter(|task| !matches!(task.status, TaskStatus::Done | TaskStatus::Canceled))
+            .filter_map(|task| {
+                let kind = if crossed(task.due_at) {
+                    ReminderKind::Due
+                } else if crossed(task.defer_until) {
+                    ReminderKind::Resurfaced
+                } else {
+                    return None;
+                };
+                Some(Reminder {
+                    task_id: task.id.clone(),
+                    title: task.title.clone(),
+                    kind,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "notifications")]
+pub(crate) use native::Notifier;
+
+#[cfg(not(feature = "notifications"))]
+pub(crate) use fallback::Notifier;
+
+#[cfg(feature = "notifications")]
+mod native {
+    use std::sync::mpsc::{channel, Receiver, Sender};
+
+    use notify_rust::Notification;
+
+    use super::{Reminder, ReminderAction};
+    use crate::app::i18n::Catalog;
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    const DONE_ACTION: &str = "done";
+    #[cfg(all(unix, not(target_os = "macos")))]
+    const SNOOZE_ACTION: &str = "snooze";
+
+    /// Posts notifications and collects the actions clicked on them.
+    pub(crate) struct Notifier {
+        sender: Sender<ReminderAction>,
+        actions: Receiver<ReminderAction>,
+    }
+
+    impl Notifier {
+        pub(crate) fn new() -> Self {
+            let (sender, actions) = channel();
+            Self { sender, actions }
+        }
+
+        /// Shows `reminder` without blocking; action buttons are offered where the platform
+        /// reports clicks back (freedesktop notification servers).
+        pub(crate) fn notify(&self, reminder: Reminder, i18n: &Catalog) {
+            let sender = self.sender.clone();
+            let summary = reminder.summary(i18n);
+            #[cfg(all(unix, not(target_os = "macos")))]
+            let (done, snooze) = (i18n.tr("reminder-done"), i18n.tr("reminder-snooze"));
+            std::thread::spawn(move || {
+                let mut notification = Notification::new();
+                notification
+                    .appname("cpt.run")
+                    .summary(&summary)
+                    .body(&reminder.title);
+                #[cfg(all(unix, not(target_os = "macos")))]
+                notification
+                    .action(DONE_ACTION, &done)
+                    .action(SNOOZE_ACTION, &snooze);
+
+                let handle = match notification.show() {
+                    Ok(handle) => handle,
+                    Err(err) => {
+                        tracing::warn!(error = %err, "failed to show reminder notification");
+                        return;
+                    }
+                };
+                #[cfg(all(unix, not(target_os = "macos")))]
+                handle.wait_for_action(|action| {
+                    let picked = match action {
+                        DONE_ACTION => ReminderAction::Done(reminder.task_id.clone()),
+                        SNOOZE_ACTION => ReminderAction::Snooze(reminder.task_id.clone()),
+                        _ => return,
+                    };
+                    let _ = sender.send(picked);
+                });
+                #[cfg(not(all(unix, not(target_os = "macos"))))]
+                let _ = (handle, sender);
+            });
+        }
+
+        /// Actions clicked since the last poll.
+        pub(crate) fn poll(&self) -> Vec<ReminderAction> {
+            self.actions.try_iter().collect()
+        }
+    }
+}
+
+#[cfg(not(feature = "notifications"))]
+mod fallback {
+    use super::{Reminder, ReminderAction};
+    use crate::app::i18n::Catalog;
+
+    /// Stand-in for builds without notification support; it can never be constructed.
+    pub(crate) enum Notifier {}
+
+    impl Notifier {
+        pub(crate) fn notify(&self, _reminder: Reminder, _i18n: &Catalog) {
+            match *self {}
+        }
+
+        pub(crate) fn poll(&self) -> Vec<ReminderAction> {
+            match *self {}
+        }
+    }
+}
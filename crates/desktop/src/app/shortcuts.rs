@@ -0,0 +1,383 @@
+//! Rebindable keyboard shortcuts for the main window, saved beside the database. Only bindings
+//! that differ from the defaults are written.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use iced::keyboard::key::Named;
+use iced::keyboard::{Key, Modifiers};
+use serde::{Deserialize, Serialize};
+
+use crate::app::state::CommandActionId;
+
+pub(crate) const SHORTCUTS_FILE: &str = "desktop-shortcuts.json";
+
+/// Named keys a shortcut may use. Escape and the arrow keys stay reserved for closing overlays and
+/// moving the selection.
+const NAMED_KEYS: &[(&str, Named)] = &[
+    ("Tab", Named::Tab),
+    ("Space", Named::Space),
+    ("Enter", Named::Enter),
+    ("Delete", Named::Delete),
+    ("Backspace", Named::Backspace),
+    ("Insert", Named::Insert),
+    ("Home", Named::Home),
+    ("End", Named::End),
+    ("PageUp", Named::PageUp),
+    ("PageDown", Named::PageDown),
+    ("F1", Named::F1),
+    ("F2", Named::F2),
+    ("F3", Named::F3),
+    ("F4", Named::F4),
+    ("F5", Named::F5),
+    ("F6", Named::F6),
+    ("F7", Named::F7),
+    ("F8", Named::F8),
+    ("F9", Named::F9),
+    ("F10", Named::F10),
+    ("F11", Named::F11),
+    ("F12", Named::F12),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ShortcutAction {
+    Capture,
+    CommandPalette,
+    FocusSearch,
+    NextView,
+    PreviousView,
+    ToggleDetails,
+    ToggleFilters,
+    MarkDone,
+    PromoteNext,
+    MoveToInbox,
+    DeleteTask,
+    Refresh,
+    Print,
+    Undo,
+    Settings,
+    NextRegion,
+    PreviousRegion,
+}
+
+impl ShortcutAction {
+    pub(crate) const ALL: [ShortcutAction; 17] = [
+        ShortcutAction::Capture,
+        ShortcutAction::CommandPalette,
+        ShortcutAction::FocusSearch,
+        ShortcutAction::NextView,
+        ShortcutAction::PreviousView,
+        ShortcutAction::ToggleDetails,
+        ShortcutAction::ToggleFilters,
+        ShortcutAction::MarkDone,
+        ShortcutAction::PromoteNext,
+        ShortcutAction::MoveToInbox,
+        ShortcutAction::DeleteTask,
+        ShortcutAction::Refresh,
+        ShortcutAction::Print,
+        ShortcutAction::Undo,
+        ShortcutAction::Settings,
+        ShortcutAction::NextRegion,
+        ShortcutAction::PreviousRegion,
+    ];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            ShortcutAction::Capture => "Add task",
+            ShortcutAction::CommandPalette => "Command palette",
+            ShortcutAction::FocusSearch => "Search",
+            ShortcutAction::NextView => "Next view",
+            ShortcutAction::PreviousView => "Previous view",
+            ShortcutAction::ToggleDetails => "Toggle details",
+            ShortcutAction::ToggleFilters => "Toggle filters",
+            ShortcutAction::MarkDone => "Mark done",
+            ShortcutAction::PromoteNext => "Promote to Next",
+            ShortcutAction::MoveToInbox => "Move to Inbox",
+            ShortcutAction::DeleteTask => "Delete task",
+            ShortcutAction::Refresh => "Refresh",
+            ShortcutAction::Print => "Print",
+            ShortcutAction::Undo => "Undo",
+            ShortcutAction::Settings => "Settings",
+            ShortcutAction::NextRegion => "Focus next area",
+            ShortcutAction::PreviousRegion => "Focus previous area",
+        }
+    }
+
+    fn default_binding(self) -> KeyBinding {
+        let spec = match self {
+            ShortcutAction::Capture => "A",
+            ShortcutAction::CommandPalette => "Cmd+K",
+            ShortcutAction::FocusSearch => "Cmd+F",
+            ShortcutAction::NextView => "Tab",
+            ShortcutAction::PreviousView => "Shift+Tab",
+            ShortcutAction::ToggleDetails => "Space",
+            ShortcutAction::ToggleFilters => "F",
+            ShortcutAction::MarkDone => "D",
+            ShortcutAction::PromoteNext => "N",
+            ShortcutAction::MoveToInbox => "I",
+            ShortcutAction::DeleteTask => "Delete",
+            ShortcutAction::Refresh => "R",
+            ShortcutAction::Print => "Cmd+P",
+            ShortcutAction::Undo => "Cmd+Z",
+            ShortcutAction::Settings => "Cmd+,",
+            ShortcutAction::NextRegion => "F6",
+            ShortcutAction::PreviousRegion => "Shift+F6",
+        };
+        KeyBinding::parse(spec).expect("default shortcuts are valid")
+    }
+
+    /// The shortcut that runs the same thing as a command palette entry, if any.
+    pub(crate) fn for_command(id: CommandActionId) -> Option<Self> {
+        match id {
+            CommandActionId::OpenCapture => Some(ShortcutAction::Capture),
+            CommandActionId::PromoteNext => Some(ShortcutAction::PromoteNext),
+            CommandActionId::MarkDone => Some(ShortcutAction::MarkDone),
+            CommandActionId::MoveToInbox => Some(ShortcutAction::MoveToInbox),
+            CommandActionId::DeleteTask => Some(ShortcutAction::DeleteTask),
+            CommandActionId::ToggleDetails => Some(ShortcutAction::ToggleDetails),
+            CommandActionId::ToggleFilters => Some(ShortcutAction::ToggleFilters),
+            CommandActionId::Refresh => Some(ShortcutAction::Refresh),
+            CommandActionId::Print => Some(ShortcutAction::Print),
+            CommandActionId::DeferTomorrow
+            | CommandActionId::DeferNextWeek
+            | CommandActionId::DeferPick
+            | CommandActionId::MoveToProject
+            | CommandActionId::CopyTask
+            | CommandActionId::Export
+            | CommandActionId::Focus
+            | CommandActionId::NewWindow => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BoundKey {
+    /// Stored lowercased; letters match regardless of Shift unless the binding asks for it.
+    Character(String),
+    Named(Named),
+}
+
+/// A key plus modifiers, written like `Cmd+Shift+K`; `Cmd` means Ctrl outside macOS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct KeyBinding {
+    key: BoundKey,
+    command: bool,
+    alt: bool,
+    shift: bool,
+}
+
+impl KeyBinding {
+    pub(crate) fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        let (modifiers, key) = if spec == "+" {
+            ("", "+")
+        } else if let Some(rest) = spec.strip_suffix("++") {
+            (rest, "+")
+        } else {
+            spec.rsplit_once('+').unwrap_or(("", spec))
+        };
+
+        let mut binding = Self {
+            key: parse_key(key)?,
+            command: false,
+            alt: false,
+            shift: false,
+        };
+        for modifier in modifiers.split('+').filter(|part| !part.is_empty()) {
+            match modifier.trim().to_ascii_lowercase().as_str() {
+                "cmd" | "ctrl" | "cmdorctrl" | "command" | "control" => binding.command = true,
+                "alt" | "option" => binding.alt = true,
+                "shift" => binding.shift = true,
+                _ => return None,
+            }
+        }
+        Some(binding)
+    }
+
+    /// The binding a key press would record, or `None` for bare modifiers and reserved keys.
+    pub(crate) fn from_press(key: &Key, modifiers: Modifiers) -> Option<Self> {
+        let key = match key {
+            Key::Character(value) => BoundKey::Character(value.to_lowercase()),
+            Key::Named(named) => NAMED_KEYS
+                .iter()
+                .find(|(_, candidate)| candidate == named)
+                .map(|(_, named)| BoundKey::Named(*named))?,
+            Key::Unidentified => return None,
+        };
+        Some(Self {
+            key,
+            command: modifiers.command(),
+            alt: modifiers.alt(),
+            shift: modifiers.shift(),
+        })
+    }
+
+    pub(crate) fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        let key_matches = match (&self.key, key) {
+            (BoundKey::Character(bound), Key::Character(pressed)) => {
+                *bound == pressed.to_lowercase()
+            }
+            (BoundKey::Named(bound), Key::Named(pressed)) => bound == pressed,
+            _ => false,
+        };
+        // Shift is part of typing symbols like `?`, so character bindings only check it when
+        // they ask for it; named keys such as Tab vs Shift+Tab need an exact match.
+        let shift_matches = match self.key {
+            BoundKey::Character(_) => !self.shift || modifiers.shift(),
+            BoundKey::Named(_) => self.shift == modifiers.shift(),
+        };
+        key_matches
+            && shift_matches
+            && self.command == modifiers.command()
+            && self.alt == modifiers.alt()
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.command {
+            f.write_str("Cmd+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        match &self.key {
+            BoundKey::Character(value) => f.write_str(&value.to_uppercase()),
+            BoundKey::Named(named) => {
+                let name = NAMED_KEYS
+                    .iter()
+                    .find(|(_, candidate)| candidate == named)
+                    .map_or("?", |(name, _)| *name);
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+fn parse_key(raw: &str) -> Option<BoundKey> {
+    let raw = raw.trim();
+    if let Some((_, named)) = NAMED_KEYS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(raw))
+    {
+        return Some(BoundKey::Named(*named));
+    }
+    let mut chars = raw.chars();
+    match (chars.next(), chars.next()) {
+        (Some(single), None) => Some(BoundKey::Character(single.to_lowercase().collect())),
+        _ => None,
+    }
+}
+
+/// Every action's current binding plus the file it persists to. `None` leaves an action unbound.
+#[derive(Debug, Clone)]
+pub(crate) struct ShortcutMap {
+    bindings: BTreeMap<ShortcutAction, Option<KeyBinding>>,
+    path: Option<PathBuf>,
+}
+
+impl Default for ShortcutMap {
+    fn default() -> Self {
+        Self {
+            bindings: ShortcutAction::ALL
+                .iter()
+                .map(|action| (*action, Some(action.default_binding())))
+                .collect(),
+            path: None,
+        }
+    }
+}
+
+impl ShortcutMap {
+    /// Reads the shortcuts file; a missing file keeps the defaults.
+    pub(crate) fn load(path: PathBuf) -> Result<Self> {
+        let mut map = Self::default();
+        if path.exists() {
+            let raw = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let saved: BTreeMap<ShortcutAction, Option<String>> = serde_json::from_str(&raw)
+                .with_context(|| format!("invalid shortcuts in {}", path.display()))?;
+            for (action, spec) in saved {
+                let binding = match spec {
+                    Some(spec) => Some(KeyBinding::parse(&spec).ok_or_else(|| {
+                        anyhow!("invalid shortcut '{spec}' in {}", path.display())
+                    })?),
+                    None => None,
+                };
+                map.bindings.insert(action, binding);
+            }
+        }
+        map.path = Some(path);
+        Ok(map)
+    }
+
+    pub(crate) fn binding(&self, action: ShortcutAction) -> Option<&KeyBinding> {
+        self.bindings.get(&action).and_then(Option::as_ref)
+    }
+
+    /// Display text for `action`'s binding, e.g. `Cmd+K`.
+    pub(crate) fn hint(&self, action: ShortcutAction) -> Option<String> {
+        self.binding(action).map(KeyBinding::to_string)
+    }
+
+    pub(crate) fn action_for(&self, key: &Key, modifiers: Modifiers) -> Option<ShortcutAction> {
+        self.bindings.iter().find_map(|(action, binding)| {
+            binding
+                .as_ref()
+                .is_some_and(|binding| binding.matches(key, modifiers))
+                .then_some(*action)
+        })
+    }
+
+    /// Binds `action` to `binding` and saves; an action that held the same keys loses them and is
+    /// returned so the caller can say so.
+    pub(crate) fn rebind(
+        &mut self,
+        action: ShortcutAction,
+        binding: KeyBinding,
+    ) -> Result<Option<ShortcutAction>> {
+        let displaced = self
+            .bindings
+            .iter()
+            .find(|(other, existing)| **other != action && existing.as_ref() == Some(&binding))
+            .map(|(other, _)| *other);
+        if let Some(other) = displaced {
+            self.bindings.insert(other, None);
+        }
+        self.bindings.insert(action, Some(binding));
+        self.save()?;
+        Ok(displaced)
+    }
+
+    /// Restores the default binding for `action`, or for every action when `None`.
+    pub(crate) fn reset(&mut self, action: Option<ShortcutAction>) -> Result<()> {
+        match action {
+            Some(action) => {
+                self.bindings.insert(action, Some(action.default_binding()));
+            }
+            None => self.bindings = Self::default().bindings,
+        }
+        self.save()
+    }
+
+    pub(crate) fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let changed: BTreeMap<ShortcutAction, Option<String>> = self
+            .bindings
+            .iter()
+            .filter(|(action, binding)| binding.as_ref() != Some(&action.default_binding()))
+            .map(|(action, binding)| (*action, binding.as_ref().map(KeyBinding::to_string)))
+            .collect();
+        let contents = serde_json::to_string_pretty(&changed)?;
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+}
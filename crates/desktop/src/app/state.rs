@@ -1,14 +1,23 @@
 //! Shared state models that keep the desktop UI in sync with cpt.run tasks.
 
-use std::time::Instant;
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use cpt_core::capture::TaskInput;
-use cpt_core::model::{ListView, TaskStatus};
+use cpt_core::model::{
+    EnergyLevel, ListFilters, ListView, SavedView, SortField, Task, TaskStatus, TimeEntry,
+};
+use cpt_core::parser::TokenError;
+use cpt_core::services::ExportFormat;
 use cpt_core::ViewSnapshot;
-use iced::widget::Id;
+use iced::widget::{text_editor, Id};
+use iced::window;
 
-use crate::app::helpers::capture_preview;
+use crate::app::helpers::{capture_preview, file_link, locate_token_error};
+use crate::app::i18n::Catalog;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum ViewTab {
@@ -17,7 +26,10 @@ pub(crate) enum ViewTab {
     Next,
     Waiting,
     Scheduled,
+    Someday,
     Projects,
+    Done,
+    Stats,
 }
 
 impl ViewTab {
@@ -27,9 +39,14 @@ impl ViewTab {
         ViewTab::Next,
         ViewTab::Scheduled,
         ViewTab::Waiting,
+        ViewTab::Someday,
         ViewTab::Projects,
+        ViewTab::Done,
+        ViewTab::Stats,
     ];
 
+    /// English name, also the key for saved layouts and telemetry; the sidebar shows
+    /// [`Catalog::view_title`] instead.
     pub(crate) fn title(self) -> &'static str {
         match self {
             ViewTab::All => "All",
@@ -37,18 +54,10 @@ impl ViewTab {
             ViewTab::Next => "Next",
             ViewTab::Waiting => "Waiting",
             ViewTab::Scheduled => "Scheduled",
+            ViewTab::Someday => "Someday",
             ViewTab::Projects => "Projects",
-        }
-    }
-
-    pub(crate) fn subtitle(self) -> &'static str {
-        match self {
-            ViewTab::All => "Unified task inventory",
-            ViewTab::Inbox => "Collect everything, triage quickly",
-            ViewTab::Next => "High-signal next actions ready for focus",
-            ViewTab::Waiting => "People & dependencies to follow up",
-            ViewTab::Scheduled => "Deferred or time-specific commitments",
-            ViewTab::Projects => "See projects with next steps",
+            ViewTab::Done => "Done",
+            ViewTab::Stats => "Stats",
         }
     }
 
@@ -59,10 +68,88 @@ impl ViewTab {
             ViewTab::Next => Some(ListView::Next),
             ViewTab::Waiting => Some(ListView::Waiting),
             ViewTab::Scheduled => Some(ListView::Scheduled),
+            ViewTab::Someday => Some(ListView::Someday),
             ViewTab::Projects => Some(ListView::Projects),
+            ViewTab::Done => Some(ListView::Done),
+            // Stats is loaded from the stats service rather than a task list.
+            ViewTab::Stats => None,
         }
     }
+
+    /// Status a task takes when dropped onto this view's sidebar entry.
+    pub(crate) fn drop_status(self) -> Option<TaskStatus> {
+        match self {
+            ViewTab::All | ViewTab::Projects | ViewTab::Stats => None,
+            tab => tab.list_view().and_then(|view| view.to_status()),
+        }
+    }
+
+    /// The list a task of `status` belongs to, or All for statuses without one (canceled).
+    pub(crate) fn for_status(status: TaskStatus) -> Self {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|tab| tab.drop_status() == Some(status))
+            .unwrap_or(ViewTab::All)
+    }
+
+    /// Rows fetched per page; only the Done history and All, the lists that grow without
+    /// bound, are paged.
+    pub(crate) fn page_size(self) -> Option<usize> {
+        match self {
+            ViewTab::Done => Some(DONE_PAGE_SIZE),
+            ViewTab::All => Some(ALL_PAGE_SIZE),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) const DONE_PAGE_SIZE: usize = 50;
+pub(crate) const ALL_PAGE_SIZE: usize = 200;
+
+/// Lists longer than this only build the rows near the scroll position.
+pub(crate) const VIRTUALIZE_AFTER: usize = 150;
+/// Estimated height of a task row or group heading, spacing included, used to place the rows
+/// that are built and to size the space standing in for the rest.
+pub(crate) const TASK_ROW_HEIGHT: f32 = 38.0;
+/// The built rows move in steps of this many so that scrolling a little reuses the last build.
+const ROW_CHUNK: usize = 25;
+/// Assumed list height until the first scroll report arrives.
+const FALLBACK_LIST_HEIGHT: f32 = 900.0;
+
+/// Scroll position and size of the task list, reported as it scrolls or resizes.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct ListViewport {
+    pub(crate) offset: f32,
+    pub(crate) height: f32,
+    /// Height of the whole list, rows out of sight included.
+    pub(crate) content: f32,
+}
+
+impl ListViewport {
+    /// Rows of a `total`-row list worth building: those in sight plus a screenful either side,
+    /// so a fast scroll does not show a gap before the next build. Group headings take a row
+    /// each, so `total` and the range count them alongside the tasks.
+    pub(crate) fn visible_rows(&self, total: usize) -> Range<usize> {
+        let height = if self.height > 0.0 {
+            self.height
+        } else {
+            FALLBACK_LIST_HEIGHT
+        };
+        let screen = (height / TASK_ROW_HEIGHT).ceil() as usize;
+        let top = (self.offset.max(0.0) / TASK_ROW_HEIGHT) as usize;
+        let start = top.saturating_sub(screen) / ROW_CHUNK * ROW_CHUNK;
+        let end = (top + 2 * screen).div_ceil(ROW_CHUNK) * ROW_CHUNK;
+        start.min(total)..end.min(total)
+    }
+
+    /// Whether the end of the list is less than a screenful away, so the next page is due.
+    pub(crate) fn near_end(&self) -> bool {
+        self.content > 0.0 && self.offset + 2.0 * self.height >= self.content
+    }
 }
+/// Weeks of completion history charted on the Stats view.
+pub(crate) const STATS_WEEKS: usize = 8;
 
 #[derive(Debug, Clone)]
 pub(crate) struct ViewStore {
@@ -70,6 +157,24 @@ pub(crate) struct ViewStore {
     pub(crate) state: LoadState,
     pub(crate) version: u64,
     pub(crate) last_refreshed: Option<Instant>,
+    /// Pages requested so far for paged views; refreshes reload all of them.
+    pub(crate) pages: usize,
+    pub(crate) has_more: bool,
+    /// Column sort picked from the header; `None` keeps the view's default order.
+    pub(crate) sort: Option<ColumnSort>,
+    /// Why the last load failed, kept while a retry loads and cleared by one that works.
+    pub(crate) failed: Option<String>,
+    /// Rows a paged view holds in the database, counted with each load.
+    pub(crate) counts: Option<ViewCounts>,
+}
+
+/// How many rows of a paged view match its search, out of all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ViewCounts {
+    /// The search counted, `None` when nothing was typed.
+    pub(crate) search: Option<String>,
+    pub(crate) matches: usize,
+    pub(crate) total: usize,
 }
 
 impl ViewStore {
@@ -79,8 +184,64 @@ impl ViewStore {
             state: LoadState::Idle,
             version: 0,
             last_refreshed: None,
+            pages: 1,
+            has_more: false,
+            sort: None,
+            failed: None,
+            counts: None,
         }
     }
+
+    /// The error to show in place of the list, if the view could not be loaded.
+    pub(crate) fn load_error(&self) -> Option<&str> {
+        match &self.state {
+            LoadState::Error(err) => Some(err),
+            _ => self.failed.as_deref(),
+        }
+    }
+}
+
+/// A header-driven sort in the user's terms: ascending is A→Z, earliest, or lowest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ColumnSort {
+    pub(crate) field: SortField,
+    pub(crate) descending: bool,
+}
+
+impl ColumnSort {
+    /// Sort field behind a header label, for the columns that can be sorted.
+    pub(crate) fn field_for_column(label: &str) -> Option<SortField> {
+        match label {
+            "Title" => Some(SortField::Title),
+            "Due" => Some(SortField::Due),
+            "Priority" => Some(SortField::Priority),
+            "Created" => Some(SortField::Created),
+            "Completed" => Some(SortField::Completed),
+            _ => None,
+        }
+    }
+
+    /// Next state after clicking `field`'s header: ascending, then descending, then off.
+    pub(crate) fn cycle(current: Option<ColumnSort>, field: SortField) -> Option<ColumnSort> {
+        match current {
+            Some(sort) if sort.field == field && !sort.descending => Some(ColumnSort {
+                field,
+                descending: true,
+            }),
+            Some(sort) if sort.field == field => None,
+            _ => Some(ColumnSort {
+                field,
+                descending: false,
+            }),
+        }
+    }
+
+    pub(crate) fn apply_to(&self, filters: &mut ListFilters) {
+        // Core sorts priority and completion newest/highest first unless reversed.
+        let natural_descending = matches!(self.field, SortField::Priority | SortField::Completed);
+        filters.sort = self.field;
+        filters.reverse = self.descending != natural_descending;
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -97,6 +258,16 @@ pub(crate) enum InlineEditableField {
     Contexts,
     Tags,
     Priority,
+    Energy,
+    TimeEstimate,
+    Due,
+    Defer,
+}
+
+impl InlineEditableField {
+    pub(crate) fn is_date(self) -> bool {
+        matches!(self, InlineEditableField::Due | InlineEditableField::Defer)
+    }
 }
 
 #[derive(Clone)]
@@ -108,13 +279,25 @@ pub(crate) struct InlineEditState {
     pub(crate) input_id: Id,
     pub(crate) options: Vec<String>,
     pub(crate) original_tokens: Vec<String>,
+    /// First day of the month shown by the date picker; only set for date fields.
+    pub(crate) month: Option<NaiveDate>,
+    /// Why the typed value was rejected, shown under the input until it changes.
+    pub(crate) error: Option<String>,
 }
 
+/// How long a toast stays up while the pointer is elsewhere.
+pub(crate) const TOAST_LIFETIME: Duration = Duration::from_secs(6);
+
 #[derive(Debug, Clone)]
 pub(crate) struct StatusToast {
+    /// Handed out by [`Toasts::push`].
+    pub(crate) id: u64,
     pub(crate) message: String,
     pub(crate) kind: ToastKind,
     pub(crate) created_at: Instant,
+    pub(crate) action: Option<ToastAction>,
+    /// Set while the pointer rests on the toast, which holds its timer.
+    pub(crate) hovered_since: Option<Instant>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -123,13 +306,155 @@ pub(crate) enum ToastKind {
     Error,
 }
 
+/// A button on a toast for following up on what it reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ToastAction {
+    Undo,
+    OpenFile,
+    Retry,
+}
+
+impl StatusToast {
+    pub(crate) fn info(message: impl Into<String>) -> Self {
+        Self::new(message.into(), ToastKind::Info)
+    }
+
+    pub(crate) fn error(message: impl Into<String>) -> Self {
+        Self::new(message.into(), ToastKind::Error)
+    }
+
+    fn new(message: String, kind: ToastKind) -> Self {
+        Self {
+            id: 0,
+            message,
+            kind,
+            created_at: Instant::now(),
+            action: None,
+            hovered_since: None,
+        }
+    }
+
+    pub(crate) fn with_action(mut self, action: Option<ToastAction>) -> Self {
+        self.action = action;
+        self
+    }
+
+    fn expired(&self) -> bool {
+        self.hovered_since.is_none() && self.created_at.elapsed() > TOAST_LIFETIME
+    }
+}
+
+/// The toasts stacked over the status bar, oldest first. Each runs its own timer, held while
+/// hovered, and the oldest gives way once [`Toasts::LIMIT`] are showing.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Toasts {
+    items: Vec<StatusToast>,
+    next_id: u64,
+}
+
+impl Toasts {
+    pub(crate) const LIMIT: usize = 4;
+
+    /// Adds `toast` on top. Its action is taken off older toasts, since Undo and Open file
+    /// only ever apply to the latest change or export.
+    pub(crate) fn push(&mut self, mut toast: StatusToast) {
+        self.next_id += 1;
+        toast.id = self.next_id;
+        if let Some(action) = toast.action {
+            for older in &mut self.items {
+                if older.action == Some(action) {
+                    older.action = None;
+                }
+            }
+        }
+        self.items.push(toast);
+        if self.items.len() > Self::LIMIT {
+            self.items.remove(0);
+        }
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &StatusToast> {
+        self.items.iter()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn latest(&self) -> Option<&StatusToast> {
+        self.items.last()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub(crate) fn dismiss(&mut self, id: u64) {
+        self.items.retain(|toast| toast.id != id);
+    }
+
+    pub(crate) fn dismiss_latest(&mut self) {
+        self.items.pop();
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Holds the toast's timer while hovered; leaving it gives back the time it was held.
+    pub(crate) fn set_hovered(&mut self, id: u64, hovered: bool) {
+        let Some(toast) = self.items.iter_mut().find(|toast| toast.id == id) else {
+            return;
+        };
+        match (hovered, toast.hovered_since) {
+            (true, None) => toast.hovered_since = Some(Instant::now()),
+            (false, Some(since)) => {
+                toast.created_at += since.elapsed();
+                toast.hovered_since = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Drops the toasts whose time is up.
+    pub(crate) fn prune(&mut self) {
+        self.items.retain(|toast| !toast.expired());
+    }
+
+    /// Whether a toast still shows `action`.
+    pub(crate) fn offers(&self, action: ToastAction) -> bool {
+        self.items.iter().any(|toast| toast.action == Some(action))
+    }
+
+    /// Whether the pointer is on the toast showing `action`.
+    pub(crate) fn holds(&self, action: ToastAction) -> bool {
+        self.items
+            .iter()
+            .any(|toast| toast.action == Some(action) && toast.hovered_since.is_some())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct CaptureState {
     pub(crate) open: bool,
     pub(crate) text: String,
     pub(crate) preview: Option<CapturePreview>,
     pub(crate) preview_error: Option<String>,
+    /// The token `preview_error` is about, located in `text`.
+    pub(crate) token_error: Option<TokenError>,
     pub(crate) submitting: bool,
+    /// Offer to add a multi-line paste as one task per line.
+    pub(crate) bulk: Option<BulkCapture>,
+    /// Set while the arrow keys are stepping through recent captures.
+    pub(crate) recall: Option<HistoryRecall>,
+    pub(crate) recent_open: bool,
+    /// Files dropped on the window, linked from the new task's notes.
+    pub(crate) attachments: Vec<PathBuf>,
+}
+
+/// Which recent capture is in the field, and what was typed before recalling it.
+#[derive(Debug, Clone)]
+pub(crate) struct HistoryRecall {
+    pub(crate) index: usize,
+    pub(crate) draft: String,
 }
 
 impl CaptureState {
@@ -139,7 +464,12 @@ impl CaptureState {
             text: String::new(),
             preview: None,
             preview_error: None,
+            token_error: None,
             submitting: false,
+            bulk: None,
+            recall: None,
+            recent_open: false,
+            attachments: Vec::new(),
         }
     }
 
@@ -154,11 +484,18 @@ impl CaptureState {
         self.text.clear();
         self.preview = None;
         self.preview_error = None;
+        self.token_error = None;
         self.submitting = false;
+        self.bulk = None;
+        self.recall = None;
+        self.recent_open = false;
+        self.attachments.clear();
     }
 
     pub(crate) fn on_text_changed(&mut self, value: String) {
         self.text = value;
+        self.token_error = None;
+        self.recall = None;
         if self.text.trim().is_empty() {
             self.preview = None;
             self.preview_error = None;
@@ -172,25 +509,183 @@ impl CaptureState {
             }
             Err(err) => {
                 self.preview = None;
-                self.preview_error = Some(err);
+                self.token_error = err
+                    .downcast_ref::<TokenError>()
+                    .map(|token| locate_token_error(token.clone(), &self.text));
+                self.preview_error = Some(err.to_string());
             }
         }
     }
 
     pub(crate) fn input(&self) -> TaskInput {
+        let notes = (!self.attachments.is_empty()).then(|| {
+            let links: Vec<String> = self
+                .attachments
+                .iter()
+                .map(|path| format!("- {}", file_link(path)))
+                .collect();
+            format!("Attached:\n{}", links.join("\n"))
+        });
         TaskInput {
             text: self
                 .text
                 .split_whitespace()
                 .map(|piece| piece.to_string())
                 .collect(),
+            notes,
             ..TaskInput::default()
         }
     }
 }
 
+/// A paste of several lines into capture, each previewed as its own task. The input itself
+/// holds the paste run together on one line, which is what is kept if the offer is declined.
+#[derive(Debug, Clone)]
+pub(crate) struct BulkCapture {
+    pub(crate) lines: Vec<BulkLine>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BulkLine {
+    pub(crate) text: String,
+    pub(crate) preview: Result<CapturePreview, String>,
+}
+
+impl BulkCapture {
+    /// One entry per non-blank line of `pasted`, with list markers (`-`, `*`, `•`, `1.`,
+    /// `- [ ]`) dropped; `None` unless there are at least two.
+    pub(crate) fn from_paste(pasted: &str, i18n: &Catalog) -> Option<Self> {
+        let lines: Vec<BulkLine> = pasted
+            .lines()
+            .map(strip_list_marker)
+            .filter(|line| !line.is_empty())
+            .map(|line| BulkLine {
+                text: line.to_string(),
+                preview: capture_preview(line)
+                    .map_err(|err| err.to_string())
+                    .and_then(|preview| preview.ok_or_else(|| i18n.tr("capture-empty"))),
+            })
+            .collect();
+        (lines.len() > 1).then_some(Self { lines })
+    }
+
+    pub(crate) fn is_valid(&self) -> bool {
+        self.lines.iter().all(|line| line.preview.is_ok())
+    }
+
+    pub(crate) fn inputs(&self) -> Vec<TaskInput> {
+        self.lines
+            .iter()
+            .map(|line| TaskInput {
+                text: line
+                    .text
+                    .split_whitespace()
+                    .map(|piece| piece.to_string())
+                    .collect(),
+                ..TaskInput::default()
+            })
+            .collect()
+    }
+}
+
+fn strip_list_marker(line: &str) -> &str {
+    let line = line.trim();
+    let line = ["- [ ] ", "* [ ] ", "- ", "* ", "• "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+        .unwrap_or(line);
+    let numbered = line
+        .split_once(['.', ')'])
+        .filter(|(number, rest)| {
+            !number.is_empty()
+                && number.chars().all(|ch| ch.is_ascii_digit())
+                && rest.starts_with(' ')
+        })
+        .map(|(_, rest)| rest);
+    numbered.unwrap_or(line).trim()
+}
+
+/// The page opened from a Projects row: the project's open tasks grouped by status and a capture
+/// field that files new tasks under the project.
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectPage {
+    pub(crate) name: String,
+    pub(crate) tasks: Vec<Task>,
+    pub(crate) state: LoadState,
+    pub(crate) capture: CaptureState,
+}
+
+impl ProjectPage {
+    /// Status groups in the order the page lists them.
+    pub(crate) const GROUPS: [TaskStatus; 5] = [
+        TaskStatus::Next,
+        TaskStatus::Inbox,
+        TaskStatus::Waiting,
+        TaskStatus::Scheduled,
+        TaskStatus::Someday,
+    ];
+
+    pub(crate) fn new(name: String) -> Self {
+        let mut page = Self {
+            name,
+            tasks: Vec::new(),
+            state: LoadState::Loading,
+            capture: CaptureState::new(),
+        };
+        page.reset_capture();
+        page
+    }
+
+    /// Empties the capture field back to just the `+Project` token.
+    pub(crate) fn reset_capture(&mut self) {
+        self.capture.clear();
+        self.capture.on_text_changed(format!("+{} ", self.name));
+    }
+
+    pub(crate) fn group(&self, status: TaskStatus) -> Vec<&Task> {
+        self.tasks
+            .iter()
+            .filter(|task| task.status == status)
+            .collect()
+    }
+
+    /// A loaded project without a Next action has nothing moving it forward.
+    pub(crate) fn is_stalled(&self) -> bool {
+        matches!(self.state, LoadState::Idle)
+            && !self
+                .tasks
+                .iter()
+                .any(|task| task.status == TaskStatus::Next)
+    }
+}
+
+/// The small always-on-top window opened by the capture hotkey; it keeps its own draft so the
+/// main window's capture bar is left alone.
+#[derive(Debug, Clone)]
+pub(crate) struct QuickCapture {
+    pub(crate) window: window::Id,
+    pub(crate) capture: CaptureState,
+}
+
+/// A further window showing a view of its own, e.g. Next beside the main window's Inbox. All
+/// windows share the loaded views, filters, and changes; only which view shows is per window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ViewWindow {
+    pub(crate) window: window::Id,
+    pub(crate) tab: ViewTab,
+}
+
+impl ViewWindow {
+    /// Views a further window can show: the task lists, not the Projects summary or Stats.
+    pub(crate) fn offers(tab: ViewTab) -> bool {
+        !matches!(tab, ViewTab::Projects | ViewTab::Stats)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct CapturePreview {
+    /// The title left once the tokens are taken out.
+    pub(crate) title: String,
     pub(crate) chips: Vec<CaptureChip>,
 }
 
@@ -220,7 +715,42 @@ pub(crate) enum CommandActionId {
     MoveToInbox,
     DeferTomorrow,
     DeferNextWeek,
+    DeferPick,
+    MoveToProject,
+    CopyTask,
+    DeleteTask,
+    ToggleDetails,
+    ToggleFilters,
     Refresh,
+    Export,
+    Print,
+    Focus,
+    NewWindow,
+}
+
+impl CommandActionId {
+    /// Stem of the action's catalog entries (`action-<id>`, `action-<id>-description`).
+    pub(crate) fn message_id(self) -> &'static str {
+        match self {
+            Self::OpenCapture => "open-capture",
+            Self::PromoteNext => "promote-next",
+            Self::MarkDone => "mark-done",
+            Self::MoveToInbox => "move-to-inbox",
+            Self::DeferTomorrow => "defer-tomorrow",
+            Self::DeferNextWeek => "defer-next-week",
+            Self::DeferPick => "defer-pick",
+            Self::MoveToProject => "move-to-project",
+            Self::CopyTask => "copy-task",
+            Self::DeleteTask => "delete-task",
+            Self::ToggleDetails => "toggle-details",
+            Self::ToggleFilters => "toggle-filters",
+            Self::Refresh => "refresh",
+            Self::Export => "export",
+            Self::Print => "print",
+            Self::Focus => "focus",
+            Self::NewWindow => "new-window",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -232,14 +762,22 @@ pub(crate) struct CommandAction {
 }
 
 impl CommandAction {
-    pub(crate) fn matches(&self, query: &str) -> bool {
+    /// Matches the translated label and description as well as the English text and keywords,
+    /// so muscle memory keeps working after switching language.
+    pub(crate) fn matches(&self, query: &str, i18n: &Catalog) -> bool {
         if query.trim().is_empty() {
             return true;
         }
-        let needle = query.trim().to_ascii_lowercase();
-        let haystack = [self.label, self.description, &self.keywords.join(" ")]
-            .join(" ")
-            .to_ascii_lowercase();
+        let needle = query.trim().to_lowercase();
+        let haystack = format!(
+            "{} {} {} {} {}",
+            i18n.action_label(self.id),
+            i18n.action_description(self.id),
+            self.label,
+            self.description,
+            self.keywords.join(" ")
+        )
+        .to_lowercase();
         haystack.contains(&needle)
     }
 }
@@ -281,14 +819,397 @@ pub(crate) const COMMAND_ACTIONS: &[CommandAction] = &[
         description: "Snooze selected task for 7 days",
         keywords: &["defer", "week", "schedule"],
     },
+    CommandAction {
+        id: CommandActionId::DeferPick,
+        label: "Defer until…",
+        description: "Pick a preset or type a date for the selected tasks",
+        keywords: &["defer", "date", "schedule", "snooze"],
+    },
+    CommandAction {
+        id: CommandActionId::MoveToProject,
+        label: "Move to project",
+        description: "Pick a project for the selected task",
+        keywords: &["project", "move", "assign"],
+    },
+    CommandAction {
+        id: CommandActionId::CopyTask,
+        label: "Copy task",
+        description: "Copy the selected task as a capture line",
+        keywords: &["copy", "clipboard", "yank"],
+    },
+    CommandAction {
+        id: CommandActionId::DeleteTask,
+        label: "Delete task",
+        description: "Permanently delete the selected task",
+        keywords: &["delete", "remove", "trash"],
+    },
+    CommandAction {
+        id: CommandActionId::ToggleDetails,
+        label: "Toggle details",
+        description: "Show or hide the task detail panel",
+        keywords: &["details", "notes", "inspect", "panel"],
+    },
+    CommandAction {
+        id: CommandActionId::ToggleFilters,
+        label: "Toggle filters",
+        description: "Filter by project, context, tag, or priority",
+        keywords: &["filter", "project", "context", "tag", "priority"],
+    },
     CommandAction {
         id: CommandActionId::Refresh,
         label: "Refresh now",
         description: "Reload active view",
         keywords: &["refresh", "reload"],
     },
+    CommandAction {
+        id: CommandActionId::Export,
+        label: "Export…",
+        description: "Save this view or every task as JSON, CSV, Markdown, or iCalendar",
+        keywords: &["export", "save", "file", "json", "csv", "markdown", "ics"],
+    },
+    CommandAction {
+        id: CommandActionId::Print,
+        label: "Print…",
+        description: "Print this view or save it as a PDF",
+        keywords: &["print", "pdf", "paper"],
+    },
+    CommandAction {
+        id: CommandActionId::Focus,
+        label: "Focus on task",
+        description: "Show only the selected task, timing the work on it",
+        keywords: &["focus", "timer", "track", "zen"],
+    },
+    CommandAction {
+        id: CommandActionId::NewWindow,
+        label: "Open in new window",
+        description: "Show this view in another window, to keep two lists side by side",
+        keywords: &["window", "monitor", "side", "split"],
+    },
 ];
 
+/// The focus screen: one task on its own, with the time tracked on it.
+#[derive(Debug, Clone)]
+pub(crate) struct FocusSession {
+    pub(crate) task: Task,
+    /// This session's time entry while its timer runs.
+    pub(crate) timer: Option<TimeEntry>,
+    /// Seconds tracked on the task before the running timer started.
+    pub(crate) tracked: i64,
+}
+
+impl FocusSession {
+    pub(crate) fn new(task: Task) -> Self {
+        Self {
+            task,
+            timer: None,
+            tracked: 0,
+        }
+    }
+
+    /// Everything tracked on the task, the running timer included.
+    pub(crate) fn total_secs(&self, now: DateTime<Utc>) -> i64 {
+        self.tracked
+            + self
+                .timer
+                .as_ref()
+                .map_or(0, |entry| (now - entry.started_at).num_seconds())
+    }
+}
+
+/// Right-click menu anchored under a task row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ContextMenuState {
+    pub(crate) task_id: String,
+    pub(crate) defer_open: bool,
+}
+
+/// Tasks the delete dialog is asking about; nothing is removed until the user confirms.
+#[derive(Debug, Clone)]
+pub(crate) struct DeleteConfirmation {
+    pub(crate) ids: Vec<String>,
+    pub(crate) titles: Vec<String>,
+}
+
+/// Notes being edited in the detail panel; `original` is what was last loaded or saved.
+pub(crate) struct NotesDraft {
+    pub(crate) task_id: String,
+    pub(crate) original: String,
+    pub(crate) content: text_editor::Content,
+    /// Whether the notes are shown rendered rather than in the editor; empty notes open in the
+    /// editor.
+    pub(crate) preview: bool,
+}
+
+impl NotesDraft {
+    pub(crate) fn new(task_id: String, notes: &str) -> Self {
+        Self {
+            task_id,
+            original: notes.to_string(),
+            content: text_editor::Content::with_text(notes),
+            preview: !notes.trim().is_empty(),
+        }
+    }
+
+    /// A fresh draft of `notes` that stays in the same edit or preview mode.
+    pub(crate) fn reset(&self, notes: &str) -> Self {
+        Self {
+            preview: self.preview,
+            ..Self::new(self.task_id.clone(), notes)
+        }
+    }
+
+    pub(crate) fn text(&self) -> String {
+        self.content.text().trim_end().to_string()
+    }
+
+    pub(crate) fn is_dirty(&self) -> bool {
+        self.text() != self.original.trim_end()
+    }
+}
+
+/// Sidebar entry, board column, calendar day, or Next row a dragged task can be dropped onto.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum DropTarget {
+    View(ViewTab),
+    Project(String),
+    Day(NaiveDate),
+    /// Takes this row's place in the Next view's manual order.
+    Row(String),
+}
+
+/// Filters picked in the filter pane, applied to every view load.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct ActiveFilters {
+    pub(crate) project: Option<String>,
+    pub(crate) contexts: BTreeSet<String>,
+    pub(crate) tags: BTreeSet<String>,
+    pub(crate) priority_min: Option<u8>,
+}
+
+/// One active filter, as shown on a chip and toggled from the pane.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum FilterChip {
+    Project(String),
+    Context(String),
+    Tag(String),
+    Priority(u8),
+}
+
+impl FilterChip {
+    pub(crate) fn label(&self) -> String {
+        match self {
+            FilterChip::Project(project) => format!("+{project}"),
+            FilterChip::Context(context) => format!("@{context}"),
+            FilterChip::Tag(tag) => format!("#{tag}"),
+            FilterChip::Priority(priority) => format!("P{priority}+"),
+        }
+    }
+}
+
+impl ActiveFilters {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.project.is_none()
+            && self.contexts.is_empty()
+            && self.tags.is_empty()
+            && self.priority_min.is_none()
+    }
+
+    pub(crate) fn contains(&self, chip: &FilterChip) -> bool {
+        match chip {
+            FilterChip::Project(project) => self.project.as_ref() == Some(project),
+            FilterChip::Context(context) => self.contexts.contains(context),
+            FilterChip::Tag(tag) => self.tags.contains(tag),
+            FilterChip::Priority(priority) => self.priority_min == Some(*priority),
+        }
+    }
+
+    /// Adds `chip`, or removes it when already active. Project and priority hold a single value.
+    pub(crate) fn toggle(&mut self, chip: FilterChip) {
+        let active = self.contains(&chip);
+        match chip {
+            FilterChip::Project(project) => self.project = (!active).then_some(project),
+            FilterChip::Context(context) => {
+                if !self.contexts.remove(&context) {
+                    self.contexts.insert(context);
+                }
+            }
+            FilterChip::Tag(tag) => {
+                if !self.tags.remove(&tag) {
+                    self.tags.insert(tag);
+                }
+            }
+            FilterChip::Priority(priority) => self.priority_min = (!active).then_some(priority),
+        }
+    }
+
+    pub(crate) fn chips(&self) -> Vec<FilterChip> {
+        let mut chips: Vec<FilterChip> = self
+            .project
+            .clone()
+            .map(FilterChip::Project)
+            .into_iter()
+            .collect();
+        chips.extend(self.contexts.iter().cloned().map(FilterChip::Context));
+        chips.extend(self.tags.iter().cloned().map(FilterChip::Tag));
+        chips.extend(self.priority_min.map(FilterChip::Priority));
+        chips
+    }
+
+    pub(crate) fn from_saved(view: &SavedView) -> Self {
+        Self {
+            project: view.project.clone(),
+            contexts: view.contexts.iter().cloned().collect(),
+            tags: view.tags.iter().cloned().collect(),
+            priority_min: view.priority_min,
+        }
+    }
+
+    pub(crate) fn to_saved(&self, slot: u8, name: String) -> SavedView {
+        SavedView {
+            slot,
+            name,
+            project: self.project.clone(),
+            contexts: self.contexts.iter().cloned().collect(),
+            tags: self.tags.iter().cloned().collect(),
+            priority_min: self.priority_min,
+        }
+    }
+
+    /// Whether `task` passes these filters, as a view load would apply them.
+    pub(crate) fn matches(&self, task: &Task) -> bool {
+        self.project
+            .as_ref()
+            .is_none_or(|project| task.project.as_ref() == Some(project))
+            && self
+                .contexts
+                .iter()
+                .all(|context| task.contexts.contains(context))
+            && self.tags.iter().all(|tag| task.tags.contains(tag))
+            && self
+                .priority_min
+                .is_none_or(|priority| task.priority >= priority)
+    }
+
+    pub(crate) fn apply_to(&self, filters: &mut ListFilters) {
+        if let Some(project) = &self.project {
+            filters.project = Some(project.clone());
+        }
+        filters.contexts.extend(self.contexts.iter().cloned());
+        filters.tags.extend(self.tags.iter().cloned());
+        if self.priority_min.is_some() {
+            filters.priority_min = self.priority_min;
+        }
+    }
+}
+
+/// Saved views fill the numbered slots 1 to this, shared with the terminal UI's presets.
+pub(crate) const SAVED_VIEW_SLOTS: u8 = 9;
+
+/// The dialog naming a pinned view: a new one made from the filters applied now, or a pinned
+/// one being edited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PinDialog {
+    /// The saved view being edited; `None` saves the current filters into a free slot.
+    pub(crate) slot: Option<u8>,
+    pub(crate) name: String,
+    /// Saves the filters applied now instead of the view's own; always the case for a new one.
+    pub(crate) use_current: bool,
+}
+
+/// A pinned view being dragged by its handle to another place in the sidebar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PinDrag {
+    pub(crate) slot: u8,
+    /// The pinned view it will take the place of.
+    pub(crate) target: Option<u8>,
+}
+
+/// A header resize handle being dragged; `origin` is the cursor x of the first move.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ColumnResize {
+    pub(crate) label: &'static str,
+    pub(crate) start_width: f32,
+    pub(crate) origin: Option<f32>,
+}
+
+/// Areas of the main window that F6 (Shift+F6 backwards) moves the keyboard focus between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum FocusRegion {
+    Toolbar,
+    Sidebar,
+    Table,
+    Capture,
+}
+
+impl FocusRegion {
+    pub(crate) const ORDER: [FocusRegion; 4] = [
+        FocusRegion::Toolbar,
+        FocusRegion::Sidebar,
+        FocusRegion::Table,
+        FocusRegion::Capture,
+    ];
+
+    pub(crate) fn step(self, delta: i32) -> Self {
+        let len = Self::ORDER.len() as i32;
+        let index = Self::ORDER
+            .iter()
+            .position(|region| *region == self)
+            .unwrap_or(0) as i32;
+        Self::ORDER[(index + delta).rem_euclid(len) as usize]
+    }
+}
+
+/// Toolbar controls reachable with the arrow keys, left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ToolbarItem {
+    AddTask,
+    Search,
+    Details,
+    Filters,
+    Theme,
+    Export,
+    Settings,
+    CommandPalette,
+}
+
+impl ToolbarItem {
+    pub(crate) const ALL: [ToolbarItem; 8] = [
+        ToolbarItem::AddTask,
+        ToolbarItem::Search,
+        ToolbarItem::Details,
+        ToolbarItem::Filters,
+        ToolbarItem::Theme,
+        ToolbarItem::Export,
+        ToolbarItem::Settings,
+        ToolbarItem::CommandPalette,
+    ];
+}
+
+/// Where keyboard traversal currently is; the focus ring is only drawn while this is set, so
+/// mouse users never see it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct KeyboardFocus {
+    pub(crate) region: FocusRegion,
+    /// Highlighted entry within the toolbar or sidebar.
+    pub(crate) index: usize,
+}
+
+impl KeyboardFocus {
+    pub(crate) fn on_toolbar(&self, item: ToolbarItem) -> bool {
+        self.region == FocusRegion::Toolbar && ToolbarItem::ALL.get(self.index) == Some(&item)
+    }
+
+    pub(crate) fn on_view(&self, tab: ViewTab) -> bool {
+        self.region == FocusRegion::Sidebar && ViewTab::ALL.get(self.index) == Some(&tab)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct DragState {
+    pub(crate) task_id: String,
+    pub(crate) target: Option<DropTarget>,
+}
+
 #[derive(Clone, Copy)]
 pub(crate) struct SampleSeed {
     pub(crate) text: &'static str,
@@ -354,22 +1275,22 @@ impl CommandPaletteState {
         self.selected = 0;
     }
 
-    pub(crate) fn filtered(&self) -> Vec<&'static CommandAction> {
+    pub(crate) fn filtered(&self, i18n: &Catalog) -> Vec<&'static CommandAction> {
         COMMAND_ACTIONS
             .iter()
-            .filter(|action| action.matches(&self.query))
+            .filter(|action| action.matches(&self.query, i18n))
             .collect()
     }
 
-    pub(crate) fn clamp_selection(&mut self) {
-        let len = self.filtered().len().saturating_sub(1);
+    pub(crate) fn clamp_selection(&mut self, i18n: &Catalog) {
+        let len = self.filtered(i18n).len().saturating_sub(1);
         if self.selected > len {
             self.selected = len;
         }
     }
 
-    pub(crate) fn move_selection(&mut self, delta: i32) {
-        let list = self.filtered();
+    pub(crate) fn move_selection(&mut self, delta: i32, i18n: &Catalog) {
+        let list = self.filtered(i18n);
         if list.is_empty() {
             self.selected = 0;
             return;
@@ -385,8 +1306,8 @@ impl CommandPaletteState {
         self.selected = next as usize;
     }
 
-    pub(crate) fn selected_action(&self) -> Option<&'static CommandAction> {
-        self.filtered().get(self.selected).copied()
+    pub(crate) fn selected_action(&self, i18n: &Catalog) -> Option<&'static CommandAction> {
+        self.filtered(i18n).get(self.selected).copied()
     }
 }
 
@@ -395,12 +1316,65 @@ pub(crate) enum MutationKind {
     Promote(Vec<String>),
     Complete(Vec<String>),
     Inbox(Vec<String>),
-    Defer { id: String, until: DateTime<Utc> },
-    Rename { id: String, title: String },
-    ChangeProject { id: String, project: Option<String> },
-    ChangeContexts { id: String, contexts: Vec<String> },
-    ChangeTags { id: String, tags: Vec<String> },
-    ChangePriority { id: String, priority: u8 },
+    Delete(Vec<String>),
+    SetStatus {
+        ids: Vec<String>,
+        status: TaskStatus,
+    },
+    Defer {
+        ids: Vec<String>,
+        until: DateTime<Utc>,
+    },
+    Rename {
+        id: String,
+        title: String,
+    },
+    ChangeProject {
+        id: String,
+        project: Option<String>,
+    },
+    ChangeContexts {
+        id: String,
+        contexts: Vec<String>,
+    },
+    ChangeTags {
+        id: String,
+        tags: Vec<String>,
+    },
+    ChangePriority {
+        id: String,
+        priority: u8,
+    },
+    ChangeEnergy {
+        id: String,
+        energy: Option<EnergyLevel>,
+    },
+    /// Minutes; `None` clears the estimate.
+    ChangeEstimate {
+        id: String,
+        minutes: Option<u32>,
+    },
+    ChangeNotes {
+        id: String,
+        notes: String,
+    },
+    ChangeDue {
+        id: String,
+        due: Option<DateTime<Utc>>,
+    },
+    ChangeDefer {
+        id: String,
+        defer: Option<DateTime<Utc>>,
+    },
+    /// Writes earlier copies of tasks back, undoing a previous mutation.
+    Restore(Vec<Task>),
+    /// The Next view's task ids in their new hand-arranged order.
+    Reorder(Vec<String>),
+    /// A line added to the end of each task's notes.
+    AppendNote {
+        ids: Vec<String>,
+        line: String,
+    },
 }
 
 impl MutationKind {
@@ -409,12 +1383,147 @@ impl MutationKind {
             MutationKind::Promote(_) => "promote",
             MutationKind::Complete(_) => "complete",
             MutationKind::Inbox(_) => "move to inbox",
+            MutationKind::Delete(_) => "delete",
+            MutationKind::SetStatus { .. } => "move",
             MutationKind::Defer { .. } => "defer",
             MutationKind::Rename { .. } => "rename",
             MutationKind::ChangeProject { .. } => "update project",
             MutationKind::ChangeContexts { .. } => "update contexts",
             MutationKind::ChangeTags { .. } => "update tags",
             MutationKind::ChangePriority { .. } => "update priority",
+            MutationKind::ChangeEnergy { .. } => "update energy",
+            MutationKind::ChangeEstimate { .. } => "update estimate",
+            MutationKind::ChangeNotes { .. } => "update notes",
+            MutationKind::ChangeDue { .. } => "update due date",
+            MutationKind::ChangeDefer { .. } => "update defer date",
+            MutationKind::Restore(_) => "undo",
+            MutationKind::Reorder(_) => "reorder",
+            MutationKind::AppendNote { .. } => "nudge",
+        }
+    }
+
+    /// Ids of the tasks this mutation touches.
+    pub(crate) fn task_ids(&self) -> Vec<String> {
+        match self {
+            MutationKind::Promote(ids)
+            | MutationKind::Complete(ids)
+            | MutationKind::Inbox(ids)
+            | MutationKind::Delete(ids)
+            | MutationKind::Reorder(ids)
+            | MutationKind::SetStatus { ids, .. }
+            | MutationKind::Defer { ids, .. }
+            | MutationKind::AppendNote { ids, .. } => ids.clone(),
+            MutationKind::Rename { id, .. }
+            | MutationKind::ChangeProject { id, .. }
+            | MutationKind::ChangeContexts { id, .. }
+            | MutationKind::ChangeTags { id, .. }
+            | MutationKind::ChangePriority { id, .. }
+            | MutationKind::ChangeEnergy { id, .. }
+            | MutationKind::ChangeEstimate { id, .. }
+            | MutationKind::ChangeNotes { id, .. }
+            | MutationKind::ChangeDue { id, .. }
+            | MutationKind::ChangeDefer { id, .. } => vec![id.clone()],
+            MutationKind::Restore(tasks) => tasks.iter().map(|task| task.id.clone()).collect(),
         }
     }
 }
+
+/// A view's rows as they were before an optimistic update, kept until its mutation finishes so
+/// a failed write can be taken back off the screen.
+#[derive(Debug, Clone)]
+pub(crate) struct Rollback {
+    pub(crate) kind: MutationKind,
+    pub(crate) view: ViewTab,
+    pub(crate) tasks: Vec<Task>,
+}
+
+impl Rollback {
+    pub(crate) fn matches(&self, kind: &MutationKind) -> bool {
+        self.kind.label() == kind.label() && self.kind.task_ids() == kind.task_ids()
+    }
+
+    /// Puts the rows the mutation touched back in `current` where they were, leaving the rest
+    /// as they are now so other changes made meanwhile survive.
+    pub(crate) fn restore(&self, current: &mut Vec<Task>) {
+        let ids = self.kind.task_ids();
+        current.retain(|task| !ids.contains(&task.id));
+        for (index, task) in self.tasks.iter().enumerate() {
+            if ids.contains(&task.id) {
+                current.insert(index.min(current.len()), task.clone());
+            }
+        }
+    }
+}
+
+/// Copies of tasks taken before a mutation so it can be reversed from its toast.
+#[derive(Debug, Clone)]
+pub(crate) struct UndoEntry {
+    pub(crate) label: &'static str,
+    pub(crate) tasks: Vec<Task>,
+    pub(crate) created_at: Instant,
+}
+
+impl UndoEntry {
+    pub(crate) fn matches(&self, kind: &MutationKind) -> bool {
+        let ids: Vec<&str> = self.tasks.iter().map(|task| task.id.as_str()).collect();
+        self.label == kind.label() && kind.task_ids().iter().map(String::as_str).eq(ids)
+    }
+}
+
+/// Quick picks in the Defer dialog, as date specs capture understands.
+pub(crate) const DEFER_PRESETS: [(&str, &str); 5] = [
+    ("Tomorrow", "tomorrow"),
+    ("This weekend", "sat"),
+    ("Next Monday", "mon"),
+    ("In two weeks", "+2w"),
+    ("Next month", "+1m"),
+];
+
+/// Tasks waiting in the Defer dialog and the date typed for them.
+#[derive(Debug, Clone)]
+pub(crate) struct DeferDialog {
+    pub(crate) ids: Vec<String>,
+    pub(crate) date: String,
+    pub(crate) error: Option<String>,
+}
+
+/// What Export writes: the rows on screen or every task in the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExportScope {
+    View,
+    Everything,
+}
+
+impl ExportScope {
+    pub(crate) const ALL: [ExportScope; 2] = [ExportScope::View, ExportScope::Everything];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            ExportScope::View => "Current view",
+            ExportScope::Everything => "Whole database",
+        }
+    }
+}
+
+/// Choices in the Export dialog before the save dialog asks where to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ExportDialog {
+    pub(crate) format: ExportFormat,
+    pub(crate) scope: ExportScope,
+}
+
+/// The file the last export wrote, offered from its toast until that goes away.
+#[derive(Debug, Clone)]
+pub(crate) struct ExportedFile {
+    pub(crate) path: PathBuf,
+}
+
+/// Label for an export format in the dialog and the save dialog's file filter.
+pub(crate) fn export_format_name(format: ExportFormat) -> &'static str {
+    match format {
+        ExportFormat::Json => "JSON",
+        ExportFormat::Csv => "CSV",
+        ExportFormat::Markdown => "Markdown",
+        ExportFormat::Ics => "iCalendar",
+    }
+}
@@ -2,18 +2,53 @@
 
 #[cfg(test)]
 mod tests {
-    use iced::keyboard::key::{NativeCode, Physical};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, Instant};
+
+    use chrono::{Datelike, Duration as ChronoDuration, Local, Months, Utc};
+    use iced::keyboard::key::{Named, NativeCode, Physical};
     use iced::keyboard::{Event as KeyboardEvent, Key, Location, Modifiers};
+    use iced::widget::text_editor;
+    use iced::{window, Color, Point, Size, Theme};
     use tempfile::TempDir;
 
-    use cpt_core::model::ListFilters;
+    use cpt_core::capture::TaskInput;
+    use cpt_core::model::{EnergyLevel, ListFilters, SortField, TaskStatus};
+    use cpt_core::services::ExportFormat;
     use cpt_core::{AppConfig, TasksService};
 
+    use crate::app::calendar::{placement, CalendarSpan};
+    use crate::app::capture_history::{CaptureHistory, HISTORY_FILE, HISTORY_LIMIT};
+    use crate::app::columns::{ColumnLayout, ColumnLayouts, LAYOUT_FILE};
+    use crate::app::commands::{count_view, write_export};
+    use crate::app::deep_link::{forward, DeepLink, LinkListener, LINK_PORT_FILE};
     use crate::app::desktop::CptDesktop;
+    use crate::app::helpers::{
+        apply_token_fix, dropped_file_title, file_link, format_elapsed, waiting_group,
+    };
+    use crate::app::i18n::{parse_catalog, Catalog};
     use crate::app::message::Message;
+    use crate::app::notes::{parse_notes, toggle_checkbox, Inline, NoteBlock};
     use crate::app::options::{DesktopFlags, DesktopOptions};
+    use crate::app::print::{PrintSection, PrintSheet};
+    use crate::app::reminders::{ReminderKind, ReminderTracker};
     use crate::app::seeding::maybe_seed_sample_data;
-    use crate::app::state::ViewTab;
+    use crate::app::shortcuts::{KeyBinding, ShortcutAction, ShortcutMap, SHORTCUTS_FILE};
+    use crate::app::state::{
+        ActiveFilters, CaptureChipKind, ColumnSort, CommandActionId, DropTarget, ExportScope,
+        FilterChip, FocusRegion, InlineEditableField, ListViewport, LoadState, MutationKind,
+        ProjectPage, StatusToast, ToastAction, ToastKind, Toasts, ToolbarItem, ViewTab,
+        COMMAND_ACTIONS, STATS_WEEKS,
+    };
+    use crate::app::theme::{ColorThemes, Palette, DEFAULT_COLOR_THEME, USER_THEMES_DIR};
+    use crate::app::tray::TrayBadge;
+    use crate::app::views::{column_labels, heading_count};
+    use crate::app::window_state::{
+        ThemeChoice, ViewMode, WindowChange, WindowStateStore, MAX_ZOOM, MIN_ZOOM,
+        WINDOW_STATE_FILE,
+    };
+    use crate::telemetry::{Sink as TelemetrySink, TELEMETRY_FILE};
 
     fn init_app() -> (CptDesktop, TasksService, TempDir) {
         let temp_dir = TempDir::new().expect("temp dir");
@@ -27,6 +62,8 @@ mod tests {
         });
 
         let (mut app, _) = CptDesktop::new(flags);
+        // Assertions read English text whatever the machine's language.
+        let _ = app.react(Message::LocalePicked(Some("en".into())));
         let snapshot = service
             .list(&ListFilters::for_view(ViewTab::Inbox.list_view()))
             .unwrap();
@@ -34,6 +71,20 @@ mod tests {
         (app, service, temp_dir)
     }
 
+    /// The samples hold a single Inbox task; captures another and reloads the Inbox.
+    fn add_inbox_task(app: &mut CptDesktop, service: &TasksService, text: &str) {
+        service
+            .capture(TaskInput {
+                text: vec![text.into()],
+                ..TaskInput::default()
+            })
+            .unwrap();
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Inbox.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Inbox, Ok(snapshot)));
+    }
+
     #[test]
     fn ctrl_k_opens_command_palette() {
         let (mut app, _service, _guard) = init_app();
@@ -91,4 +142,2284 @@ mod tests {
         let _ = app.react(Message::Keyboard(event));
         assert!(app.pending_mutations >= 1);
     }
+
+    #[test]
+    fn tab_cycles_into_someday_and_done_and_done_pages() {
+        let (mut app, service, _guard) = init_app();
+        let tab = KeyboardEvent::KeyPressed {
+            key: Key::Named(Named::Tab),
+            modified_key: Key::Named(Named::Tab),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers: Modifiers::default(),
+            text: None,
+        };
+
+        app.active = ViewTab::Waiting;
+        let _ = app.react(Message::Keyboard(tab.clone()));
+        assert_eq!(app.active, ViewTab::Someday);
+        let _ = app.react(Message::Keyboard(tab.clone()));
+        let _ = app.react(Message::Keyboard(tab));
+        assert_eq!(app.active, ViewTab::Done);
+
+        let ids: Vec<String> = service
+            .list(&ListFilters::for_view(None))
+            .unwrap()
+            .tasks
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+        service.mark_done(&ids).unwrap();
+        let mut filters = ListFilters::for_view(ViewTab::Done.list_view());
+        filters.limit = Some(1);
+        let snapshot = service.list(&filters).unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Done, Ok(snapshot)));
+        assert!(app.views[&ViewTab::Done].has_more);
+
+        let _ = app.react(Message::LoadMoreRequested);
+        assert_eq!(app.views[&ViewTab::Done].pages, 2);
+        // The next page starts after the loaded rows and is appended to them.
+        filters.offset = 1;
+        let page = service.list(&filters).unwrap();
+        let second = page.tasks[0].id.clone();
+        let _ = app.react(Message::PageLoaded(ViewTab::Done, Ok(page)));
+        let done = &app.views[&ViewTab::Done];
+        let tasks = &done.snapshot.as_ref().unwrap().tasks;
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[1].id, second);
+        assert!(done.has_more);
+        // A page for rows since replaced by a reload is ignored.
+        let stale = service.list(&filters).unwrap();
+        let _ = app.react(Message::PageLoaded(ViewTab::Done, Ok(stale)));
+        assert_eq!(
+            app.views[&ViewTab::Done]
+                .snapshot
+                .as_ref()
+                .unwrap()
+                .tasks
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn context_menu_runs_palette_actions_on_the_row() {
+        let (mut app, _service, _guard) = init_app();
+        let ids: Vec<String> = app
+            .current_tasks()
+            .iter()
+            .map(|task| task.id.clone())
+            .collect();
+        let target = ids.last().expect("sample tasks available").clone();
+
+        let _ = app.react(Message::ContextMenuOpened(target.clone()));
+        assert_eq!(app.selected_task.as_deref(), Some(target.as_str()));
+        let _ = app.react(Message::ContextMenuDeferToggled);
+        assert!(app
+            .context_menu
+            .as_ref()
+            .is_some_and(|menu| menu.defer_open));
+        // Clicking beside the menu dismisses it.
+        let _ = app.react(Message::ContextMenuClosed);
+        assert!(app.context_menu.is_none());
+
+        let _ = app.react(Message::ContextMenuOpened(target.clone()));
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::DeleteTask));
+        assert!(app.context_menu.is_none());
+        assert_eq!(app.pending_mutations, 0);
+        let _ = app.react(Message::DeleteConfirmed);
+        assert_eq!(app.pending_mutations, 1);
+        assert!(app.current_tasks().iter().all(|task| task.id != target));
+    }
+
+    #[test]
+    fn dropping_a_row_on_the_sidebar_moves_it() {
+        let (mut app, service, _guard) = init_app();
+        let first = app
+            .current_tasks()
+            .first()
+            .expect("sample tasks available")
+            .id
+            .clone();
+
+        let _ = app.react(Message::DragStarted(first.clone()));
+        let next = DropTarget::View(ViewTab::Next);
+        let _ = app.react(Message::DragTargetEntered(DropTarget::View(
+            ViewTab::Waiting,
+        )));
+        let _ = app.react(Message::DragTargetEntered(next.clone()));
+        let _ = app.react(Message::DragTargetLeft(DropTarget::View(ViewTab::Waiting)));
+        assert_eq!(app.drag.as_ref().unwrap().target, Some(next));
+
+        let _ = app.react(Message::DragReleased);
+        assert!(app.drag.is_none());
+        assert_eq!(app.pending_mutations, 1);
+        assert!(app.current_tasks().iter().all(|task| task.id != first));
+
+        let snapshot = service.list(&ListFilters::for_view(None)).unwrap();
+        let _ = app.react(Message::ViewRequested(ViewTab::All));
+        let _ = app.react(Message::ViewLoaded(ViewTab::All, Ok(snapshot)));
+        let second = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.project.as_deref() != Some("Ops"))
+            .expect("a task outside +Ops")
+            .id
+            .clone();
+
+        // Releasing outside any target leaves the task alone.
+        let _ = app.react(Message::DragStarted(second.clone()));
+        let _ = app.react(Message::DragReleased);
+        assert_eq!(app.pending_mutations, 1);
+
+        let _ = app.react(Message::DragStarted(second.clone()));
+        let _ = app.react(Message::DragTargetEntered(DropTarget::Project(
+            "Ops".into(),
+        )));
+        let _ = app.react(Message::DragReleased);
+        assert_eq!(app.pending_mutations, 2);
+        let moved = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == second)
+            .expect("reassigned task stays listed");
+        assert_eq!(moved.project.as_deref(), Some("Ops"));
+    }
+
+    #[test]
+    fn detail_panel_edits_notes_and_saves_on_selection_change() {
+        let (mut app, _service, _guard) = init_app();
+        let first = app
+            .current_tasks()
+            .first()
+            .expect("sample tasks available")
+            .id
+            .clone();
+        let _ = app.react(Message::RowSelected(first.clone()));
+        assert!(app.notes_draft.is_none());
+
+        let _ = app.react(Message::DetailToggled);
+        let draft = app.notes_draft.as_mut().expect("draft for selected task");
+        assert_eq!(draft.task_id, first);
+        assert!(!draft.is_dirty());
+        draft.content = text_editor::Content::with_text("Call back after lunch");
+
+        let _ = app.react(Message::NotesSaved);
+        assert_eq!(app.pending_mutations, 1);
+        let notes = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == first)
+            .and_then(|task| task.notes.clone());
+        assert_eq!(notes.as_deref(), Some("Call back after lunch"));
+        assert!(!app.notes_draft.as_ref().unwrap().is_dirty());
+
+        // Closing the panel saves edits that were not saved yet.
+        app.notes_draft.as_mut().unwrap().content = text_editor::Content::with_text("Draft");
+        let _ = app.react(Message::DetailToggled);
+        assert!(app.notes_draft.is_none());
+        assert_eq!(app.pending_mutations, 2);
+    }
+
+    #[test]
+    fn notes_render_markdown_and_toggle_checklists_from_the_preview() {
+        let notes = "# Trip\n- [ ] Book **flights** via https://example.com.\n  - [x] Renew passport\n1. Pack";
+        let blocks = parse_notes(notes);
+        assert!(matches!(blocks[0], NoteBlock::Heading { level: 1, .. }));
+        match &blocks[1] {
+            NoteBlock::Checkbox {
+                line,
+                checked,
+                spans,
+                ..
+            } => {
+                assert_eq!((*line, *checked), (1, false));
+                assert!(spans.contains(&Inline::Strong("flights".into())));
+                assert!(spans.contains(&Inline::Link {
+                    label: "https://example.com".into(),
+                    url: "https://example.com".into(),
+                }));
+            }
+            other => panic!("expected a checklist item, got {other:?}"),
+        }
+        assert!(matches!(
+            blocks[2],
+            NoteBlock::Checkbox {
+                depth: 1,
+                checked: true,
+                ..
+            }
+        ));
+        assert!(matches!(blocks[3], NoteBlock::Numbered { .. }));
+        assert_eq!(
+            toggle_checkbox(notes, 2).unwrap().lines().nth(2),
+            Some("  - [ ] Renew passport")
+        );
+        assert!(toggle_checkbox(notes, 0).is_none());
+
+        let (mut app, _service, _guard) = init_app();
+        let first = app.current_tasks().first().unwrap().id.clone();
+        let _ = app.react(Message::RowSelected(first.clone()));
+        let _ = app.react(Message::DetailToggled);
+        let draft = app.notes_draft.as_mut().unwrap();
+        draft.content = text_editor::Content::with_text(notes);
+        let _ = app.react(Message::NotesSaved);
+        // A task with notes opens on the rendered preview; the toggle switches to the editor.
+        assert!(app.notes_draft.as_ref().unwrap().preview);
+        let _ = app.react(Message::NotesPreviewToggled);
+        assert!(!app.notes_draft.as_ref().unwrap().preview);
+        let _ = app.react(Message::NotesPreviewToggled);
+        assert!(app.notes_draft.as_ref().unwrap().preview);
+
+        let _ = app.react(Message::NotesCheckboxToggled(1));
+        let draft = app.notes_draft.as_ref().unwrap();
+        assert!(draft.preview, "toggling a box keeps the preview open");
+        assert!(!draft.is_dirty());
+        let saved = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == first)
+            .and_then(|task| task.notes.clone())
+            .unwrap();
+        assert!(saved.contains("- [x] Book **flights**"));
+    }
+
+    #[test]
+    fn date_picker_sets_and_validates_due_and_defer() {
+        let (mut app, _service, _guard) = init_app();
+        let first = app
+            .current_tasks()
+            .first()
+            .expect("sample tasks available")
+            .id
+            .clone();
+
+        let _ = app.react(Message::TaskDuePressed(first.clone()));
+        let edit = app.inline_edit.as_ref().expect("due editor open");
+        assert_eq!(edit.field, InlineEditableField::Due);
+        let month = edit.month.expect("picker month");
+        assert_eq!(month.day(), 1);
+
+        let _ = app.react(Message::DatePickerMonthChanged(1));
+        let next_month = app
+            .inline_edit
+            .as_ref()
+            .and_then(|edit| edit.month)
+            .unwrap();
+        assert_eq!(
+            next_month,
+            month.checked_add_months(Months::new(1)).unwrap()
+        );
+
+        let picked = next_month.with_day(15).unwrap();
+        let _ = app.react(Message::DatePicked(picked));
+        assert!(app.inline_edit.is_none());
+        assert_eq!(app.pending_mutations, 1);
+        let due = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == first)
+            .and_then(|task| task.due_at)
+            .expect("due date set");
+        assert_eq!(due.with_timezone(&Local).date_naive(), picked);
+
+        // The clear button drops the date whatever has been typed.
+        let _ = app.react(Message::TaskDuePressed(first.clone()));
+        let _ = app.react(Message::InlineEditChanged("+3d".into()));
+        let _ = app.react(Message::DateCleared);
+        assert!(app.inline_edit.is_none());
+        assert_eq!(app.pending_mutations, 2);
+        let task = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == first);
+        assert!(task.unwrap().due_at.is_none());
+
+        let _ = app.react(Message::TaskDeferPressed(first.clone()));
+        let _ = app.react(Message::InlineEditChanged("someday soon".into()));
+        let _ = app.react(Message::InlineEditSubmitted);
+        let error = app.inline_edit.as_ref().and_then(|edit| edit.error.clone());
+        assert!(error.unwrap().starts_with("Invalid date"));
+        assert_eq!(app.pending_mutations, 2);
+
+        let _ = app.react(Message::InlineEditChanged("tomorrow".into()));
+        assert!(app.inline_edit.as_ref().unwrap().error.is_none());
+        let _ = app.react(Message::InlineEditSubmitted);
+        assert!(app.inline_edit.is_none());
+        assert_eq!(app.pending_mutations, 3);
+        // Deferred tasks leave the Inbox until their date comes around.
+        assert!(app.current_tasks().iter().all(|task| task.id != first));
+    }
+
+    #[test]
+    fn defer_dialog_applies_presets_and_typed_dates() {
+        let (mut app, service, _guard) = init_app();
+        add_inbox_task(&mut app, &service, "Book dentist");
+        let first = app.current_tasks()[0].id.clone();
+        let second = app.current_tasks()[1].id.clone();
+
+        let _ = app.react(Message::RowSelected(first.clone()));
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::DeferPick));
+        assert_eq!(app.defer_dialog.as_ref().unwrap().ids, vec![first.clone()]);
+
+        let _ = app.react(Message::DeferDateChanged("someday".into()));
+        let _ = app.react(Message::DeferConfirmed);
+        let error = app
+            .defer_dialog
+            .as_ref()
+            .and_then(|dialog| dialog.error.clone());
+        assert!(error.unwrap().starts_with("Invalid date"));
+        assert_eq!(app.pending_mutations, 0);
+
+        let _ = app.react(Message::DeferDateChanged("+3d".into()));
+        assert!(app.defer_dialog.as_ref().unwrap().error.is_none());
+        let _ = app.react(Message::DeferConfirmed);
+        assert!(app.defer_dialog.is_none());
+        assert_eq!(app.pending_mutations, 1);
+        assert!(app.current_tasks().iter().all(|task| task.id != first));
+
+        let _ = app.react(Message::RowSelected(second.clone()));
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::DeferPick));
+        let _ = app.react(Message::DeferPresetPicked("tomorrow"));
+        assert!(app.defer_dialog.is_none());
+        assert_eq!(app.pending_mutations, 2);
+        assert!(app.current_tasks().iter().all(|task| task.id != second));
+
+        // The batch write behind the dialog.
+        let until = Utc::now() + ChronoDuration::days(3);
+        let deferred = service.defer_tasks(&[first, second], until).unwrap();
+        assert!(deferred
+            .iter()
+            .all(|task| task.status == TaskStatus::Scheduled));
+    }
+
+    #[test]
+    fn energy_and_estimate_edit_inline_with_validation() {
+        let (mut app, service, _guard) = init_app();
+        let first = app.current_tasks().first().unwrap().id.clone();
+
+        let _ = app.react(Message::TaskEnergyPressed(first.clone()));
+        let edit = app.inline_edit.as_ref().expect("energy editor open");
+        assert_eq!(edit.field, InlineEditableField::Energy);
+        assert_eq!(edit.options, vec!["(none)", "low", "med", "high"]);
+        let _ = app.react(Message::InlineEditOptionSelected("high".into()));
+        assert!(app.inline_edit.is_none());
+        let energy = |app: &CptDesktop| {
+            app.current_tasks()
+                .into_iter()
+                .find(|task| task.id == first)
+                .and_then(|task| task.energy)
+        };
+        assert_eq!(energy(&app), Some(EnergyLevel::High));
+
+        let _ = app.react(Message::TaskEstimatePressed(first.clone()));
+        let _ = app.react(Message::InlineEditChanged("a while".into()));
+        let _ = app.react(Message::InlineEditSubmitted);
+        assert!(
+            app.inline_edit.is_some(),
+            "invalid estimates keep the editor open"
+        );
+        assert!(!app.toasts.is_empty());
+
+        let _ = app.react(Message::InlineEditChanged("1h30m".into()));
+        let _ = app.react(Message::InlineEditSubmitted);
+        assert!(app.inline_edit.is_none());
+        assert_eq!(app.pending_mutations, 2);
+        let estimate = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == first)
+            .and_then(|task| task.time_estimate);
+        assert_eq!(estimate, Some(90));
+
+        // The service writes the same change the table shows.
+        service.update_time_estimate(&first, Some(90)).unwrap();
+        let _ = app.react(Message::TaskEstimatePressed(first.clone()));
+        assert_eq!(app.inline_edit.as_ref().unwrap().value, "1h30m");
+        let _ = app.react(Message::InlineEditChanged(String::new()));
+        let _ = app.react(Message::InlineEditSubmitted);
+        let _ = app.react(Message::TaskEnergyPressed(first.clone()));
+        let _ = app.react(Message::InlineEditOptionSelected("(none)".into()));
+        let task = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == first)
+            .cloned()
+            .unwrap();
+        assert_eq!((task.energy, task.time_estimate), (None, None));
+    }
+
+    #[test]
+    fn search_filters_the_active_view_and_escape_clears_it() {
+        let (mut app, service, _guard) = init_app();
+        let snapshot = service.list(&ListFilters::for_view(None)).unwrap();
+        let _ = app.react(Message::ViewRequested(ViewTab::All));
+        let _ = app.react(Message::ViewLoaded(ViewTab::All, Ok(snapshot)));
+        let total = app.current_tasks().len();
+        assert!(total > 1);
+        let target = app.current_tasks()[1].clone();
+        let word = target
+            .title
+            .split_whitespace()
+            .max_by_key(|word| word.len())
+            .unwrap()
+            .to_uppercase();
+
+        let _ = app.react(Message::SearchChanged(word.clone()));
+        // All is paged, so the search goes to the database and counts rows not loaded yet.
+        assert_eq!(app.search_counts(), None);
+        let filters = app.view_filters(ViewTab::All);
+        assert_eq!(filters.text, Some(word));
+        let _ = app.react(Message::ViewLoaded(
+            ViewTab::All,
+            Ok(service.list(&filters).unwrap()),
+        ));
+        let _ = app.react(Message::ViewCounted(
+            ViewTab::All,
+            Ok(count_view(&service, &filters).unwrap()),
+        ));
+        let matches = app.current_tasks();
+        assert!(matches.iter().any(|task| task.id == target.id));
+        assert!(matches.len() < total);
+        assert_eq!(app.search_counts(), Some((matches.len(), total)));
+        assert_eq!(app.view_row_count(), matches.len());
+        assert!(matches
+            .iter()
+            .any(|task| Some(&task.id) == app.selected_task.as_ref()));
+
+        let _ = app.react(Message::SearchChanged("zzz-no-such-task".into()));
+        assert!(app.current_tasks().is_empty());
+        assert!(app.selected_task.is_none());
+
+        let key = Key::Named(Named::Escape);
+        let _ = app.react(Message::Keyboard(KeyboardEvent::KeyPressed {
+            key: key.clone(),
+            modified_key: key,
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers: Modifiers::empty(),
+            text: None,
+        }));
+        assert!(app.search.is_empty());
+        assert_eq!(app.search_counts(), None);
+        // Rows loaded for an earlier search are dropped rather than shown under the new one.
+        let version = app.views[&ViewTab::All].version;
+        let _ = app.react(Message::ViewLoaded(
+            ViewTab::All,
+            Ok(service.list(&filters).unwrap()),
+        ));
+        assert_eq!(app.views[&ViewTab::All].version, version);
+        let filters = app.view_filters(ViewTab::All);
+        let _ = app.react(Message::ViewLoaded(
+            ViewTab::All,
+            Ok(service.list(&filters).unwrap()),
+        ));
+        assert_eq!(app.current_tasks().len(), total);
+    }
+
+    #[test]
+    fn filter_panel_toggles_facets_into_list_filters() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::FilterPanelToggled);
+        assert!(app.filter_panel_open);
+        let _ = app.react(Message::FacetsLoaded(Ok(service.facets().unwrap())));
+        let facets = app.facets.clone().expect("facets loaded");
+        let project = facets
+            .projects
+            .first()
+            .expect("seeded project")
+            .name
+            .clone();
+        let context = facets
+            .contexts
+            .first()
+            .expect("seeded context")
+            .name
+            .clone();
+
+        let _ = app.react(Message::FilterToggled(FilterChip::Project(project.clone())));
+        let _ = app.react(Message::FilterToggled(FilterChip::Context(context.clone())));
+        let _ = app.react(Message::FilterToggled(FilterChip::Priority(2)));
+        assert!(matches!(
+            app.views.get(&ViewTab::Inbox).map(|view| &view.state),
+            Some(LoadState::Loading)
+        ));
+        assert_eq!(
+            app.filters.chips(),
+            vec![
+                FilterChip::Project(project.clone()),
+                FilterChip::Context(context.clone()),
+                FilterChip::Priority(2),
+            ]
+        );
+
+        let mut filters = ListFilters::for_view(None);
+        app.filters.apply_to(&mut filters);
+        assert_eq!(filters.project, Some(project));
+        assert_eq!(filters.contexts, vec![context.clone()]);
+        assert_eq!(filters.priority_min, Some(2));
+
+        // Removing a chip toggles it off again.
+        let _ = app.react(Message::FilterToggled(FilterChip::Context(context)));
+        assert_eq!(app.filters.chips().len(), 2);
+        let _ = app.react(Message::FiltersCleared);
+        assert!(app.filters.is_empty());
+    }
+
+    #[test]
+    fn header_clicks_cycle_column_sort_per_view() {
+        let (mut app, _service, _guard) = init_app();
+        let sort = |app: &CptDesktop| app.views.get(&ViewTab::Inbox).and_then(|view| view.sort);
+
+        let _ = app.react(Message::ColumnSortPressed(SortField::Priority));
+        assert_eq!(
+            sort(&app),
+            Some(ColumnSort {
+                field: SortField::Priority,
+                descending: false,
+            })
+        );
+        // Ascending priority means lowest first, which core spells as a reversed priority sort.
+        let mut filters = ListFilters::for_view(None);
+        sort(&app).unwrap().apply_to(&mut filters);
+        assert_eq!(filters.sort, SortField::Priority);
+        assert!(filters.reverse);
+
+        let _ = app.react(Message::ColumnSortPressed(SortField::Priority));
+        assert!(sort(&app).unwrap().descending);
+        let _ = app.react(Message::ColumnSortPressed(SortField::Priority));
+        assert_eq!(sort(&app), None);
+
+        let _ = app.react(Message::ColumnSortPressed(SortField::Title));
+        let _ = app.react(Message::ViewRequested(ViewTab::Next));
+        assert_eq!(
+            app.views.get(&ViewTab::Next).and_then(|view| view.sort),
+            None
+        );
+        assert_eq!(sort(&app).map(|sort| sort.field), Some(SortField::Title));
+        assert_eq!(ColumnSort::field_for_column("Tags"), None);
+    }
+
+    #[test]
+    fn column_layout_changes_persist_per_view() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::ColumnMenuToggled);
+        assert!(app.column_menu_open);
+
+        let _ = app.react(Message::ColumnMoved("Due", -1));
+        let _ = app.react(Message::ColumnVisibilityToggled("Tags"));
+        let _ = app.react(Message::ColumnVisibilityToggled("Title"));
+        let _ = app.react(Message::ColumnResizeStarted("Project", 120.0));
+        let _ = app.react(Message::ColumnResizeMoved(400.0));
+        let _ = app.react(Message::ColumnResizeMoved(460.0));
+        let _ = app.react(Message::DragReleased);
+        assert!(app.column_resize.is_none());
+
+        let path = service.config().data_dir().join(LAYOUT_FILE);
+        let reloaded = ColumnLayouts::load(path).unwrap();
+        let inbox = reloaded.get(ViewTab::Inbox);
+        assert_eq!(
+            inbox.visible_labels(&column_labels(ViewTab::Inbox)),
+            vec!["Title", "Project", "Contexts", "Due", "Priority", "Created"]
+        );
+        assert_eq!(inbox.width("Project"), Some(180.0));
+        assert_eq!(reloaded.get(ViewTab::Next), ColumnLayout::default());
+
+        let _ = app.react(Message::ColumnsReset);
+        let reloaded = ColumnLayouts::load(service.config().data_dir().join(LAYOUT_FILE)).unwrap();
+        assert_eq!(reloaded.get(ViewTab::Inbox), ColumnLayout::default());
+    }
+
+    #[test]
+    fn finished_mutations_offer_an_undo_that_restores_tasks() {
+        let (mut app, service, _guard) = init_app();
+        let first = app
+            .current_tasks()
+            .first()
+            .expect("sample tasks available")
+            .id
+            .clone();
+        let ids = vec![first.clone()];
+        let _ = app.react(Message::RowSelected(first.clone()));
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::MarkDone));
+        assert!(app.current_tasks().iter().all(|task| task.id != first));
+        assert!(app.undo.is_none());
+
+        service.mark_done(&ids).unwrap();
+        let _ = app.react(Message::MutationFinished(
+            MutationKind::Complete(ids.clone()),
+            Ok(()),
+        ));
+        let undo = app.undo.clone().expect("undo offered");
+        assert_eq!(undo.tasks.len(), 1);
+        assert_eq!(undo.tasks[0].status, TaskStatus::Inbox);
+
+        let _ = app.react(Message::UndoRequested);
+        assert!(app.undo.is_none());
+        assert_eq!(app.pending_mutations, 1);
+        for task in &undo.tasks {
+            service.restore_task(task).unwrap();
+        }
+        let restored = service.fetch_task(&first).unwrap().unwrap();
+        assert_eq!(restored.status, TaskStatus::Inbox);
+        assert!(restored.completed_at.is_none());
+
+        // Undoing an undo, or a failed mutation, offers nothing further.
+        let _ = app.react(Message::MutationFinished(
+            MutationKind::Restore(undo.tasks.clone()),
+            Ok(()),
+        ));
+        assert!(app.undo.is_none());
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Inbox.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Inbox, Ok(snapshot)));
+        let _ = app.react(Message::RowSelected(first.clone()));
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::MarkDone));
+        assert_eq!(app.undo_pending.len(), 1);
+        let _ = app.react(Message::MutationFinished(
+            MutationKind::Complete(ids),
+            Err("database locked".into()),
+        ));
+        assert!(app.undo.is_none());
+        assert!(app.undo_pending.is_empty());
+    }
+
+    #[test]
+    fn delete_key_asks_for_confirmation_first() {
+        let (mut app, _service, _guard) = init_app();
+        let task = app
+            .current_tasks()
+            .first()
+            .map(|task| (task.id.clone(), task.title.clone()))
+            .expect("sample tasks available");
+        let _ = app.react(Message::RowSelected(task.0.clone()));
+
+        let press = |named: Named| KeyboardEvent::KeyPressed {
+            key: Key::Named(named),
+            modified_key: Key::Named(named),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers: Modifiers::default(),
+            text: None,
+        };
+
+        let _ = app.react(Message::Keyboard(press(Named::Delete)));
+        let confirmation = app.delete_confirmation.as_ref().expect("dialog open");
+        assert_eq!(confirmation.ids, vec![task.0.clone()]);
+        assert_eq!(confirmation.titles, vec![task.1.clone()]);
+
+        // While the dialog is open other shortcuts are swallowed, and Esc backs out.
+        let _ = app.react(Message::Keyboard(press(Named::Tab)));
+        assert_eq!(app.active, ViewTab::Inbox);
+        let _ = app.react(Message::Keyboard(press(Named::Escape)));
+        assert!(app.delete_confirmation.is_none());
+        assert_eq!(app.pending_mutations, 0);
+        assert!(app.current_tasks().iter().any(|row| row.id == task.0));
+
+        let _ = app.react(Message::Keyboard(press(Named::Backspace)));
+        let _ = app.react(Message::Keyboard(press(Named::Enter)));
+        assert!(app.delete_confirmation.is_none());
+        assert_eq!(app.pending_mutations, 1);
+        assert!(app.current_tasks().iter().all(|row| row.id != task.0));
+    }
+
+    #[test]
+    fn tray_badge_counts_inbox_and_overdue_tasks() {
+        let (mut app, service, _guard) = init_app();
+        service
+            .capture(TaskInput {
+                text: vec!["Renew passport".into(), "due:2001-01-01".into()],
+                ..TaskInput::default()
+            })
+            .unwrap();
+
+        let open = service.list(&ListFilters::for_view(None)).unwrap();
+        let inbox = service
+            .list(&ListFilters::for_view(ViewTab::Inbox.list_view()))
+            .unwrap();
+        let badge = TrayBadge::from_tasks(&open.tasks, Utc::now());
+        assert_eq!(badge.inbox, inbox.tasks.len());
+        assert!(badge.overdue >= 1);
+        assert_eq!(TrayBadge::default().title(), "");
+        assert_eq!(
+            TrayBadge {
+                inbox: 3,
+                overdue: 1
+            }
+            .title(),
+            "3 · 1!"
+        );
+
+        // Without a tray icon closing the window quits rather than hiding it.
+        assert!(app.tray.is_none());
+        let id = window::Id::unique();
+        let _ = app.react(Message::WindowCloseRequested(id));
+        assert!(!app.window_hidden);
+        assert_eq!(app.window_id, Some(id));
+    }
+
+    #[test]
+    fn quick_capture_window_adds_a_task_and_closes() {
+        let (mut app, service, _guard) = init_app();
+        let main_window = app.window_id.expect("main window opened at boot");
+
+        let _ = app.open_quick_capture();
+        let quick = app.quick_capture.as_ref().expect("quick capture open");
+        let quick_window = quick.window;
+        assert_ne!(quick_window, main_window);
+        assert!(app.is_quick_capture(quick_window));
+
+        let _ = app.react(Message::QuickCaptureTextChanged(
+            "Call plumber @phone +House".into(),
+        ));
+        let preview = app
+            .quick_capture
+            .as_ref()
+            .and_then(|quick| quick.capture.preview.as_ref())
+            .expect("token preview");
+        assert_eq!(preview.chips.len(), 2);
+        // The main capture bar keeps its own draft.
+        assert!(app.capture.text.is_empty());
+
+        // An unfocused window with a draft stays put.
+        let _ = app.react(Message::WindowUnfocused(quick_window));
+        assert!(app.quick_capture.is_some());
+
+        let _ = app.react(Message::QuickCaptureSubmit);
+        let input = app.quick_capture.as_ref().unwrap().capture.input();
+        assert!(app.quick_capture.as_ref().unwrap().capture.submitting);
+        let outcome = service.capture(input).unwrap();
+        let _ = app.react(Message::QuickCaptureCompleted(Ok(outcome)));
+        assert!(app.quick_capture.is_none());
+        assert!(app
+            .toasts
+            .latest()
+            .is_some_and(|toast| toast.message.contains("Call plumber")));
+
+        // Closing or escaping the quick window never touches the main one.
+        let _ = app.open_quick_capture();
+        let quick_window = app.quick_capture.as_ref().unwrap().window;
+        let _ = app.react(Message::WindowCloseRequested(quick_window));
+        assert!(app.quick_capture.is_none());
+        assert_eq!(app.window_id, Some(main_window));
+
+        let _ = app.open_quick_capture();
+        let escape = KeyboardEvent::KeyPressed {
+            key: Key::Named(Named::Escape),
+            modified_key: Key::Named(Named::Escape),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers: Modifiers::default(),
+            text: None,
+        };
+        let _ = app.react(Message::Keyboard(escape));
+        assert!(app.quick_capture.is_none());
+    }
+
+    #[test]
+    fn reminders_announce_each_due_and_defer_date_once() {
+        let (app, _service, _guard) = init_app();
+        let template = app.current_tasks()[0].clone();
+        let start = Utc::now();
+        let task = |title: &str, status: TaskStatus, due: Option<i64>, defer: Option<i64>| {
+            let mut task = template.clone();
+            task.id = title.to_lowercase();
+            task.title = title.into();
+            task.status = status;
+            task.due_at = due.map(|minutes| start + ChronoDuration::minutes(minutes));
+            task.defer_until = defer.map(|minutes| start + ChronoDuration::minutes(minutes));
+            task
+        };
+        let tasks = vec![
+            task("Invoice", TaskStatus::Next, Some(10), None),
+            task("Dentist", TaskStatus::Scheduled, None, Some(20)),
+            task("Overdue", TaskStatus::Next, Some(-60), None),
+            task("Later", TaskStatus::Next, Some(90), None),
+            task("Finished", TaskStatus::Done, Some(5), None),
+        ];
+
+        let mut tracker = ReminderTracker::new(start);
+        let reminders = tracker.check(&tasks, start + ChronoDuration::minutes(30));
+        let fired: Vec<(&str, ReminderKind)> = reminders
+            .iter()
+            .map(|reminder| (reminder.title.as_str(), reminder.kind))
+            .collect();
+        assert_eq!(
+            fired,
+            vec![
+                ("Invoice", ReminderKind::Due),
+                ("Dentist", ReminderKind::Resurfaced)
+            ]
+        );
+        assert!(tracker
+            .check(&tasks, start + ChronoDuration::minutes(45))
+            .is_empty());
+        let later = tracker.check(&tasks, start + ChronoDuration::minutes(120));
+        assert_eq!(later.len(), 1);
+        assert_eq!(later[0].task_id, "later");
+    }
+
+    #[test]
+    fn window_state_is_saved_and_restored_on_the_next_launch() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::ViewRequested(ViewTab::Waiting));
+        let _ = app.react(Message::ToggleTheme);
+        let _ = app.react(Message::WindowChangeChecked(
+            WindowChange::Resized(Size::new(1280.0, 820.0)),
+            false,
+        ));
+        let _ = app.react(Message::WindowChangeChecked(
+            WindowChange::Moved(Point::new(40.0, 60.0)),
+            false,
+        ));
+        // A maximized window keeps the size it will be restored to.
+        let _ = app.react(Message::WindowChangeChecked(
+            WindowChange::Resized(Size::new(1920.0, 1080.0)),
+            true,
+        ));
+
+        let path = service.config().data_dir().join(WINDOW_STATE_FILE);
+        assert!(!path.exists(), "changes are written on the next tick");
+        let _ = app.react(Message::RefreshTick);
+
+        let saved = WindowStateStore::load(path).unwrap().state().clone();
+        assert_eq!(saved.size(), Size::new(1280.0, 820.0));
+        assert_eq!(saved.position(), Some(Point::new(40.0, 60.0)));
+        assert!(saved.maximized);
+        assert_eq!(saved.active_view(), Some(ViewTab::Waiting));
+
+        let (relaunched, _) = CptDesktop::new(DesktopFlags::from(DesktopOptions {
+            data_dir: Some(service.config().data_dir().to_path_buf()),
+            ..Default::default()
+        }));
+        assert_eq!(relaunched.active, ViewTab::Waiting);
+        assert_eq!(relaunched.theme, Theme::Light);
+    }
+
+    #[test]
+    fn theme_follows_the_system_until_overridden() {
+        let (mut app, _service, _guard) = init_app();
+        assert_eq!(app.window_state.state().theme, ThemeChoice::System);
+
+        let follow = if app.theme == Theme::Dark {
+            Theme::Light
+        } else {
+            Theme::Dark
+        };
+        let _ = app.react(Message::SystemThemeDetected(follow.clone()));
+        assert_eq!(app.theme, follow);
+
+        let _ = app.react(Message::ToggleTheme);
+        assert_eq!(app.window_state.state().theme, ThemeChoice::Light);
+        assert_eq!(app.theme, Theme::Light);
+        let _ = app.react(Message::SystemThemeDetected(Theme::Dark));
+        assert_eq!(app.theme, Theme::Light, "an override ignores the OS");
+
+        let _ = app.react(Message::ToggleTheme);
+        assert_eq!(app.theme, Theme::Dark);
+        let _ = app.react(Message::ToggleTheme);
+        assert_eq!(app.window_state.state().theme, ThemeChoice::System);
+    }
+
+    #[test]
+    fn color_themes_load_user_files_and_apply_from_settings() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let themes_dir = temp_dir.path().join(USER_THEMES_DIR);
+        fs::create_dir_all(&themes_dir).unwrap();
+        fs::write(
+            themes_dir.join("brand.toml"),
+            "name = \"Brand\"\n\n[light]\nbackground = \"#ff000080\"\n",
+        )
+        .unwrap();
+        fs::write(
+            themes_dir.join("broken.toml"),
+            "name = \"Broken\"\n[dark]\nnope = \"#000\"\n",
+        )
+        .unwrap();
+
+        let (themes, errors) = ColorThemes::load(themes_dir);
+        assert_eq!(errors.len(), 1, "the broken file is reported");
+        let names: Vec<&str> = themes.names().collect();
+        assert_eq!(names.first(), Some(&DEFAULT_COLOR_THEME));
+        assert!(names.contains(&"Nord") && names.contains(&"Brand"));
+        assert!(!names.contains(&"Broken"));
+
+        // A light-only theme pins the light appearance whatever the OS asks for.
+        let (theme, palette) = themes.get(Some("Brand")).resolve(Theme::Dark);
+        assert_eq!(theme, Theme::Light);
+        assert_eq!(
+            palette.background,
+            Color::from_rgba8(255, 0, 0, 128.0 / 255.0)
+        );
+        assert_eq!(
+            palette.primary,
+            Palette::for_theme(&Theme::Light).primary,
+            "unset colors keep the built-in palette"
+        );
+        assert_eq!(themes.get(Some("Missing")).name, DEFAULT_COLOR_THEME);
+    }
+
+    #[test]
+    fn settings_dialog_picks_appearance_and_color_theme() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::SettingsToggled);
+        assert!(app.settings_open);
+
+        let _ = app.react(Message::AppearancePicked(ThemeChoice::Dark));
+        let _ = app.react(Message::ColorThemePicked("Solarized Light".into()));
+        assert_eq!(app.theme, Theme::Light);
+        assert_eq!(app.window_state.state().theme, ThemeChoice::Dark);
+        assert_eq!(
+            app.window_state.state().color_theme.as_deref(),
+            Some("Solarized Light")
+        );
+
+        let _ = app.react(Message::ColorThemePicked(DEFAULT_COLOR_THEME.into()));
+        assert_eq!(app.theme, Theme::Dark);
+        assert_eq!(app.window_state.state().color_theme, None);
+
+        let _ = app.react(Message::ColorThemePicked("Nord".into()));
+        let _ = app.react(Message::RefreshTick);
+        let (relaunched, _) = CptDesktop::new(DesktopFlags::from(DesktopOptions {
+            data_dir: Some(service.config().data_dir().to_path_buf()),
+            ..Default::default()
+        }));
+        assert_eq!(
+            relaunched.window_state.state().color_theme.as_deref(),
+            Some("Nord")
+        );
+    }
+
+    #[test]
+    fn cmd_plus_and_minus_zoom_within_bounds() {
+        let (mut app, _service, _guard) = init_app();
+        let press = |value: &str| {
+            let key = Key::Character(value.into());
+            Message::Keyboard(KeyboardEvent::KeyPressed {
+                key: key.clone(),
+                modified_key: key,
+                physical_key: Physical::Unidentified(NativeCode::Unidentified),
+                location: Location::Standard,
+                modifiers: Modifiers::COMMAND,
+                text: Some(value.into()),
+            })
+        };
+
+        let _ = app.react(press("="));
+        let _ = app.react(press("+"));
+        assert_eq!(app.window_state.state().zoom, 1.2);
+        assert_eq!(
+            app.toasts.latest().map(|toast| toast.message.as_str()),
+            Some("Zoom 120%")
+        );
+
+        for _ in 0..10 {
+            let _ = app.react(press("-"));
+        }
+        assert_eq!(app.window_state.state().zoom, MIN_ZOOM);
+        let _ = app.react(Message::ZoomChanged(5.0));
+        assert_eq!(app.window_state.state().zoom, MAX_ZOOM);
+        let _ = app.react(press("0"));
+        assert_eq!(app.window_state.state().zoom, 1.0);
+    }
+
+    #[test]
+    fn catalogs_cover_the_english_strings_and_fall_back_to_them() {
+        let english = Catalog::new(Some("en"));
+        for tab in ViewTab::ALL {
+            assert_eq!(english.view_title(*tab), tab.title());
+            for label in column_labels(*tab) {
+                assert_eq!(english.column(label), label);
+            }
+        }
+        // The Projects table's counts.
+        for label in ["Total", "Next", "Waiting", "Someday"] {
+            assert_eq!(english.column(label), label);
+        }
+        for action in ShortcutAction::ALL {
+            assert_eq!(english.shortcut(action), action.label());
+        }
+        for action in COMMAND_ACTIONS {
+            assert_eq!(english.action_label(action.id), action.label);
+            assert_eq!(english.action_description(action.id), action.description);
+        }
+        let kind = MutationKind::Inbox(Vec::new());
+        assert_eq!(english.mutation(kind.label()), "move to inbox");
+        assert_eq!(
+            english.tr_args(
+                "toast-exported-many",
+                &[("count", "3".into()), ("path", "a.csv".into())]
+            ),
+            "Exported 3 tasks to a.csv"
+        );
+
+        let en = parse_catalog(include_str!("../../locales/en.ftl"));
+        let de = parse_catalog(include_str!("../../locales/de.ftl"));
+        let unknown: Vec<&String> = de.keys().filter(|id| !en.contains_key(*id)).collect();
+        assert!(unknown.is_empty(), "ids missing from en.ftl: {unknown:?}");
+
+        let german = Catalog::new(Some("DE"));
+        assert_eq!(german.locale(), "de");
+        assert_eq!(german.view_title(ViewTab::Inbox), "Eingang");
+        assert_eq!(german.tr("no-such-message"), "no-such-message");
+    }
+
+    #[test]
+    fn picking_a_language_translates_the_app_and_persists() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::LocalePicked(Some("de".into())));
+        assert_eq!(app.i18n.view_title(ViewTab::Next), "Als Nächstes");
+        assert_eq!(app.window_state.state().locale.as_deref(), Some("de"));
+
+        // The palette finds actions by their translated and their English names.
+        app.command_palette.query = "erledigen".into();
+        let found = app.command_palette.filtered(&app.i18n);
+        assert_eq!(
+            found.first().map(|action| action.id),
+            Some(CommandActionId::MarkDone)
+        );
+        app.command_palette.query = "mark done".into();
+        let found = app.command_palette.filtered(&app.i18n);
+        assert_eq!(
+            found.first().map(|action| action.id),
+            Some(CommandActionId::MarkDone)
+        );
+
+        let _ = app.react(Message::RefreshTick);
+        let path = service.config().data_dir().join(WINDOW_STATE_FILE);
+        let saved = WindowStateStore::load(path).unwrap().state().clone();
+        assert_eq!(saved.locale.as_deref(), Some("de"));
+
+        let _ = app.react(Message::LocalePicked(None));
+        assert_eq!(app.window_state.state().locale, None);
+    }
+
+    #[test]
+    fn f6_moves_keyboard_focus_between_areas() {
+        let (mut app, _service, _guard) = init_app();
+        let press = |named: Named, modifiers: Modifiers| {
+            Message::Keyboard(KeyboardEvent::KeyPressed {
+                key: Key::Named(named),
+                modified_key: Key::Named(named),
+                physical_key: Physical::Unidentified(NativeCode::Unidentified),
+                location: Location::Standard,
+                modifiers,
+                text: None,
+            })
+        };
+        let region = |app: &CptDesktop| app.keyboard_focus.map(|focus| focus.region);
+        assert_eq!(region(&app), None, "no focus ring for mouse users");
+
+        // Toolbar: arrows wrap around its controls and Enter presses the focused one.
+        let _ = app.react(press(Named::F6, Modifiers::empty()));
+        assert_eq!(region(&app), Some(FocusRegion::Toolbar));
+        let _ = app.react(press(Named::ArrowLeft, Modifiers::empty()));
+        let focus = app.keyboard_focus.unwrap();
+        assert!(focus.on_toolbar(ToolbarItem::CommandPalette));
+        let _ = app.react(press(Named::Enter, Modifiers::empty()));
+        assert!(app.command_palette.open);
+        let _ = app.react(press(Named::Escape, Modifiers::empty()));
+        assert!(!app.command_palette.open);
+
+        // Sidebar: starts on the open view; Down then Enter opens the next one.
+        let _ = app.react(press(Named::F6, Modifiers::empty()));
+        assert!(app.keyboard_focus.unwrap().on_view(ViewTab::Inbox));
+        let _ = app.react(press(Named::ArrowDown, Modifiers::empty()));
+        let _ = app.react(press(Named::Enter, Modifiers::empty()));
+        assert_eq!(app.active, ViewTab::Next);
+
+        let _ = app.react(press(Named::F6, Modifiers::empty()));
+        assert_eq!(region(&app), Some(FocusRegion::Table));
+        let _ = app.react(press(Named::F6, Modifiers::empty()));
+        assert_eq!(region(&app), Some(FocusRegion::Capture));
+        assert!(app.capture.open);
+
+        // Going back leaves the empty capture bar closed; Esc hides the ring.
+        let _ = app.react(press(Named::F6, Modifiers::SHIFT));
+        assert_eq!(region(&app), Some(FocusRegion::Table));
+        assert!(!app.capture.open);
+        let _ = app.react(press(Named::Escape, Modifiers::empty()));
+        assert_eq!(region(&app), None);
+    }
+
+    #[cfg(feature = "telemetry")]
+    #[test]
+    fn telemetry_sink_is_configurable_and_can_be_turned_off() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::ViewRequested(ViewTab::Next));
+        assert!(app
+            .telemetry
+            .recent()
+            .iter()
+            .any(|recorded| recorded.event.name() == "view_changed"));
+
+        let _ = app.react(Message::TelemetrySinkPicked(TelemetrySink::File));
+        let _ = app.react(Message::ViewRequested(ViewTab::Waiting));
+        let log = service.config().data_dir().join(TELEMETRY_FILE);
+        let written = fs::read_to_string(&log).expect("telemetry log");
+        assert!(written.contains("\"view_changed\""));
+
+        let _ = app.react(Message::TelemetrySinkPicked(TelemetrySink::Disabled));
+        let _ = app.react(Message::ViewRequested(ViewTab::Inbox));
+        assert!(app.telemetry.recent().is_empty());
+        assert_eq!(fs::read_to_string(&log).unwrap(), written);
+        assert_eq!(app.window_state.state().telemetry, TelemetrySink::Disabled);
+
+        let _ = app.react(Message::TelemetryEventsToggled);
+        assert!(app.telemetry_events_open);
+        assert!(app.telemetry_dialog().is_some());
+    }
+
+    #[test]
+    fn reduced_motion_keeps_status_messages_until_replaced() {
+        let (mut app, _service, _guard) = init_app();
+        let stale = || StatusToast {
+            created_at: Instant::now() - Duration::from_secs(60),
+            ..StatusToast::info("Copied")
+        };
+
+        let _ = app.react(Message::ReduceMotionToggled(true));
+        app.toasts.push(stale());
+        let _ = app.react(Message::SearchChanged(String::new()));
+        assert!(!app.toasts.is_empty());
+        assert!(app.window_state.state().reduce_motion);
+
+        let _ = app.react(Message::ReduceMotionToggled(false));
+        app.toasts.push(stale());
+        let _ = app.react(Message::SearchChanged(String::new()));
+        assert!(app.toasts.is_empty());
+    }
+
+    #[test]
+    fn shortcuts_can_be_rebound_and_persist() {
+        let (mut app, service, _guard) = init_app();
+        let press = |value: &str, modifiers: Modifiers| {
+            let key = Key::Character(value.into());
+            Message::Keyboard(KeyboardEvent::KeyPressed {
+                key: key.clone(),
+                modified_key: key,
+                physical_key: Physical::Unidentified(NativeCode::Unidentified),
+                location: Location::Standard,
+                modifiers,
+                text: Some(value.into()),
+            })
+        };
+
+        let _ = app.react(Message::ShortcutsToggled);
+        let _ = app.react(Message::ShortcutRecordStarted(ShortcutAction::Capture));
+        let _ = app.react(press("k", Modifiers::COMMAND));
+        assert!(app.shortcut_recording.is_none());
+        assert_eq!(
+            app.shortcuts.hint(ShortcutAction::Capture).as_deref(),
+            Some("Cmd+K")
+        );
+        assert_eq!(
+            app.shortcuts.hint(ShortcutAction::CommandPalette),
+            None,
+            "the palette lost Cmd+K"
+        );
+        let _ = app.react(Message::ShortcutsToggled);
+
+        let _ = app.react(press("a", Modifiers::empty()));
+        assert!(!app.capture.open, "A is no longer bound");
+        let _ = app.react(press("k", Modifiers::COMMAND));
+        assert!(app.capture.open);
+        assert!(!app.command_palette.open);
+
+        let reloaded = ShortcutMap::load(service.config().data_dir().join(SHORTCUTS_FILE)).unwrap();
+        assert_eq!(
+            reloaded.action_for(&Key::Character("k".into()), Modifiers::COMMAND),
+            Some(ShortcutAction::Capture)
+        );
+
+        let _ = app.react(Message::ShortcutReset(None));
+        assert_eq!(
+            app.shortcuts
+                .hint(ShortcutAction::CommandPalette)
+                .as_deref(),
+            Some("Cmd+K")
+        );
+    }
+
+    #[test]
+    fn key_bindings_parse_and_match_like_they_display() {
+        for spec in [
+            "A",
+            "Cmd+K",
+            "Shift+Tab",
+            "Cmd+,",
+            "Cmd++",
+            "Cmd+Alt+Shift+F5",
+        ] {
+            let binding = KeyBinding::parse(spec).expect(spec);
+            assert_eq!(binding.to_string(), spec);
+        }
+        assert!(KeyBinding::parse("Hyper+K").is_none());
+        assert!(KeyBinding::parse("Cmd+Escape").is_none());
+
+        let tab = KeyBinding::parse("Tab").unwrap();
+        assert!(tab.matches(&Key::Named(Named::Tab), Modifiers::empty()));
+        assert!(!tab.matches(&Key::Named(Named::Tab), Modifiers::SHIFT));
+        let question = KeyBinding::parse("?").unwrap();
+        assert!(question.matches(&Key::Character("?".into()), Modifiers::SHIFT));
+        assert!(!question.matches(&Key::Character("?".into()), Modifiers::COMMAND));
+    }
+
+    #[test]
+    fn board_cards_move_between_status_columns() {
+        let (mut app, service, _guard) = init_app();
+        assert!(app.board().is_none());
+        let _ = app.react(Message::ViewModePicked(ViewMode::Board));
+        assert_eq!(app.window_state.state().view_mode, ViewMode::Board);
+
+        let snapshot = service.list(&ListFilters::for_view(None)).unwrap();
+        let _ = app.react(Message::ViewRequested(ViewTab::All));
+        let _ = app.react(Message::ViewLoaded(ViewTab::All, Ok(snapshot)));
+        assert!(app.board().is_some());
+        let card = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.status == TaskStatus::Inbox)
+            .expect("an inbox task")
+            .id
+            .clone();
+
+        let _ = app.react(Message::DragStarted(card.clone()));
+        let _ = app.react(Message::DragTargetEntered(DropTarget::View(ViewTab::Next)));
+        let _ = app.react(Message::DragReleased);
+        assert_eq!(app.pending_mutations, 1);
+        let moved = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == card)
+            .expect("the card stays on the board");
+        assert_eq!(moved.status, TaskStatus::Next);
+
+        // Dropping onto the column it already sits in is a no-op.
+        let _ = app.react(Message::DragStarted(card.clone()));
+        let _ = app.react(Message::DragTargetEntered(DropTarget::View(ViewTab::Next)));
+        let _ = app.react(Message::DragReleased);
+        assert_eq!(app.pending_mutations, 1);
+
+        let _ = app.react(Message::RefreshTick);
+        let (relaunched, _) = CptDesktop::new(DesktopFlags::from(DesktopOptions {
+            data_dir: Some(service.config().data_dir().to_path_buf()),
+            ..Default::default()
+        }));
+        assert_eq!(relaunched.window_state.state().view_mode, ViewMode::Board);
+    }
+
+    #[test]
+    fn dragging_a_next_row_onto_another_reorders_the_view() {
+        let (mut app, service, _guard) = init_app();
+        let inbox: Vec<String> = app
+            .current_tasks()
+            .iter()
+            .map(|task| task.id.clone())
+            .collect();
+        service.promote_to_next(&inbox).unwrap();
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Next.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewRequested(ViewTab::Next));
+        let _ = app.react(Message::ViewLoaded(ViewTab::Next, Ok(snapshot)));
+        let order = |app: &CptDesktop| -> Vec<String> {
+            app.current_tasks()
+                .iter()
+                .map(|task| task.id.clone())
+                .collect()
+        };
+        let before = order(&app);
+        assert!(before.len() >= 3);
+
+        let last = before.last().unwrap().clone();
+        let _ = app.react(Message::DragStarted(last.clone()));
+        let _ = app.react(Message::DragTargetEntered(DropTarget::Row(
+            before[0].clone(),
+        )));
+        let _ = app.react(Message::DragReleased);
+        assert_eq!(app.pending_mutations, 1);
+        let after = order(&app);
+        assert_eq!(after[0], last);
+        assert_eq!(after[1..], before[..before.len() - 1]);
+        assert!(app.undo_pending.is_empty(), "manual order is not undoable");
+
+        // Dropping a row onto itself leaves the order alone.
+        let _ = app.react(Message::DragStarted(last.clone()));
+        let _ = app.react(Message::DragTargetEntered(DropTarget::Row(last)));
+        let _ = app.react(Message::DragReleased);
+        assert_eq!(app.pending_mutations, 1);
+
+        service.reorder_tasks(&after).unwrap();
+        let reloaded = service
+            .list(&ListFilters::for_view(ViewTab::Next.list_view()))
+            .unwrap();
+        let saved: Vec<String> = reloaded.tasks.into_iter().map(|task| task.id).collect();
+        assert_eq!(saved, after);
+    }
+
+    #[test]
+    fn calendar_places_and_reschedules_scheduled_tasks() {
+        let (mut app, service, _guard) = init_app();
+        let today = Local::now().date_naive();
+        let dentist = service
+            .capture(TaskInput {
+                text: vec![
+                    "Dentist".into(),
+                    format!("due:{}", today.format("%Y-%m-%d")),
+                ],
+                ..TaskInput::default()
+            })
+            .unwrap();
+
+        let _ = app.react(Message::ViewRequested(ViewTab::Scheduled));
+        let _ = app.react(Message::ViewModePicked(ViewMode::Calendar));
+        let mut filters = ListFilters::for_view(ViewTab::Scheduled.list_view());
+        filters.dated_between = Some(app.calendar.range());
+        let snapshot = service.list(&filters).unwrap();
+        let grid = app.calendar.days();
+        assert!(snapshot
+            .tasks
+            .iter()
+            .all(|task| placement(task).is_some_and(|day| grid.contains(&day))));
+        let _ = app.react(Message::ViewLoaded(ViewTab::Scheduled, Ok(snapshot)));
+        assert!(app.calendar_view().is_some());
+
+        let tomorrow = today + ChronoDuration::days(1);
+        let drop_on = |app: &mut CptDesktop, day| {
+            let _ = app.react(Message::DragStarted(dentist.id.clone()));
+            let _ = app.react(Message::DragTargetEntered(DropTarget::Day(day)));
+            let _ = app.react(Message::DragReleased);
+        };
+        drop_on(&mut app, tomorrow);
+        assert_eq!(app.pending_mutations, 1);
+        let moved = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == dentist.id)
+            .expect("rescheduled task stays on the calendar");
+        assert_eq!(placement(moved), Some(tomorrow));
+        assert_eq!(app.calendar.selected_day, Some(tomorrow));
+
+        // Dropping a task on the day it already sits on changes nothing.
+        drop_on(&mut app, tomorrow);
+        assert_eq!(app.pending_mutations, 1);
+
+        let _ = app.react(Message::CalendarSpanPicked(CalendarSpan::Week));
+        let _ = app.react(Message::CalendarShifted(1));
+        assert_eq!(app.calendar.days().len(), 7);
+        assert!(app.calendar.days()[0] > today);
+        let _ = app.react(Message::CalendarShifted(0));
+        assert!(app.calendar.days().contains(&today));
+        assert_eq!(app.window_state.state().calendar_span, CalendarSpan::Week);
+    }
+
+    #[test]
+    fn project_page_groups_tasks_and_captures_into_the_project() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::ViewRequested(ViewTab::Projects));
+        let _ = app.react(Message::ProjectOpened("Ops".into()));
+        let page = app.project_page.as_ref().expect("project page opens");
+        assert_eq!(page.capture.text, "+Ops ");
+        assert!(!page.is_stalled(), "nothing is known before the tasks load");
+
+        let mut filters = ListFilters::for_view(None);
+        filters.project = Some("Ops".into());
+        let tasks = service.list(&filters).unwrap().tasks;
+        let has_next = tasks.iter().any(|task| task.status == TaskStatus::Next);
+        let _ = app.react(Message::ProjectTasksLoaded("Ops".into(), Ok(tasks.clone())));
+        let page = app.project_page.as_ref().unwrap();
+        let grouped: usize = ProjectPage::GROUPS
+            .iter()
+            .map(|status| page.group(*status).len())
+            .sum();
+        assert_eq!(grouped, tasks.len());
+        assert_eq!(page.is_stalled(), !has_next);
+
+        // Submitting just the project token is refused.
+        let _ = app.react(Message::ProjectCaptureSubmit);
+        assert!(app
+            .project_page
+            .as_ref()
+            .unwrap()
+            .capture
+            .preview_error
+            .is_some());
+
+        let _ = app.react(Message::ProjectCaptureChanged("+Ops Rotate keys".into()));
+        let input = app.project_page.as_ref().unwrap().capture.input();
+        let outcome = service.capture(input).unwrap();
+        let _ = app.react(Message::ProjectCaptureCompleted(Ok(outcome.clone())));
+        assert_eq!(app.project_page.as_ref().unwrap().capture.text, "+Ops ");
+        let captured = service.fetch_task(&outcome.id).unwrap().unwrap();
+        assert_eq!(captured.project.as_deref(), Some("Ops"));
+
+        let _ = app.react(Message::ProjectTasksLoaded("Ops".into(), Ok(Vec::new())));
+        assert!(app.project_page.as_ref().unwrap().is_stalled());
+
+        let _ = app.react(Message::ViewRequested(ViewTab::Inbox));
+        assert!(app.project_page.is_none());
+    }
+
+    #[test]
+    fn stats_view_charts_completions_by_week() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::ViewRequested(ViewTab::Stats));
+        assert!(app.stats_view().is_some(), "stats tab shows while loading");
+        assert!(app.board().is_none());
+
+        let stats = service.stats(STATS_WEEKS * 7).unwrap();
+        let _ = app.react(Message::StatsLoaded(Ok(stats)));
+        let stats = app.stats.as_ref().expect("stats loaded");
+        assert_eq!(stats.weekly_completions().len(), STATS_WEEKS);
+        assert!(stats
+            .by_status
+            .iter()
+            .any(|(status, count)| *status == TaskStatus::Inbox && *count > 0));
+
+        let _ = app.react(Message::StatsLoaded(Err("disk gone".into())));
+        assert!(!app.toasts.is_empty());
+
+        let _ = app.react(Message::ViewRequested(ViewTab::Inbox));
+        assert!(app.stats_view().is_none());
+    }
+
+    #[test]
+    fn export_writes_the_view_or_everything_and_links_the_file() {
+        let (mut app, service, guard) = init_app();
+        let _ = app.react(Message::ExportRequested);
+        let dialog = app.export_dialog.expect("export dialog opens");
+        assert_eq!(dialog.scope, ExportScope::View);
+        let _ = app.react(Message::ExportFormatPicked(ExportFormat::Csv));
+        let _ = app.react(Message::ExportScopePicked(ExportScope::Everything));
+        let dialog = app.export_dialog.unwrap();
+        assert_eq!(dialog.format, ExportFormat::Csv);
+        assert_eq!(dialog.scope, ExportScope::Everything);
+        let _ = app.react(Message::ExportCancelled);
+        assert!(app.export_dialog.is_none());
+
+        let inbox: Vec<_> = app.current_tasks().into_iter().cloned().collect();
+        let path = guard.path().join("inbox.md");
+        let filters = app.view_filters(app.active);
+        let count = write_export(&service, &filters, ExportFormat::Markdown, &path).unwrap();
+        assert_eq!(count, inbox.len());
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(inbox.iter().all(|task| written.contains(&task.title)));
+
+        let path = guard.path().join("all.ics");
+        let filters = ListFilters {
+            include_done: true,
+            ..ListFilters::for_view(None)
+        };
+        let everything = write_export(&service, &filters, ExportFormat::Ics, &path).unwrap();
+        assert!(everything > inbox.len());
+        assert_eq!(
+            fs::read_to_string(&path)
+                .unwrap()
+                .matches("BEGIN:VTODO")
+                .count(),
+            everything
+        );
+
+        let _ = app.react(Message::ExportFinished(Ok(Some((
+            path.clone(),
+            everything,
+        )))));
+        assert_eq!(app.exported.as_ref().map(|file| &file.path), Some(&path));
+        assert!(app
+            .toasts
+            .latest()
+            .is_some_and(|toast| toast.message.contains("all.ics")));
+
+        // Dismissing the save dialog is not an error.
+        app.toasts.clear();
+        let _ = app.react(Message::ExportFinished(Ok(None)));
+        assert!(app.toasts.is_empty());
+    }
+
+    #[test]
+    fn deep_links_parse_and_reach_the_running_app() {
+        assert_eq!(
+            DeepLink::parse("cpt://task/01HZX").unwrap(),
+            DeepLink::Task("01HZX".into())
+        );
+        assert_eq!(
+            DeepLink::parse("CPT://task/a%20b/").unwrap(),
+            DeepLink::Task("a b".into())
+        );
+        assert_eq!(
+            DeepLink::parse("cpt://capture?text=Call+Sam+%2Bhome+due%3Atomorrow").unwrap(),
+            DeepLink::Capture("Call Sam +home due:tomorrow".into())
+        );
+        assert_eq!(
+            DeepLink::parse("cpt://capture").unwrap(),
+            DeepLink::Capture(String::new())
+        );
+        assert!(DeepLink::parse("cpt://task/").is_err());
+        assert!(DeepLink::parse("cpt://project/x").is_err());
+        assert!(DeepLink::parse("https://task/x").is_err());
+        assert!(DeepLink::parse("cpt://capture?text=%zz").is_err());
+
+        let dir = TempDir::new().unwrap();
+        let port_file = dir.path().join(LINK_PORT_FILE);
+        assert!(!forward(&port_file, "cpt://task/1"));
+        let listener = LinkListener::start(port_file.clone()).unwrap();
+        assert!(forward(&port_file, "cpt://task/1"));
+        assert_eq!(listener.poll(), vec!["cpt://task/1".to_string()]);
+        assert!(listener.poll().is_empty());
+    }
+
+    #[test]
+    fn opening_links_shows_the_task_or_fills_in_capture() {
+        let (mut app, service, _guard) = init_app();
+        assert!(app.links.is_some());
+
+        let _ = app.react(Message::LinkOpened(
+            "cpt://capture?text=Call+Sam+%40phone".into(),
+        ));
+        assert!(app.capture.open);
+        assert_eq!(app.capture.text, "Call Sam @phone");
+
+        let id = app.current_tasks()[0].id.clone();
+        service.promote_to_next(std::slice::from_ref(&id)).unwrap();
+        let task = service.fetch_task(&id).unwrap().unwrap();
+        app.search = "no such task".into();
+        let _ = app.react(Message::LinkOpened(format!("cpt://task/{}", task.id)));
+        let _ = app.react(Message::LinkedTaskLoaded(
+            task.id.clone(),
+            Ok(Some(task.clone())),
+        ));
+        assert_eq!(app.active, ViewTab::Next);
+        assert!(app.search.is_empty());
+        assert!(app.detail_open);
+        assert_eq!(app.selected_task.as_deref(), Some(task.id.as_str()));
+
+        let _ = app.react(Message::LinkedTaskLoaded("missing".into(), Ok(None)));
+        assert!(app.toasts.latest().is_some_and(
+            |toast| matches!(toast.kind, ToastKind::Error) && toast.message.contains("missing")
+        ));
+
+        app.toasts.clear();
+        let _ = app.react(Message::LinkOpened("cpt://nowhere".into()));
+        assert!(!app.toasts.is_empty());
+    }
+
+    #[test]
+    fn pasting_several_lines_offers_one_task_per_line() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::CaptureToggled);
+
+        let _ = app.react(Message::CapturePasted("Call Sam".into()));
+        let _ = app.react(Message::CaptureClipboardRead(Some("Call Sam".into())));
+        assert!(app.capture.bulk.is_none());
+
+        let pasted =
+            "- Buy milk @errands\n\n2. Call Sam +Home due:tomorrow\n- [ ] File taxes #admin\n";
+        let _ = app.react(Message::CapturePasted(pasted.replace('\n', "")));
+        let _ = app.react(Message::CaptureClipboardRead(Some(pasted.into())));
+        let bulk = app.capture.bulk.clone().expect("bulk offer");
+        let titles: Vec<_> = bulk
+            .lines
+            .iter()
+            .map(|line| line.preview.as_ref().unwrap().title.clone())
+            .collect();
+        assert_eq!(titles, ["Buy milk", "Call Sam", "File taxes"]);
+        assert!(bulk.is_valid());
+
+        // Typing afterwards keeps the paste as one task.
+        let _ = app.react(Message::CaptureTextChanged("Buy milk".into()));
+        assert!(app.capture.bulk.is_none());
+
+        let _ = app.react(Message::CaptureClipboardRead(Some(
+            "Buy milk\nCall Sam due:someday-ish".into(),
+        )));
+        assert!(!app.capture.bulk.as_ref().unwrap().is_valid());
+        let _ = app.react(Message::CaptureSubmit);
+        assert!(!app.capture.submitting);
+        let _ = app.react(Message::BulkCaptureDismissed);
+        assert!(app.capture.bulk.is_none());
+
+        let _ = app.react(Message::CaptureClipboardRead(Some(pasted.into())));
+        let _ = app.react(Message::CaptureSubmit);
+        assert!(app.capture.submitting);
+        let outcomes = service
+            .capture_many(&app.capture.bulk.as_ref().unwrap().inputs())
+            .unwrap();
+        let call = service.fetch_task(&outcomes[1].id).unwrap().unwrap();
+        assert_eq!(call.project.as_deref(), Some("Home"));
+        assert!(call.due_at.is_some());
+        let _ = app.react(Message::BulkCaptureCompleted(Ok(outcomes)));
+        assert!(!app.capture.open);
+        assert!(app.capture.bulk.is_none());
+        assert!(app
+            .toasts
+            .latest()
+            .is_some_and(|toast| toast.message == "Added 3 tasks."));
+    }
+
+    #[test]
+    fn sample_data_is_opt_in_and_offered_by_empty_views() {
+        let open_tasks = |service: &TasksService| {
+            service
+                .list(&ListFilters::for_view(None))
+                .unwrap()
+                .tasks
+                .len()
+        };
+        let launch = |seed: Option<bool>| {
+            let temp_dir = TempDir::new().unwrap();
+            let config = AppConfig::from_data_dir(temp_dir.path().to_path_buf()).unwrap();
+            let flags = DesktopFlags::from(DesktopOptions {
+                data_dir: Some(config.data_dir().to_path_buf()),
+                seed_sample_data: seed,
+                ..Default::default()
+            });
+            let (app, _) = CptDesktop::new(flags);
+            (app, TasksService::new(config).unwrap(), temp_dir)
+        };
+
+        let (_, service, _seeded) = launch(Some(true));
+        assert!(open_tasks(&service) > 0);
+
+        let (mut app, service, _guard) = launch(None);
+        assert_eq!(open_tasks(&service), 0);
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Inbox.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Inbox, Ok(snapshot)));
+        let _ = app.react(Message::WorkspaceChecked(Ok(true)));
+        assert!(app.workspace_empty);
+
+        assert!(maybe_seed_sample_data(&service).unwrap());
+        let _ = app.react(Message::SampleDataLoaded(Ok(true)));
+        assert!(!app.workspace_empty);
+        assert!(!app.toasts.is_empty());
+        assert!(open_tasks(&service) > 0);
+        // A database with tasks is never seeded again.
+        assert!(!maybe_seed_sample_data(&service).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn notes_saved_in_the_external_editor_are_applied() {
+        let (mut app, _service, _guard) = init_app();
+        let first = app.current_tasks().first().unwrap().id.clone();
+        let _ = app.react(Message::RowSelected(first.clone()));
+        let _ = app.react(Message::DetailToggled);
+        app.notes_draft.as_mut().unwrap().content = text_editor::Content::with_text("Unsaved");
+        // `true` exits straight away, so watching ends after the next poll.
+        let _ = app.react(Message::ExternalEditorChanged("true".into()));
+
+        let _ = app.react(Message::NotesExternalEditRequested);
+        let path = app.external_edit.as_ref().expect("editing").path.clone();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Unsaved");
+
+        // Asking again starts over in a file of its own, which dropping the first edit leaves.
+        let _ = app.react(Message::NotesExternalEditRequested);
+        let again = app.external_edit.as_ref().expect("editing").path.clone();
+        assert_ne!(again, path);
+        assert!(!path.exists());
+        assert_eq!(fs::read_to_string(&again).unwrap(), "Unsaved");
+        let path = again;
+
+        fs::write(&path, "Written elsewhere").unwrap();
+        std::thread::sleep(Duration::from_millis(100));
+        let _ = app.react(Message::ExternalEditPolled);
+        assert_eq!(app.pending_mutations, 1);
+        let draft = app.notes_draft.as_ref().unwrap();
+        assert_eq!(draft.text(), "Written elsewhere");
+        assert!(!draft.is_dirty());
+        let notes = app
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == first)
+            .and_then(|task| task.notes.clone());
+        assert_eq!(notes.as_deref(), Some("Written elsewhere"));
+        assert!(app.external_edit.is_none());
+        assert!(!path.exists(), "the temp file goes once watching ends");
+    }
+
+    #[test]
+    fn print_layout_follows_the_view_on_screen() {
+        let (mut app, _service, _guard) = init_app();
+        let title = app.current_tasks().first().unwrap().title.clone();
+        let _ = app.react(Message::SearchChanged(title.clone()));
+
+        let sheet = app.print_layout().expect("inbox prints");
+        assert_eq!(sheet.title, "Inbox");
+        assert_eq!(sheet.columns, column_labels(ViewTab::Inbox));
+        assert!(sheet.checkboxes);
+        assert_eq!(sheet.sections[0].rows[0][0], title);
+        assert_eq!(sheet.row_count(), app.current_tasks().len());
+        assert!(sheet.subtitle.unwrap().contains(&title));
+
+        let html = PrintSheet {
+            title: "R&D <draft>".into(),
+            columns: vec!["Title".into()],
+            sections: vec![PrintSection {
+                heading: Some("Today".into()),
+                rows: vec![vec!["Ship \"v2\"".into()]],
+            }],
+            ..PrintSheet::default()
+        }
+        .to_html();
+        assert!(html.contains("<h1>R&amp;D &lt;draft&gt;</h1>"));
+        assert!(html.contains("<td>Ship &quot;v2&quot;</td>"));
+        assert!(html.contains("colspan=\"1\">Today"));
+        assert!(html.contains("window.print()"));
+
+        app.active = ViewTab::Stats;
+        assert!(app.print_layout().is_none());
+    }
+
+    #[test]
+    fn capture_marks_a_bad_token_and_offers_its_fix() {
+        let (mut app, _service, _guard) = init_app();
+        let _ = app.react(Message::CaptureTextChanged(
+            "Call Sam  due:tomorow, @phone".into(),
+        ));
+        let token = app.capture.token_error.clone().expect("bad token found");
+        assert_eq!(&app.capture.text[token.range()], "due:tomorow");
+        assert!(app.capture.preview_error.is_some());
+
+        let fixed = apply_token_fix(&app.capture.text, &token).expect("a fix");
+        assert_eq!(fixed, "Call Sam  due:tomorrow, @phone");
+        let _ = app.react(Message::CaptureTextChanged(fixed));
+        assert!(app.capture.token_error.is_none());
+        assert!(app.capture.preview_error.is_none());
+        assert!(app
+            .capture
+            .preview
+            .as_ref()
+            .unwrap()
+            .chips
+            .iter()
+            .any(|chip| chip.kind == CaptureChipKind::Due));
+    }
+
+    #[test]
+    fn recent_captures_come_back_with_the_arrow_keys() {
+        let (mut app, service, guard) = init_app();
+        let press = |named: Named| {
+            Message::Keyboard(KeyboardEvent::KeyPressed {
+                key: Key::Named(named),
+                modified_key: Key::Named(named),
+                physical_key: Physical::Unidentified(NativeCode::Unidentified),
+                location: Location::Standard,
+                modifiers: Modifiers::default(),
+                text: None,
+            })
+        };
+        for line in ["Call Sam @phone due:fri", "Book flights +Trip p:2"] {
+            let _ = app.react(Message::CaptureToggled);
+            let _ = app.react(Message::CaptureTextChanged(line.into()));
+            let _ = app.react(Message::CaptureSubmit);
+            let outcome = service.capture(app.capture.input()).unwrap();
+            let _ = app.react(Message::CaptureCompleted(Ok(outcome)));
+        }
+        assert_eq!(
+            app.capture_history.entries(),
+            ["Book flights +Trip p:2", "Call Sam @phone due:fri"]
+        );
+
+        // Up walks back through them, Down returns to the unsent draft.
+        let _ = app.react(Message::CaptureToggled);
+        let _ = app.react(Message::CaptureTextChanged("Half typed".into()));
+        let _ = app.react(press(Named::ArrowUp));
+        assert_eq!(app.capture.text, "Book flights +Trip p:2");
+        assert!(app.capture.preview.is_some());
+        let _ = app.react(press(Named::ArrowUp));
+        let _ = app.react(press(Named::ArrowUp));
+        assert_eq!(app.capture.text, "Call Sam @phone due:fri");
+        let _ = app.react(press(Named::ArrowDown));
+        let _ = app.react(press(Named::ArrowDown));
+        assert_eq!(app.capture.text, "Half typed");
+        assert!(app.capture.recall.is_none());
+
+        // Picking from the Recent list, and the history surviving a restart.
+        let _ = app.react(Message::CaptureRecentToggled);
+        assert!(app.capture.recent_open);
+        let _ = app.react(Message::CaptureRecentPicked(
+            "Call Sam @phone due:fri".into(),
+        ));
+        assert_eq!(app.capture.text, "Call Sam @phone due:fri");
+        assert!(!app.capture.recent_open);
+        let path = guard.path().join(HISTORY_FILE);
+        assert_eq!(
+            CaptureHistory::load(path.clone()).unwrap().entries().len(),
+            2
+        );
+
+        // Repeats move to the front instead of piling up, and the oldest fall off.
+        let mut history = CaptureHistory::load(path.clone()).unwrap();
+        for n in 0..HISTORY_LIMIT {
+            history.record(&format!("Task {n}")).unwrap();
+        }
+        history.record("Task 3").unwrap();
+        assert_eq!(history.entries().len(), HISTORY_LIMIT);
+        assert_eq!(history.get(0), Some("Task 3"));
+        assert!(!history
+            .entries()
+            .contains(&"Call Sam @phone due:fri".to_string()));
+
+        let _ = app.react(Message::CaptureHistoryCleared);
+        assert!(app.capture_history.entries().is_empty());
+        assert!(CaptureHistory::load(path).unwrap().entries().is_empty());
+    }
+
+    #[test]
+    fn toasts_stack_with_their_own_timers_and_actions() {
+        let (mut app, service, _guard) = init_app();
+        add_inbox_task(&mut app, &service, "Book dentist");
+        let ids: Vec<String> = app
+            .current_tasks()
+            .iter()
+            .take(2)
+            .map(|task| task.id.clone())
+            .collect();
+        for id in &ids {
+            let _ = app.react(Message::RowSelected(id.clone()));
+            let _ = app.react(Message::ContextMenuAction(CommandActionId::MarkDone));
+        }
+        for id in &ids {
+            service.mark_done(std::slice::from_ref(id)).unwrap();
+            let _ = app.react(Message::MutationFinished(
+                MutationKind::Complete(vec![id.clone()]),
+                Ok(()),
+            ));
+        }
+
+        // Both queued notices and both results stay up; only the newest offers Undo, which is
+        // what Undo reverts.
+        let actions: Vec<_> = app.toasts.iter().map(|toast| toast.action).collect();
+        assert_eq!(actions, [None, None, None, Some(ToastAction::Undo)]);
+        assert_eq!(app.undo.as_ref().unwrap().tasks[0].id, ids[1]);
+
+        // A hovered toast outlives its time; leaving it resumes the countdown.
+        let first = app.toasts.iter().nth(2).unwrap().id;
+        let _ = app.react(Message::ToastHovered(first, true));
+        let mut toasts = app.toasts.clone();
+        toasts.push(StatusToast {
+            created_at: Instant::now() - Duration::from_secs(60),
+            ..StatusToast::info("Stale")
+        });
+        toasts.set_hovered(first, true);
+        toasts.prune();
+        assert_eq!(
+            toasts.iter().count(),
+            3,
+            "stale toast expires, hovered one stays"
+        );
+        toasts.set_hovered(first, false);
+        assert!(toasts.iter().any(|toast| toast.id == first));
+
+        // Dismissing the Undo toast withdraws the offer; Esc clears the newest toast.
+        let newest = app.toasts.latest().unwrap().id;
+        let _ = app.react(Message::ToastDismissed(newest));
+        assert_eq!(app.toasts.iter().count(), 3);
+        let newest = app.toasts.latest().unwrap().id;
+        let _ = app.react(Message::Keyboard(KeyboardEvent::KeyPressed {
+            key: Key::Named(Named::Escape),
+            modified_key: Key::Named(Named::Escape),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers: Modifiers::default(),
+            text: None,
+        }));
+        assert!(app.undo.is_none());
+        assert_eq!(app.toasts.iter().count(), 2);
+        assert!(app.toasts.iter().all(|toast| toast.id != newest));
+
+        let mut many = Toasts::default();
+        for n in 0..Toasts::LIMIT + 2 {
+            many.push(StatusToast::error(format!("Failed {n}")));
+        }
+        assert_eq!(many.iter().count(), Toasts::LIMIT);
+        assert_eq!(
+            many.latest().map(|toast| toast.message.as_str()),
+            Some("Failed 5")
+        );
+        assert!(matches!(many.latest().unwrap().kind, ToastKind::Error));
+    }
+
+    #[test]
+    fn failed_mutations_roll_back_and_offer_a_retry() {
+        let (mut app, service, _guard) = init_app();
+        add_inbox_task(&mut app, &service, "Book dentist");
+        let titles = |app: &CptDesktop| -> Vec<String> {
+            app.current_tasks()
+                .iter()
+                .map(|task| task.title.clone())
+                .collect()
+        };
+        let before = titles(&app);
+        let first = app.current_tasks()[0].id.clone();
+        let second = app.current_tasks()[1].id.clone();
+
+        // Completing one task and renaming another are both drawn right away.
+        let _ = app.react(Message::RowSelected(first.clone()));
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::MarkDone));
+        let rename = MutationKind::Rename {
+            id: second.clone(),
+            title: "Renamed".into(),
+        };
+        app.apply_optimistic_update(std::slice::from_ref(&second), &rename);
+        assert_eq!(app.rollbacks.len(), 2);
+        assert!(app.current_tasks().iter().all(|task| task.id != first));
+
+        // The completion fails: its row comes back in place, the rename stays.
+        let _ = app.react(Message::MutationFinished(
+            MutationKind::Complete(vec![first.clone()]),
+            Err("database locked".into()),
+        ));
+        assert_eq!(app.rollbacks.len(), 1);
+        let now = titles(&app);
+        assert_eq!(now.len(), before.len());
+        assert_eq!(now[0], before[0]);
+        assert_eq!(now[1], "Renamed");
+        let toast = app.toasts.latest().unwrap();
+        assert!(matches!(toast.kind, ToastKind::Error));
+        assert_eq!(toast.action, Some(ToastAction::Retry));
+
+        // Retry runs it again; this time it goes through.
+        let _ = app.react(Message::RetryRequested);
+        assert!(app.retry.is_none());
+        assert_eq!(app.pending_mutations, 1);
+        assert!(app.current_tasks().iter().all(|task| task.id != first));
+        service.mark_done(std::slice::from_ref(&first)).unwrap();
+        let _ = app.react(Message::MutationFinished(
+            MutationKind::Complete(vec![first.clone()]),
+            Ok(()),
+        ));
+        assert_eq!(app.rollbacks.len(), 1, "the rename is still pending");
+        assert!(app.toasts.offers(ToastAction::Undo));
+    }
+
+    #[test]
+    fn auto_refresh_can_be_paused_and_its_interval_picked() {
+        let (mut app, service, _guard) = init_app();
+        let loading = |app: &CptDesktop| {
+            matches!(
+                app.views.get(&ViewTab::Inbox).map(|view| &view.state),
+                Some(LoadState::Loading)
+            )
+        };
+        assert_eq!(app.refresh_every(), Duration::from_secs(5));
+
+        let _ = app.react(Message::AutoRefreshToggled);
+        assert!(app.auto_refresh_paused);
+        let _ = app.react(Message::RefreshTick);
+        assert!(!loading(&app), "a paused view is left as it is");
+
+        // Resuming reloads right away; so does Refresh now while paused.
+        let _ = app.react(Message::AutoRefreshToggled);
+        assert!(loading(&app));
+        let _ = app.react(Message::AutoRefreshToggled);
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Inbox.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Inbox, Ok(snapshot)));
+        let _ = app.react(Message::CommandPaletteExecute(CommandActionId::Refresh));
+        assert!(loading(&app));
+
+        let _ = app.react(Message::RefreshIntervalPicked(30));
+        assert_eq!(app.refresh_every(), Duration::from_secs(30));
+        let _ = app.react(Message::RefreshTick);
+        let path = service.config().data_dir().join(WINDOW_STATE_FILE);
+        let saved = WindowStateStore::load(path).unwrap().state().clone();
+        assert_eq!(saved.refresh_interval, Some(30));
+    }
+
+    #[test]
+    fn focus_mode_times_the_task_and_completes_it() {
+        let (mut app, service, _guard) = init_app();
+        app.selected_task = None;
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::Focus));
+        assert!(app.focus.is_none());
+        assert!(app.toasts.latest().is_some());
+
+        let id = app.current_tasks()[0].id.clone();
+        let _ = app.react(Message::RowSelected(id.clone()));
+        let _ = app.react(Message::ContextMenuAction(CommandActionId::Focus));
+        assert_eq!(app.focus.as_ref().unwrap().task.id, id);
+
+        // The timer shows once the store has started it, on top of earlier tracked time.
+        let entry = service.start_timer(&id).unwrap();
+        let _ = app.react(Message::FocusTimerStarted(Ok((90, entry))));
+        let focus = app.focus.as_ref().unwrap();
+        assert!(focus.timer.is_some());
+        assert!(focus.total_secs(Utc::now()) >= 90);
+        assert_eq!(format_elapsed(90), "01:30");
+        assert_eq!(format_elapsed(3725), "1:02:05");
+
+        // Other keys are held back from the hidden list while focused.
+        let _ = app.react(Message::Keyboard(KeyboardEvent::KeyPressed {
+            key: Key::Named(Named::Delete),
+            modified_key: Key::Named(Named::Delete),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers: Modifiers::default(),
+            text: None,
+        }));
+        assert!(app.delete_confirmation.is_none());
+
+        // Done leaves focus and completes the task like any other completion.
+        let _ = app.react(Message::FocusDone);
+        assert!(app.focus.is_none());
+        assert_eq!(app.pending_mutations, 1);
+        assert!(app.current_tasks().iter().all(|task| task.id != id));
+    }
+
+    #[test]
+    fn waiting_groups_by_person_and_nudges_a_whole_group() {
+        let (mut app, service, _guard) = init_app();
+        for (title, person) in [
+            ("Invoice", Some("Sam")),
+            ("Keys", None),
+            ("Contract", Some("Ada")),
+            ("Quote", Some("Sam")),
+        ] {
+            service
+                .capture(TaskInput {
+                    text: vec![title.into()],
+                    status: Some(TaskStatus::Waiting),
+                    waiting_on: person.map(str::to_string),
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        let _ = app.react(Message::ViewRequested(ViewTab::Waiting));
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Waiting.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Waiting, Ok(snapshot)));
+
+        // People alphabetically (the sample data waits on Alex), whoever waits on no one last.
+        let groups: Vec<String> = app.current_tasks().into_iter().map(waiting_group).collect();
+        assert_eq!(groups, ["Ada", "Alex", "Sam", "Sam", "No one named"]);
+
+        let _ = app.react(Message::WaitingNudged(Some("Sam".into())));
+        assert_eq!(app.pending_mutations, 1);
+        let nudged: Vec<_> = app
+            .current_tasks()
+            .into_iter()
+            .filter(|task| {
+                task.notes
+                    .as_deref()
+                    .is_some_and(|notes| notes.contains("Sam"))
+            })
+            .map(|task| task.title.clone())
+            .collect();
+        assert_eq!(nudged.len(), 2);
+        assert!(nudged.contains(&"Invoice".to_string()));
+        assert!(nudged.contains(&"Quote".to_string()));
+    }
+
+    #[test]
+    fn pinned_views_save_filters_count_matches_and_reorder() {
+        let (mut app, service, _guard) = init_app();
+        // Only filters make a view worth pinning.
+        let _ = app.react(Message::PinDialogOpened(None));
+        assert!(app.pin_dialog.is_none());
+
+        let _ = app.react(Message::FilterToggled(FilterChip::Priority(3)));
+        let _ = app.react(Message::PinDialogOpened(None));
+        assert!(app
+            .pin_dialog
+            .as_ref()
+            .is_some_and(|dialog| dialog.use_current));
+        let _ = app.react(Message::PinNameChanged("Urgent".into()));
+        let _ = app.react(Message::PinConfirmed);
+        assert!(app.pin_dialog.is_none());
+
+        // The store writes the first free slot; saving pins it and counts its open tasks.
+        let urgent = app.filters.to_saved(1, "Urgent".into());
+        service.save_view(&urgent).unwrap();
+        let _ = app.react(Message::SavedViewSaved(Ok(urgent)));
+        let everything = ActiveFilters::default().to_saved(2, "Everything".into());
+        service.save_view(&everything).unwrap();
+        let _ = app.react(Message::SavedViewSaved(Ok(everything)));
+        assert_eq!(app.window_state.state().pinned_views, vec![1, 2]);
+
+        let open = service.list(&ListFilters::for_view(None)).unwrap().tasks;
+        let urgent_count = open.iter().filter(|task| task.priority >= 3).count();
+        let _ = app.react(Message::OpenTasksLoaded(Ok(open.clone())));
+        let counts: Vec<(u8, usize)> = app
+            .pinned_views()
+            .into_iter()
+            .map(|(view, count)| (view.slot, count))
+            .collect();
+        assert_eq!(counts, vec![(1, urgent_count), (2, open.len())]);
+
+        // Dropping a pinned view on another takes its place.
+        let _ = app.react(Message::PinDragStarted(2));
+        let _ = app.react(Message::PinDragEntered(1));
+        let _ = app.react(Message::DragReleased);
+        assert!(app.pin_drag.is_none());
+        assert_eq!(app.window_state.state().pinned_views, vec![2, 1]);
+
+        let _ = app.react(Message::FiltersCleared);
+        let _ = app.react(Message::SavedViewOpened(1));
+        assert_eq!(app.filters.chips(), vec![FilterChip::Priority(3)]);
+
+        // Removing it from the sidebar keeps the saved view for the terminal UI.
+        let _ = app.react(Message::PinnedMenuOpened(Some(1)));
+        let _ = app.react(Message::SavedViewPinToggled(1));
+        assert!(app.pinned_menu.is_none());
+        assert_eq!(app.window_state.state().pinned_views, vec![2]);
+        assert_eq!(service.saved_views().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn view_windows_show_their_own_view_and_share_changes() {
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::CommandPaletteExecute(CommandActionId::NewWindow));
+        assert_eq!(app.view_windows.len(), 1);
+        let window = app.view_windows[0].window;
+        assert_eq!(app.view_windows[0].tab, ViewTab::Inbox);
+
+        // Picking Next there loads it without moving the main window off Inbox.
+        let _ = app.react(Message::ViewWindowTabPicked(window, ViewTab::Next));
+        assert_eq!(app.view_windows[0].tab, ViewTab::Next);
+        assert_eq!(app.active, ViewTab::Inbox);
+        assert!(matches!(
+            app.views.get(&ViewTab::Next).map(|view| &view.state),
+            Some(LoadState::Loading)
+        ));
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Next.list_view()))
+            .unwrap();
+        let next = snapshot.tasks[0].id.clone();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Next, Ok(snapshot)));
+        assert!(app.view_window_view(window).is_some());
+
+        let _ = app.react(Message::ViewWindowTaskDone(next));
+        assert_eq!(app.pending_mutations, 1);
+
+        // Keys pressed in the further window leave the main window's selection alone.
+        let inbox = app.current_tasks()[0].id.clone();
+        let _ = app.react(Message::RowSelected(inbox));
+        let _ = app.react(Message::WindowFocused(window));
+        let _ = app.react(Message::Keyboard(KeyboardEvent::KeyPressed {
+            key: Key::Named(Named::Delete),
+            modified_key: Key::Named(Named::Delete),
+            physical_key: Physical::Unidentified(NativeCode::Unidentified),
+            location: Location::Standard,
+            modifiers: Modifiers::default(),
+            text: None,
+        }));
+        assert!(app.delete_confirmation.is_none());
+
+        let _ = app.react(Message::WindowCloseRequested(window));
+        assert!(app.view_windows.is_empty());
+    }
+
+    #[test]
+    fn long_lists_build_rows_near_the_scroll_position_and_page_ahead() {
+        let top = ListViewport {
+            offset: 0.0,
+            height: 380.0,
+            content: 38_000.0,
+        };
+        assert_eq!(top.visible_rows(1000), 0..25);
+        let middle = ListViewport {
+            offset: 3800.0,
+            ..top
+        };
+        assert_eq!(middle.visible_rows(1000), 75..125);
+        assert_eq!(middle.visible_rows(110), 75..110);
+        assert!(!middle.near_end());
+        // Before the first scroll report a screen is assumed.
+        assert_eq!(ListViewport::default().visible_rows(1000), 0..50);
+
+        let (mut app, service, _guard) = init_app();
+        let _ = app.react(Message::ViewRequested(ViewTab::Done));
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Done.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Done, Ok(snapshot)));
+        // Waiting lists its rows under who they wait on, and those headings take rows too.
+        let tasks = service.list(&ListFilters::for_view(None)).unwrap().tasks;
+        assert!(heading_count(ViewTab::Waiting, None, &tasks) >= 1);
+        assert_eq!(heading_count(ViewTab::All, None, &tasks), 0);
+        app.views.get_mut(&ViewTab::Done).unwrap().has_more = true;
+
+        let _ = app.react(Message::TaskListScrolled(middle));
+        assert_eq!(app.list_viewport, middle);
+        assert_eq!(app.views[&ViewTab::Done].pages, 1);
+        let _ = app.react(Message::TaskListScrolled(ListViewport {
+            offset: 37_400.0,
+            ..top
+        }));
+        assert_eq!(app.views[&ViewTab::Done].pages, 2);
+    }
+
+    #[test]
+    fn failed_view_load_shows_an_error_card_until_a_retry_works() {
+        let (mut app, service, _guard) = init_app();
+        let toasts = app.toasts.iter().count();
+        let _ = app.react(Message::ViewLoaded(
+            ViewTab::Inbox,
+            Err("database locked".into()),
+        ));
+        assert_eq!(
+            app.views[&ViewTab::Inbox].load_error(),
+            Some("database locked")
+        );
+        assert!(app.load_error_view().is_some());
+        // The card explains it, so no toast flashes as well.
+        assert_eq!(app.toasts.iter().count(), toasts);
+
+        // The card stays while the retry loads, then gives way to the list.
+        let _ = app.react(Message::CommandPaletteExecute(CommandActionId::Refresh));
+        assert!(matches!(
+            app.views[&ViewTab::Inbox].state,
+            LoadState::Loading
+        ));
+        assert!(app.load_error_view().is_some());
+        let snapshot = service
+            .list(&ListFilters::for_view(ViewTab::Inbox.list_view()))
+            .unwrap();
+        let _ = app.react(Message::ViewLoaded(ViewTab::Inbox, Ok(snapshot)));
+        assert!(app.load_error_view().is_none());
+
+        // A view that is not open still reports its failure as a toast.
+        app.ensure_view_entry(ViewTab::Next);
+        let _ = app.react(Message::ViewLoaded(ViewTab::Next, Err("disk gone".into())));
+        assert_eq!(app.toasts.iter().count(), toasts + 1);
+    }
+
+    #[test]
+    fn dropped_files_prefill_capture_with_a_linked_task() {
+        assert_eq!(
+            dropped_file_title(Path::new("/tmp/+ops_#q3 due:fri.txt")),
+            "ops q3 due fri"
+        );
+        assert_eq!(
+            file_link(Path::new("/tmp/My notes (v2).md")),
+            "[My notes (v2).md](file:///tmp/My%20notes%20%28v2%29.md)"
+        );
+
+        let (mut app, _service, _guard) = init_app();
+        let _ = app.react(Message::FileDropped(PathBuf::from(
+            "/tmp/Quarterly_report.pdf",
+        )));
+        assert!(app.capture.open);
+        assert_eq!(app.capture.text, "Quarterly report");
+
+        // A second file joins the same task rather than replacing the title.
+        let _ = app.react(Message::FileDropped(PathBuf::from("/tmp/figures.csv")));
+        assert_eq!(app.capture.text, "Quarterly report");
+        assert_eq!(app.capture.attachments.len(), 2);
+        let _ = app.react(Message::CaptureAttachmentRemoved(1));
+        assert_eq!(
+            app.capture.input().notes.as_deref(),
+            Some("Attached:\n- [Quarterly_report.pdf](file:///tmp/Quarterly_report.pdf)")
+        );
+
+        // Closing the capture bar forgets the files along with the text.
+        let _ = app.react(Message::CaptureToggled);
+        assert!(app.capture.attachments.is_empty());
+    }
 }
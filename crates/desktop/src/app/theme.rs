@@ -1,34 +1,79 @@
-//! Palette definitions so the desktop shell matches the cpt.run brand language.
-
-use iced::Color;
-
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct Palette {
-    pub(crate) background: Color,
-    pub(crate) surface: Color,
-    pub(crate) surface_muted: Color,
-    pub(crate) sidebar_background: Color,
-    pub(crate) sidebar_border: Color,
-    pub(crate) sidebar_hover: Color,
-    pub(crate) sidebar_active: Color,
-    pub(crate) sidebar_text: Color,
-    pub(crate) sidebar_text_muted: Color,
-    pub(crate) primary: Color,
-    pub(crate) primary_hover: Color,
-    pub(crate) primary_text: Color,
-    pub(crate) secondary_hover: Color,
-    pub(crate) secondary_text: Color,
-    pub(crate) ghost_hover: Color,
-    pub(crate) success: Color,
-    pub(crate) warning: Color,
-    pub(crate) danger: Color,
-    pub(crate) info: Color,
-    pub(crate) text_primary: Color,
-    pub(crate) text_secondary: Color,
-    pub(crate) text_muted: Color,
-    pub(crate) border: Color,
+//! Palette definitions so the desktop shell matches the cpt.run brand language, plus loadable
+//! color themes (bundled presets and user TOML files) layered on top of it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use iced::{Color, Theme};
+use serde::{Deserialize, Deserializer};
+
+/// Directory under the data dir holding user themes, one `*.toml` file each.
+pub(crate) const USER_THEMES_DIR: &str = "desktop-themes";
+/// The built-in theme; it follows the light/dark appearance.
+pub(crate) const DEFAULT_COLOR_THEME: &str = "cpt.run";
+
+const PRESETS: &[&str] = &[
+    include_str!("../../themes/gruvbox-dark.toml"),
+    include_str!("../../themes/nord.toml"),
+    include_str!("../../themes/solarized-dark.toml"),
+    include_str!("../../themes/solarized-light.toml"),
+];
+
+// Declares `Palette` and the matching all-optional `PaletteOverrides` from one field list so the
+// theme file format cannot drift from the palette.
+macro_rules! palette {
+    ($($field:ident),* $(,)?) => {
+        #[derive(Debug, Clone, Copy)]
+        pub(crate) struct Palette {
+            $(pub(crate) $field: Color,)*
+        }
+
+        /// Colors a theme sets for one appearance; anything left out keeps the built-in value.
+        #[derive(Debug, Clone, Default, Deserialize)]
+        #[serde(default, deny_unknown_fields)]
+        pub(crate) struct PaletteOverrides {
+            $($field: Option<HexColor>,)*
+        }
+
+        impl PaletteOverrides {
+            fn apply(&self, palette: &mut Palette) {
+                $(
+                    if let Some(HexColor(color)) = self.$field {
+                        palette.$field = color;
+                    }
+                )*
+            }
+        }
+    };
 }
 
+palette!(
+    background,
+    surface,
+    surface_muted,
+    sidebar_background,
+    sidebar_border,
+    sidebar_hover,
+    sidebar_active,
+    sidebar_text,
+    sidebar_text_muted,
+    primary,
+    primary_hover,
+    primary_text,
+    secondary_hover,
+    secondary_text,
+    ghost_hover,
+    success,
+    warning,
+    danger,
+    info,
+    text_primary,
+    text_secondary,
+    text_muted,
+    border,
+);
+
 impl Palette {
     pub(crate) fn for_theme(theme: &iced::Theme) -> Self {
         match theme {
@@ -86,3 +131,167 @@ impl Palette {
         }
     }
 }
+
+/// A `#rrggbb` or `#rrggbbaa` color from a theme file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HexColor(Color);
+
+impl HexColor {
+    fn parse(raw: &str) -> Option<Self> {
+        let hex = raw.strip_prefix('#')?;
+        if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+        Some(Self(Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            f32::from(alpha) / 255.0,
+        )))
+    }
+}
+
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Self::parse(&raw).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "expected a #rrggbb or #rrggbbaa color, got '{raw}'"
+            ))
+        })
+    }
+}
+
+/// One selectable color theme. A theme with only a `[dark]` or only a `[light]` table always uses
+/// that appearance; one with both (or neither) follows the System/Light/Dark choice.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ThemeDefinition {
+    pub(crate) name: String,
+    #[serde(default)]
+    dark: Option<PaletteOverrides>,
+    #[serde(default)]
+    light: Option<PaletteOverrides>,
+}
+
+impl ThemeDefinition {
+    fn builtin() -> Self {
+        Self {
+            name: DEFAULT_COLOR_THEME.to_string(),
+            dark: None,
+            light: None,
+        }
+    }
+
+    pub(crate) fn parse(raw: &str) -> Result<Self> {
+        let definition: Self = toml::from_str(raw)?;
+        if definition.name.trim().is_empty() {
+            bail!("theme name must not be empty");
+        }
+        Ok(definition)
+    }
+
+    /// The iced theme and palette to draw with when the appearance choice asks for `requested`.
+    pub(crate) fn resolve(&self, requested: Theme) -> (Theme, Palette) {
+        let theme = match (&self.dark, &self.light) {
+            (Some(_), None) => Theme::Dark,
+            (None, Some(_)) => Theme::Light,
+            _ => requested,
+        };
+        let mut palette = Palette::for_theme(&theme);
+        let overrides = match theme {
+            Theme::Light => &self.light,
+            _ => &self.dark,
+        };
+        if let Some(overrides) = overrides {
+            overrides.apply(&mut palette);
+        }
+        (theme, palette)
+    }
+}
+
+/// The built-in theme, the bundled presets, and any user themes, in picker order.
+#[derive(Debug, Clone)]
+pub(crate) struct ColorThemes {
+    themes: Vec<ThemeDefinition>,
+    pub(crate) user_dir: Option<PathBuf>,
+}
+
+impl Default for ColorThemes {
+    fn default() -> Self {
+        let mut themes = vec![ThemeDefinition::builtin()];
+        themes.extend(
+            PRESETS
+                .iter()
+                .map(|raw| ThemeDefinition::parse(raw).expect("bundled theme presets are valid")),
+        );
+        Self {
+            themes,
+            user_dir: None,
+        }
+    }
+}
+
+impl ColorThemes {
+    /// Bundled themes plus every `*.toml` in `dir`. A user theme named like a bundled one replaces
+    /// it; files that fail to parse are skipped and reported back.
+    pub(crate) fn load(dir: PathBuf) -> (Self, Vec<anyhow::Error>) {
+        let mut themes = Self::default();
+        let mut errors = Vec::new();
+        match user_theme_files(&dir) {
+            Ok(paths) => {
+                for path in paths {
+                    match read_theme(&path) {
+                        Ok(definition) => themes.insert(definition),
+                        Err(err) => errors.push(err),
+                    }
+                }
+            }
+            Err(err) => errors.push(err),
+        }
+        themes.user_dir = Some(dir);
+        (themes, errors)
+    }
+
+    pub(crate) fn names(&self) -> impl Iterator<Item = &str> {
+        self.themes.iter().map(|theme| theme.name.as_str())
+    }
+
+    /// The named theme, falling back to the built-in one when it no longer exists.
+    pub(crate) fn get(&self, name: Option<&str>) -> &ThemeDefinition {
+        name.and_then(|name| self.themes.iter().find(|theme| theme.name == name))
+            .unwrap_or(&self.themes[0])
+    }
+
+    fn insert(&mut self, definition: ThemeDefinition) {
+        match self
+            .themes
+            .iter_mut()
+            .find(|theme| theme.name == definition.name)
+        {
+            Some(existing) => *existing = definition,
+            None => self.themes.push(definition),
+        }
+    }
+}
+
+fn user_theme_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+fn read_theme(path: &Path) -> Result<ThemeDefinition> {
+    let raw =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    ThemeDefinition::parse(&raw).with_context(|| format!("invalid theme in {}", path.display()))
+}
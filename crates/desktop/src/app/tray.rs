@@ -0,0 +1,220 @@
+//! System tray presence: quick actions, inbox/overdue counts, and keeping the app alive when its
+//! window closes.
+
+use chrono::{DateTime, Utc};
+use cpt_core::model::{Task, TaskStatus};
+
+use crate::app::i18n::Catalog;
+
+/// Whether this build can show a tray icon at all; without one, closing the window quits.
+pub(crate) const TRAY_SUPPORTED: bool = cfg!(all(
+    feature = "tray",
+    any(target_os = "macos", target_os = "windows")
+));
+
+/// Counts surfaced next to the tray icon.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct TrayBadge {
+    pub(crate) inbox: usize,
+    pub(crate) overdue: usize,
+}
+
+impl TrayBadge {
+    /// Tallies open tasks; `tasks` is expected to be the All view.
+    pub(crate) fn from_tasks(tasks: &[Task], now: DateTime<Utc>) -> Self {
+        let mut badge = Self::default();
+        for task in tasks {
+            if matches!(task.status, TaskStatus::Done | TaskStatus::Canceled) {
+                continue;
+            }
+            if task.status == TaskStatus::Inbox {
+                badge.inbox += 1;
+            }
+            if task.due_at.is_some_and(|due| due < now) {
+                badge.overdue += 1;
+            }
+        }
+        badge
+    }
+
+    /// Menu line listing both counts; only the native tray shows it.
+    #[cfg_attr(
+        not(all(feature = "tray", any(target_os = "macos", target_os = "windows"))),
+        allow(dead_code)
+    )]
+    pub(crate) fn summary(&self, i18n: &Catalog) -> String {
+        i18n.tr_args(
+            "tray-summary",
+            &[
+                ("inbox", self.inbox.to_string()),
+                ("overdue", self.overdue.to_string()),
+            ],
+        )
+    }
+
+    /// Short text shown beside the icon in the macOS menu bar; empty when there is nothing to do.
+    #[cfg_attr(
+        not(all(feature = "tray", any(target_os = "macos", target_os = "windows"))),
+        allow(dead_code)
+    )]
+    pub(crate) fn title(&self) -> String {
+        match (self.inbox, self.overdue) {
+            (0, 0) => String::new(),
+            (inbox, 0) => inbox.to_string(),
+            (inbox, overdue) => format!("{inbox} · {overdue}!"),
+        }
+    }
+}
+
+/// Entries of the tray menu; only the native tray constructs them.
+#[cfg_attr(
+    not(all(feature = "tray", any(target_os = "macos", target_os = "windows"))),
+    allow(dead_code)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrayAction {
+    OpenWindow,
+    QuickCapture,
+    ToggleCloseToTray,
+    Quit,
+}
+
+#[cfg(all(feature = "tray", any(target_os = "macos", target_os = "windows")))]
+pub(crate) use native::Tray;
+
+#[cfg(not(all(feature = "tray", any(target_os = "macos", target_os = "windows"))))]
+pub(crate) use fallback::Tray;
+
+#[cfg(all(feature = "tray", any(target_os = "macos", target_os = "windows")))]
+mod native {
+    use anyhow::{Context, Result};
+    use tray_icon::menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+    use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+    use super::{TrayAction, TrayBadge};
+    use crate::app::desktop::icon_rgba;
+    use crate::app::i18n::Catalog;
+
+    pub(crate) struct Tray {
+        icon: TrayIcon,
+        counts: MenuItem,
+        open: MenuItem,
+        capture: MenuItem,
+        close_to_tray: CheckMenuItem,
+        quit: MenuItem,
+    }
+
+    impl Tray {
+        /// Must run on the main thread once the event loop is up.
+        pub(crate) fn new(close_to_tray: bool, i18n: &Catalog) -> Result<Self> {
+            let counts = MenuItem::new(TrayBadge::default().summary(i18n), false, None);
+            let open = MenuItem::new(i18n.tr("tray-open"), true, None);
+            let capture = MenuItem::new(i18n.tr("tray-quick-capture"), true, None);
+            let close_item =
+                CheckMenuItem::new(i18n.tr("tray-keep-running"), true, close_to_tray, None);
+            let quit = MenuItem::new(i18n.tr("tray-quit"), true, None);
+
+            let menu = Menu::new();
+            menu.append_items(&[
+                &counts,
+                &PredefinedMenuItem::separator(),
+                &open,
+                &capture,
+                &close_item,
+                &PredefinedMenuItem::separator(),
+                &quit,
+            ])
+            .context("failed to build tray menu")?;
+
+            let (rgba, width, height) = icon_rgba().context("failed to decode tray icon")?;
+            let image = Icon::from_rgba(rgba, width, height).context("invalid tray icon")?;
+            let icon = TrayIconBuilder::new()
+                .with_menu(Box::new(menu))
+                .with_tooltip("cpt.run")
+                .with_icon(image)
+                .build()
+                .context("failed to create tray icon")?;
+
+            Ok(Self {
+                icon,
+                counts,
+                open,
+                capture,
+                close_to_tray: close_item,
+                quit,
+            })
+        }
+
+        pub(crate) fn set_badge(&self, badge: TrayBadge, i18n: &Catalog) {
+            let summary = badge.summary(i18n);
+            self.counts.set_text(&summary);
+            let _ = self.icon.set_tooltip(Some(format!("cpt.run — {summary}")));
+            self.icon.set_title(Some(badge.title()));
+        }
+
+        pub(crate) fn set_close_to_tray(&self, enabled: bool) {
+            self.close_to_tray.set_checked(enabled);
+        }
+
+        /// Relabels the menu after a language change; the counts follow on the next badge.
+        pub(crate) fn set_locale(&self, i18n: &Catalog) {
+            self.open.set_text(i18n.tr("tray-open"));
+            self.capture.set_text(i18n.tr("tray-quick-capture"));
+            self.close_to_tray.set_text(i18n.tr("tray-keep-running"));
+            self.quit.set_text(i18n.tr("tray-quit"));
+        }
+
+        /// Menu clicks since the last poll.
+        pub(crate) fn poll(&self) -> Vec<TrayAction> {
+            let mut actions = Vec::new();
+            while let Ok(event) = MenuEvent::receiver().try_recv() {
+                let action = if event.id == *self.open.id() {
+                    TrayAction::OpenWindow
+                } else if event.id == *self.capture.id() {
+                    TrayAction::QuickCapture
+                } else if event.id == *self.close_to_tray.id() {
+                    TrayAction::ToggleCloseToTray
+                } else if event.id == *self.quit.id() {
+                    TrayAction::Quit
+                } else {
+                    continue;
+                };
+                actions.push(action);
+            }
+            actions
+        }
+    }
+}
+
+#[cfg(not(all(feature = "tray", any(target_os = "macos", target_os = "windows"))))]
+mod fallback {
+    use anyhow::{bail, Result};
+
+    use super::{TrayAction, TrayBadge};
+    use crate::app::i18n::Catalog;
+
+    /// Stand-in for platforms without tray support; it can never be constructed.
+    pub(crate) enum Tray {}
+
+    impl Tray {
+        pub(crate) fn new(_close_to_tray: bool, _i18n: &Catalog) -> Result<Self> {
+            bail!("tray icons are not supported in this build")
+        }
+
+        pub(crate) fn set_badge(&self, _badge: TrayBadge, _i18n: &Catalog) {
+            match *self {}
+        }
+
+        pub(crate) fn set_close_to_tray(&self, _enabled: bool) {
+            match *self {}
+        }
+
+        pub(crate) fn set_locale(&self, _i18n: &Catalog) {
+            match *self {}
+        }
+
+        pub(crate) fn poll(&self) -> Vec<TrayAction> {
+            match *self {}
+        }
+    }
+}
@@ -1,44 +1,587 @@
 //! Core update loop translating user interactions into state changes.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
 use std::time::{Duration as StdDuration, Instant};
 
-use chrono::{DateTime, Duration as ChronoDuration, Utc};
-use cpt_core::model::{AddOutcome, Task, TaskStatus};
-use iced::keyboard::{key::Named, Event as KeyboardEvent, Key};
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Local, Months, NaiveDate, Utc};
+use cpt_core::model::{
+    AddOutcome, EnergyLevel, ListFilters, SavedView, Task, TaskStatus, TimeEntry,
+};
+use cpt_core::parser::{parse_date_spec, parse_duration_minutes};
+use cpt_core::services::ExportFormat;
+use iced::keyboard::{key::Named, Event as KeyboardEvent, Key, Modifiers};
 use iced::widget::operation::{focus, move_cursor_to_end};
 use iced::widget::Id;
-use iced::Theme;
-
-use crate::app::commands::{capture_command, load_view_command, mutation_command};
-use crate::app::helpers::capitalize;
+use iced::{window, Theme};
+
+use crate::app::calendar::{move_to_day, placement, view_range};
+use crate::app::commands::{
+    capture_command, capture_many_command, export_command, facets_command, fetch_task_command,
+    load_page_command, load_view_command, mutation_command, open_tasks_command, print_command,
+    print_view_command, project_tasks_command, save_view_command, search_text,
+    seed_sample_data_command, start_timer_command, stats_command, stop_timer_command,
+    view_counts_command, view_filters, workspace_empty_command,
+};
+use crate::app::deep_link::DeepLink;
+use crate::app::desktop::{quick_capture_window_settings, view_window_settings};
+use crate::app::external_editor::ExternalEdit;
+use crate::app::helpers::{
+    capitalize, capture_line, detect_theme, dropped_file_title, format_minutes,
+    group_by_waiting_on, matches_search, waiting_person,
+};
+use crate::app::hotkey::CaptureHotkey;
+use crate::app::i18n::Catalog;
 use crate::app::message::{Effect, Message};
+use crate::app::notes::toggle_checkbox;
+use crate::app::print::PrintSheet;
+use crate::app::reminders::ReminderAction;
+use crate::app::shortcuts::{KeyBinding, ShortcutAction};
 use crate::app::state::{
-    CommandActionId, InlineEditState, InlineEditableField, LoadState, MutationKind, StatusToast,
-    ToastKind, ViewTab,
+    ActiveFilters, BulkCapture, CaptureState, ColumnResize, ColumnSort, CommandActionId,
+    ContextMenuState, DeferDialog, DeleteConfirmation, DragState, DropTarget, ExportDialog,
+    ExportScope, ExportedFile, FocusRegion, FocusSession, HistoryRecall, InlineEditState,
+    InlineEditableField, KeyboardFocus, ListViewport, LoadState, MutationKind, NotesDraft,
+    PinDialog, PinDrag, ProjectPage, QuickCapture, Rollback, StatusToast, ToastAction, ToolbarItem,
+    UndoEntry, ViewCounts, ViewTab, ViewWindow, SAVED_VIEW_SLOTS,
 };
-use crate::app::theme::Palette;
+use crate::app::theme::DEFAULT_COLOR_THEME;
+use crate::app::tray::{Tray, TrayAction, TrayBadge};
+use crate::app::views::column_labels;
+use crate::app::window_state::{ThemeChoice, ZOOM_STEP};
 use crate::telemetry::Event as TelemetryEvent;
 
 use super::desktop::CptDesktop;
 
 const TITLE_DOUBLE_CLICK_WINDOW: StdDuration = StdDuration::from_millis(350);
 const NONE_OPTION_LABEL: &str = "(none)";
+const DATE_INPUT_FORMAT: &str = "%Y-%m-%d";
+/// How far "Snooze 1h" on a reminder pushes the task's defer date.
+const SNOOZE_HOURS: i64 = 1;
 const PRIORITY_CHOICES: &[(u8, &str)] = &[(0, "None"), (1, "Low"), (2, "Medium"), (3, "High")];
 
 impl CptDesktop {
     pub(super) fn react(&mut self, message: Message) -> Effect {
         self.prune_toast();
+        let effect = self.dispatch(message);
+        let save_notes = self.sync_notes_draft();
+        Effect::batch(vec![effect, save_notes])
+    }
+
+    fn dispatch(&mut self, message: Message) -> Effect {
         match message {
             Message::ViewRequested(tab) => self.switch_view(tab),
             Message::ViewLoaded(tab, result) => self.handle_view_loaded(tab, result),
+            Message::PageLoaded(tab, result) => self.handle_page_loaded(tab, result),
+            Message::ViewCounted(tab, result) => {
+                match result {
+                    Ok(counts) => {
+                        if let Some(store) = self.views.get_mut(&tab) {
+                            store.counts = Some(counts);
+                        }
+                    }
+                    Err(err) => {
+                        tracing::warn!(error = %err, view = tab.title(), "failed to count view rows")
+                    }
+                }
+                Effect::none()
+            }
             Message::RefreshTick => self.on_refresh_tick(),
+            Message::LoadMoreRequested => self.load_more(),
+            Message::TaskListScrolled(viewport) => {
+                self.list_viewport = viewport;
+                // Paged lists fetch their next page before the scroll reaches the end.
+                if viewport.near_end() {
+                    self.load_more()
+                } else {
+                    Effect::none()
+                }
+            }
             Message::ToggleTheme => self.toggle_theme(),
+            Message::SystemThemePolled => {
+                Effect::perform(async { detect_theme() }, Message::SystemThemeDetected)
+            }
+            Message::SystemThemeDetected(theme) => {
+                // A late answer must not undo an override picked meanwhile.
+                if self.window_state.state().theme == ThemeChoice::System {
+                    self.set_theme(theme);
+                }
+                Effect::none()
+            }
+            Message::SettingsToggled => {
+                self.settings_open = !self.settings_open;
+                Effect::none()
+            }
+            Message::AppearancePicked(choice) => {
+                self.pick_appearance(choice);
+                Effect::none()
+            }
+            Message::ColorThemePicked(name) => {
+                self.pick_color_theme(name);
+                Effect::none()
+            }
+            Message::TelemetrySinkPicked(sink) => {
+                let data_dir = self
+                    .service
+                    .as_ref()
+                    .map(|service| service.config().data_dir().to_path_buf());
+                self.telemetry.configure(sink, data_dir.as_deref());
+                self.window_state.update(|state| state.telemetry = sink);
+                Effect::none()
+            }
+            Message::TelemetryEventsToggled => {
+                self.telemetry_events_open = !self.telemetry_events_open;
+                self.settings_open = false;
+                Effect::none()
+            }
+            Message::ReduceMotionToggled(enabled) => {
+                self.window_state
+                    .update(|state| state.reduce_motion = enabled);
+                Effect::none()
+            }
+            Message::RefreshIntervalPicked(seconds) => {
+                self.window_state
+                    .update(|state| state.refresh_interval = Some(seconds));
+                Effect::none()
+            }
+            Message::AutoRefreshToggled => {
+                self.auto_refresh_paused = !self.auto_refresh_paused;
+                // Resuming catches up straight away rather than on the next tick.
+                if self.auto_refresh_paused {
+                    Effect::none()
+                } else {
+                    self.refresh_active_view()
+                }
+            }
+            Message::ExternalEditorChanged(command) => {
+                let command = (!command.trim().is_empty()).then_some(command);
+                self.window_state
+                    .update(|state| state.external_editor = command);
+                Effect::none()
+            }
+            Message::LocalePicked(locale) => {
+                self.i18n = Catalog::new(locale.as_deref());
+                self.window_state.update(|state| state.locale = locale);
+                match &self.tray {
+                    Some(tray) => {
+                        tray.set_locale(&self.i18n);
+                        self.refresh_open_tasks()
+                    }
+                    None => Effect::none(),
+                }
+            }
+            Message::ShortcutsToggled => {
+                self.shortcuts_open = !self.shortcuts_open;
+                self.settings_open = false;
+                self.shortcut_recording = None;
+                Effect::none()
+            }
+            Message::ShortcutRecordStarted(action) => {
+                self.shortcut_recording = Some(action);
+                Effect::none()
+            }
+            Message::ShortcutReset(action) => {
+                self.shortcut_recording = None;
+                if let Err(err) = self.shortcuts.reset(action) {
+                    self.toasts.push(StatusToast::error(
+                        self.i18n
+                            .tr_args("toast-shortcuts-save-failed", &[("error", err.to_string())]),
+                    ));
+                }
+                Effect::none()
+            }
+            Message::ViewModePicked(mode) => {
+                self.window_state.update(|state| state.view_mode = mode);
+                self.refresh_calendar()
+            }
+            Message::CalendarSpanPicked(span) => {
+                self.calendar.span = span;
+                self.window_state.update(|state| state.calendar_span = span);
+                self.refresh_calendar()
+            }
+            Message::CalendarShifted(0) => {
+                let today = Local::now().date_naive();
+                self.calendar.anchor = today;
+                self.calendar.selected_day = Some(today);
+                self.refresh_calendar()
+            }
+            Message::CalendarShifted(steps) => {
+                self.calendar.shift(steps);
+                self.refresh_calendar()
+            }
+            Message::CalendarDaySelected(day) => {
+                self.calendar.selected_day = Some(day);
+                Effect::none()
+            }
+            Message::ZoomChanged(zoom) => {
+                self.window_state.update(|state| state.set_zoom(zoom));
+                Effect::none()
+            }
+            Message::SearchChanged(query) => {
+                self.search = query;
+                self.sync_selection_with_view();
+                self.reload_searched_view()
+            }
+            Message::SearchCleared => self.clear_search(),
+            Message::UndoRequested => self.undo_last_mutation(),
+            Message::RetryRequested => self.retry_mutation(),
+            Message::WaitingNudged(person) => self.nudge_waiting(person),
+            Message::FocusTimerStarted(result) => self.finish_timer_start(result),
+            Message::FocusTimerStopped(result) => {
+                self.finish_timer_stop(result);
+                Effect::none()
+            }
+            Message::FocusTimerToggled => self.toggle_focus_timer(),
+            // Only redraws; the elapsed time is worked out from the entry's start.
+            Message::FocusTick => Effect::none(),
+            Message::FocusDone => {
+                let Some(id) = self.focus.as_ref().map(|focus| focus.task.id.clone()) else {
+                    return Effect::none();
+                };
+                let stop = self.exit_focus();
+                let complete = self.apply_status_change(vec![id], MutationKind::Complete);
+                Effect::batch(vec![stop, complete])
+            }
+            Message::FocusDefer => {
+                let Some(id) = self.focus.as_ref().map(|focus| focus.task.id.clone()) else {
+                    return Effect::none();
+                };
+                let stop = self.exit_focus();
+                self.selected_task = Some(id);
+                self.open_defer_dialog();
+                stop
+            }
+            Message::FocusExited => self.exit_focus(),
+            Message::ToastHovered(id, hovered) => {
+                self.toasts.set_hovered(id, hovered);
+                Effect::none()
+            }
+            Message::ToastDismissed(id) => {
+                self.toasts.dismiss(id);
+                Effect::none()
+            }
+            Message::DeleteConfirmed => self.confirm_delete(),
+            Message::TrayRequested => self.start_tray(),
+            Message::TrayPolled => self.poll_tray(),
+            Message::OpenTasksLoaded(Ok(tasks)) => {
+                let now = Utc::now();
+                if let Some(tray) = &self.tray {
+                    tray.set_badge(TrayBadge::from_tasks(&tasks, now), &self.i18n);
+                }
+                if let Some(notifier) = &self.notifier {
+                    for reminder in self.reminders.check(&tasks, now) {
+                        notifier.notify(reminder, &self.i18n);
+                    }
+                }
+                self.open_tasks = tasks;
+                Effect::none()
+            }
+            Message::OpenTasksLoaded(Err(err)) => {
+                tracing::warn!(error = %err, "failed to load open tasks for the tray and reminders");
+                Effect::none()
+            }
+            Message::RemindersPolled => self.poll_reminders(),
+            Message::WindowCloseRequested(id) => self.close_window(id),
+            Message::WindowFocused(id) => {
+                self.focused_window = Some(id);
+                Effect::none()
+            }
+            Message::WindowUnfocused(id) => {
+                // Clicking away from an empty quick-capture window puts it away.
+                let empty = self
+                    .quick_capture
+                    .as_ref()
+                    .is_some_and(|quick| quick.capture.text.trim().is_empty());
+                if self.is_quick_capture(id) && empty {
+                    self.dismiss_quick_capture()
+                } else {
+                    Effect::none()
+                }
+            }
+            Message::WindowChanged(id, change) => {
+                if self.window_id != Some(id) {
+                    return Effect::none();
+                }
+                window::is_maximized(id)
+                    .map(move |maximized| Message::WindowChangeChecked(change, maximized))
+            }
+            Message::WindowChangeChecked(change, maximized) => {
+                self.window_state
+                    .update(|state| state.apply(change, maximized));
+                Effect::none()
+            }
+            Message::HotkeyRequested => {
+                self.start_hotkey();
+                Effect::none()
+            }
+            Message::SampleDataRequested => match self.service.clone() {
+                Some(service) => seed_sample_data_command(service),
+                None => Effect::none(),
+            },
+            Message::SampleDataLoaded(Ok(seeded)) => {
+                // Nothing is added to a database that already has tasks.
+                self.workspace_empty = false;
+                if seeded {
+                    self.toasts
+                        .push(StatusToast::info(self.i18n.tr("toast-sample-data-loaded")));
+                }
+                self.refresh_active_view()
+            }
+            Message::SampleDataLoaded(Err(err)) => {
+                self.toasts.push(StatusToast::error(
+                    self.i18n
+                        .tr_args("toast-sample-data-failed", &[("error", err)]),
+                ));
+                Effect::none()
+            }
+            Message::WorkspaceChecked(Ok(empty)) => {
+                self.workspace_empty = empty;
+                Effect::none()
+            }
+            Message::WorkspaceChecked(Err(err)) => {
+                tracing::warn!(error = %err, "failed to check for an empty database");
+                Effect::none()
+            }
+            Message::LinksPolled => {
+                let urls = self
+                    .links
+                    .as_ref()
+                    .map(|links| links.poll())
+                    .unwrap_or_default();
+                Effect::batch(urls.into_iter().map(|url| self.open_link(&url)))
+            }
+            Message::LinkOpened(url) => self.open_link(&url),
+            Message::LinkedTaskLoaded(_, Ok(Some(task))) => self.show_linked_task(task),
+            Message::LinkedTaskLoaded(id, Ok(None)) => {
+                self.toasts.push(StatusToast::error(
+                    self.i18n.tr_args("toast-link-task-missing", &[("id", id)]),
+                ));
+                Effect::none()
+            }
+            Message::LinkedTaskLoaded(_, Err(err)) => {
+                self.toasts.push(StatusToast::error(
+                    self.i18n.tr_args("toast-link-invalid", &[("error", err)]),
+                ));
+                Effect::none()
+            }
+            Message::HotkeyPolled => {
+                if self.hotkey.as_ref().is_some_and(|hotkey| hotkey.pressed()) {
+                    self.open_quick_capture()
+                } else {
+                    Effect::none()
+                }
+            }
+            Message::ViewWindowOpened(id) => match self.view_window(id).copied() {
+                Some(view_window) => self.load_view(view_window.tab),
+                None => Effect::none(),
+            },
+            Message::ViewWindowTabPicked(id, tab) => {
+                let Some(view_window) = self
+                    .view_windows
+                    .iter_mut()
+                    .find(|view_window| view_window.window == id)
+                else {
+                    return Effect::none();
+                };
+                view_window.tab = tab;
+                self.load_view(tab)
+            }
+            Message::ViewWindowTaskDone(id) => {
+                self.apply_status_change(vec![id], MutationKind::Complete)
+            }
+            Message::ViewWindowTaskShown(id) => {
+                let task = self
+                    .views
+                    .values()
+                    .filter_map(|view| view.snapshot.as_ref())
+                    .flat_map(|snapshot| &snapshot.tasks)
+                    .find(|task| task.id == id)
+                    .cloned();
+                match task {
+                    Some(task) => {
+                        Effect::batch(vec![self.show_window(), self.show_linked_task(task)])
+                    }
+                    None => Effect::none(),
+                }
+            }
+            Message::QuickCaptureOpened => match &self.quick_capture {
+                Some(quick) => Effect::batch(vec![
+                    window::gain_focus(quick.window),
+                    focus(self.quick_capture_input_id.clone()),
+                ]),
+                None => Effect::none(),
+            },
+            Message::QuickCaptureTextChanged(value) => {
+                if let Some(quick) = self.quick_capture.as_mut() {
+                    quick.capture.on_text_changed(value);
+                }
+                Effect::none()
+            }
+            Message::QuickCaptureSubmit => self.submit_quick_capture(),
+            Message::QuickCaptureCompleted(result) => self.finish_quick_capture(result),
+            Message::StatsLoaded(result) => {
+                match result {
+                    Ok(stats) => self.stats = Some(stats),
+                    Err(err) => {
+                        self.toasts.push(StatusToast::error(
+                            self.i18n
+                                .tr_args("toast-stats-load-failed", &[("error", err.to_string())]),
+                        ));
+                    }
+                }
+                Effect::none()
+            }
+            Message::ExportRequested => {
+                self.open_export_dialog();
+                Effect::none()
+            }
+            Message::ExportFormatPicked(format) => {
+                if let Some(dialog) = &mut self.export_dialog {
+                    dialog.format = format;
+                }
+                Effect::none()
+            }
+            Message::ExportScopePicked(scope) => {
+                if let Some(dialog) = &mut self.export_dialog {
+                    dialog.scope = scope;
+                }
+                Effect::none()
+            }
+            Message::ExportCancelled => {
+                self.export_dialog = None;
+                Effect::none()
+            }
+            Message::ExportConfirmed => self.start_export(),
+            Message::ExportFinished(result) => {
+                self.finish_export(result);
+                Effect::none()
+            }
+            Message::DeferDateChanged(date) => {
+                if let Some(dialog) = &mut self.defer_dialog {
+                    dialog.date = date;
+                    dialog.error = None;
+                }
+                Effect::none()
+            }
+            Message::DeferPresetPicked(spec) => self.confirm_defer(spec),
+            Message::DeferConfirmed => {
+                let date = self
+                    .defer_dialog
+                    .as_ref()
+                    .map(|dialog| dialog.date.clone())
+                    .unwrap_or_default();
+                self.confirm_defer(&date)
+            }
+            Message::DeferCancelled => {
+                self.defer_dialog = None;
+                Effect::none()
+            }
+            Message::PrintFinished(result) => {
+                self.toasts.push(match result {
+                    Ok(path) => StatusToast::info(self.i18n.tr_args(
+                        "toast-print-opened",
+                        &[("path", path.display().to_string())],
+                    )),
+                    Err(err) => StatusToast::error(
+                        self.i18n.tr_args("toast-print-failed", &[("error", err)]),
+                    ),
+                });
+                Effect::none()
+            }
+            Message::PrintViewLoaded(result) => match result {
+                Ok(snapshot) => {
+                    let sheet = self.view_sheet(snapshot);
+                    self.print_sheet(sheet)
+                }
+                Err(err) => {
+                    self.toasts.push(StatusToast::error(
+                        self.i18n.tr_args("toast-print-failed", &[("error", err)]),
+                    ));
+                    Effect::none()
+                }
+            },
+            Message::ExportedFileOpened => {
+                self.open_exported_file();
+                Effect::none()
+            }
+            Message::ProjectOpened(name) => {
+                self.project_page = Some(ProjectPage::new(name));
+                self.load_project_page()
+            }
+            Message::ProjectClosed => {
+                self.project_page = None;
+                Effect::none()
+            }
+            Message::ProjectTasksLoaded(name, result) => {
+                if let Some(page) = self.project_page.as_mut().filter(|page| page.name == name) {
+                    match result {
+                        Ok(tasks) => {
+                            page.tasks = tasks;
+                            page.state = LoadState::Idle;
+                        }
+                        Err(err) => page.state = LoadState::Error(err),
+                    }
+                }
+                Effect::none()
+            }
+            Message::ProjectCaptureChanged(value) => {
+                if let Some(page) = self.project_page.as_mut() {
+                    page.capture.on_text_changed(value);
+                }
+                Effect::none()
+            }
+            Message::ProjectCaptureSubmit => self.submit_project_capture(),
+            Message::ProjectCaptureCompleted(result) => self.finish_project_capture(result),
+            Message::DeleteCancelled => {
+                self.delete_confirmation = None;
+                Effect::none()
+            }
             Message::CaptureToggled => self.toggle_capture(),
             Message::CaptureTextChanged(value) => {
+                // Typing over a multi-line paste means it is meant as one task.
+                self.capture.bulk = None;
+                self.capture.on_text_changed(value);
+                Effect::none()
+            }
+            Message::CapturePasted(value) => {
                 self.capture.on_text_changed(value);
+                iced::clipboard::read().map(Message::CaptureClipboardRead)
+            }
+            Message::CaptureClipboardRead(pasted) => {
+                self.capture.bulk = pasted
+                    .as_deref()
+                    .and_then(|pasted| BulkCapture::from_paste(pasted, &self.i18n));
+                Effect::none()
+            }
+            Message::BulkCaptureDismissed => {
+                self.capture.bulk = None;
+                self.focus_capture_input()
+            }
+            Message::FileDropped(path) => self.capture_dropped_file(path),
+            Message::CaptureAttachmentRemoved(index) => {
+                if index < self.capture.attachments.len() {
+                    self.capture.attachments.remove(index);
+                }
+                Effect::none()
+            }
+            Message::CaptureRecentToggled => {
+                self.capture.recent_open = !self.capture.recent_open;
+                Effect::none()
+            }
+            Message::CaptureRecentPicked(line) => {
+                self.capture.bulk = None;
+                self.capture.on_text_changed(line);
+                self.capture.recent_open = false;
+                self.focus_capture_input()
+            }
+            Message::CaptureHistoryCleared => {
+                if let Err(err) = self.capture_history.clear() {
+                    tracing::warn!(error = %err, "failed to clear the capture history");
+                }
+                self.capture.recent_open = false;
                 Effect::none()
             }
+            Message::BulkCaptureCompleted(result) => self.finish_bulk_capture(result),
             Message::CaptureSubmit => self.submit_capture(),
             Message::CaptureCompleted(result) => self.finish_capture(result),
             Message::CommandPaletteToggled => self.toggle_command_palette(),
@@ -48,7 +591,7 @@ impl CptDesktop {
             }
             Message::CommandPaletteQueryChanged(value) => {
                 self.command_palette.query = value;
-                self.command_palette.clamp_selection();
+                self.command_palette.clamp_selection(&self.i18n);
                 Effect::none()
             }
             Message::CommandPaletteExecute(action) => {
@@ -65,106 +608,1333 @@ impl CptDesktop {
                 {
                     self.inline_edit = None;
                 }
+                self.context_menu = None;
                 self.selected_task = Some(id);
                 Effect::none()
             }
-            Message::TaskTitlePressed(id) => self.handle_title_press(id),
-            Message::TaskProjectPressed(id) => {
-                self.start_field_edit(id, InlineEditableField::Project)
+            Message::ContextMenuOpened(id) => {
+                self.cancel_inline_edit();
+                self.selected_task = Some(id.clone());
+                self.context_menu = Some(ContextMenuState {
+                    task_id: id,
+                    defer_open: false,
+                });
+                Effect::none()
             }
-            Message::TaskContextsPressed(id) => {
-                self.start_field_edit(id, InlineEditableField::Contexts)
+            Message::ContextMenuClosed => {
+                self.context_menu = None;
+                Effect::none()
             }
-            Message::TaskTagsPressed(id) => self.start_field_edit(id, InlineEditableField::Tags),
-            Message::TaskPriorityPressed(id) => {
-                self.start_field_edit(id, InlineEditableField::Priority)
+            Message::ContextMenuDeferToggled => {
+                if let Some(menu) = self.context_menu.as_mut() {
+                    menu.defer_open = !menu.defer_open;
+                }
+                Effect::none()
             }
-            Message::InlineEditChanged(value) => {
-                self.update_inline_edit(value);
+            Message::ContextMenuAction(action) => {
+                self.context_menu = None;
+                self.handle_action(action)
+            }
+            Message::DragStarted(id) => {
+                self.context_menu = None;
+                self.selected_task = Some(id.clone());
+                self.drag = Some(DragState {
+                    task_id: id,
+                    target: None,
+                });
                 Effect::none()
             }
-            Message::InlineEditSubmitted => self.submit_inline_edit(),
-            Message::InlineEditOptionSelected(option) => self.handle_inline_option(option),
-            Message::Keyboard(event) => self.handle_keyboard(event),
-        }
-    }
-
-    pub(super) fn handle_title_press(&mut self, id: String) -> Effect {
-        let now = Instant::now();
-        let is_double_click = self
-            .last_title_click
-            .as_ref()
-            .filter(|(prev_id, prev_time)| {
-                prev_id == &id && now.duration_since(*prev_time) <= TITLE_DOUBLE_CLICK_WINDOW
-            })
-            .is_some();
-
-        self.last_title_click = Some((id.clone(), now));
-        self.selected_task = Some(id.clone());
-
-        if is_double_click {
-            self.last_title_click = None;
-            return self.start_title_edit(id);
-        }
-
-        Effect::none()
-    }
-
-    pub(super) fn handle_view_loaded(
-        &mut self,
-        tab: ViewTab,
-        result: Result<cpt_core::ViewSnapshot, String>,
-    ) -> Effect {
-        if let Some(store) = self.views.get_mut(&tab) {
-            match result {
-                Ok(snapshot) => {
-                    let task_count = snapshot.tasks.len();
-                    store.last_refreshed = Some(Instant::now());
-                    store.state = LoadState::Idle;
-                    store.version = store.version.wrapping_add(1);
-                    store.snapshot = Some(snapshot);
-                    self.telemetry.record(TelemetryEvent::RefreshCompleted {
-                        view: tab.title().into(),
-                        count: task_count,
-                    });
-                    if tab == self.active {
-                        self.sync_selection_with_view();
+            Message::DragTargetEntered(target) => {
+                if let Some(drag) = self.drag.as_mut() {
+                    drag.target = Some(target);
+                }
+                Effect::none()
+            }
+            Message::DragTargetLeft(target) => {
+                if let Some(drag) = self.drag.as_mut() {
+                    // Enter for the next entry can arrive before exit from the previous one.
+                    if drag.target.as_ref() == Some(&target) {
+                        drag.target = None;
                     }
                 }
-                Err(err) => {
-                    store.state = LoadState::Error(err.clone());
-                    self.telemetry.record(TelemetryEvent::RefreshFailed {
-                        view: tab.title().into(),
-                        error: err.clone(),
+                Effect::none()
+            }
+            Message::DragReleased => {
+                if let Some(drag) = self.pin_drag.take() {
+                    self.move_pinned_view(drag);
+                    return Effect::none();
+                }
+                if self.column_resize.take().is_some() {
+                    let result = self.columns.save();
+                    self.report_layout_error(result);
+                    return Effect::none();
+                }
+                self.finish_drag()
+            }
+            Message::ColumnMenuToggled => {
+                self.column_menu_open = !self.column_menu_open;
+                Effect::none()
+            }
+            Message::ColumnMoved(label, delta) => {
+                let defaults = column_labels(self.active);
+                let result = self.columns.update(self.active, |layout| {
+                    layout.move_column(&defaults, label, delta)
+                });
+                self.report_layout_error(result);
+                Effect::none()
+            }
+            Message::ColumnVisibilityToggled(label) => {
+                let result = self
+                    .columns
+                    .update(self.active, |layout| layout.toggle_hidden(label));
+                self.report_layout_error(result);
+                Effect::none()
+            }
+            Message::ColumnsReset => {
+                let result = self.columns.reset(self.active);
+                self.report_layout_error(result);
+                Effect::none()
+            }
+            Message::ColumnResizeStarted(label, width) => {
+                self.column_resize = Some(ColumnResize {
+                    label,
+                    start_width: width,
+                    origin: None,
+                });
+                Effect::none()
+            }
+            Message::ColumnResizeMoved(x) => {
+                if let Some(resize) = self.column_resize.as_mut() {
+                    let origin = *resize.origin.get_or_insert(x);
+                    let width = resize.start_width + (x - origin);
+                    let label = resize.label;
+                    self.columns.preview_width(self.active, label, width);
+                }
+                Effect::none()
+            }
+            Message::DetailToggled => self.handle_action(CommandActionId::ToggleDetails),
+            Message::FilterPanelToggled => self.handle_action(CommandActionId::ToggleFilters),
+            Message::FacetsLoaded(Ok(facets)) => {
+                self.facets = Some(facets);
+                Effect::none()
+            }
+            Message::FacetsLoaded(Err(err)) => {
+                self.toasts.push(StatusToast::error(
+                    self.i18n
+                        .tr_args("toast-filters-load-failed", &[("error", err.to_string())]),
+                ));
+                Effect::none()
+            }
+            Message::FilterToggled(chip) => {
+                self.filters.toggle(chip);
+                self.refresh_active_view()
+            }
+            Message::ColumnSortPressed(field) => {
+                if let Some(store) = self.views.get_mut(&self.active) {
+                    store.sort = ColumnSort::cycle(store.sort, field);
+                }
+                self.refresh_active_view()
+            }
+            Message::FiltersCleared => {
+                self.filters = ActiveFilters::default();
+                self.refresh_active_view()
+            }
+            Message::SavedViewsLoaded(Ok(views)) => {
+                self.saved_views = views;
+                self.refresh_open_tasks()
+            }
+            Message::SavedViewsLoaded(Err(err)) => {
+                tracing::warn!(error = %err, "failed to load saved views");
+                Effect::none()
+            }
+            Message::SavedViewOpened(slot) => {
+                self.pinned_menu = None;
+                match self.saved_views.iter().find(|view| view.slot == slot) {
+                    Some(view) => {
+                        self.filters = ActiveFilters::from_saved(view);
+                        self.refresh_active_view()
+                    }
+                    None => Effect::none(),
+                }
+            }
+            Message::SavedViewPinToggled(slot) => {
+                self.pinned_menu = None;
+                self.window_state.update(|state| {
+                    if state.pinned_views.contains(&slot) {
+                        state.pinned_views.retain(|pinned| *pinned != slot);
+                    } else {
+                        state.pinned_views.push(slot);
+                    }
+                });
+                self.refresh_open_tasks()
+            }
+            Message::PinDialogOpened(slot) => {
+                self.open_pin_dialog(slot);
+                Effect::none()
+            }
+            Message::PinNameChanged(name) => {
+                if let Some(dialog) = self.pin_dialog.as_mut() {
+                    dialog.name = name;
+                }
+                Effect::none()
+            }
+            Message::PinUseCurrentToggled(enabled) => {
+                if let Some(dialog) = self.pin_dialog.as_mut() {
+                    dialog.use_current = enabled;
+                }
+                Effect::none()
+            }
+            Message::PinConfirmed => self.confirm_pin(),
+            Message::PinCancelled => {
+                self.pin_dialog = None;
+                Effect::none()
+            }
+            Message::SavedViewSaved(result) => self.finish_saving_view(result),
+            Message::PinnedMenuOpened(slot) => {
+                self.pinned_menu = slot;
+                Effect::none()
+            }
+            Message::PinDragStarted(slot) => {
+                self.pinned_menu = None;
+                self.pin_drag = Some(PinDrag { slot, target: None });
+                Effect::none()
+            }
+            Message::PinDragEntered(slot) => {
+                if let Some(drag) = self.pin_drag.as_mut() {
+                    drag.target = Some(slot);
+                }
+                Effect::none()
+            }
+            Message::NotesEdited(action) => {
+                if let Some(draft) = self.notes_draft.as_mut() {
+                    draft.content.perform(action);
+                }
+                Effect::none()
+            }
+            Message::NotesSaved => match self.notes_draft.as_ref() {
+                Some(draft) if draft.is_dirty() => {
+                    let (id, notes) = (draft.task_id.clone(), draft.text());
+                    if let Some(draft) = self.notes_draft.as_mut() {
+                        draft.original = notes.clone();
+                    }
+                    self.save_notes(id, notes)
+                }
+                _ => Effect::none(),
+            },
+            Message::NotesReverted => {
+                if let Some(draft) = self.notes_draft.as_mut() {
+                    *draft = draft.reset(&draft.original);
+                }
+                Effect::none()
+            }
+            Message::NotesPreviewToggled => {
+                if let Some(draft) = self.notes_draft.as_mut() {
+                    draft.preview = !draft.preview;
+                }
+                Effect::none()
+            }
+            Message::NotesCheckboxToggled(line) => self.toggle_note_checkbox(line),
+            Message::NotesExternalEditRequested => {
+                self.start_external_edit();
+                Effect::none()
+            }
+            Message::NotesExternalEditStopped => {
+                self.external_edit = None;
+                Effect::none()
+            }
+            Message::ExternalEditPolled => self.poll_external_edit(),
+            Message::NoteLinkClicked(url) if DeepLink::is_link(&url) => self.open_link(&url),
+            Message::NoteLinkClicked(url) => {
+                if let Err(err) = opener::open(&url) {
+                    self.toasts.push(StatusToast::error(self.i18n.tr_args(
+                        "toast-open-failed",
+                        &[("target", url.clone()), ("error", err.to_string())],
+                    )));
+                }
+                Effect::none()
+            }
+            Message::TaskTitlePressed(id) => self.handle_title_press(id),
+            Message::TaskProjectPressed(id) => {
+                self.start_field_edit(id, InlineEditableField::Project)
+            }
+            Message::TaskContextsPressed(id) => {
+                self.start_field_edit(id, InlineEditableField::Contexts)
+            }
+            Message::TaskTagsPressed(id) => self.start_field_edit(id, InlineEditableField::Tags),
+            Message::TaskPriorityPressed(id) => {
+                self.start_field_edit(id, InlineEditableField::Priority)
+            }
+            Message::TaskEnergyPressed(id) => {
+                self.start_field_edit(id, InlineEditableField::Energy)
+            }
+            Message::TaskEstimatePressed(id) => {
+                self.start_field_edit(id, InlineEditableField::TimeEstimate)
+            }
+            Message::TaskDuePressed(id) => self.start_field_edit(id, InlineEditableField::Due),
+            Message::TaskDeferPressed(id) => self.start_field_edit(id, InlineEditableField::Defer),
+            Message::DatePickerMonthChanged(delta) => {
+                self.shift_picker_month(delta);
+                Effect::none()
+            }
+            Message::DatePicked(date) => self.pick_date(date),
+            Message::DateCleared => {
+                self.update_inline_edit(String::new());
+                self.submit_inline_edit()
+            }
+            Message::InlineEditChanged(value) => {
+                self.update_inline_edit(value);
+                Effect::none()
+            }
+            Message::InlineEditSubmitted => self.submit_inline_edit(),
+            Message::InlineEditOptionSelected(option) => self.handle_inline_option(option),
+            Message::Keyboard(event) => self.handle_keyboard(event),
+        }
+    }
+
+    pub(super) fn handle_title_press(&mut self, id: String) -> Effect {
+        let now = Instant::now();
+        let is_double_click = self
+            .last_title_click
+            .as_ref()
+            .filter(|(prev_id, prev_time)| {
+                prev_id == &id && now.duration_since(*prev_time) <= TITLE_DOUBLE_CLICK_WINDOW
+            })
+            .is_some();
+
+        self.last_title_click = Some((id.clone(), now));
+        self.selected_task = Some(id.clone());
+
+        if is_double_click {
+            self.last_title_click = None;
+            return self.start_title_edit(id);
+        }
+
+        Effect::none()
+    }
+
+    pub(super) fn handle_view_loaded(
+        &mut self,
+        tab: ViewTab,
+        result: Result<cpt_core::ViewSnapshot, String>,
+    ) -> Effect {
+        // A paged view reloads as its search changes; rows for an earlier search are dropped.
+        if let Ok(snapshot) = &result {
+            if snapshot.filters.text != self.view_filters(tab).text {
+                return Effect::none();
+            }
+        }
+        let mut effect = Effect::none();
+        if let Some(store) = self.views.get_mut(&tab) {
+            match result {
+                Ok(mut snapshot) => {
+                    // Waiting lists its tasks under who they wait on unless a header sorts it.
+                    if tab == ViewTab::Waiting && store.sort.is_none() {
+                        group_by_waiting_on(&mut snapshot.tasks);
+                    }
+                    let task_count = snapshot.tasks.len();
+                    let empty = snapshot.tasks.is_empty() && snapshot.projects.is_empty();
+                    store.has_more = snapshot.filters.limit == Some(task_count);
+                    store.last_refreshed = Some(Instant::now());
+                    store.state = LoadState::Idle;
+                    store.failed = None;
+                    store.version = store.version.wrapping_add(1);
+                    store.snapshot = Some(snapshot);
+                    self.telemetry.record(TelemetryEvent::RefreshCompleted {
+                        view: tab.title().into(),
+                        count: task_count,
                     });
-                    self.status = Some(StatusToast {
-                        message: err,
-                        kind: ToastKind::Error,
-                        created_at: Instant::now(),
+                    if tab == self.active {
+                        self.sync_selection_with_view();
+                        // An empty view only offers sample data when the whole database is empty.
+                        match self.service.clone() {
+                            Some(service) if empty => effect = workspace_empty_command(service),
+                            _ => self.workspace_empty = false,
+                        }
+                    }
+                }
+                Err(err) => {
+                    store.state = LoadState::Error(err.clone());
+                    store.failed = Some(err.clone());
+                    self.telemetry.record(TelemetryEvent::RefreshFailed {
+                        view: tab.title().into(),
+                        error: err.clone(),
                     });
+                    // The open view explains the error in place of its list instead.
+                    if tab != self.active {
+                        self.toasts.push(StatusToast::error(err));
+                    }
+                }
+            }
+        }
+        effect
+    }
+
+    pub(super) fn on_refresh_tick(&mut self) -> Effect {
+        self.save_window_state();
+        let open_tasks = self.refresh_open_tasks();
+        // A window hidden in the tray only needs its badge and reminders kept current, and a
+        // paused window keeps showing what it has.
+        if self.pending_mutations == 0 && !self.window_hidden && !self.auto_refresh_paused {
+            Effect::batch(vec![self.refresh_active_view(), open_tasks])
+        } else {
+            open_tasks
+        }
+    }
+
+    fn start_tray(&mut self) -> Effect {
+        match Tray::new(self.close_to_tray, &self.i18n) {
+            Ok(tray) => {
+                self.tray = Some(tray);
+                self.refresh_open_tasks()
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to start the tray icon");
+                Effect::none()
+            }
+        }
+    }
+
+    fn refresh_open_tasks(&self) -> Effect {
+        let wanted = self.tray.is_some()
+            || self.notifier.is_some()
+            || !self.window_state.state().pinned_views.is_empty();
+        match self.service.clone() {
+            Some(service) if wanted => open_tasks_command(service),
+            _ => Effect::none(),
+        }
+    }
+
+    /// Applies the Done and Snooze buttons clicked on reminder notifications.
+    fn poll_reminders(&mut self) -> Effect {
+        let Some(notifier) = &self.notifier else {
+            return Effect::none();
+        };
+        let mut effects = Vec::new();
+        for action in notifier.poll() {
+            effects.push(match action {
+                ReminderAction::Done(id) => {
+                    self.apply_status_change(vec![id], MutationKind::Complete)
+                }
+                ReminderAction::Snooze(id) => self.snooze_task(id),
+            });
+        }
+        Effect::batch(effects)
+    }
+
+    /// Moves a task dropped on a calendar day: its due date, or its defer date when it has no due
+    /// date, goes to that day at the same time.
+    fn reschedule(&mut self, id: String, day: NaiveDate) -> Effect {
+        let Some(task) = self
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == id)
+            .cloned()
+        else {
+            return Effect::none();
+        };
+        if placement(&task) == Some(day) {
+            return Effect::none();
+        }
+        let kind = match (task.due_at, task.defer_until) {
+            (Some(due), _) => MutationKind::ChangeDue {
+                id: id.clone(),
+                due: Some(move_to_day(due, day)),
+            },
+            (None, Some(defer)) => MutationKind::ChangeDefer {
+                id: id.clone(),
+                defer: Some(move_to_day(defer, day)),
+            },
+            (None, None) => return Effect::none(),
+        };
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        self.calendar.selected_day = Some(day);
+        self.apply_optimistic_update(&[id], &kind);
+        self.pending_mutations += 1;
+        Effect::perform(mutation_command(service, kind.clone()), move |result| {
+            Message::MutationFinished(kind.clone(), result)
+        })
+    }
+
+    /// Moves a Next task dropped on another row into that row's place and saves the new order.
+    fn reorder(&mut self, id: String, target_id: String) -> Effect {
+        let mut order: Vec<String> = self
+            .views
+            .get(&ViewTab::Next)
+            .and_then(|view| view.snapshot.as_ref())
+            .map(|snapshot| snapshot.tasks.iter().map(|task| task.id.clone()).collect())
+            .unwrap_or_default();
+        let (Some(from), Some(to)) = (
+            order.iter().position(|task| *task == id),
+            order.iter().position(|task| *task == target_id),
+        ) else {
+            return Effect::none();
+        };
+        if from == to {
+            return Effect::none();
+        }
+        let moved = order.remove(from);
+        order.insert(to, moved);
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let kind = MutationKind::Reorder(order);
+        self.apply_optimistic_update(&[id], &kind);
+        self.pending_mutations += 1;
+        Effect::perform(mutation_command(service, kind.clone()), move |result| {
+            Message::MutationFinished(kind.clone(), result)
+        })
+    }
+
+    /// Opens the pin dialog on a pinned view, or on the current filters for a new one.
+    fn open_pin_dialog(&mut self, slot: Option<u8>) {
+        self.pinned_menu = None;
+        let name = match slot {
+            Some(slot) => match self.saved_views.iter().find(|view| view.slot == slot) {
+                Some(view) => view.name.clone(),
+                None => return,
+            },
+            None if self.filters.is_empty() => return,
+            None => self
+                .filters
+                .chips()
+                .iter()
+                .map(|chip| chip.label())
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        self.pin_dialog = Some(PinDialog {
+            slot,
+            name,
+            use_current: slot.is_none(),
+        });
+    }
+
+    /// Saves the dialog's view into its own slot, or the first free one for a new view.
+    fn confirm_pin(&mut self) -> Effect {
+        let Some(dialog) = self.pin_dialog.as_ref() else {
+            return Effect::none();
+        };
+        let name = dialog.name.trim().to_string();
+        if name.is_empty() {
+            return Effect::none();
+        }
+        let existing = dialog
+            .slot
+            .and_then(|slot| self.saved_views.iter().find(|view| view.slot == slot));
+        let filters = match existing {
+            Some(view) if !dialog.use_current => ActiveFilters::from_saved(view),
+            _ => self.filters.clone(),
+        };
+        let free = (1..=SAVED_VIEW_SLOTS)
+            .find(|slot| self.saved_views.iter().all(|view| view.slot != *slot));
+        let Some(slot) = dialog.slot.or(free) else {
+            self.toasts
+                .push(StatusToast::error(self.i18n.tr("toast-view-slots-full")));
+            return Effect::none();
+        };
+        self.pin_dialog = None;
+        match self.service.clone() {
+            Some(service) => save_view_command(service, filters.to_saved(slot, name)),
+            None => Effect::none(),
+        }
+    }
+
+    fn finish_saving_view(&mut self, result: Result<SavedView, String>) -> Effect {
+        match result {
+            Ok(view) => {
+                let slot = view.slot;
+                self.toasts.push(StatusToast::info(
+                    self.i18n
+                        .tr_args("toast-view-saved", &[("name", view.name.clone())]),
+                ));
+                self.saved_views.retain(|existing| existing.slot != slot);
+                self.saved_views.push(view);
+                self.saved_views.sort_by_key(|view| view.slot);
+                self.window_state.update(|state| {
+                    if !state.pinned_views.contains(&slot) {
+                        state.pinned_views.push(slot);
+                    }
+                });
+                self.refresh_open_tasks()
+            }
+            Err(error) => {
+                let message = self
+                    .i18n
+                    .tr_args("toast-view-save-failed", &[("error", error)]);
+                self.toasts.push(StatusToast::error(message));
+                Effect::none()
+            }
+        }
+    }
+
+    /// Puts the dragged pinned view where the one it was dropped on was.
+    fn move_pinned_view(&mut self, drag: PinDrag) {
+        let Some(target) = drag.target.filter(|target| *target != drag.slot) else {
+            return;
+        };
+        self.window_state.update(|state| {
+            let pinned = &mut state.pinned_views;
+            if let (Some(from), Some(to)) = (
+                pinned.iter().position(|slot| *slot == drag.slot),
+                pinned.iter().position(|slot| *slot == target),
+            ) {
+                let moved = pinned.remove(from);
+                pinned.insert(to, moved);
+            }
+        });
+    }
+
+    /// Pinned views that still exist, in sidebar order, with their open task counts.
+    pub(super) fn pinned_views(&self) -> Vec<(&SavedView, usize)> {
+        self.window_state
+            .state()
+            .pinned_views
+            .iter()
+            .filter_map(|slot| self.saved_views.iter().find(|view| view.slot == *slot))
+            .map(|view| {
+                let filters = ActiveFilters::from_saved(view);
+                let count = self
+                    .open_tasks
+                    .iter()
+                    .filter(|task| filters.matches(task))
+                    .count();
+                (view, count)
+            })
+            .collect()
+    }
+
+    fn snooze_task(&mut self, id: String) -> Effect {
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let kind = MutationKind::ChangeDefer {
+            id: id.clone(),
+            defer: Some(Utc::now() + ChronoDuration::hours(SNOOZE_HOURS)),
+        };
+        self.apply_optimistic_update(&[id], &kind);
+        self.pending_mutations += 1;
+        Effect::perform(mutation_command(service, kind.clone()), move |result| {
+            Message::MutationFinished(kind.clone(), result)
+        })
+    }
+
+    fn poll_tray(&mut self) -> Effect {
+        let Some(tray) = &self.tray else {
+            return Effect::none();
+        };
+        let mut effects = Vec::new();
+        for action in tray.poll() {
+            match action {
+                TrayAction::OpenWindow => effects.push(self.show_window()),
+                TrayAction::QuickCapture => effects.push(self.open_quick_capture()),
+                TrayAction::ToggleCloseToTray => {
+                    self.close_to_tray = !self.close_to_tray;
+                    if let Some(tray) = &self.tray {
+                        tray.set_close_to_tray(self.close_to_tray);
+                    }
+                }
+                TrayAction::Quit => effects.push(self.quit()),
+            }
+        }
+        Effect::batch(effects)
+    }
+
+    /// Closes the quick-capture window; the main window hides into the tray when allowed, so
+    /// refreshes keep running, and otherwise quits the app.
+    fn close_window(&mut self, id: window::Id) -> Effect {
+        if self.is_quick_capture(id) {
+            return self.dismiss_quick_capture();
+        }
+        if self.view_window(id).is_some() {
+            self.view_windows
+                .retain(|view_window| view_window.window != id);
+            return window::close(id);
+        }
+        self.window_id = Some(id);
+        if self.close_to_tray && self.tray.is_some() {
+            self.window_hidden = true;
+            window::set_mode(id, window::Mode::Hidden)
+        } else {
+            self.quit()
+        }
+    }
+
+    fn quit(&mut self) -> Effect {
+        self.save_window_state();
+        iced::exit()
+    }
+
+    fn save_window_state(&mut self) {
+        if let Err(err) = self.window_state.save() {
+            tracing::warn!(error = %err, "failed to save desktop window state");
+        }
+    }
+
+    fn show_window(&mut self) -> Effect {
+        let Some(id) = self.window_id else {
+            return Effect::none();
+        };
+        let was_hidden = std::mem::replace(&mut self.window_hidden, false);
+        let mut effects = vec![
+            window::set_mode(id, window::Mode::Windowed),
+            window::gain_focus(id),
+        ];
+        if was_hidden {
+            effects.push(self.refresh_active_view());
+        }
+        Effect::batch(effects)
+    }
+
+    /// Brings the window forward for a `cpt://` link: a task link looks the task up first so
+    /// its list can be opened, a capture link fills in the capture bar without submitting it.
+    fn open_link(&mut self, url: &str) -> Effect {
+        let link = match DeepLink::parse(url) {
+            Ok(link) => link,
+            Err(err) => {
+                self.toasts.push(StatusToast::error(
+                    self.i18n
+                        .tr_args("toast-link-invalid", &[("error", err.to_string())]),
+                ));
+                return Effect::none();
+            }
+        };
+        let show = self.show_window();
+        match link {
+            DeepLink::Task(id) => match self.service.clone() {
+                Some(service) => Effect::batch(vec![show, fetch_task_command(service, id)]),
+                None => show,
+            },
+            DeepLink::Capture(text) => {
+                self.capture.open = true;
+                self.command_palette.close();
+                if !text.is_empty() {
+                    self.capture.on_text_changed(text);
+                }
+                Effect::batch(vec![show, self.focus_capture_input()])
+            }
+        }
+    }
+
+    /// Opens the list `task` belongs to with it selected and its details showing; the search
+    /// is cleared so it cannot hide the row.
+    fn show_linked_task(&mut self, task: Task) -> Effect {
+        let tab = ViewTab::for_status(task.status);
+        self.search.clear();
+        self.cancel_inline_edit();
+        self.context_menu = None;
+        self.selected_task = Some(task.id);
+        self.detail_open = true;
+        if tab == self.active && self.project_page.is_none() {
+            self.refresh_active_view()
+        } else {
+            self.switch_view(tab)
+        }
+    }
+
+    fn start_hotkey(&mut self) {
+        let Some(spec) = self.capture_hotkey.clone() else {
+            return;
+        };
+        match CaptureHotkey::register(&spec) {
+            Ok(hotkey) => self.hotkey = Some(hotkey),
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to register the capture hotkey");
+                self.toasts.push(StatusToast::error(err.to_string()));
+            }
+        }
+    }
+
+    /// Pops the quick-capture window, or brings it forward when it is already open.
+    pub(super) fn open_quick_capture(&mut self) -> Effect {
+        if let Some(quick) = &self.quick_capture {
+            return Effect::batch(vec![
+                window::gain_focus(quick.window),
+                focus(self.quick_capture_input_id.clone()),
+            ]);
+        }
+        let (id, open) = window::open(quick_capture_window_settings());
+        let mut capture = CaptureState::new();
+        capture.open = true;
+        self.quick_capture = Some(QuickCapture {
+            window: id,
+            capture,
+        });
+        open.map(|_| Message::QuickCaptureOpened)
+    }
+
+    /// Opens a further window on the active view, or on Inbox when that is not a task list.
+    fn open_view_window(&mut self) -> Effect {
+        let tab = if ViewWindow::offers(self.active) {
+            self.active
+        } else {
+            ViewTab::Inbox
+        };
+        let (id, open) = window::open(view_window_settings());
+        self.view_windows.push(ViewWindow { window: id, tab });
+        open.map(Message::ViewWindowOpened)
+    }
+
+    /// Loads `tab` with the filters every window shares, counting the rows of a paged view.
+    fn load_view(&mut self, tab: ViewTab) -> Effect {
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        self.ensure_view_entry(tab);
+        let pages = self.views.get(&tab).map_or(1, |view| view.pages);
+        self.views
+            .entry(tab)
+            .and_modify(|view| view.state = LoadState::Loading);
+        let filters = self.view_filters(tab);
+        let counts = match tab.page_size() {
+            Some(_) => view_counts_command(service.clone(), tab, filters.clone()),
+            None => Effect::none(),
+        };
+        Effect::batch(vec![
+            load_view_command(service, tab, filters, pages),
+            counts,
+        ])
+    }
+
+    /// Filters `tab` loads with; only the active view takes the toolbar search.
+    pub(super) fn view_filters(&self, tab: ViewTab) -> ListFilters {
+        let sort = self.views.get(&tab).and_then(|view| view.sort);
+        let search = if tab == self.active {
+            self.search.as_str()
+        } else {
+            ""
+        };
+        view_filters(tab, &self.filters, sort, self.load_range(tab), search)
+    }
+
+    /// Reloads the views further windows show, apart from the active one loaded anyway.
+    fn refresh_view_windows(&mut self) -> Effect {
+        let mut tabs = Vec::new();
+        for view_window in &self.view_windows {
+            if view_window.tab != self.active && !tabs.contains(&view_window.tab) {
+                tabs.push(view_window.tab);
+            }
+        }
+        Effect::batch(
+            tabs.into_iter()
+                .map(|tab| self.load_view(tab))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn submit_quick_capture(&mut self) -> Effect {
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let Some(quick) = self.quick_capture.as_mut() else {
+            return Effect::none();
+        };
+        if quick.capture.submitting {
+            return Effect::none();
+        }
+        if quick.capture.text.trim().is_empty() {
+            quick.capture.preview_error = Some(self.i18n.tr("capture-empty"));
+            return Effect::none();
+        }
+        quick.capture.submitting = true;
+        self.telemetry.record(TelemetryEvent::CaptureStarted);
+        Effect::perform(
+            capture_command(service, quick.capture.input()),
+            Message::QuickCaptureCompleted,
+        )
+    }
+
+    fn finish_quick_capture(&mut self, result: Result<AddOutcome, String>) -> Effect {
+        match result {
+            Ok(outcome) => {
+                self.toasts.push(StatusToast::info(
+                    self.i18n
+                        .tr_args("toast-task-added", &[("title", outcome.title.clone())]),
+                ));
+                self.telemetry
+                    .record(TelemetryEvent::CaptureFinished(outcome.id));
+                if let Some(line) = self.quick_capture.as_ref().map(|q| q.capture.text.clone()) {
+                    self.remember_capture(&line);
+                }
+                Effect::batch(vec![
+                    self.dismiss_quick_capture(),
+                    self.refresh_active_view(),
+                    self.refresh_open_tasks(),
+                ])
+            }
+            Err(err) => {
+                if let Some(quick) = self.quick_capture.as_mut() {
+                    quick.capture.submitting = false;
+                    quick.capture.preview_error = Some(err);
+                }
+                Effect::none()
+            }
+        }
+    }
+
+    /// Opens the Export dialog; views without task rows default to exporting everything.
+    fn open_export_dialog(&mut self) {
+        let scope = if self.current_tasks().is_empty() {
+            ExportScope::Everything
+        } else {
+            ExportScope::View
+        };
+        self.export_dialog = Some(ExportDialog {
+            format: ExportFormat::Json,
+            scope,
+        });
+    }
+
+    fn start_export(&mut self) -> Effect {
+        let Some(dialog) = self.export_dialog.take() else {
+            return Effect::none();
+        };
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        // The view's rows come from the database, so pages not loaded yet are exported too.
+        let (filters, stem) = match dialog.scope {
+            ExportScope::View => {
+                let mut filters = self.view_filters(self.active);
+                filters.text = search_text(&self.search);
+                let stem = format!("cpt-{}", self.active.title().to_ascii_lowercase());
+                (filters, stem)
+            }
+            ExportScope::Everything => (
+                ListFilters {
+                    include_done: true,
+                    ..ListFilters::for_view(None)
+                },
+                "cpt-tasks".to_string(),
+            ),
+        };
+        let file_name = format!("{stem}.{}", dialog.format);
+        export_command(service, filters, dialog.format, file_name)
+    }
+
+    /// Opens the current view's print layout in the browser; Stats and empty lists have nothing
+    /// to print.
+    fn start_print(&mut self) -> Effect {
+        // A paged view prints every row, so the pages not loaded yet are fetched first.
+        let paged = self
+            .views
+            .get(&self.active)
+            .is_some_and(|view| view.has_more);
+        if let Some(service) = self.service.clone().filter(|_| paged) {
+            return print_view_command(service, self.view_filters(self.active));
+        }
+        let sheet = self.print_layout();
+        self.print_sheet(sheet)
+    }
+
+    fn print_sheet(&mut self, sheet: Option<PrintSheet>) -> Effect {
+        match sheet {
+            Some(sheet) if sheet.row_count() > 0 => print_command(sheet),
+            _ => {
+                self.toasts
+                    .push(StatusToast::info(self.i18n.tr("toast-print-nothing")));
+                Effect::none()
+            }
+        }
+    }
+
+    /// Opens the focus screen on the selected task and starts its timer.
+    fn start_focus(&mut self) -> Effect {
+        let Some(task) = self
+            .current_tasks()
+            .into_iter()
+            .find(|task| Some(&task.id) == self.selected_task.as_ref())
+            .cloned()
+        else {
+            self.toasts
+                .push(StatusToast::info(self.i18n.tr("toast-focus-nothing")));
+            return Effect::none();
+        };
+        self.command_palette.close();
+        self.context_menu = None;
+        let id = task.id.clone();
+        self.focus = Some(FocusSession::new(task));
+        match self.service.clone() {
+            Some(service) => start_timer_command(service, id),
+            None => Effect::none(),
+        }
+    }
+
+    /// Closes the focus screen, stopping its timer when it runs.
+    fn exit_focus(&mut self) -> Effect {
+        let running = self.focus.take().is_some_and(|focus| focus.timer.is_some());
+        match self.service.clone() {
+            Some(service) if running => stop_timer_command(service),
+            _ => Effect::none(),
+        }
+    }
+
+    fn toggle_focus_timer(&mut self) -> Effect {
+        let (Some(focus), Some(service)) = (self.focus.as_ref(), self.service.clone()) else {
+            return Effect::none();
+        };
+        if focus.timer.is_some() {
+            stop_timer_command(service)
+        } else {
+            start_timer_command(service, focus.task.id.clone())
+        }
+    }
+
+    fn finish_timer_start(&mut self, result: Result<(i64, TimeEntry), String>) -> Effect {
+        match result {
+            Ok((tracked, entry)) => match self.focus.as_mut() {
+                Some(focus) if focus.task.id == entry.task_id => {
+                    focus.tracked = tracked;
+                    focus.timer = Some(entry);
+                    Effect::none()
+                }
+                // The screen closed before the timer started; don't leave it running.
+                _ => match self.service.clone() {
+                    Some(service) if self.focus.is_none() => stop_timer_command(service),
+                    _ => Effect::none(),
+                },
+            },
+            Err(error) => {
+                self.report_timer_error(error);
+                Effect::none()
+            }
+        }
+    }
+
+    fn finish_timer_stop(&mut self, result: Result<Option<TimeEntry>, String>) {
+        match result {
+            Ok(stopped) => {
+                let Some(focus) = self.focus.as_mut() else {
+                    return;
+                };
+                if let Some(entry) = stopped.filter(|entry| entry.task_id == focus.task.id) {
+                    let ended = entry.ended_at.unwrap_or_else(Utc::now);
+                    focus.tracked += (ended - entry.started_at).num_seconds();
+                }
+                focus.timer = None;
+            }
+            Err(error) => self.report_timer_error(error),
+        }
+    }
+
+    fn report_timer_error(&mut self, error: String) {
+        tracing::warn!(error = %error, "failed to update the focus timer");
+        let message = self.i18n.tr_args("toast-timer-failed", &[("error", error)]);
+        self.toasts.push(StatusToast::error(message));
+    }
+
+    fn finish_export(&mut self, result: Result<Option<(PathBuf, usize)>, String>) {
+        match result {
+            Ok(Some((path, count))) => {
+                let id = if count == 1 {
+                    "toast-exported-one"
+                } else {
+                    "toast-exported-many"
+                };
+                let message = self.i18n.tr_args(
+                    id,
+                    &[
+                        ("count", count.to_string()),
+                        ("path", path.display().to_string()),
+                    ],
+                );
+                self.toasts
+                    .push(StatusToast::info(message).with_action(Some(ToastAction::OpenFile)));
+                self.exported = Some(ExportedFile { path });
+            }
+            // The save dialog was dismissed.
+            Ok(None) => {}
+            Err(err) => {
+                self.toasts.push(StatusToast::error(
+                    self.i18n.tr_args("toast-export-failed", &[("error", err)]),
+                ));
+            }
+        }
+    }
+
+    /// Opens the last exported file with the system's default app.
+    fn open_exported_file(&mut self) {
+        let Some(exported) = &self.exported else {
+            return;
+        };
+        if let Err(err) = opener::open(&exported.path) {
+            self.toasts.push(StatusToast::error(self.i18n.tr_args(
+                "toast-open-failed",
+                &[
+                    ("target", exported.path.display().to_string()),
+                    ("error", err.to_string()),
+                ],
+            )));
+        }
+    }
+
+    fn load_stats(&self) -> Effect {
+        match self.service.clone() {
+            Some(service) => stats_command(service),
+            None => Effect::none(),
+        }
+    }
+
+    fn load_project_page(&self) -> Effect {
+        match (self.service.clone(), &self.project_page) {
+            (Some(service), Some(page)) => project_tasks_command(service, page.name.clone()),
+            _ => Effect::none(),
+        }
+    }
+
+    fn submit_project_capture(&mut self) -> Effect {
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let Some(page) = self.project_page.as_mut() else {
+            return Effect::none();
+        };
+        if page.capture.submitting {
+            return Effect::none();
+        }
+        // The field starts out holding only the project token.
+        let input = page.capture.input();
+        if input.text.iter().all(|piece| piece.starts_with('+')) {
+            page.capture.preview_error = Some(self.i18n.tr("capture-empty"));
+            return Effect::none();
+        }
+        page.capture.submitting = true;
+        self.telemetry.record(TelemetryEvent::CaptureStarted);
+        Effect::perform(
+            capture_command(service, input),
+            Message::ProjectCaptureCompleted,
+        )
+    }
+
+    fn finish_project_capture(&mut self, result: Result<AddOutcome, String>) -> Effect {
+        match result {
+            Ok(outcome) => {
+                self.toasts.push(StatusToast::info(
+                    self.i18n
+                        .tr_args("toast-task-added", &[("title", outcome.title.clone())]),
+                ));
+                self.telemetry
+                    .record(TelemetryEvent::CaptureFinished(outcome.id));
+                if let Some(line) = self.project_page.as_ref().map(|p| p.capture.text.clone()) {
+                    self.remember_capture(&line);
+                }
+                if let Some(page) = self.project_page.as_mut() {
+                    page.reset_capture();
+                }
+                Effect::batch(vec![self.refresh_active_view(), self.refresh_open_tasks()])
+            }
+            Err(err) => {
+                if let Some(page) = self.project_page.as_mut() {
+                    page.capture.submitting = false;
+                    page.capture.preview_error = Some(err);
+                }
+                Effect::none()
+            }
+        }
+    }
+
+    fn dismiss_quick_capture(&mut self) -> Effect {
+        match self.quick_capture.take() {
+            Some(quick) => window::close(quick.window),
+            None => Effect::none(),
+        }
+    }
+
+    pub(super) fn toggle_theme(&mut self) -> Effect {
+        let choice = self.window_state.state().theme.next();
+        self.pick_appearance(choice);
+        Effect::none()
+    }
+
+    fn run_shortcut(&mut self, action: ShortcutAction) -> Effect {
+        match action {
+            ShortcutAction::Capture => self.toggle_capture(),
+            ShortcutAction::CommandPalette => self.toggle_command_palette(),
+            ShortcutAction::FocusSearch => Effect::batch(vec![
+                focus(self.search_input_id.clone()),
+                move_cursor_to_end(self.search_input_id.clone()),
+            ]),
+            ShortcutAction::NextView => self.move_tab(1),
+            ShortcutAction::PreviousView => self.move_tab(-1),
+            ShortcutAction::ToggleDetails => self.handle_action(CommandActionId::ToggleDetails),
+            ShortcutAction::ToggleFilters => self.handle_action(CommandActionId::ToggleFilters),
+            ShortcutAction::MarkDone => self.handle_action(CommandActionId::MarkDone),
+            ShortcutAction::PromoteNext => self.handle_action(CommandActionId::PromoteNext),
+            ShortcutAction::MoveToInbox => self.handle_action(CommandActionId::MoveToInbox),
+            ShortcutAction::DeleteTask => self.handle_action(CommandActionId::DeleteTask),
+            ShortcutAction::Refresh => self.refresh_active_view(),
+            ShortcutAction::Print => self.handle_action(CommandActionId::Print),
+            ShortcutAction::Undo if self.undo.is_some() => self.undo_last_mutation(),
+            ShortcutAction::Undo => Effect::none(),
+            ShortcutAction::Settings => {
+                self.settings_open = !self.settings_open;
+                Effect::none()
+            }
+            ShortcutAction::NextRegion => self.move_focus_region(1),
+            ShortcutAction::PreviousRegion => self.move_focus_region(-1),
+        }
+    }
+
+    /// Moves keyboard focus to the next area of the window. The table selects its first row if
+    /// nothing is selected, and the capture area opens the capture bar with the cursor in it.
+    fn move_focus_region(&mut self, delta: i32) -> Effect {
+        let region = match self.keyboard_focus {
+            Some(focus) => focus.region.step(delta),
+            None if delta > 0 => FocusRegion::Toolbar,
+            None => FocusRegion::Capture,
+        };
+        // Leaving an empty capture bar closes it, as Esc would.
+        if region != FocusRegion::Capture && self.capture.open && self.capture.text.is_empty() {
+            self.capture.open = false;
+        }
+        let index = match region {
+            FocusRegion::Sidebar => ViewTab::ALL
+                .iter()
+                .position(|tab| *tab == self.active)
+                .unwrap_or(0),
+            _ => 0,
+        };
+        self.keyboard_focus = Some(KeyboardFocus { region, index });
+        match region {
+            FocusRegion::Table => {
+                if self.selected_task.is_none() {
+                    self.move_selection(0);
                 }
+                Effect::none()
             }
+            FocusRegion::Capture => self.handle_action(CommandActionId::OpenCapture),
+            FocusRegion::Toolbar | FocusRegion::Sidebar => Effect::none(),
         }
-        Effect::none()
     }
 
-    pub(super) fn on_refresh_tick(&mut self) -> Effect {
-        if self.pending_mutations == 0 {
-            self.refresh_active_view()
-        } else {
-            Effect::none()
+    /// Arrow keys move within the focused toolbar or sidebar and Enter or Space activates the
+    /// entry; `None` leaves the key to the usual handling.
+    fn navigate_focus(&mut self, key: Key<&str>) -> Option<Effect> {
+        let focus = self.keyboard_focus?;
+        let len = match focus.region {
+            FocusRegion::Toolbar => ToolbarItem::ALL.len(),
+            FocusRegion::Sidebar => ViewTab::ALL.len(),
+            FocusRegion::Table | FocusRegion::Capture => return None,
+        };
+        let step = match (focus.region, key) {
+            (FocusRegion::Toolbar, Key::Named(Named::ArrowRight))
+            | (FocusRegion::Sidebar, Key::Named(Named::ArrowDown)) => 1,
+            (FocusRegion::Toolbar, Key::Named(Named::ArrowLeft))
+            | (FocusRegion::Sidebar, Key::Named(Named::ArrowUp)) => -1,
+            (_, Key::Named(Named::Enter | Named::Space)) => {
+                return Some(self.activate_focused(focus));
+            }
+            _ => return None,
+        };
+        self.keyboard_focus = Some(KeyboardFocus {
+            index: (focus.index as i32 + step).rem_euclid(len as i32) as usize,
+            ..focus
+        });
+        Some(Effect::none())
+    }
+
+    fn activate_focused(&mut self, focus: KeyboardFocus) -> Effect {
+        if focus.region == FocusRegion::Sidebar {
+            return match ViewTab::ALL.get(focus.index) {
+                Some(tab) => self.switch_view(*tab),
+                None => Effect::none(),
+            };
         }
+        let message = match ToolbarItem::ALL.get(focus.index) {
+            // The search field takes the keys from here on.
+            Some(ToolbarItem::Search) => {
+                self.keyboard_focus = None;
+                return self.run_shortcut(ShortcutAction::FocusSearch);
+            }
+            Some(ToolbarItem::AddTask) => Message::CaptureToggled,
+            Some(ToolbarItem::Details) => Message::DetailToggled,
+            Some(ToolbarItem::Filters) => Message::FilterPanelToggled,
+            Some(ToolbarItem::Theme) => Message::ToggleTheme,
+            Some(ToolbarItem::Export) => Message::ExportRequested,
+            Some(ToolbarItem::Settings) => Message::SettingsToggled,
+            Some(ToolbarItem::CommandPalette) => Message::CommandPaletteToggled,
+            None => return Effect::none(),
+        };
+        self.dispatch(message)
     }
 
-    pub(super) fn toggle_theme(&mut self) -> Effect {
-        self.theme = match self.theme {
-            Theme::Dark => Theme::Light,
-            _ => Theme::Dark,
+    /// Binds the key pressed while the shortcuts dialog is listening; Esc cancels.
+    fn record_shortcut(
+        &mut self,
+        action: ShortcutAction,
+        key: &Key,
+        modifiers: Modifiers,
+    ) -> Effect {
+        if let Key::Named(Named::Escape) = key.as_ref() {
+            self.shortcut_recording = None;
+            return Effect::none();
+        }
+        // Bare modifiers and reserved keys keep listening.
+        let Some(binding) = KeyBinding::from_press(key, modifiers) else {
+            return Effect::none();
         };
-        self.palette = Palette::for_theme(&self.theme);
+        self.shortcut_recording = None;
+        let keys = binding.to_string();
+        let toast = match self.shortcuts.rebind(action, binding) {
+            Ok(Some(displaced)) => StatusToast::info(self.i18n.tr_args(
+                "toast-shortcut-displaced",
+                &[
+                    ("keys", keys),
+                    ("action", self.i18n.shortcut(action)),
+                    ("displaced", self.i18n.shortcut(displaced)),
+                ],
+            )),
+            Ok(None) => StatusToast::info(self.i18n.tr_args(
+                "toast-shortcut-bound",
+                &[("keys", keys), ("action", self.i18n.shortcut(action))],
+            )),
+            Err(err) => StatusToast::error(
+                self.i18n
+                    .tr_args("toast-shortcuts-save-failed", &[("error", err.to_string())]),
+            ),
+        };
+        self.toasts.push(toast);
+        Effect::none()
+    }
+
+    fn zoom_by(&mut self, delta: f32) -> Effect {
+        let zoom = self.window_state.state().zoom + delta;
+        self.window_state.update(|state| state.set_zoom(zoom));
+        self.toasts.push(StatusToast::info(self.i18n.tr_args(
+            "toast-zoom",
+            &[(
+                "percent",
+                format!("{:.0}", self.window_state.state().zoom * 100.0),
+            )],
+        )));
         Effect::none()
     }
 
+    fn pick_appearance(&mut self, choice: ThemeChoice) {
+        self.window_state.update(|state| state.theme = choice);
+        self.set_theme(choice.resolve());
+    }
+
+    fn pick_color_theme(&mut self, name: String) {
+        let color_theme = (name != DEFAULT_COLOR_THEME).then_some(name);
+        self.window_state
+            .update(|state| state.color_theme = color_theme);
+        self.set_theme(self.window_state.state().theme.resolve());
+    }
+
+    /// Applies the appearance `requested` by the System/Light/Dark choice through the picked color
+    /// theme, which may pin its own appearance.
+    fn set_theme(&mut self, requested: Theme) {
+        let saved = self.window_state.state();
+        let (theme, palette) = self
+            .color_themes
+            .get(saved.color_theme.as_deref())
+            .resolve(requested);
+        self.theme = theme;
+        self.palette = palette;
+    }
+
     pub(super) fn toggle_capture(&mut self) -> Effect {
         self.capture.toggle();
         if self.capture.open {
@@ -232,6 +2002,8 @@ impl CptDesktop {
                     input_id,
                     options: Vec::new(),
                     original_tokens: Vec::new(),
+                    month: None,
+                    error: None,
                 })
             }
             InlineEditableField::Project => {
@@ -254,6 +2026,8 @@ impl CptDesktop {
                     input_id,
                     options,
                     original_tokens: Vec::new(),
+                    month: None,
+                    error: None,
                 })
             }
             InlineEditableField::Contexts => {
@@ -272,6 +2046,8 @@ impl CptDesktop {
                     input_id,
                     options: self.collect_contexts(),
                     original_tokens,
+                    month: None,
+                    error: None,
                 })
             }
             InlineEditableField::Tags => {
@@ -290,6 +2066,8 @@ impl CptDesktop {
                     input_id,
                     options: self.collect_tags(),
                     original_tokens,
+                    month: None,
+                    error: None,
                 })
             }
             InlineEditableField::Priority => {
@@ -304,6 +2082,61 @@ impl CptDesktop {
                     input_id,
                     options,
                     original_tokens: Vec::new(),
+                    month: None,
+                    error: None,
+                })
+            }
+            InlineEditableField::Energy => {
+                let display = task
+                    .energy
+                    .map(|energy| energy.as_str().to_string())
+                    .unwrap_or_else(|| NONE_OPTION_LABEL.to_string());
+                Some(InlineEditState {
+                    task_id: id.to_string(),
+                    field,
+                    value: display.clone(),
+                    original_value: display,
+                    input_id,
+                    options: energy_options(),
+                    original_tokens: Vec::new(),
+                    month: None,
+                    error: None,
+                })
+            }
+            InlineEditableField::TimeEstimate => {
+                let display = task.time_estimate.map(format_minutes).unwrap_or_default();
+                Some(InlineEditState {
+                    task_id: id.to_string(),
+                    field,
+                    value: display.clone(),
+                    original_value: display,
+                    input_id,
+                    options: Vec::new(),
+                    original_tokens: Vec::new(),
+                    month: None,
+                    error: None,
+                })
+            }
+            InlineEditableField::Due | InlineEditableField::Defer => {
+                let current = match field {
+                    InlineEditableField::Due => task.due_at,
+                    _ => task.defer_until,
+                };
+                let date = current.map(|at| at.with_timezone(&Local).date_naive());
+                let value = date
+                    .map(|date| date.format(DATE_INPUT_FORMAT).to_string())
+                    .unwrap_or_default();
+                let shown = date.unwrap_or_else(|| Local::now().date_naive());
+                Some(InlineEditState {
+                    task_id: id.to_string(),
+                    field,
+                    value: value.clone(),
+                    original_value: value,
+                    input_id,
+                    options: Vec::new(),
+                    original_tokens: Vec::new(),
+                    month: shown.with_day(1),
+                    error: None,
                 })
             }
         }
@@ -312,6 +2145,7 @@ impl CptDesktop {
     pub(super) fn update_inline_edit(&mut self, value: String) {
         if let Some(edit) = self.inline_edit.as_mut() {
             edit.value = value;
+            edit.error = None;
         }
     }
 
@@ -346,13 +2180,40 @@ impl CptDesktop {
                 }
                 Effect::none()
             }
-            InlineEditableField::Priority => {
+            InlineEditableField::Priority | InlineEditableField::Energy => {
                 if let Some(edit) = self.inline_edit.as_mut() {
                     edit.value = option.clone();
                 }
                 self.submit_inline_edit()
             }
-            InlineEditableField::Title => Effect::none(),
+            InlineEditableField::Title
+            | InlineEditableField::TimeEstimate
+            | InlineEditableField::Due
+            | InlineEditableField::Defer => Effect::none(),
+        }
+    }
+
+    /// Pages the date picker by `delta` months.
+    pub(super) fn shift_picker_month(&mut self, delta: i32) {
+        if let Some(edit) = self.inline_edit.as_mut() {
+            if let Some(month) = edit.month {
+                let shifted = if delta >= 0 {
+                    month.checked_add_months(Months::new(delta as u32))
+                } else {
+                    month.checked_sub_months(Months::new(delta.unsigned_abs()))
+                };
+                edit.month = shifted.or(Some(month));
+            }
+        }
+    }
+
+    pub(super) fn pick_date(&mut self, date: NaiveDate) -> Effect {
+        match self.inline_edit.as_mut() {
+            Some(edit) if edit.field.is_date() => {
+                edit.value = date.format(DATE_INPUT_FORMAT).to_string();
+                self.submit_inline_edit()
+            }
+            _ => Effect::none(),
         }
     }
 
@@ -369,11 +2230,8 @@ impl CptDesktop {
             InlineEditableField::Title => {
                 let trimmed = edit.value.trim();
                 if trimmed.is_empty() {
-                    self.status = Some(StatusToast {
-                        message: "Task title cannot be empty".into(),
-                        kind: ToastKind::Error,
-                        created_at: Instant::now(),
-                    });
+                    self.toasts
+                        .push(StatusToast::error(self.i18n.tr("toast-title-empty")));
                     return Effect::none();
                 }
 
@@ -487,11 +2345,8 @@ impl CptDesktop {
             }
             InlineEditableField::Priority => {
                 let Some(new_priority) = priority_from_input(&edit.value) else {
-                    self.status = Some(StatusToast {
-                        message: "Priority must be between 0 (none) and 3 (high).".into(),
-                        kind: ToastKind::Error,
-                        created_at: Instant::now(),
-                    });
+                    self.toasts
+                        .push(StatusToast::error(self.i18n.tr("toast-priority-range")));
                     return Effect::none();
                 };
 
@@ -525,31 +2380,192 @@ impl CptDesktop {
                     return Effect::none();
                 }
             }
+            InlineEditableField::Energy => {
+                let value = edit.value.trim();
+                if value == edit.original_value {
+                    self.inline_edit = None;
+                    return Effect::none();
+                }
+                let energy = if value.is_empty() || value == NONE_OPTION_LABEL {
+                    None
+                } else {
+                    match value.parse::<EnergyLevel>() {
+                        Ok(energy) => Some(energy),
+                        Err(err) => {
+                            self.toasts.push(StatusToast::error(err.to_string()));
+                            return Effect::none();
+                        }
+                    }
+                };
+                let kind = MutationKind::ChangeEnergy {
+                    id: edit.task_id.clone(),
+                    energy,
+                };
+                self.submit_field_change(edit.task_id.clone(), kind)
+            }
+            InlineEditableField::TimeEstimate => {
+                let value = edit.value.trim();
+                if value == edit.original_value {
+                    self.inline_edit = None;
+                    return Effect::none();
+                }
+                let minutes = if value.is_empty() {
+                    None
+                } else {
+                    match parse_duration_minutes(value) {
+                        Ok(minutes) => Some(minutes).filter(|minutes| *minutes > 0),
+                        Err(_) => {
+                            self.toasts
+                                .push(StatusToast::error(self.i18n.tr("toast-estimate-invalid")));
+                            return Effect::none();
+                        }
+                    }
+                };
+                let kind = MutationKind::ChangeEstimate {
+                    id: edit.task_id.clone(),
+                    minutes,
+                };
+                self.submit_field_change(edit.task_id.clone(), kind)
+            }
+            InlineEditableField::Due | InlineEditableField::Defer => {
+                let trimmed = edit.value.trim();
+                if trimmed == edit.original_value {
+                    self.inline_edit = None;
+                    return Effect::none();
+                }
+                let at = if trimmed.is_empty() {
+                    None
+                } else {
+                    match parse_date_spec(trimmed) {
+                        Ok(at) => Some(at),
+                        Err(err) => {
+                            if let Some(edit) = self.inline_edit.as_mut() {
+                                edit.error =
+                                    Some(self.i18n.tr_args(
+                                        "error-invalid-date",
+                                        &[("error", err.to_string())],
+                                    ));
+                            }
+                            return Effect::none();
+                        }
+                    }
+                };
+
+                let id = edit.task_id.clone();
+                let kind = match edit.field {
+                    InlineEditableField::Due => MutationKind::ChangeDue { id, due: at },
+                    _ => MutationKind::ChangeDefer { id, defer: at },
+                };
+                self.inline_edit = None;
+                self.apply_optimistic_update(std::slice::from_ref(&edit.task_id), &kind);
+                let Some(service) = self.service.clone() else {
+                    return Effect::none();
+                };
+                self.pending_mutations += 1;
+                Effect::perform(mutation_command(service, kind.clone()), move |result| {
+                    Message::MutationFinished(kind.clone(), result)
+                })
+            }
         }
     }
 
+    /// Closes the inline editor and applies `kind` optimistically before writing it.
+    fn submit_field_change(&mut self, task_id: String, kind: MutationKind) -> Effect {
+        self.inline_edit = None;
+        self.apply_optimistic_update(&[task_id], &kind);
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        self.pending_mutations += 1;
+        Effect::perform(mutation_command(service, kind.clone()), move |result| {
+            Message::MutationFinished(kind.clone(), result)
+        })
+    }
+
     pub(super) fn finish_capture(&mut self, result: Result<AddOutcome, String>) -> Effect {
         self.capture.submitting = false;
         match result {
             Ok(outcome) => {
-                self.status = Some(StatusToast {
-                    message: format!("Added task '{}'.", outcome.title),
-                    kind: ToastKind::Info,
-                    created_at: Instant::now(),
-                });
+                self.toasts.push(StatusToast::info(
+                    self.i18n
+                        .tr_args("toast-task-added", &[("title", outcome.title.clone())]),
+                ));
                 self.telemetry
                     .record(TelemetryEvent::CaptureFinished(outcome.id));
+                let line = self.capture.text.clone();
+                self.remember_capture(&line);
                 self.capture.clear();
                 self.capture.open = false;
                 self.refresh_active_view()
             }
             Err(err) => {
                 self.capture.preview_error = Some(err.clone());
-                self.status = Some(StatusToast {
-                    message: err,
-                    kind: ToastKind::Error,
-                    created_at: Instant::now(),
-                });
+                self.toasts.push(StatusToast::error(err));
+                Effect::none()
+            }
+        }
+    }
+
+    /// Adds a captured line to the recent captures; a failed write only costs the history.
+    fn remember_capture(&mut self, line: &str) {
+        if let Err(err) = self.capture_history.record(line) {
+            tracing::warn!(error = %err, "failed to save the capture history");
+        }
+    }
+
+    /// Steps through recent captures from the capture bar: `1` is Up (older), `-1` Down. Going
+    /// past the newest brings back what was typed before recalling.
+    fn recall_capture(&mut self, step: isize) -> Effect {
+        if self.capture.bulk.is_some() {
+            return Effect::none();
+        }
+        let current = self
+            .capture
+            .recall
+            .as_ref()
+            .map_or(-1, |recall| recall.index as isize);
+        let next = current + step;
+        if next < -1 || next >= self.capture_history.entries().len() as isize {
+            return Effect::none();
+        }
+        let draft = self
+            .capture
+            .recall
+            .take()
+            .map_or_else(|| self.capture.text.clone(), |recall| recall.draft);
+        if next < 0 {
+            self.capture.on_text_changed(draft);
+        } else {
+            let index = next as usize;
+            let line = self
+                .capture_history
+                .get(index)
+                .unwrap_or_default()
+                .to_string();
+            self.capture.on_text_changed(line);
+            self.capture.recall = Some(HistoryRecall { index, draft });
+        }
+        move_cursor_to_end(self.capture_input_id.clone())
+    }
+
+    fn finish_bulk_capture(&mut self, result: Result<Vec<AddOutcome>, String>) -> Effect {
+        self.capture.submitting = false;
+        match result {
+            Ok(outcomes) => {
+                self.toasts.push(StatusToast::info(self.i18n.tr_args(
+                    "toast-tasks-added",
+                    &[("count", outcomes.len().to_string())],
+                )));
+                for outcome in outcomes {
+                    self.telemetry
+                        .record(TelemetryEvent::CaptureFinished(outcome.id));
+                }
+                self.capture.clear();
+                self.capture.open = false;
+                self.refresh_active_view()
+            }
+            Err(err) => {
+                self.toasts.push(StatusToast::error(err));
                 Effect::none()
             }
         }
@@ -561,23 +2577,41 @@ impl CptDesktop {
         result: Result<(), String>,
     ) -> Effect {
         self.pending_mutations = self.pending_mutations.saturating_sub(1);
+        let undo = self
+            .undo_pending
+            .iter()
+            .position(|entry| entry.matches(&kind))
+            .map(|index| self.undo_pending.remove(index));
+        let rollback = self
+            .rollbacks
+            .iter()
+            .position(|rollback| rollback.matches(&kind))
+            .map(|index| self.rollbacks.remove(index));
         match result {
             Ok(()) => {
-                self.status = Some(StatusToast {
-                    message: format!("{} succeeded", capitalize(kind.label())),
-                    kind: ToastKind::Info,
+                let offer = undo.is_some().then_some(ToastAction::Undo);
+                self.toasts.push(
+                    StatusToast::info(self.i18n.tr_args(
+                        "toast-mutation-succeeded",
+                        &[("action", capitalize(&self.i18n.mutation(kind.label())))],
+                    ))
+                    .with_action(offer),
+                );
+                self.undo = undo.map(|entry| UndoEntry {
                     created_at: Instant::now(),
+                    ..entry
                 });
                 self.telemetry
                     .record(TelemetryEvent::MutationApplied(kind.label().into()));
                 self.refresh_active_view()
             }
             Err(err) => {
-                self.status = Some(StatusToast {
-                    message: err.clone(),
-                    kind: ToastKind::Error,
-                    created_at: Instant::now(),
-                });
+                if let Some(rollback) = &rollback {
+                    self.roll_back(rollback);
+                }
+                self.toasts
+                    .push(StatusToast::error(err.clone()).with_action(Some(ToastAction::Retry)));
+                self.retry = Some(kind.clone());
                 self.telemetry.record(TelemetryEvent::MutationFailed {
                     action: kind.label().into(),
                     error: err,
@@ -587,6 +2621,25 @@ impl CptDesktop {
         }
     }
 
+    /// Opens the capture bar on a task for a dropped file: titled after the file, with a link
+    /// to it in the notes. Nothing is added until Enter; further files join the same task.
+    fn capture_dropped_file(&mut self, path: PathBuf) -> Effect {
+        // The focus screen hides the capture bar.
+        if self.focus.is_some() {
+            return Effect::none();
+        }
+        self.command_palette.close();
+        self.capture.open = true;
+        self.capture.bulk = None;
+        if self.capture.attachments.is_empty() && self.capture.text.trim().is_empty() {
+            self.capture.on_text_changed(dropped_file_title(&path));
+        }
+        if !self.capture.attachments.contains(&path) {
+            self.capture.attachments.push(path);
+        }
+        Effect::batch(vec![self.show_window(), self.focus_capture_input()])
+    }
+
     pub(super) fn focus_capture_input(&self) -> Effect {
         Effect::batch(vec![
             focus(self.capture_input_id.clone()),
@@ -598,8 +2651,22 @@ impl CptDesktop {
         if !self.capture.open || self.capture.submitting {
             return Effect::none();
         }
+        if let Some(bulk) = &self.capture.bulk {
+            if !bulk.is_valid() {
+                return Effect::none();
+            }
+            let Some(service) = self.service.clone() else {
+                return Effect::none();
+            };
+            self.capture.submitting = true;
+            self.telemetry.record(TelemetryEvent::CaptureStarted);
+            return Effect::perform(
+                capture_many_command(service, bulk.inputs()),
+                Message::BulkCaptureCompleted,
+            );
+        }
         if self.capture.text.trim().is_empty() {
-            self.capture.preview_error = Some("Task text cannot be empty".into());
+            self.capture.preview_error = Some(self.i18n.tr("capture-empty"));
             return Effect::none();
         }
         if let Some(service) = self.service.clone() {
@@ -613,33 +2680,118 @@ impl CptDesktop {
     }
 
     pub(super) fn refresh_active_view(&mut self) -> Effect {
-        if let Some(service) = self.service.clone() {
+        let windows = self.refresh_view_windows();
+        if self.active == ViewTab::Stats {
+            return Effect::batch(vec![self.load_stats(), windows]);
+        }
+        if self.service.is_some() {
             self.telemetry
                 .record(TelemetryEvent::RefreshRequested(self.active.title().into()));
-            self.views
-                .entry(self.active)
-                .and_modify(|view| view.state = LoadState::Loading);
-            load_view_command(service, self.active)
+            Effect::batch(vec![
+                self.load_view(self.active),
+                self.load_project_page(),
+                windows,
+            ])
+        } else {
+            Effect::none()
+        }
+    }
+
+    /// Date window `tab` loads with, so the calendar only fetches the days on its grid.
+    fn load_range(&self, tab: ViewTab) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        view_range(&self.calendar, tab, self.window_state.state().view_mode)
+    }
+
+    /// Reloads the Scheduled view after the calendar's mode, span, or page changed.
+    fn refresh_calendar(&mut self) -> Effect {
+        if self.active == ViewTab::Scheduled {
+            self.refresh_active_view()
         } else {
             Effect::none()
         }
     }
 
+    /// Requests the next page of a paged view such as Done, to follow the rows already loaded.
+    pub(super) fn load_more(&mut self) -> Effect {
+        let filters = self.view_filters(self.active);
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let Some(store) = self.views.get_mut(&self.active) else {
+            return Effect::none();
+        };
+        if !store.has_more || matches!(store.state, LoadState::Loading) {
+            return Effect::none();
+        }
+        let offset = store
+            .snapshot
+            .as_ref()
+            .map_or(0, |snapshot| snapshot.tasks.len());
+        store.pages += 1;
+        store.state = LoadState::Loading;
+        load_page_command(service, self.active, filters, offset)
+    }
+
+    fn handle_page_loaded(
+        &mut self,
+        tab: ViewTab,
+        result: Result<cpt_core::ViewSnapshot, String>,
+    ) -> Effect {
+        let text = self.view_filters(tab).text;
+        let Some(store) = self.views.get_mut(&tab) else {
+            return Effect::none();
+        };
+        match result {
+            Ok(page) => {
+                let Some(snapshot) = store.snapshot.as_mut() else {
+                    return Effect::none();
+                };
+                // A reload or a new search since the request replaced the rows this page follows.
+                if page.filters.offset != snapshot.tasks.len() || page.filters.text != text {
+                    return Effect::none();
+                }
+                store.has_more = page.filters.limit == Some(page.tasks.len());
+                // Rows shift when tasks change between pages; one already listed stays put.
+                let listed: HashSet<String> =
+                    snapshot.tasks.iter().map(|task| task.id.clone()).collect();
+                snapshot.tasks.extend(
+                    page.tasks
+                        .into_iter()
+                        .filter(|task| !listed.contains(&task.id)),
+                );
+                store.state = LoadState::Idle;
+                store.version = store.version.wrapping_add(1);
+                if tab == self.active {
+                    self.sync_selection_with_view();
+                }
+            }
+            Err(err) => {
+                store.state = LoadState::Idle;
+                store.pages = store.pages.saturating_sub(1).max(1);
+                self.toasts.push(StatusToast::error(err));
+            }
+        }
+        Effect::none()
+    }
+
     pub(super) fn switch_view(&mut self, tab: ViewTab) -> Effect {
         self.active = tab;
+        self.project_page = None;
+        self.list_viewport = ListViewport::default();
         self.ensure_view_entry(tab);
+        self.window_state
+            .update(|state| state.view = Some(tab.title().to_string()));
         self.telemetry
             .record(TelemetryEvent::ViewChanged(tab.title().into()));
         self.telemetry
             .record(TelemetryEvent::RefreshRequested(tab.title().into()));
+        if tab == ViewTab::Stats {
+            return self.load_stats();
+        }
         if let Some(store) = self.views.get_mut(&tab) {
             store.state = LoadState::Loading;
         }
-        if let Some(service) = self.service.clone() {
-            load_view_command(service, tab)
-        } else {
-            Effect::none()
-        }
+        self.load_view(tab)
     }
 
     pub(super) fn sync_selection_with_view(&mut self) {
@@ -654,14 +2806,97 @@ impl CptDesktop {
         }
     }
 
-    pub(super) fn handle_keyboard(&mut self, event: KeyboardEvent) -> Effect {
-        match event {
-            KeyboardEvent::KeyPressed { key, modifiers, .. } => {
-                if modifiers.command() {
-                    if let Key::Character(value) = key.as_ref() {
-                        if value.eq_ignore_ascii_case("k") {
-                            return self.toggle_command_palette();
-                        }
+    pub(super) fn handle_keyboard(&mut self, event: KeyboardEvent) -> Effect {
+        match event {
+            KeyboardEvent::KeyPressed { key, modifiers, .. } => {
+                if let Some(action) = self.shortcut_recording {
+                    return self.record_shortcut(action, &key, modifiers);
+                }
+
+                // Keys pressed in a further window would act on the main window's list.
+                if self
+                    .focused_window
+                    .is_some_and(|id| self.view_window(id).is_some())
+                {
+                    return Effect::none();
+                }
+
+                if modifiers.command() {
+                    // Only zoom works on the focus screen.
+                    if let Some(action) = self
+                        .shortcuts
+                        .action_for(&key, modifiers)
+                        .filter(|_| self.focus.is_none())
+                    {
+                        return self.run_shortcut(action);
+                    }
+                    if let Key::Character(value) = key.as_ref() {
+                        if value == "=" || value == "+" {
+                            return self.zoom_by(ZOOM_STEP);
+                        }
+                        if value == "-" {
+                            return self.zoom_by(-ZOOM_STEP);
+                        }
+                        if value == "0" {
+                            return self.zoom_by(1.0 - self.window_state.state().zoom);
+                        }
+                    }
+                }
+
+                if self.quick_capture.is_some() {
+                    if let Key::Named(Named::Escape) = key.as_ref() {
+                        return self.dismiss_quick_capture();
+                    }
+                }
+
+                // Other keys would act on the list hidden behind the focus screen.
+                if self.focus.is_some() {
+                    if let Key::Named(Named::Escape) = key.as_ref() {
+                        return self.exit_focus();
+                    }
+                    return Effect::none();
+                }
+
+                if self.delete_confirmation.is_some() {
+                    match key.as_ref() {
+                        Key::Named(Named::Escape) => self.delete_confirmation = None,
+                        Key::Named(Named::Enter) => return self.confirm_delete(),
+                        _ => {}
+                    }
+                    return Effect::none();
+                }
+
+                if self.settings_open
+                    || self.shortcuts_open
+                    || self.telemetry_events_open
+                    || self.export_dialog.is_some()
+                    || self.defer_dialog.is_some()
+                    || self.pin_dialog.is_some()
+                {
+                    if let Key::Named(Named::Escape) = key.as_ref() {
+                        self.settings_open = false;
+                        self.shortcuts_open = false;
+                        self.telemetry_events_open = false;
+                        self.export_dialog = None;
+                        self.defer_dialog = None;
+                        self.pin_dialog = None;
+                    }
+                    return Effect::none();
+                }
+
+                if self.context_menu.is_some()
+                    || self.drag.is_some()
+                    || self.column_menu_open
+                    || self.pinned_menu.is_some()
+                    || self.pin_drag.is_some()
+                {
+                    if let Key::Named(Named::Escape) = key.as_ref() {
+                        self.context_menu = None;
+                        self.drag = None;
+                        self.column_menu_open = false;
+                        self.pinned_menu = None;
+                        self.pin_drag = None;
+                        return Effect::none();
                     }
                 }
 
@@ -679,16 +2914,16 @@ impl CptDesktop {
                 if self.command_palette.open {
                     match key.as_ref() {
                         Key::Named(Named::ArrowDown) => {
-                            self.command_palette.move_selection(1);
+                            self.command_palette.move_selection(1, &self.i18n);
                         }
                         Key::Named(Named::ArrowUp) => {
-                            self.command_palette.move_selection(-1);
+                            self.command_palette.move_selection(-1, &self.i18n);
                         }
                         Key::Named(Named::Escape) => {
                             self.command_palette.close();
                         }
                         Key::Named(Named::Enter) => {
-                            if let Some(action) = self.command_palette.selected_action() {
+                            if let Some(action) = self.command_palette.selected_action(&self.i18n) {
                                 let id = action.id;
                                 return self.handle_action(id);
                             }
@@ -698,6 +2933,19 @@ impl CptDesktop {
                     return Effect::none();
                 }
 
+                // F6 has to reach the focus handling even while the capture bar has the cursor.
+                if let Some(action) = self.shortcuts.action_for(&key, modifiers).filter(|action| {
+                    matches!(
+                        action,
+                        ShortcutAction::NextRegion | ShortcutAction::PreviousRegion
+                    )
+                }) {
+                    return self.run_shortcut(action);
+                }
+                if let Some(effect) = self.navigate_focus(key.as_ref()) {
+                    return effect;
+                }
+
                 if self.capture.open {
                     match key.as_ref() {
                         Key::Named(Named::Escape) => {
@@ -705,18 +2953,31 @@ impl CptDesktop {
                             self.capture.open = false;
                         }
                         Key::Named(Named::Enter) => return self.submit_capture(),
+                        Key::Named(Named::ArrowUp) => return self.recall_capture(1),
+                        Key::Named(Named::ArrowDown) => return self.recall_capture(-1),
                         _ => {}
                     }
                     return Effect::none();
                 }
 
+                if let Some(action) = self.shortcuts.action_for(&key, modifiers) {
+                    return self.run_shortcut(action);
+                }
+
                 match key.as_ref() {
-                    Key::Named(Named::Tab) => {
-                        if modifiers.shift() {
-                            return self.move_tab(-1);
-                        } else {
-                            return self.move_tab(1);
-                        }
+                    Key::Named(Named::Backspace) => self.handle_action(CommandActionId::DeleteTask),
+                    Key::Named(Named::Escape) if !self.search.is_empty() => self.clear_search(),
+                    Key::Named(Named::Escape) if self.project_page.is_some() => {
+                        self.project_page = None;
+                        Effect::none()
+                    }
+                    Key::Named(Named::Escape) if self.keyboard_focus.is_some() => {
+                        self.keyboard_focus = None;
+                        Effect::none()
+                    }
+                    Key::Named(Named::Escape) if !self.toasts.is_empty() => {
+                        self.toasts.dismiss_latest();
+                        Effect::none()
                     }
                     Key::Named(Named::ArrowDown) => {
                         self.move_selection(1);
@@ -726,15 +2987,7 @@ impl CptDesktop {
                         self.move_selection(-1);
                         Effect::none()
                     }
-                    Key::Character(value) => match value.to_ascii_lowercase().as_str() {
-                        "/" | "?" => self.toggle_command_palette(),
-                        "a" => self.toggle_capture(),
-                        "d" => self.handle_action(CommandActionId::MarkDone),
-                        "n" => self.handle_action(CommandActionId::PromoteNext),
-                        "i" => self.handle_action(CommandActionId::MoveToInbox),
-                        "r" => self.refresh_active_view(),
-                        _ => Effect::none(),
-                    },
+                    Key::Character("/" | "?") => self.toggle_command_palette(),
                     _ => Effect::none(),
                 }
             }
@@ -787,6 +3040,13 @@ impl CptDesktop {
                 }
             }
             CommandActionId::Refresh => self.refresh_active_view(),
+            CommandActionId::Export => {
+                self.open_export_dialog();
+                Effect::none()
+            }
+            CommandActionId::Print => self.start_print(),
+            CommandActionId::Focus => self.start_focus(),
+            CommandActionId::NewWindow => self.open_view_window(),
             CommandActionId::PromoteNext => {
                 if let Some(ids) = self.selected_ids() {
                     self.apply_status_change(ids, MutationKind::Promote)
@@ -810,6 +3070,219 @@ impl CptDesktop {
             }
             CommandActionId::DeferTomorrow => self.defer_selected(ChronoDuration::days(1)),
             CommandActionId::DeferNextWeek => self.defer_selected(ChronoDuration::days(7)),
+            CommandActionId::DeferPick => {
+                self.open_defer_dialog();
+                Effect::none()
+            }
+            CommandActionId::MoveToProject => match self.selected_task.clone() {
+                Some(id) => self.start_field_edit(id, InlineEditableField::Project),
+                None => Effect::none(),
+            },
+            CommandActionId::CopyTask => self.copy_selected(),
+            CommandActionId::ToggleDetails => {
+                self.detail_open = !self.detail_open;
+                Effect::none()
+            }
+            CommandActionId::ToggleFilters => {
+                self.filter_panel_open = !self.filter_panel_open;
+                match self.service.clone() {
+                    Some(service) if self.filter_panel_open => facets_command(service),
+                    _ => Effect::none(),
+                }
+            }
+            CommandActionId::DeleteTask => {
+                self.request_delete();
+                Effect::none()
+            }
+        }
+    }
+
+    pub(super) fn copy_selected(&mut self) -> Effect {
+        let Some(line) = self
+            .current_tasks()
+            .into_iter()
+            .find(|task| Some(&task.id) == self.selected_task.as_ref())
+            .map(capture_line)
+        else {
+            return Effect::none();
+        };
+        self.toasts.push(StatusToast::info(
+            self.i18n.tr_args("toast-copied", &[("line", line.clone())]),
+        ));
+        iced::clipboard::write(line)
+    }
+
+    /// Keeps the detail panel's notes editor on the selected task. Unsaved edits are saved when
+    /// the selection moves away, and clean drafts pick up notes changed elsewhere on refresh.
+    pub(super) fn sync_notes_draft(&mut self) -> Effect {
+        let selected = self.selected_task.clone().filter(|_| self.detail_open);
+        let notes = selected.as_ref().and_then(|id| {
+            self.current_tasks()
+                .into_iter()
+                .find(|task| &task.id == id)
+                .map(|task| task.notes.clone().unwrap_or_default())
+        });
+        if let (Some(draft), Some(notes)) = (self.notes_draft.as_ref(), notes.as_ref()) {
+            if Some(&draft.task_id) == selected.as_ref() {
+                if !draft.is_dirty() && draft.original != *notes {
+                    self.notes_draft = Some(draft.reset(notes));
+                }
+                return Effect::none();
+            }
+        }
+
+        let previous = self.notes_draft.take();
+        self.notes_draft = selected
+            .zip(notes)
+            .map(|(id, notes)| NotesDraft::new(id, &notes));
+        match previous {
+            Some(draft) if draft.is_dirty() => self.save_notes(draft.task_id.clone(), draft.text()),
+            _ => Effect::none(),
+        }
+    }
+
+    /// Ticks or unticks a checklist item from the rendered notes and saves straight away.
+    fn toggle_note_checkbox(&mut self, line: usize) -> Effect {
+        let Some(draft) = self.notes_draft.as_mut() else {
+            return Effect::none();
+        };
+        let Some(notes) = toggle_checkbox(&draft.text(), line) else {
+            return Effect::none();
+        };
+        *draft = draft.reset(&notes);
+        let id = draft.task_id.clone();
+        self.save_notes(id, notes)
+    }
+
+    /// Opens the detail panel's notes, unsaved edits included, in the configured editor.
+    fn start_external_edit(&mut self) {
+        let Some(draft) = self.notes_draft.as_ref() else {
+            return;
+        };
+        let title = self
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == draft.task_id)
+            .map(|task| task.title.clone())
+            .unwrap_or_default();
+        let editor = self.window_state.state().external_editor.clone();
+        match ExternalEdit::start(
+            draft.task_id.clone(),
+            &title,
+            &draft.text(),
+            editor.as_deref(),
+        ) {
+            Ok(edit) => self.external_edit = Some(edit),
+            Err(err) => {
+                self.toasts.push(StatusToast::error(
+                    self.i18n
+                        .tr_args("toast-editor-failed", &[("error", format!("{err:#}"))]),
+                ));
+            }
+        }
+    }
+
+    /// Saves notes written in the external editor, replacing the panel's draft of that task.
+    /// Watching ends once a configured editor command exits.
+    fn poll_external_edit(&mut self) -> Effect {
+        let Some(edit) = self.external_edit.as_mut() else {
+            return Effect::none();
+        };
+        let saved = edit.poll();
+        let id = edit.task_id.clone();
+        if edit.finished() {
+            self.external_edit = None;
+        }
+        let Some(notes) = saved else {
+            return Effect::none();
+        };
+        if let Some(draft) = self
+            .notes_draft
+            .as_mut()
+            .filter(|draft| draft.task_id == id)
+        {
+            *draft = draft.reset(&notes);
+        }
+        self.save_notes(id, notes)
+    }
+
+    fn save_notes(&mut self, id: String, notes: String) -> Effect {
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let kind = MutationKind::ChangeNotes {
+            id: id.clone(),
+            notes,
+        };
+        self.apply_optimistic_update(&[id], &kind);
+        self.pending_mutations += 1;
+        Effect::perform(mutation_command(service, kind.clone()), move |result| {
+            Message::MutationFinished(kind.clone(), result)
+        })
+    }
+
+    /// Surfaces a failed save of the column layout as an error toast.
+    fn report_layout_error(&mut self, result: anyhow::Result<()>) {
+        if let Err(err) = result {
+            self.toasts.push(StatusToast::error(
+                self.i18n
+                    .tr_args("toast-layout-save-failed", &[("error", err.to_string())]),
+            ));
+        }
+    }
+
+    /// Applies a row dropped onto a sidebar view (status change) or project (reassignment).
+    pub(super) fn finish_drag(&mut self) -> Effect {
+        let Some(DragState {
+            task_id,
+            target: Some(target),
+        }) = self.drag.take()
+        else {
+            return Effect::none();
+        };
+        let ids = vec![task_id.clone()];
+        match target {
+            DropTarget::View(tab) => match tab.drop_status() {
+                // Dropping a card back onto its own column changes nothing.
+                Some(status)
+                    if self
+                        .current_tasks()
+                        .into_iter()
+                        .any(|task| task.id == task_id && task.status == status) =>
+                {
+                    Effect::none()
+                }
+                Some(TaskStatus::Inbox) => self.apply_status_change(ids, MutationKind::Inbox),
+                Some(TaskStatus::Next) => self.apply_status_change(ids, MutationKind::Promote),
+                Some(TaskStatus::Done) => self.apply_status_change(ids, MutationKind::Complete),
+                Some(status) => {
+                    self.apply_status_change(ids, |ids| MutationKind::SetStatus { ids, status })
+                }
+                None => Effect::none(),
+            },
+            DropTarget::Day(day) => self.reschedule(task_id, day),
+            DropTarget::Row(target_id) => self.reorder(task_id, target_id),
+            DropTarget::Project(project) => {
+                let unchanged = self
+                    .current_tasks()
+                    .into_iter()
+                    .any(|task| task.id == task_id && task.project.as_ref() == Some(&project));
+                if unchanged {
+                    return Effect::none();
+                }
+                let Some(service) = self.service.clone() else {
+                    return Effect::none();
+                };
+                let kind = MutationKind::ChangeProject {
+                    id: task_id,
+                    project: Some(project),
+                };
+                self.apply_optimistic_update(&ids, &kind);
+                self.pending_mutations += 1;
+                Effect::perform(mutation_command(service, kind.clone()), move |result| {
+                    Message::MutationFinished(kind.clone(), result)
+                })
+            }
         }
     }
 
@@ -818,6 +3291,29 @@ impl CptDesktop {
         Some(vec![selected.clone()])
     }
 
+    /// Opens the delete dialog for the selected task instead of deleting it outright.
+    fn request_delete(&mut self) {
+        let Some(ids) = self.selected_ids() else {
+            return;
+        };
+        let titles = self
+            .current_tasks()
+            .into_iter()
+            .filter(|task| ids.contains(&task.id))
+            .map(|task| task.title.clone())
+            .collect();
+        self.command_palette.close();
+        self.context_menu = None;
+        self.delete_confirmation = Some(DeleteConfirmation { ids, titles });
+    }
+
+    fn confirm_delete(&mut self) -> Effect {
+        match self.delete_confirmation.take() {
+            Some(confirmation) => self.apply_status_change(confirmation.ids, MutationKind::Delete),
+            None => Effect::none(),
+        }
+    }
+
     pub(super) fn apply_status_change(
         &mut self,
         ids: Vec<String>,
@@ -835,27 +3331,74 @@ impl CptDesktop {
         }
     }
 
+    /// Adds a dated follow-up line to the notes of every Waiting task on `person`.
+    fn nudge_waiting(&mut self, person: Option<String>) -> Effect {
+        let ids: Vec<String> = self
+            .current_tasks()
+            .into_iter()
+            .filter(|task| waiting_person(task) == person.as_deref())
+            .map(|task| task.id.clone())
+            .collect();
+        if ids.is_empty() {
+            return Effect::none();
+        }
+        let line = format!(
+            "Followed up with {} on {}.",
+            person.as_deref().unwrap_or("them"),
+            Local::now().format("%a %-d %b %Y")
+        );
+        self.apply_status_change(ids, move |ids| MutationKind::AppendNote {
+            ids,
+            line: line.clone(),
+        })
+    }
+
     pub(super) fn defer_selected(&mut self, offset: ChronoDuration) -> Effect {
-        let Some(selected) = self.selected_task.clone() else {
+        let Some(ids) = self.selected_ids() else {
             return Effect::none();
         };
         let until = Utc::now() + offset;
-        if let Some(service) = self.service.clone() {
-            let kind = MutationKind::Defer {
-                id: selected.clone(),
-                until,
-            };
-            self.apply_optimistic_defer(&selected, until);
-            self.pending_mutations += 1;
-            Effect::perform(mutation_command(service, kind.clone()), move |result| {
-                Message::MutationFinished(kind.clone(), result)
-            })
-        } else {
-            Effect::none()
+        self.apply_status_change(ids, move |ids| MutationKind::Defer { ids, until })
+    }
+
+    /// Opens the Defer dialog for the selected tasks.
+    fn open_defer_dialog(&mut self) {
+        let Some(ids) = self.selected_ids() else {
+            return;
+        };
+        self.command_palette.close();
+        self.context_menu = None;
+        self.defer_dialog = Some(DeferDialog {
+            ids,
+            date: String::new(),
+            error: None,
+        });
+    }
+
+    /// Defers the dialog's tasks to `spec`, or explains in the dialog why the date was rejected.
+    fn confirm_defer(&mut self, spec: &str) -> Effect {
+        let Some(dialog) = self.defer_dialog.as_mut() else {
+            return Effect::none();
+        };
+        match parse_date_spec(spec) {
+            Ok(until) => {
+                let ids = std::mem::take(&mut dialog.ids);
+                self.defer_dialog = None;
+                self.apply_status_change(ids, move |ids| MutationKind::Defer { ids, until })
+            }
+            Err(err) => {
+                dialog.error = Some(
+                    self.i18n
+                        .tr_args("error-invalid-date", &[("error", err.to_string())]),
+                );
+                Effect::none()
+            }
         }
     }
 
     pub(super) fn apply_optimistic_update(&mut self, ids: &[String], kind: &MutationKind) {
+        self.remember_rollback(kind);
+        self.remember_undo(kind);
         match kind {
             MutationKind::Rename { id, title } => self.apply_optimistic_title(id, title),
             MutationKind::ChangeProject { id, project } => {
@@ -868,18 +3411,63 @@ impl CptDesktop {
             MutationKind::ChangePriority { id, priority } => {
                 self.apply_optimistic_priority(id, *priority)
             }
+            MutationKind::ChangeEnergy { id, energy } => {
+                self.apply_optimistic_task(id, |task| task.energy = *energy)
+            }
+            MutationKind::ChangeEstimate { id, minutes } => {
+                self.apply_optimistic_task(id, |task| task.time_estimate = *minutes)
+            }
+            MutationKind::ChangeNotes { id, notes } => self.apply_optimistic_notes(id, notes),
+            MutationKind::ChangeDue { id, due } => self.apply_optimistic_due(id, *due),
+            MutationKind::Defer { ids, until } => {
+                for id in ids {
+                    self.apply_optimistic_defer(id, *until);
+                }
+            }
+            MutationKind::ChangeDefer {
+                id,
+                defer: Some(until),
+            } => self.apply_optimistic_defer(id, *until),
+            MutationKind::ChangeDefer { id, defer: None } => self.apply_optimistic_undefer(id),
+            MutationKind::Restore(tasks) => self.apply_optimistic_restore(tasks),
+            MutationKind::Reorder(order) => self.apply_optimistic_order(order),
+            MutationKind::AppendNote { ids, line } => {
+                for id in ids {
+                    self.apply_optimistic_task(id, |task| {
+                        task.notes = Some(task.notes_with_line(line))
+                    });
+                }
+            }
             _ => {
                 if let Some(store) = self.views.get_mut(&self.active) {
                     if let Some(snapshot) = store.snapshot.as_mut() {
-                        let should_remove = match kind {
-                            MutationKind::Promote(_) => self.active != ViewTab::Next,
-                            MutationKind::Complete(_) => true,
-                            MutationKind::Inbox(_) => self.active != ViewTab::Inbox,
-                            MutationKind::Defer { .. } => false,
-                            _ => false,
+                        let new_status = match kind {
+                            MutationKind::Promote(_) => Some(TaskStatus::Next),
+                            MutationKind::Complete(_) => Some(TaskStatus::Done),
+                            MutationKind::Inbox(_) => Some(TaskStatus::Inbox),
+                            MutationKind::SetStatus { status, .. } => Some(*status),
+                            _ => None,
+                        };
+                        let should_remove = match (kind, new_status) {
+                            (MutationKind::Delete(_), _) => true,
+                            // All lists every open task, so only completing one drops it.
+                            (_, Some(status)) if self.active == ViewTab::All => {
+                                status == TaskStatus::Done
+                            }
+                            (_, Some(status)) => self.active.drop_status() != Some(status),
+                            (_, None) => false,
                         };
                         if should_remove {
                             snapshot.tasks.retain(|task| !ids.contains(&task.id));
+                        } else if let Some(status) = new_status {
+                            // Tasks that stay (e.g. on the board) move to their new column.
+                            for task in snapshot
+                                .tasks
+                                .iter_mut()
+                                .filter(|task| ids.contains(&task.id))
+                            {
+                                task.status = status;
+                            }
                         }
                         store.version = store.version.wrapping_add(1);
                     }
@@ -887,12 +3475,11 @@ impl CptDesktop {
             }
         }
         self.sync_selection_with_view();
-        let label = kind.label();
-        self.status = Some(StatusToast {
-            message: format!("Queued {label}…"),
-            kind: ToastKind::Info,
-            created_at: Instant::now(),
-        });
+        let label = self.i18n.mutation(kind.label());
+        self.toasts.push(StatusToast::info(
+            self.i18n
+                .tr_args("toast-mutation-queued", &[("action", label)]),
+        ));
     }
 
     fn apply_optimistic_title(&mut self, id: &str, title: &str) {
@@ -950,6 +3537,161 @@ impl CptDesktop {
         }
     }
 
+    /// Applies `change` to the task in the active view's snapshot.
+    fn apply_optimistic_task(&mut self, id: &str, change: impl FnOnce(&mut Task)) {
+        if let Some(store) = self.views.get_mut(&self.active) {
+            if let Some(snapshot) = store.snapshot.as_mut() {
+                if let Some(task) = snapshot.tasks.iter_mut().find(|task| task.id == id) {
+                    change(task);
+                }
+                store.version = store.version.wrapping_add(1);
+            }
+        }
+    }
+
+    fn apply_optimistic_notes(&mut self, id: &str, notes: &str) {
+        if let Some(store) = self.views.get_mut(&self.active) {
+            if let Some(snapshot) = store.snapshot.as_mut() {
+                if let Some(task) = snapshot.tasks.iter_mut().find(|task| task.id == id) {
+                    task.notes = Some(notes.to_string());
+                }
+                store.version = store.version.wrapping_add(1);
+            }
+        }
+    }
+
+    fn apply_optimistic_due(&mut self, id: &str, due: Option<DateTime<Utc>>) {
+        if let Some(store) = self.views.get_mut(&self.active) {
+            if let Some(snapshot) = store.snapshot.as_mut() {
+                if let Some(task) = snapshot.tasks.iter_mut().find(|task| task.id == id) {
+                    task.due_at = due;
+                }
+                store.version = store.version.wrapping_add(1);
+            }
+        }
+    }
+
+    fn apply_optimistic_undefer(&mut self, id: &str) {
+        if let Some(store) = self.views.get_mut(&self.active) {
+            if let Some(snapshot) = store.snapshot.as_mut() {
+                if let Some(task) = snapshot.tasks.iter_mut().find(|task| task.id == id) {
+                    task.defer_until = None;
+                }
+                store.version = store.version.wrapping_add(1);
+            }
+        }
+    }
+
+    /// Keeps the rows of the view `kind` is about to change, for [`Self::roll_back`].
+    fn remember_rollback(&mut self, kind: &MutationKind) {
+        // A new order is drawn on the Next list wherever it was started from.
+        let view = match kind {
+            MutationKind::Reorder(_) => ViewTab::Next,
+            _ => self.active,
+        };
+        if let Some(snapshot) = self
+            .views
+            .get(&view)
+            .and_then(|store| store.snapshot.as_ref())
+        {
+            self.rollbacks.push(Rollback {
+                kind: kind.clone(),
+                view,
+                tasks: snapshot.tasks.clone(),
+            });
+        }
+    }
+
+    /// Takes a failed mutation's optimistic update back off the screen.
+    fn roll_back(&mut self, rollback: &Rollback) {
+        if let Some(store) = self.views.get_mut(&rollback.view) {
+            if let Some(snapshot) = store.snapshot.as_mut() {
+                rollback.restore(&mut snapshot.tasks);
+                store.version = store.version.wrapping_add(1);
+            }
+        }
+        self.sync_selection_with_view();
+    }
+
+    /// Runs the last failed mutation again.
+    fn retry_mutation(&mut self) -> Effect {
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let Some(kind) = self.retry.take() else {
+            return Effect::none();
+        };
+        self.apply_optimistic_update(&kind.task_ids(), &kind);
+        self.pending_mutations += 1;
+        Effect::perform(mutation_command(service, kind.clone()), move |result| {
+            Message::MutationFinished(kind.clone(), result)
+        })
+    }
+
+    /// Keeps copies of the tasks `kind` is about to change so the change can be undone.
+    /// Deletes are not reversible, and neither is an undo.
+    fn remember_undo(&mut self, kind: &MutationKind) {
+        // Restoring task copies cannot bring back a manual order.
+        if matches!(
+            kind,
+            MutationKind::Delete(_) | MutationKind::Restore(_) | MutationKind::Reorder(_)
+        ) {
+            return;
+        }
+        let Some(snapshot) = self
+            .views
+            .get(&self.active)
+            .and_then(|view| view.snapshot.as_ref())
+        else {
+            return;
+        };
+        let tasks: Option<Vec<Task>> = kind
+            .task_ids()
+            .iter()
+            .map(|id| snapshot.tasks.iter().find(|task| &task.id == id).cloned())
+            .collect();
+        if let Some(tasks) = tasks {
+            self.undo_pending.push(UndoEntry {
+                label: kind.label(),
+                tasks,
+                created_at: Instant::now(),
+            });
+        }
+    }
+
+    /// Writes back the tasks saved before the last successful mutation.
+    fn undo_last_mutation(&mut self) -> Effect {
+        let Some(service) = self.service.clone() else {
+            return Effect::none();
+        };
+        let Some(entry) = self.undo.take() else {
+            return Effect::none();
+        };
+        let kind = MutationKind::Restore(entry.tasks);
+        self.apply_optimistic_update(&kind.task_ids(), &kind);
+        self.pending_mutations += 1;
+        Effect::perform(mutation_command(service, kind.clone()), move |result| {
+            Message::MutationFinished(kind.clone(), result)
+        })
+    }
+
+    fn apply_optimistic_restore(&mut self, tasks: &[Task]) {
+        if let Some(store) = self.views.get_mut(&self.active) {
+            if let Some(snapshot) = store.snapshot.as_mut() {
+                for restored in tasks {
+                    if let Some(task) = snapshot
+                        .tasks
+                        .iter_mut()
+                        .find(|task| task.id == restored.id)
+                    {
+                        *task = restored.clone();
+                    }
+                }
+                store.version = store.version.wrapping_add(1);
+            }
+        }
+    }
+
     pub(super) fn apply_optimistic_defer(&mut self, id: &str, until: DateTime<Utc>) {
         if let Some(store) = self.views.get_mut(&self.active) {
             if let Some(snapshot) = store.snapshot.as_mut() {
@@ -966,24 +3708,106 @@ impl CptDesktop {
             }
         }
         self.sync_selection_with_view();
-        self.status = Some(StatusToast {
-            message: "Queued defer…".into(),
-            kind: ToastKind::Info,
-            created_at: Instant::now(),
-        });
+        self.toasts.push(StatusToast::info(self.i18n.tr_args(
+            "toast-mutation-queued",
+            &[("action", self.i18n.mutation("defer"))],
+        )));
+    }
+
+    pub(super) fn apply_optimistic_order(&mut self, order: &[String]) {
+        if let Some(store) = self.views.get_mut(&ViewTab::Next) {
+            if let Some(snapshot) = store.snapshot.as_mut() {
+                snapshot.tasks.sort_by_key(|task| {
+                    order
+                        .iter()
+                        .position(|id| *id == task.id)
+                        .unwrap_or(order.len())
+                });
+                store.version = store.version.wrapping_add(1);
+            }
+        }
     }
 
+    /// Tasks of the active view that match the toolbar search.
     pub(super) fn current_tasks(&self) -> Vec<&Task> {
         self.views
             .get(&self.active)
             .and_then(|view| view.snapshot.as_ref())
-            .map(|snapshot| snapshot.tasks.iter().collect())
+            .map(|snapshot| {
+                snapshot
+                    .tasks
+                    .iter()
+                    .filter(|task| matches_search(task, &self.search))
+                    .collect()
+            })
             .unwrap_or_default()
     }
+
+    /// `(matches, total)` for the active view while a search is typed. Paged views count in the
+    /// database, rows not loaded yet included, and report nothing until that count arrives.
+    pub(super) fn search_counts(&self) -> Option<(usize, usize)> {
+        if self.search.trim().is_empty() {
+            return None;
+        }
+        if self.active.page_size().is_some() {
+            return self
+                .view_counts()
+                .map(|counts| (counts.matches, counts.total));
+        }
+        let total = self
+            .views
+            .get(&self.active)
+            .and_then(|view| view.snapshot.as_ref())
+            .map(|snapshot| snapshot.tasks.len())
+            .unwrap_or_default();
+        Some((self.current_tasks().len(), total))
+    }
+
+    /// Rows of the active view matching the search, those a paged view has not loaded included.
+    pub(super) fn view_row_count(&self) -> usize {
+        self.view_counts()
+            .map_or_else(|| self.current_tasks().len(), |counts| counts.matches)
+    }
+
+    /// The active paged view's database counts, once they are for the current search.
+    fn view_counts(&self) -> Option<&ViewCounts> {
+        let text = search_text(&self.search);
+        self.views
+            .get(&self.active)
+            .and_then(|view| view.counts.as_ref())
+            .filter(|counts| self.active.page_size().is_some() && counts.search == text)
+    }
+
+    pub(super) fn clear_search(&mut self) -> Effect {
+        self.search.clear();
+        self.sync_selection_with_view();
+        self.reload_searched_view()
+    }
+
+    /// Paged views search in the database, so a changed search reloads them from the first page.
+    fn reload_searched_view(&mut self) -> Effect {
+        let text = search_text(&self.search);
+        let Some(store) = self
+            .views
+            .get_mut(&self.active)
+            .filter(|_| self.active.page_size().is_some())
+        else {
+            return Effect::none();
+        };
+        if store
+            .snapshot
+            .as_ref()
+            .is_some_and(|snapshot| snapshot.filters.text == text)
+        {
+            return Effect::none();
+        }
+        store.pages = 1;
+        self.load_view(self.active)
+    }
 }
 
 impl CptDesktop {
-    fn collect_projects(&self) -> Vec<String> {
+    pub(super) fn collect_projects(&self) -> Vec<String> {
         let mut set = BTreeSet::new();
         for task in self.current_tasks() {
             if let Some(project) = task.project.as_ref() {
@@ -1023,6 +3847,16 @@ impl CptDesktop {
     }
 }
 
+fn energy_options() -> Vec<String> {
+    std::iter::once(NONE_OPTION_LABEL.to_string())
+        .chain(
+            [EnergyLevel::Low, EnergyLevel::Med, EnergyLevel::High]
+                .iter()
+                .map(|energy| energy.as_str().to_string()),
+        )
+        .collect()
+}
+
 fn priority_options() -> Vec<String> {
     PRIORITY_CHOICES
         .iter()
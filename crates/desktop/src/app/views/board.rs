@@ -0,0 +1,178 @@
+use cpt_core::model::Task;
+use iced::border::{Border, Radius};
+use iced::widget::{column, container, mouse_area, row, scrollable, text, Space};
+use iced::{mouse, Background, Element, Length, Shadow};
+
+use crate::app::helpers::{format_datetime, matches_search};
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::state::{DropTarget, ViewTab};
+use crate::app::theme::Palette;
+use crate::app::window_state::ViewMode;
+
+use super::super::desktop::CptDesktop;
+use super::styles::with_alpha;
+
+/// Status columns in sidebar order; dropping a card on one sets the task to that status.
+const BOARD_COLUMNS: [ViewTab; 6] = [
+    ViewTab::Inbox,
+    ViewTab::Next,
+    ViewTab::Scheduled,
+    ViewTab::Waiting,
+    ViewTab::Someday,
+    ViewTab::Done,
+];
+
+impl CptDesktop {
+    /// The active view as a kanban board, or `None` when the table should be drawn instead.
+    pub(crate) fn board(&self) -> Option<Element<'_, Message>> {
+        if self.window_state.state().view_mode != ViewMode::Board {
+            return None;
+        }
+        let snapshot = self.views.get(&self.active)?.snapshot.as_ref()?;
+        if snapshot.is_project_view() {
+            return None;
+        }
+        let palette = self.palette;
+        let drag_target = self.drag.as_ref().and_then(|drag| drag.target.as_ref());
+        let dragging = self.drag.is_some();
+
+        let columns = BOARD_COLUMNS.iter().fold(row![].spacing(12), |board, tab| {
+            let status = tab.drop_status();
+            let cards: Vec<&Task> = snapshot
+                .tasks
+                .iter()
+                .filter(|task| status == Some(task.status))
+                .filter(|task| matches_search(task, &self.search))
+                .collect();
+            let target = DropTarget::View(*tab);
+            let highlighted = drag_target == Some(&target);
+
+            let header = row![
+                text(self.i18n.view_title(*tab))
+                    .size(14)
+                    .color(palette.text_primary),
+                Space::new().width(Length::Fill),
+                text(cards.len().to_string())
+                    .size(12)
+                    .color(palette.text_muted),
+            ];
+            let list = if cards.is_empty() {
+                column![text(self.i18n.tr("board-drop-here"))
+                    .size(12)
+                    .color(palette.text_muted)]
+            } else {
+                cards.into_iter().fold(column![].spacing(8), |list, task| {
+                    let selected = self.selected_task.as_deref() == Some(task.id.as_str());
+                    list.push(board_card(task, palette, selected, &self.i18n))
+                })
+            };
+
+            let lane = container(
+                column![
+                    header,
+                    scrollable(list.width(Length::Fill)).height(Length::Fill)
+                ]
+                .spacing(10),
+            )
+            .padding(10)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(move |_| board_column_style(palette, highlighted));
+
+            let lane: Element<'_, Message> = if dragging {
+                mouse_area(lane)
+                    .on_enter(Message::DragTargetEntered(target.clone()))
+                    .on_exit(Message::DragTargetLeft(target))
+                    .into()
+            } else {
+                lane.into()
+            };
+            board.push(lane)
+        });
+
+        Some(
+            container(columns.height(Length::Fill))
+                .padding([12, 0])
+                .height(Length::Fill)
+                .into(),
+        )
+    }
+}
+
+fn board_card<'a>(
+    task: &'a Task,
+    palette: Palette,
+    selected: bool,
+    i18n: &Catalog,
+) -> Element<'a, Message> {
+    let mut details = Vec::new();
+    if let Some(project) = &task.project {
+        details.push(project.clone());
+    }
+    if let Some(due) = task.due_at {
+        details.push(i18n.tr_args("task-due", &[("date", format_datetime(due))]));
+    }
+    if !task.contexts.is_empty() {
+        details.push(task.contexts.join(" "));
+    }
+
+    let mut body = column![text(&task.title).size(14).color(palette.text_primary)].spacing(4);
+    if !details.is_empty() {
+        body = body.push(
+            text(details.join(" · "))
+                .size(12)
+                .color(palette.text_secondary),
+        );
+    }
+
+    let card = container(body)
+        .padding(10)
+        .width(Length::Fill)
+        .style(move |_| board_card_style(palette, selected));
+
+    mouse_area(card)
+        .on_press(Message::DragStarted(task.id.clone()))
+        .on_right_press(Message::ContextMenuOpened(task.id.clone()))
+        .interaction(mouse::Interaction::Grab)
+        .into()
+}
+
+fn board_column_style(palette: Palette, highlighted: bool) -> container::Style {
+    let (background, border) = if highlighted {
+        (with_alpha(palette.primary, 0.12), palette.primary)
+    } else {
+        (
+            with_alpha(palette.surface_muted, 0.3),
+            with_alpha(palette.border, 0.5),
+        )
+    };
+    container::Style {
+        background: Some(Background::Color(background)),
+        border: Border {
+            color: border,
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    }
+}
+
+fn board_card_style(palette: Palette, selected: bool) -> container::Style {
+    let background = if selected {
+        with_alpha(palette.primary, 0.18)
+    } else {
+        palette.surface
+    };
+    container::Style {
+        background: Some(Background::Color(background)),
+        border: Border {
+            color: with_alpha(palette.border, 0.6),
+            width: 1.0,
+            radius: Radius::from(6.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+
+use chrono::{Local, NaiveDate};
+use cpt_core::model::Task;
+use iced::alignment::Horizontal;
+use iced::border::{Border, Radius};
+use iced::widget::{button, column, container, mouse_area, row, scrollable, text, Space};
+use iced::{mouse, Alignment, Background, Element, Length, Shadow};
+
+use crate::app::calendar::{placement, CalendarSpan};
+use crate::app::helpers::matches_search;
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::state::{DropTarget, ViewTab};
+use crate::app::theme::Palette;
+use crate::app::window_state::ViewMode;
+
+use super::super::desktop::CptDesktop;
+use super::styles::{ghost_button_style, labelled, primary_button_style, with_alpha};
+
+/// Weekday ids, `calendar-mon` and so on in the catalogs.
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+const MONTH_CELL_HEIGHT: f32 = 104.0;
+/// Task chips shown in a month cell before collapsing into "+N more".
+const MONTH_CELL_TASKS: usize = 3;
+const DAY_PANEL_HEIGHT: f32 = 180.0;
+
+impl CptDesktop {
+    /// The Scheduled view as a month or week calendar, or `None` when it is drawn another way.
+    pub(crate) fn calendar_view(&self) -> Option<Element<'_, Message>> {
+        if self.window_state.state().view_mode != ViewMode::Calendar
+            || self.active != ViewTab::Scheduled
+        {
+            return None;
+        }
+        let snapshot = self.views.get(&self.active)?.snapshot.as_ref()?;
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let calendar = &self.calendar;
+        let today = Local::now().date_naive();
+        let drag_target = self.drag.as_ref().and_then(|drag| drag.target.as_ref());
+        let dragging = self.drag.is_some();
+
+        let mut by_day: BTreeMap<NaiveDate, Vec<&Task>> = BTreeMap::new();
+        for task in snapshot
+            .tasks
+            .iter()
+            .filter(|task| matches_search(task, &self.search))
+        {
+            if let Some(day) = placement(task) {
+                by_day.entry(day).or_default().push(task);
+            }
+        }
+
+        let spans = CalendarSpan::ALL
+            .iter()
+            .fold(row![].spacing(2), |spans, span| {
+                let selected = *span == calendar.span;
+                spans.push(toggle_button(
+                    i18n.tr(&format!("calendar-{}", span.name().to_ascii_lowercase())),
+                    selected,
+                    Message::CalendarSpanPicked(*span),
+                    palette,
+                ))
+            });
+        let header = row![
+            labelled(
+                nav_button("‹".into(), Message::CalendarShifted(-1), palette),
+                i18n.tr("calendar-previous"),
+                palette
+            ),
+            nav_button(
+                i18n.tr("calendar-today"),
+                Message::CalendarShifted(0),
+                palette
+            ),
+            labelled(
+                nav_button("›".into(), Message::CalendarShifted(1), palette),
+                i18n.tr("calendar-next"),
+                palette
+            ),
+            text(calendar.title()).size(16).color(palette.text_primary),
+            Space::new().width(Length::Fill),
+            spans,
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center);
+
+        let weekdays = WEEKDAYS.iter().fold(row![].spacing(6), |weekdays, day| {
+            weekdays.push(
+                text(i18n.tr(&format!("calendar-{day}")))
+                    .size(12)
+                    .color(palette.text_muted)
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center),
+            )
+        });
+
+        let cell_height = match calendar.span {
+            CalendarSpan::Month => Length::Fixed(MONTH_CELL_HEIGHT),
+            CalendarSpan::Week => Length::Fill,
+        };
+        let days = calendar.days();
+        let mut grid = column![].spacing(6);
+        for week in days.chunks(7) {
+            let cells = week.iter().fold(row![].spacing(6), |cells, day| {
+                let tasks = by_day.get(day).cloned().unwrap_or_default();
+                let target = DropTarget::Day(*day);
+                let cell = DayCell {
+                    day: *day,
+                    tasks,
+                    today: *day == today,
+                    in_focus: calendar.in_focus(*day),
+                    selected: calendar.selected_day == Some(*day),
+                    highlighted: drag_target == Some(&target),
+                    limit: match calendar.span {
+                        CalendarSpan::Month => Some(MONTH_CELL_TASKS),
+                        CalendarSpan::Week => None,
+                    },
+                };
+                let cell = mouse_area(cell.view(cell_height, palette, i18n))
+                    .on_press(Message::CalendarDaySelected(*day));
+                let cell = if dragging {
+                    cell.on_enter(Message::DragTargetEntered(target.clone()))
+                        .on_exit(Message::DragTargetLeft(target))
+                } else {
+                    cell
+                };
+                cells.push(cell)
+            });
+            grid = grid.push(cells);
+        }
+
+        let mut content = column![header, weekdays, grid].spacing(10);
+        if let Some(day) = calendar.selected_day {
+            let tasks = by_day.get(&day).cloned().unwrap_or_default();
+            content = content.push(day_panel(
+                day,
+                tasks,
+                palette,
+                self.selected_task.as_deref(),
+                i18n,
+            ));
+        }
+
+        Some(
+            container(content)
+                .padding([12, 0])
+                .height(Length::Fill)
+                .into(),
+        )
+    }
+}
+
+struct DayCell<'a> {
+    day: NaiveDate,
+    tasks: Vec<&'a Task>,
+    today: bool,
+    in_focus: bool,
+    selected: bool,
+    highlighted: bool,
+    limit: Option<usize>,
+}
+
+impl<'a> DayCell<'a> {
+    fn view(self, height: Length, palette: Palette, i18n: &Catalog) -> Element<'a, Message> {
+        let number_color = if self.today {
+            palette.primary
+        } else if self.in_focus {
+            palette.text_primary
+        } else {
+            palette.text_muted
+        };
+        let mut body = column![text(self.day.format("%-d").to_string())
+            .size(13)
+            .color(number_color)]
+        .spacing(4);
+
+        let shown = self.limit.unwrap_or(self.tasks.len()).min(self.tasks.len());
+        for task in self.tasks.iter().take(shown).copied() {
+            body = body.push(task_chip(task, palette));
+        }
+        if shown < self.tasks.len() {
+            body = body.push(
+                text(i18n.tr_args(
+                    "calendar-more",
+                    &[("count", (self.tasks.len() - shown).to_string())],
+                ))
+                .size(11)
+                .color(palette.text_muted),
+            );
+        }
+
+        let (selected, highlighted) = (self.selected, self.highlighted);
+        container(scrollable(body.width(Length::Fill)))
+            .padding(6)
+            .width(Length::Fill)
+            .height(height)
+            .style(move |_| day_cell_style(palette, selected, highlighted))
+            .into()
+    }
+}
+
+/// A task on the grid; dragging it to another day moves its date there.
+fn task_chip<'a>(task: &'a Task, palette: Palette) -> Element<'a, Message> {
+    let color = if task.due_at.is_some() {
+        palette.warning
+    } else {
+        palette.info
+    };
+    let chip = container(text(&task.title).size(12).color(palette.text_primary))
+        .padding([2, 6])
+        .width(Length::Fill)
+        .style(move |_| container::Style {
+            background: Some(Background::Color(with_alpha(color, 0.18))),
+            border: Border {
+                color,
+                width: 0.0,
+                radius: Radius::from(4.0),
+            },
+            shadow: Shadow::default(),
+            ..container::Style::default()
+        });
+    mouse_area(chip)
+        .on_press(Message::DragStarted(task.id.clone()))
+        .on_right_press(Message::ContextMenuOpened(task.id.clone()))
+        .interaction(mouse::Interaction::Grab)
+        .into()
+}
+
+/// Everything on the selected day with its due or defer time.
+fn day_panel<'a>(
+    day: NaiveDate,
+    tasks: Vec<&'a Task>,
+    palette: Palette,
+    selected_task: Option<&str>,
+    i18n: &Catalog,
+) -> Element<'a, Message> {
+    let mut list = column![].spacing(4);
+    if tasks.is_empty() {
+        list = list.push(
+            text(i18n.tr("calendar-nothing"))
+                .size(12)
+                .color(palette.text_muted),
+        );
+    }
+    for task in tasks {
+        let when = match (task.due_at, task.defer_until) {
+            (Some(due), _) => i18n.tr_args(
+                "calendar-due",
+                &[(
+                    "time",
+                    due.with_timezone(&Local).format("%H:%M").to_string(),
+                )],
+            ),
+            (None, Some(defer)) => i18n.tr_args(
+                "calendar-starts",
+                &[(
+                    "time",
+                    defer.with_timezone(&Local).format("%H:%M").to_string(),
+                )],
+            ),
+            (None, None) => String::new(),
+        };
+        let selected = selected_task == Some(task.id.as_str());
+        list = list.push(
+            mouse_area(
+                container(
+                    row![
+                        text(&task.title)
+                            .size(14)
+                            .color(palette.text_primary)
+                            .width(Length::Fill),
+                        text(when).size(12).color(palette.text_secondary),
+                    ]
+                    .spacing(8),
+                )
+                .padding([4, 8])
+                .width(Length::Fill)
+                .style(move |_| container::Style {
+                    background: selected
+                        .then(|| Background::Color(with_alpha(palette.primary, 0.18))),
+                    ..container::Style::default()
+                }),
+            )
+            .on_press(Message::RowSelected(task.id.clone()))
+            .on_right_press(Message::ContextMenuOpened(task.id.clone())),
+        );
+    }
+
+    column![
+        text(day.format("%A, %B %-d").to_string())
+            .size(14)
+            .color(palette.text_secondary),
+        scrollable(list).height(Length::Fixed(DAY_PANEL_HEIGHT)),
+    ]
+    .spacing(6)
+    .into()
+}
+
+fn nav_button(label: String, message: Message, palette: Palette) -> Element<'static, Message> {
+    button(text(label).size(14).color(palette.secondary_text))
+        .on_press(message)
+        .style(move |_, status| ghost_button_style(palette, status))
+        .into()
+}
+
+fn toggle_button(
+    label: String,
+    selected: bool,
+    message: Message,
+    palette: Palette,
+) -> Element<'static, Message> {
+    let color = if selected {
+        palette.primary_text
+    } else {
+        palette.secondary_text
+    };
+    button(text(label).size(14).color(color))
+        .on_press(message)
+        .style(move |_, status| {
+            if selected {
+                primary_button_style(palette, status)
+            } else {
+                ghost_button_style(palette, status)
+            }
+        })
+        .into()
+}
+
+fn day_cell_style(palette: Palette, selected: bool, highlighted: bool) -> container::Style {
+    let background = if highlighted {
+        with_alpha(palette.primary, 0.12)
+    } else {
+        with_alpha(palette.surface_muted, 0.3)
+    };
+    let border = if selected || highlighted {
+        palette.primary
+    } else {
+        with_alpha(palette.border, 0.5)
+    };
+    container::Style {
+        background: Some(Background::Color(background)),
+        border: Border {
+            color: border,
+            width: 1.0,
+            radius: Radius::from(6.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    }
+}
@@ -1,30 +1,40 @@
+use std::path::Path;
+
 use iced::border::{Border, Radius};
 use iced::font::Weight as FontWeight;
-use iced::widget::{column, container, row, text, text_input, Space};
+use iced::widget::text::Span;
+use iced::widget::{
+    button, column, container, rich_text, row, scrollable, span, text, text_input, Space,
+};
 use iced::{Alignment, Background, Element, Font, Length, Shadow};
 
+use crate::app::helpers::apply_token_fix;
+use crate::app::i18n::Catalog;
 use crate::app::message::Message;
-use crate::app::state::{CaptureChip, CaptureChipKind};
+use crate::app::state::{BulkCapture, CaptureChip, CaptureChipKind, CaptureState};
 use crate::app::theme::Palette;
 
 use super::super::desktop::CptDesktop;
-use super::styles::{chip_style, text_input_style, with_alpha};
+use super::styles::{
+    capture_input_style, chip_style, ghost_button_style, labelled, primary_button_style, with_alpha,
+};
+
+/// Tallest the multi-line paste preview and the Recent list grow before they scroll.
+const BULK_PREVIEW_HEIGHT: f32 = 220.0;
 
 // Capture tokens are the power-user syntax, so keep them discoverable in the desktop capture flow.
+// Each token is paired with the catalog id of its description.
 const TOKEN_HINTS: [(&str, &str); 10] = [
-    ("@context", "Context label (@home, @phone)"),
-    ("+project", "Project name (+Website)"),
-    ("#tag", "Tag (#ops)"),
-    (
-        "due:DATE",
-        "Due date (today, tomorrow, fri, 2025-01-20, +3d)",
-    ),
-    ("defer:DATE", "Start date (tomorrow, +1w)"),
-    ("t:30m", "Time estimate (minutes or 2h)"),
-    ("e:low|med|high", "Energy level"),
-    ("p:0-3", "Priority (0=low … 3=high)"),
-    ("wait:Name", "Waiting on person/contact"),
-    ("since:DATE", "Waiting since (today, +2d)"),
+    ("@context", "capture-hint-context"),
+    ("+project", "capture-hint-project"),
+    ("#tag", "capture-hint-tag"),
+    ("due:DATE", "capture-hint-due"),
+    ("defer:DATE", "capture-hint-defer"),
+    ("t:30m", "capture-hint-estimate"),
+    ("e:low|med|high", "capture-hint-energy"),
+    ("p:0-3", "capture-hint-priority"),
+    ("wait:Name", "capture-hint-wait"),
+    ("since:DATE", "capture-hint-since"),
 ];
 
 impl CptDesktop {
@@ -34,38 +44,291 @@ impl CptDesktop {
         }
 
         let palette = self.palette;
-        let input = text_input("Add a task with inline tokens", &self.capture.text)
+        let invalid = self.capture.token_error.is_some();
+        let input = text_input(&self.i18n.tr("capture-placeholder"), &self.capture.text)
             .id(self.capture_input_id.clone())
             .on_input(Message::CaptureTextChanged)
+            .on_paste(Message::CapturePasted)
             .on_submit(Message::CaptureSubmit)
             .padding(12)
-            .style(move |_, status| text_input_style(palette, status));
+            .style(move |_, status| capture_input_style(palette, status, invalid));
 
-        let chips_row: Element<'_, Message> = if let Some(preview) = &self.capture.preview {
-            preview
-                .chips
-                .iter()
-                .fold(row![].spacing(8).align_y(Alignment::Center), |row, chip| {
-                    row.push(capture_chip(chip, palette))
-                })
-                .into()
+        if let Some(bulk) = &self.capture.bulk {
+            return column![input, self.bulk_capture_panel(bulk)]
+                .spacing(4)
+                .into();
+        }
+
+        let mut chips = row![].spacing(8).align_y(Alignment::Center);
+        if let Some(preview) = &self.capture.preview {
+            for chip in &preview.chips {
+                chips = chips.push(capture_chip(chip, palette));
+            }
+        }
+        for (index, path) in self.capture.attachments.iter().enumerate() {
+            chips = chips.push(attachment_chip(index, path, palette, &self.i18n));
+        }
+        let chips_row: Element<'_, Message> = chips.into();
+
+        let error = capture_error(
+            &self.capture,
+            palette,
+            &self.i18n,
+            Message::CaptureTextChanged,
+        )
+        .unwrap_or_else(|| text("").size(12).color(palette.text_muted).into());
+
+        let entries = self.capture_history.entries();
+        if entries.is_empty() {
+            return column![
+                input,
+                chips_row,
+                error,
+                token_hint_section(palette, &self.i18n)
+            ]
+            .spacing(4)
+            .into();
+        }
+        let arrow = if self.capture.recent_open {
+            "▴"
         } else {
-            Space::new().height(Length::Shrink).into()
+            "▾"
         };
+        let recent_toggle = button(
+            text(format!("{} {arrow}", self.i18n.tr("capture-recent")))
+                .size(13)
+                .color(palette.secondary_text),
+        )
+        .padding([12, 12])
+        .on_press(Message::CaptureRecentToggled)
+        .style(move |_, status| ghost_button_style(palette, status));
+        let input_row = row![input, recent_toggle]
+            .spacing(8)
+            .align_y(Alignment::Center);
+
+        let mut body = column![input_row].spacing(4);
+        if self.capture.recent_open {
+            body = body.push(self.recent_captures(entries));
+        }
+        body.push(chips_row)
+            .push(error)
+            .push(token_hint_section(palette, &self.i18n))
+            .into()
+    }
+}
+
+/// A dropped file the task will link to from its notes, with × to leave it out.
+fn attachment_chip(
+    index: usize,
+    path: &Path,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    container(
+        row![
+            text(format!("📎 {name}")).size(12),
+            labelled(
+                button(text("×").size(12))
+                    .padding([0, 4])
+                    .on_press(Message::CaptureAttachmentRemoved(index))
+                    .style(move |_, status| ghost_button_style(palette, status)),
+                i18n.tr("capture-remove-attachment"),
+                palette,
+            ),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center),
+    )
+    .padding([2, 8])
+    .style(move |_| chip_style(palette.info))
+    .into()
+}
 
-        let error = if let Some(err) = &self.capture.preview_error {
-            text(err).size(12).color(self.palette.danger)
+impl CptDesktop {
+    /// The Recent list: earlier capture lines, newest first, each put back in the field as typed.
+    fn recent_captures<'a>(&'a self, entries: &'a [String]) -> Element<'a, Message> {
+        let palette = self.palette;
+        let lines = entries.iter().fold(column![].spacing(2), |lines, line| {
+            lines.push(
+                button(text(line).size(13).color(palette.text_primary))
+                    .width(Length::Fill)
+                    .on_press(Message::CaptureRecentPicked(line.clone()))
+                    .style(move |_, status| ghost_button_style(palette, status)),
+            )
+        });
+        let footer = row![
+            text(self.i18n.tr("capture-recent-hint"))
+                .size(12)
+                .color(palette.text_muted),
+            Space::new().width(Length::Fill),
+            button(
+                text(self.i18n.tr("capture-recent-clear"))
+                    .size(12)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::CaptureHistoryCleared)
+            .style(move |_, status| ghost_button_style(palette, status)),
+        ]
+        .align_y(Alignment::Center);
+
+        let lines =
+            container(scrollable(lines.width(Length::Fill))).max_height(BULK_PREVIEW_HEIGHT);
+        container(column![lines, footer].spacing(6))
+            .padding(8)
+            .style(move |_| recent_list_style(palette))
+            .into()
+    }
+}
+
+fn recent_list_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface)),
+        border: Border {
+            color: palette.border,
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        ..container::Style::default()
+    }
+}
+
+impl CptDesktop {
+    /// Offer shown after pasting several lines: a preview of the task each line becomes, and a
+    /// choice between adding them all (Enter) or keeping the paste as a single task.
+    fn bulk_capture_panel<'a>(&'a self, bulk: &'a BulkCapture) -> Element<'a, Message> {
+        let palette = self.palette;
+        let count = bulk.lines.len().to_string();
+
+        let lines =
+            bulk.lines
+                .iter()
+                .fold(column![].spacing(6), |lines, line| match &line.preview {
+                    Ok(preview) => lines.push(
+                        preview.chips.iter().fold(
+                            row![text(&preview.title).size(13).color(palette.text_primary)]
+                                .spacing(8)
+                                .align_y(Alignment::Center),
+                            |row, chip| row.push(capture_chip(chip, palette)),
+                        ),
+                    ),
+                    Err(err) => lines.push(
+                        column![
+                            text(&line.text).size(13).color(palette.text_primary),
+                            text(err).size(12).color(palette.danger),
+                        ]
+                        .spacing(2),
+                    ),
+                });
+
+        let valid = bulk.is_valid();
+        let note = if valid {
+            text("").size(12)
         } else {
-            text("").size(12).color(self.palette.text_muted)
+            text(self.i18n.tr("capture-bulk-invalid"))
+                .size(12)
+                .color(palette.danger)
         };
+        let confirm = (valid && !self.capture.submitting).then_some(Message::CaptureSubmit);
+        let actions = row![
+            note,
+            Space::new().width(Length::Fill),
+            button(
+                text(self.i18n.tr("capture-bulk-single"))
+                    .size(13)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::BulkCaptureDismissed)
+            .style(move |_, status| ghost_button_style(palette, status)),
+            button(
+                text(
+                    self.i18n
+                        .tr_args("capture-bulk-confirm", &[("count", count.clone())])
+                )
+                .size(13)
+                .color(palette.primary_text)
+            )
+            .on_press_maybe(confirm)
+            .style(move |_, status| primary_button_style(palette, status)),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center);
 
-        column![input, chips_row, error, token_hint_section(palette)]
-            .spacing(4)
-            .into()
+        container(
+            column![
+                text(self.i18n.tr_args("capture-bulk-title", &[("count", count)]))
+                    .size(14)
+                    .color(palette.text_primary),
+                container(scrollable(lines.width(Length::Fill))).max_height(BULK_PREVIEW_HEIGHT),
+                actions,
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .padding([12, 16])
+        .style(move |_| token_hint_container_style(palette))
+        .into()
+    }
+}
+
+/// What is wrong with the capture text: for a bad token, the text again with that token marked
+/// and a button that applies the suggested fix through `on_fix`; otherwise the parser's message.
+pub(super) fn capture_error<'a>(
+    capture: &'a CaptureState,
+    palette: Palette,
+    i18n: &Catalog,
+    on_fix: fn(String) -> Message,
+) -> Option<Element<'a, Message>> {
+    let message = capture.preview_error.as_ref()?;
+    let Some(token) = &capture.token_error else {
+        return Some(text(message).size(12).color(palette.danger).into());
+    };
+    let range = token.range();
+    let (Some(before), Some(bad), Some(after)) = (
+        capture.text.get(..range.start),
+        capture.text.get(range.clone()),
+        capture.text.get(range.end..),
+    ) else {
+        return Some(text(message).size(12).color(palette.danger).into());
+    };
+
+    let spans: [Span<'_, ()>; 3] = [
+        span(before).size(13).color(palette.text_secondary),
+        span(bad)
+            .size(13)
+            .color(palette.danger)
+            .background(with_alpha(palette.danger, 0.15))
+            .underline(true),
+        span(after).size(13).color(palette.text_secondary),
+    ];
+    let marked = rich_text(spans);
+    let mut details = row![text(message).size(12).color(palette.danger)]
+        .spacing(8)
+        .align_y(Alignment::Center);
+    if let (Some(fixed), Some(suggestion)) =
+        (apply_token_fix(&capture.text, token), &token.suggestion)
+    {
+        details = details.push(
+            button(
+                text(i18n.tr_args(
+                    "capture-did-you-mean",
+                    &[("suggestion", suggestion.clone())],
+                ))
+                .size(12)
+                .color(palette.secondary_text),
+            )
+            .padding([2, 8])
+            .on_press(on_fix(fixed))
+            .style(move |_, status| ghost_button_style(palette, status)),
+        );
     }
+    Some(column![marked, details].spacing(2).into())
 }
 
-fn capture_chip(chip: &CaptureChip, palette: Palette) -> Element<'_, Message> {
+pub(super) fn capture_chip(chip: &CaptureChip, palette: Palette) -> Element<'_, Message> {
     use iced::alignment::{Horizontal, Vertical};
     use iced::widget::{container, text};
 
@@ -91,19 +354,19 @@ fn capture_chip(chip: &CaptureChip, palette: Palette) -> Element<'_, Message> {
     .into()
 }
 
-fn token_hint_section(palette: Palette) -> Element<'static, Message> {
+fn token_hint_section(palette: Palette, i18n: &Catalog) -> Element<'static, Message> {
     use iced::widget::{column, row, text};
 
     let hints = TOKEN_HINTS
         .iter()
-        .fold(column![].spacing(6), |column, (token, description)| {
+        .fold(column![].spacing(6), |column, (token, id)| {
             column.push(
                 row![
                     text(*token).size(12).color(palette.info).font(Font {
                         weight: FontWeight::Bold,
                         ..Font::DEFAULT
                     }),
-                    text(*description).size(12).color(palette.text_muted),
+                    text(i18n.tr(id)).size(12).color(palette.text_muted),
                 ]
                 .spacing(12)
                 .align_y(Alignment::Center),
@@ -4,6 +4,7 @@ use iced::widget::{button, column, container, row, text, text_input, Space};
 use iced::{Alignment, Background, Element, Length, Shadow};
 
 use crate::app::message::Message;
+use crate::app::shortcuts::ShortcutAction;
 use crate::app::state::CommandActionId;
 
 use super::super::desktop::CptDesktop;
@@ -16,20 +17,35 @@ impl CptDesktop {
             return None;
         }
 
-        let filtered = self.command_palette.filtered();
+        let filtered = self.command_palette.filtered(&self.i18n);
         let palette = self.palette;
         let items = if filtered.is_empty() {
-            column![text("No matches").size(14).color(palette.text_muted)]
+            column![text(self.i18n.tr("palette-empty"))
+                .size(14)
+                .color(palette.text_muted)]
         } else {
             filtered.into_iter().enumerate().fold(
                 column![].spacing(6),
                 |column, (index, action)| {
-                    let content = column![
-                        text(action.label).size(16).color(palette.text_primary),
-                        text(action.description).size(12).color(palette.text_muted),
+                    let keys = ShortcutAction::for_command(action.id)
+                        .and_then(|shortcut| self.shortcuts.hint(shortcut))
+                        .unwrap_or_default();
+                    let content = row![
+                        column![
+                            text(self.i18n.action_label(action.id))
+                                .size(16)
+                                .color(palette.text_primary),
+                            text(self.i18n.action_description(action.id))
+                                .size(12)
+                                .color(palette.text_muted),
+                        ]
+                        .spacing(4)
+                        .width(Length::Fill)
+                        .align_x(Alignment::Start),
+                        text(keys).size(12).color(palette.text_muted),
                     ]
-                    .spacing(4)
-                    .align_x(Alignment::Start);
+                    .spacing(8)
+                    .align_y(Alignment::Center);
 
                     let selected = index == self.command_palette.selected;
 
@@ -44,20 +60,23 @@ impl CptDesktop {
         };
 
         let header = row![
-            text("Command palette").size(20).color(palette.text_primary),
+            text(self.i18n.tr("palette-title"))
+                .size(20)
+                .color(palette.text_primary),
             Space::new().width(Length::Fill),
-            button(text("Close").color(palette.secondary_text))
+            button(text(self.i18n.tr("settings-close")).color(palette.secondary_text))
                 .on_press(Message::CommandPaletteClosed)
                 .style(move |_, status| ghost_button_style(palette, status)),
         ]
         .align_y(Alignment::Center);
 
-        let input = text_input("Type a command", &self.command_palette.query)
+        let placeholder = self.i18n.tr("palette-placeholder");
+        let input = text_input(&placeholder, &self.command_palette.query)
             .id(self.command_palette_input_id.clone())
             .on_input(Message::CommandPaletteQueryChanged)
             .on_submit(Message::CommandPaletteExecute(
                 self.command_palette
-                    .selected_action()
+                    .selected_action(&self.i18n)
                     .map(|action| action.id)
                     .unwrap_or(CommandActionId::Refresh),
             ))
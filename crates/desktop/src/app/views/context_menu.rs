@@ -0,0 +1,150 @@
+use iced::border::{Border, Radius};
+use iced::widget::{button, column, container, row, text, Space};
+use iced::{Alignment, Background, Color, Element, Length, Shadow, Vector};
+
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::state::{CommandActionId, ContextMenuState};
+use crate::app::theme::Palette;
+
+use super::styles::with_alpha;
+
+const MENU_WIDTH: f32 = 240.0;
+
+/// Right-click menu for a task row; every entry maps onto a palette action so both stay in sync.
+pub(super) fn task_context_menu(
+    menu: ContextMenuState,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let item = |id: CommandActionId, color: Color| {
+        menu_button(
+            text(i18n.action_label(id)).size(14).color(color),
+            Message::ContextMenuAction(id),
+            palette,
+        )
+    };
+    let mut items = column![
+        item(CommandActionId::MarkDone, palette.text_primary),
+        item(CommandActionId::PromoteNext, palette.text_primary),
+        menu_button(
+            row![
+                text(i18n.tr("menu-defer"))
+                    .size(14)
+                    .color(palette.text_primary),
+                Space::new().width(Length::Fill),
+                text(if menu.defer_open { "▾" } else { "▸" })
+                    .size(14)
+                    .color(palette.text_muted),
+            ]
+            .align_y(Alignment::Center),
+            Message::ContextMenuDeferToggled,
+            palette,
+        ),
+    ]
+    .spacing(2);
+
+    if menu.defer_open {
+        for id in [
+            CommandActionId::DeferTomorrow,
+            CommandActionId::DeferNextWeek,
+            CommandActionId::DeferPick,
+        ] {
+            items = items.push(container(item(id, palette.text_secondary)).padding([0, 12]));
+        }
+    }
+
+    items = items
+        .push(item(CommandActionId::MoveToProject, palette.text_primary))
+        .push(item(CommandActionId::CopyTask, palette.text_primary))
+        .push(item(CommandActionId::DeleteTask, palette.danger));
+
+    row![
+        Space::new().width(Length::Fill),
+        container(items)
+            .width(Length::Fixed(MENU_WIDTH))
+            .padding(6)
+            .style(move |_| menu_container_style(palette)),
+    ]
+    .into()
+}
+
+/// Right-click menu for a pinned view in the sidebar.
+pub(super) fn pinned_view_menu(
+    slot: u8,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let items = column![
+        menu_button(
+            text(i18n.tr("pinned-edit"))
+                .size(14)
+                .color(palette.text_primary),
+            Message::PinDialogOpened(Some(slot)),
+            palette,
+        ),
+        menu_button(
+            text(i18n.tr("pinned-remove"))
+                .size(14)
+                .color(palette.danger),
+            Message::SavedViewPinToggled(slot),
+            palette,
+        ),
+    ]
+    .spacing(2);
+
+    container(items)
+        .width(Length::Fill)
+        .padding(6)
+        .style(move |_| menu_container_style(palette))
+        .into()
+}
+
+fn menu_button<'a>(
+    content: impl Into<Element<'a, Message>>,
+    message: Message,
+    palette: Palette,
+) -> Element<'a, Message> {
+    button(content)
+        .width(Length::Fill)
+        .padding([6, 10])
+        .on_press(message)
+        .style(move |_, status| menu_item_style(palette, status))
+        .into()
+}
+
+fn menu_item_style(palette: Palette, status: button::Status) -> button::Style {
+    let mut style = button::Style {
+        background: None,
+        border: Border {
+            radius: Radius::from(6.0),
+            ..Border::default()
+        },
+        text_color: palette.text_primary,
+        shadow: Shadow::default(),
+        ..button::Style::default()
+    };
+
+    if let button::Status::Hovered | button::Status::Pressed = status {
+        style.background = Some(Background::Color(palette.sidebar_hover));
+    }
+
+    style
+}
+
+fn menu_container_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface_muted)),
+        border: Border {
+            color: with_alpha(palette.border, 0.9),
+            width: 1.0,
+            radius: Radius::from(10.0),
+        },
+        shadow: Shadow {
+            color: with_alpha(Color::BLACK, 0.4),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        ..container::Style::default()
+    }
+}
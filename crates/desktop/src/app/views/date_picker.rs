@@ -0,0 +1,184 @@
+use chrono::{Datelike, Local, Months, NaiveDate};
+use iced::alignment::Horizontal;
+use iced::border::{Border, Radius};
+use iced::widget::{button, column, container, row, text, Space};
+use iced::{Alignment, Background, Color, Element, Length, Shadow, Vector};
+
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::state::{InlineEditState, InlineEditableField};
+use crate::app::theme::Palette;
+
+use super::styles::{ghost_button_style, labelled, with_alpha};
+
+const PICKER_WIDTH: f32 = 280.0;
+const DAY_WIDTH: f32 = 34.0;
+/// Weekday ids, `date-picker-mo` and so on in the catalogs.
+const WEEKDAYS: [&str; 7] = ["mo", "tu", "we", "th", "fr", "sa", "su"];
+
+/// Month calendar shown under a row while its due or defer date is being edited.
+pub(super) fn date_picker(
+    edit: &InlineEditState,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let today = Local::now().date_naive();
+    let month = edit
+        .month
+        .unwrap_or_else(|| today.with_day(1).unwrap_or(today));
+    let picked = NaiveDate::parse_from_str(edit.value.trim(), "%Y-%m-%d").ok();
+    let title = match edit.field {
+        InlineEditableField::Defer => i18n.tr("date-picker-defer"),
+        _ => i18n.tr("date-picker-due"),
+    };
+
+    let header = row![
+        text(title).size(12).color(palette.text_secondary),
+        Space::new().width(Length::Fill),
+        labelled(
+            nav_button("‹", -1, palette),
+            i18n.tr("date-picker-previous"),
+            palette
+        ),
+        text(month.format("%B %Y").to_string())
+            .size(14)
+            .color(palette.text_primary),
+        labelled(
+            nav_button("›", 1, palette),
+            i18n.tr("date-picker-next"),
+            palette
+        ),
+    ]
+    .spacing(6)
+    .align_y(Alignment::Center);
+
+    let weekday_row = WEEKDAYS.iter().fold(row![], |row, day| {
+        row.push(
+            text(i18n.tr(&format!("date-picker-{day}")))
+                .size(12)
+                .color(palette.text_muted)
+                .width(Length::Fixed(DAY_WIDTH))
+                .align_x(Horizontal::Center),
+        )
+    });
+
+    let mut weeks = column![weekday_row].spacing(2);
+    let mut week = row![];
+    let lead = month.weekday().num_days_from_monday() as usize;
+    for _ in 0..lead {
+        week = week.push(Space::new().width(Length::Fixed(DAY_WIDTH)));
+    }
+    let mut filled = lead;
+    for date in month_days(month) {
+        week = week.push(day_button(
+            date,
+            picked == Some(date),
+            date == today,
+            palette,
+        ));
+        filled += 1;
+        if filled.is_multiple_of(7) {
+            weeks = weeks.push(week);
+            week = row![];
+        }
+    }
+    if !filled.is_multiple_of(7) {
+        weeks = weeks.push(week);
+    }
+
+    let footer = row![
+        button(text(i18n.tr("date-picker-today")).size(13))
+            .on_press(Message::DatePicked(today))
+            .style(move |_, status| ghost_button_style(palette, status)),
+        Space::new().width(Length::Fill),
+        button(text(i18n.tr("date-picker-clear")).size(13))
+            .on_press(Message::DateCleared)
+            .style(move |_, status| ghost_button_style(palette, status)),
+    ]
+    .align_y(Alignment::Center);
+
+    row![
+        Space::new().width(Length::Fill),
+        container(column![header, weeks, footer].spacing(8))
+            .width(Length::Fixed(PICKER_WIDTH))
+            .padding(10)
+            .style(move |_| picker_container_style(palette)),
+    ]
+    .into()
+}
+
+fn month_days(month: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    let next = month.checked_add_months(Months::new(1));
+    month
+        .iter_days()
+        .take_while(move |date| Some(*date) != next)
+}
+
+fn nav_button(label: &'static str, delta: i32, palette: Palette) -> Element<'static, Message> {
+    button(text(label).size(14))
+        .padding([2, 8])
+        .on_press(Message::DatePickerMonthChanged(delta))
+        .style(move |_, status| ghost_button_style(palette, status))
+        .into()
+}
+
+fn day_button(
+    date: NaiveDate,
+    picked: bool,
+    today: bool,
+    palette: Palette,
+) -> Element<'static, Message> {
+    button(
+        text(date.day().to_string())
+            .size(13)
+            .align_x(Horizontal::Center),
+    )
+    .width(Length::Fixed(DAY_WIDTH))
+    .padding([4, 0])
+    .on_press(Message::DatePicked(date))
+    .style(move |_, status| day_style(palette, picked, today, status))
+    .into()
+}
+
+fn day_style(palette: Palette, picked: bool, today: bool, status: button::Status) -> button::Style {
+    let mut style = button::Style {
+        background: None,
+        border: Border {
+            radius: Radius::from(6.0),
+            ..Border::default()
+        },
+        text_color: palette.text_primary,
+        shadow: Shadow::default(),
+        ..button::Style::default()
+    };
+
+    if today {
+        style.border.color = with_alpha(palette.primary, 0.6);
+        style.border.width = 1.0;
+    }
+    if picked {
+        style.background = Some(Background::Color(palette.primary));
+        style.text_color = palette.primary_text;
+    } else if let button::Status::Hovered | button::Status::Pressed = status {
+        style.background = Some(Background::Color(palette.sidebar_hover));
+    }
+
+    style
+}
+
+fn picker_container_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface_muted)),
+        border: Border {
+            color: with_alpha(palette.border, 0.9),
+            width: 1.0,
+            radius: Radius::from(10.0),
+        },
+        shadow: Shadow {
+            color: with_alpha(Color::BLACK, 0.4),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        ..container::Style::default()
+    }
+}
@@ -0,0 +1,111 @@
+use chrono::Local;
+use cpt_core::parser::parse_date_spec;
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{button, column, container, mouse_area, opaque, row, text, text_input, Space};
+use iced::{Alignment, Element, Length};
+
+use crate::app::message::Message;
+use crate::app::state::DEFER_PRESETS;
+
+use super::super::desktop::CptDesktop;
+use super::styles::{
+    backdrop_style, dialog_container_style, ghost_button_style, primary_button_style,
+    text_input_style,
+};
+
+const DIALOG_WIDTH: f32 = 460.0;
+
+impl CptDesktop {
+    /// Defer modal: a preset applies straight away, or type any date capture accepts.
+    pub(crate) fn defer_dialog(&self) -> Option<Element<'_, Message>> {
+        let dialog = self.defer_dialog.as_ref()?;
+        let palette = self.palette;
+        let title = match dialog.ids.len() {
+            1 => self.i18n.tr("defer-title-one"),
+            count => self
+                .i18n
+                .tr_args("defer-title-many", &[("count", count.to_string())]),
+        };
+
+        let presets = DEFER_PRESETS
+            .iter()
+            .fold(column![].spacing(2), |presets, (label, spec)| {
+                // Show where each preset lands so "This weekend" is unambiguous.
+                let date = parse_date_spec(spec)
+                    .map(|at| at.with_timezone(&Local).format("%a %-d %b").to_string())
+                    .unwrap_or_default();
+                presets.push(
+                    button(
+                        row![
+                            text(self.i18n.tr(&format!(
+                                "defer-preset-{}",
+                                label.to_ascii_lowercase().replace(' ', "-")
+                            )))
+                            .size(14)
+                            .color(palette.text_primary),
+                            Space::new().width(Length::Fill),
+                            text(date).size(13).color(palette.text_muted),
+                        ]
+                        .align_y(Alignment::Center),
+                    )
+                    .width(Length::Fill)
+                    .on_press(Message::DeferPresetPicked(spec))
+                    .style(move |_, status| ghost_button_style(palette, status)),
+                )
+            });
+
+        let input = text_input(&self.i18n.tr("defer-placeholder"), &dialog.date)
+            .on_input(Message::DeferDateChanged)
+            .on_submit(Message::DeferConfirmed)
+            .padding(10)
+            .style(move |_, status| text_input_style(palette, status));
+
+        let mut content = column![
+            text(title).size(18).color(palette.text_primary),
+            presets,
+            input
+        ]
+        .spacing(12);
+        if let Some(err) = &dialog.error {
+            content = content.push(text(err).size(12).color(palette.danger));
+        }
+        let confirm = (!dialog.date.trim().is_empty()).then_some(Message::DeferConfirmed);
+        content = content.push(
+            row![
+                Space::new().width(Length::Fill),
+                button(
+                    text(self.i18n.tr("dialog-cancel"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::DeferCancelled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+                button(
+                    text(self.i18n.tr("defer-confirm"))
+                        .size(14)
+                        .color(palette.primary_text)
+                )
+                .on_press_maybe(confirm)
+                .style(move |_, status| primary_button_style(palette, status)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+
+        let dialog = container(content)
+            .width(Length::Fixed(DIALOG_WIDTH))
+            .padding(20)
+            .style(move |_| dialog_container_style(palette));
+
+        let backdrop = container(opaque(dialog))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center)
+            .style(move |_| backdrop_style());
+
+        Some(opaque(
+            mouse_area(backdrop).on_press(Message::DeferCancelled),
+        ))
+    }
+}
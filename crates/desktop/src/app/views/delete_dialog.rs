@@ -0,0 +1,89 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::border::{Border, Radius};
+use iced::widget::{button, column, container, mouse_area, opaque, row, text, Space};
+use iced::{Alignment, Background, Element, Length, Shadow};
+
+use crate::app::message::Message;
+use crate::app::theme::Palette;
+
+use super::super::desktop::CptDesktop;
+use super::styles::{backdrop_style, dialog_container_style, ghost_button_style, with_alpha};
+
+const DIALOG_WIDTH: f32 = 380.0;
+
+impl CptDesktop {
+    /// Modal asking the user to confirm a delete; clicking the backdrop cancels.
+    pub(crate) fn delete_dialog(&self) -> Option<Element<'_, Message>> {
+        let confirmation = self.delete_confirmation.as_ref()?;
+        let palette = self.palette;
+        let prompt = match confirmation.titles.as_slice() {
+            [title] => self
+                .i18n
+                .tr_args("delete-title-one", &[("title", title.clone())]),
+            _ => self.i18n.tr_args(
+                "delete-title-many",
+                &[("count", confirmation.ids.len().to_string())],
+            ),
+        };
+
+        let dialog = column![
+            text(prompt).size(18).color(palette.text_primary),
+            text(self.i18n.tr("delete-hint"))
+                .size(14)
+                .color(palette.text_secondary),
+            row![
+                Space::new().width(Length::Fill),
+                button(
+                    text(self.i18n.tr("dialog-cancel"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::DeleteCancelled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+                button(
+                    text(self.i18n.tr("delete-confirm"))
+                        .size(14)
+                        .color(palette.primary_text)
+                )
+                .on_press(Message::DeleteConfirmed)
+                .style(move |_, status| danger_button_style(palette, status)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(12);
+
+        let dialog = container(dialog)
+            .width(Length::Fixed(DIALOG_WIDTH))
+            .padding(20)
+            .style(move |_| dialog_container_style(palette));
+
+        let backdrop = container(opaque(dialog))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center)
+            .style(move |_| backdrop_style());
+
+        Some(opaque(
+            mouse_area(backdrop).on_press(Message::DeleteCancelled),
+        ))
+    }
+}
+
+fn danger_button_style(palette: Palette, status: button::Status) -> button::Style {
+    let background = match status {
+        button::Status::Hovered | button::Status::Pressed => with_alpha(palette.danger, 0.85),
+        _ => palette.danger,
+    };
+    button::Style {
+        background: Some(Background::Color(background)),
+        border: Border {
+            radius: Radius::from(6.0),
+            ..Border::default()
+        },
+        text_color: palette.primary_text,
+        shadow: Shadow::default(),
+        ..button::Style::default()
+    }
+}
@@ -0,0 +1,287 @@
+use chrono::{DateTime, Utc};
+use cpt_core::model::Task;
+use iced::border::{Border, Radius};
+use iced::font::Weight as FontWeight;
+use iced::widget::{button, column, container, row, rule, scrollable, text, text_editor, Space};
+use iced::{Alignment, Background, Element, Font, Length, Shadow};
+
+use crate::app::helpers::{format_datetime, format_minutes};
+use crate::app::message::Message;
+use crate::app::theme::Palette;
+
+use super::super::desktop::CptDesktop;
+use super::notes::notes_preview;
+use super::styles::{ghost_button_style, primary_button_style, with_alpha};
+
+const DETAIL_PANEL_WIDTH: f32 = 360.0;
+
+impl CptDesktop {
+    /// Right-hand panel with every field of the selected task, its notes, and its history.
+    pub(crate) fn detail_panel(&self) -> Option<Element<'_, Message>> {
+        if !self.detail_open {
+            return None;
+        }
+        let palette = self.palette;
+        let task = self
+            .selected_task
+            .as_ref()
+            .and_then(|id| self.current_tasks().into_iter().find(|task| &task.id == id));
+
+        let body: Element<'_, Message> = match task {
+            Some(task) => self.detail_body(task),
+            None => text(self.i18n.tr("detail-empty"))
+                .size(14)
+                .color(palette.text_muted)
+                .into(),
+        };
+
+        let header = row![
+            text(self.i18n.tr("detail-title"))
+                .size(18)
+                .color(palette.text_primary),
+            Space::new().width(Length::Fill),
+            button(
+                text(self.i18n.tr("detail-close"))
+                    .size(14)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::DetailToggled)
+            .style(move |_, status| ghost_button_style(palette, status)),
+        ]
+        .align_y(Alignment::Center);
+
+        Some(
+            container(column![header, rule::horizontal(1), scrollable(body)].spacing(12))
+                .width(Length::Fixed(DETAIL_PANEL_WIDTH))
+                .height(Length::Fill)
+                .padding(16)
+                .style(move |_| detail_container_style(palette))
+                .into(),
+        )
+    }
+
+    fn detail_body<'a>(&'a self, task: &'a Task) -> Element<'a, Message> {
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let fields = [
+            ("detail-status", task.status.to_string()),
+            ("detail-project", display(task.project.clone())),
+            ("detail-areas", display_list(&task.areas)),
+            ("detail-contexts", display_list(&task.contexts)),
+            ("detail-tags", display_list(&task.tags)),
+            (
+                "detail-priority",
+                if task.priority > 0 {
+                    format!("P{}", task.priority)
+                } else {
+                    "—".into()
+                },
+            ),
+            (
+                "detail-energy",
+                display(task.energy.map(|energy| energy.as_str().to_string())),
+            ),
+            (
+                "detail-estimate",
+                display(task.time_estimate.map(format_minutes)),
+            ),
+            ("detail-due", display(task.due_at.map(format_datetime))),
+            (
+                "detail-defer",
+                display(task.defer_until.map(format_datetime)),
+            ),
+            ("detail-waiting-on", display(task.waiting_on.clone())),
+            ("detail-repeat", display(task.repeat.clone())),
+        ];
+        let field_rows = fields
+            .into_iter()
+            .fold(column![].spacing(6), |column, (id, value)| {
+                column.push(
+                    row![
+                        text(i18n.tr(id))
+                            .size(12)
+                            .color(palette.text_secondary)
+                            .width(Length::Fixed(90.0)),
+                        text(value).size(14).color(palette.text_primary),
+                    ]
+                    .align_y(Alignment::Center),
+                )
+            });
+
+        let mut content = column![
+            text(task.title.clone())
+                .size(18)
+                .color(palette.text_primary)
+                .font(Font {
+                    weight: FontWeight::Bold,
+                    ..Font::DEFAULT
+                }),
+            field_rows,
+        ]
+        .spacing(12);
+
+        if let Some(draft) = self
+            .notes_draft
+            .as_ref()
+            .filter(|draft| draft.task_id == task.id)
+        {
+            let dirty = draft.is_dirty();
+            let mode_label = if draft.preview {
+                i18n.tr("notes-edit")
+            } else {
+                i18n.tr("notes-preview")
+            };
+            let mode = button(text(mode_label).size(12).color(palette.secondary_text))
+                .padding([2, 8])
+                .on_press(Message::NotesPreviewToggled)
+                .style(move |_, status| ghost_button_style(palette, status));
+            let notes: Element<'a, Message> = if draft.preview {
+                notes_preview(&draft.text(), palette, i18n)
+            } else {
+                text_editor(&draft.content)
+                    .placeholder(i18n.tr("notes-placeholder"))
+                    .on_action(Message::NotesEdited)
+                    .height(Length::Fixed(180.0))
+                    .size(14)
+                    .into()
+            };
+            let external = button(
+                text(i18n.tr("notes-external-editor"))
+                    .size(12)
+                    .color(palette.secondary_text),
+            )
+            .padding([2, 8])
+            .on_press(Message::NotesExternalEditRequested)
+            .style(move |_, status| ghost_button_style(palette, status));
+            let mut save = button(
+                text(i18n.tr("notes-save"))
+                    .size(14)
+                    .color(palette.primary_text),
+            )
+            .style(move |_, status| primary_button_style(palette, status));
+            let mut revert = button(
+                text(i18n.tr("notes-revert"))
+                    .size(14)
+                    .color(palette.secondary_text),
+            )
+            .style(move |_, status| ghost_button_style(palette, status));
+            if dirty {
+                save = save.on_press(Message::NotesSaved);
+                revert = revert.on_press(Message::NotesReverted);
+            }
+            content = content
+                .push(
+                    row![
+                        section_title(i18n.tr("detail-notes"), palette),
+                        Space::new().width(Length::Fill),
+                        external,
+                        mode
+                    ]
+                    .spacing(4)
+                    .align_y(Alignment::Center),
+                )
+                .push(notes)
+                .push(row![save, revert].spacing(8).align_y(Alignment::Center));
+            if let Some(edit) = self
+                .external_edit
+                .as_ref()
+                .filter(|edit| edit.task_id == task.id)
+            {
+                let file = edit
+                    .path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                content = content.push(
+                    row![
+                        text(i18n.tr_args("notes-watching", &[("file", file)]))
+                            .size(12)
+                            .color(palette.text_muted)
+                            .width(Length::Fill),
+                        button(
+                            text(i18n.tr("notes-stop-watching"))
+                                .size(12)
+                                .color(palette.secondary_text)
+                        )
+                        .padding([2, 8])
+                        .on_press(Message::NotesExternalEditStopped)
+                        .style(move |_, status| ghost_button_style(palette, status)),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center),
+                );
+            }
+        }
+
+        let history =
+            task_history(task)
+                .into_iter()
+                .fold(column![].spacing(4), |column, (id, at)| {
+                    column.push(
+                        row![
+                            text(format_datetime(at))
+                                .size(12)
+                                .color(palette.text_muted)
+                                .width(Length::Fixed(130.0)),
+                            text(i18n.tr(id)).size(12).color(palette.text_secondary),
+                        ]
+                        .align_y(Alignment::Center),
+                    )
+                });
+        content = content
+            .push(section_title(i18n.tr("detail-history"), palette))
+            .push(history);
+
+        content.into()
+    }
+}
+
+/// Timeline entries recorded on the task itself, oldest first, each with its catalog id.
+fn task_history(task: &Task) -> Vec<(&'static str, DateTime<Utc>)> {
+    let mut entries = vec![("history-captured", task.created_at)];
+    if let Some(since) = task.waiting_since {
+        entries.push(("history-waiting", since));
+    }
+    if task.updated_at > task.created_at {
+        entries.push(("history-updated", task.updated_at));
+    }
+    if let Some(completed) = task.completed_at {
+        entries.push(("history-completed", completed));
+    }
+    entries.sort_by_key(|(_, at)| *at);
+    entries
+}
+
+fn section_title(label: String, palette: Palette) -> Element<'static, Message> {
+    text(label.to_uppercase())
+        .size(12)
+        .color(palette.text_secondary)
+        .into()
+}
+
+fn display(value: Option<String>) -> String {
+    value
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "—".into())
+}
+
+fn display_list(values: &[String]) -> String {
+    if values.is_empty() {
+        "—".into()
+    } else {
+        values.join(", ")
+    }
+}
+
+fn detail_container_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface_muted)),
+        border: Border {
+            color: with_alpha(palette.border, 0.6),
+            width: 1.0,
+            radius: Radius::from(0.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    }
+}
@@ -0,0 +1,109 @@
+use iced::widget::{button, column, container, row, text};
+use iced::{Alignment, Element, Length};
+
+use crate::app::message::Message;
+use crate::app::state::{CommandActionId, ViewTab};
+
+use super::super::desktop::CptDesktop;
+use super::styles::{ghost_button_style, primary_button_style};
+
+const EMPTY_STATE_WIDTH: f32 = 440.0;
+
+impl CptDesktop {
+    /// Shown in place of an empty list: why it is empty and what to do next. Search and filters
+    /// come first since they are the likeliest reason; an empty database also offers sample data.
+    pub(crate) fn empty_state(&self, tab: ViewTab) -> Element<'_, Message> {
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let view = tab.title().to_ascii_lowercase();
+
+        let action = |label: String, message: Message, primary: bool| {
+            button(text(label).size(13).color(if primary {
+                palette.primary_text
+            } else {
+                palette.secondary_text
+            }))
+            .on_press(message)
+            .style(move |_, status| {
+                if primary {
+                    primary_button_style(palette, status)
+                } else {
+                    ghost_button_style(palette, status)
+                }
+            })
+        };
+
+        let (heading, hint, actions) = if !self.search.trim().is_empty() {
+            (
+                i18n.tr_args("empty-search", &[("query", self.search.trim().to_string())]),
+                i18n.tr("empty-search-hint"),
+                row![action(
+                    i18n.tr("empty-clear-search"),
+                    Message::SearchCleared,
+                    true
+                )],
+            )
+        } else if !self.filters.is_empty() {
+            (
+                i18n.tr("empty-filtered"),
+                i18n.tr("empty-filtered-hint"),
+                row![action(
+                    i18n.tr("empty-clear-filters"),
+                    Message::FiltersCleared,
+                    true
+                )],
+            )
+        } else {
+            let mut actions = row![action(
+                i18n.tr("empty-add-task"),
+                Message::CommandPaletteExecute(CommandActionId::OpenCapture),
+                true
+            )];
+            if tab == ViewTab::Next {
+                actions = actions.push(action(
+                    i18n.tr("empty-open-inbox"),
+                    Message::ViewRequested(ViewTab::Inbox),
+                    false,
+                ));
+            }
+            if self.workspace_empty {
+                actions = actions.push(action(
+                    i18n.tr("empty-sample-data"),
+                    Message::SampleDataRequested,
+                    false,
+                ));
+            }
+            (
+                i18n.tr(&format!("empty-{view}")),
+                i18n.tr(&format!("empty-{view}-hint")),
+                actions,
+            )
+        };
+
+        let mut content = column![
+            text(heading).size(18).color(palette.text_primary),
+            text(hint).size(14).color(palette.text_secondary),
+            actions.spacing(8).align_y(Alignment::Center),
+        ]
+        .spacing(10)
+        .align_x(Alignment::Center);
+        if self.workspace_empty {
+            if let Some(service) = &self.service {
+                content = content.push(
+                    text(i18n.tr_args(
+                        "empty-data-dir",
+                        &[("path", service.config().data_dir().display().to_string())],
+                    ))
+                    .size(12)
+                    .color(palette.text_muted),
+                );
+            }
+        }
+
+        container(container(content).max_width(EMPTY_STATE_WIDTH))
+            .center_x(Length::Fill)
+            .height(Length::Fill)
+            .padding([48, 24])
+            .into()
+    }
+}
@@ -0,0 +1,123 @@
+use cpt_core::services::ExportFormat;
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{button, column, container, mouse_area, opaque, row, text, Space};
+use iced::{Alignment, Element, Length};
+
+use crate::app::message::Message;
+use crate::app::state::{export_format_name, ExportScope};
+
+use super::super::desktop::CptDesktop;
+use super::styles::{
+    backdrop_style, dialog_container_style, ghost_button_style, primary_button_style,
+};
+
+const DIALOG_WIDTH: f32 = 440.0;
+
+impl CptDesktop {
+    /// Export modal: pick a format and what to include, then choose the file in the save dialog.
+    pub(crate) fn export_dialog(&self) -> Option<Element<'_, Message>> {
+        let choices = self.export_dialog?;
+        let palette = self.palette;
+
+        let choice_button = |label: String, selected: bool, message: Message| {
+            let color = if selected {
+                palette.primary_text
+            } else {
+                palette.secondary_text
+            };
+            button(text(label).size(14).color(color))
+                .width(Length::Fill)
+                .on_press(message)
+                .style(move |_, status| {
+                    if selected {
+                        primary_button_style(palette, status)
+                    } else {
+                        ghost_button_style(palette, status)
+                    }
+                })
+        };
+
+        let formats = ExportFormat::ALL
+            .iter()
+            .fold(row![].spacing(6), |formats, format| {
+                formats.push(choice_button(
+                    export_format_name(*format).to_string(),
+                    choices.format == *format,
+                    Message::ExportFormatPicked(*format),
+                ))
+            });
+        let scopes = ExportScope::ALL
+            .iter()
+            .fold(row![].spacing(6), |scopes, scope| {
+                scopes.push(choice_button(
+                    self.i18n.tr(&format!(
+                        "export-scope-{}",
+                        scope.name().to_ascii_lowercase().replace(' ', "-")
+                    )),
+                    choices.scope == *scope,
+                    Message::ExportScopePicked(*scope),
+                ))
+            });
+        let summary = match choices.scope {
+            ExportScope::View => self.i18n.tr_args(
+                "export-summary-view",
+                &[
+                    ("count", self.view_row_count().to_string()),
+                    ("view", self.i18n.view_title(self.active)),
+                ],
+            ),
+            ExportScope::Everything => self.i18n.tr("export-summary-everything"),
+        };
+
+        let dialog = column![
+            text(self.i18n.tr("export-title"))
+                .size(18)
+                .color(palette.text_primary),
+            text(self.i18n.tr("export-format"))
+                .size(14)
+                .color(palette.text_secondary),
+            formats,
+            text(self.i18n.tr("export-include"))
+                .size(14)
+                .color(palette.text_secondary),
+            scopes,
+            text(summary).size(12).color(palette.text_muted),
+            row![
+                Space::new().width(Length::Fill),
+                button(
+                    text(self.i18n.tr("dialog-cancel"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::ExportCancelled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+                button(
+                    text(self.i18n.tr("export-confirm"))
+                        .size(14)
+                        .color(palette.primary_text)
+                )
+                .on_press(Message::ExportConfirmed)
+                .style(move |_, status| primary_button_style(palette, status)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(12);
+
+        let dialog = container(dialog)
+            .width(Length::Fixed(DIALOG_WIDTH))
+            .padding(20)
+            .style(move |_| dialog_container_style(palette));
+
+        let backdrop = container(opaque(dialog))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center)
+            .style(move |_| backdrop_style());
+
+        Some(opaque(
+            mouse_area(backdrop).on_press(Message::ExportCancelled),
+        ))
+    }
+}
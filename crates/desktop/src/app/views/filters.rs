@@ -0,0 +1,245 @@
+use cpt_core::model::FacetCount;
+use iced::border::{Border, Radius};
+use iced::widget::{button, column, container, row, scrollable, text, Space};
+use iced::{Alignment, Background, Element, Length, Shadow};
+
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::state::{ActiveFilters, FilterChip};
+use crate::app::theme::Palette;
+
+use super::super::desktop::CptDesktop;
+use super::styles::{chip_style, ghost_button_style, labelled, with_alpha};
+
+const FACET_LIST_HEIGHT: f32 = 180.0;
+/// Minimum priorities with their catalog ids.
+const PRIORITY_CHOICES: [(u8, &str); 3] = [
+    (1, "filters-priority-low"),
+    (2, "filters-priority-medium"),
+    (3, "filters-priority-high"),
+];
+
+impl CptDesktop {
+    /// Collapsible pane listing every project, context, and tag with open tasks, plus priority.
+    pub(crate) fn filter_panel(&self) -> Option<Element<'_, Message>> {
+        if !self.filter_panel_open {
+            return None;
+        }
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let Some(facets) = self.facets.as_ref() else {
+            return Some(
+                text(i18n.tr("filters-loading"))
+                    .size(14)
+                    .color(palette.text_muted)
+                    .into(),
+            );
+        };
+
+        let facet_column = |title: &str, counts: &[FacetCount], chip: fn(String) -> FilterChip| {
+            let items = counts.iter().fold(column![].spacing(2), |items, facet| {
+                let chip = chip(facet.name.clone());
+                items.push(facet_toggle(
+                    chip.label(),
+                    Some(facet.count),
+                    self.filters.contains(&chip),
+                    chip,
+                    palette,
+                ))
+            });
+            column![
+                column_title(i18n.tr(title), palette),
+                scrollable(items).height(Length::Fixed(FACET_LIST_HEIGHT)),
+            ]
+            .spacing(6)
+            .width(Length::FillPortion(1))
+        };
+
+        let priorities = PRIORITY_CHOICES.iter().fold(
+            column![column_title(i18n.tr("filters-priority"), palette)].spacing(2),
+            |items, (priority, id)| {
+                let chip = FilterChip::Priority(*priority);
+                items.push(facet_toggle(
+                    i18n.tr(id),
+                    None,
+                    self.filters.contains(&chip),
+                    chip,
+                    palette,
+                ))
+            },
+        );
+
+        let mut columns = row![
+            facet_column("filters-projects", &facets.projects, FilterChip::Project),
+            facet_column("filters-contexts", &facets.contexts, FilterChip::Context),
+            facet_column("filters-tags", &facets.tags, FilterChip::Tag),
+            priorities.width(Length::FillPortion(1)),
+        ]
+        .spacing(16);
+        if !self.saved_views.is_empty() {
+            // Ticking one pins it to the sidebar, including presets saved in the terminal UI.
+            let pinned = &self.window_state.state().pinned_views;
+            let saved = self.saved_views.iter().fold(
+                column![column_title(i18n.tr("filters-saved-views"), palette)].spacing(2),
+                |items, view| {
+                    items.push(pin_toggle(
+                        view.name.clone(),
+                        pinned.contains(&view.slot),
+                        view.slot,
+                        palette,
+                    ))
+                },
+            );
+            columns = columns.push(saved.width(Length::FillPortion(1)));
+        }
+
+        Some(
+            container(columns)
+                .width(Length::Fill)
+                .padding(12)
+                .style(move |_| panel_container_style(palette))
+                .into(),
+        )
+    }
+
+    /// One removable chip per active filter; hidden when nothing is filtered.
+    pub(crate) fn filter_chips(&self) -> Option<Element<'_, Message>> {
+        if self.filters.is_empty() {
+            return None;
+        }
+        Some(active_filter_row(&self.filters, self.palette, &self.i18n))
+    }
+}
+
+fn active_filter_row(
+    filters: &ActiveFilters,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let mut chips = row![text(i18n.tr("filters-filtered-by"))
+        .size(12)
+        .color(palette.text_secondary)]
+    .spacing(8)
+    .align_y(Alignment::Center);
+    for chip in filters.chips() {
+        let label = chip.label();
+        chips = chips.push(
+            container(
+                row![
+                    text(label).size(13),
+                    labelled(
+                        button(text("×").size(13))
+                            .padding([0, 4])
+                            .on_press(Message::FilterToggled(chip))
+                            .style(move |_, status| ghost_button_style(palette, status)),
+                        i18n.tr("filters-remove"),
+                        palette,
+                    ),
+                ]
+                .spacing(4)
+                .align_y(Alignment::Center),
+            )
+            .padding([2, 8])
+            .style(move |_| chip_style(palette.info)),
+        );
+    }
+    chips
+        .push(Space::new().width(Length::Fill))
+        .push(
+            button(
+                text(i18n.tr("filters-pin"))
+                    .size(12)
+                    .color(palette.secondary_text),
+            )
+            .on_press(Message::PinDialogOpened(None))
+            .style(move |_, status| ghost_button_style(palette, status)),
+        )
+        .push(
+            button(
+                text(i18n.tr("filters-clear"))
+                    .size(12)
+                    .color(palette.secondary_text),
+            )
+            .on_press(Message::FiltersCleared)
+            .style(move |_, status| ghost_button_style(palette, status)),
+        )
+        .into()
+}
+
+fn facet_toggle(
+    label: String,
+    count: Option<usize>,
+    checked: bool,
+    chip: FilterChip,
+    palette: Palette,
+) -> Element<'static, Message> {
+    let mut content = row![
+        text(if checked { "☑" } else { "☐" })
+            .size(14)
+            .color(if checked {
+                palette.primary
+            } else {
+                palette.text_muted
+            }),
+        text(label).size(14).color(palette.text_primary),
+    ]
+    .spacing(6)
+    .align_y(Alignment::Center);
+    if let Some(count) = count {
+        content = content
+            .push(Space::new().width(Length::Fill))
+            .push(text(count.to_string()).size(12).color(palette.text_muted));
+    }
+    button(content)
+        .width(Length::Fill)
+        .padding([3, 6])
+        .on_press(Message::FilterToggled(chip))
+        .style(move |_, status| ghost_button_style(palette, status))
+        .into()
+}
+
+fn pin_toggle(
+    label: String,
+    pinned: bool,
+    slot: u8,
+    palette: Palette,
+) -> Element<'static, Message> {
+    button(
+        row![
+            text(if pinned { "☑" } else { "☐" })
+                .size(14)
+                .color(if pinned {
+                    palette.primary
+                } else {
+                    palette.text_muted
+                }),
+            text(label).size(14).color(palette.text_primary),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .on_press(Message::SavedViewPinToggled(slot))
+    .style(move |_, status| ghost_button_style(palette, status))
+    .into()
+}
+
+fn column_title(label: String, palette: Palette) -> Element<'static, Message> {
+    text(label.to_uppercase())
+        .size(12)
+        .color(palette.text_secondary)
+        .into()
+}
+
+fn panel_container_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface_muted)),
+        border: Border {
+            color: with_alpha(palette.border, 0.8),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::Utc;
+use iced::widget::{button, column, container, row, scrollable, text, Space};
+use iced::{Alignment, Background, Element, Length};
+
+use crate::app::helpers::format_elapsed;
+use crate::app::message::Message;
+
+use super::super::desktop::CptDesktop;
+use super::notes::notes_preview;
+use super::styles::{ghost_button_style, primary_button_style};
+
+const FOCUS_WIDTH: f32 = 640.0;
+
+impl CptDesktop {
+    /// The focus screen: the task's title, notes, and timer, with nothing else on screen.
+    pub(crate) fn focus_view(&self) -> Option<Element<'_, Message>> {
+        let focus = self.focus.as_ref()?;
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        // The list's copy picks up refreshes, e.g. a ticked checklist item.
+        let task = self
+            .current_tasks()
+            .into_iter()
+            .find(|task| task.id == focus.task.id)
+            .unwrap_or(&focus.task);
+
+        let running = focus.timer.is_some();
+        let timer = column![
+            text(format_elapsed(focus.total_secs(Utc::now())))
+                .size(48)
+                .color(if running {
+                    palette.text_primary
+                } else {
+                    palette.text_muted
+                }),
+            text(if running {
+                i18n.tr("focus-timer-running")
+            } else {
+                i18n.tr("focus-timer-paused")
+            })
+            .size(13)
+            .color(palette.text_muted),
+        ]
+        .spacing(4)
+        .align_x(Alignment::Center);
+
+        let actions = row![
+            button(
+                text(if running {
+                    i18n.tr("focus-pause-timer")
+                } else {
+                    i18n.tr("focus-start-timer")
+                })
+                .size(14)
+                .color(palette.secondary_text)
+            )
+            .on_press(Message::FocusTimerToggled)
+            .style(move |_, status| ghost_button_style(palette, status)),
+            button(
+                text(i18n.tr("focus-defer"))
+                    .size(14)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::FocusDefer)
+            .style(move |_, status| ghost_button_style(palette, status)),
+            button(
+                text(i18n.tr("focus-done"))
+                    .size(14)
+                    .color(palette.primary_text)
+            )
+            .on_press(Message::FocusDone)
+            .style(move |_, status| primary_button_style(palette, status)),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center);
+
+        let notes = scrollable(notes_preview(
+            task.notes.as_deref().unwrap_or_default(),
+            palette,
+            i18n,
+        ))
+        .height(Length::Fill);
+
+        let header = row![
+            Space::new().width(Length::Fill),
+            button(
+                text(i18n.tr("focus-exit"))
+                    .size(13)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::FocusExited)
+            .style(move |_, status| ghost_button_style(palette, status)),
+        ];
+
+        let content = column![
+            text(task.title.clone())
+                .size(28)
+                .color(palette.text_primary),
+            timer,
+            actions,
+            notes,
+            text(i18n.tr("focus-hint"))
+                .size(12)
+                .color(palette.text_muted),
+        ]
+        .spacing(24)
+        .align_x(Alignment::Center)
+        .max_width(FOCUS_WIDTH);
+
+        Some(
+            container(
+                column![header, container(content).center_x(Length::Fill)]
+                    .spacing(16)
+                    .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding([20, 24])
+            .style(move |_| container::Style {
+                background: Some(Background::Color(palette.background)),
+                ..container::Style::default()
+            })
+            .into(),
+        )
+    }
+}
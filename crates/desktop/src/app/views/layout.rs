@@ -1,23 +1,63 @@
 use iced::alignment::Horizontal;
 use iced::border::{Border, Radius};
 use iced::widget::rule;
-use iced::widget::{column, container, row};
-use iced::{Alignment, Background, Element, Length, Shadow};
+use iced::widget::{column, container, mouse_area, row, stack};
+use iced::{window, Alignment, Background, Element, Length, Shadow};
 
 use crate::app::message::Message;
+use crate::app::state::FocusRegion;
 use crate::app::theme::Palette;
 
 use super::super::desktop::CptDesktop;
+use super::styles::focus_ring;
+
+pub(crate) fn compose(app: &CptDesktop, window: window::Id) -> Element<'_, Message> {
+    if app.is_quick_capture(window) {
+        return app.quick_capture_view();
+    }
+    if let Some(view_window) = app.view_window_view(window) {
+        return view_window;
+    }
+    if let Some(focus) = app.focus_view() {
+        return match app.toast_stack() {
+            Some(toasts) => stack![focus, toasts].into(),
+            None => focus,
+        };
+    }
 
-pub(crate) fn compose(app: &CptDesktop) -> Element<'_, Message> {
     let toolbar = app.toolbar();
     let status_line = app.status_line();
-    let capture = app.capture_view();
-    let task_list = app.task_list();
-
-    let mut main_column = column![capture, task_list]
-        .spacing(16)
-        .align_x(Alignment::Start);
+    let region = app.keyboard_focus.map(|focus| focus.region);
+    let capture = focus_ring(
+        app.capture_view(),
+        region == Some(FocusRegion::Capture),
+        app.palette,
+    );
+    let task_list = app
+        .stats_view()
+        .or_else(|| app.project_page_view())
+        .or_else(|| app.load_error_view())
+        .or_else(|| app.calendar_view())
+        .or_else(|| app.board())
+        .unwrap_or_else(|| app.task_list());
+    let task_list = focus_ring(task_list, region == Some(FocusRegion::Table), app.palette);
+    // A click the list leaves unhandled, e.g. beside the open row menu, dismisses the menu.
+    let task_list: Element<'_, Message> = if app.context_menu.is_some() {
+        mouse_area(task_list)
+            .on_press(Message::ContextMenuClosed)
+            .into()
+    } else {
+        task_list
+    };
+
+    let mut main_column = column![capture].spacing(16).align_x(Alignment::Start);
+    if let Some(panel) = app.filter_panel() {
+        main_column = main_column.push(panel);
+    }
+    if let Some(chips) = app.filter_chips() {
+        main_column = main_column.push(chips);
+    }
+    main_column = main_column.push(task_list);
 
     if app.command_palette.open {
         if let Some(palette) = app.command_palette_view() {
@@ -45,6 +85,14 @@ pub(crate) fn compose(app: &CptDesktop) -> Element<'_, Message> {
         .padding([20, 24])
         .style(move |_| surface_container_style(app.palette));
 
+    let content: Element<'_, Message> = match app.detail_panel() {
+        Some(panel) => row![content, panel]
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into(),
+        None => content.into(),
+    };
+
     let main_area = column![toolbar, toolbar_divider, content]
         .spacing(0)
         .width(Length::Fill)
@@ -60,7 +108,7 @@ pub(crate) fn compose(app: &CptDesktop) -> Element<'_, Message> {
         .padding([8, 24])
         .style(move |_| status_container_style(app.palette));
 
-    container(
+    let root = container(
         column![body, status]
             .spacing(0)
             .width(Length::Fill)
@@ -69,8 +117,24 @@ pub(crate) fn compose(app: &CptDesktop) -> Element<'_, Message> {
     .width(Length::Fill)
     .height(Length::Fill)
     .align_x(Horizontal::Left)
-    .style(move |_| app_background_style(app.palette))
-    .into()
+    .style(move |_| app_background_style(app.palette));
+    let root: Element<'_, Message> = match app.toast_stack() {
+        Some(toasts) => stack![root, toasts].into(),
+        None => root.into(),
+    };
+
+    let dialog = app
+        .delete_dialog()
+        .or_else(|| app.defer_dialog())
+        .or_else(|| app.export_dialog())
+        .or_else(|| app.pin_dialog())
+        .or_else(|| app.settings_dialog())
+        .or_else(|| app.shortcuts_dialog())
+        .or_else(|| app.telemetry_dialog());
+    match dialog {
+        Some(dialog) => stack![root, dialog].into(),
+        None => root,
+    }
 }
 
 fn sidebar_container_style(palette: Palette) -> container::Style {
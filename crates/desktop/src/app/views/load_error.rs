@@ -0,0 +1,71 @@
+use iced::widget::{button, column, container, row, text};
+use iced::{Alignment, Element, Length};
+
+use crate::app::message::Message;
+use crate::app::state::{CommandActionId, LoadState, ViewTab};
+
+use super::super::desktop::CptDesktop;
+use super::styles::{dialog_container_style, ghost_button_style, primary_button_style};
+
+const ERROR_CARD_WIDTH: f32 = 480.0;
+
+impl CptDesktop {
+    /// Shown in place of the list when the open view failed to load: the error, a retry, and
+    /// the recent events, where refresh failures are highlighted.
+    pub(crate) fn load_error_view(&self) -> Option<Element<'_, Message>> {
+        if self.active == ViewTab::Stats {
+            return None;
+        }
+        let store = self.views.get(&self.active)?;
+        let error = store.load_error()?;
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let retrying = matches!(store.state, LoadState::Loading);
+
+        let retry = button(
+            text(if retrying {
+                i18n.tr("error-retrying")
+            } else {
+                i18n.tr("error-retry")
+            })
+            .size(13)
+            .color(palette.primary_text),
+        )
+        .on_press_maybe(
+            (!retrying).then_some(Message::CommandPaletteExecute(CommandActionId::Refresh)),
+        )
+        .style(move |_, status| primary_button_style(palette, status));
+        let events = button(
+            text(i18n.tr("error-recent-events"))
+                .size(13)
+                .color(palette.secondary_text),
+        )
+        .on_press(Message::TelemetryEventsToggled)
+        .style(move |_, status| ghost_button_style(palette, status));
+
+        let card = column![
+            text(i18n.tr_args("error-view", &[("view", i18n.view_title(self.active))]))
+                .size(18)
+                .color(palette.text_primary),
+            text(error.to_string()).size(14).color(palette.danger),
+            text(i18n.tr("error-view-hint"))
+                .size(13)
+                .color(palette.text_secondary),
+            row![retry, events].spacing(8).align_y(Alignment::Center),
+        ]
+        .spacing(10);
+
+        Some(
+            container(
+                container(card)
+                    .max_width(ERROR_CARD_WIDTH)
+                    .padding(20)
+                    .style(move |_| dialog_container_style(palette)),
+            )
+            .center_x(Length::Fill)
+            .height(Length::Fill)
+            .padding([48, 24])
+            .into(),
+        )
+    }
+}
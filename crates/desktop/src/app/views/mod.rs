@@ -1,13 +1,36 @@
 //! View composition for the desktop shell, balancing productivity affordances with cpt.run visuals.
 
+mod board;
+mod calendar;
 mod capture;
 mod command_palette;
+mod context_menu;
+mod date_picker;
+mod defer_dialog;
+mod delete_dialog;
+mod detail;
+mod empty_state;
+mod export_dialog;
+mod filters;
+mod focus;
 mod layout;
+mod load_error;
+mod notes;
+mod pin_dialog;
+mod print;
+mod project_page;
+mod quick_capture;
+mod settings;
 mod sidebar;
+mod stats;
 mod status;
 mod styles;
 mod task_table;
 mod tasks;
 mod toolbar;
+mod view_window;
 
 pub(crate) use layout::compose as compose_root;
+pub(crate) use task_table::column_labels;
+#[cfg(test)]
+pub(crate) use task_table::heading_count;
@@ -0,0 +1,185 @@
+use iced::font::Weight as FontWeight;
+use iced::widget::text::Span;
+use iced::widget::{button, column, container, rich_text, row, span, text, Space};
+use iced::{Alignment, Background, Color, Element, Font, Length};
+
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::notes::{parse_notes, Inline, NoteBlock};
+use crate::app::theme::Palette;
+
+use super::styles::{ghost_button_style, with_alpha};
+
+const BODY_SIZE: f32 = 14.0;
+/// Indent per nested list level.
+const LIST_INDENT: f32 = 16.0;
+
+/// Notes rendered as markdown; links open in the browser and checklist boxes toggle in place.
+pub(super) fn notes_preview(
+    notes: &str,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let blocks = parse_notes(notes);
+    if blocks.iter().all(|block| *block == NoteBlock::Blank) {
+        return text(i18n.tr("notes-empty"))
+            .size(14)
+            .color(palette.text_muted)
+            .into();
+    }
+
+    blocks
+        .into_iter()
+        .fold(column![].spacing(4), |column, block| {
+            column.push(note_block(block, palette))
+        })
+        .width(Length::Fill)
+        .into()
+}
+
+fn note_block(block: NoteBlock, palette: Palette) -> Element<'static, Message> {
+    match block {
+        NoteBlock::Heading { level, spans } => {
+            let size = match level {
+                1 => 18.0,
+                2 => 16.0,
+                _ => 15.0,
+            };
+            let bold = Font {
+                weight: FontWeight::Bold,
+                ..Font::DEFAULT
+            };
+            rich_text(inline_spans(
+                spans,
+                size,
+                palette.text_primary,
+                bold,
+                palette,
+            ))
+            .on_link_click(Message::NoteLinkClicked)
+            .into()
+        }
+        NoteBlock::Bullet { depth, spans } => list_item(
+            depth,
+            text("•")
+                .size(BODY_SIZE)
+                .color(palette.text_secondary)
+                .into(),
+            paragraph(spans, palette),
+        ),
+        NoteBlock::Numbered {
+            depth,
+            number,
+            spans,
+        } => list_item(
+            depth,
+            text(number)
+                .size(BODY_SIZE)
+                .color(palette.text_secondary)
+                .into(),
+            paragraph(spans, palette),
+        ),
+        NoteBlock::Checkbox {
+            line,
+            depth,
+            checked,
+            spans,
+        } => {
+            let (mark, color) = if checked {
+                ("☑", palette.success)
+            } else {
+                ("☐", palette.text_secondary)
+            };
+            let toggle = button(text(mark).size(BODY_SIZE + 2.0).color(color))
+                .padding(0)
+                .on_press(Message::NotesCheckboxToggled(line))
+                .style(move |_, status| ghost_button_style(palette, status));
+            let label = if checked {
+                // Finished items are dimmed rather than struck through.
+                rich_text(inline_spans(
+                    spans,
+                    BODY_SIZE,
+                    palette.text_muted,
+                    Font::DEFAULT,
+                    palette,
+                ))
+                .on_link_click(Message::NoteLinkClicked)
+                .into()
+            } else {
+                paragraph(spans, palette)
+            };
+            list_item(depth, toggle.into(), label)
+        }
+        NoteBlock::Quote(spans) => {
+            let bar = container(Space::new())
+                .width(Length::Fixed(3.0))
+                .height(Length::Fixed(BODY_SIZE + 6.0))
+                .style(move |_| container::Style {
+                    background: Some(Background::Color(with_alpha(palette.border, 0.9))),
+                    ..container::Style::default()
+                });
+            row![bar, paragraph(spans, palette)]
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .into()
+        }
+        NoteBlock::Paragraph(spans) => paragraph(spans, palette),
+        NoteBlock::Blank => Space::new().height(Length::Fixed(6.0)).into(),
+    }
+}
+
+fn list_item(
+    depth: usize,
+    marker: Element<'static, Message>,
+    body: Element<'static, Message>,
+) -> Element<'static, Message> {
+    row![
+        Space::new().width(Length::Fixed(depth as f32 * LIST_INDENT)),
+        marker,
+        body,
+    ]
+    .spacing(6)
+    .align_y(Alignment::Start)
+    .into()
+}
+
+fn paragraph(spans: Vec<Inline>, palette: Palette) -> Element<'static, Message> {
+    rich_text(inline_spans(
+        spans,
+        BODY_SIZE,
+        palette.text_primary,
+        Font::DEFAULT,
+        palette,
+    ))
+    .on_link_click(Message::NoteLinkClicked)
+    .into()
+}
+
+fn inline_spans(
+    spans: Vec<Inline>,
+    size: f32,
+    color: Color,
+    font: Font,
+    palette: Palette,
+) -> Vec<Span<'static, String>> {
+    spans
+        .into_iter()
+        .map(|inline| match inline {
+            Inline::Text(value) => span(value).size(size).color(color).font(font),
+            Inline::Strong(value) => span(value).size(size).color(color).font(Font {
+                weight: FontWeight::Bold,
+                ..font
+            }),
+            Inline::Code(value) => span(value)
+                .size(size - 1.0)
+                .color(palette.info)
+                .font(Font::MONOSPACE),
+            Inline::Link { label, url } => span(label)
+                .size(size)
+                .color(palette.primary)
+                .font(font)
+                .underline(true)
+                .link(url),
+        })
+        .collect()
+}
@@ -0,0 +1,90 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{button, column, container, mouse_area, opaque, row, text, text_input, Space};
+use iced::{Alignment, Element, Length};
+
+use crate::app::message::Message;
+
+use super::super::desktop::CptDesktop;
+use super::styles::{
+    backdrop_style, dialog_container_style, ghost_button_style, primary_button_style,
+    text_input_style,
+};
+
+const DIALOG_WIDTH: f32 = 420.0;
+
+impl CptDesktop {
+    /// Names a view for the sidebar; editing a pinned one can also swap in the current filters.
+    pub(crate) fn pin_dialog(&self) -> Option<Element<'_, Message>> {
+        let dialog = self.pin_dialog.as_ref()?;
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let title = if dialog.slot.is_some() {
+            i18n.tr("pin-title-edit")
+        } else {
+            i18n.tr("pin-title-new")
+        };
+
+        let input = text_input(&i18n.tr("pin-name"), &dialog.name)
+            .on_input(Message::PinNameChanged)
+            .on_submit(Message::PinConfirmed)
+            .padding(10)
+            .style(move |_, status| text_input_style(palette, status));
+
+        let mut content =
+            column![text(title).size(18).color(palette.text_primary), input].spacing(12);
+        if dialog.slot.is_some() && !self.filters.is_empty() {
+            content = content.push(
+                button(
+                    row![
+                        text(if dialog.use_current { "☑" } else { "☐" })
+                            .size(14)
+                            .color(palette.primary),
+                        text(i18n.tr("pin-use-current"))
+                            .size(14)
+                            .color(palette.text_primary),
+                    ]
+                    .spacing(6)
+                    .align_y(Alignment::Center),
+                )
+                .on_press(Message::PinUseCurrentToggled(!dialog.use_current))
+                .style(move |_, status| ghost_button_style(palette, status)),
+            );
+        }
+        let confirm = (!dialog.name.trim().is_empty()).then_some(Message::PinConfirmed);
+        content = content.push(
+            row![
+                Space::new().width(Length::Fill),
+                button(
+                    text(i18n.tr("dialog-cancel"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::PinCancelled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+                button(
+                    text(i18n.tr("pin-save"))
+                        .size(14)
+                        .color(palette.primary_text)
+                )
+                .on_press_maybe(confirm)
+                .style(move |_, status| primary_button_style(palette, status)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+
+        let dialog = container(content)
+            .width(Length::Fixed(DIALOG_WIDTH))
+            .padding(20)
+            .style(move |_| dialog_container_style(palette));
+
+        let backdrop = container(opaque(dialog))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center)
+            .style(move |_| backdrop_style());
+
+        Some(opaque(mouse_area(backdrop).on_press(Message::PinCancelled)))
+    }
+}
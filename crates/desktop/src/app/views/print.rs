@@ -0,0 +1,130 @@
+use cpt_core::ViewSnapshot;
+
+use crate::app::helpers::{capitalize, format_datetime, matches_search};
+use crate::app::i18n::Catalog;
+use crate::app::print::{PrintSection, PrintSheet};
+use crate::app::state::{ProjectPage, ViewTab};
+
+use super::super::desktop::CptDesktop;
+use super::task_table::{apply_layout, build_project_table, build_task_table, keeps_groups};
+
+impl CptDesktop {
+    /// The open project page or the active view's table as a [`PrintSheet`], with the columns,
+    /// order, search, and filters on screen; `None` for Stats or a view still loading.
+    pub(crate) fn print_layout(&self) -> Option<PrintSheet> {
+        if self.active == ViewTab::Stats {
+            return None;
+        }
+        if let Some(page) = self
+            .project_page
+            .as_ref()
+            .filter(|_| self.active == ViewTab::Projects)
+        {
+            return Some(project_page_sheet(page, &self.i18n));
+        }
+
+        let snapshot = self.views.get(&self.active)?.snapshot.clone()?;
+        self.view_sheet(snapshot)
+    }
+
+    /// `snapshot` of the active view as a [`PrintSheet`], narrowed by the search on screen.
+    pub(crate) fn view_sheet(&self, mut snapshot: ViewSnapshot) -> Option<PrintSheet> {
+        let sort = self.views.get(&self.active).and_then(|view| view.sort);
+        let title = self.i18n.view_title(self.active);
+        if snapshot.is_project_view() {
+            let table = build_project_table(&snapshot);
+            return Some(PrintSheet {
+                title,
+                subtitle: None,
+                columns: table
+                    .columns
+                    .iter()
+                    .map(|c| self.i18n.column(c.label))
+                    .collect(),
+                checkboxes: false,
+                sections: vec![PrintSection {
+                    heading: None,
+                    rows: table.rows.into_iter().map(|row| row.cells).collect(),
+                }],
+            });
+        }
+
+        snapshot
+            .tasks
+            .retain(|task| matches_search(task, &self.search));
+        let mut table = apply_layout(
+            build_task_table(self.active, &snapshot),
+            &self.columns.get(self.active),
+        );
+        if !keeps_groups(self.active, sort) {
+            for row in &mut table.rows {
+                row.group = None;
+            }
+        }
+
+        let mut sections: Vec<PrintSection> = Vec::new();
+        for row in table.rows {
+            let heading = row.group;
+            match sections.last_mut() {
+                Some(section) if section.heading == heading => section.rows.push(row.cells),
+                _ => sections.push(PrintSection {
+                    heading,
+                    rows: vec![row.cells],
+                }),
+            }
+        }
+
+        let mut narrowed = Vec::new();
+        if !self.search.trim().is_empty() {
+            narrowed.push(self.i18n.tr_args(
+                "print-matching",
+                &[("query", self.search.trim().to_string())],
+            ));
+        }
+        narrowed.extend(self.filters.chips().iter().map(|chip| chip.label()));
+        Some(PrintSheet {
+            title,
+            subtitle: (!narrowed.is_empty()).then(|| narrowed.join(" ")),
+            columns: table
+                .columns
+                .iter()
+                .map(|c| self.i18n.column(c.label))
+                .collect(),
+            checkboxes: self.active != ViewTab::Done,
+            sections,
+        })
+    }
+}
+
+/// A project page prints its open tasks grouped by status, like the page lists them.
+fn project_page_sheet(page: &ProjectPage, i18n: &Catalog) -> PrintSheet {
+    let sections = ProjectPage::GROUPS
+        .iter()
+        .filter_map(|status| {
+            let tasks = page.group(*status);
+            (!tasks.is_empty()).then(|| PrintSection {
+                heading: Some(format!("{} ({})", capitalize(status.as_str()), tasks.len())),
+                rows: tasks
+                    .into_iter()
+                    .map(|task| {
+                        vec![
+                            task.title.clone(),
+                            task.contexts.join(" "),
+                            task.due_at.map(format_datetime).unwrap_or_default(),
+                        ]
+                    })
+                    .collect(),
+            })
+        })
+        .collect();
+    PrintSheet {
+        title: format!("+{}", page.name),
+        subtitle: page.is_stalled().then(|| i18n.tr("print-stalled")),
+        columns: ["Title", "Contexts", "Due"]
+            .into_iter()
+            .map(|label| i18n.column(label))
+            .collect(),
+        checkboxes: true,
+        sections,
+    }
+}
@@ -0,0 +1,170 @@
+use cpt_core::model::{Task, TaskStatus};
+use iced::border::{Border, Radius};
+use iced::widget::{button, column, container, row, scrollable, text, text_input, Space};
+use iced::{Alignment, Background, Element, Length, Shadow};
+
+use crate::app::helpers::{capitalize, format_datetime};
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::state::{LoadState, ProjectPage, ViewTab};
+use crate::app::theme::Palette;
+
+use super::super::desktop::CptDesktop;
+use super::capture::{capture_chip, capture_error};
+use super::styles::{capture_input_style, ghost_button_style, with_alpha};
+
+impl CptDesktop {
+    /// The open project's page, shown instead of the Projects table.
+    pub(crate) fn project_page_view(&self) -> Option<Element<'_, Message>> {
+        if self.active != ViewTab::Projects {
+            return None;
+        }
+        let page = self.project_page.as_ref()?;
+        let palette = self.palette;
+        let i18n = &self.i18n;
+
+        let header = row![
+            button(
+                text(i18n.tr("project-back"))
+                    .size(14)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::ProjectClosed)
+            .style(move |_, status| ghost_button_style(palette, status)),
+            text(format!("+{}", page.name))
+                .size(20)
+                .color(palette.text_primary),
+            Space::new().width(Length::Fill),
+            text(i18n.tr_args("project-open", &[("count", page.tasks.len().to_string())]))
+                .size(14)
+                .color(palette.text_secondary),
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center);
+
+        let mut content = column![header].spacing(14);
+        if page.is_stalled() {
+            content = content.push(stalled_warning(palette, i18n));
+        }
+        content = content.push(project_capture(page, palette, i18n));
+
+        let body: Element<'_, Message> = match &page.state {
+            LoadState::Loading if page.tasks.is_empty() => text(i18n.tr("project-loading"))
+                .size(14)
+                .color(palette.text_muted)
+                .into(),
+            LoadState::Error(err) => text(i18n.tr_args(
+                "project-load-failed",
+                &[("project", page.name.clone()), ("error", err.clone())],
+            ))
+            .size(14)
+            .color(palette.danger)
+            .into(),
+            _ => ProjectPage::GROUPS
+                .iter()
+                .fold(column![].spacing(16), |groups, status| {
+                    let tasks = page.group(*status);
+                    if tasks.is_empty() {
+                        groups
+                    } else {
+                        groups.push(status_group(*status, tasks, palette, i18n))
+                    }
+                })
+                .into(),
+        };
+        content = content.push(scrollable(body).height(Length::Fill));
+
+        Some(container(content).padding([12, 0]).into())
+    }
+}
+
+fn stalled_warning(palette: Palette, i18n: &Catalog) -> Element<'static, Message> {
+    container(
+        text(i18n.tr("project-stalled"))
+            .size(14)
+            .color(palette.warning),
+    )
+    .padding([8, 12])
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(Background::Color(with_alpha(palette.warning, 0.12))),
+        border: Border {
+            color: with_alpha(palette.warning, 0.6),
+            width: 1.0,
+            radius: Radius::from(6.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    })
+    .into()
+}
+
+/// Capture field pre-filled with the `+Project` token, with the usual token chips.
+fn project_capture<'a>(
+    page: &'a ProjectPage,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'a, Message> {
+    let invalid = page.capture.token_error.is_some();
+    let input = text_input(&i18n.tr("project-capture-placeholder"), &page.capture.text)
+        .on_input(Message::ProjectCaptureChanged)
+        .on_submit(Message::ProjectCaptureSubmit)
+        .padding(10)
+        .style(move |_, status| capture_input_style(palette, status, invalid));
+
+    let mut capture = column![input].spacing(4);
+    if let Some(preview) = &page.capture.preview {
+        capture = capture.push(preview.chips.iter().fold(
+            row![].spacing(8).align_y(Alignment::Center),
+            |chips, chip| chips.push(capture_chip(chip, palette)),
+        ));
+    }
+    if let Some(error) = capture_error(&page.capture, palette, i18n, Message::ProjectCaptureChanged)
+    {
+        capture = capture.push(error);
+    }
+    capture.into()
+}
+
+fn status_group<'a>(
+    status: TaskStatus,
+    tasks: Vec<&'a Task>,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'a, Message> {
+    let heading = text(format!("{} ({})", capitalize(status.as_str()), tasks.len()))
+        .size(14)
+        .color(palette.text_secondary);
+
+    tasks
+        .into_iter()
+        .fold(column![heading].spacing(6), |group, task| {
+            let mut details = Vec::new();
+            if let Some(due) = task.due_at {
+                details.push(i18n.tr_args("task-due", &[("date", format_datetime(due))]));
+            }
+            if !task.contexts.is_empty() {
+                details.push(task.contexts.join(" "));
+            }
+            group.push(
+                container(
+                    row![
+                        text(&task.title)
+                            .size(14)
+                            .color(palette.text_primary)
+                            .width(Length::Fill),
+                        text(details.join(" · ")).size(12).color(palette.text_muted),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center),
+                )
+                .padding([6, 12])
+                .width(Length::Fill)
+                .style(move |_| container::Style {
+                    background: Some(Background::Color(with_alpha(palette.surface_muted, 0.25))),
+                    ..container::Style::default()
+                }),
+            )
+        })
+        .into()
+}
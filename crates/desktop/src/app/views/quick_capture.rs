@@ -0,0 +1,67 @@
+use iced::widget::{column, container, row, text, text_input, Space};
+use iced::{Alignment, Background, Element, Length};
+
+use crate::app::message::Message;
+
+use super::super::desktop::CptDesktop;
+use super::capture::{capture_chip, capture_error};
+use super::styles::capture_input_style;
+
+impl CptDesktop {
+    /// Contents of the hotkey capture window: one input plus the same token preview as the
+    /// main capture bar.
+    pub(crate) fn quick_capture_view(&self) -> Element<'_, Message> {
+        let palette = self.palette;
+        let Some(quick) = self.quick_capture.as_ref() else {
+            return Space::new().into();
+        };
+
+        let invalid = quick.capture.token_error.is_some();
+        let input = text_input(
+            &self.i18n.tr("quick-capture-placeholder"),
+            &quick.capture.text,
+        )
+        .id(self.quick_capture_input_id.clone())
+        .on_input(Message::QuickCaptureTextChanged)
+        .on_submit(Message::QuickCaptureSubmit)
+        .padding(12)
+        .size(18)
+        .style(move |_, status| capture_input_style(palette, status, invalid));
+
+        let chips = quick.capture.preview.as_ref().map_or_else(
+            || row![],
+            |preview| {
+                preview.chips.iter().fold(row![].spacing(8), |row, chip| {
+                    row.push(capture_chip(chip, palette))
+                })
+            },
+        );
+
+        let footer = capture_error(
+            &quick.capture,
+            palette,
+            &self.i18n,
+            Message::QuickCaptureTextChanged,
+        )
+        .unwrap_or_else(|| {
+            text(self.i18n.tr("quick-capture-hint"))
+                .size(12)
+                .color(palette.text_muted)
+                .into()
+        });
+
+        container(
+            column![input, chips.align_y(Alignment::Center), footer]
+                .spacing(10)
+                .width(Length::Fill),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(16)
+        .style(move |_| container::Style {
+            background: Some(Background::Color(palette.surface)),
+            ..container::Style::default()
+        })
+        .into()
+    }
+}
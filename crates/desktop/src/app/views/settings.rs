@@ -0,0 +1,455 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{
+    button, column, container, mouse_area, opaque, row, scrollable, slider, text, text_input, Space,
+};
+use iced::{Alignment, Element, Length};
+
+use crate::app::i18n::{locales, Catalog};
+use crate::app::message::Message;
+use crate::app::shortcuts::ShortcutAction;
+use crate::app::theme::Palette;
+use crate::app::window_state::{ThemeChoice, MAX_ZOOM, MIN_ZOOM, REFRESH_INTERVALS};
+use crate::telemetry::{Sink as TelemetrySink, RECENT_LIMIT};
+
+use super::super::desktop::CptDesktop;
+use super::styles::{
+    backdrop_style, dialog_container_style, ghost_button_style, primary_button_style,
+    text_input_style,
+};
+
+const DIALOG_WIDTH: f32 = 420.0;
+const THEME_LIST_HEIGHT: f32 = 220.0;
+const ZOOM_SLIDER_STEP: f32 = 0.05;
+const SHORTCUTS_DIALOG_WIDTH: f32 = 520.0;
+const SHORTCUT_LIST_HEIGHT: f32 = 360.0;
+const EVENTS_DIALOG_WIDTH: f32 = 640.0;
+const EVENT_LIST_HEIGHT: f32 = 380.0;
+
+impl CptDesktop {
+    /// Settings modal with the appearance choice, the color theme picker, and the language.
+    pub(crate) fn settings_dialog(&self) -> Option<Element<'_, Message>> {
+        if !self.settings_open {
+            return None;
+        }
+        let palette = self.palette;
+        let saved = self.window_state.state();
+        let current_theme = self
+            .color_themes
+            .get(saved.color_theme.as_deref())
+            .name
+            .as_str();
+
+        let choice_button = |label: String, selected: bool, message: Message| {
+            let color = if selected {
+                palette.primary_text
+            } else {
+                palette.secondary_text
+            };
+            button(text(label).size(14).color(color))
+                .width(Length::Fill)
+                .on_press(message)
+                .style(move |_, status| {
+                    if selected {
+                        primary_button_style(palette, status)
+                    } else {
+                        ghost_button_style(palette, status)
+                    }
+                })
+        };
+
+        let i18n = &self.i18n;
+        let appearance = ThemeChoice::ALL
+            .iter()
+            .fold(row![].spacing(6), |bar, choice| {
+                bar.push(choice_button(
+                    i18n.tr(&format!(
+                        "settings-appearance-{}",
+                        choice.name().to_ascii_lowercase()
+                    )),
+                    saved.theme == *choice,
+                    Message::AppearancePicked(*choice),
+                ))
+            });
+
+        let themes = self
+            .color_themes
+            .names()
+            .fold(column![].spacing(4), |list, name| {
+                list.push(choice_button(
+                    name.to_string(),
+                    name == current_theme,
+                    Message::ColorThemePicked(name.to_string()),
+                ))
+            });
+
+        let zoom = row![
+            slider(MIN_ZOOM..=MAX_ZOOM, saved.zoom, Message::ZoomChanged)
+                .step(ZOOM_SLIDER_STEP)
+                .width(Length::Fill),
+            text(format!("{:.0}%", saved.zoom * 100.0))
+                .size(14)
+                .color(palette.text_primary),
+            button(
+                text(i18n.tr("settings-reset"))
+                    .size(14)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::ZoomChanged(1.0))
+            .style(move |_, status| ghost_button_style(palette, status)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        // "System" names the language it resolves to right now.
+        let system_language = Catalog::new(None).tr("locale-name");
+        let languages = locales().into_iter().fold(
+            row![choice_button(
+                i18n.tr_args("settings-language-system", &[("language", system_language)]),
+                saved.locale.is_none(),
+                Message::LocalePicked(None),
+            )]
+            .spacing(6),
+            |bar, (code, name)| {
+                bar.push(choice_button(
+                    name,
+                    saved.locale.as_deref() == Some(code),
+                    Message::LocalePicked(Some(code.to_string())),
+                ))
+            },
+        );
+
+        let every = self.refresh_every().as_secs();
+        let refresh = REFRESH_INTERVALS
+            .iter()
+            .fold(row![].spacing(6), |bar, seconds| {
+                bar.push(choice_button(
+                    i18n.tr_args(
+                        "settings-refresh-seconds",
+                        &[("seconds", seconds.to_string())],
+                    ),
+                    every == *seconds,
+                    Message::RefreshIntervalPicked(*seconds),
+                ))
+            });
+
+        // Telemetry stays on this machine; the picked sink decides whether it is kept at all.
+        let sink = self.telemetry.sink();
+        let telemetry = TelemetrySink::ALL
+            .iter()
+            .fold(row![].spacing(6), |bar, choice| {
+                bar.push(choice_button(
+                    i18n.tr(&format!("settings-telemetry-{}", choice.name())),
+                    sink == *choice,
+                    Message::TelemetrySinkPicked(*choice),
+                ))
+            })
+            .push(
+                button(
+                    text(i18n.tr("settings-telemetry-events"))
+                        .size(14)
+                        .color(palette.secondary_text),
+                )
+                .on_press(Message::TelemetryEventsToggled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+            );
+        let telemetry_hint = match (sink, self.telemetry.file()) {
+            (TelemetrySink::File, Some(path)) => i18n.tr_args(
+                "settings-telemetry-file-hint",
+                &[("path", path.display().to_string())],
+            ),
+            (TelemetrySink::Disabled, _) => i18n.tr("settings-telemetry-disabled-hint"),
+            _ => i18n.tr("settings-telemetry-hint"),
+        };
+
+        let mut dialog = column![
+            text(i18n.tr("settings-title"))
+                .size(18)
+                .color(palette.text_primary),
+            text(i18n.tr("settings-appearance"))
+                .size(14)
+                .color(palette.text_secondary),
+            appearance,
+            text(i18n.tr("settings-text-size"))
+                .size(14)
+                .color(palette.text_secondary),
+            zoom,
+            text(i18n.tr("settings-language"))
+                .size(14)
+                .color(palette.text_secondary),
+            languages,
+            choice_button(
+                i18n.tr("settings-reduce-motion"),
+                saved.reduce_motion,
+                Message::ReduceMotionToggled(!saved.reduce_motion),
+            ),
+            text(i18n.tr("settings-reduce-motion-hint"))
+                .size(12)
+                .color(palette.text_muted),
+            text(i18n.tr("settings-refresh"))
+                .size(14)
+                .color(palette.text_secondary),
+            refresh,
+            choice_button(
+                i18n.tr("settings-refresh-paused"),
+                self.auto_refresh_paused,
+                Message::AutoRefreshToggled,
+            ),
+            text(i18n.tr("settings-refresh-hint"))
+                .size(12)
+                .color(palette.text_muted),
+            text(i18n.tr("settings-telemetry"))
+                .size(14)
+                .color(palette.text_secondary),
+            telemetry,
+            text(telemetry_hint).size(12).color(palette.text_muted),
+            text(i18n.tr("settings-external-editor"))
+                .size(14)
+                .color(palette.text_secondary),
+            text_input(
+                &i18n.tr("settings-external-editor-placeholder"),
+                saved.external_editor.as_deref().unwrap_or_default(),
+            )
+            .on_input(Message::ExternalEditorChanged)
+            .padding(8)
+            .size(14)
+            .style(move |_, status| text_input_style(palette, status)),
+            text(i18n.tr("settings-external-editor-hint"))
+                .size(12)
+                .color(palette.text_muted),
+            text(i18n.tr("settings-color-theme"))
+                .size(14)
+                .color(palette.text_secondary),
+            scrollable(themes).height(Length::Fixed(THEME_LIST_HEIGHT)),
+        ]
+        .spacing(12);
+
+        if let Some(dir) = &self.color_themes.user_dir {
+            dialog = dialog.push(
+                text(i18n.tr_args(
+                    "settings-themes-hint",
+                    &[("dir", dir.display().to_string())],
+                ))
+                .size(12)
+                .color(palette.text_muted),
+            );
+        }
+
+        dialog = dialog.push(
+            row![
+                button(
+                    text(i18n.tr("settings-shortcuts"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::ShortcutsToggled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+                Space::new().width(Length::Fill),
+                button(
+                    text(i18n.tr("settings-close"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::SettingsToggled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+            ]
+            .align_y(Alignment::Center),
+        );
+
+        Some(modal_frame(
+            dialog,
+            DIALOG_WIDTH,
+            palette,
+            Message::SettingsToggled,
+        ))
+    }
+}
+
+impl CptDesktop {
+    /// Lists every rebindable action; "Change" listens for the next key press.
+    pub(crate) fn shortcuts_dialog(&self) -> Option<Element<'_, Message>> {
+        if !self.shortcuts_open {
+            return None;
+        }
+        let palette = self.palette;
+        let i18n = &self.i18n;
+
+        let rows = ShortcutAction::ALL
+            .iter()
+            .fold(column![].spacing(4), |list, action| {
+                let recording = self.shortcut_recording == Some(*action);
+                let keys = if recording {
+                    i18n.tr("shortcuts-recording")
+                } else {
+                    self.shortcuts
+                        .hint(*action)
+                        .unwrap_or_else(|| i18n.tr("shortcuts-unbound"))
+                };
+                let keys_color = if recording {
+                    palette.primary
+                } else {
+                    palette.text_secondary
+                };
+                list.push(
+                    row![
+                        text(i18n.shortcut(*action))
+                            .size(14)
+                            .color(palette.text_primary)
+                            .width(Length::Fill),
+                        text(keys).size(14).color(keys_color),
+                        button(
+                            text(i18n.tr("shortcuts-change"))
+                                .size(12)
+                                .color(palette.secondary_text)
+                        )
+                        .on_press(Message::ShortcutRecordStarted(*action))
+                        .style(move |_, status| ghost_button_style(palette, status)),
+                        button(
+                            text(i18n.tr("shortcuts-reset"))
+                                .size(12)
+                                .color(palette.secondary_text)
+                        )
+                        .on_press(Message::ShortcutReset(Some(*action)))
+                        .style(move |_, status| ghost_button_style(palette, status)),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center),
+                )
+            });
+
+        let dialog = column![
+            text(i18n.tr("shortcuts-title"))
+                .size(18)
+                .color(palette.text_primary),
+            text(i18n.tr("shortcuts-hint"))
+                .size(12)
+                .color(palette.text_muted),
+            scrollable(rows).height(Length::Fixed(SHORTCUT_LIST_HEIGHT)),
+            row![
+                button(
+                    text(i18n.tr("shortcuts-reset-all"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::ShortcutReset(None))
+                .style(move |_, status| ghost_button_style(palette, status)),
+                Space::new().width(Length::Fill),
+                button(
+                    text(i18n.tr("settings-close"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::ShortcutsToggled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+            ]
+            .align_y(Alignment::Center),
+        ]
+        .spacing(12);
+
+        Some(modal_frame(
+            dialog,
+            SHORTCUTS_DIALOG_WIDTH,
+            palette,
+            Message::ShortcutsToggled,
+        ))
+    }
+}
+
+impl CptDesktop {
+    /// Recent telemetry events, newest first, for troubleshooting refresh and save failures.
+    pub(crate) fn telemetry_dialog(&self) -> Option<Element<'_, Message>> {
+        if !self.telemetry_events_open {
+            return None;
+        }
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let events = self.telemetry.recent();
+
+        let list: Element<'_, Message> = if events.is_empty() {
+            let note = if self.telemetry.sink() == TelemetrySink::Disabled {
+                i18n.tr("events-disabled")
+            } else {
+                i18n.tr("events-empty")
+            };
+            text(note).size(14).color(palette.text_muted).into()
+        } else {
+            let rows = events.iter().fold(column![].spacing(4), |list, recorded| {
+                let color = if recorded.event.is_failure() {
+                    palette.danger
+                } else {
+                    palette.text_primary
+                };
+                list.push(
+                    row![
+                        text(recorded.at.format("%H:%M:%S").to_string())
+                            .size(12)
+                            .color(palette.text_muted),
+                        text(recorded.event.name())
+                            .size(13)
+                            .color(color)
+                            .width(Length::Fixed(150.0)),
+                        text(recorded.event.details())
+                            .size(13)
+                            .color(palette.text_secondary)
+                            .width(Length::Fill),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Start),
+                )
+            });
+            scrollable(rows)
+                .height(Length::Fixed(EVENT_LIST_HEIGHT))
+                .into()
+        };
+
+        let dialog = column![
+            text(i18n.tr("events-title"))
+                .size(18)
+                .color(palette.text_primary),
+            text(i18n.tr_args("events-hint", &[("count", RECENT_LIMIT.to_string())]))
+                .size(12)
+                .color(palette.text_muted),
+            list,
+            row![
+                Space::new().width(Length::Fill),
+                button(
+                    text(i18n.tr("settings-close"))
+                        .size(14)
+                        .color(palette.secondary_text)
+                )
+                .on_press(Message::TelemetryEventsToggled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+            ]
+            .align_y(Alignment::Center),
+        ]
+        .spacing(12);
+
+        Some(modal_frame(
+            dialog,
+            EVENTS_DIALOG_WIDTH,
+            palette,
+            Message::TelemetryEventsToggled,
+        ))
+    }
+}
+
+/// Centers `content` in a dialog card over a dimmed backdrop; clicking the backdrop sends `dismiss`.
+fn modal_frame<'a>(
+    content: impl Into<Element<'a, Message>>,
+    width: f32,
+    palette: Palette,
+    dismiss: Message,
+) -> Element<'a, Message> {
+    let dialog = container(content)
+        .width(Length::Fixed(width))
+        .padding(20)
+        .style(move |_| dialog_container_style(palette));
+
+    let backdrop = container(opaque(dialog))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .style(move |_| backdrop_style());
+
+    opaque(mouse_area(backdrop).on_press(dismiss))
+}
@@ -1,17 +1,19 @@
 use iced::border::{Border, Radius};
-use iced::widget::{button, column, text};
-use iced::{Alignment, Background, Element, Length, Shadow};
+use iced::widget::{button, column, mouse_area, row, text, Space};
+use iced::{mouse, Alignment, Background, Element, Length, Shadow};
 
 use crate::app::message::Message;
-use crate::app::state::ViewTab;
+use crate::app::state::{ActiveFilters, DropTarget, ViewTab};
 
 use super::super::desktop::CptDesktop;
-use super::styles::with_alpha;
+use super::context_menu::pinned_view_menu;
+use super::styles::{focus_ring, labelled, with_alpha};
 
 impl CptDesktop {
     pub(crate) fn tabs(&self) -> Element<'_, Message> {
         let palette = self.palette;
         let mut menu = column![].spacing(12).align_x(Alignment::Start);
+        let hovered = self.drag.as_ref().and_then(|drag| drag.target.as_ref());
 
         for tab in ViewTab::ALL {
             let active = *tab == self.active;
@@ -27,28 +29,152 @@ impl CptDesktop {
             };
 
             let label = column![
-                text(tab.title()).size(16).color(title_color),
-                text(tab.subtitle()).size(12).color(subtitle_color),
+                text(self.i18n.view_title(*tab)).size(16).color(title_color),
+                text(self.i18n.view_subtitle(*tab))
+                    .size(12)
+                    .color(subtitle_color),
             ]
             .spacing(2)
             .align_x(Alignment::Start);
 
+            let target = DropTarget::View(*tab);
+            let drop = match (&self.drag, tab.drop_status()) {
+                (Some(_), Some(_)) => Some(DropState {
+                    hovered: hovered == Some(&target),
+                }),
+                _ => None,
+            };
             let button = button(label)
                 .padding([5, 8])
                 .width(Length::Fill)
                 .on_press(Message::ViewRequested(*tab))
-                .style(move |_, status| sidebar_button_style(palette, active, status));
+                .style(move |_, status| sidebar_button_style(palette, active, drop, status));
 
-            menu = menu.push(button);
+            let focused = self.keyboard_focus.is_some_and(|focus| focus.on_view(*tab));
+            menu = menu.push(focus_ring(
+                drop_zone(button, drop, target),
+                focused,
+                palette,
+            ));
+        }
+
+        let pinned = self.pinned_views();
+        if !pinned.is_empty() {
+            menu = menu.push(
+                text(self.i18n.tr("sidebar-pinned"))
+                    .size(12)
+                    .color(with_alpha(palette.sidebar_text_muted, 0.65)),
+            );
+        }
+        for (view, count) in pinned {
+            let slot = view.slot;
+            let active = ActiveFilters::from_saved(view) == self.filters;
+            let drop = self
+                .pin_drag
+                .filter(|drag| drag.slot != slot)
+                .map(|drag| DropState {
+                    hovered: drag.target == Some(slot),
+                });
+            let grip = mouse_area(text("⠿").size(12).color(palette.sidebar_text_muted))
+                .on_press(Message::PinDragStarted(slot))
+                .interaction(mouse::Interaction::Grab);
+            let label = row![
+                labelled(grip, self.i18n.tr("sidebar-drag-to-reorder"), palette),
+                text(view.name.clone()).size(14),
+                Space::new().width(Length::Fill),
+                text(count.to_string())
+                    .size(12)
+                    .color(palette.sidebar_text_muted),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center);
+            let entry = button(label)
+                .padding([5, 8])
+                .width(Length::Fill)
+                .on_press(Message::SavedViewOpened(slot))
+                .style(move |_, status| sidebar_button_style(palette, active, drop, status));
+            let mut entry = mouse_area(entry).on_right_press(Message::PinnedMenuOpened(Some(slot)));
+            if self.pin_drag.is_some() {
+                entry = entry.on_enter(Message::PinDragEntered(slot));
+            }
+            menu = menu.push(entry);
+            if self.pinned_menu == Some(slot) {
+                menu = menu.push(pinned_view_menu(slot, palette, &self.i18n));
+            }
+        }
+
+        if self.drag.is_some() {
+            let projects = self.drop_projects();
+            if !projects.is_empty() {
+                menu = menu.push(
+                    text(self.i18n.tr("sidebar-drop-project"))
+                        .size(12)
+                        .color(with_alpha(palette.sidebar_text_muted, 0.65)),
+                );
+            }
+            for project in projects {
+                let target = DropTarget::Project(project.clone());
+                let drop = Some(DropState {
+                    hovered: hovered == Some(&target),
+                });
+                let button = button(text(format!("+{project}")).size(14))
+                    .padding([5, 8])
+                    .width(Length::Fill)
+                    .style(move |_, status| sidebar_button_style(palette, false, drop, status));
+                menu = menu.push(drop_zone(button, drop, target));
+            }
         }
 
         menu.width(Length::Fill).into()
     }
+
+    /// Projects offered as drop targets: those in the active view plus the Projects summary.
+    fn drop_projects(&self) -> Vec<String> {
+        let mut projects = self.collect_projects();
+        let summaries = self
+            .views
+            .get(&ViewTab::Projects)
+            .and_then(|view| view.snapshot.as_ref())
+            .map(|snapshot| snapshot.projects.as_slice())
+            .unwrap_or_default();
+        for summary in summaries {
+            if !projects.contains(&summary.project) {
+                projects.push(summary.project.clone());
+            }
+        }
+        projects.sort();
+        projects
+    }
 }
 
 use crate::app::theme::Palette;
 
-fn sidebar_button_style(palette: Palette, active: bool, status: button::Status) -> button::Style {
+/// How a sidebar entry looks while a task row or pinned view is being dragged.
+#[derive(Debug, Clone, Copy)]
+struct DropState {
+    hovered: bool,
+}
+
+fn drop_zone<'a>(
+    content: impl Into<Element<'a, Message>>,
+    drop: Option<DropState>,
+    target: DropTarget,
+) -> Element<'a, Message> {
+    match drop {
+        Some(_) => mouse_area(content)
+            .on_enter(Message::DragTargetEntered(target.clone()))
+            .on_exit(Message::DragTargetLeft(target))
+            .into(),
+        None => content.into(),
+    }
+}
+
+fn sidebar_button_style(
+    palette: Palette,
+    active: bool,
+    drop: Option<DropState>,
+    status: button::Status,
+) -> button::Style {
     let mut style = button::Style {
         background: None,
         border: Border::default(),
@@ -83,5 +209,21 @@ fn sidebar_button_style(palette: Palette, active: bool, status: button::Status)
         button::Status::Active => {}
     }
 
+    if let Some(drop) = drop {
+        style.border = Border {
+            radius: Radius::from(6),
+            color: if drop.hovered {
+                palette.primary
+            } else {
+                with_alpha(palette.primary, 0.35)
+            },
+            width: if drop.hovered { 2.0 } else { 1.0 },
+        };
+        if drop.hovered {
+            style.background = Some(Background::Color(with_alpha(palette.primary, 0.22)));
+            style.text_color = palette.sidebar_text;
+        }
+    }
+
     style
 }
@@ -0,0 +1,341 @@
+use cpt_core::model::TaskStatus;
+use cpt_core::services::stats::format_age;
+use cpt_core::services::StatsSnapshot;
+use iced::alignment::{Horizontal, Vertical};
+use iced::border::{Border, Radius};
+use iced::widget::{column, container, row, scrollable, text, Space};
+use iced::{Alignment, Background, Color, Element, Length, Shadow};
+
+use crate::app::helpers::capitalize;
+use crate::app::i18n::Catalog;
+use crate::app::message::Message;
+use crate::app::state::{ViewTab, STATS_WEEKS};
+use crate::app::theme::Palette;
+
+use super::super::desktop::CptDesktop;
+use super::styles::with_alpha;
+
+const WEEK_CHART_HEIGHT: f32 = 160.0;
+const BAR_HEIGHT: f32 = 14.0;
+/// Estimate rows listed before the rest are left out.
+const ESTIMATE_ROWS: usize = 10;
+
+impl CptDesktop {
+    /// Completion trends, open work per status, and estimate accuracy for the Stats tab.
+    pub(crate) fn stats_view(&self) -> Option<Element<'_, Message>> {
+        if self.active != ViewTab::Stats {
+            return None;
+        }
+        let palette = self.palette;
+        let i18n = &self.i18n;
+        let Some(stats) = &self.stats else {
+            return Some(
+                container(
+                    text(i18n.tr("stats-loading"))
+                        .size(14)
+                        .color(palette.text_muted),
+                )
+                .padding([12, 0])
+                .into(),
+            );
+        };
+
+        let open: usize = stats
+            .by_status
+            .iter()
+            .filter(|(status, _)| *status != TaskStatus::Done)
+            .map(|(_, count)| count)
+            .sum();
+        let inbox_age = stats
+            .average_inbox_age_secs
+            .map(format_age)
+            .unwrap_or_else(|| "—".into());
+        let tiles = row![
+            summary_tile(
+                i18n.tr("stats-completed"),
+                stats.completed_total().to_string(),
+                i18n.tr_args(
+                    "stats-completed-caption",
+                    &[("weeks", STATS_WEEKS.to_string())]
+                ),
+                palette,
+            ),
+            summary_tile(
+                i18n.tr("stats-open"),
+                open.to_string(),
+                i18n.tr("stats-open-caption"),
+                palette
+            ),
+            summary_tile(
+                i18n.tr("stats-inbox-age"),
+                inbox_age,
+                i18n.tr_args(
+                    "stats-inbox-age-caption",
+                    &[("count", stats.inbox_count.to_string())]
+                ),
+                palette,
+            ),
+        ]
+        .spacing(12);
+
+        let content = column![
+            tiles,
+            section(
+                i18n.tr("stats-weekly"),
+                weekly_chart(stats, palette, i18n),
+                palette
+            ),
+            section(
+                i18n.tr("stats-by-status"),
+                status_chart(stats, palette),
+                palette
+            ),
+            section(
+                i18n.tr("stats-estimates"),
+                estimate_chart(stats, palette, i18n),
+                palette
+            ),
+        ]
+        .spacing(20);
+
+        Some(
+            container(scrollable(content).height(Length::Fill))
+                .padding([12, 0])
+                .height(Length::Fill)
+                .into(),
+        )
+    }
+}
+
+fn summary_tile(
+    label: String,
+    value: String,
+    caption: String,
+    palette: Palette,
+) -> Element<'static, Message> {
+    container(
+        column![
+            text(label).size(12).color(palette.text_muted),
+            text(value).size(24).color(palette.text_primary),
+            text(caption).size(12).color(palette.text_secondary),
+        ]
+        .spacing(4),
+    )
+    .padding(12)
+    .width(Length::Fill)
+    .style(move |_| panel_style(palette))
+    .into()
+}
+
+fn section<'a>(
+    title: String,
+    chart: Element<'a, Message>,
+    palette: Palette,
+) -> Element<'a, Message> {
+    container(column![text(title).size(14).color(palette.text_secondary), chart].spacing(10))
+        .padding(12)
+        .width(Length::Fill)
+        .style(move |_| panel_style(palette))
+        .into()
+}
+
+/// One vertical bar per week, oldest on the left; the tallest week fills the chart.
+fn weekly_chart(
+    stats: &StatsSnapshot,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let weeks = stats.weekly_completions();
+    let peak = weeks.iter().copied().max().unwrap_or(0);
+    let count = weeks.len();
+    weeks
+        .into_iter()
+        .enumerate()
+        .fold(
+            row![].spacing(8).height(Length::Fixed(WEEK_CHART_HEIGHT)),
+            |chart, (index, done)| {
+                let label = match count - 1 - index {
+                    0 => i18n.tr("stats-this-week"),
+                    ago => i18n.tr_args("stats-weeks-ago", &[("weeks", ago.to_string())]),
+                };
+                let bar = column![
+                    text(done.to_string())
+                        .size(12)
+                        .color(palette.text_secondary)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center),
+                    vertical_bar(done, peak, palette.primary),
+                    text(label)
+                        .size(11)
+                        .color(palette.text_muted)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center),
+                ]
+                .spacing(4)
+                .width(Length::Fill)
+                .height(Length::Fill);
+                chart.push(bar)
+            },
+        )
+        .into()
+}
+
+fn status_chart(stats: &StatsSnapshot, palette: Palette) -> Element<'static, Message> {
+    let open: Vec<(TaskStatus, usize)> = stats
+        .by_status
+        .iter()
+        .copied()
+        .filter(|(status, _)| *status != TaskStatus::Done)
+        .collect();
+    let peak = open.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    open.into_iter()
+        .fold(column![].spacing(8), |chart, (status, count)| {
+            chart.push(
+                row![
+                    text(capitalize(status.as_str()))
+                        .size(13)
+                        .color(palette.text_primary)
+                        .width(Length::Fixed(90.0)),
+                    horizontal_bar(count as u64, peak as u64, palette.info),
+                    text(count.to_string())
+                        .size(13)
+                        .color(palette.text_secondary)
+                        .width(Length::Fixed(40.0))
+                        .align_x(Horizontal::Right),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center),
+            )
+        })
+        .into()
+}
+
+/// Estimate and tracked time side by side; overruns are drawn in the warning color.
+fn estimate_chart<'a>(
+    stats: &'a StatsSnapshot,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'a, Message> {
+    if stats.estimates.is_empty() {
+        return text(i18n.tr("stats-estimates-empty"))
+            .size(13)
+            .color(palette.text_muted)
+            .into();
+    }
+    let entries = &stats.estimates[..stats.estimates.len().min(ESTIMATE_ROWS)];
+    let peak = entries
+        .iter()
+        .map(|entry| entry.estimate_secs.max(entry.actual_secs))
+        .max()
+        .unwrap_or(0)
+        .max(0) as u64;
+    entries
+        .iter()
+        .fold(column![].spacing(10), |chart, entry| {
+            let actual_color = if entry.actual_secs > entry.estimate_secs {
+                palette.warning
+            } else {
+                palette.success
+            };
+            chart.push(
+                row![
+                    text(&entry.title)
+                        .size(13)
+                        .color(palette.text_primary)
+                        .width(Length::FillPortion(2)),
+                    column![
+                        horizontal_bar(entry.estimate_secs.max(0) as u64, peak, palette.info),
+                        horizontal_bar(entry.actual_secs.max(0) as u64, peak, actual_color),
+                    ]
+                    .spacing(2)
+                    .width(Length::FillPortion(3)),
+                    text(format!(
+                        "{} / {}",
+                        format_age(entry.estimate_secs),
+                        format_age(entry.actual_secs)
+                    ))
+                    .size(12)
+                    .color(palette.text_secondary)
+                    .width(Length::Fixed(90.0))
+                    .align_x(Horizontal::Right),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center),
+            )
+        })
+        .into()
+}
+
+/// A bar filling `value / peak` of the available height, anchored to the bottom.
+fn vertical_bar(value: u64, peak: u64, color: Color) -> Element<'static, Message> {
+    let (filled, empty) = portions(value, peak);
+    let mut bar = column![].width(Length::Fill).height(Length::Fill);
+    if empty > 0 {
+        bar = bar.push(Space::new().height(Length::FillPortion(empty)));
+    }
+    if filled > 0 {
+        bar = bar.push(
+            container(Space::new())
+                .width(Length::Fill)
+                .height(Length::FillPortion(filled))
+                .style(move |_| bar_style(color)),
+        );
+    }
+    container(bar)
+        .height(Length::Fill)
+        .align_y(Vertical::Bottom)
+        .into()
+}
+
+/// A bar filling `value / peak` of the available width.
+fn horizontal_bar(value: u64, peak: u64, color: Color) -> Element<'static, Message> {
+    let (filled, empty) = portions(value, peak);
+    let mut bar = row![].width(Length::Fill).height(Length::Fixed(BAR_HEIGHT));
+    if filled > 0 {
+        bar = bar.push(
+            container(Space::new())
+                .width(Length::FillPortion(filled))
+                .height(Length::Fill)
+                .style(move |_| bar_style(color)),
+        );
+    }
+    if empty > 0 {
+        bar = bar.push(Space::new().width(Length::FillPortion(empty)));
+    }
+    bar.into()
+}
+
+/// Splits a bar into filled and empty fill portions out of 100.
+fn portions(value: u64, peak: u64) -> (u16, u16) {
+    if peak == 0 {
+        return (0, 100);
+    }
+    let filled = ((value.min(peak) * 100) / peak) as u16;
+    (filled, 100 - filled)
+}
+
+fn bar_style(color: Color) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(color)),
+        border: Border {
+            color,
+            width: 0.0,
+            radius: Radius::from(3.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    }
+}
+
+fn panel_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(with_alpha(palette.surface_muted, 0.3))),
+        border: Border {
+            color: with_alpha(palette.border, 0.5),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        shadow: Shadow::default(),
+        ..container::Style::default()
+    }
+}
@@ -1,19 +1,46 @@
-use iced::widget::{row, text, Space};
-use iced::{Alignment, Element, Length};
+use std::time::Duration;
 
+use chrono::{Duration as ChronoDuration, Local};
+use cpt_core::services::stats::format_age;
+use iced::alignment::{Horizontal, Vertical};
+use iced::border::{Border, Radius};
+use iced::widget::{button, column, container, mouse_area, row, text, Space};
+use iced::{Alignment, Background, Color, Element, Length, Padding, Shadow, Vector};
+
+use crate::app::i18n::Catalog;
 use crate::app::message::Message;
-use crate::app::state::{LoadState, ToastKind};
+use crate::app::state::{LoadState, StatusToast, ToastAction, ToastKind};
+use crate::app::theme::Palette;
 
 use super::super::desktop::CptDesktop;
+use super::styles::{ghost_button_style, labelled, with_alpha};
+
+/// Width of a toast card.
+const TOAST_WIDTH: f32 = 360.0;
 
 impl CptDesktop {
     pub(crate) fn status_line(&self) -> Element<'_, Message> {
         let store = self.views.get(&self.active);
+        let reduce_motion = self.window_state.state().reduce_motion;
         let mut left = match store.and_then(|view| view.last_refreshed) {
-            Some(ts) => text(format!("Last refreshed {}s ago", ts.elapsed().as_secs()))
+            // A clock time holds still where the running seconds count would not.
+            Some(ts) if reduce_motion => {
+                let age = ChronoDuration::from_std(ts.elapsed()).unwrap_or_default();
+                let at = Local::now() - age;
+                text(self.i18n.tr_args(
+                    "status-refreshed-at",
+                    &[("time", at.format("%H:%M").to_string())],
+                ))
                 .size(12)
-                .color(self.palette.text_secondary),
-            None => text("Not yet refreshed")
+                .color(self.palette.text_secondary)
+            }
+            Some(ts) => text(self.i18n.tr_args(
+                "status-refreshed-ago",
+                &[("age", refresh_age(ts.elapsed(), &self.i18n))],
+            ))
+            .size(12)
+            .color(self.palette.text_secondary),
+            None => text(self.i18n.tr("status-not-refreshed"))
                 .size(12)
                 .color(self.palette.text_secondary),
         };
@@ -21,10 +48,12 @@ impl CptDesktop {
         if let Some(store) = store {
             match &store.state {
                 LoadState::Loading => {
-                    left = text("Loading…").size(12).color(self.palette.info);
+                    left = text(self.i18n.tr("status-loading"))
+                        .size(12)
+                        .color(self.palette.info);
                 }
                 LoadState::Error(err) => {
-                    left = text(format!("Error: {err}"))
+                    left = text(self.i18n.tr_args("status-error", &[("error", err.clone())]))
                         .size(12)
                         .color(self.palette.danger);
                 }
@@ -32,18 +61,135 @@ impl CptDesktop {
             }
         }
 
-        let right = if let Some(status) = &self.status {
-            let color = match status.kind {
-                ToastKind::Info => self.palette.info,
-                ToastKind::Error => self.palette.danger,
-            };
-            text(&status.message).size(12).color(color)
+        let palette = self.palette;
+        let (state, toggle) = if self.auto_refresh_paused {
+            (
+                self.i18n.tr("status-refresh-paused"),
+                self.i18n.tr("status-refresh-resume"),
+            )
         } else {
-            text("").size(12).color(self.palette.text_secondary)
+            (String::new(), self.i18n.tr("status-refresh-pause"))
+        };
+        row![
+            left,
+            text(state).size(12).color(palette.warning),
+            Space::new().width(Length::Fill),
+            button(text(toggle).size(12).color(palette.secondary_text))
+                .padding([2, 8])
+                .on_press(Message::AutoRefreshToggled)
+                .style(move |_, status| ghost_button_style(palette, status)),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .into()
+    }
+
+    /// The toasts, newest at the bottom, in the window's lower right corner above the status
+    /// bar; `None` when there are none.
+    pub(crate) fn toast_stack(&self) -> Option<Element<'_, Message>> {
+        if self.toasts.is_empty() {
+            return None;
+        }
+        let cards = self
+            .toasts
+            .iter()
+            .fold(column![].spacing(8), |cards, toast| {
+                cards.push(self.toast_card(toast))
+            });
+        Some(
+            container(cards)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(Padding {
+                    top: 0.0,
+                    right: 24.0,
+                    bottom: 44.0,
+                    left: 0.0,
+                })
+                .align_x(Horizontal::Right)
+                .align_y(Vertical::Bottom)
+                .into(),
+        )
+    }
+
+    fn toast_card<'a>(&'a self, toast: &'a StatusToast) -> Element<'a, Message> {
+        let palette = self.palette;
+        let accent = match toast.kind {
+            ToastKind::Info => palette.info,
+            ToastKind::Error => palette.danger,
         };
+        let mut body = row![text(&toast.message)
+            .size(13)
+            .color(palette.text_primary)
+            .width(Length::Fill)]
+        .spacing(8)
+        .align_y(Alignment::Center);
 
-        row![left, Space::new().width(Length::Fill), right]
-            .align_y(Alignment::Center)
+        // An offer shows only while it can still be taken up.
+        let action = match toast.action {
+            Some(ToastAction::Undo) if self.undo.is_some() => {
+                Some((self.i18n.tr("toast-action-undo"), Message::UndoRequested))
+            }
+            Some(ToastAction::OpenFile) if self.exported.is_some() => Some((
+                self.i18n.tr("toast-action-open-file"),
+                Message::ExportedFileOpened,
+            )),
+            Some(ToastAction::Retry) if self.retry.is_some() => {
+                Some((self.i18n.tr("toast-action-retry"), Message::RetryRequested))
+            }
+            _ => None,
+        };
+        if let Some((label, message)) = action {
+            body = body.push(
+                button(text(label).size(12).color(palette.primary))
+                    .padding([2, 8])
+                    .on_press(message)
+                    .style(move |_, status| ghost_button_style(palette, status)),
+            );
+        }
+        body = body.push(labelled(
+            button(text("×").size(14).color(palette.text_muted))
+                .padding([0, 6])
+                .on_press(Message::ToastDismissed(toast.id))
+                .style(move |_, status| ghost_button_style(palette, status)),
+            self.i18n.tr("toast-dismiss"),
+            palette,
+        ));
+
+        let card = container(body)
+            .width(Length::Fixed(TOAST_WIDTH))
+            .padding([8, 12])
+            .style(move |_| toast_style(palette, accent));
+        mouse_area(card)
+            .on_enter(Message::ToastHovered(toast.id, true))
+            .on_exit(Message::ToastHovered(toast.id, false))
             .into()
     }
 }
+
+/// Seconds for the first minute, then minutes, hours, and days like the Stats ages.
+fn refresh_age(elapsed: Duration, i18n: &Catalog) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        i18n.tr_args("settings-refresh-seconds", &[("seconds", secs.to_string())])
+    } else {
+        format_age(secs as i64)
+    }
+}
+
+fn toast_style(palette: Palette, accent: Color) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface)),
+        border: Border {
+            color: with_alpha(accent, 0.8),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        shadow: Shadow {
+            color: with_alpha(Color::BLACK, 0.25),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        ..container::Style::default()
+    }
+}
@@ -1,7 +1,9 @@
 use iced::border::{Border, Radius};
-use iced::widget::{button, container, text_input};
-use iced::{Background, Color, Shadow, Vector};
+use iced::widget::text::IntoFragment;
+use iced::widget::{button, container, text, text_input, tooltip};
+use iced::{Background, Color, Element, Shadow, Vector};
 
+use crate::app::message::Message;
 use crate::app::theme::Palette;
 
 pub(super) fn with_alpha(color: Color, alpha: f32) -> Color {
@@ -82,6 +84,19 @@ pub(super) fn ghost_button_style(palette: Palette, status: button::Status) -> bu
     style
 }
 
+/// [`text_input_style`] with a danger border while the capture text has a bad token.
+pub(super) fn capture_input_style(
+    palette: Palette,
+    status: text_input::Status,
+    invalid: bool,
+) -> text_input::Style {
+    let mut style = text_input_style(palette, status);
+    if invalid {
+        style.border.color = palette.danger;
+    }
+    style
+}
+
 pub(super) fn text_input_style(palette: Palette, status: text_input::Status) -> text_input::Style {
     let mut style = text_input::Style {
         background: Background::Color(palette.surface_muted),
@@ -136,3 +151,67 @@ pub(super) fn chip_style(color: Color) -> container::Style {
         ..container::Style::default()
     }
 }
+
+pub(super) fn dialog_container_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface)),
+        border: Border {
+            color: with_alpha(palette.border, 0.9),
+            width: 1.0,
+            radius: Radius::from(10.0),
+        },
+        shadow: Shadow {
+            color: with_alpha(Color::BLACK, 0.4),
+            offset: Vector::new(0.0, 8.0),
+            blur_radius: 24.0,
+        },
+        ..container::Style::default()
+    }
+}
+
+pub(super) fn backdrop_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(with_alpha(Color::BLACK, 0.45))),
+        ..container::Style::default()
+    }
+}
+
+/// Outline drawn around whatever F6 traversal has reached. Unfocused content keeps a transparent
+/// ring of the same width so the layout does not shift as focus moves.
+pub(super) fn focus_ring<'a>(
+    content: impl Into<Element<'a, Message>>,
+    focused: bool,
+    palette: Palette,
+) -> Element<'a, Message> {
+    container(content)
+        .padding(2)
+        .style(move |_| container::Style {
+            border: Border {
+                color: if focused {
+                    palette.primary
+                } else {
+                    Color::TRANSPARENT
+                },
+                width: 2.0,
+                radius: Radius::from(8.0),
+            },
+            ..container::Style::default()
+        })
+        .into()
+}
+
+/// Names a symbol-only control (⠿, ×, ⋮, ‹ ›) in a hover tooltip.
+pub(super) fn labelled<'a>(
+    content: impl Into<Element<'a, Message>>,
+    label: impl IntoFragment<'a>,
+    palette: Palette,
+) -> Element<'a, Message> {
+    tooltip(
+        content,
+        container(text(label).size(12).color(palette.text_primary))
+            .padding([4, 8])
+            .style(move |_| dialog_container_style(palette)),
+        tooltip::Position::Bottom,
+    )
+    .into()
+}
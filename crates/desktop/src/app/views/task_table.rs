@@ -1,10 +1,15 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 
-use cpt_core::model::EnergyLevel;
+use cpt_core::model::{EnergyLevel, SortField, Task};
 use cpt_core::ViewSnapshot;
 
-use crate::app::helpers::format_datetime;
-use crate::app::state::ViewTab;
+use iced::Length;
+
+use crate::app::columns::ColumnLayout;
+use crate::app::helpers::{
+    completion_group, format_datetime, format_minutes, waiting_group, waiting_person,
+};
+use crate::app::state::{ColumnSort, ViewTab};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum ColumnAlignment {
@@ -17,6 +22,8 @@ pub(crate) struct TableColumn {
     pub label: &'static str,
     pub portion: u16,
     pub alignment: ColumnAlignment,
+    /// Width the user dragged the column to; `None` shares space by `portion`.
+    pub width: Option<f32>,
 }
 
 impl TableColumn {
@@ -25,6 +32,7 @@ impl TableColumn {
             label,
             portion,
             alignment: ColumnAlignment::Left,
+            width: None,
         }
     }
 
@@ -33,24 +41,60 @@ impl TableColumn {
             label,
             portion,
             alignment: ColumnAlignment::Right,
+            width: None,
+        }
+    }
+
+    pub(crate) fn length(&self) -> Length {
+        match self.width {
+            Some(width) => Length::Fixed(width),
+            None => Length::FillPortion(self.portion),
         }
     }
+
+    /// Pixel width a resize starts from when the column has no stored width yet.
+    pub(crate) fn estimated_width(&self) -> f32 {
+        self.width.unwrap_or(self.portion as f32 * 40.0)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct TaskRow {
     pub id: String,
     pub cells: Vec<String>,
+    /// Heading this row sits under; a heading is drawn whenever it changes.
+    pub group: Option<String>,
+}
+
+/// A Waiting view heading: one person and how long their oldest task has waited.
+#[derive(Debug, Clone)]
+pub(crate) struct WaitingGroup {
+    pub label: String,
+    pub person: Option<String>,
+    pub count: usize,
+    pub waited_secs: i64,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct TaskTable {
     pub columns: Vec<TableColumn>,
     pub rows: Vec<TaskRow>,
+    /// The Waiting view's groups, in no particular order; empty for other views.
+    pub waiting: Vec<WaitingGroup>,
+}
+
+/// Whether a view keeps its group headings under `sort`: Done's days only while rows stay in
+/// completion order, and Waiting's people only in the default order.
+pub(crate) fn keeps_groups(view: ViewTab, sort: Option<ColumnSort>) -> bool {
+    match view {
+        ViewTab::Waiting => sort.is_none(),
+        _ => !sort.is_some_and(|sort| sort.field != SortField::Completed),
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct ProjectRow {
+    pub project: String,
     pub cells: Vec<String>,
 }
 
@@ -60,12 +104,40 @@ pub(crate) struct ProjectTable {
     pub rows: Vec<ProjectRow>,
 }
 
+/// The heading `task` is listed under in `view`, if the view groups its rows.
+fn row_group(view: ViewTab, task: &Task, today: NaiveDate) -> Option<String> {
+    match view {
+        ViewTab::Done => Some(completion_group(task.completed_at, today).to_string()),
+        ViewTab::Waiting => Some(waiting_group(task)),
+        _ => None,
+    }
+}
+
+/// How many group headings `tasks` are listed under in `view` with `sort`.
+pub(crate) fn heading_count(view: ViewTab, sort: Option<ColumnSort>, tasks: &[Task]) -> usize {
+    if !keeps_groups(view, sort) {
+        return 0;
+    }
+    let today = Local::now().date_naive();
+    let mut current = None;
+    let mut count = 0;
+    for group in tasks.iter().map(|task| row_group(view, task, today)) {
+        if group.is_some() && group != current {
+            count += 1;
+            current = group;
+        }
+    }
+    count
+}
+
 pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTable {
+    let today = Local::now().date_naive();
     let rows = snapshot
         .tasks
         .iter()
         .map(|task| TaskRow {
             id: task.id.clone(),
+            group: row_group(view, task, today),
             cells: match view {
                 ViewTab::All => vec![
                     task.title.clone(),
@@ -76,6 +148,7 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
                     format_date(task.due_at),
                     format_priority(task.priority),
                     format_energy(task.energy),
+                    format_estimate(task.time_estimate),
                 ],
                 ViewTab::Inbox => vec![
                     task.title.clone(),
@@ -84,6 +157,7 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
                     display_list(&task.tags),
                     format_date(task.due_at),
                     format_priority(task.priority),
+                    format_date(Some(task.created_at)),
                 ],
                 ViewTab::Next => vec![
                     task.title.clone(),
@@ -91,6 +165,7 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
                     display_list(&task.contexts),
                     display_list(&task.tags),
                     format_energy(task.energy),
+                    format_estimate(task.time_estimate),
                     format_date(task.due_at),
                 ],
                 ViewTab::Waiting => vec![
@@ -107,12 +182,58 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
                     format_date(task.due_at),
                     display_list(&task.tags),
                 ],
-                ViewTab::Projects => vec![],
+                ViewTab::Someday => vec![
+                    task.title.clone(),
+                    display_option(task.project.clone()),
+                    display_list(&task.contexts),
+                    display_list(&task.tags),
+                    format_priority(task.priority),
+                ],
+                ViewTab::Done => vec![
+                    task.title.clone(),
+                    display_option(task.project.clone()),
+                    display_list(&task.contexts),
+                    display_list(&task.tags),
+                    format_date(task.completed_at),
+                ],
+                ViewTab::Projects | ViewTab::Stats => vec![],
             },
         })
         .collect();
 
-    let columns = match view {
+    let mut waiting: Vec<WaitingGroup> = Vec::new();
+    if view == ViewTab::Waiting {
+        let now = Utc::now();
+        for task in &snapshot.tasks {
+            let label = waiting_group(task);
+            let waited_secs = (now - task.waiting_since.unwrap_or(task.created_at))
+                .num_seconds()
+                .max(0);
+            match waiting.iter_mut().find(|group| group.label == label) {
+                Some(group) => {
+                    group.count += 1;
+                    group.waited_secs = group.waited_secs.max(waited_secs);
+                }
+                None => waiting.push(WaitingGroup {
+                    label,
+                    person: waiting_person(task).map(str::to_string),
+                    count: 1,
+                    waited_secs,
+                }),
+            }
+        }
+    }
+
+    TaskTable {
+        waiting,
+        columns: view_columns(view),
+        rows,
+    }
+}
+
+/// Default columns of a view's task table, before the user's layout is applied.
+pub(crate) fn view_columns(view: ViewTab) -> Vec<TableColumn> {
+    match view {
         ViewTab::All => vec![
             TableColumn::left("Title", 6),
             TableColumn::left("Status", 2),
@@ -122,6 +243,7 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
             TableColumn::left("Due", 2),
             TableColumn::left("Priority", 1),
             TableColumn::left("Energy", 1),
+            TableColumn::left("Estimate", 1),
         ],
         ViewTab::Inbox => vec![
             TableColumn::left("Title", 8),
@@ -130,6 +252,7 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
             TableColumn::left("Tags", 3),
             TableColumn::left("Due", 2),
             TableColumn::left("Priority", 1),
+            TableColumn::left("Created", 2),
         ],
         ViewTab::Next => vec![
             TableColumn::left("Title", 8),
@@ -137,6 +260,7 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
             TableColumn::left("Contexts", 3),
             TableColumn::left("Tags", 3),
             TableColumn::left("Energy", 1),
+            TableColumn::left("Estimate", 1),
             TableColumn::left("Due", 2),
         ],
         ViewTab::Waiting => vec![
@@ -153,10 +277,64 @@ pub(crate) fn build_task_table(view: ViewTab, snapshot: &ViewSnapshot) -> TaskTa
             TableColumn::left("Due", 2),
             TableColumn::left("Tags", 3),
         ],
-        ViewTab::Projects => Vec::new(),
-    };
+        ViewTab::Someday => vec![
+            TableColumn::left("Title", 8),
+            TableColumn::left("Project", 3),
+            TableColumn::left("Contexts", 3),
+            TableColumn::left("Tags", 3),
+            TableColumn::left("Priority", 1),
+        ],
+        ViewTab::Done => vec![
+            TableColumn::left("Title", 8),
+            TableColumn::left("Project", 3),
+            TableColumn::left("Contexts", 3),
+            TableColumn::left("Tags", 3),
+            TableColumn::left("Completed", 2),
+        ],
+        ViewTab::Projects | ViewTab::Stats => Vec::new(),
+    }
+}
 
-    TaskTable { columns, rows }
+/// Labels of a view's default columns, in their default order.
+pub(crate) fn column_labels(view: ViewTab) -> Vec<&'static str> {
+    view_columns(view)
+        .into_iter()
+        .map(|column| column.label)
+        .collect()
+}
+
+/// Reorders, hides, and sizes `table`'s columns (and every row's cells) per `layout`.
+pub(crate) fn apply_layout(table: TaskTable, layout: &ColumnLayout) -> TaskTable {
+    let labels: Vec<&'static str> = table.columns.iter().map(|column| column.label).collect();
+    let picks: Vec<usize> = layout
+        .visible_labels(&labels)
+        .into_iter()
+        .filter_map(|label| labels.iter().position(|candidate| *candidate == label))
+        .collect();
+    let columns = picks
+        .iter()
+        .map(|&idx| {
+            let mut column = table.columns[idx].clone();
+            column.width = layout.width(column.label);
+            column
+        })
+        .collect();
+    let rows = table
+        .rows
+        .into_iter()
+        .map(|row| TaskRow {
+            cells: picks
+                .iter()
+                .map(|&idx| row.cells.get(idx).cloned().unwrap_or_default())
+                .collect(),
+            ..row
+        })
+        .collect();
+    TaskTable {
+        columns,
+        rows,
+        waiting: table.waiting,
+    }
 }
 
 pub(crate) fn build_project_table(snapshot: &ViewSnapshot) -> ProjectTable {
@@ -164,6 +342,7 @@ pub(crate) fn build_project_table(snapshot: &ViewSnapshot) -> ProjectTable {
         .projects
         .iter()
         .map(|summary| ProjectRow {
+            project: summary.project.clone(),
             cells: vec![
                 summary.project.clone(),
                 summary.total.to_string(),
@@ -216,3 +395,7 @@ fn format_energy(energy: Option<EnergyLevel>) -> String {
         .map(|value| value.as_str().to_string())
         .unwrap_or_else(|| "—".into())
 }
+
+fn format_estimate(minutes: Option<u32>) -> String {
+    minutes.map(format_minutes).unwrap_or_else(|| "—".into())
+}
@@ -1,100 +1,365 @@
+use std::ops::Range;
+
+use cpt_core::services::stats::format_age;
 use iced::alignment::Horizontal;
 use iced::border::Border;
-use iced::widget::{button, column, container, lazy, pick_list, row, scrollable, text, text_input};
-use iced::{Alignment, Background, Color, Element, Length, Shadow};
+use iced::widget::Space;
+use iced::widget::{
+    button, column, container, lazy, mouse_area, pick_list, row, scrollable, text, text_input,
+};
+use iced::{mouse, Alignment, Background, Color, Element, Length, Shadow};
 
+use crate::app::columns::{ColumnLayout, TITLE_COLUMN};
+use crate::app::helpers::matches_search;
+use crate::app::i18n::Catalog;
 use crate::app::message::Message;
-use crate::app::state::{InlineEditState, InlineEditableField, ViewTab};
+use crate::app::state::{
+    ColumnSort, ContextMenuState, DropTarget, InlineEditState, InlineEditableField, ListViewport,
+    ViewTab, TASK_ROW_HEIGHT, VIRTUALIZE_AFTER,
+};
 use crate::app::theme::Palette;
 
 use super::super::desktop::CptDesktop;
-use super::styles::{text_input_style, with_alpha};
+use super::context_menu::task_context_menu;
+use super::date_picker::date_picker;
+use super::styles::{chip_style, ghost_button_style, labelled, text_input_style, with_alpha};
 use super::task_table::{
-    build_project_table, build_task_table, ColumnAlignment, ProjectRow, ProjectTable, TableColumn,
-    TaskRow, TaskTable,
+    apply_layout, build_project_table, build_task_table, column_labels, heading_count,
+    keeps_groups, ColumnAlignment, ProjectRow, ProjectTable, TableColumn, TaskRow, TaskTable,
+    WaitingGroup,
 };
 
+/// Drag handle column; dragging it onto a sidebar entry moves the task, and onto another row in
+/// Next reorders it.
+const GRIP_WIDTH: f32 = 14.0;
+/// Trailing column holding the header's column menu button.
+const COLUMN_MENU_WIDTH: f32 = 16.0;
+const COLUMN_MENU_PANEL_WIDTH: f32 = 240.0;
+
 impl CptDesktop {
     pub(crate) fn task_list(&self) -> Element<'_, Message> {
         let palette = self.palette;
         let active: ViewTab = self.active;
         let entry = self.views.get(&active);
         let version = entry.map(|view| view.version).unwrap_or_default();
+        let has_more = entry.map(|view| view.has_more).unwrap_or(false);
+        let sort = entry.and_then(|view| view.sort);
         let snapshot = entry.and_then(|view| view.snapshot.clone());
         let selected = self.selected_task.clone();
         let inline_edit = self.inline_edit.clone();
+        let context_menu = self.context_menu.clone();
+        let search = self.search.clone();
+        let layout = self.columns.get(active);
+        let layout_version = self.columns.version;
+        let column_menu_open = self.column_menu_open;
+        // Rows take drops only while Next shows its manual order rather than a header sort.
+        let reorder = (active == ViewTab::Next && sort.is_none()).then(|| {
+            self.drag.as_ref().and_then(|drag| match &drag.target {
+                Some(DropTarget::Row(id)) => Some(id.clone()),
+                _ => None,
+            })
+        });
 
         if let Some(snapshot) = snapshot {
             if snapshot.is_project_view() {
-                let dependency = (active, version, snapshot.projects.len());
+                if snapshot.projects.is_empty() {
+                    return self.empty_state(active);
+                }
+                let dependency = (active, version, snapshot.projects.len(), self.i18n.locale());
                 let palette = palette;
                 let snapshot_clone = snapshot.clone();
+                let i18n = self.i18n.clone();
                 let list = lazy(dependency, move |_| {
                     let data = build_project_table(&snapshot_clone);
-                    render_project_table(data, palette)
+                    render_project_table(data, palette, &i18n)
                 });
 
                 return scrollable(list).height(Length::Fill).into();
             }
 
-            let inline_edit_key = inline_edit
-                .as_ref()
-                .map(|edit| (edit.task_id.clone(), edit.value.clone(), edit.field));
+            let mut snapshot = snapshot;
+            snapshot.tasks.retain(|task| matches_search(task, &search));
+            if snapshot.tasks.is_empty() {
+                return self.empty_state(active);
+            }
+            let visible = (snapshot.tasks.len() > VIRTUALIZE_AFTER).then(|| {
+                let headings = heading_count(active, sort, &snapshot.tasks);
+                self.list_viewport
+                    .visible_rows(snapshot.tasks.len() + headings)
+            });
+
+            let inline_edit_key = inline_edit.as_ref().map(|edit| {
+                (
+                    edit.task_id.clone(),
+                    edit.value.clone(),
+                    edit.field,
+                    edit.month,
+                    edit.error.clone(),
+                )
+            });
             let dependency = (
                 active,
                 version,
                 snapshot.tasks.len(),
+                has_more,
                 selected.clone(),
                 inline_edit_key,
+                // Headers, placeholders, and the row menu are translated.
+                (context_menu.clone(), self.i18n.locale()),
+                search,
+                sort,
+                layout_version,
+                column_menu_open,
+                (reorder.clone(), visible.clone()),
             );
-            let palette = palette;
+            let props = TaskTableProps {
+                palette,
+                selected,
+                inline_edit,
+                context_menu,
+                has_more,
+                sort,
+                reorder,
+                visible,
+            };
             let snapshot_clone = snapshot.clone();
-            let selected_clone = selected.clone();
-            let inline_edit_clone = inline_edit.clone();
+            let i18n = self.i18n.clone();
             let list = lazy(dependency, move |_| {
-                let data = build_task_table(active, &snapshot_clone);
-                render_task_table(
-                    data,
-                    palette,
-                    selected_clone.clone(),
-                    inline_edit_clone.clone(),
-                )
+                let mut data = apply_layout(build_task_table(active, &snapshot_clone), &layout);
+                if !keeps_groups(active, sort) {
+                    for row in &mut data.rows {
+                        row.group = None;
+                    }
+                }
+                render_task_table(data, &props, &i18n)
             });
-
-            return scrollable(list).height(Length::Fill).into();
+            let list: Element<'_, Message> = if column_menu_open {
+                column![
+                    column_menu(active, &self.columns.get(active), palette, &self.i18n),
+                    list
+                ]
+                .spacing(8)
+                .into()
+            } else {
+                list.into()
+            };
+
+            return scrollable(list)
+                .height(Length::Fill)
+                .on_scroll(|viewport| {
+                    Message::TaskListScrolled(ListViewport {
+                        offset: viewport.absolute_offset().y,
+                        height: viewport.bounds().height,
+                        content: viewport.content_bounds().height,
+                    })
+                })
+                .into();
         }
 
         scrollable(column![].spacing(0)).height(Length::Fill).into()
     }
 }
 
-fn render_task_table(
-    data: TaskTable,
+/// View state the task table is drawn from, besides the rows themselves.
+struct TaskTableProps {
     palette: Palette,
     selected: Option<String>,
     inline_edit: Option<InlineEditState>,
+    context_menu: Option<ContextMenuState>,
+    has_more: bool,
+    sort: Option<ColumnSort>,
+    /// `Some` when rows accept drops for reordering, holding the row currently dragged over.
+    reorder: Option<Option<String>>,
+    /// `Some` for a long list: only these rows are built, with space standing in for the rest.
+    visible: Option<Range<usize>>,
+}
+
+fn render_task_table(
+    data: TaskTable,
+    props: &TaskTableProps,
+    i18n: &Catalog,
 ) -> Element<'static, Message> {
-    let mut table = column![build_header_row(&data.columns, palette)].spacing(4);
+    let TaskTableProps {
+        palette,
+        selected,
+        inline_edit,
+        context_menu,
+        has_more,
+        sort,
+        reorder,
+        visible,
+    } = props;
+    let (palette, has_more, sort) = (*palette, *has_more, *sort);
+    let mut table = column![build_header_row(&data.columns, palette, true, sort, i18n)].spacing(4);
+    let mut current_group = None;
+    let (mut hidden_above, mut hidden_below) = (0usize, 0usize);
+    // Position in the list counting group headings, which take a row each.
+    let mut line = 0usize;
 
     for row_data in data.rows {
+        let heading = row_data.group.is_some() && row_data.group != current_group;
+        if heading {
+            current_group = row_data.group.clone();
+        }
+        if let Some(visible) = &visible {
+            let lines = 1 + usize::from(heading);
+            let first = line;
+            line += lines;
+            if line <= visible.start {
+                hidden_above += lines;
+                continue;
+            }
+            if first >= visible.end {
+                hidden_below += lines;
+                continue;
+            }
+            if hidden_above > 0 {
+                table = table.push(row_spacer(hidden_above));
+                hidden_above = 0;
+            }
+        }
+        if heading {
+            let label = current_group.clone().unwrap_or_default();
+            table = table.push(
+                match data.waiting.iter().find(|group| group.label == label) {
+                    Some(group) => build_waiting_heading(group, palette, i18n),
+                    None => build_group_heading(label, palette),
+                },
+            );
+        }
         let is_selected = selected
             .as_ref()
             .map(|id| id == &row_data.id)
             .unwrap_or(false);
-        table = table.push(build_task_row(
+        let menu = context_menu
+            .clone()
+            .filter(|menu| menu.task_id == row_data.id);
+        let id = row_data.id.clone();
+        let picker = inline_edit
+            .clone()
+            .filter(|edit| edit.task_id == row_data.id && edit.field.is_date());
+        let mut row = mouse_area(build_task_row(
             &data.columns,
             row_data,
             palette,
             is_selected,
             inline_edit.clone(),
-        ));
+            i18n,
+        ))
+        .on_right_press(Message::ContextMenuOpened(id.clone()));
+        if let Some(drop_row) = &reorder {
+            if drop_row.as_ref() == Some(&id) {
+                table = table.push(drop_indicator(palette));
+            }
+            let target = DropTarget::Row(id);
+            row = row
+                .on_enter(Message::DragTargetEntered(target.clone()))
+                .on_exit(Message::DragTargetLeft(target));
+        }
+        table = table.push(row);
+        if let Some(menu) = menu {
+            table = table.push(task_context_menu(menu, palette, i18n));
+        }
+        if let Some(edit) = picker {
+            table = table.push(date_picker(&edit, palette, i18n));
+        }
+    }
+
+    if hidden_below > 0 {
+        table = table.push(row_spacer(hidden_below));
+    }
+    if has_more {
+        table = table.push(
+            container(
+                button(
+                    text(i18n.tr("table-load-more"))
+                        .size(14)
+                        .color(palette.secondary_text),
+                )
+                .on_press(Message::LoadMoreRequested)
+                .style(move |_, status| ghost_button_style(palette, status)),
+            )
+            .width(Length::Fill)
+            .padding([8, 12])
+            .align_x(Horizontal::Center),
+        );
     }
 
     table.into()
 }
 
-fn render_project_table(data: ProjectTable, palette: Palette) -> Element<'static, Message> {
-    let mut table = column![build_header_row(&data.columns, palette)].spacing(4);
+/// Empty space the height of `rows` rows that are not built.
+fn row_spacer(rows: usize) -> Element<'static, Message> {
+    // The column's own spacing is added once around the spacer.
+    Space::new()
+        .width(Length::Fill)
+        .height(Length::Fixed(rows as f32 * TASK_ROW_HEIGHT - 4.0))
+        .into()
+}
+
+/// Line drawn above the row a dragged task will take the place of.
+fn drop_indicator(palette: Palette) -> Element<'static, Message> {
+    container(Space::new())
+        .width(Length::Fill)
+        .height(Length::Fixed(2.0))
+        .style(move |_| container::Style {
+            background: Some(Background::Color(palette.primary)),
+            ..container::Style::default()
+        })
+        .into()
+}
+
+fn build_group_heading(label: String, palette: Palette) -> Element<'static, Message> {
+    container(text(label).size(13).color(palette.info))
+        .width(Length::Fill)
+        .padding([6, 12])
+        .into()
+}
+
+/// A person's heading in the Waiting view: their task count, how long the oldest has waited,
+/// and a button logging a follow-up on all of them.
+fn build_waiting_heading(
+    group: &WaitingGroup,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let count = match group.count {
+        1 => i18n.tr("waiting-one-task"),
+        count => i18n.tr_args("waiting-tasks", &[("count", count.to_string())]),
+    };
+    container(
+        row![
+            text(group.label.clone()).size(13).color(palette.info),
+            text(count).size(12).color(palette.text_muted),
+            container(
+                text(i18n.tr_args("waiting-for", &[("age", format_age(group.waited_secs))]))
+                    .size(12)
+            )
+            .padding([2, 8])
+            .style(move |_| chip_style(palette.warning)),
+            Space::new().width(Length::Fill),
+            button(
+                text(i18n.tr("waiting-nudge-all"))
+                    .size(12)
+                    .color(palette.secondary_text)
+            )
+            .on_press(Message::WaitingNudged(group.person.clone()))
+            .style(move |_, status| ghost_button_style(palette, status)),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .padding([6, 12])
+    .into()
+}
+
+fn render_project_table(
+    data: ProjectTable,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let mut table = column![build_header_row(&data.columns, palette, false, None, i18n)].spacing(4);
 
     for (index, row) in data.rows.into_iter().enumerate() {
         table = table.push(build_project_row(
@@ -108,16 +373,58 @@ fn render_project_table(data: ProjectTable, palette: Palette) -> Element<'static
     table.into()
 }
 
-fn build_header_row(columns: &[TableColumn], palette: Palette) -> Element<'static, Message> {
+fn build_header_row(
+    columns: &[TableColumn],
+    palette: Palette,
+    draggable: bool,
+    sort: Option<ColumnSort>,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
     let mut header = row![].spacing(8).align_y(Alignment::Center);
+    if draggable {
+        header = header.push(Space::new().width(Length::Fixed(GRIP_WIDTH)));
+    }
     for column in columns {
-        header = header.push(
-            text(column.label.to_uppercase())
-                .size(12)
-                .color(palette.text_secondary)
-                .width(Length::FillPortion(column.portion))
-                .align_x(horizontal_alignment(column.alignment)),
-        );
+        let field = draggable
+            .then(|| ColumnSort::field_for_column(column.label))
+            .flatten();
+        let glyph = match sort.filter(|sort| Some(sort.field) == field) {
+            Some(sort) if sort.descending => " ▼",
+            Some(_) => " ▲",
+            None => "",
+        };
+        let label = text(format!(
+            "{}{glyph}",
+            i18n.column(column.label).to_uppercase()
+        ))
+        .size(12)
+        .width(Length::Fill)
+        .align_x(horizontal_alignment(column.alignment));
+        let label: Element<'static, Message> = match field {
+            Some(field) => button(label)
+                .padding(0)
+                .width(Length::Fill)
+                .on_press(Message::ColumnSortPressed(field))
+                .style(move |_, status| header_button_style(palette, status))
+                .into(),
+            None => label.color(palette.text_secondary).into(),
+        };
+        let mut cell = row![label].align_y(Alignment::Center);
+        if draggable && column.label != TITLE_COLUMN {
+            cell = cell.push(resize_handle(column, palette));
+        }
+        header = header.push(container(cell).width(column.length()));
+    }
+    if draggable {
+        header = header.push(labelled(
+            button(text("⋮").size(14).color(palette.text_secondary))
+                .padding(0)
+                .width(Length::Fixed(COLUMN_MENU_WIDTH))
+                .on_press(Message::ColumnMenuToggled)
+                .style(move |_, status| header_button_style(palette, status)),
+            i18n.tr("table-columns"),
+            palette,
+        ));
     }
 
     container(header)
@@ -133,8 +440,18 @@ fn build_task_row(
     palette: Palette,
     selected: bool,
     inline_edit: Option<InlineEditState>,
+    i18n: &Catalog,
 ) -> Element<'static, Message> {
-    let mut cells = row![].spacing(8).align_y(Alignment::Center);
+    let grip = mouse_area(
+        text("⠿")
+            .size(14)
+            .color(palette.text_muted)
+            .width(Length::Fixed(GRIP_WIDTH)),
+    )
+    .on_press(Message::DragStarted(row_data.id.clone()))
+    .interaction(mouse::Interaction::Grab);
+    let grip = labelled(grip, i18n.tr("table-drag-to-move"), palette);
+    let mut cells = row![grip].spacing(8).align_y(Alignment::Center);
     let active_edit = inline_edit.as_ref().and_then(|edit| {
         if edit.task_id == row_data.id {
             Some(edit.clone())
@@ -147,27 +464,36 @@ fn build_task_row(
         let cell: Element<'static, Message> = if let Some(edit) = active_edit.as_ref() {
             match (edit.field, column.label) {
                 (InlineEditableField::Title, "Title") => {
-                    render_title_editor(edit.clone(), column, palette)
+                    render_title_editor(edit.clone(), column, palette, i18n)
                 }
                 (InlineEditableField::Project, "Project") => {
-                    render_project_editor(edit.clone(), column, palette)
+                    render_project_editor(edit.clone(), column, palette, i18n)
                 }
                 (InlineEditableField::Contexts, "Contexts") => render_token_editor(
                     edit.clone(),
                     column,
                     palette,
-                    "Contexts (comma separated)",
-                    "Add context",
+                    &i18n.tr("editor-contexts"),
+                    &i18n.tr("editor-add-context"),
                 ),
                 (InlineEditableField::Tags, "Tags") => render_token_editor(
                     edit.clone(),
                     column,
                     palette,
-                    "Tags (comma separated)",
-                    "Add tag",
+                    &i18n.tr("editor-tags"),
+                    &i18n.tr("editor-add-tag"),
                 ),
                 (InlineEditableField::Priority, "Priority") => {
-                    render_priority_editor(edit.clone(), column, palette)
+                    render_priority_editor(edit.clone(), column, palette, i18n)
+                }
+                (InlineEditableField::Energy, "Energy") => {
+                    render_energy_editor(edit.clone(), column, i18n)
+                }
+                (InlineEditableField::TimeEstimate, "Estimate") => {
+                    render_estimate_editor(edit.clone(), column, palette)
+                }
+                (InlineEditableField::Due, "Due") | (InlineEditableField::Defer, "Defer") => {
+                    render_date_editor(edit.clone(), column, palette, i18n)
                 }
                 _ => build_default_cell(column, value, palette, selected, &row_data.id),
             }
@@ -176,6 +502,7 @@ fn build_task_row(
         };
         cells = cells.push(cell);
     }
+    cells = cells.push(Space::new().width(Length::Fixed(COLUMN_MENU_WIDTH)));
 
     container(cells)
         .width(Length::Fill)
@@ -199,7 +526,7 @@ fn build_task_cell_button(
         .align_x(horizontal_alignment(column.alignment));
 
     button(label)
-        .width(Length::FillPortion(column.portion))
+        .width(column.length())
         .padding([0, 4])
         .style(move |_, status| task_cell_style(palette, selected, status, disable_hover_bg))
         .on_press(message)
@@ -219,6 +546,10 @@ fn build_default_cell(
         "Contexts" => Message::TaskContextsPressed(row_id.to_string()),
         "Tags" => Message::TaskTagsPressed(row_id.to_string()),
         "Priority" => Message::TaskPriorityPressed(row_id.to_string()),
+        "Energy" => Message::TaskEnergyPressed(row_id.to_string()),
+        "Estimate" => Message::TaskEstimatePressed(row_id.to_string()),
+        "Due" => Message::TaskDuePressed(row_id.to_string()),
+        "Defer" => Message::TaskDeferPressed(row_id.to_string()),
         _ => Message::RowSelected(row_id.to_string()),
     };
     let disable_hover_bg = column.label == "Title";
@@ -229,9 +560,10 @@ fn render_title_editor(
     edit: InlineEditState,
     column: &TableColumn,
     palette: Palette,
+    i18n: &Catalog,
 ) -> Element<'static, Message> {
     let palette_copy = palette;
-    let input = text_input("Task title", &edit.value)
+    let input = text_input(&i18n.tr("editor-title"), &edit.value)
         .id(edit.input_id.clone())
         .on_input(Message::InlineEditChanged)
         .on_submit(Message::InlineEditSubmitted)
@@ -240,18 +572,47 @@ fn render_title_editor(
         .style(move |_, status| text_input_style(palette_copy, status))
         .width(Length::Fill);
 
-    container(input)
-        .width(Length::FillPortion(column.portion))
-        .into()
+    container(input).width(column.length()).into()
+}
+
+fn render_date_editor(
+    edit: InlineEditState,
+    column: &TableColumn,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let input = text_input(&i18n.tr("editor-date"), &edit.value)
+        .id(edit.input_id.clone())
+        .on_input(Message::InlineEditChanged)
+        .on_submit(Message::InlineEditSubmitted)
+        .padding([6, 8])
+        .size(14)
+        .style(move |_, status| text_input_style(palette, status))
+        .width(Length::Fill);
+    let clear = labelled(
+        button(text("×").size(14).color(palette.text_secondary))
+            .padding([4, 6])
+            .on_press(Message::DateCleared)
+            .style(move |_, status| ghost_button_style(palette, status)),
+        i18n.tr("editor-clear-date"),
+        palette,
+    );
+
+    let mut content = column![row![input, clear].spacing(4).align_y(Alignment::Center)].spacing(4);
+    if let Some(err) = edit.error {
+        content = content.push(text(err).size(12).color(palette.danger));
+    }
+    container(content).width(column.length()).into()
 }
 
 fn render_project_editor(
     edit: InlineEditState,
     column: &TableColumn,
     palette: Palette,
+    i18n: &Catalog,
 ) -> Element<'static, Message> {
     let palette_copy = palette;
-    let input = text_input("Project", &edit.value)
+    let input = text_input(&i18n.tr("editor-project"), &edit.value)
         .id(edit.input_id.clone())
         .on_input(Message::InlineEditChanged)
         .on_submit(Message::InlineEditSubmitted)
@@ -275,14 +636,12 @@ fn render_project_editor(
                 selected,
                 Message::InlineEditOptionSelected,
             )
-            .placeholder("Select project")
+            .placeholder(i18n.tr("editor-select-project"))
             .width(Length::Fill),
         );
     }
 
-    container(content)
-        .width(Length::FillPortion(column.portion))
-        .into()
+    container(content).width(column.length()).into()
 }
 
 fn render_token_editor(
@@ -315,15 +674,14 @@ fn render_token_editor(
         );
     }
 
-    container(content)
-        .width(Length::FillPortion(column.portion))
-        .into()
+    container(content).width(column.length()).into()
 }
 
 fn render_priority_editor(
     edit: InlineEditState,
     column: &TableColumn,
     _palette: Palette,
+    i18n: &Catalog,
 ) -> Element<'static, Message> {
     let selected = if edit.value.is_empty() {
         None
@@ -338,12 +696,48 @@ fn render_priority_editor(
         selected,
         Message::InlineEditOptionSelected,
     )
-    .placeholder("Select priority")
+    .placeholder(i18n.tr("editor-select-priority"))
     .width(Length::Fill);
 
-    container(dropdown)
-        .width(Length::FillPortion(column.portion))
-        .into()
+    container(dropdown).width(column.length()).into()
+}
+
+fn render_energy_editor(
+    edit: InlineEditState,
+    column: &TableColumn,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let selected = edit
+        .options
+        .iter()
+        .find(|option| **option == edit.value)
+        .cloned();
+    let dropdown = pick_list(
+        edit.options.clone(),
+        selected,
+        Message::InlineEditOptionSelected,
+    )
+    .placeholder(i18n.tr("editor-select-energy"))
+    .width(Length::Fill);
+
+    container(dropdown).width(column.length()).into()
+}
+
+fn render_estimate_editor(
+    edit: InlineEditState,
+    column: &TableColumn,
+    palette: Palette,
+) -> Element<'static, Message> {
+    let input = text_input("45m, 2h, 1h30m", &edit.value)
+        .id(edit.input_id.clone())
+        .on_input(Message::InlineEditChanged)
+        .on_submit(Message::InlineEditSubmitted)
+        .padding([6, 8])
+        .size(14)
+        .style(move |_, status| text_input_style(palette, status))
+        .width(Length::Fill);
+
+    container(input).width(column.length()).into()
 }
 
 fn build_project_row(
@@ -359,16 +753,21 @@ fn build_project_row(
             text(value.clone())
                 .size(14)
                 .color(palette.text_primary)
-                .width(Length::FillPortion(column.portion))
+                .width(column.length())
                 .align_x(horizontal_alignment(column.alignment)),
         );
     }
 
-    container(cells)
-        .width(Length::Fill)
-        .padding([8, 12])
-        .style(move |_| project_row_style(palette, striped))
-        .into()
+    // Clicking a project opens its page.
+    mouse_area(
+        container(cells)
+            .width(Length::Fill)
+            .padding([8, 12])
+            .style(move |_| project_row_style(palette, striped)),
+    )
+    .on_press(Message::ProjectOpened(row.project))
+    .interaction(mouse::Interaction::Pointer)
+    .into()
 }
 
 fn horizontal_alignment(alignment: ColumnAlignment) -> Horizontal {
@@ -378,6 +777,112 @@ fn horizontal_alignment(alignment: ColumnAlignment) -> Horizontal {
     }
 }
 
+fn resize_handle(column: &TableColumn, palette: Palette) -> Element<'static, Message> {
+    mouse_area(
+        container(
+            Space::new()
+                .width(Length::Fixed(1.0))
+                .height(Length::Fixed(14.0)),
+        )
+        .padding([0, 3])
+        .style(move |_| container::Style {
+            background: Some(Background::Color(with_alpha(palette.border, 0.8))),
+            ..container::Style::default()
+        }),
+    )
+    .on_press(Message::ColumnResizeStarted(
+        column.label,
+        column.estimated_width(),
+    ))
+    .interaction(mouse::Interaction::ResizingHorizontally)
+    .into()
+}
+
+/// Lists the view's columns with show/hide toggles and ↑/↓ buttons to reorder them.
+fn column_menu(
+    view: ViewTab,
+    layout: &ColumnLayout,
+    palette: Palette,
+    i18n: &Catalog,
+) -> Element<'static, Message> {
+    let defaults = column_labels(view);
+    let labels = layout.ordered_labels(&defaults);
+    let last = labels.len().saturating_sub(1);
+    let mut items = column![text(i18n.tr("table-columns"))
+        .size(12)
+        .color(palette.text_secondary)]
+    .spacing(2);
+    for (idx, label) in labels.into_iter().enumerate() {
+        let shown = !layout.is_hidden(label);
+        let mut toggle = button(
+            row![
+                text(if shown { "☑" } else { "☐" }).size(14),
+                text(i18n.column(label)).size(14),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .padding([3, 6])
+        .style(move |_, status| ghost_button_style(palette, status));
+        if label != TITLE_COLUMN {
+            toggle = toggle.on_press(Message::ColumnVisibilityToggled(label));
+        }
+        let mut up = button(text("↑").size(13))
+            .padding([2, 6])
+            .style(move |_, status| ghost_button_style(palette, status));
+        if idx > 0 {
+            up = up.on_press(Message::ColumnMoved(label, -1));
+        }
+        let mut down = button(text("↓").size(13))
+            .padding([2, 6])
+            .style(move |_, status| ghost_button_style(palette, status));
+        if idx < last {
+            down = down.on_press(Message::ColumnMoved(label, 1));
+        }
+        items = items.push(row![toggle, up, down].spacing(2).align_y(Alignment::Center));
+    }
+    items = items.push(
+        button(text(i18n.tr("table-reset-layout")).size(13))
+            .on_press(Message::ColumnsReset)
+            .style(move |_, status| ghost_button_style(palette, status)),
+    );
+
+    row![
+        Space::new().width(Length::Fill),
+        container(items)
+            .width(Length::Fixed(COLUMN_MENU_PANEL_WIDTH))
+            .padding(8)
+            .style(move |_| column_menu_style(palette)),
+    ]
+    .into()
+}
+
+fn column_menu_style(palette: Palette) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(palette.surface_muted)),
+        border: Border {
+            color: with_alpha(palette.border, 0.9),
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        ..container::Style::default()
+    }
+}
+
+fn header_button_style(palette: Palette, status: button::Status) -> button::Style {
+    button::Style {
+        background: None,
+        border: Border::default(),
+        text_color: match status {
+            button::Status::Hovered | button::Status::Pressed => palette.text_primary,
+            _ => palette.text_secondary,
+        },
+        shadow: Shadow::default(),
+        ..button::Style::default()
+    }
+}
+
 fn table_header_style(palette: Palette) -> container::Style {
     container::Style {
         background: Some(Background::Color(with_alpha(palette.surface_muted, 0.6))),
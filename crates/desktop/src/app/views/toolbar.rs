@@ -1,32 +1,65 @@
-use iced::widget::{button, row, text, Space};
-use iced::{Alignment, Element, Length, Theme};
+use iced::widget::{button, row, text, text_input, Space};
+use iced::{Alignment, Element, Length};
 
 use crate::app::message::Message;
+use crate::app::shortcuts::ShortcutAction;
+use crate::app::state::{ToolbarItem, ViewTab};
+use crate::app::window_state::ViewMode;
 
-use super::styles::{ghost_button_style, primary_button_style};
+use super::styles::{focus_ring, ghost_button_style, primary_button_style, text_input_style};
 
 use super::super::desktop::CptDesktop;
 
+const SEARCH_WIDTH: f32 = 280.0;
+
 impl CptDesktop {
     pub(crate) fn toolbar(&self) -> Element<'_, Message> {
         let palette = self.palette;
-        let theme_label = match self.theme {
-            Theme::Dark => "Switch to light",
-            _ => "Switch to dark",
+        let i18n = &self.i18n;
+        let theme_label = i18n.tr_args(
+            "toolbar-theme",
+            &[(
+                "theme",
+                i18n.tr(&format!(
+                    "settings-appearance-{}",
+                    self.window_state.state().theme.name().to_ascii_lowercase()
+                )),
+            )],
+        );
+        // Hints follow the user's shortcut bindings; unbound actions show none.
+        let hint = |action: ShortcutAction| {
+            text(self.shortcuts.hint(action).unwrap_or_default())
+                .size(12)
+                .color(palette.text_muted)
+        };
+        let focused = |item: ToolbarItem| {
+            self.keyboard_focus
+                .is_some_and(|focus| focus.on_toolbar(item))
         };
 
         let capture_button = button(
-            row![text("Add Task").color(palette.primary_text).size(14)]
-                .spacing(8)
-                .align_y(Alignment::Center),
+            row![
+                text(i18n.tr("toolbar-add-task"))
+                    .color(palette.primary_text)
+                    .size(14),
+                text(
+                    self.shortcuts
+                        .hint(ShortcutAction::Capture)
+                        .unwrap_or_default()
+                )
+                .size(12)
+                .color(palette.primary_text),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
         )
         .on_press(Message::CaptureToggled)
         .style(move |_, status| primary_button_style(palette, status));
 
-        let palette_label = text("Command Palette")
+        let palette_label = text(i18n.tr("toolbar-command-palette"))
             .size(14)
             .color(palette.secondary_text);
-        let palette_hint = text("Cmd+K").size(12).color(palette.text_muted);
+        let palette_hint = hint(ShortcutAction::CommandPalette);
 
         let command_palette_button = button(
             row![palette_label, palette_hint]
@@ -36,21 +69,189 @@ impl CptDesktop {
         .on_press(Message::CommandPaletteToggled)
         .style(move |_, status| ghost_button_style(palette, status));
 
-        let mut bar = row![capture_button].spacing(16).align_y(Alignment::Center);
+        let search_placeholder = match self.shortcuts.hint(ShortcutAction::FocusSearch) {
+            Some(keys) => i18n.tr_args("toolbar-search-keys", &[("keys", keys)]),
+            None => i18n.tr("toolbar-search"),
+        };
+        let search_input = text_input(&search_placeholder, &self.search)
+            .id(self.search_input_id.clone())
+            .on_input(Message::SearchChanged)
+            .padding([6, 10])
+            .size(14)
+            .style(move |_, status| text_input_style(palette, status))
+            .width(Length::Fixed(SEARCH_WIDTH));
+        let mut search = row![search_input].spacing(8).align_y(Alignment::Center);
+        if let Some((matches, total)) = self.search_counts() {
+            search = search
+                .push(
+                    text(i18n.tr_args(
+                        "toolbar-search-matches",
+                        &[
+                            ("matches", matches.to_string()),
+                            ("total", total.to_string()),
+                        ],
+                    ))
+                    .size(12)
+                    .color(palette.text_muted),
+                )
+                .push(
+                    button(
+                        text(i18n.tr("toolbar-search-clear"))
+                            .size(12)
+                            .color(palette.secondary_text),
+                    )
+                    .on_press(Message::SearchCleared)
+                    .style(move |_, status| ghost_button_style(palette, status)),
+                );
+        }
+
+        let mut bar = row![
+            focus_ring(capture_button, focused(ToolbarItem::AddTask), palette),
+            focus_ring(search, focused(ToolbarItem::Search), palette)
+        ]
+        .spacing(16)
+        .align_y(Alignment::Center);
 
         bar = bar.push(Space::new().width(Length::Fill));
 
         if self.pending_mutations > 0 {
-            bar = bar.push(text("Applying changes…").size(14).color(palette.info));
+            bar = bar.push(
+                text(i18n.tr("toolbar-applying"))
+                    .size(14)
+                    .color(palette.info),
+            );
         }
 
+        let details_label = if self.detail_open {
+            i18n.tr("toolbar-hide-details")
+        } else {
+            i18n.tr("toolbar-show-details")
+        };
+        let details_button = button(
+            row![
+                text(details_label).size(14).color(palette.secondary_text),
+                hint(ShortcutAction::ToggleDetails),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        )
+        .on_press(Message::DetailToggled)
+        .style(move |_, status| ghost_button_style(palette, status));
+
+        bar = bar.push(focus_ring(
+            details_button,
+            focused(ToolbarItem::Details),
+            palette,
+        ));
+
+        let filters_label = if self.filters.is_empty() {
+            i18n.tr("toolbar-filters")
+        } else {
+            i18n.tr_args(
+                "toolbar-filters-count",
+                &[("count", self.filters.chips().len().to_string())],
+            )
+        };
+        let filters_button = button(
+            row![
+                text(filters_label).size(14).color(palette.secondary_text),
+                hint(ShortcutAction::ToggleFilters),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        )
+        .on_press(Message::FilterPanelToggled)
+        .style(move |_, status| ghost_button_style(palette, status));
+
+        bar = bar.push(focus_ring(
+            filters_button,
+            focused(ToolbarItem::Filters),
+            palette,
+        ));
+
         let theme_button = button(text(theme_label).size(14).color(palette.secondary_text))
             .on_press(Message::ToggleTheme)
             .style(move |_, status| ghost_button_style(palette, status));
 
-        bar = bar.push(theme_button);
+        bar = bar.push(focus_ring(
+            theme_button,
+            focused(ToolbarItem::Theme),
+            palette,
+        ));
+
+        // The calendar is only offered on the Scheduled view; other views draw it as a table.
+        let current_mode = match self.window_state.state().view_mode {
+            ViewMode::Calendar if self.active != ViewTab::Scheduled => ViewMode::Table,
+            mode => mode,
+        };
+        let view_modes = ViewMode::ALL
+            .iter()
+            .filter(|mode| **mode != ViewMode::Calendar || self.active == ViewTab::Scheduled)
+            .fold(row![].spacing(2), |modes, mode| {
+                let selected = *mode == current_mode;
+                modes.push(
+                    button(
+                        text(i18n.tr(&format!(
+                            "toolbar-mode-{}",
+                            mode.name().to_ascii_lowercase()
+                        )))
+                        .size(14)
+                        .color(if selected {
+                            palette.primary_text
+                        } else {
+                            palette.secondary_text
+                        }),
+                    )
+                    .on_press(Message::ViewModePicked(*mode))
+                    .style(move |_, status| {
+                        if selected {
+                            primary_button_style(palette, status)
+                        } else {
+                            ghost_button_style(palette, status)
+                        }
+                    }),
+                )
+            });
+
+        bar = bar.push(view_modes);
+
+        let settings_button = button(
+            row![
+                text(i18n.tr("toolbar-settings"))
+                    .size(14)
+                    .color(palette.secondary_text),
+                hint(ShortcutAction::Settings),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        )
+        .on_press(Message::SettingsToggled)
+        .style(move |_, status| ghost_button_style(palette, status));
+
+        let export_button = button(
+            text(i18n.tr("toolbar-export"))
+                .size(14)
+                .color(palette.secondary_text),
+        )
+        .on_press(Message::ExportRequested)
+        .style(move |_, status| ghost_button_style(palette, status));
+
+        bar = bar.push(focus_ring(
+            export_button,
+            focused(ToolbarItem::Export),
+            palette,
+        ));
+        bar = bar.push(focus_ring(
+            settings_button,
+            focused(ToolbarItem::Settings),
+            palette,
+        ));
 
-        bar = bar.push(command_palette_button);
+        bar = bar.push(focus_ring(
+            command_palette_button,
+            focused(ToolbarItem::CommandPalette),
+            palette,
+        ));
 
         bar.into()
     }
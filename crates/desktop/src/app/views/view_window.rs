@@ -0,0 +1,108 @@
+use iced::widget::{button, column, container, row, scrollable, text};
+use iced::{window, Alignment, Background, Element, Length};
+
+use crate::app::helpers::format_datetime;
+use crate::app::message::Message;
+use crate::app::state::{CommandActionId, LoadState, ViewTab, ViewWindow};
+
+use super::super::desktop::CptDesktop;
+use super::styles::{ghost_button_style, labelled, primary_button_style};
+
+impl CptDesktop {
+    /// A further window: its own pick of view above a plain list of that view's tasks. A row
+    /// completes its task or shows it in the main window, where the rest of the editing is.
+    pub(crate) fn view_window_view(&self, id: window::Id) -> Option<Element<'_, Message>> {
+        let shown = self.view_window(id)?.tab;
+        let palette = self.palette;
+
+        let tabs = ViewTab::ALL
+            .iter()
+            .copied()
+            .filter(|tab| ViewWindow::offers(*tab))
+            .fold(row![].spacing(4), |tabs, tab| {
+                let label = text(self.i18n.view_title(tab)).size(13);
+                let entry = if tab == shown {
+                    button(label.color(palette.primary_text))
+                        .style(move |_, status| primary_button_style(palette, status))
+                } else {
+                    button(label.color(palette.secondary_text))
+                        .style(move |_, status| ghost_button_style(palette, status))
+                };
+                tabs.push(entry.on_press(Message::ViewWindowTabPicked(id, tab)))
+            });
+
+        let store = self.views.get(&shown);
+        let body: Element<'_, Message> = match store.and_then(|view| view.snapshot.as_ref()) {
+            Some(snapshot) if !snapshot.tasks.is_empty() => {
+                let rows = snapshot
+                    .tasks
+                    .iter()
+                    .fold(column![].spacing(2), |rows, task| {
+                        let mut meta = Vec::new();
+                        if let Some(project) = &task.project {
+                            meta.push(format!("+{project}"));
+                        }
+                        if let Some(due) = task.due_at {
+                            meta.push(
+                                self.i18n
+                                    .tr_args("window-due", &[("date", format_datetime(due))]),
+                            );
+                        }
+                        let mut details = column![text(task.title.clone())
+                            .size(14)
+                            .color(palette.text_primary)]
+                        .spacing(2);
+                        if !meta.is_empty() {
+                            details = details
+                                .push(text(meta.join("  ")).size(12).color(palette.text_muted));
+                        }
+                        let mut line = row![].spacing(6).align_y(Alignment::Center);
+                        if shown != ViewTab::Done {
+                            line = line.push(labelled(
+                                button(text("✓").size(14).color(palette.secondary_text))
+                                    .padding([2, 6])
+                                    .on_press(Message::ViewWindowTaskDone(task.id.clone()))
+                                    .style(move |_, status| ghost_button_style(palette, status)),
+                                self.i18n.action_label(CommandActionId::MarkDone),
+                                palette,
+                            ));
+                        }
+                        rows.push(
+                            line.push(
+                                button(details)
+                                    .width(Length::Fill)
+                                    .on_press(Message::ViewWindowTaskShown(task.id.clone()))
+                                    .style(move |_, status| ghost_button_style(palette, status)),
+                            ),
+                        )
+                    });
+                scrollable(rows).height(Length::Fill).into()
+            }
+            Some(_) => text(self.i18n.tr("window-empty"))
+                .size(14)
+                .color(palette.text_muted)
+                .into(),
+            None => match store.map(|view| &view.state) {
+                Some(LoadState::Error(err)) => {
+                    text(err.clone()).size(14).color(palette.danger).into()
+                }
+                _ => text(self.i18n.tr("window-loading"))
+                    .size(14)
+                    .color(palette.text_muted)
+                    .into(),
+            },
+        };
+
+        Some(
+            container(column![tabs, body].spacing(16).height(Length::Fill))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding([16, 20])
+                .style(move |_| container::Style {
+                    background: Some(Background::Color(palette.background)),
+                    ..container::Style::default()
+                })
+                .into(),
+        )
+    }
+}
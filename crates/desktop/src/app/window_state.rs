@@ -0,0 +1,243 @@
+//! Main window geometry, last active view, and theme settings saved beside the database so the next
+//! launch picks up where the previous one left off.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use iced::{Point, Size, Theme};
+use serde::{Deserialize, Serialize};
+
+use crate::app::calendar::CalendarSpan;
+use crate::app::helpers::detect_theme;
+use crate::app::state::ViewTab;
+use crate::telemetry::Sink as TelemetrySink;
+
+pub(crate) const WINDOW_STATE_FILE: &str = "desktop-window.json";
+/// Bounds and keyboard step for the UI zoom, as a multiple of the OS scale factor.
+pub(crate) const MIN_ZOOM: f32 = 0.75;
+pub(crate) const MAX_ZOOM: f32 = 2.0;
+pub(crate) const ZOOM_STEP: f32 = 0.1;
+/// Auto-refresh intervals offered in Settings, in seconds.
+pub(crate) const REFRESH_INTERVALS: [u64; 5] = [2, 5, 10, 30, 60];
+
+/// The theme picked with the toolbar button; `System` follows the OS appearance as it changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ThemeChoice {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemeChoice {
+    pub(crate) const ALL: [ThemeChoice; 3] =
+        [ThemeChoice::System, ThemeChoice::Light, ThemeChoice::Dark];
+
+    /// The choice after this one when the toolbar button is pressed.
+    pub(crate) fn next(self) -> Self {
+        match self {
+            ThemeChoice::System => ThemeChoice::Light,
+            ThemeChoice::Light => ThemeChoice::Dark,
+            ThemeChoice::Dark => ThemeChoice::System,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            ThemeChoice::System => "System",
+            ThemeChoice::Light => "Light",
+            ThemeChoice::Dark => "Dark",
+        }
+    }
+
+    /// The theme to draw with; `System` asks the OS.
+    pub(crate) fn resolve(self) -> Theme {
+        match self {
+            ThemeChoice::System => detect_theme(),
+            ThemeChoice::Light => Theme::Light,
+            ThemeChoice::Dark => Theme::Dark,
+        }
+    }
+}
+
+/// How task views are drawn: the sortable table, status columns of cards, or (Scheduled only) a
+/// calendar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ViewMode {
+    #[default]
+    Table,
+    Board,
+    Calendar,
+}
+
+impl ViewMode {
+    pub(crate) const ALL: [ViewMode; 3] = [ViewMode::Table, ViewMode::Board, ViewMode::Calendar];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            ViewMode::Table => "Table",
+            ViewMode::Board => "Board",
+            ViewMode::Calendar => "Calendar",
+        }
+    }
+}
+
+/// A geometry event reported by the main window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum WindowChange {
+    Resized(Size),
+    Moved(Point),
+}
+
+/// What gets written to the file; `width`/`height` and `x`/`y` describe the un-maximized window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct WindowState {
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) x: Option<f32>,
+    pub(crate) y: Option<f32>,
+    pub(crate) maximized: bool,
+    pub(crate) view: Option<String>,
+    pub(crate) theme: ThemeChoice,
+    /// Name of the picked color theme; `None` is the built-in cpt.run palette.
+    pub(crate) color_theme: Option<String>,
+    /// Scales text and spacing in every window.
+    pub(crate) zoom: f32,
+    pub(crate) view_mode: ViewMode,
+    pub(crate) calendar_span: CalendarSpan,
+    /// Picked UI language code; `None` follows the system language.
+    pub(crate) locale: Option<String>,
+    /// Keeps status text from changing or disappearing on its own.
+    pub(crate) reduce_motion: bool,
+    pub(crate) telemetry: TelemetrySink,
+    /// Command that edits notes, given the file as its last argument; `None` opens the file in
+    /// the system's default app.
+    pub(crate) external_editor: Option<String>,
+    /// Seconds between view refreshes; `None` keeps the interval given at launch.
+    pub(crate) refresh_interval: Option<u64>,
+    /// Slots of the saved views pinned to the sidebar, in sidebar order.
+    pub(crate) pinned_views: Vec<u8>,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self {
+            width: 1140.0,
+            height: 780.0,
+            x: None,
+            y: None,
+            maximized: true,
+            view: None,
+            theme: ThemeChoice::System,
+            color_theme: None,
+            zoom: 1.0,
+            view_mode: ViewMode::Table,
+            calendar_span: CalendarSpan::Month,
+            locale: None,
+            reduce_motion: false,
+            telemetry: TelemetrySink::default(),
+            external_editor: None,
+            refresh_interval: None,
+            pinned_views: Vec::new(),
+        }
+    }
+}
+
+impl WindowState {
+    pub(crate) fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    pub(crate) fn position(&self) -> Option<Point> {
+        Some(Point::new(self.x?, self.y?))
+    }
+
+    /// Clamps `zoom` to the supported range, rounded to whole percent.
+    pub(crate) fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = ((zoom * 100.0).round() / 100.0).clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    /// The saved view, if it still names one of the tabs.
+    pub(crate) fn active_view(&self) -> Option<ViewTab> {
+        let saved = self.view.as_deref()?;
+        ViewTab::ALL
+            .iter()
+            .copied()
+            .find(|tab| tab.title() == saved)
+    }
+
+    /// Records a resize or move; the geometry of a maximized window is not kept so that
+    /// un-maximizing next time returns to the previous size and place.
+    pub(crate) fn apply(&mut self, change: WindowChange, maximized: bool) {
+        self.maximized = maximized;
+        if maximized {
+            return;
+        }
+        match change {
+            WindowChange::Resized(size) => {
+                self.width = size.width;
+                self.height = size.height;
+            }
+            WindowChange::Moved(position) => {
+                self.x = Some(position.x);
+                self.y = Some(position.y);
+            }
+        }
+    }
+}
+
+/// The saved state plus the file it persists to. Changes are only marked here and written by
+/// `save` so a resize drag does not rewrite the file on every frame.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WindowStateStore {
+    state: WindowState,
+    path: Option<PathBuf>,
+    dirty: bool,
+}
+
+impl WindowStateStore {
+    /// Reads the state file; a missing file starts from the defaults.
+    pub(crate) fn load(path: PathBuf) -> Result<Self> {
+        let state = if path.exists() {
+            let raw = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            serde_json::from_str(&raw)
+                .with_context(|| format!("invalid window state in {}", path.display()))?
+        } else {
+            WindowState::default()
+        };
+        Ok(Self {
+            state,
+            path: Some(path),
+            dirty: false,
+        })
+    }
+
+    pub(crate) fn state(&self) -> &WindowState {
+        &self.state
+    }
+
+    pub(crate) fn update(&mut self, change: impl FnOnce(&mut WindowState)) {
+        let before = self.state.clone();
+        change(&mut self.state);
+        self.dirty |= self.state != before;
+    }
+
+    /// Writes the file if anything changed since the last save.
+    pub(crate) fn save(&mut self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+        let contents = serde_json::to_string_pretty(&self.state)?;
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+        self.dirty = false;
+        Ok(())
+    }
+}
@@ -3,4 +3,4 @@
 mod app;
 mod telemetry;
 
-pub use app::{run, DesktopOptions};
+pub use app::{register_url_scheme, run, DesktopOptions};
@@ -1,8 +1,25 @@
 //! Collects lightweight desktop telemetry so product tweaks can be validated during prototyping.
+//! Events never leave the machine: a short history is kept in memory for the Settings event list,
+//! and the chosen sink can also append them to a JSONL file in the data directory or to stdout.
 
+#[cfg(feature = "telemetry")]
+use std::collections::VecDeque;
+#[cfg(feature = "telemetry")]
+use std::fs::OpenOptions;
+#[cfg(feature = "telemetry")]
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
 use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+pub const TELEMETRY_FILE: &str = "desktop-telemetry.jsonl";
+/// Events kept for the Settings event list.
+pub const RECENT_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
 pub enum Event {
     AppStarted,
     ViewChanged(String),
@@ -15,22 +32,158 @@ pub enum Event {
     MutationFailed { action: String, error: String },
 }
 
+impl Event {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::AppStarted => "app_started",
+            Event::ViewChanged(_) => "view_changed",
+            Event::RefreshRequested(_) => "refresh_requested",
+            Event::RefreshCompleted { .. } => "refresh_completed",
+            Event::RefreshFailed { .. } => "refresh_failed",
+            Event::CaptureStarted => "capture_started",
+            Event::CaptureFinished(_) => "capture_finished",
+            Event::MutationApplied(_) => "mutation_applied",
+            Event::MutationFailed { .. } => "mutation_failed",
+        }
+    }
+
+    /// The event's payload in a line of text, for the event list.
+    pub fn details(&self) -> String {
+        match self {
+            Event::AppStarted | Event::CaptureStarted => String::new(),
+            Event::ViewChanged(view) | Event::RefreshRequested(view) => view.clone(),
+            Event::RefreshCompleted { view, count } => format!("{view}: {count} tasks"),
+            Event::RefreshFailed { view, error } => format!("{view}: {error}"),
+            Event::CaptureFinished(id) => id.clone(),
+            Event::MutationApplied(action) => action.clone(),
+            Event::MutationFailed { action, error } => format!("{action}: {error}"),
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Event::RefreshFailed { .. } | Event::MutationFailed { .. }
+        )
+    }
+}
+
+/// Where recorded events go besides the in-memory history; `Disabled` records nothing at all.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sink {
+    #[default]
+    Memory,
+    File,
+    Stdout,
+    Disabled,
+}
+
+impl Sink {
+    pub const ALL: [Sink; 4] = [Sink::Memory, Sink::File, Sink::Stdout, Sink::Disabled];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Sink::Memory => "memory",
+            Sink::File => "file",
+            Sink::Stdout => "stdout",
+            Sink::Disabled => "disabled",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub at: DateTime<Local>,
+    pub event: Event,
+}
+
+#[cfg(feature = "telemetry")]
+struct State {
+    sink: Sink,
+    file: Option<PathBuf>,
+    recent: VecDeque<Recorded>,
+}
+
 pub struct Handle {
     #[cfg(feature = "telemetry")]
-    events: Mutex<Vec<Event>>,
+    state: Mutex<State>,
 }
 
 impl Handle {
     pub fn new() -> Self {
         Self {
             #[cfg(feature = "telemetry")]
-            events: Mutex::new(Vec::new()),
+            state: Mutex::new(State {
+                sink: Sink::default(),
+                file: None,
+                recent: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Switches the sink; the file sink writes to [`TELEMETRY_FILE`] in `data_dir`. Disabling
+    /// also forgets the events already kept in memory.
+    pub fn configure(&self, sink: Sink, data_dir: Option<&Path>) {
+        #[cfg(feature = "telemetry")]
+        {
+            let mut state = self.state.lock();
+            state.sink = sink;
+            if let Some(dir) = data_dir {
+                state.file = Some(dir.join(TELEMETRY_FILE));
+            }
+            if sink == Sink::Disabled {
+                state.recent.clear();
+            }
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            let _ = (sink, data_dir);
+        }
+    }
+
+    pub fn sink(&self) -> Sink {
+        #[cfg(feature = "telemetry")]
+        {
+            self.state.lock().sink
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            Sink::Disabled
+        }
+    }
+
+    /// Where the file sink writes, once a data directory is known.
+    pub fn file(&self) -> Option<PathBuf> {
+        #[cfg(feature = "telemetry")]
+        {
+            self.state.lock().file.clone()
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            None
+        }
+    }
+
+    /// Recorded events, newest first.
+    pub fn recent(&self) -> Vec<Recorded> {
+        #[cfg(feature = "telemetry")]
+        {
+            self.state.lock().recent.iter().rev().cloned().collect()
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            Vec::new()
         }
     }
 
     pub fn record(&self, event: Event) {
         #[cfg(feature = "telemetry")]
         {
+            let mut state = self.state.lock();
+            if state.sink == Sink::Disabled {
+                return;
+            }
             match &event {
                 Event::RefreshCompleted { view, count } => {
                     tracing::debug!(
@@ -63,7 +216,25 @@ impl Handle {
                     "desktop telemetry mutation failed"
                 ),
             }
-            self.events.lock().push(event);
+            let recorded = Recorded {
+                at: Local::now(),
+                event,
+            };
+            match state.sink {
+                Sink::File => {
+                    if let Some(path) = &state.file {
+                        if let Err(err) = append_line(path, &recorded) {
+                            tracing::warn!(error = %err, "failed to write desktop telemetry");
+                        }
+                    }
+                }
+                Sink::Stdout => println!("{}", json_line(&recorded)),
+                Sink::Memory | Sink::Disabled => {}
+            }
+            if state.recent.len() == RECENT_LIMIT {
+                state.recent.pop_front();
+            }
+            state.recent.push_back(recorded);
         }
         #[cfg(not(feature = "telemetry"))]
         {
@@ -78,15 +249,24 @@ impl Handle {
 
     #[cfg(test)]
     pub(crate) fn events_len(&self) -> usize {
-        #[cfg(feature = "telemetry")]
-        {
-            self.events.lock().len()
-        }
-        #[cfg(not(feature = "telemetry"))]
-        {
-            0
-        }
+        self.recent().len()
+    }
+}
+
+/// One JSONL record: the event's `event` and `data` fields plus an RFC 3339 `at` timestamp.
+#[cfg(feature = "telemetry")]
+fn json_line(recorded: &Recorded) -> String {
+    let mut line = serde_json::to_value(&recorded.event).unwrap_or_default();
+    if let serde_json::Value::Object(fields) = &mut line {
+        fields.insert("at".into(), recorded.at.to_rfc3339().into());
     }
+    line.to_string()
+}
+
+#[cfg(feature = "telemetry")]
+fn append_line(path: &Path, recorded: &Recorded) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", json_line(recorded))
 }
 
 #[cfg(test)]
@@ -106,4 +286,47 @@ mod tests {
             assert_eq!(handle.events_len(), 0);
         }
     }
+
+    #[cfg(feature = "telemetry")]
+    #[test]
+    fn file_sink_appends_json_lines_until_disabled() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(TELEMETRY_FILE);
+        let handle = Handle::new();
+        handle.configure(Sink::File, Some(dir.path()));
+        handle.record(Event::ViewChanged("Next".into()));
+        handle.record(Event::RefreshFailed {
+            view: "Next".into(),
+            error: "database is locked".into(),
+        });
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = written
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["event"], "view_changed");
+        assert_eq!(lines[0]["data"], "Next");
+        assert_eq!(lines[1]["data"]["error"], "database is locked");
+        assert!(lines[1]["at"].is_string());
+        assert!(handle.recent()[0].event.is_failure(), "newest first");
+
+        handle.configure(Sink::Disabled, None);
+        handle.record(Event::AppStarted);
+        assert!(handle.recent().is_empty());
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+    }
+
+    #[cfg(feature = "telemetry")]
+    #[test]
+    fn keeps_only_the_latest_events() {
+        let handle = Handle::new();
+        for index in 0..RECENT_LIMIT + 5 {
+            handle.record(Event::CaptureFinished(index.to_string()));
+        }
+        let recent = handle.recent();
+        assert_eq!(recent.len(), RECENT_LIMIT);
+        assert_eq!(recent[0].event.details(), (RECENT_LIMIT + 4).to_string());
+    }
 }
@@ -0,0 +1,24 @@
+//! MCP server that lets AI assistants read and change cpt.run tasks, built on the official Rust
+//! SDK and speaking JSON-RPC over stdin/stdout.
+
+mod params;
+mod search;
+mod server;
+
+use anyhow::Result;
+use cpt_core::{AppConfig, TasksService};
+use rmcp::transport::stdio;
+use rmcp::ServiceExt;
+
+pub use server::CptMcp;
+
+/// Serves the tools on stdin/stdout until the client disconnects.
+pub fn run(config: AppConfig) -> Result<()> {
+    let service = TasksService::new(config)?;
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async move {
+        let running = CptMcp::new(service).serve(stdio()).await?;
+        running.waiting().await?;
+        Ok(())
+    })
+}
@@ -0,0 +1,328 @@
+//! Tool arguments as assistants send them, with the checks that turn them into core types.
+
+use chrono::{DateTime, Duration, Local, Utc};
+use cpt_core::model::{
+    BatchAction, BatchOperation, EnergyLevel, ListFilters, ListView, TaskChanges, TaskStatus,
+};
+use cpt_core::parser::parse_date_spec;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Rows a list returns when the assistant does not ask for a number.
+const DEFAULT_LIMIT: usize = 50;
+/// Days of completion history `get_stats` covers when the assistant does not say.
+const DEFAULT_STATS_DAYS: usize = 7;
+/// Longest completion history `get_stats` returns.
+const MAX_STATS_DAYS: usize = 366;
+/// Days without activity after which an open project is due for review: one weekly review.
+const DEFAULT_REVIEW_DAYS: u32 = 7;
+
+/// A task list as the apps show it.
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum View {
+    Today,
+    Inbox,
+    Next,
+    Waiting,
+    Scheduled,
+    Someday,
+    Done,
+}
+
+impl From<View> for ListView {
+    fn from(view: View) -> Self {
+        match view {
+            View::Today => ListView::Today,
+            View::Inbox => ListView::Inbox,
+            View::Next => ListView::Next,
+            View::Waiting => ListView::Waiting,
+            View::Scheduled => ListView::Scheduled,
+            View::Someday => ListView::Someday,
+            View::Done => ListView::Done,
+        }
+    }
+}
+
+/// Where a task stands, finished ones included.
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Status {
+    Inbox,
+    Next,
+    Waiting,
+    Scheduled,
+    Someday,
+    Done,
+    Canceled,
+}
+
+impl From<Status> for TaskStatus {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Inbox => TaskStatus::Inbox,
+            Status::Next => TaskStatus::Next,
+            Status::Waiting => TaskStatus::Waiting,
+            Status::Scheduled => TaskStatus::Scheduled,
+            Status::Someday => TaskStatus::Someday,
+            Status::Done => TaskStatus::Done,
+            Status::Canceled => TaskStatus::Canceled,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct ListTasksParams {
+    /// List to read; every open task when left out.
+    pub view: Option<View>,
+    /// Only tasks in this project, with or without the `+`.
+    pub project: Option<String>,
+    /// Only tasks with this context, with or without the `@`.
+    pub context: Option<String>,
+    /// Only tasks with this tag, with or without the `#`.
+    pub tag: Option<String>,
+    /// Most tasks to return (default 50).
+    pub limit: Option<usize>,
+}
+
+impl ListTasksParams {
+    pub(crate) fn filters(&self) -> ListFilters {
+        let mut filters = ListFilters::for_view(self.view.map(ListView::from));
+        filters.project = self.project.as_deref().map(|project| bare(project, '+'));
+        filters.contexts = self
+            .context
+            .iter()
+            .map(|context| bare(context, '@'))
+            .collect();
+        filters.tags = self.tag.iter().map(|tag| bare(tag, '#')).collect();
+        filters.limit = Some(self.limit.unwrap_or(DEFAULT_LIMIT));
+        filters
+    }
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct SearchTasksParams {
+    /// Words that must all appear, in any case, in the title or the notes.
+    pub query: String,
+    /// List to search; every open task when left out.
+    pub view: Option<View>,
+    /// Only tasks with this status; `done` or `canceled` searches finished tasks.
+    pub status: Option<Status>,
+    /// Most matches to return (default 50).
+    pub limit: Option<usize>,
+}
+
+impl SearchTasksParams {
+    /// Every task the query matches; ranking and the limit apply afterwards.
+    pub(crate) fn filters(&self) -> Result<ListFilters, String> {
+        let query = self.query.trim();
+        if query.is_empty() {
+            return Err("Search query cannot be empty".to_string());
+        }
+        let mut filters = ListFilters::for_view(self.view.map(ListView::from));
+        if let Some(status) = self.status {
+            filters.status = Some(status.into());
+        }
+        filters.text = Some(query.to_string());
+        Ok(filters)
+    }
+
+    pub(crate) fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct UpdateTaskParams {
+    /// Id of the task to change.
+    pub id: String,
+    pub title: Option<String>,
+    /// Replaces the notes; an empty string clears them.
+    pub notes: Option<String>,
+    /// Project name, with or without the `+`; an empty string clears it.
+    pub project: Option<String>,
+    /// Replaces the contexts, such as `office` or `@office`.
+    pub contexts: Option<Vec<String>>,
+    /// Replaces the tags, such as `legal` or `#legal`.
+    pub tags: Option<Vec<String>>,
+    /// 0 (none) to 3 (highest).
+    pub priority: Option<u8>,
+    /// `low`, `med`, or `high`; an empty string clears it.
+    pub energy: Option<String>,
+    /// Estimate in minutes; 0 clears it.
+    pub time_estimate: Option<u32>,
+    /// Date such as `tomorrow`, `fri`, or `2026-11-02 17:00`; an empty string clears it.
+    pub due: Option<String>,
+    /// Date the task stays hidden until, written like `due`; an empty string clears it.
+    pub defer: Option<String>,
+    /// Who the task waits on, which moves it to Waiting; an empty string moves it back to Next.
+    pub waiting_on: Option<String>,
+}
+
+impl UpdateTaskParams {
+    pub(crate) fn changes(&self) -> Result<TaskChanges, String> {
+        Ok(TaskChanges {
+            title: self.title.clone(),
+            notes: self.notes.clone(),
+            project: clearable(&self.project).map(|project| project.map(|p| bare(&p, '+'))),
+            contexts: self.contexts.as_ref().map(|list| bare_all(list, '@')),
+            tags: self.tags.as_ref().map(|list| bare_all(list, '#')),
+            priority: self.priority,
+            energy: clearable(&self.energy)
+                .map(|energy| energy.map(|label| label.parse::<EnergyLevel>()).transpose())
+                .transpose()
+                .map_err(|err| err.to_string())?,
+            time_estimate: self.time_estimate.map(Some),
+            due_at: date(&self.due)?,
+            defer_until: date(&self.defer)?,
+            waiting_on: clearable(&self.waiting_on),
+        })
+    }
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct GetProjectsParams {
+    /// Only projects with open tasks but no Next action.
+    pub stalled_only: Option<bool>,
+    /// Days without a change to any task after which an open project counts as overdue for
+    /// review (default 7).
+    pub review_days: Option<u32>,
+}
+
+impl GetProjectsParams {
+    /// Projects whose newest task change is older than this are overdue for review.
+    pub(crate) fn review_cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - Duration::days(self.review_days.unwrap_or(DEFAULT_REVIEW_DAYS).into())
+    }
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct GetStatsParams {
+    /// Days of completion history ending today (default 7, at most 366).
+    pub days: Option<usize>,
+}
+
+impl GetStatsParams {
+    pub(crate) fn days(&self) -> usize {
+        self.days
+            .unwrap_or(DEFAULT_STATS_DAYS)
+            .clamp(1, MAX_STATS_DAYS)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct AppendNoteParams {
+    /// Id of the task to add to.
+    pub id: String,
+    /// What to log, such as "Sam says the contract is with legal".
+    pub text: String,
+    /// Steps to add as unchecked Markdown checklist items under the text.
+    pub checklist: Option<Vec<String>>,
+}
+
+impl AppendNoteParams {
+    /// The block to append: `now`, the text, then one `- [ ]` line per step.
+    pub(crate) fn entry(&self, now: DateTime<Local>) -> Result<String, String> {
+        let steps: Vec<&str> = self
+            .checklist
+            .iter()
+            .flatten()
+            .map(|step| step.trim())
+            .filter(|step| !step.is_empty())
+            .collect();
+        let text = self.text.trim();
+        if text.is_empty() && steps.is_empty() {
+            return Err("Nothing to append: give some text or checklist items".to_string());
+        }
+        let mut entry = now.format("%a %-d %b %Y %H:%M").to_string();
+        if !text.is_empty() {
+            entry.push_str(": ");
+            entry.push_str(text);
+        }
+        for step in steps {
+            entry.push_str("\n- [ ] ");
+            entry.push_str(step);
+        }
+        Ok(entry)
+    }
+}
+
+/// What `bulk_update` does to the tasks of one operation.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Action {
+    Done,
+    Next,
+    Inbox,
+    Someday,
+    Cancel,
+    Defer,
+    Delete,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct BulkOperation {
+    /// Ids of the tasks to act on.
+    pub ids: Vec<String>,
+    pub action: Action,
+    /// For `defer`: when the tasks show up again, written like `due` in `update_task`.
+    pub until: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct BulkUpdateParams {
+    /// Operations to run in order, all in one transaction.
+    pub operations: Vec<BulkOperation>,
+}
+
+impl BulkUpdateParams {
+    /// Checks every operation up front, so a bad one rejects the batch before anything is written.
+    pub(crate) fn operations(&self) -> Result<Vec<BatchOperation>, String> {
+        self.operations
+            .iter()
+            .map(|operation| {
+                let action = match operation.action {
+                    Action::Done => BatchAction::Status(TaskStatus::Done),
+                    Action::Next => BatchAction::Status(TaskStatus::Next),
+                    Action::Inbox => BatchAction::Status(TaskStatus::Inbox),
+                    Action::Someday => BatchAction::Status(TaskStatus::Someday),
+                    Action::Cancel => BatchAction::Status(TaskStatus::Canceled),
+                    Action::Delete => BatchAction::Delete,
+                    Action::Defer => match date(&operation.until)?.flatten() {
+                        Some(until) => BatchAction::Defer(until),
+                        None => return Err("A defer operation needs an `until` date".to_string()),
+                    },
+                };
+                Ok(BatchOperation {
+                    ids: operation.ids.clone(),
+                    action,
+                })
+            })
+            .collect()
+    }
+}
+
+/// `value` without the capture sigil an assistant may have copied along, as in `@office`.
+fn bare(value: &str, sigil: char) -> String {
+    value.trim().trim_start_matches(sigil).to_string()
+}
+
+fn bare_all(values: &[String], sigil: char) -> Vec<String> {
+    values.iter().map(|value| bare(value, sigil)).collect()
+}
+
+/// A text field where an empty string means "clear it".
+fn clearable(value: &Option<String>) -> Option<Option<String>> {
+    value.as_ref().map(|value| {
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+/// A date field, parsed like capture tokens, where an empty string means "clear it".
+fn date(spec: &Option<String>) -> Result<Option<Option<DateTime<Utc>>>, String> {
+    clearable(spec)
+        .map(|spec| spec.map(|spec| parse_date_spec(&spec)).transpose())
+        .transpose()
+        .map_err(|err| err.to_string())
+}
@@ -0,0 +1,74 @@
+//! Ranking and snippets for `search_tasks`, applied to the rows the text filter already matched.
+
+use cpt_core::Task;
+use serde::Serialize;
+
+/// Longest snippet returned, in characters.
+const SNIPPET_CHARS: usize = 120;
+/// Characters kept before the matched word when a long line is cut.
+const SNIPPET_LEAD: usize = 40;
+
+/// One search hit: the task plus the notes line that matched, if the notes did.
+#[derive(Debug, Serialize)]
+pub(crate) struct SearchMatch {
+    pub task: Task,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+/// Orders `tasks` best match first: words found in the title outweigh words only in the notes,
+/// and the whole query in the title beats both. Ties keep the order the view sorted them in.
+pub(crate) fn rank(tasks: Vec<Task>, query: &str) -> Vec<SearchMatch> {
+    let query = query.trim().to_ascii_lowercase();
+    let words: Vec<&str> = query.split_whitespace().collect();
+    let mut scored: Vec<(usize, SearchMatch)> = tasks
+        .into_iter()
+        .map(|task| {
+            let title = task.title.to_ascii_lowercase();
+            let notes = task.notes.as_deref().unwrap_or("").to_ascii_lowercase();
+            let mut score = words
+                .iter()
+                .map(|word| {
+                    if title.contains(word) {
+                        2
+                    } else if notes.contains(word) {
+                        1
+                    } else {
+                        0
+                    }
+                })
+                .sum::<usize>();
+            if words.len() > 1 && title.contains(query.as_str()) {
+                score += words.len();
+            }
+            let snippet = task
+                .notes
+                .as_deref()
+                .and_then(|notes| snippet(notes, &words));
+            (score, SearchMatch { task, snippet })
+        })
+        .collect();
+    scored.sort_by(|(a, _), (b, _)| b.cmp(a));
+    scored.into_iter().map(|(_, found)| found).collect()
+}
+
+/// The first notes line holding one of `words`, cut around the word when the line is long.
+fn snippet(notes: &str, words: &[&str]) -> Option<String> {
+    notes.lines().map(str::trim).find_map(|line| {
+        // ASCII lowercasing keeps byte offsets, so `at` indexes `line` too.
+        let lower = line.to_ascii_lowercase();
+        let at = words.iter().filter_map(|word| lower.find(word)).min()?;
+        if line.chars().count() <= SNIPPET_CHARS {
+            return Some(line.to_string());
+        }
+        let start_char = line[..at].chars().count().saturating_sub(SNIPPET_LEAD);
+        let mut cut: String = line.chars().skip(start_char).take(SNIPPET_CHARS).collect();
+        if start_char > 0 {
+            cut.insert(0, '…');
+        }
+        if start_char + SNIPPET_CHARS < line.chars().count() {
+            cut.push('…');
+        }
+        Some(cut)
+    })
+}
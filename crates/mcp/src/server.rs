@@ -0,0 +1,423 @@
+//! The tools, each a thin layer over one `TasksService` call.
+
+use chrono::{Duration, Local, Utc};
+use cpt_core::TasksService;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use rmcp::{tool, tool_handler, tool_router, ServerHandler};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::params::{
+    AppendNoteParams, BulkUpdateParams, GetProjectsParams, GetStatsParams, ListTasksParams,
+    SearchTasksParams, UpdateTaskParams,
+};
+use crate::search;
+
+/// What a tool answers: structured JSON, or an error message the assistant can act on.
+type ToolResult = Result<CallToolResult, String>;
+
+/// MCP server over one cpt.run database.
+#[derive(Debug, Clone)]
+pub struct CptMcp {
+    service: TasksService,
+}
+
+impl CptMcp {
+    pub fn new(service: TasksService) -> Self {
+        Self { service }
+    }
+}
+
+#[tool_router]
+impl CptMcp {
+    #[tool(
+        description = "List tasks in a view (today, inbox, next, waiting, scheduled, someday, done), optionally narrowed to a project, context, or tag. Returns the tasks and how many match in all."
+    )]
+    fn list_tasks(&self, Parameters(params): Parameters<ListTasksParams>) -> ToolResult {
+        let filters = params.filters();
+        let total = self.service.count(&filters).map_err(failed)?;
+        let snapshot = self.service.list(&filters).map_err(failed)?;
+        reply(&json!({ "tasks": snapshot.tasks, "total": total }))
+    }
+
+    #[tool(
+        description = "Find tasks whose title or notes contain every word of a free-text query, optionally within a view or status. Returns the best matches first, each with the notes line that matched, and how many match in all."
+    )]
+    fn search_tasks(&self, Parameters(params): Parameters<SearchTasksParams>) -> ToolResult {
+        let filters = params.filters()?;
+        let snapshot = self.service.list(&filters).map_err(failed)?;
+        let total = snapshot.tasks.len();
+        let mut matches = search::rank(snapshot.tasks, &params.query);
+        matches.truncate(params.limit());
+        reply(&json!({ "matches": matches, "total": total }))
+    }
+
+    #[tool(
+        description = "Change any subset of a task's fields (title, notes, project, contexts, tags, priority, energy, time estimate, due, defer, waiting on) without recapturing it. Returns the updated task."
+    )]
+    fn update_task(&self, Parameters(params): Parameters<UpdateTaskParams>) -> ToolResult {
+        let changes = params.changes()?;
+        match self
+            .service
+            .update_task(&params.id, &changes)
+            .map_err(failed)?
+        {
+            Some(task) => reply(&task),
+            None => Err(format!("No task with id {}", params.id)),
+        }
+    }
+
+    #[tool(
+        description = "List projects with task counts per status, open tasks, and health flags: `stalled` when open tasks have no Next action, and `review_overdue` when no task changed within the review window. Answers \"which projects have no next action?\" in one call."
+    )]
+    fn get_projects(&self, Parameters(params): Parameters<GetProjectsParams>) -> ToolResult {
+        let cutoff = params.review_cutoff(Utc::now());
+        let stalled_only = params.stalled_only.unwrap_or(false);
+        let projects: Vec<_> = self
+            .service
+            .project_health()
+            .map_err(failed)?
+            .into_iter()
+            .filter(|health| health.stalled || !stalled_only)
+            .map(|health| {
+                let review_overdue = health.open > 0 && health.last_activity < cutoff;
+                let mut project = json!(health);
+                project["review_overdue"] = review_overdue.into();
+                project
+            })
+            .collect();
+        reply(&json!({ "projects": projects }))
+    }
+
+    #[tool(
+        description = "List every known project, context, tag, and area with how many open tasks use it, sorted by name. Check spellings here before capturing or filtering."
+    )]
+    fn list_facets(&self) -> ToolResult {
+        reply(&self.service.facets().map_err(failed)?)
+    }
+
+    #[tool(
+        description = "Report on the task system: completions per day over the last `days` (oldest first, starting on `first_day`), open tasks per status and per project, inbox size and average age in seconds, waiting tasks with how long they have waited, and estimated versus tracked minutes for recently completed tasks. Use it for weekly summaries."
+    )]
+    fn get_stats(&self, Parameters(params): Parameters<GetStatsParams>) -> ToolResult {
+        let days = params.days();
+        let stats = self.service.stats(days).map_err(failed)?;
+        let first_day = Local::now().date_naive() - Duration::days(days as i64 - 1);
+        let mut report = json!(stats);
+        report["first_day"] = json!(first_day);
+        reply(&report)
+    }
+
+    #[tool(
+        description = "Add timestamped text, optionally with Markdown checklist items, to the end of a task's notes without touching what is already there. Returns the updated task."
+    )]
+    fn append_note(&self, Parameters(params): Parameters<AppendNoteParams>) -> ToolResult {
+        let entry = params.entry(Local::now())?;
+        let ids = std::slice::from_ref(&params.id);
+        match self.service.append_note(ids, &entry).map_err(failed)?.pop() {
+            Some(task) => reply(&task),
+            None => Err(format!("No task with id {}", params.id)),
+        }
+    }
+
+    #[tool(
+        description = "Apply many changes in one call: each operation is a list of task ids and an action (done, next, inbox, someday, cancel, defer, delete). Everything runs in one transaction, so either every operation lands or none does. Returns a result per id; `changed` is false for ids that do not exist."
+    )]
+    fn bulk_update(&self, Parameters(params): Parameters<BulkUpdateParams>) -> ToolResult {
+        let operations = params.operations()?;
+        let outcomes = self.service.apply_batch(&operations).map_err(failed)?;
+        let results: Vec<_> = params
+            .operations
+            .iter()
+            .zip(outcomes)
+            .flat_map(|(operation, updates)| {
+                updates.into_iter().map(move |update| {
+                    json!({ "id": update.id, "action": operation.action, "changed": update.changed })
+                })
+            })
+            .collect();
+        reply(&json!({ "results": results }))
+    }
+}
+
+#[tool_handler(
+    name = "cpt.run",
+    instructions = "Tasks of a GTD system: captured into Inbox, then filed as Next, Waiting, Scheduled, or Someday, and finally Done. Task ids come from the results of other tools."
+)]
+impl ServerHandler for CptMcp {}
+
+fn reply<T: Serialize>(value: &T) -> ToolResult {
+    serde_json::to_value(value)
+        .map(CallToolResult::structured)
+        .map_err(|err| err.to_string())
+}
+
+fn failed(err: anyhow::Error) -> String {
+    format!("{err:#}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cpt_core::capture::TaskInput;
+    use cpt_core::AppConfig;
+    use tempfile::TempDir;
+
+    use crate::params::{Status, View};
+
+    fn server() -> (CptMcp, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let config = AppConfig::from_data_dir(temp_dir.path().to_path_buf()).unwrap();
+        (CptMcp::new(TasksService::new(config).unwrap()), temp_dir)
+    }
+
+    fn capture(server: &CptMcp, line: &str) -> String {
+        let input = TaskInput {
+            text: line.split_whitespace().map(str::to_string).collect(),
+            ..Default::default()
+        };
+        server.service.capture(input).unwrap().id
+    }
+
+    fn structured(result: ToolResult) -> serde_json::Value {
+        result.unwrap().structured_content.unwrap()
+    }
+
+    #[test]
+    fn list_tasks_reads_a_view_narrowed_by_tokens() {
+        let (server, _guard) = server();
+        let call = capture(&server, "Call Sam @phone +Legal");
+        capture(&server, "Buy milk @errands");
+
+        let params = ListTasksParams {
+            view: Some(View::Inbox),
+            context: Some("@phone".into()),
+            ..Default::default()
+        };
+        let listed = structured(server.list_tasks(Parameters(params)));
+        assert_eq!(listed["total"], 1);
+        assert_eq!(listed["tasks"][0]["id"], call.as_str());
+
+        let params = ListTasksParams {
+            limit: Some(1),
+            ..Default::default()
+        };
+        let listed = structured(server.list_tasks(Parameters(params)));
+        assert_eq!(listed["tasks"].as_array().unwrap().len(), 1);
+        assert_eq!(listed["total"], 2);
+    }
+
+    #[test]
+    fn search_tasks_ranks_title_hits_first_with_snippets() {
+        let (server, _guard) = server();
+        let notes_hit = capture(&server, "Call Sam");
+        server
+            .service
+            .append_note(
+                std::slice::from_ref(&notes_hit),
+                "Ask about the VENDOR contract renewal",
+            )
+            .unwrap();
+        let title_hit = capture(&server, "Sign vendor contract +Legal");
+        capture(&server, "Buy milk");
+
+        let params = SearchTasksParams {
+            query: "vendor contract".into(),
+            ..Default::default()
+        };
+        let found = structured(server.search_tasks(Parameters(params)));
+        assert_eq!(found["total"], 2);
+        assert_eq!(found["matches"][0]["task"]["id"], title_hit.as_str());
+        assert!(found["matches"][0].get("snippet").is_none());
+        assert_eq!(found["matches"][1]["task"]["id"], notes_hit.as_str());
+        assert_eq!(
+            found["matches"][1]["snippet"],
+            "Ask about the VENDOR contract renewal"
+        );
+
+        let params = SearchTasksParams {
+            query: "vendor".into(),
+            status: Some(Status::Done),
+            ..Default::default()
+        };
+        assert_eq!(
+            structured(server.search_tasks(Parameters(params)))["total"],
+            0
+        );
+        let blank = SearchTasksParams {
+            query: "  ".into(),
+            ..Default::default()
+        };
+        assert!(server.search_tasks(Parameters(blank)).is_err());
+    }
+
+    #[test]
+    fn get_projects_flags_stalled_and_quiet_projects() {
+        let (server, _guard) = server();
+        let agenda = capture(&server, "Draft agenda +Offsite");
+        server.service.promote_to_next(&[agenda]).unwrap();
+        capture(&server, "Renew contract +Legal");
+
+        let all = structured(server.get_projects(Parameters(GetProjectsParams::default())));
+        assert_eq!(all["projects"][0]["project"], "Legal");
+        assert_eq!(all["projects"][0]["stalled"], true);
+        assert_eq!(all["projects"][0]["review_overdue"], false);
+        assert_eq!(all["projects"][1]["next_actions"], 1);
+
+        let params = GetProjectsParams {
+            stalled_only: Some(true),
+            review_days: Some(0),
+        };
+        let stalled = structured(server.get_projects(Parameters(params)));
+        assert_eq!(stalled["projects"].as_array().unwrap().len(), 1);
+        assert_eq!(stalled["projects"][0]["review_overdue"], true);
+    }
+
+    #[test]
+    fn list_facets_counts_open_tasks_per_token() {
+        let (server, _guard) = server();
+        capture(&server, "Call Sam @phone +Legal #contract");
+        capture(&server, "Call Ana @phone");
+        let done = capture(&server, "Old call @office");
+        server.service.mark_done(&[done]).unwrap();
+
+        let facets = structured(server.list_facets());
+        assert_eq!(facets["contexts"], json!([{ "name": "phone", "count": 2 }]));
+        assert_eq!(facets["projects"], json!([{ "name": "Legal", "count": 1 }]));
+        assert_eq!(facets["tags"][0]["name"], "contract");
+    }
+
+    #[test]
+    fn get_stats_reports_completions_from_the_first_day() {
+        let (server, _guard) = server();
+        let done = capture(&server, "Pay invoice");
+        server.service.mark_done(&[done]).unwrap();
+        capture(&server, "Plan offsite +Offsite");
+
+        let params = GetStatsParams { days: Some(3) };
+        let stats = structured(server.get_stats(Parameters(params)));
+        assert_eq!(stats["completions"], json!([0, 0, 1]));
+        assert_eq!(stats["inbox_count"], 1);
+        assert_eq!(stats["by_project"], json!([["Offsite", 1]]));
+        let first_day = Local::now().date_naive() - Duration::days(2);
+        assert_eq!(stats["first_day"], first_day.to_string());
+    }
+
+    #[test]
+    fn append_note_stamps_text_and_adds_checklist_items() {
+        let (server, _guard) = server();
+        let id = capture(&server, "Renew vendor contract");
+        server
+            .service
+            .update_notes(&id, "Draft is with legal".into())
+            .unwrap();
+
+        let params = AppendNoteParams {
+            id: id.clone(),
+            text: "Sam replied".into(),
+            checklist: Some(vec!["Check the terms".into(), " ".into(), "Sign".into()]),
+        };
+        let task = structured(server.append_note(Parameters(params)));
+        let notes = task["notes"].as_str().unwrap();
+        let (before, added) = notes.split_once("\n\n").unwrap();
+        assert_eq!(before, "Draft is with legal");
+        let mut lines = added.lines();
+        assert!(lines.next().unwrap().ends_with(": Sam replied"));
+        assert_eq!(
+            lines.collect::<Vec<_>>(),
+            vec!["- [ ] Check the terms", "- [ ] Sign"]
+        );
+
+        let empty = AppendNoteParams {
+            id: id.clone(),
+            ..Default::default()
+        };
+        assert!(server.append_note(Parameters(empty)).is_err());
+        let missing = AppendNoteParams {
+            id: "missing".into(),
+            text: "Hello".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            server.append_note(Parameters(missing)).unwrap_err(),
+            "No task with id missing"
+        );
+    }
+
+    #[test]
+    fn bulk_update_applies_every_operation_or_none() {
+        let (server, _guard) = server();
+        let pay = capture(&server, "Pay invoice");
+        let plan = capture(&server, "Plan offsite");
+        let junk = capture(&server, "asdf");
+
+        let params: BulkUpdateParams = serde_json::from_value(json!({
+            "operations": [
+                { "ids": [pay, "missing"], "action": "done" },
+                { "ids": [plan], "action": "defer", "until": "tomorrow" },
+                { "ids": [junk], "action": "delete" },
+            ]
+        }))
+        .unwrap();
+        let done = structured(server.bulk_update(Parameters(params)));
+        assert_eq!(
+            done["results"],
+            json!([
+                { "id": pay, "action": "done", "changed": true },
+                { "id": "missing", "action": "done", "changed": false },
+                { "id": plan, "action": "defer", "changed": true },
+                { "id": junk, "action": "delete", "changed": true },
+            ])
+        );
+        let plan_task = server.service.fetch_task(&plan).unwrap().unwrap();
+        assert_eq!(plan_task.status, cpt_core::TaskStatus::Scheduled);
+
+        let params: BulkUpdateParams = serde_json::from_value(json!({
+            "operations": [
+                { "ids": [plan], "action": "cancel" },
+                { "ids": [plan], "action": "defer" },
+            ]
+        }))
+        .unwrap();
+        assert!(server.bulk_update(Parameters(params)).is_err());
+        let plan_task = server.service.fetch_task(&plan).unwrap().unwrap();
+        assert_eq!(plan_task.status, cpt_core::TaskStatus::Scheduled);
+    }
+
+    #[test]
+    fn update_task_changes_the_given_fields_and_reports_bad_input() {
+        let (server, _guard) = server();
+        let id = capture(&server, "Sign vendr contract +Legal @office");
+
+        let params = UpdateTaskParams {
+            id: id.clone(),
+            title: Some("Sign vendor contract".into()),
+            energy: Some("high".into()),
+            due: Some("2026-11-02".into()),
+            project: Some(String::new()),
+            ..Default::default()
+        };
+        let task = structured(server.update_task(Parameters(params)));
+        assert_eq!(task["title"], "Sign vendor contract");
+        assert_eq!(task["energy"], "high");
+        assert!(task["due_at"].as_str().unwrap().starts_with("2026-11-0"));
+        assert!(task["project"].is_null());
+        assert_eq!(task["contexts"][0], "office");
+
+        let bad = UpdateTaskParams {
+            id: id.clone(),
+            energy: Some("huge".into()),
+            ..Default::default()
+        };
+        assert!(server.update_task(Parameters(bad)).is_err());
+        let missing = UpdateTaskParams {
+            id: "missing".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            server.update_task(Parameters(missing)).unwrap_err(),
+            "No task with id missing"
+        );
+    }
+}
@@ -28,6 +28,8 @@ pub enum CliCommand {
     Tui,
     /// Launch the iced-based desktop shell
     Desktop(DesktopArgs),
+    /// Serve tasks to AI assistants over MCP on stdin/stdout
+    Mcp,
     /// Delete one or more tasks by id
     Delete(DeleteArgs),
 }
@@ -37,6 +39,30 @@ pub struct DesktopArgs {
     /// Refresh interval (seconds) for background view updates
     #[arg(long = "refresh-interval", value_name = "SECONDS", default_value_t = 5, value_parser = value_parser!(u64))]
     pub refresh_interval: u64,
+
+    /// Quit when the window closes instead of staying in the system tray
+    #[arg(long = "exit-on-close")]
+    pub exit_on_close: bool,
+
+    /// System-wide shortcut that opens quick capture (default CmdOrCtrl+Shift+Space; `off` disables it)
+    #[arg(long = "capture-hotkey", value_name = "KEYS")]
+    pub capture_hotkey: Option<String>,
+
+    /// Open a cpt:// link (cpt://task/<ID>, cpt://capture?text=...), in the running app if there is one
+    #[arg(long = "open", value_name = "URL")]
+    pub open: Option<String>,
+
+    /// Make cpt.run the handler for cpt:// links for the current user (Linux and Windows) and exit
+    #[arg(long = "register-url-scheme")]
+    pub register_url_scheme: bool,
+
+    /// Fill an empty database with sample tasks on launch
+    #[arg(long = "seed", conflicts_with = "no_seed")]
+    pub seed: bool,
+
+    /// Never add sample tasks, even in the debug sandbox
+    #[arg(long = "no-seed")]
+    pub no_seed: bool,
 }
 
 #[derive(Args, Debug, Clone)]
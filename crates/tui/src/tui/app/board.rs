@@ -0,0 +1,164 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::model::{Task, TaskStatus};
+
+use super::App;
+
+pub(crate) const BOARD_COLUMNS: [(TaskStatus, &str); 5] = [
+    (TaskStatus::Inbox, "📥 Inbox"),
+    (TaskStatus::Next, "⚡ Next"),
+    (TaskStatus::Waiting, "⏳ Waiting"),
+    (TaskStatus::Scheduled, "📅 Scheduled"),
+    (TaskStatus::Someday, "🌱 Someday"),
+];
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BoardState {
+    pub(crate) column: usize,
+    pub(crate) rows: [usize; BOARD_COLUMNS.len()],
+}
+
+impl App {
+    pub(crate) fn toggle_board(&mut self) -> Result<()> {
+        if self.board.take().is_some() {
+            self.refresh()?;
+            self.set_status_info("Back to the list view");
+            return Ok(());
+        }
+
+        let selected_id = self.tasks.get(self.selected).map(|task| task.id.clone());
+        self.board = Some(BoardState::default());
+        self.refresh()?;
+        if let Some(id) = selected_id {
+            self.board_select_task(&id);
+        }
+        self.set_status_info("Board view — h/l column • j/k move • H/L change status • b to exit");
+        Ok(())
+    }
+
+    /// Tasks in the given board column, paired with their index in `self.tasks`.
+    pub(crate) fn board_column(&self, column: usize) -> Vec<(usize, &Task)> {
+        let Some((status, _)) = BOARD_COLUMNS.get(column) else {
+            return Vec::new();
+        };
+        self.tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| task.status == *status)
+            .collect()
+    }
+
+    /// Points `selected` at the task under the board cursor, clamping rows as columns shrink.
+    pub(crate) fn sync_board_selection(&mut self) {
+        let Some(board) = self.board.as_ref() else {
+            return;
+        };
+        let column = board.column;
+        let entries: Vec<usize> = self
+            .board_column(column)
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect();
+        let Some(board) = self.board.as_mut() else {
+            return;
+        };
+        if entries.is_empty() {
+            board.rows[column] = 0;
+            self.table_state.select(None);
+            return;
+        }
+        let row = board.rows[column].min(entries.len() - 1);
+        board.rows[column] = row;
+        self.selected = entries[row];
+        self.table_state.select(Some(self.selected));
+    }
+
+    /// True when the board is showing an empty column, so no task is under the cursor.
+    pub(crate) fn board_column_empty(&self) -> bool {
+        self.board
+            .as_ref()
+            .is_some_and(|board| self.board_column(board.column).is_empty())
+    }
+
+    fn board_select_task(&mut self, id: &str) {
+        let found = (0..BOARD_COLUMNS.len()).find_map(|column| {
+            self.board_column(column)
+                .iter()
+                .position(|(_, task)| task.id == id)
+                .map(|row| (column, row))
+        });
+        if let (Some((column, row)), Some(board)) = (found, self.board.as_mut()) {
+            board.column = column;
+            board.rows[column] = row;
+        }
+        self.sync_board_selection();
+    }
+
+    fn board_move_column(&mut self, delta: isize) {
+        if let Some(board) = self.board.as_mut() {
+            let last = BOARD_COLUMNS.len() as isize - 1;
+            board.column = (board.column as isize + delta).clamp(0, last) as usize;
+        }
+        self.sync_board_selection();
+    }
+
+    pub(crate) fn board_move_row(&mut self, delta: isize) {
+        let Some(column) = self.board.as_ref().map(|board| board.column) else {
+            return;
+        };
+        let len = self.board_column(column).len();
+        if let Some(board) = self.board.as_mut() {
+            if len > 0 {
+                let row = board.rows[column] as isize + delta;
+                board.rows[column] = row.clamp(0, len as isize - 1) as usize;
+            }
+        }
+        self.sync_board_selection();
+    }
+
+    fn board_shift_task(&mut self, delta: isize) -> Result<()> {
+        let Some(column) = self.board.as_ref().map(|board| board.column) else {
+            return Ok(());
+        };
+        let Some(task_id) = self
+            .board_column(column)
+            .get(self.board.as_ref().map_or(0, |board| board.rows[column]))
+            .map(|(_, task)| task.id.clone())
+        else {
+            self.set_status_info("Nothing to move");
+            return Ok(());
+        };
+
+        let target = column as isize + delta;
+        if target < 0 || target >= BOARD_COLUMNS.len() as isize {
+            return Ok(());
+        }
+        let (status, label) = BOARD_COLUMNS[target as usize];
+        self.database
+            .set_status(std::slice::from_ref(&task_id), status)?;
+        self.refresh()?;
+        self.board_select_task(&task_id);
+        self.set_status_info(format!("Moved task to {}", self.label(label)));
+        Ok(())
+    }
+
+    /// Handles board-specific navigation; returns `false` when the key should fall through.
+    pub(crate) fn handle_board_key(&mut self, key: &KeyEvent) -> Result<bool> {
+        if self.board.is_none() {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Char('h') | KeyCode::Left => self.board_move_column(-1),
+            KeyCode::Char('l') | KeyCode::Right => self.board_move_column(1),
+            KeyCode::Char('j') | KeyCode::Down => self.board_move_row(1),
+            KeyCode::Char('k') | KeyCode::Up => self.board_move_row(-1),
+            KeyCode::Home => self.board_move_row(-(self.tasks.len() as isize)),
+            KeyCode::End => self.board_move_row(self.tasks.len() as isize),
+            KeyCode::Char('H') => self.board_shift_task(-1)?,
+            KeyCode::Char('L') => self.board_shift_task(1)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
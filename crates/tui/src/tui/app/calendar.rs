@@ -0,0 +1,340 @@
+use anyhow::Result;
+use chrono::{Datelike, Duration, Local, Months, NaiveDate};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use crate::tui::helpers::{due_urgency, local_date};
+
+use super::{App, TabKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CalendarSpan {
+    Week,
+    Month,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum CalendarMark {
+    Due,
+    Defer,
+}
+
+impl CalendarMark {
+    fn symbol(self, no_emoji: bool) -> &'static str {
+        match self {
+            CalendarMark::Due if no_emoji => "!",
+            CalendarMark::Due => "⏰",
+            CalendarMark::Defer => "▶",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CalendarEntry {
+    pub(crate) date: NaiveDate,
+    pub(crate) mark: CalendarMark,
+    pub(crate) task_index: usize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CalendarState {
+    pub(crate) span: CalendarSpan,
+    pub(crate) anchor: NaiveDate,
+    pub(crate) selected: usize,
+}
+
+impl CalendarState {
+    pub(crate) fn new(today: NaiveDate) -> Self {
+        Self {
+            span: CalendarSpan::Week,
+            anchor: today,
+            selected: 0,
+        }
+    }
+
+    /// First visible day (a Monday) and the number of days in the grid.
+    pub(crate) fn range(&self) -> (NaiveDate, i64) {
+        match self.span {
+            CalendarSpan::Week => (start_of_week(self.anchor), 7),
+            CalendarSpan::Month => {
+                let first = self.anchor.with_day(1).unwrap_or(self.anchor);
+                (start_of_week(first), 42)
+            }
+        }
+    }
+
+    pub(crate) fn shift(&mut self, forward: bool) {
+        self.anchor = match (self.span, forward) {
+            (CalendarSpan::Week, true) => self.anchor + Duration::days(7),
+            (CalendarSpan::Week, false) => self.anchor - Duration::days(7),
+            (CalendarSpan::Month, true) => self
+                .anchor
+                .checked_add_months(Months::new(1))
+                .unwrap_or(self.anchor),
+            (CalendarSpan::Month, false) => self
+                .anchor
+                .checked_sub_months(Months::new(1))
+                .unwrap_or(self.anchor),
+        };
+        self.selected = 0;
+    }
+
+    fn title(&self) -> String {
+        match self.span {
+            CalendarSpan::Week => {
+                let (start, _) = self.range();
+                format!("🗓️ Week of {}", start.format("%a %d %b %Y"))
+            }
+            CalendarSpan::Month => format!("🗓️ {}", self.anchor.format("%B %Y")),
+        }
+    }
+}
+
+pub(crate) fn start_of_week(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+impl App {
+    pub(crate) fn showing_calendar(&self) -> bool {
+        self.board.is_none() && self.current_tab_kind() == TabKind::Calendar
+    }
+
+    /// Due and defer dates that fall inside the visible range, in date order.
+    pub(crate) fn calendar_entries(&self) -> Vec<CalendarEntry> {
+        let (start, days) = self.calendar.range();
+        let end = start + Duration::days(days);
+        let mut entries = Vec::new();
+        for (task_index, task) in self.tasks.iter().enumerate() {
+            let marks = [
+                (CalendarMark::Due, task.due_at.as_ref()),
+                (CalendarMark::Defer, task.defer_until.as_ref()),
+            ];
+            for (mark, value) in marks {
+                let Some(date) = value.map(local_date) else {
+                    continue;
+                };
+                if date >= start && date < end {
+                    entries.push(CalendarEntry {
+                        date,
+                        mark,
+                        task_index,
+                    });
+                }
+            }
+        }
+        entries.sort_by_key(|entry| (entry.date, entry.mark, entry.task_index));
+        entries
+    }
+
+    pub(crate) fn sync_calendar_selection(&mut self) {
+        if !self.showing_calendar() {
+            return;
+        }
+        let entries = self.calendar_entries();
+        if entries.is_empty() {
+            self.calendar.selected = 0;
+            self.table_state.select(None);
+            return;
+        }
+        self.calendar.selected = self.calendar.selected.min(entries.len() - 1);
+        self.selected = entries[self.calendar.selected].task_index;
+        self.table_state.select(Some(self.selected));
+    }
+
+    pub(crate) fn calendar_move(&mut self, delta: isize) {
+        let len = self.calendar_entries().len();
+        if len > 0 {
+            let next = self.calendar.selected as isize + delta;
+            self.calendar.selected = next.clamp(0, len as isize - 1) as usize;
+        }
+        self.sync_calendar_selection();
+    }
+
+    fn calendar_shift(&mut self, forward: bool) {
+        self.calendar.shift(forward);
+        self.sync_calendar_selection();
+    }
+
+    fn calendar_today(&mut self) {
+        self.calendar.anchor = Local::now().date_naive();
+        self.calendar.selected = 0;
+        self.sync_calendar_selection();
+    }
+
+    fn calendar_toggle_span(&mut self) {
+        self.calendar.span = match self.calendar.span {
+            CalendarSpan::Week => CalendarSpan::Month,
+            CalendarSpan::Month => CalendarSpan::Week,
+        };
+        self.calendar.selected = 0;
+        self.sync_calendar_selection();
+    }
+
+    fn calendar_jump(&mut self) -> Result<()> {
+        let entries = self.calendar_entries();
+        let Some(entry) = entries.get(self.calendar.selected) else {
+            self.set_status_info("Nothing scheduled in this range");
+            return Ok(());
+        };
+        let task = self.tasks[entry.task_index].clone();
+        self.reveal_task(&task)?;
+        self.set_status_info(format!("Jumped to {}", task.title));
+        Ok(())
+    }
+
+    /// Handles calendar navigation; returns `false` when the key should fall through.
+    pub(crate) fn handle_calendar_key(&mut self, key: &KeyEvent) -> Result<bool> {
+        if !self.showing_calendar() {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Left => self.calendar_shift(false),
+            KeyCode::Right => self.calendar_shift(true),
+            KeyCode::Char('j') | KeyCode::Down => self.calendar_move(1),
+            KeyCode::Char('k') | KeyCode::Up => self.calendar_move(-1),
+            KeyCode::Char('v') => self.calendar_toggle_span(),
+            KeyCode::Char('t') => self.calendar_today(),
+            KeyCode::Enter => self.calendar_jump()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    pub(crate) fn draw_calendar(&self, f: &mut Frame<'_>, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&self.label(&self.calendar.title())))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        if inner.width < 7 || inner.height < 3 {
+            return;
+        }
+
+        let entries = self.calendar_entries();
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(inner);
+
+        let hint = if entries.is_empty() {
+            "Nothing due or deferred in this range • ←/→ change range • v week/month • t today"
+        } else {
+            "←/→ change range • j/k select • Enter jump to task • v week/month • t today"
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                hint,
+                self.theme.fg(self.theme.muted),
+            ))),
+            rows[0],
+        );
+
+        let (start, days) = self.calendar.range();
+        let weeks = (days / 7) as usize;
+        let week_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, weeks as u32); weeks])
+            .split(rows[1]);
+        let today = Local::now().date_naive();
+
+        for (week, week_area) in week_rows.iter().enumerate() {
+            let day_cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Ratio(1, 7); 7])
+                .split(*week_area);
+            for (weekday, cell) in day_cols.iter().enumerate() {
+                let date = start + Duration::days((week * 7 + weekday) as i64);
+                self.draw_calendar_day(f, *cell, date, today, &entries);
+            }
+        }
+    }
+
+    fn draw_calendar_day(
+        &self,
+        f: &mut Frame<'_>,
+        area: Rect,
+        date: NaiveDate,
+        today: NaiveDate,
+        entries: &[CalendarEntry],
+    ) {
+        let in_month = self.calendar.span == CalendarSpan::Week
+            || date.month() == self.calendar.anchor.month();
+        let title_style = if date == today {
+            self.theme.bold(self.theme.success)
+        } else if in_month {
+            self.theme.fg(self.theme.subtle)
+        } else {
+            self.theme.fg(self.theme.muted)
+        };
+        let border_style = if date == today {
+            self.theme.fg(self.theme.success)
+        } else {
+            self.theme.fg(self.theme.muted)
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled(date.format("%a %d").to_string(), title_style))
+            .border_style(border_style);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let day_entries: Vec<(usize, &CalendarEntry)> = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.date == date)
+            .collect();
+        if day_entries.is_empty() || inner.height == 0 {
+            return;
+        }
+
+        let now = chrono::Utc::now();
+        let capacity = inner.height as usize;
+        let selected_pos = day_entries
+            .iter()
+            .position(|(idx, _)| *idx == self.calendar.selected);
+        let overflow = day_entries.len() > capacity;
+        let visible = if overflow {
+            capacity.saturating_sub(1).max(1)
+        } else {
+            capacity
+        };
+        let skip = selected_pos
+            .map(|pos| (pos + 1).saturating_sub(visible))
+            .unwrap_or(0);
+
+        let mut lines: Vec<Line> = day_entries
+            .iter()
+            .skip(skip)
+            .take(visible)
+            .map(|(idx, entry)| {
+                let task = &self.tasks[entry.task_index];
+                let style = if *idx == self.calendar.selected {
+                    self.theme.selected(self.theme.highlight)
+                } else if entry.mark == CalendarMark::Due {
+                    due_urgency(task, now)
+                        .map_or_else(Style::default, |urgency| self.urgency_style(urgency))
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(
+                    format!("{} {}", entry.mark.symbol(self.no_emoji), task.title),
+                    style,
+                ))
+            })
+            .collect();
+        let hidden = day_entries.len().saturating_sub(skip + visible);
+        if hidden > 0 {
+            lines.push(Line::from(Span::styled(
+                format!("+{} more", hidden),
+                self.theme.fg(self.theme.muted),
+            )));
+        }
+        f.render_widget(Paragraph::new(lines), inner);
+    }
+}
@@ -1,8 +1,13 @@
-use anyhow::Result;
+use std::path::PathBuf;
 
-use crate::tui::constants::COMMAND_HELP;
+use anyhow::{Context, Result};
 
-use super::App;
+use crate::core::services::{export_tasks, ExportFormat};
+use crate::model::{ListFilters, ListOutputItem, SortField, Task, TaskStatus};
+use crate::tui::constants::{COMMAND_HELP, MAX_TASK_SUGGESTIONS, STATUS_PROJECT_SORT};
+use crate::tui::helpers::{fuzzy_score, short_id};
+
+use super::{App, SortChoice};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Suggestion {
@@ -17,6 +22,13 @@ impl App {
             self.set_status_error("Commands must start with '/'");
             return Ok(());
         }
+        if raw.len() > 1 {
+            if let Err(err) = self.history.record(raw) {
+                self.set_status_error(format!("Could not save command history: {:#}", err));
+            }
+        }
+        // Re-borrow the input; reporting a history error needed `&mut self`.
+        let raw = self.input.as_str().trim();
         let mut parts = raw[1..].split_whitespace();
         let cmd = match parts.next() {
             Some(c) => c.to_ascii_lowercase(),
@@ -70,13 +82,15 @@ impl App {
                 if let Some(id) = parts.next() {
                     let results = self.database.delete_tasks(&[id.to_string()])?;
                     if results.iter().any(|r| r.deleted) {
-                        self.set_status_info("Deleted task 🗑️");
+                        self.set_status_info(self.label("Deleted task 🗑️").into_owned());
                     } else {
                         self.set_status_info("Task not found");
                     }
                     self.refresh()?;
                 } else {
-                    self.prompt_delete();
+                    // Clear the prompt first so the confirmation overlay stays open.
+                    self.finish_command();
+                    return self.prompt_delete(false);
                 }
             }
             "edit" => {
@@ -119,33 +133,129 @@ impl App {
                     );
                 }
             }
+            "sort" => {
+                let rest: Vec<String> = parts.map(|s| s.to_ascii_lowercase()).collect();
+                if !self.ensure_task_view(STATUS_PROJECT_SORT) {
+                    self.finish_command();
+                    return Ok(());
+                }
+                let Some(name) = rest.first() else {
+                    self.cycle_sort()?;
+                    self.finish_command();
+                    return Ok(());
+                };
+                if matches!(name.as_str(), "reset" | "default") {
+                    self.apply_sort(None)?;
+                    self.finish_command();
+                    return Ok(());
+                }
+                let field = match name.parse::<SortField>() {
+                    Ok(field) => field,
+                    Err(err) => {
+                        self.set_status_error(err.to_string());
+                        self.finish_command();
+                        return Ok(());
+                    }
+                };
+                let current = self.current_sort();
+                let natural_ascending = matches!(
+                    field,
+                    SortField::Due | SortField::Created | SortField::Title | SortField::Manual
+                );
+                let reverse = match rest.get(1).map(String::as_str) {
+                    Some("asc") => !natural_ascending,
+                    Some("desc") => natural_ascending,
+                    Some(_) => {
+                        self.set_status_error(
+                            "Usage: /sort <due|priority|created|urgency|title|manual> [asc|desc]",
+                        );
+                        self.finish_command();
+                        return Ok(());
+                    }
+                    None if current.field == field => !current.reverse,
+                    None => false,
+                };
+                self.apply_sort(Some(SortChoice { field, reverse }))?;
+            }
+            "savefilter" | "preset" => {
+                let slot = parts
+                    .next()
+                    .and_then(|slot| slot.parse::<u8>().ok())
+                    .filter(|slot| (1..=9).contains(slot));
+                let name = parts.collect::<Vec<_>>().join(" ");
+                match slot {
+                    None => self.set_status_error("Usage: /savefilter <1-9> [name]"),
+                    Some(_) if self.active_filters.is_empty() => {
+                        self.set_status_error("Set some filters before saving a preset")
+                    }
+                    Some(slot) => {
+                        let name = if name.is_empty() {
+                            format!("preset {}", slot)
+                        } else {
+                            name
+                        };
+                        self.save_preset(slot, name)?;
+                    }
+                }
+            }
+            "set" => {
+                let option = parts.next().map(str::to_ascii_lowercase);
+                let value = parts.next().and_then(parse_toggle);
+                match (option.as_deref(), value) {
+                    (Some("confirm-delete"), Some(on)) => {
+                        self.confirm_delete = on;
+                        self.set_status_info(if on {
+                            "Deletes will ask for confirmation"
+                        } else {
+                            "Single deletes no longer ask for confirmation (this session)"
+                        });
+                    }
+                    _ => self.set_status_error("Usage: /set confirm-delete <on|off>"),
+                }
+            }
+            "goto" | "go" => {
+                let query = parts.collect::<Vec<_>>().join(" ");
+                self.finish_command();
+                return self.goto_task(&query);
+            }
+            "stats" => {
+                self.finish_command();
+                return self.open_stats();
+            }
+            "review" => {
+                self.finish_command();
+                return self.start_review();
+            }
+            "export" => {
+                let args: Vec<String> = parts.map(|s| s.to_string()).collect();
+                self.finish_command();
+                return self.export_view(&args);
+            }
+            "tutorial" => {
+                let args: Vec<String> = parts.map(|s| s.to_ascii_lowercase()).collect();
+                self.run_tutorial_command(&args);
+            }
             "refresh" | "r" => {
                 self.refresh()?;
                 self.set_status_info("Refreshed tasks");
             }
             "quit" | "q" | "exit" => {
-                self.should_quit = true;
+                self.request_quit();
+            }
+            "tabs" => {
+                let args: Vec<String> = parts.map(|s| s.to_ascii_lowercase()).collect();
+                self.finish_command();
+                return self.run_tabs_command(&args);
             }
             "view" | "tab" => {
                 if let Some(name) = parts.next() {
                     let name = name.to_ascii_lowercase();
-                    let idx = match name.as_str() {
-                        "all" => 0,
-                        "inbox" => 1,
-                        "next" => 2,
-                        "waiting" => 3,
-                        "scheduled" => 4,
-                        "someday" => 5,
-                        "projects" => 6,
-                        "done" => 7,
-                        _ => {
-                            self.set_status_error("Unknown view; try: all/inbox/next/waiting/scheduled/someday/projects/done");
-                            self.finish_command();
-                            return Ok(());
-                        }
-                    };
-                    self.tab_index = idx;
-                    self.refresh()?;
+                    if self.select_tab_by_name(&name).is_some() {
+                        self.refresh()?;
+                    } else {
+                        let names: Vec<&str> = self.tabs.iter().map(|tab| tab.name).collect();
+                        self.set_status_error(format!("Unknown view; try: {}", names.join("/")));
+                    }
                 } else {
                     self.set_status_error("Usage: /view <tab>");
                 }
@@ -159,6 +269,103 @@ impl App {
         Ok(())
     }
 
+    /// `/export [json|csv|md|ics] [path]`: writes the rows on screen, filters applied, to `path` or
+    /// the clipboard. Without a format the path's extension decides, then JSON.
+    fn export_view(&mut self, args: &[String]) -> Result<()> {
+        if !self.ensure_task_view("Select a task view to export") {
+            return Ok(());
+        }
+        let (format, path) = match args.split_first() {
+            Some((first, rest)) => match first.parse::<ExportFormat>() {
+                Ok(format) => (Some(format), rest.join(" ")),
+                Err(_) => (None, args.join(" ")),
+            },
+            None => (None, String::new()),
+        };
+        let format = format
+            .or_else(|| ExportFormat::from_path(&path))
+            .unwrap_or(ExportFormat::Json);
+        let text = export_tasks(&self.tasks, format)?;
+        let count = self.tasks.len();
+        let noun = if count == 1 { "task" } else { "tasks" };
+
+        if path.is_empty() {
+            match self.clipboard.copy(&text) {
+                Ok(target) => self.set_status_info(format!(
+                    "Exported {} {} as {} to the {}",
+                    count, noun, format, target
+                )),
+                Err(err) => self.set_status_error(format!("Export failed: {:#}", err)),
+            }
+            return Ok(());
+        }
+        let mut destination = match path.strip_prefix("~/") {
+            Some(rest) => std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join(rest))
+                .unwrap_or_else(|| PathBuf::from(&path)),
+            None => PathBuf::from(&path),
+        };
+        if destination.is_relative() {
+            destination = std::env::current_dir()
+                .context("failed to resolve the current directory")?
+                .join(destination);
+        }
+        match std::fs::write(&destination, text) {
+            Ok(()) => self.set_status_info(format!(
+                "Exported {} {} as {} to {}",
+                count,
+                noun,
+                format,
+                destination.display()
+            )),
+            Err(err) => self.set_status_error(format!(
+                "Export to {} failed: {}",
+                destination.display(),
+                err
+            )),
+        }
+        Ok(())
+    }
+
+    /// Finds a task by id, id prefix, or title across every view and reveals it.
+    fn goto_task(&mut self, query: &str) -> Result<()> {
+        if query.trim().is_empty() {
+            self.set_status_error("Usage: /goto <id or title>");
+            return Ok(());
+        }
+        let tasks = self.all_tasks()?;
+        let Some(task) = goto_matches(&tasks, query).into_iter().next().cloned() else {
+            self.set_status_error(format!("No task matches \"{}\"", query.trim()));
+            return Ok(());
+        };
+        if self.reveal_task(&task)? {
+            self.set_status_info(format!("Jumped to {}", task.title));
+        } else {
+            self.set_status_error(format!(
+                "Found {} but the active filters hide it",
+                task.title
+            ));
+        }
+        Ok(())
+    }
+
+    /// Every task, completed ones included, for lookups that ignore the current tab.
+    fn all_tasks(&self) -> Result<Vec<Task>> {
+        let filters = ListFilters {
+            include_done: true,
+            ..ListFilters::for_view(None)
+        };
+        Ok(self
+            .database
+            .fetch_tasks(&filters)?
+            .into_iter()
+            .filter_map(|item| match item {
+                ListOutputItem::Task(task) => Some(*task),
+                ListOutputItem::Project(_) => None,
+            })
+            .collect())
+    }
+
     pub(crate) fn finish_command(&mut self) {
         self.input.clear();
         self.input_mode = super::InputMode::Normal;
@@ -179,6 +386,14 @@ impl App {
     }
 }
 
+fn parse_toggle(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "on" | "true" | "yes" => Some(true),
+        "off" | "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
 fn build_command_suggestions(app: &App) -> Vec<Suggestion> {
     let raw = app.input.as_str();
     if !raw.starts_with('/') {
@@ -196,27 +411,75 @@ fn build_command_suggestions(app: &App) -> Vec<Suggestion> {
     let mut base: Vec<Suggestion> = vec![
         Suggestion {
             fill: String::from("/help"),
-            label: String::from("❓ Help — show available commands"),
+            label: app.label("❓ Help — show available commands").into_owned(),
         },
         Suggestion {
             fill: String::from("/delete "),
-            label: String::from("🗑️ Delete selected (or id)"),
+            label: app.label("🗑️ Delete selected (or id)").into_owned(),
         },
         Suggestion {
             fill: String::from("/filter"),
-            label: String::from("🔍 Open the filter picker"),
+            label: app.label("🔍 Open the filter picker").into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/savefilter "),
+            label: app
+                .label("⭐ Save filters to a preset slot (1-9)")
+                .into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/sort "),
+            label: String::from("⇅ Sort by due/priority/created/urgency"),
+        },
+        Suggestion {
+            fill: String::from("/tabs "),
+            label: app.label("🗂️ Hide, show, or reorder tabs").into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/goto "),
+            label: app
+                .label("🎯 Jump to a task by id or title in any view")
+                .into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/export "),
+            label: app
+                .label("💾 Export this view as json, csv, md, or ics to a file or the clipboard")
+                .into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/tutorial"),
+            label: app
+                .label("🎓 Walk through capture, promote, filter, and complete")
+                .into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/stats"),
+            label: app
+                .label("📊 Stats — completions, status and project counts")
+                .into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/review"),
+            label: app
+                .label("🧭 Weekly review — triage inbox and projects")
+                .into_owned(),
+        },
+        Suggestion {
+            fill: String::from("/set confirm-delete "),
+            label: app.label("⚙️ Turn delete confirmation on/off").into_owned(),
         },
         Suggestion {
             fill: String::from("/refresh"),
-            label: String::from("🔄 Refresh current view"),
+            label: app.label("🔄 Refresh current view").into_owned(),
         },
         Suggestion {
             fill: String::from("/view "),
-            label: String::from("👀 Switch view (all/inbox/next/…)"),
+            label: app.label("👀 Switch view (all/inbox/next/…)").into_owned(),
         },
         Suggestion {
             fill: String::from("/quit"),
-            label: String::from("🚪 Quit the application"),
+            label: app.label("🚪 Quit the application").into_owned(),
         },
     ];
 
@@ -232,39 +495,55 @@ fn build_command_suggestions(app: &App) -> Vec<Suggestion> {
         if first.is_empty() {
             return base;
         } else {
-            return base
-                .into_iter()
-                .filter(|s| s.fill[1..].starts_with(&first))
-                .collect();
+            // Names outrank descriptions, so `/del` prefers /delete over a label mentioning it.
+            return ranked(base.into_iter().filter_map(|s| {
+                let by_name = fuzzy_score(&first, s.fill[1..].trim_end());
+                let by_label = fuzzy_score(&first, &s.label).map(|score| score - 10);
+                by_name.max(by_label).map(|score| (score, s))
+            }));
         }
     }
 
     match first.as_str() {
         "view" | "tab" => {
+            let partial = rest.trim();
+            ranked(app.tabs.iter().filter_map(|tab| {
+                let score = fuzzy_score(partial, tab.name)
+                    .max(fuzzy_score(partial, tab.description).map(|score| score - 10))?;
+                Some((
+                    score,
+                    Suggestion {
+                        fill: format!("/view {}", tab.name),
+                        label: tab.description.to_string(),
+                    },
+                ))
+            }))
+        }
+        "sort" => {
             let partial = rest.trim().to_ascii_lowercase();
-            let views = [
-                ("all", "All active tasks"),
-                ("inbox", "Inbox items"),
-                ("next", "Next actions"),
-                ("waiting", "Waiting on others"),
-                ("scheduled", "Scheduled work"),
-                ("someday", "Someday/Maybe"),
-                ("projects", "Project health"),
-                ("done", "Completed tasks"),
-            ];
-            views
+            let mut options: Vec<(String, String)> = SortField::ALL
                 .iter()
-                .filter_map(|(name, desc)| {
-                    if partial.is_empty() || name.starts_with(&partial) {
-                        Some(Suggestion {
-                            fill: format!("/view {}", name),
-                            label: (*desc).to_string(),
-                        })
-                    } else {
-                        None
-                    }
+                .map(|field| {
+                    (
+                        field.as_str().to_string(),
+                        format!("Sort by {}", field.as_str()),
+                    )
                 })
-                .collect()
+                .collect();
+            options.push((
+                String::from("reset"),
+                String::from("Restore the view default"),
+            ));
+            ranked(options.into_iter().filter_map(|(name, label)| {
+                let score = fuzzy_score(&partial, &name)?;
+                Some((
+                    score,
+                    Suggestion {
+                        fill: format!("/sort {}", name),
+                        label,
+                    },
+                ))
+            }))
         }
         "add" => {
             let entered = rest.trim();
@@ -281,78 +560,203 @@ fn build_command_suggestions(app: &App) -> Vec<Suggestion> {
             }
         }
         "done" => {
-            if can_done {
-                if rest.trim().is_empty() {
-                    if let Some(t) = app.tasks.get(app.selected) {
-                        return vec![Suggestion {
-                            fill: format!("/done {}", t.id),
-                            label: String::from("✅ Use selected task id"),
-                        }];
-                    }
+            if can_done && rest.trim().is_empty() {
+                if let Some(t) = app.tasks.get(app.selected) {
+                    return vec![Suggestion {
+                        fill: format!("/done {}", t.id),
+                        label: app.label("✅ Use selected task id").into_owned(),
+                    }];
                 }
             }
+            let matches = task_suggestions(app, "done", &rest, "");
+            if !matches.is_empty() {
+                return matches;
+            }
             vec![Suggestion {
                 fill: String::from("/done "),
-                label: String::from("🔎 Provide a full task id"),
+                label: app
+                    .label("🔎 Provide a task id or part of its title")
+                    .into_owned(),
             }]
         }
         "delete" | "del" | "rm" => {
-            if can_done {
-                if rest.trim().is_empty() {
-                    if let Some(t) = app.tasks.get(app.selected) {
-                        return vec![Suggestion {
-                            fill: format!("/delete {}", t.id),
-                            label: String::from("🗑️ Use selected task id"),
-                        }];
-                    }
+            if can_done && rest.trim().is_empty() {
+                if let Some(t) = app.tasks.get(app.selected) {
+                    return vec![Suggestion {
+                        fill: format!("/delete {}", t.id),
+                        label: app.label("🗑️ Use selected task id").into_owned(),
+                    }];
                 }
             }
+            let matches = task_suggestions(app, "delete", &rest, "");
+            if !matches.is_empty() {
+                return matches;
+            }
             vec![Suggestion {
                 fill: String::from("/delete "),
-                label: String::from("🔎 Provide a full task id"),
+                label: app
+                    .label("🔎 Provide a task id or part of its title")
+                    .into_owned(),
             }]
         }
         "edit" => {
-            if can_done {
-                if rest.trim().is_empty() {
-                    if let Some(t) = app.tasks.get(app.selected) {
-                        return vec![Suggestion {
-                            fill: format!("/edit {} ", t.id),
-                            label: String::from("✏️ Edit selected task"),
-                        }];
-                    }
+            if can_done && rest.trim().is_empty() {
+                if let Some(t) = app.tasks.get(app.selected) {
+                    return vec![Suggestion {
+                        fill: format!("/edit {} ", t.id),
+                        label: app.label("✏️ Edit selected task").into_owned(),
+                    }];
+                }
+            }
+            // Only the first word can be a task reference; later words are the new details.
+            if !rest.trim().contains(' ') {
+                let matches = task_suggestions(app, "edit", &rest, " ");
+                if !matches.is_empty() {
+                    return matches;
                 }
             }
             vec![Suggestion {
                 fill: String::from("/edit "),
-                label: String::from("✏️ Provide an id and details"),
+                label: app.label("✏️ Provide an id and details").into_owned(),
             }]
         }
+        "tabs" => {
+            let partial = rest.trim();
+            let hide = app.tabs.iter().map(|tab| ("hide", tab));
+            let show = app.hidden_tabs.iter().map(|tab| ("show", tab));
+            ranked(hide.chain(show).filter_map(|(verb, tab)| {
+                let fill = format!("{} {}", verb, tab.name);
+                let score = fuzzy_score(partial, &fill)?;
+                Some((
+                    score,
+                    Suggestion {
+                        fill: format!("/tabs {}", fill),
+                        label: format!("{} {}", verb, app.label(tab.label)),
+                    },
+                ))
+            }))
+        }
+        "export" => {
+            let partial = rest.trim();
+            ranked(ExportFormat::ALL.iter().filter_map(|format| {
+                let score = fuzzy_score(partial, format.as_str())?;
+                Some((
+                    score,
+                    Suggestion {
+                        fill: format!("/export {} ", format),
+                        label: format!("{} to the clipboard, or add a path", format),
+                    },
+                ))
+            }))
+        }
+        "goto" | "go" => {
+            if rest.trim().is_empty() {
+                return vec![Suggestion {
+                    fill: String::from("/goto "),
+                    label: app
+                        .label("🔎 Provide a task id or part of its title")
+                        .into_owned(),
+                }];
+            }
+            let tasks = app.all_tasks().unwrap_or_default();
+            goto_matches(&tasks, &rest)
+                .into_iter()
+                .take(MAX_TASK_SUGGESTIONS)
+                .map(|task| Suggestion {
+                    fill: format!("/goto {}", task.id),
+                    label: format!("{} {} · {}", short_id(&task.id), task.title, task.status),
+                })
+                .collect()
+        }
         "filter" => {
             let entered = rest.trim();
             if entered.is_empty() {
                 vec![
                     Suggestion {
                         fill: String::from("/filter"),
-                        label: String::from("🔍 Open the filter picker"),
+                        label: app.label("🔍 Open the filter picker").into_owned(),
                     },
                     Suggestion {
                         fill: String::from("/filter clear"),
-                        label: String::from("🧹 Clear active filters"),
+                        label: app.label("🧹 Clear active filters").into_owned(),
                     },
                 ]
             } else if "clear".starts_with(&entered.to_ascii_lowercase()) {
                 vec![Suggestion {
                     fill: String::from("/filter clear"),
-                    label: String::from("🧹 Clear active filters"),
+                    label: app.label("🧹 Clear active filters").into_owned(),
                 }]
             } else {
                 vec![Suggestion {
                     fill: String::from("/filter"),
-                    label: String::from("🔍 Open the filter picker"),
+                    label: app.label("🔍 Open the filter picker").into_owned(),
                 }]
             }
         }
         _ => Vec::new(),
     }
 }
+
+/// `/goto` candidates best-first: an exact id, then id prefixes, then fuzzy titles. Open tasks win
+/// ties, and a pasted link resolves by its last path segment.
+fn goto_matches<'a>(tasks: &'a [Task], query: &str) -> Vec<&'a Task> {
+    let query = query.trim().trim_end_matches('/');
+    let query = match query.rsplit_once('/') {
+        Some((_, id)) if query.contains("://") => id,
+        _ => query,
+    };
+    let lowered = query.to_ascii_lowercase();
+    let mut scored: Vec<(i64, bool, &Task)> = tasks
+        .iter()
+        .filter_map(|task| {
+            let id = task.id.to_ascii_lowercase();
+            let score = if id == lowered {
+                i64::MAX
+            } else if id.starts_with(&lowered) {
+                i64::MAX - 1
+            } else {
+                fuzzy_score(query, &task.title)?
+            };
+            let open = !matches!(task.status, TaskStatus::Done | TaskStatus::Canceled);
+            Some((score, open, task))
+        })
+        .collect();
+    scored.sort_by_key(|(score, open, _)| std::cmp::Reverse((*score, *open)));
+    scored.into_iter().map(|(_, _, task)| task).collect()
+}
+
+/// Orders scored suggestions best-first, keeping the original order among ties.
+fn ranked(scored: impl Iterator<Item = (i64, Suggestion)>) -> Vec<Suggestion> {
+    let mut scored: Vec<(i64, Suggestion)> = scored.collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored
+        .into_iter()
+        .map(|(_, suggestion)| suggestion)
+        .collect()
+}
+
+/// Tasks in the current view whose id prefix or fuzzy title matches `query`.
+fn task_suggestions(app: &App, command: &str, query: &str, trailing: &str) -> Vec<Suggestion> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let lowered = query.to_ascii_lowercase();
+    let mut matches = ranked(app.tasks.iter().filter_map(|task| {
+        let by_id = task
+            .id
+            .to_ascii_lowercase()
+            .starts_with(&lowered)
+            .then_some(i64::MAX);
+        let score = by_id.or_else(|| fuzzy_score(query, &task.title))?;
+        Some((
+            score,
+            Suggestion {
+                fill: format!("/{} {}{}", command, task.id, trailing),
+                label: format!("{} {}", short_id(&task.id), task.title),
+            },
+        ))
+    }));
+    matches.truncate(MAX_TASK_SUGGESTIONS);
+    matches
+}
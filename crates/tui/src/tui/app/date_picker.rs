@@ -0,0 +1,426 @@
+use std::cmp::min;
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, TimeZone, Timelike, Utc};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::tui::helpers::centered_rect;
+
+use super::calendar::start_of_week;
+use super::{App, InputMode};
+
+/// Time of day given to a picked date, matching `parse_date_spec`.
+const DAY_START_HOUR: u32 = 9;
+const EVENING_HOUR: u32 = 19;
+const DATE_FORMAT: &str = "%a %d %b %H:%M";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTarget {
+    Defer,
+    Due,
+}
+
+impl DateTarget {
+    fn title(self) -> &'static str {
+        match self {
+            DateTarget::Defer => "💤 Snooze",
+            DateTarget::Due => "⏰ Due Date",
+        }
+    }
+
+    fn current_label(self) -> &'static str {
+        match self {
+            DateTarget::Defer => "Deferred until",
+            DateTarget::Due => "Due",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QuickAction {
+    At(DateTime<Local>),
+    /// Opens the mini calendar.
+    Pick,
+    Clear,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct QuickDate {
+    pub(crate) key: char,
+    pub(crate) label: &'static str,
+    pub(crate) action: QuickAction,
+}
+
+impl QuickDate {
+    fn at(key: char, label: &'static str, date: NaiveDate, hour: u32) -> Option<Self> {
+        Some(Self {
+            key,
+            label,
+            action: QuickAction::At(at_hour(date, hour)?),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DatePicker {
+    pub(crate) target: DateTarget,
+    pub(crate) task_id: String,
+    pub(crate) current: Option<DateTime<Utc>>,
+    /// The task's defer date, which a due date may not precede.
+    pub(crate) defer_until: Option<DateTime<Utc>>,
+    pub(crate) choices: Vec<QuickDate>,
+    pub(crate) selected: usize,
+    /// Cursor day while the mini calendar is open.
+    pub(crate) calendar: Option<NaiveDate>,
+}
+
+fn at_hour(date: NaiveDate, hour: u32) -> Option<DateTime<Local>> {
+    Local
+        .from_local_datetime(&date.and_hms_opt(hour, 0, 0)?)
+        .earliest()
+}
+
+fn pick_entry() -> QuickDate {
+    QuickDate {
+        key: 'p',
+        label: "Pick a date…",
+        action: QuickAction::Pick,
+    }
+}
+
+/// Tonight (until the evening starts), tomorrow, the coming weekend, next Monday, or a picked day.
+pub(crate) fn snooze_choices(now: DateTime<Local>) -> Vec<QuickDate> {
+    let today = now.date_naive();
+    let weekday = today.weekday().num_days_from_monday() as i64;
+    let until_saturday = (5 - weekday).rem_euclid(7);
+    let weekend = if until_saturday == 0 {
+        1
+    } else {
+        until_saturday
+    };
+    let mut choices: Vec<QuickDate> = [
+        (now.hour() < EVENING_HOUR)
+            .then(|| QuickDate::at('t', "Tonight", today, EVENING_HOUR))
+            .flatten(),
+        QuickDate::at('m', "Tomorrow", today + Duration::days(1), DAY_START_HOUR),
+        QuickDate::at(
+            'w',
+            "This weekend",
+            today + Duration::days(weekend),
+            DAY_START_HOUR,
+        ),
+        QuickDate::at(
+            'n',
+            "Next week",
+            today + Duration::days(7 - weekday),
+            DAY_START_HOUR,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    choices.push(pick_entry());
+    choices
+}
+
+/// Today, tomorrow, this Friday, next Monday, a picked day, and clearing an existing due date.
+pub(crate) fn due_choices(now: DateTime<Local>, has_due: bool) -> Vec<QuickDate> {
+    let today = now.date_naive();
+    let weekday = today.weekday().num_days_from_monday() as i64;
+    let until_friday = (4 - weekday).rem_euclid(7);
+    let mut choices: Vec<QuickDate> = [
+        QuickDate::at('t', "Today", today, DAY_START_HOUR),
+        QuickDate::at('m', "Tomorrow", today + Duration::days(1), DAY_START_HOUR),
+        QuickDate::at(
+            'f',
+            "Friday",
+            today + Duration::days(until_friday),
+            DAY_START_HOUR,
+        ),
+        QuickDate::at(
+            'n',
+            "Next week",
+            today + Duration::days(7 - weekday),
+            DAY_START_HOUR,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    choices.push(pick_entry());
+    if has_due {
+        choices.push(QuickDate {
+            key: 'c',
+            label: "Clear due date",
+            action: QuickAction::Clear,
+        });
+    }
+    choices
+}
+
+impl App {
+    pub(crate) fn open_date_picker(&mut self, target: DateTarget) {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing selected");
+            return;
+        };
+        let (current, choices) = match target {
+            DateTarget::Defer => (task.defer_until, snooze_choices(Local::now())),
+            DateTarget::Due => (
+                task.due_at,
+                due_choices(Local::now(), task.due_at.is_some()),
+            ),
+        };
+        self.date_picker = Some(DatePicker {
+            target,
+            task_id: task.id.clone(),
+            current,
+            defer_until: task.defer_until,
+            choices,
+            selected: 0,
+            calendar: None,
+        });
+        self.input_mode = InputMode::DatePicker;
+        self.status = None;
+    }
+
+    fn close_date_picker(&mut self) {
+        self.date_picker = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub(crate) fn handle_date_picker_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(picker) = self.date_picker.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return Ok(());
+        };
+        if let Some(day) = picker.calendar {
+            let moved = match key.code {
+                KeyCode::Char('h') | KeyCode::Left => day - Duration::days(1),
+                KeyCode::Char('l') | KeyCode::Right => day + Duration::days(1),
+                KeyCode::Char('k') | KeyCode::Up => day - Duration::days(7),
+                KeyCode::Char('j') | KeyCode::Down => day + Duration::days(7),
+                KeyCode::Char('<') | KeyCode::PageUp => {
+                    day.checked_sub_months(Months::new(1)).unwrap_or(day)
+                }
+                KeyCode::Char('>') | KeyCode::PageDown => {
+                    day.checked_add_months(Months::new(1)).unwrap_or(day)
+                }
+                KeyCode::Enter => {
+                    return match at_hour(day, DAY_START_HOUR) {
+                        Some(at) => self.apply_picked_date(Some(at)),
+                        None => {
+                            self.set_status_error("That day has no 09:00 in the local time zone");
+                            Ok(())
+                        }
+                    };
+                }
+                KeyCode::Esc => {
+                    picker.calendar = None;
+                    return Ok(());
+                }
+                _ => day,
+            };
+            picker.calendar = Some(moved);
+            return Ok(());
+        }
+
+        let last = picker.choices.len().saturating_sub(1);
+        let chosen = match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                picker.selected = min(picker.selected + 1, last);
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                picker.selected = picker.selected.saturating_sub(1);
+                None
+            }
+            KeyCode::Enter => Some(picker.selected),
+            KeyCode::Char(c) => picker.choices.iter().position(|choice| choice.key == c),
+            KeyCode::Esc => {
+                self.close_date_picker();
+                return Ok(());
+            }
+            _ => None,
+        };
+        let Some(action) =
+            chosen.and_then(|idx| picker.choices.get(idx).map(|choice| choice.action))
+        else {
+            return Ok(());
+        };
+        match action {
+            QuickAction::At(at) => self.apply_picked_date(Some(at)),
+            QuickAction::Clear => self.apply_picked_date(None),
+            QuickAction::Pick => {
+                picker.calendar = Some(Local::now().date_naive() + Duration::days(1));
+                Ok(())
+            }
+        }
+    }
+
+    /// Defers the selected task to the morning `days` from today without opening the picker.
+    pub(crate) fn snooze_for_days(&mut self, days: i64) -> Result<()> {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing selected");
+            return Ok(());
+        };
+        let task_id = task.id.clone();
+        let day = Local::now().date_naive() + Duration::days(days);
+        let Some(at) = at_hour(day, DAY_START_HOUR) else {
+            self.set_status_error("That day has no 09:00 in the local time zone");
+            return Ok(());
+        };
+        let updated = self
+            .database
+            .defer_task(&task_id, Some(at.with_timezone(&Utc)))?;
+        self.refresh()?;
+        self.select_task_by_id(&task_id);
+        self.sync_board_selection();
+        match updated {
+            Some(_) => self.set_status_info(format!("Snoozed until {}", at.format(DATE_FORMAT))),
+            None => self.set_status_info("Task not found"),
+        }
+        Ok(())
+    }
+
+    /// Writes the chosen date (`None` clears it) after checking it makes sense for the target.
+    fn apply_picked_date(&mut self, at: Option<DateTime<Local>>) -> Result<()> {
+        let Some(picker) = self.date_picker.as_ref() else {
+            return Ok(());
+        };
+        if let Some(problem) = at.and_then(|at| date_problem(picker, at, Local::now())) {
+            self.set_status_error(problem);
+            return Ok(());
+        }
+        let task_id = picker.task_id.clone();
+        let target = picker.target;
+        let utc = at.map(|at| at.with_timezone(&Utc));
+        let updated = match target {
+            DateTarget::Defer => self.database.defer_task(&task_id, utc)?,
+            DateTarget::Due => self.database.set_due(&task_id, utc)?,
+        };
+        self.close_date_picker();
+        self.refresh()?;
+        self.select_task_by_id(&task_id);
+        self.sync_board_selection();
+        let message = match (updated.is_some(), target, at) {
+            (false, _, _) => String::from("Task not found"),
+            (true, DateTarget::Defer, Some(at)) => {
+                format!("Snoozed until {}", at.format(DATE_FORMAT))
+            }
+            (true, DateTarget::Defer, None) => String::from("Cleared the defer date"),
+            (true, DateTarget::Due, Some(at)) => format!("Due {}", at.format(DATE_FORMAT)),
+            (true, DateTarget::Due, None) => String::from("Cleared the due date"),
+        };
+        self.set_status_info(message);
+        Ok(())
+    }
+
+    pub(crate) fn draw_date_picker(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(picker) = &self.date_picker else {
+            return;
+        };
+        let muted = self.theme.fg(self.theme.muted);
+        let mut lines = vec![
+            Line::from(match picker.current {
+                Some(current) => Span::raw(format!(
+                    "{}: {}",
+                    picker.target.current_label(),
+                    current.with_timezone(&Local).format(DATE_FORMAT)
+                )),
+                None => Span::styled(format!("{}: not set", picker.target.current_label()), muted),
+            }),
+            Line::default(),
+        ];
+        for (idx, choice) in picker.choices.iter().enumerate() {
+            let when = match choice.action {
+                QuickAction::At(at) => at.format(DATE_FORMAT).to_string(),
+                QuickAction::Pick | QuickAction::Clear => String::new(),
+            };
+            let style = if idx == picker.selected && picker.calendar.is_none() {
+                self.theme.selected(self.theme.highlight)
+            } else {
+                self.theme.fg(self.theme.text)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", choice.key), self.theme.fg(self.theme.info)),
+                Span::styled(format!("{:<14}", choice.label), style),
+                Span::styled(when, muted),
+            ]));
+        }
+        if let Some(day) = picker.calendar {
+            lines.push(Line::default());
+            lines.extend(self.mini_calendar_lines(day));
+        }
+
+        let width = min(area.width.saturating_sub(4), 44);
+        let height = min(lines.len() as u16 + 2, area.height.saturating_sub(2));
+        let popup_area = centered_rect(width, height, area);
+        f.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&self.label(picker.target.title())))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
+        f.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+
+    /// Month grid around `cursor`, Monday first, with today and the cursor marked.
+    fn mini_calendar_lines(&self, cursor: NaiveDate) -> Vec<Line<'static>> {
+        let today = Local::now().date_naive();
+        let first = cursor.with_day(1).unwrap_or(cursor);
+        let start = start_of_week(first);
+        let mut lines = vec![
+            Line::from(Span::styled(
+                cursor.format("%B %Y").to_string(),
+                self.theme.bold(self.theme.accent),
+            )),
+            Line::from(Span::styled(
+                "Mo Tu We Th Fr Sa Su",
+                self.theme.fg(self.theme.muted),
+            )),
+        ];
+        for week in 0..6 {
+            let spans = (0..7)
+                .map(|day| {
+                    let date = start + Duration::days(week * 7 + day);
+                    let style = if date == cursor {
+                        self.theme.selected(self.theme.highlight)
+                    } else if date == today {
+                        self.theme.bold(self.theme.success)
+                    } else if date.month() != cursor.month() {
+                        self.theme.fg(self.theme.muted)
+                    } else {
+                        self.theme.fg(self.theme.text)
+                    };
+                    Span::styled(format!("{:>2} ", date.day()), style)
+                })
+                .collect::<Vec<_>>();
+            lines.push(Line::from(spans));
+        }
+        lines
+    }
+}
+
+/// Why `at` can't be used: snoozes must be in the future, and due dates can't fall before today
+/// or before the task's defer date.
+pub(crate) fn date_problem(
+    picker: &DatePicker,
+    at: DateTime<Local>,
+    now: DateTime<Local>,
+) -> Option<&'static str> {
+    match picker.target {
+        DateTarget::Defer if at <= now => Some("Pick a time in the future"),
+        DateTarget::Due if at.date_naive() < now.date_naive() => {
+            Some("The due date can't be in the past")
+        }
+        DateTarget::Due if picker.defer_until.is_some_and(|defer| at < defer) => {
+            Some("The due date can't be before the task's defer date")
+        }
+        _ => None,
+    }
+}
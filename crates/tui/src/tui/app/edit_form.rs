@@ -0,0 +1,413 @@
+use std::cmp::min;
+
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::model::{NewTask, Task};
+use crate::parser::{normalize_labels, parse_date_spec};
+use crate::tui::buffer::TextBuffer;
+use crate::tui::helpers::{centered_rect, join_prefixed};
+
+use super::{App, InputMode};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const LABEL_WIDTH: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EditField {
+    Title,
+    Project,
+    Contexts,
+    Tags,
+    Due,
+    Defer,
+    Priority,
+    Notes,
+}
+
+impl EditField {
+    pub(crate) const ALL: [EditField; 8] = [
+        EditField::Title,
+        EditField::Project,
+        EditField::Contexts,
+        EditField::Tags,
+        EditField::Due,
+        EditField::Defer,
+        EditField::Priority,
+        EditField::Notes,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            EditField::Title => "Title",
+            EditField::Project => "Project",
+            EditField::Contexts => "Contexts",
+            EditField::Tags => "Tags",
+            EditField::Due => "Due",
+            EditField::Defer => "Start",
+            EditField::Priority => "Priority",
+            EditField::Notes => "Notes",
+        }
+    }
+
+    fn placeholder(self) -> &'static str {
+        match self {
+            EditField::Project => "none — ←/→ to pick",
+            EditField::Contexts => "@home @phone",
+            EditField::Tags => "#ops #later",
+            EditField::Due | EditField::Defer => "tomorrow, fri, +3d, 2025-01-20",
+            EditField::Notes => "Shift+Enter for a new line",
+            EditField::Title | EditField::Priority => "",
+        }
+    }
+}
+
+/// Field-by-field editor for an existing task. Fields the form doesn't show (areas, energy,
+/// estimate, repeat, waiting) pass through unchanged.
+#[derive(Debug, Clone)]
+pub(crate) struct EditForm {
+    task: Task,
+    pub(crate) fields: Vec<TextBuffer>,
+    pub(crate) focus: usize,
+    projects: Vec<String>,
+    pub(crate) error: Option<String>,
+}
+
+fn local_date(at: Option<DateTime<Utc>>) -> String {
+    at.map(|at| at.with_timezone(&Local).format(DATE_FORMAT).to_string())
+        .unwrap_or_default()
+}
+
+/// Splits `@a, @b c` style input into normalized labels without their sigil.
+fn parse_labels(text: &str, sigil: char) -> Vec<String> {
+    let labels: Vec<String> = text
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .map(|label| label.trim_start_matches(sigil).to_string())
+        .filter(|label| !label.is_empty())
+        .collect();
+    normalize_labels(&labels)
+}
+
+impl EditForm {
+    pub(crate) fn new(task: Task, projects: Vec<String>) -> Self {
+        let fields = EditField::ALL
+            .iter()
+            .map(|field| {
+                let mut buffer = TextBuffer::new();
+                buffer.set(match field {
+                    EditField::Title => task.title.clone(),
+                    EditField::Project => task.project.clone().unwrap_or_default(),
+                    EditField::Contexts => join_prefixed(&task.contexts, "@"),
+                    EditField::Tags => join_prefixed(&task.tags, "#"),
+                    EditField::Due => local_date(task.due_at),
+                    EditField::Defer => local_date(task.defer_until),
+                    EditField::Priority => task.priority.to_string(),
+                    EditField::Notes => task.notes.clone().unwrap_or_default(),
+                });
+                buffer
+            })
+            .collect();
+        Self {
+            task,
+            fields,
+            focus: 0,
+            projects,
+            error: None,
+        }
+    }
+
+    pub(crate) fn field(&self) -> EditField {
+        EditField::ALL[self.focus]
+    }
+
+    fn value(&self, field: EditField) -> &str {
+        let idx = EditField::ALL.iter().position(|f| *f == field).unwrap_or(0);
+        self.fields[idx].as_str()
+    }
+
+    fn buffer(&mut self) -> &mut TextBuffer {
+        &mut self.fields[self.focus]
+    }
+
+    /// Steps the project field through known projects; past either end it clears the field.
+    fn cycle_project(&mut self, forward: bool) {
+        if self.projects.is_empty() {
+            return;
+        }
+        let current = self.value(EditField::Project).trim().to_string();
+        let position = self
+            .projects
+            .iter()
+            .position(|project| project.eq_ignore_ascii_case(&current));
+        let next = match (position, forward) {
+            (None, true) => Some(0),
+            (None, false) => Some(self.projects.len() - 1),
+            (Some(idx), true) => (idx + 1 < self.projects.len()).then_some(idx + 1),
+            (Some(idx), false) => idx.checked_sub(1),
+        };
+        let value = next
+            .map(|idx| self.projects[idx].clone())
+            .unwrap_or_default();
+        self.buffer().set(value);
+    }
+
+    fn step_priority(&mut self, up: bool) {
+        let current: u8 = self.value(EditField::Priority).trim().parse().unwrap_or(0);
+        let next = if up {
+            min(current + 1, 3)
+        } else {
+            current.saturating_sub(1)
+        };
+        self.buffer().set(next.to_string());
+    }
+
+    /// Dates keep their stored time unless the text changed from what the form showed.
+    fn parse_date(
+        &self,
+        field: EditField,
+        original: Option<DateTime<Utc>>,
+    ) -> Result<Option<DateTime<Utc>>, String> {
+        let text = self.value(field).trim();
+        if text.is_empty() {
+            return Ok(None);
+        }
+        if text == local_date(original) {
+            return Ok(original);
+        }
+        parse_date_spec(text)
+            .map(Some)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Builds the update from the form, or names the field that needs fixing.
+    pub(crate) fn to_new_task(&self) -> Result<NewTask, (EditField, String)> {
+        let mut updated = NewTask::from(&self.task);
+        let title = self.value(EditField::Title).trim();
+        if title.is_empty() {
+            return Err((EditField::Title, String::from("The title can't be empty")));
+        }
+        updated.title = title.to_string();
+        let project = self.value(EditField::Project).trim();
+        updated.project = (!project.is_empty()).then(|| project.to_string());
+        updated.contexts = parse_labels(self.value(EditField::Contexts), '@');
+        updated.tags = parse_labels(self.value(EditField::Tags), '#');
+        updated.due_at = self
+            .parse_date(EditField::Due, self.task.due_at)
+            .map_err(|err| (EditField::Due, err))?;
+        updated.defer_until = self
+            .parse_date(EditField::Defer, self.task.defer_until)
+            .map_err(|err| (EditField::Defer, err))?;
+        if let (Some(due), Some(defer)) = (updated.due_at, updated.defer_until) {
+            if due < defer {
+                return Err((
+                    EditField::Due,
+                    String::from("The due date can't be before the start date"),
+                ));
+            }
+        }
+        updated.priority = match self.value(EditField::Priority).trim() {
+            "" => 0,
+            text => match text.parse::<u8>() {
+                Ok(priority) if priority <= 3 => priority,
+                _ => {
+                    return Err((
+                        EditField::Priority,
+                        String::from("Priority is a number from 0 to 3"),
+                    ))
+                }
+            },
+        };
+        let notes = self.value(EditField::Notes).trim_end();
+        if !notes.is_empty() || self.task.notes.is_some() {
+            updated.notes = Some(notes.to_string());
+        }
+        Ok(updated)
+    }
+}
+
+impl App {
+    pub(crate) fn open_edit_form(&mut self, task: Task) -> Result<()> {
+        let projects = self
+            .database
+            .fetch_facets()?
+            .projects
+            .into_iter()
+            .map(|facet| facet.name)
+            .collect();
+        self.edit_form = Some(EditForm::new(task, projects));
+        self.input_mode = InputMode::EditForm;
+        self.status = None;
+        Ok(())
+    }
+
+    pub(crate) fn handle_edit_form_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(form) = self.edit_form.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return Ok(());
+        };
+        let field = form.field();
+        let notes = field == EditField::Notes;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => {
+                self.edit_form = None;
+                self.input_mode = InputMode::Normal;
+                self.set_status_info("Edit cancelled");
+            }
+            KeyCode::Enter if notes && key.modifiers.contains(KeyModifiers::SHIFT) => {
+                form.buffer().insert_newline();
+            }
+            KeyCode::Enter => return self.save_edit_form(),
+            KeyCode::Char('t') if ctrl => {
+                // Hand over to the capture-line editor, which covers energy, estimate and waiting.
+                let task = form.task.clone();
+                self.edit_form = None;
+                self.begin_edit_with_task(task);
+            }
+            KeyCode::Char('r') if ctrl => {
+                let task = form.task.clone();
+                self.edit_form = None;
+                self.open_recurrence_editor(&task);
+            }
+            KeyCode::Tab => form.focus = (form.focus + 1) % EditField::ALL.len(),
+            KeyCode::BackTab => {
+                form.focus = (form.focus + EditField::ALL.len() - 1) % EditField::ALL.len()
+            }
+            KeyCode::Down if notes => form.buffer().move_down(),
+            KeyCode::Up if notes => form.buffer().move_up(),
+            KeyCode::Down => form.focus = min(form.focus + 1, EditField::ALL.len() - 1),
+            KeyCode::Up => form.focus = form.focus.saturating_sub(1),
+            KeyCode::Left if field == EditField::Project => form.cycle_project(false),
+            KeyCode::Right if field == EditField::Project => form.cycle_project(true),
+            KeyCode::Left if field == EditField::Priority => form.step_priority(false),
+            KeyCode::Right if field == EditField::Priority => form.step_priority(true),
+            KeyCode::Left => form.buffer().move_left(),
+            KeyCode::Right => form.buffer().move_right(),
+            KeyCode::Home => form.buffer().move_home(),
+            KeyCode::End => form.buffer().move_end(),
+            KeyCode::Backspace => form.buffer().backspace(),
+            KeyCode::Delete => form.buffer().delete_char(),
+            KeyCode::Char(ch) if !ctrl => form.buffer().insert_char(ch),
+            _ => return Ok(()),
+        }
+        if let Some(form) = self.edit_form.as_mut() {
+            form.error = None;
+        }
+        Ok(())
+    }
+
+    fn save_edit_form(&mut self) -> Result<()> {
+        let Some(form) = self.edit_form.as_mut() else {
+            return Ok(());
+        };
+        let updated = match form.to_new_task() {
+            Ok(updated) => updated,
+            Err((field, message)) => {
+                form.focus = EditField::ALL.iter().position(|f| *f == field).unwrap_or(0);
+                form.error = Some(message);
+                return Ok(());
+            }
+        };
+        let id = form.task.id.clone();
+        self.edit_form = None;
+        self.input_mode = InputMode::Normal;
+        match self.database.update_task(&id, &updated)? {
+            Some(task) => {
+                self.refresh()?;
+                self.select_task_by_id(&id);
+                self.set_status_info(format!("Updated [{}] {}", task.status.as_str(), task.title));
+            }
+            None => self.set_status_error("Task not found"),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn draw_edit_form(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(form) = &self.edit_form else {
+            return;
+        };
+        let label_style = self.theme.fg(self.theme.muted);
+        let focus_style = self.theme.bold(self.theme.accent);
+        let muted = self.theme.fg(self.theme.muted);
+        let mut lines = Vec::new();
+        for (idx, field) in EditField::ALL.iter().enumerate() {
+            let focused = idx == form.focus;
+            let buffer = &form.fields[idx];
+            let mut text = buffer.as_str().to_string();
+            if focused {
+                let (line, col) = buffer.cursor_line_col();
+                text = insert_cursor(&text, line, col);
+            }
+            let marker = if focused { "› " } else { "  " };
+            let label = Span::styled(
+                format!("{}{:<width$}", marker, field.label(), width = LABEL_WIDTH),
+                if focused { focus_style } else { label_style },
+            );
+            if buffer.as_str().is_empty() && !field.placeholder().is_empty() {
+                lines.push(Line::from(vec![
+                    label,
+                    Span::raw(if focused { "▏" } else { "" }),
+                    Span::styled(field.placeholder(), muted),
+                ]));
+                continue;
+            }
+            let mut rows = text.split('\n');
+            lines.push(Line::from(vec![
+                label,
+                Span::raw(rows.next().unwrap_or_default().to_string()),
+            ]));
+            for row in rows {
+                lines.push(Line::from(vec![
+                    Span::raw(" ".repeat(LABEL_WIDTH + 2)),
+                    Span::raw(row.to_string()),
+                ]));
+            }
+        }
+        lines.push(Line::default());
+        match &form.error {
+            Some(error) => lines.push(Line::from(Span::styled(
+                format!("{}{}", self.icon("⚠ ", "Error: "), error),
+                self.theme.bold(self.theme.danger),
+            ))),
+            None => lines.push(Line::from(Span::styled(
+                "Tab/↑↓ field • ←→ pick project or priority • Enter save • Ctrl+R repeat • Ctrl+T tokens • Esc cancel",
+                muted,
+            ))),
+        }
+
+        let width = min(area.width.saturating_sub(10), 80);
+        let height = min(lines.len() as u16 + 2, area.height.saturating_sub(2));
+        let popup_area = centered_rect(width, height, area);
+        f.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&self.label("✏️ Edit Task")))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
+        f.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
+
+/// Places a `▏` cursor at `line`/`col` (in characters) of `text`.
+fn insert_cursor(text: &str, line: usize, col: usize) -> String {
+    text.split('\n')
+        .enumerate()
+        .map(|(idx, row)| {
+            if idx != line {
+                return row.to_string();
+            }
+            let split = row
+                .char_indices()
+                .nth(col)
+                .map(|(offset, _)| offset)
+                .unwrap_or(row.len());
+            format!("{}▏{}", &row[..split], &row[split..])
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,21 +1,26 @@
+use std::time::Instant;
+
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::tui::constants::{
-    STATUS_COMMAND_PALETTE, STATUS_ENTER_ADD, STATUS_PROJECT_DELETE, STATUS_PROJECT_DONE,
-    STATUS_PROJECT_INBOX, STATUS_PROJECT_MOVE, STATUS_PROJECT_SOMEDAY, STATUS_REFRESHED,
+    CHORD_TIMEOUT, MAX_COUNT, STATUS_ENTER_ADD, STATUS_PROJECT_DELETE, STATUS_PROJECT_DONE,
+    STATUS_PROJECT_EDIT, STATUS_PROJECT_INBOX, STATUS_PROJECT_MOVE, STATUS_PROJECT_SOMEDAY,
+    STATUS_PROJECT_SORT, STATUS_REFRESHED,
 };
 
+use super::date_picker::DateTarget;
+use super::keymap::{pending_label, KeyLookup, KeyPress};
 use super::{App, ConfirmChoice, InputMode};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct CommandTrigger {
     initial_input: &'static str,
     status: &'static str,
 }
 
 impl CommandTrigger {
-    const fn new(initial_input: &'static str, status: &'static str) -> Self {
+    pub(crate) const fn new(initial_input: &'static str, status: &'static str) -> Self {
         Self {
             initial_input,
             status,
@@ -23,15 +28,34 @@ impl CommandTrigger {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum NormalAction {
     Quit,
     EnterAdd,
     EnterEdit,
+    EditNotes,
     ShowDetails,
+    Back,
     ShowHelp,
     Refresh,
     OpenFilter,
+    ApplyPreset,
+    CycleSort,
+    ToggleBoard,
+    ToggleSidebar,
+    QuickFilter,
+    Yank,
+    YankJson,
+    OpenLinks,
+    MoveToProject,
+    Snooze,
+    SnoozeDay,
+    SnoozeWeek,
+    SnoozeMonth,
+    SetDue,
+    ToggleTimer,
+    RaisePriority,
+    LowerPriority,
     EnterCommand(CommandTrigger),
     MarkNext,
     MarkSomeday,
@@ -44,63 +68,184 @@ pub(crate) enum NormalAction {
     NextTab,
     SelectFirst,
     SelectLast,
+    HalfPageDown,
+    HalfPageUp,
 }
 
 impl NormalAction {
-    fn from_event(key: &KeyEvent) -> Option<Self> {
-        if matches!(key.code, KeyCode::Char('c')) && key.modifiers.contains(KeyModifiers::CONTROL) {
-            return Some(Self::Quit);
-        }
-
-        match key.code {
-            KeyCode::Char('q') => Some(Self::Quit),
-            KeyCode::Char('a') => Some(Self::EnterAdd),
-            KeyCode::Char('e') => Some(Self::EnterEdit),
-            KeyCode::Char('i') => Some(Self::MarkInbox),
-            KeyCode::Char('s') => Some(Self::MarkSomeday),
-            KeyCode::Char('r') => Some(Self::Refresh),
-            KeyCode::Char('f') => Some(Self::OpenFilter),
-            KeyCode::Char('h') => Some(Self::ShowHelp),
-            KeyCode::Char('/') => Some(Self::EnterCommand(CommandTrigger::new(
-                "/",
-                STATUS_COMMAND_PALETTE,
-            ))),
-            KeyCode::Char('n') => Some(Self::MarkNext),
-            KeyCode::Char('d') => Some(Self::MarkDone),
-            KeyCode::Char('x') | KeyCode::Delete => Some(Self::Delete),
-            KeyCode::Char('j') | KeyCode::Down => Some(Self::SelectNext),
-            KeyCode::Char('k') | KeyCode::Up => Some(Self::SelectPrev),
-            KeyCode::Left | KeyCode::BackTab => Some(Self::PrevTab),
-            KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => Some(Self::NextTab),
-            KeyCode::Enter => Some(Self::ShowDetails),
-            KeyCode::Home => Some(Self::SelectFirst),
-            KeyCode::End => Some(Self::SelectLast),
-            _ => None,
-        }
+    fn targets_task(self) -> bool {
+        matches!(
+            self,
+            Self::EnterEdit
+                | Self::EditNotes
+                | Self::ShowDetails
+                | Self::MarkNext
+                | Self::MarkSomeday
+                | Self::MarkInbox
+                | Self::MarkDone
+                | Self::Delete
+                | Self::Yank
+                | Self::YankJson
+                | Self::OpenLinks
+                | Self::MoveToProject
+                | Self::Snooze
+                | Self::SnoozeDay
+                | Self::SnoozeWeek
+                | Self::SnoozeMonth
+                | Self::SetDue
+                | Self::ToggleTimer
+                | Self::RaisePriority
+                | Self::LowerPriority
+        )
     }
 }
 
 impl App {
     pub(crate) fn on_key(&mut self, key: KeyEvent) -> Result<()> {
-        match self.input_mode {
+        let handled = match self.input_mode {
             InputMode::Normal => self.handle_normal_mode(key),
             InputMode::Add => self.handle_add_mode(key),
             InputMode::Command => self.handle_command_mode(key),
             InputMode::Filter => self.handle_filter_mode(key),
             InputMode::Edit => self.handle_edit_mode(key),
+            InputMode::EditForm => self.handle_edit_form_mode(key),
             InputMode::Inspect => self.handle_inspect_mode(key),
             InputMode::Help => self.handle_help_mode(key),
             InputMode::ConfirmDelete => self.handle_confirm_delete_mode(key),
-        }
+            InputMode::Sidebar => self.handle_sidebar_mode(key),
+            InputMode::QuickFilter => self.handle_quick_filter_mode(key),
+            InputMode::LinkPicker => self.handle_link_picker_mode(key),
+            InputMode::MoveProject => self.handle_project_picker_mode(key),
+            InputMode::DatePicker => self.handle_date_picker_mode(key),
+            InputMode::Recurrence => self.handle_recurrence_mode(key),
+            InputMode::Review => self.handle_review_mode(key),
+            InputMode::Stats => self.handle_stats_mode(key),
+        };
+        handled?;
+        self.advance_tutorial()
     }
 
     fn handle_normal_mode(&mut self, key: KeyEvent) -> Result<()> {
-        if let Some(action) = NormalAction::from_event(&key) {
-            self.execute_normal_action(action)?;
+        let idle = self.pending_keys.is_empty() && self.pending_count.is_none();
+        if idle && (self.handle_board_key(&key)? || self.handle_calendar_key(&key)?) {
+            return Ok(());
+        }
+        if self.preset_pending {
+            return self.apply_preset_key(&key);
+        }
+        if self.push_count_digit(&key) {
+            return Ok(());
+        }
+        if key.code == KeyCode::Esc && !idle {
+            // Esc abandons a half-typed chord or count rather than completing it.
+            self.pending_keys.clear();
+            self.pending_count = None;
+            self.status = None;
+            return Ok(());
+        }
+
+        self.pending_keys.push(KeyPress::from(&key));
+        self.pending_since = Instant::now();
+        match self.keymap.lookup(&self.pending_keys) {
+            KeyLookup::Action(action) => {
+                let chorded = std::mem::take(&mut self.pending_keys).len() > 1;
+                self.run_normal_action(action, chorded)
+            }
+            KeyLookup::Pending | KeyLookup::Ambiguous(_) => {
+                self.set_status_info(pending_label(self.pending_count, &self.pending_keys));
+                Ok(())
+            }
+            KeyLookup::Unbound => {
+                self.pending_keys.pop();
+                let prefix = std::mem::take(&mut self.pending_keys);
+                if prefix.is_empty() {
+                    self.pending_count = None;
+                    return Ok(());
+                }
+                // A broken chord runs whatever its prefix means alone, then retries the new key.
+                self.status = None;
+                if let KeyLookup::Ambiguous(action) = self.keymap.lookup(&prefix) {
+                    self.run_normal_action(action, prefix.len() > 1)?;
+                }
+                self.handle_normal_mode(key)
+            }
+        }
+    }
+
+    /// The key after the `preset` prefix: `1`–`9` re-apply that slot, anything else cancels.
+    /// Bare digits are never presets, so they always start a count.
+    fn apply_preset_key(&mut self, key: &KeyEvent) -> Result<()> {
+        self.preset_pending = false;
+        self.status = None;
+        match key.code {
+            KeyCode::Char(c @ '1'..='9') if key.modifiers.is_empty() => {
+                let slot = c as u8 - b'0';
+                if !self.apply_preset(slot)? {
+                    self.set_status_info(format!("No preset saved in slot {}", slot));
+                }
+            }
+            _ => {}
         }
         Ok(())
     }
 
+    /// Accumulates a vim-style count (`5j`); `0` only counts once a count has started.
+    fn push_count_digit(&mut self, key: &KeyEvent) -> bool {
+        let KeyCode::Char(c) = key.code else {
+            return false;
+        };
+        let Some(digit) = c.to_digit(10) else {
+            return false;
+        };
+        if !self.pending_keys.is_empty()
+            || (digit == 0 && self.pending_count.is_none())
+            || !key.modifiers.is_empty()
+            || self.keymap.lookup(&[KeyPress::from(key)]) != KeyLookup::Unbound
+        {
+            return false;
+        }
+        let count = self
+            .pending_count
+            .unwrap_or(0)
+            .saturating_mul(10)
+            .saturating_add(digit as usize)
+            .min(MAX_COUNT);
+        self.pending_count = Some(count);
+        self.set_status_info(pending_label(self.pending_count, &[]));
+        true
+    }
+
+    /// Fires an ambiguous chord prefix (`d` while `d d` is also bound) once it times out.
+    pub(crate) fn flush_pending_keys(&mut self) -> Result<()> {
+        if (self.pending_keys.is_empty() && self.pending_count.is_none())
+            || self.pending_since.elapsed() < CHORD_TIMEOUT
+        {
+            return Ok(());
+        }
+        let keys = std::mem::take(&mut self.pending_keys);
+        self.status = None;
+        match self.keymap.lookup(&keys) {
+            KeyLookup::Ambiguous(action) => self.run_normal_action(action, keys.len() > 1),
+            _ => {
+                self.pending_count = None;
+                Ok(())
+            }
+        }
+    }
+
+    /// `chorded` is set when the action came from a multi-key chord such as `d d`.
+    fn run_normal_action(&mut self, action: NormalAction, chorded: bool) -> Result<()> {
+        let count = self.pending_count.take();
+        if action != NormalAction::Quit {
+            self.quit_warned = false;
+        }
+        if action.targets_task() && self.selection_missing() {
+            self.set_status_info("Nothing selected");
+            return Ok(());
+        }
+        self.execute_normal_action(action, count, chorded)
+    }
+
     fn handle_filter_mode(&mut self, key: KeyEvent) -> Result<()> {
         if self.filter_overlay.is_none() {
             self.input_mode = InputMode::Normal;
@@ -184,21 +329,95 @@ impl App {
         Ok(())
     }
 
-    fn execute_normal_action(&mut self, action: NormalAction) -> Result<()> {
+    fn execute_normal_action(
+        &mut self,
+        action: NormalAction,
+        count: Option<usize>,
+        chorded: bool,
+    ) -> Result<()> {
+        let repeat = count.unwrap_or(1);
         match action {
-            NormalAction::Quit => {
-                self.should_quit = true;
-            }
+            NormalAction::Quit => self.request_quit(),
             NormalAction::EnterAdd => {
                 self.input_mode = InputMode::Add;
                 self.input.clear();
+                self.add_notes.clear();
+                self.add_notes_focus = false;
                 self.set_status_info(STATUS_ENTER_ADD);
             }
             NormalAction::EnterEdit => {
                 self.start_edit_current()?;
             }
+            NormalAction::EditNotes => {
+                if self.ensure_task_view(STATUS_PROJECT_EDIT) {
+                    self.request_notes_edit();
+                }
+            }
             NormalAction::ShowDetails => {
-                self.show_selected_details()?;
+                if self.showing_projects {
+                    self.open_selected_project()?;
+                } else {
+                    self.show_selected_details()?;
+                }
+            }
+            NormalAction::Back => {
+                if self.quick_filter.is_some() {
+                    self.clear_quick_filter();
+                } else if self.project_drill.is_some() {
+                    self.close_project_drill()?;
+                }
+            }
+            NormalAction::QuickFilter => self.start_quick_filter(),
+            NormalAction::OpenLinks => {
+                if self.ensure_task_view("Select a task view to open links") {
+                    self.open_selected_links();
+                }
+            }
+            NormalAction::RaisePriority | NormalAction::LowerPriority => {
+                if self.ensure_task_view("Select a task view to change priority") {
+                    let step = repeat as i64;
+                    let delta = if action == NormalAction::RaisePriority {
+                        step
+                    } else {
+                        -step
+                    };
+                    self.bump_priority(delta)?;
+                }
+            }
+            NormalAction::Snooze => {
+                if self.ensure_task_view("Select a task view to snooze items") {
+                    self.open_date_picker(DateTarget::Defer);
+                }
+            }
+            NormalAction::SnoozeDay | NormalAction::SnoozeWeek | NormalAction::SnoozeMonth => {
+                if self.ensure_task_view("Select a task view to snooze items") {
+                    let days = match action {
+                        NormalAction::SnoozeDay => 1,
+                        NormalAction::SnoozeWeek => 7,
+                        _ => 30,
+                    };
+                    self.snooze_for_days(days)?;
+                }
+            }
+            NormalAction::ToggleTimer => {
+                if self.ensure_task_view("Select a task view to track time") {
+                    self.toggle_timer()?;
+                }
+            }
+            NormalAction::SetDue => {
+                if self.ensure_task_view("Select a task view to set due dates") {
+                    self.open_date_picker(DateTarget::Due);
+                }
+            }
+            NormalAction::MoveToProject => {
+                if self.ensure_task_view("Select a task view to move items") {
+                    self.open_project_picker()?;
+                }
+            }
+            NormalAction::Yank | NormalAction::YankJson => {
+                if self.ensure_task_view("Select a task view to copy items") {
+                    self.yank_selected(action == NormalAction::YankJson)?;
+                }
             }
             NormalAction::ShowHelp => {
                 self.show_help_overlay();
@@ -213,6 +432,10 @@ impl App {
                     self.mark_someday()?;
                 }
             }
+            NormalAction::ApplyPreset => {
+                self.preset_pending = true;
+                self.set_status_info("Preset — press 1-9 to re-apply a saved filter, Esc cancels");
+            }
             NormalAction::Refresh => {
                 self.refresh()?;
                 self.set_status_info(STATUS_REFRESHED);
@@ -220,8 +443,20 @@ impl App {
             NormalAction::OpenFilter => {
                 self.open_filter_overlay()?;
             }
+            NormalAction::ToggleBoard => {
+                self.toggle_board()?;
+            }
+            NormalAction::ToggleSidebar => {
+                self.open_sidebar()?;
+            }
+            NormalAction::CycleSort => {
+                if self.ensure_task_view(STATUS_PROJECT_SORT) {
+                    self.cycle_sort()?;
+                }
+            }
             NormalAction::EnterCommand(trigger) => {
                 self.input_mode = InputMode::Command;
+                self.history.reset_cursor();
                 self.input.set(trigger.initial_input);
                 self.update_command_suggestions();
                 self.set_status_info(trigger.status);
@@ -237,27 +472,56 @@ impl App {
                 }
             }
             NormalAction::Delete => {
+                // `d d` is one slip away from `d` (done), so a chorded delete always asks first.
                 if self.ensure_task_view(STATUS_PROJECT_DELETE) {
-                    self.prompt_delete();
+                    self.prompt_delete(chorded)?;
                 }
             }
-            NormalAction::SelectNext => self.select_next(),
-            NormalAction::SelectPrev => self.select_prev(),
+            NormalAction::SelectNext => self.move_selection(repeat as isize)?,
+            NormalAction::SelectPrev => self.move_selection(-(repeat as isize))?,
+            NormalAction::HalfPageDown => {
+                self.move_selection((self.half_page() * repeat) as isize)?;
+            }
+            NormalAction::HalfPageUp => {
+                self.move_selection(-((self.half_page() * repeat) as isize))?;
+            }
             NormalAction::PrevTab => {
                 self.prev_tab()?;
             }
             NormalAction::NextTab => {
                 self.next_tab()?;
             }
-            NormalAction::SelectFirst => {
-                if !self.tasks.is_empty() {
-                    self.selected = 0;
-                    self.table_state.select(Some(self.selected));
+            // With a count, `5gg` and `5G` both jump to the fifth task, like vim.
+            NormalAction::SelectFirst | NormalAction::SelectLast
+                if self.board.is_some() || self.showing_calendar() =>
+            {
+                let span = self.tasks.len() as isize;
+                let delta = if action == NormalAction::SelectFirst {
+                    -span
+                } else {
+                    span
+                };
+                self.move_selection(delta)?;
+            }
+            NormalAction::SelectFirst | NormalAction::SelectLast if self.showing_projects => {
+                if !self.projects.is_empty() {
+                    let last = self.projects.len() - 1;
+                    let row = match (count, action) {
+                        (Some(line), _) => line.saturating_sub(1).min(last),
+                        (None, NormalAction::SelectFirst) => 0,
+                        (None, _) => last,
+                    };
+                    self.project_state.select(Some(row));
                 }
             }
-            NormalAction::SelectLast => {
+            NormalAction::SelectFirst | NormalAction::SelectLast => {
                 if !self.tasks.is_empty() {
-                    self.selected = self.tasks.len() - 1;
+                    let last = self.tasks.len() - 1;
+                    self.selected = match (count, action) {
+                        (Some(line), _) => line.saturating_sub(1).min(last),
+                        (None, NormalAction::SelectFirst) => 0,
+                        (None, _) => last,
+                    };
                     self.table_state.select(Some(self.selected));
                 }
             }
@@ -266,10 +530,15 @@ impl App {
     }
 
     fn handle_add_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let buffer = if self.add_notes_focus {
+            &mut self.add_notes
+        } else {
+            &mut self.input
+        };
         match key.code {
             KeyCode::Enter => {
                 if key.modifiers.contains(KeyModifiers::SHIFT) {
-                    self.input.insert_newline();
+                    buffer.insert_newline();
                     Ok(())
                 } else {
                     self.add_task()
@@ -280,44 +549,44 @@ impl App {
                 self.status = None;
                 Ok(())
             }
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.add_notes_focus = !self.add_notes_focus;
+                Ok(())
+            }
             KeyCode::Backspace => {
-                self.input.backspace();
+                buffer.backspace();
                 Ok(())
             }
             KeyCode::Delete => {
-                self.input.delete_char();
+                buffer.delete_char();
                 Ok(())
             }
             KeyCode::Char(c) => {
-                self.input.insert_char(c);
-                Ok(())
-            }
-            KeyCode::Tab => {
-                self.input.insert_tab();
+                buffer.insert_char(c);
                 Ok(())
             }
             KeyCode::Left => {
-                self.input.move_left();
+                buffer.move_left();
                 Ok(())
             }
             KeyCode::Right => {
-                self.input.move_right();
+                buffer.move_right();
                 Ok(())
             }
             KeyCode::Up => {
-                self.input.move_up();
+                buffer.move_up();
                 Ok(())
             }
             KeyCode::Down => {
-                self.input.move_down();
+                buffer.move_down();
                 Ok(())
             }
             KeyCode::Home => {
-                self.input.move_home();
+                buffer.move_home();
                 Ok(())
             }
             KeyCode::End => {
-                self.input.move_end();
+                buffer.move_end();
                 Ok(())
             }
             _ => Ok(()),
@@ -390,19 +659,102 @@ impl App {
                 self.status = None;
                 Ok(())
             }
+            KeyCode::Char('r') => {
+                if let Some(task) = self.inspect_task.take() {
+                    self.open_recurrence_editor(&task);
+                }
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
 
+    fn handle_link_picker_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(picker) = self.link_picker.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return Ok(());
+        };
+        let chosen = match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                picker.selected = (picker.selected + 1).min(picker.urls.len() - 1);
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                picker.selected = picker.selected.saturating_sub(1);
+                None
+            }
+            KeyCode::Enter => Some(picker.selected),
+            KeyCode::Char(c @ '1'..='9') => Some(c as usize - '1' as usize),
+            KeyCode::Esc => {
+                self.link_picker = None;
+                self.input_mode = InputMode::Normal;
+                self.status = None;
+                return Ok(());
+            }
+            _ => None,
+        };
+        if let Some(url) = chosen.and_then(|idx| picker.urls.get(idx).cloned()) {
+            self.link_picker = None;
+            self.input_mode = InputMode::Normal;
+            self.open_link(&url);
+        }
+        Ok(())
+    }
+
     fn handle_help_mode(&mut self, key: KeyEvent) -> Result<()> {
+        if self.help.searching {
+            match key.code {
+                KeyCode::Esc => {
+                    self.help.query.clear();
+                    self.help.searching = false;
+                }
+                KeyCode::Enter => self.help.searching = false,
+                KeyCode::Backspace => {
+                    self.help.query.pop();
+                }
+                KeyCode::Char(c) => {
+                    self.help.query.push(c);
+                    self.help.scroll = 0;
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let page = (self.help.height / 2).max(1);
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
         match key.code {
-            KeyCode::Esc | KeyCode::Enter => {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
                 self.input_mode = InputMode::Normal;
                 self.status = None;
-                Ok(())
             }
-            _ => Ok(()),
+            KeyCode::Char('/') => {
+                self.help.searching = true;
+                self.help.query.clear();
+                self.help.scroll = 0;
+            }
+            KeyCode::Char('d') if ctrl => self.help.scroll += page,
+            KeyCode::Char('u') if ctrl => {
+                self.help.scroll = self.help.scroll.saturating_sub(page);
+            }
+            KeyCode::Char('j') | KeyCode::Down => self.help.scroll += 1,
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.help.scroll = self.help.scroll.saturating_sub(1);
+            }
+            KeyCode::PageDown | KeyCode::Char(' ') => self.help.scroll += page,
+            KeyCode::PageUp => self.help.scroll = self.help.scroll.saturating_sub(page),
+            KeyCode::Char('g') | KeyCode::Home => self.help.scroll = 0,
+            // Clamped to the last page when the overlay is drawn.
+            KeyCode::Char('G') | KeyCode::End => self.help.scroll = usize::MAX,
+            _ if self.keymap.lookup(&[KeyPress::from(&key)])
+                == KeyLookup::Action(NormalAction::ShowHelp) =>
+            {
+                self.input_mode = InputMode::Normal;
+                self.status = None;
+            }
+            _ => {}
         }
+        Ok(())
     }
 
     fn handle_confirm_delete_mode(&mut self, key: KeyEvent) -> Result<()> {
@@ -429,6 +781,11 @@ impl App {
         }
     }
 
+    /// Up recalls history only from an untouched `/` prompt, so suggestion browsing still works.
+    fn command_input_blank(&self) -> bool {
+        self.input.as_str().trim() == "/" && !self.history.is_empty()
+    }
+
     fn handle_command_mode(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Enter => {
@@ -451,24 +808,45 @@ impl App {
                 Ok(())
             }
             KeyCode::Backspace => {
+                self.history.reset_cursor();
                 self.input.backspace();
                 self.update_command_suggestions();
                 Ok(())
             }
             KeyCode::Char(c) => {
+                self.history.reset_cursor();
                 self.input.insert_char(c);
                 self.update_command_suggestions();
                 Ok(())
             }
             KeyCode::Delete => {
+                self.history.reset_cursor();
                 self.input.delete_char();
                 self.update_command_suggestions();
                 Ok(())
             }
             KeyCode::Tab | KeyCode::Right => {
+                self.history.reset_cursor();
                 self.accept_suggestion();
                 Ok(())
             }
+            KeyCode::Up if self.history.is_browsing() || self.command_input_blank() => {
+                if let Some(entry) = self.history.older() {
+                    let entry = entry.to_string();
+                    self.input.set(entry);
+                    self.update_command_suggestions();
+                }
+                Ok(())
+            }
+            KeyCode::Down if self.history.is_browsing() => {
+                let entry = self
+                    .history
+                    .newer()
+                    .map_or_else(|| String::from("/"), String::from);
+                self.input.set(entry);
+                self.update_command_suggestions();
+                Ok(())
+            }
             KeyCode::Up => {
                 if !self.suggestions.is_empty() {
                     if self.suggestion_index == 0 {
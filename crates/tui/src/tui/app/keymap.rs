@@ -0,0 +1,519 @@
+use std::fmt;
+
+use anyhow::{anyhow, bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::tui::constants::STATUS_COMMAND_PALETTE;
+use crate::tui::settings::KeySettings;
+
+use super::input::{CommandTrigger, NormalAction};
+
+pub(crate) const LAYOUTS: [&str; 2] = ["default", "emacs"];
+
+/// Every remappable action: config name, action, and the help-overlay description.
+pub(crate) const ACTIONS: [(&str, NormalAction, &str); 40] = [
+    ("next-tab", NormalAction::NextTab, "Next view"),
+    ("prev-tab", NormalAction::PrevTab, "Previous view"),
+    ("down", NormalAction::SelectNext, "Move selection down"),
+    ("up", NormalAction::SelectPrev, "Move selection up"),
+    ("first", NormalAction::SelectFirst, "Jump to the first task"),
+    ("last", NormalAction::SelectLast, "Jump to the last task"),
+    (
+        "half-page-down",
+        NormalAction::HalfPageDown,
+        "Move half a page down",
+    ),
+    (
+        "half-page-up",
+        NormalAction::HalfPageUp,
+        "Move half a page up",
+    ),
+    ("quit", NormalAction::Quit, "Quit"),
+    (
+        "details",
+        NormalAction::ShowDetails,
+        "Toggle task detail overlay / open project",
+    ),
+    (
+        "back",
+        NormalAction::Back,
+        "Clear a quick filter or return to Projects",
+    ),
+    ("help", NormalAction::ShowHelp, "Toggle this help overlay"),
+    ("add", NormalAction::EnterAdd, "Capture a new task"),
+    ("edit", NormalAction::EnterEdit, "Edit selected task"),
+    ("notes", NormalAction::EditNotes, "Edit notes in $EDITOR"),
+    ("next", NormalAction::MarkNext, "Promote to Next actions"),
+    (
+        "someday",
+        NormalAction::MarkSomeday,
+        "Move to Someday/Maybe",
+    ),
+    ("inbox", NormalAction::MarkInbox, "Send back to Inbox"),
+    ("done", NormalAction::MarkDone, "Mark as Done"),
+    (
+        "delete",
+        NormalAction::Delete,
+        "Delete task (with confirmation)",
+    ),
+    (
+        "snooze",
+        NormalAction::Snooze,
+        "Defer the task until a later date",
+    ),
+    (
+        "snooze-day",
+        NormalAction::SnoozeDay,
+        "Defer until tomorrow morning",
+    ),
+    ("snooze-week", NormalAction::SnoozeWeek, "Defer for a week"),
+    (
+        "snooze-month",
+        NormalAction::SnoozeMonth,
+        "Defer for 30 days",
+    ),
+    ("due", NormalAction::SetDue, "Set or clear the due date"),
+    (
+        "timer",
+        NormalAction::ToggleTimer,
+        "Start or stop the task timer",
+    ),
+    (
+        "priority-up",
+        NormalAction::RaisePriority,
+        "Raise priority (up to p:3)",
+    ),
+    (
+        "priority-down",
+        NormalAction::LowerPriority,
+        "Lower priority",
+    ),
+    (
+        "move",
+        NormalAction::MoveToProject,
+        "Move task to another project",
+    ),
+    ("yank", NormalAction::Yank, "Copy task as a capture line"),
+    ("yank-json", NormalAction::YankJson, "Copy task as JSON"),
+    (
+        "open-link",
+        NormalAction::OpenLinks,
+        "Open links from the task title or notes",
+    ),
+    ("filter", NormalAction::OpenFilter, "Open filter picker"),
+    (
+        "preset",
+        NormalAction::ApplyPreset,
+        "Re-apply a saved filter preset (then 1-9)",
+    ),
+    (
+        "quick-filter",
+        NormalAction::QuickFilter,
+        "Narrow rows by typing part of a title",
+    ),
+    (
+        "sort",
+        NormalAction::CycleSort,
+        "Cycle sort field and direction",
+    ),
+    (
+        "board",
+        NormalAction::ToggleBoard,
+        "Toggle the kanban board",
+    ),
+    (
+        "sidebar",
+        NormalAction::ToggleSidebar,
+        "Open the project/context sidebar",
+    ),
+    (
+        "command",
+        NormalAction::EnterCommand(CommandTrigger::new("/", STATUS_COMMAND_PALETTE)),
+        "Command palette",
+    ),
+    ("refresh", NormalAction::Refresh, "Refresh from storage"),
+];
+
+/// How the help overlay groups actions; every entry in `ACTIONS` belongs to one section.
+const HELP_SECTIONS: [(&str, &[&str]); 4] = [
+    (
+        "Navigation",
+        &[
+            "next-tab",
+            "prev-tab",
+            "down",
+            "up",
+            "first",
+            "last",
+            "half-page-down",
+            "half-page-up",
+            "back",
+        ],
+    ),
+    (
+        "Task actions",
+        &[
+            "add",
+            "edit",
+            "notes",
+            "next",
+            "someday",
+            "inbox",
+            "done",
+            "delete",
+            "snooze",
+            "snooze-day",
+            "snooze-week",
+            "snooze-month",
+            "due",
+            "timer",
+            "priority-up",
+            "priority-down",
+            "move",
+        ],
+    ),
+    ("Copy & links", &["yank", "yank-json", "open-link"]),
+    (
+        "Views & tools",
+        &[
+            "details",
+            "help",
+            "board",
+            "sidebar",
+            "filter",
+            "preset",
+            "quick-filter",
+            "sort",
+            "command",
+            "refresh",
+            "quit",
+        ],
+    ),
+];
+
+const DEFAULT_LAYOUT: [(&str, &[&str]); 40] = [
+    ("next-tab", &["l", "right", "tab"]),
+    ("prev-tab", &["left", "shift+tab"]),
+    ("down", &["j", "down"]),
+    ("up", &["k", "up"]),
+    ("first", &["g g", "home"]),
+    ("last", &["G", "end"]),
+    ("half-page-down", &["ctrl+d", "pagedown"]),
+    ("half-page-up", &["ctrl+u", "pageup"]),
+    ("quit", &["q", "ctrl+c"]),
+    ("details", &["enter"]),
+    ("back", &["esc"]),
+    ("help", &["h"]),
+    ("add", &["a"]),
+    ("edit", &["e"]),
+    ("notes", &["E"]),
+    ("next", &["n"]),
+    ("someday", &["s"]),
+    ("inbox", &["i"]),
+    ("done", &["d"]),
+    ("delete", &["x", "delete", "d d"]),
+    ("snooze", &["z"]),
+    ("snooze-day", &["z 1"]),
+    ("snooze-week", &["z 7"]),
+    ("snooze-month", &["z 3"]),
+    ("due", &["D"]),
+    ("timer", &["T"]),
+    ("priority-up", &["+", "="]),
+    ("priority-down", &["-"]),
+    ("move", &["m"]),
+    ("yank", &["y"]),
+    ("yank-json", &["Y"]),
+    ("open-link", &["O", "g x"]),
+    ("filter", &["f"]),
+    ("preset", &["'"]),
+    ("quick-filter", &[","]),
+    ("sort", &["o"]),
+    ("board", &["b"]),
+    ("sidebar", &["\\"]),
+    ("command", &["/"]),
+    ("refresh", &["r"]),
+];
+
+/// Emacs-flavoured navigation; everything not listed keeps its default keys.
+const EMACS_LAYOUT: [(&str, &[&str]); 7] = [
+    ("next-tab", &["ctrl+f", "right", "tab"]),
+    ("prev-tab", &["ctrl+b", "left", "shift+tab"]),
+    ("down", &["ctrl+n", "down"]),
+    ("up", &["ctrl+p", "up"]),
+    ("first", &["alt+<", "home"]),
+    ("last", &["alt+>", "end"]),
+    ("quit", &["ctrl+x ctrl+c", "q"]),
+];
+
+/// A single key press with only the modifiers that matter for matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct KeyPress {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyPress {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // Shift is already folded into the character (`G`) or the code (`BackTab`).
+        Self {
+            code,
+            modifiers: modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+
+    fn parse(token: &str) -> Result<Self> {
+        let (mods, key) = if token == "+" {
+            ("", "+")
+        } else if let Some(prefix) = token.strip_suffix("++") {
+            (prefix, "+")
+        } else {
+            token.rsplit_once('+').unwrap_or(("", token))
+        };
+
+        let mut modifiers = KeyModifiers::empty();
+        let mut shift = false;
+        for part in mods.split('+').filter(|part| !part.is_empty()) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" | "meta" => modifiers |= KeyModifiers::ALT,
+                "shift" => shift = true,
+                other => bail!("unknown modifier '{}' in '{}'", other, token),
+            }
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if shift => KeyCode::Char(c.to_ascii_uppercase()),
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "tab" if shift => KeyCode::BackTab,
+                "backtab" => KeyCode::BackTab,
+                "tab" => KeyCode::Tab,
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" | "pgup" => KeyCode::PageUp,
+                "pagedown" | "pgdn" => KeyCode::PageDown,
+                name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n) if (1..=24).contains(&n) => KeyCode::F(n),
+                    _ => bail!("unknown key '{}'", key),
+                },
+            },
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl From<&KeyEvent> for KeyPress {
+    fn from(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl fmt::Display for KeyPress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("shift+tab"),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Delete => f.write_str("delete"),
+            KeyCode::Insert => f.write_str("insert"),
+            KeyCode::Up => f.write_str("↑"),
+            KeyCode::Down => f.write_str("↓"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pgup"),
+            KeyCode::PageDown => f.write_str("pgdn"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// Formats a chord such as `g g` or `ctrl+x ctrl+c`.
+pub(crate) fn describe(keys: &[KeyPress]) -> String {
+    keys.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone)]
+struct Binding {
+    keys: Vec<KeyPress>,
+    action: NormalAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyLookup {
+    Action(NormalAction),
+    /// The keys so far start a longer chord; wait for the next press.
+    Pending,
+    /// The keys are bound on their own but also start a longer chord (`d` vs `d d`);
+    /// the action fires once the chord times out or is broken.
+    Ambiguous(NormalAction),
+    Unbound,
+}
+
+/// Normal-mode key bindings, built from a named layout plus `[keys.bindings]` overrides.
+#[derive(Debug, Clone)]
+pub(crate) struct Keymap {
+    bindings: Vec<Binding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::layout("default").expect("default layout parses")
+    }
+}
+
+impl Keymap {
+    pub(crate) fn layout(name: &str) -> Result<Self> {
+        let mut map = Self {
+            bindings: Vec::new(),
+        };
+        for (action, keys) in DEFAULT_LAYOUT {
+            map.bind(action, keys.iter().copied())?;
+        }
+        match name.trim().to_ascii_lowercase().as_str() {
+            "default" => {}
+            "emacs" => {
+                for (action, keys) in EMACS_LAYOUT {
+                    map.bind(action, keys.iter().copied())?;
+                }
+            }
+            other => bail!(
+                "unknown key layout '{}' (expected one of: {})",
+                other,
+                LAYOUTS.join(", ")
+            ),
+        }
+        Ok(map)
+    }
+
+    pub(crate) fn from_settings(settings: &KeySettings) -> Result<Self> {
+        let mut map = Self::layout(settings.layout.as_deref().unwrap_or("default"))?;
+        for (action, keys) in &settings.bindings {
+            map.bind(action, keys.iter().map(String::as_str))?;
+        }
+        Ok(map)
+    }
+
+    /// Replaces every key bound to `action` with `specs` (each a space-separated chord).
+    fn bind<'a>(&mut self, action: &str, specs: impl Iterator<Item = &'a str>) -> Result<()> {
+        let action = ACTIONS
+            .iter()
+            .find(|(name, _, _)| *name == action)
+            .map(|(_, action, _)| *action)
+            .ok_or_else(|| anyhow!("unknown action '{}' in key bindings", action))?;
+        let mut parsed = Vec::new();
+        for spec in specs {
+            let keys = spec
+                .split_whitespace()
+                .map(KeyPress::parse)
+                .collect::<Result<Vec<_>>>()?;
+            if keys.is_empty() {
+                bail!("empty key binding for '{}'", action_name(action));
+            }
+            parsed.push(keys);
+        }
+        self.bindings.retain(|binding| binding.action != action);
+        // A key can only do one thing; the newest binding wins.
+        self.bindings
+            .retain(|binding| !parsed.contains(&binding.keys));
+        self.bindings
+            .extend(parsed.into_iter().map(|keys| Binding { keys, action }));
+        Ok(())
+    }
+
+    pub(crate) fn lookup(&self, keys: &[KeyPress]) -> KeyLookup {
+        let exact = self
+            .bindings
+            .iter()
+            .find(|binding| binding.keys == keys)
+            .map(|binding| binding.action);
+        let longer = self
+            .bindings
+            .iter()
+            .any(|binding| binding.keys.len() > keys.len() && binding.keys.starts_with(keys));
+        match (exact, longer) {
+            (Some(action), true) => KeyLookup::Ambiguous(action),
+            (Some(action), false) => KeyLookup::Action(action),
+            (None, true) => KeyLookup::Pending,
+            (None, false) => KeyLookup::Unbound,
+        }
+    }
+
+    /// All keys bound to `action`, formatted for display.
+    pub(crate) fn keys_for(&self, action: NormalAction) -> Vec<String> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.action == action)
+            .map(|binding| describe(&binding.keys))
+            .collect()
+    }
+
+    /// The primary key for the named action, used in compact footer hints.
+    pub(crate) fn hint(&self, name: &str) -> String {
+        ACTIONS
+            .iter()
+            .find(|(candidate, _, _)| *candidate == name)
+            .and_then(|(_, action, _)| self.keys_for(*action).into_iter().next())
+            .unwrap_or_else(|| String::from("—"))
+    }
+
+    /// Help-overlay sections, each listing its actions that have at least one key.
+    pub(crate) fn help_sections(&self) -> Vec<(String, Vec<(String, String)>)> {
+        HELP_SECTIONS
+            .iter()
+            .map(|(section, names)| {
+                let rows = names
+                    .iter()
+                    .filter_map(|name| ACTIONS.iter().find(|(candidate, _, _)| candidate == name))
+                    .filter_map(|(_, action, description)| {
+                        let keys = self.keys_for(*action);
+                        (!keys.is_empty()).then(|| (keys.join(" / "), description.to_string()))
+                    })
+                    .collect();
+                (section.to_string(), rows)
+            })
+            .collect()
+    }
+}
+
+fn action_name(action: NormalAction) -> &'static str {
+    ACTIONS
+        .iter()
+        .find(|(_, candidate, _)| *candidate == action)
+        .map_or("?", |(name, _, _)| name)
+}
+
+/// Pending counts and chord prefixes are shown to the user as they type, e.g. `5 g …`.
+pub(crate) fn pending_label(count: Option<usize>, keys: &[KeyPress]) -> String {
+    match count {
+        Some(count) if keys.is_empty() => format!("{} …", count),
+        Some(count) => format!("{} {} …", count, describe(keys)),
+        None => format!("{} …", describe(keys)),
+    }
+}
@@ -1,46 +1,170 @@
+use std::borrow::Cow;
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::widgets::TableState;
 
 use super::buffer::TextBuffer;
+use super::clipboard::Clipboard;
 use super::constants::*;
 use super::filters::{ActiveFilters, FilterFacets, FilterOverlay};
-use super::helpers::compose_task_capture;
+use super::helpers::{
+    completion_bucket, compose_task_capture, plain_label, schedule_bucket, sort_waiting,
+    today_bucket, waiting_heading,
+};
+use super::history::CommandHistory;
+use super::links::{extract_urls, open_in_browser};
+use super::settings::{Settings, TabSettings};
+use super::theme::Theme;
 use crate::capture::TaskInput;
 use crate::config::AppConfig;
+use crate::core::services::StatsSnapshot;
 use crate::db::Database;
-use crate::model::{ListFilters, ListOutputItem, ListView, ProjectSummary, Task, TaskStatus};
+use crate::model::{
+    ListFilters, ListOutputItem, ListView, NewTask, ProjectSummary, SavedView, SortField, Task,
+    TaskStatus,
+};
 use crate::parser;
 
+mod board;
+mod calendar;
 mod commands;
+mod date_picker;
+mod edit_form;
 mod input;
+mod keymap;
+mod project_picker;
+mod quick_filter;
+mod recurrence;
 mod render;
+mod review;
+mod sidebar;
+mod stats;
+mod status_bar;
+mod tabs;
 #[cfg(test)]
 mod tests;
+mod timer;
+mod tutorial;
 
+use board::BoardState;
+use calendar::CalendarState;
 use commands::Suggestion;
+use date_picker::DatePicker;
+use edit_form::EditForm;
+use keymap::{KeyPress, Keymap};
+use project_picker::ProjectPicker;
+use quick_filter::QuickFilter;
+use recurrence::RecurrenceEditor;
+use review::ReviewState;
+use sidebar::SidebarState;
+use status_bar::{Segment, StatusBar, TabCount};
+use timer::ActiveTimer;
+use tutorial::Tutorial;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TabKind {
+    Tasks,
+    Calendar,
+}
 
 #[derive(Debug, Clone)]
 struct ViewTab {
+    name: &'static str,
     label: &'static str,
     view: Option<ListView>,
     description: &'static str,
+    kind: TabKind,
+    sort: Option<SortChoice>,
 }
 
 impl ViewTab {
     pub(crate) fn new(
+        name: &'static str,
         label: &'static str,
         view: Option<ListView>,
         description: &'static str,
     ) -> Self {
         Self {
+            name,
             label,
             view,
             description,
+            kind: TabKind::Tasks,
+            sort: None,
+        }
+    }
+
+    pub(crate) fn calendar(
+        name: &'static str,
+        label: &'static str,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            kind: TabKind::Calendar,
+            ..Self::new(name, label, None, description)
+        }
+    }
+}
+
+/// Fields `o` steps through. `manual` (arranged in the desktop app) and `completed` (only useful
+/// on Done) stay reachable through `/sort`.
+const SORT_CYCLE: [SortField; 5] = [
+    SortField::Due,
+    SortField::Priority,
+    SortField::Created,
+    SortField::Urgency,
+    SortField::Title,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SortChoice {
+    field: SortField,
+    reverse: bool,
+}
+
+impl SortChoice {
+    fn for_view(view: Option<ListView>) -> Self {
+        let filters = ListFilters::for_view(view);
+        Self {
+            field: filters.sort,
+            reverse: filters.reverse,
         }
     }
+
+    /// Steps through `SORT_CYCLE` in both directions: due ↑, due ↓, priority ↓, … A sort outside
+    /// the cycle, such as the manual order of Next, moves on to due.
+    fn cycle(self) -> Self {
+        let Some(idx) = SORT_CYCLE.iter().position(|field| *field == self.field) else {
+            return Self {
+                field: SORT_CYCLE[0],
+                reverse: false,
+            };
+        };
+        if !self.reverse {
+            return Self {
+                field: self.field,
+                reverse: true,
+            };
+        }
+        Self {
+            field: SORT_CYCLE[(idx + 1) % SORT_CYCLE.len()],
+            reverse: false,
+        }
+    }
+
+    fn label(self) -> String {
+        let ascending = matches!(
+            self.field,
+            SortField::Due | SortField::Created | SortField::Title | SortField::Manual
+        ) != self.reverse;
+        format!(
+            "{} {}",
+            self.field.as_str(),
+            if ascending { '↑' } else { '↓' }
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -50,9 +174,18 @@ enum InputMode {
     Command,
     Filter,
     Edit,
+    EditForm,
     Inspect,
     Help,
     ConfirmDelete,
+    Sidebar,
+    QuickFilter,
+    LinkPicker,
+    MoveProject,
+    DatePicker,
+    Recurrence,
+    Review,
+    Stats,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -70,6 +203,35 @@ impl ConfirmChoice {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct NotesEditRequest {
+    pub(crate) task_id: String,
+    pub(crate) notes: String,
+}
+
+/// Scroll position and search query for the help overlay.
+#[derive(Debug, Clone, Default)]
+struct HelpState {
+    scroll: usize,
+    height: usize,
+    query: String,
+    searching: bool,
+}
+
+/// Links found in a task when there is more than one to choose from.
+#[derive(Debug, Clone)]
+struct LinkPicker {
+    urls: Vec<String>,
+    selected: usize,
+}
+
+/// A project opened from the Projects tab, shown as a filtered All view.
+#[derive(Debug, Clone)]
+struct ProjectDrill {
+    project: String,
+    from_tab: usize,
+}
+
 #[derive(Debug, Clone)]
 struct StatusMessage {
     text: String,
@@ -86,10 +248,10 @@ impl StatusMessage {
         }
     }
 
-    fn style(&self) -> Style {
+    fn style(&self, theme: &Theme) -> Style {
         match self.kind {
-            StatusKind::Info => Style::default().fg(Color::Cyan),
-            StatusKind::Error => Style::default().fg(Color::Red),
+            StatusKind::Info => theme.fg(theme.info),
+            StatusKind::Error => theme.fg(theme.danger),
         }
     }
 }
@@ -104,85 +266,288 @@ pub(crate) struct App {
     config: AppConfig,
     database: Database,
     first_run: bool,
+    theme: Theme,
+    keymap: Keymap,
+    pending_keys: Vec<KeyPress>,
+    pending_count: Option<usize>,
+    pending_since: Instant,
+    /// Set by the `preset` key; the next digit picks the slot to re-apply.
+    preset_pending: bool,
+    page_rows: usize,
     tabs: Vec<ViewTab>,
+    /// Tabs left out by `[tabs] hidden` or `/tabs hide`, kept so `/tabs show` restores them.
+    hidden_tabs: Vec<ViewTab>,
+    tab_settings: TabSettings,
     tab_index: usize,
     tasks: Vec<Task>,
     projects: Vec<ProjectSummary>,
     showing_projects: bool,
+    project_state: TableState,
+    project_drill: Option<ProjectDrill>,
     selected: usize,
     table_state: TableState,
     input_mode: InputMode,
     input: TextBuffer,
+    /// Notes typed in the Add overlay's second field; `add_notes_focus` says which field has focus.
+    add_notes: TextBuffer,
+    add_notes_focus: bool,
     suggestions: Vec<Suggestion>,
     suggestion_index: usize,
+    history: CommandHistory,
     status: Option<StatusMessage>,
     active_filters: ActiveFilters,
     filter_overlay: Option<FilterOverlay>,
+    quick_filter: Option<QuickFilter>,
+    presets: Vec<SavedView>,
+    clipboard: Clipboard,
+    link_picker: Option<LinkPicker>,
+    project_picker: Option<ProjectPicker>,
+    date_picker: Option<DatePicker>,
+    recurrence: Option<RecurrenceEditor>,
+    edit_form: Option<EditForm>,
+    review: Option<ReviewState>,
+    stats: Option<StatsSnapshot>,
+    tutorial: Option<Tutorial>,
+    timer: Option<ActiveTimer>,
+    /// Set after the first quit request while a timer runs.
+    quit_warned: bool,
+    help: HelpState,
+    data_version: i64,
+    last_poll: Instant,
+    status_bar: StatusBar,
+    tab_counts: Vec<Option<TabCount>>,
+    /// Shows open and overdue counts on tab labels (`[tabs] counts`).
+    tab_badges: bool,
+    highlight_due_rows: bool,
+    /// `[display] no_emoji`: interface labels are drawn as plain text; see [`App::label`].
+    no_emoji: bool,
+    last_refresh: chrono::DateTime<chrono::Local>,
     editing_task_id: Option<String>,
     inspect_task: Option<Task>,
     confirm_choice: ConfirmChoice,
+    /// Whether single deletes go through the confirmation overlay; `/set confirm-delete` toggles it.
+    confirm_delete: bool,
+    notes_request: Option<NotesEditRequest>,
+    board: Option<BoardState>,
+    calendar: CalendarState,
+    sidebar: SidebarState,
+    done_pages: usize,
+    done_has_more: bool,
     should_quit: bool,
 }
 
 impl App {
     pub(crate) fn new(config: AppConfig, database: Database, first_run: bool) -> Result<Self> {
-        let tabs = vec![
-            ViewTab::new("📋 All", None, "All active tasks"),
-            ViewTab::new("📥 Inbox", Some(ListView::Inbox), "Inbox items"),
-            ViewTab::new("⚡ Next", Some(ListView::Next), "Next actions"),
-            ViewTab::new("⏳ Waiting", Some(ListView::Waiting), "Waiting on others"),
-            ViewTab::new("📅 Scheduled", Some(ListView::Scheduled), "Scheduled work"),
-            ViewTab::new("🌱 Someday", Some(ListView::Someday), "Someday/Maybe"),
-            ViewTab::new("📂 Projects", Some(ListView::Projects), "Project health"),
-            ViewTab::new("✅ Done", Some(ListView::Done), "Completed tasks"),
-        ];
+        let mut settings_errors = Vec::new();
+        let settings = Settings::load(config.config_path()).unwrap_or_else(|err| {
+            settings_errors.push(format!("{:#}", err));
+            Settings::default()
+        });
+        let theme = settings.theme().unwrap_or_else(|err| {
+            settings_errors.push(format!("{:#}; using the default theme", err));
+            Theme::default()
+        });
+        let keymap = Keymap::from_settings(&settings.keys).unwrap_or_else(|err| {
+            settings_errors.push(format!("{:#}; using the default keys", err));
+            Keymap::default()
+        });
+        let (tabs, hidden_tabs) = tabs::arrange_tabs(tabs::default_tabs(), &settings.tabs)
+            .unwrap_or_else(|err| {
+                settings_errors.push(format!("{:#}; showing every tab", err));
+                (tabs::default_tabs(), Vec::new())
+            });
+        let status_bar = StatusBar::from_settings(&settings.status_bar).unwrap_or_else(|err| {
+            settings_errors.push(format!("{:#}; using the default status bar", err));
+            StatusBar::default()
+        });
+        let history = if settings.history.persist {
+            CommandHistory::persisted(config.data_dir().join(HISTORY_FILE)).unwrap_or_else(|err| {
+                settings_errors.push(format!("{:#}", err));
+                CommandHistory::in_memory()
+            })
+        } else {
+            CommandHistory::in_memory()
+        };
 
         let mut app = Self {
             config,
             database,
             first_run,
+            theme,
+            keymap,
+            pending_keys: Vec::new(),
+            pending_count: None,
+            pending_since: Instant::now(),
+            preset_pending: false,
+            page_rows: 20,
             tabs,
+            hidden_tabs,
+            tab_settings: settings.tabs.clone(),
             tab_index: 0,
             tasks: Vec::new(),
             projects: Vec::new(),
             showing_projects: false,
+            project_state: TableState::default(),
+            project_drill: None,
             selected: 0,
             table_state: TableState::default(),
             input_mode: InputMode::Normal,
             input: TextBuffer::new(),
+            add_notes: TextBuffer::new(),
+            add_notes_focus: false,
             suggestions: Vec::new(),
             suggestion_index: 0,
+            history,
             status: None,
             active_filters: ActiveFilters::default(),
             filter_overlay: None,
+            quick_filter: None,
+            presets: Vec::new(),
+            clipboard: Clipboard::default(),
+            link_picker: None,
+            project_picker: None,
+            date_picker: None,
+            recurrence: None,
+            edit_form: None,
+            review: None,
+            stats: None,
+            tutorial: None,
+            timer: None,
+            quit_warned: false,
+            help: HelpState::default(),
+            data_version: 0,
+            last_poll: Instant::now(),
+            status_bar,
+            tab_counts: Vec::new(),
+            tab_badges: settings.tabs.counts,
+            highlight_due_rows: settings.display.highlight_due_rows,
+            no_emoji: settings.display.no_emoji,
+            last_refresh: chrono::Local::now(),
             editing_task_id: None,
             inspect_task: None,
             confirm_choice: ConfirmChoice::No,
+            confirm_delete: settings.confirm.delete,
+            notes_request: None,
+            board: None,
+            calendar: CalendarState::new(chrono::Local::now().date_naive()),
+            sidebar: SidebarState::default(),
+            done_pages: 1,
+            done_has_more: false,
             should_quit: false,
         };
+        app.presets = app.database.fetch_saved_views()?;
+        app.data_version = app.database.data_version()?;
+        if !settings_errors.is_empty() {
+            app.set_status_error(settings_errors.join(" • "));
+        }
         app.refresh()?;
         Ok(app)
     }
 
+    fn current_tab_kind(&self) -> TabKind {
+        self.tabs
+            .get(self.tab_index)
+            .map(|tab| tab.kind)
+            .unwrap_or(TabKind::Tasks)
+    }
+
+    /// True when the board or calendar cursor sits on an empty slot, so no task is targeted.
+    fn selection_missing(&self) -> bool {
+        self.board_column_empty() || (self.showing_calendar() && self.calendar_entries().is_empty())
+    }
+
+    fn select_tab_by_name(&mut self, name: &str) -> Option<usize> {
+        let idx = self.tabs.iter().position(|tab| tab.name == name)?;
+        self.board = None;
+        self.project_drill = None;
+        self.tab_index = idx;
+        Some(idx)
+    }
+
+    /// Heading a task is filed under in grouped views; `None` renders a flat table.
+    fn group_label(&self, task: &Task) -> Option<String> {
+        match self.current_view() {
+            Some(ListView::Today) => today_bucket(task, chrono::Local::now().date_naive())
+                .map(|bucket| self.label(bucket.label()).into_owned()),
+            Some(ListView::Done) => Some(
+                self.label(completion_bucket(task, chrono::Local::now().date_naive()).label())
+                    .into_owned(),
+            ),
+            Some(ListView::Scheduled) => Some(
+                self.label(schedule_bucket(task, chrono::Local::now().date_naive()).label())
+                    .into_owned(),
+            ),
+            Some(ListView::Waiting) => {
+                let heading = waiting_heading(&self.tasks, task, chrono::Utc::now());
+                // The heading carries the waited-on name, so only its marker is dropped.
+                match heading.strip_prefix("👤 ") {
+                    Some(rest) if self.no_emoji => Some(rest.to_string()),
+                    _ => Some(heading),
+                }
+            }
+            _ => None,
+        }
+    }
+
     fn current_view(&self) -> Option<ListView> {
         self.tabs
             .get(self.tab_index)
             .and_then(|tab| tab.view.clone())
     }
 
-    pub(crate) fn refresh(&mut self) -> Result<()> {
-        let mut filters = ListFilters::for_view(self.current_view());
+    fn current_sort(&self) -> SortChoice {
+        self.tabs
+            .get(self.tab_index)
+            .and_then(|tab| tab.sort)
+            .unwrap_or_else(|| SortChoice::for_view(self.current_view()))
+    }
+
+    fn current_filters(&self) -> ListFilters {
+        let view = if self.board.is_some() {
+            None
+        } else {
+            self.current_view()
+        };
+        let mut filters = ListFilters::for_view(view);
         self.active_filters.apply_to(&mut filters);
+        if let Some(drill) = &self.project_drill {
+            filters.project = Some(drill.project.clone());
+        }
+        let sort = self.current_sort();
+        filters.sort = sort.field;
+        filters.reverse = sort.reverse;
+        filters
+    }
+
+    pub(crate) fn refresh(&mut self) -> Result<()> {
+        let mut filters = self.current_filters();
+        let view = filters.view.clone();
+        let paged = matches!(view, Some(ListView::Done));
+        if paged {
+            filters.limit = Some(DONE_PAGE_SIZE * self.done_pages);
+        }
         let items = self.database.fetch_tasks(&filters)?;
         self.tasks.clear();
         self.projects.clear();
-        self.showing_projects = matches!(self.current_view(), Some(ListView::Projects));
+        self.showing_projects = matches!(view, Some(ListView::Projects));
         for item in items {
             match item {
                 ListOutputItem::Task(task) => self.tasks.push(*task),
                 ListOutputItem::Project(project) => self.projects.push(project),
             }
         }
+        if matches!(view, Some(ListView::Today)) {
+            let today = chrono::Local::now().date_naive();
+            self.tasks.sort_by_key(|task| today_bucket(task, today));
+        }
+        if matches!(view, Some(ListView::Scheduled)) {
+            let today = chrono::Local::now().date_naive();
+            self.tasks.sort_by_key(|task| schedule_bucket(task, today));
+        }
+        if matches!(view, Some(ListView::Waiting)) {
+            sort_waiting(&mut self.tasks);
+        }
+        self.done_has_more = paged && Some(self.tasks.len()) == filters.limit;
 
         let had_items = !self.tasks.is_empty() || !self.projects.is_empty();
 
@@ -190,6 +555,33 @@ impl App {
             self.first_run = false;
         }
 
+        self.narrow_tasks();
+        self.clamp_selection();
+        if self.projects.is_empty() {
+            self.project_state.select(None);
+        } else {
+            let row = self
+                .project_state
+                .selected()
+                .unwrap_or(0)
+                .min(self.projects.len() - 1);
+            self.project_state.select(Some(row));
+        }
+        self.sync_board_selection();
+        self.sync_calendar_selection();
+        self.last_refresh = chrono::Local::now();
+        self.load_timer()?;
+        if self.tab_badges || self.status_bar.shows(Segment::Counts) {
+            self.tab_counts = self.count_tabs()?;
+        }
+        if self.sidebar.visible {
+            self.load_sidebar()?;
+        }
+
+        Ok(())
+    }
+
+    fn clamp_selection(&mut self) {
         if self.tasks.is_empty() {
             self.selected = 0;
             self.table_state.select(None);
@@ -199,16 +591,77 @@ impl App {
             }
             self.table_state.select(Some(self.selected));
         }
+    }
 
+    fn load_more_done(&mut self) -> Result<()> {
+        // Page from the unfiltered rows so a quick filter does not skew the offset.
+        if let Some(filter) = self.quick_filter.as_mut() {
+            self.tasks = std::mem::take(&mut filter.source);
+        }
+        let mut filters = self.current_filters();
+        filters.limit = Some(DONE_PAGE_SIZE);
+        filters.offset = self.tasks.len();
+        let items = self.database.fetch_tasks(&filters)?;
+        let before = self.tasks.len();
+        self.tasks
+            .extend(items.into_iter().filter_map(|item| match item {
+                ListOutputItem::Task(task) => Some(*task),
+                ListOutputItem::Project(_) => None,
+            }));
+        let loaded = self.tasks.len() - before;
+        self.done_pages += 1;
+        self.done_has_more = loaded == DONE_PAGE_SIZE;
+        self.narrow_tasks();
+        if loaded == 0 {
+            self.set_status_info("No more completed tasks");
+        } else {
+            self.set_status_info(format!("Loaded {} more completed tasks", loaded));
+        }
         Ok(())
     }
 
-    pub(crate) fn on_tick(&mut self) {
+    pub(crate) fn on_tick(&mut self) -> Result<()> {
         if let Some(status) = &self.status {
             if status.created_at.elapsed() > Duration::from_secs(5) {
                 self.status = None;
             }
         }
+        if self.last_poll.elapsed() >= EXTERNAL_POLL {
+            self.last_poll = Instant::now();
+            // A failed poll (say, a briefly locked database) is shown and retried on the next one.
+            if let Err(err) = self.check_external_changes() {
+                self.set_status_error(format!("Could not check for outside changes: {:#}", err));
+            }
+        }
+        self.flush_pending_keys()?;
+        self.advance_tutorial()
+    }
+
+    /// Reloads the view when the CLI or another process wrote to the database.
+    ///
+    /// Overlays that act on the selected row (edit, delete, …) hold the reload until they close,
+    /// so a refresh can never swap the task underneath them.
+    fn check_external_changes(&mut self) -> Result<()> {
+        let version = self.database.data_version()?;
+        if version == self.data_version
+            || !matches!(
+                self.input_mode,
+                InputMode::Normal | InputMode::Sidebar | InputMode::QuickFilter
+            )
+        {
+            return Ok(());
+        }
+        self.data_version = version;
+        let selected_id = self.tasks.get(self.selected).map(|task| task.id.clone());
+        self.refresh()?;
+        if let Some(id) = selected_id {
+            self.select_task_by_id(&id);
+            self.sync_board_selection();
+        }
+        if self.status.is_none() {
+            self.set_status_info("Updated with changes from another process");
+        }
+        Ok(())
     }
 
     pub(crate) fn should_quit(&self) -> bool {
@@ -224,12 +677,46 @@ impl App {
         }
     }
 
-    fn select_next(&mut self) {
+    fn select_next(&mut self) -> Result<()> {
         if self.tasks.is_empty() {
-            return;
+            return Ok(());
+        }
+        let at_bottom = self.selected + 1 >= self.tasks.len();
+        if at_bottom && self.done_has_more && matches!(self.current_view(), Some(ListView::Done)) {
+            self.load_more_done()?;
         }
         self.selected = (self.selected + 1).min(self.tasks.len() - 1);
         self.table_state.select(Some(self.selected));
+        Ok(())
+    }
+
+    /// Moves the cursor in whichever view is showing; list views page in more Done tasks.
+    fn move_selection(&mut self, delta: isize) -> Result<()> {
+        if self.board.is_some() {
+            self.board_move_row(delta);
+        } else if self.showing_calendar() {
+            self.calendar_move(delta);
+        } else if self.showing_projects {
+            if let Some(row) = self.project_state.selected() {
+                let last = self.projects.len().saturating_sub(1) as isize;
+                let row = (row as isize + delta).clamp(0, last);
+                self.project_state.select(Some(row as usize));
+            }
+        } else if delta > 0 {
+            for _ in 0..delta {
+                self.select_next()?;
+            }
+        } else {
+            for _ in 0..delta.unsigned_abs() {
+                self.select_prev();
+            }
+        }
+        Ok(())
+    }
+
+    /// Rows moved by ctrl-d/ctrl-u, based on the table height from the last draw.
+    fn half_page(&self) -> usize {
+        (self.page_rows / 2).max(1)
     }
 
     fn select_prev(&mut self) {
@@ -254,12 +741,94 @@ impl App {
         }
     }
 
+    /// Switches to the tab that lists `task` (All when its status tab is missing or its filters
+    /// hide it) and selects it; returns `false` when the active filters still hide it.
+    fn reveal_task(&mut self, task: &Task) -> Result<bool> {
+        let tab = match task.status {
+            TaskStatus::Inbox => "inbox",
+            TaskStatus::Next => "next",
+            TaskStatus::Waiting => "waiting",
+            TaskStatus::Scheduled => "scheduled",
+            TaskStatus::Someday => "someday",
+            TaskStatus::Done => "done",
+            TaskStatus::Canceled => "all",
+        };
+        if self.quick_filter.is_some() {
+            self.clear_quick_filter();
+        }
+        let mut fallback = tab != "all";
+        if self.select_tab_by_name(tab).is_none() {
+            self.select_tab_by_name("all");
+            fallback = false;
+        }
+        self.refresh()?;
+        let listed = |app: &Self| app.tasks.iter().any(|t| t.id == task.id);
+        // The Done tab pages its history; keep loading until the task turns up.
+        while !listed(self) && self.done_has_more {
+            self.load_more_done()?;
+        }
+        if !listed(self) && fallback && self.select_tab_by_name("all").is_some() {
+            self.refresh()?;
+        }
+        self.select_task_by_id(&task.id);
+        Ok(listed(self))
+    }
+
+    /// Opens the highlighted project's tasks on the All view; `back` returns to Projects.
+    fn open_selected_project(&mut self) -> Result<()> {
+        let Some(project) = self
+            .project_state
+            .selected()
+            .and_then(|row| self.projects.get(row))
+            .map(|summary| summary.project.clone())
+        else {
+            self.set_status_info("No project selected");
+            return Ok(());
+        };
+        let from_tab = self.tab_index;
+        self.select_tab_by_name("all");
+        self.project_drill = Some(ProjectDrill {
+            project: project.clone(),
+            from_tab,
+        });
+        self.selected = 0;
+        self.refresh()?;
+        self.set_status_info(format!(
+            "Project {} • {} returns to Projects",
+            project,
+            self.keymap.hint("back")
+        ));
+        Ok(())
+    }
+
+    fn close_project_drill(&mut self) -> Result<()> {
+        let Some(drill) = self.project_drill.take() else {
+            return Ok(());
+        };
+        self.board = None;
+        self.tab_index = drill.from_tab;
+        self.refresh()?;
+        if let Some(row) = self
+            .projects
+            .iter()
+            .position(|summary| summary.project == drill.project)
+        {
+            self.project_state.select(Some(row));
+        }
+        self.status = None;
+        Ok(())
+    }
+
     fn next_tab(&mut self) -> Result<()> {
+        self.board = None;
+        self.project_drill = None;
         self.tab_index = (self.tab_index + 1) % self.tabs.len();
         self.refresh()
     }
 
     fn prev_tab(&mut self) -> Result<()> {
+        self.board = None;
+        self.project_drill = None;
         if self.tab_index == 0 {
             self.tab_index = self.tabs.len() - 1;
         } else {
@@ -268,6 +837,24 @@ impl App {
         self.refresh()
     }
 
+    fn cycle_sort(&mut self) -> Result<()> {
+        let next = self.current_sort().cycle();
+        self.apply_sort(Some(next))
+    }
+
+    fn apply_sort(&mut self, sort: Option<SortChoice>) -> Result<()> {
+        if let Some(tab) = self.tabs.get_mut(self.tab_index) {
+            tab.sort = sort;
+        }
+        let selected_id = self.tasks.get(self.selected).map(|task| task.id.clone());
+        self.refresh()?;
+        if let Some(id) = selected_id {
+            self.select_task_by_id(&id);
+        }
+        self.set_status_info(format!("Sorted by {}", self.current_sort().label()));
+        Ok(())
+    }
+
     fn start_edit_current(&mut self) -> Result<()> {
         if !self.ensure_task_view(STATUS_PROJECT_EDIT) {
             return Ok(());
@@ -278,8 +865,7 @@ impl App {
         }
 
         let task = self.tasks[self.selected].clone();
-        self.begin_edit_with_task(task);
-        Ok(())
+        self.open_edit_form(task)
     }
 
     fn start_edit_by_id(&mut self, id: String) -> Result<()> {
@@ -291,15 +877,11 @@ impl App {
         {
             self.selected = idx;
             self.table_state.select(Some(idx));
-            self.begin_edit_with_task(task.clone());
-            return Ok(());
+            return self.open_edit_form(task.clone());
         }
 
         match self.database.fetch_task(&id)? {
-            Some(task) => {
-                self.begin_edit_with_task(task);
-                Ok(())
-            }
+            Some(task) => self.open_edit_form(task),
             None => {
                 self.set_status_error("Task not found");
                 Ok(())
@@ -307,13 +889,68 @@ impl App {
         }
     }
 
-    fn begin_edit_with_task(&mut self, task: Task) {
+    pub(crate) fn begin_edit_with_task(&mut self, task: Task) {
         self.input.set(compose_task_capture(&task));
         self.input_mode = InputMode::Edit;
         self.editing_task_id = Some(task.id);
         self.set_status_info(STATUS_ENTER_EDIT);
     }
 
+    fn request_notes_edit(&mut self) {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing to edit");
+            return;
+        };
+        self.notes_request = Some(NotesEditRequest {
+            task_id: task.id.clone(),
+            notes: task.notes.clone().unwrap_or_default(),
+        });
+    }
+
+    pub(crate) fn take_notes_request(&mut self) -> Option<NotesEditRequest> {
+        self.notes_request.take()
+    }
+
+    pub(crate) fn finish_notes_edit(
+        &mut self,
+        request: NotesEditRequest,
+        outcome: Result<String>,
+    ) -> Result<()> {
+        let edited = match outcome {
+            Ok(text) => text,
+            Err(err) => {
+                self.set_status_error(format!("Notes not saved: {}", err));
+                return Ok(());
+            }
+        };
+
+        let edited = edited.trim_end().to_string();
+        if edited == request.notes.trim_end() {
+            self.set_status_info("Notes unchanged");
+            return Ok(());
+        }
+
+        let existing = match self.database.fetch_task(&request.task_id)? {
+            Some(task) => task,
+            None => {
+                self.set_status_error("Task not found");
+                return Ok(());
+            }
+        };
+
+        let mut updated_task = NewTask::from(&existing);
+        updated_task.notes = Some(edited);
+        match self.database.update_task(&request.task_id, &updated_task)? {
+            Some(task) => {
+                self.refresh()?;
+                self.select_task_by_id(&request.task_id);
+                self.set_status_info(format!("Updated notes for {}", task.title));
+            }
+            None => self.set_status_error("Task not found"),
+        }
+        Ok(())
+    }
+
     fn open_filter_overlay(&mut self) -> Result<()> {
         if self.showing_projects {
             self.set_status_info("Filters are not available on the Projects summary");
@@ -337,6 +974,43 @@ impl App {
         Ok(())
     }
 
+    /// The saved preset matching the current filters, for the header indicator.
+    fn active_preset(&self) -> Option<&SavedView> {
+        if self.active_filters.is_empty() {
+            return None;
+        }
+        self.presets
+            .iter()
+            .find(|preset| ActiveFilters::from_saved(preset) == self.active_filters)
+    }
+
+    fn save_preset(&mut self, slot: u8, name: String) -> Result<()> {
+        let preset = self.active_filters.to_saved(slot, name);
+        self.database.save_view(&preset)?;
+        self.set_status_info(format!(
+            "Saved filters as preset {} ({})",
+            slot, preset.name
+        ));
+        self.presets.retain(|existing| existing.slot != slot);
+        self.presets.push(preset);
+        self.presets.sort_by_key(|preset| preset.slot);
+        Ok(())
+    }
+
+    /// Applies the preset saved under `slot`; returns false when the slot is empty.
+    fn apply_preset(&mut self, slot: u8) -> Result<bool> {
+        let Some(preset) = self.presets.iter().find(|preset| preset.slot == slot) else {
+            return Ok(false);
+        };
+        let name = preset.name.clone();
+        self.active_filters = ActiveFilters::from_saved(preset);
+        self.project_drill = None;
+        self.selected = 0;
+        self.refresh()?;
+        self.set_status_info(format!("Applied preset {} ({})", slot, name));
+        Ok(true)
+    }
+
     fn add_task(&mut self) -> Result<()> {
         let parts: Vec<String> = self
             .input
@@ -349,9 +1023,10 @@ impl App {
             return Ok(());
         }
 
+        let notes = self.add_notes.as_str().trim_end();
         let capture = TaskInput {
             text: parts,
-            notes: None,
+            notes: (!notes.is_empty()).then(|| notes.to_string()),
             project: None,
             areas: Vec::new(),
             status: None,
@@ -373,18 +1048,41 @@ impl App {
             outcome.title
         ));
         self.input.clear();
+        self.add_notes.clear();
+        self.add_notes_focus = false;
         self.input_mode = InputMode::Normal;
         self.refresh()?;
         Ok(())
     }
 
+    /// Raises or lowers the selected task's priority by `delta`, staying within 0–3.
+    fn bump_priority(&mut self, delta: i64) -> Result<()> {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing to reprioritize");
+            return Ok(());
+        };
+        let id = task.id.clone();
+        let priority = (task.priority as i64 + delta).clamp(0, 3) as u8;
+        if priority == task.priority {
+            self.set_status_info(format!("Priority already at p:{}", priority));
+            return Ok(());
+        }
+        self.database
+            .set_priority(std::slice::from_ref(&id), priority)?;
+        self.refresh()?;
+        self.select_task_by_id(&id);
+        self.sync_board_selection();
+        self.set_status_info(format!("Priority set to p:{}", priority));
+        Ok(())
+    }
+
     fn mark_next(&mut self) -> Result<()> {
         if self.tasks.is_empty() {
             self.set_status_info("Nothing to mark next");
             return Ok(());
         }
         let id = self.tasks[self.selected].id.clone();
-        let results = self.database.mark_next(&[id.clone()])?;
+        let results = self.database.mark_next(std::slice::from_ref(&id))?;
         if results.iter().any(|r| r.changed) {
             self.set_status_info("Moved task to next actions");
         } else {
@@ -400,7 +1098,7 @@ impl App {
             return Ok(());
         }
         let id = self.tasks[self.selected].id.clone();
-        let results = self.database.mark_someday(&[id.clone()])?;
+        let results = self.database.mark_someday(std::slice::from_ref(&id))?;
         if results.iter().any(|r| r.changed) {
             self.set_status_info("Moved task to Someday/Maybe");
         } else {
@@ -416,7 +1114,7 @@ impl App {
             return Ok(());
         }
         let id = self.tasks[self.selected].id.clone();
-        let results = self.database.mark_inbox(&[id.clone()])?;
+        let results = self.database.mark_inbox(std::slice::from_ref(&id))?;
         if results.iter().any(|r| r.changed) {
             self.set_status_info("Moved task back to Inbox");
         } else {
@@ -443,20 +1141,85 @@ impl App {
         Ok(())
     }
 
+    /// Copies the selected task as a capture line (`title +Project @ctx …`) or as JSON.
+    fn yank_selected(&mut self, json: bool) -> Result<()> {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing to copy");
+            return Ok(());
+        };
+        let text = if json {
+            serde_json::to_string_pretty(task)?
+        } else {
+            compose_task_capture(task)
+        };
+        let title = task.title.clone();
+        match self.clipboard.copy(&text) {
+            Ok(target) => self.set_status_info(format!(
+                "Copied {}{} to the {}",
+                title,
+                if json { " as JSON" } else { "" },
+                target
+            )),
+            Err(err) => self.set_status_error(format!("Copy failed: {:#}", err)),
+        }
+        Ok(())
+    }
+
+    /// Opens the selected task's only link, or lists them when there are several.
+    fn open_selected_links(&mut self) {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing selected");
+            return;
+        };
+        let text = format!(
+            "{}\n{}",
+            task.title,
+            task.notes.as_deref().unwrap_or_default()
+        );
+        let mut urls = extract_urls(&text);
+        match urls.len() {
+            0 => self.set_status_info("No links in this task"),
+            1 => self.open_link(&urls.remove(0)),
+            _ => {
+                self.link_picker = Some(LinkPicker { urls, selected: 0 });
+                self.input_mode = InputMode::LinkPicker;
+                self.set_status_info("Pick a link — j/k move • Enter or 1-9 open • Esc cancel");
+            }
+        }
+    }
+
+    fn open_link(&mut self, url: &str) {
+        match open_in_browser(url) {
+            Ok(()) => self.set_status_info(format!("Opened {}", url)),
+            Err(err) => self.set_status_error(format!("{:#}", err)),
+        }
+    }
+
     fn show_help_overlay(&mut self) {
         self.inspect_task = None;
+        self.help = HelpState::default();
         self.input_mode = InputMode::Help;
         self.set_status_info(STATUS_HELP);
     }
 
-    fn prompt_delete(&mut self) {
+    /// Deletes removing more than one task always ask first, whatever the setting says.
+    fn delete_needs_confirmation(&self, count: usize) -> bool {
+        count > 1 || self.confirm_delete
+    }
+
+    /// `always_confirm` asks even when the setting would skip the prompt.
+    fn prompt_delete(&mut self, always_confirm: bool) -> Result<()> {
         if self.tasks.is_empty() {
             self.set_status_info("Nothing to delete");
-            return;
+            return Ok(());
+        }
+        if !always_confirm && !self.delete_needs_confirmation(1) {
+            return self.perform_delete();
         }
         self.confirm_choice = ConfirmChoice::No;
         self.input_mode = InputMode::ConfirmDelete;
         self.set_status_info(STATUS_CONFIRM_DELETE);
+        Ok(())
     }
 
     fn mark_done(&mut self) -> Result<()> {
@@ -465,7 +1228,7 @@ impl App {
             return Ok(());
         }
         let id = self.tasks[self.selected].id.clone();
-        let results = self.database.mark_done(&[id.clone()])?;
+        let results = self.database.mark_done(std::slice::from_ref(&id))?;
         if results.iter().any(|r| r.changed) {
             self.set_status_info("Marked task as done");
         } else {
@@ -552,9 +1315,6 @@ impl App {
         if updated_task.areas.is_empty() {
             updated_task.areas = existing.areas.clone();
         }
-        if updated_task.repeat.is_none() {
-            updated_task.repeat = existing.repeat.clone();
-        }
 
         let mut final_status = existing.status;
         if updated_task.waiting_on.is_some() {
@@ -566,13 +1326,19 @@ impl App {
         }
         updated_task.status = final_status;
 
-        let updated = match self.database.update_task(&id, &updated_task)? {
+        let mut updated = match self.database.update_task(&id, &updated_task)? {
             Some(task) => task,
             None => {
                 self.set_status_error("Task not found");
                 return Ok(());
             }
         };
+        // The edit line carries the `rep:` token, so leaving it out stops the repeat.
+        if updated_task.repeat.is_none() && updated.repeat.is_some() {
+            if let Some(task) = self.database.set_repeat(&id, None)? {
+                updated = task;
+            }
+        }
 
         self.refresh()?;
         self.select_task_by_id(&id);
@@ -590,9 +1356,9 @@ impl App {
             return Ok(());
         }
         let id = self.tasks[self.selected].id.clone();
-        let results = self.database.delete_tasks(&[id.clone()])?;
+        let results = self.database.delete_tasks(std::slice::from_ref(&id))?;
         if results.iter().any(|r| r.deleted) {
-            self.set_status_info("Deleted task 🗑️");
+            self.set_status_info(self.label("Deleted task 🗑️").into_owned());
         } else {
             self.set_status_info("Task not found");
         }
@@ -600,14 +1366,33 @@ impl App {
         Ok(())
     }
 
+    /// Interface text for the current `no_emoji` setting: emoji and the words they leave empty
+    /// are dropped. Task titles, notes, and other user data must not go through this.
+    pub(crate) fn label<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.no_emoji {
+            Cow::Owned(plain_label(text))
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// A standalone marker: `emoji` normally, the `plain` wording under `no_emoji`.
+    pub(crate) fn icon<'a>(&self, emoji: &'a str, plain: &'a str) -> &'a str {
+        if self.no_emoji {
+            plain
+        } else {
+            emoji
+        }
+    }
+
     pub(crate) fn set_status_info<T: Into<String>>(&mut self, message: T) {
-        let mut text = String::from("ℹ️  ");
+        let mut text = String::from(self.icon("ℹ️  ", ""));
         text.push_str(&message.into());
         self.status = Some(StatusMessage::new(text, StatusKind::Info));
     }
 
     pub(crate) fn set_status_error<T: Into<String>>(&mut self, message: T) {
-        let mut text = String::from("⚠️  ");
+        let mut text = String::from(self.icon("⚠️  ", "Error: "));
         text.push_str(&message.into());
         self.status = Some(StatusMessage::new(text, StatusKind::Error));
     }
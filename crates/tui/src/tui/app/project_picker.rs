@@ -0,0 +1,217 @@
+use std::cmp::min;
+
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+use crate::tui::helpers::{centered_rect, fuzzy_score};
+
+use super::{App, InputMode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ProjectChoice {
+    /// Creates a project named after the query.
+    Create,
+    Existing(String),
+    Clear,
+}
+
+/// Searchable project list for reassigning the selected task; entries are rebuilt as the query
+/// changes, with "create" always on top.
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectPicker {
+    pub(crate) task_id: String,
+    pub(crate) query: String,
+    projects: Vec<String>,
+    pub(crate) entries: Vec<ProjectChoice>,
+    pub(crate) selected: usize,
+}
+
+impl ProjectPicker {
+    fn new(task_id: String, projects: Vec<String>) -> Self {
+        let mut picker = Self {
+            task_id,
+            query: String::new(),
+            projects,
+            entries: Vec::new(),
+            selected: 0,
+        };
+        picker.rebuild();
+        picker
+    }
+
+    /// Matches best-first; the cursor starts on the top match so Enter picks it.
+    fn rebuild(&mut self) {
+        let query = self.query.trim();
+        let mut scored: Vec<(i64, &String)> = self
+            .projects
+            .iter()
+            .filter_map(|project| Some((fuzzy_score(query, project)?, project)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        self.entries = vec![ProjectChoice::Create];
+        self.entries.extend(
+            scored
+                .into_iter()
+                .map(|(_, project)| ProjectChoice::Existing(project.clone())),
+        );
+        if query.is_empty() {
+            self.entries.push(ProjectChoice::Clear);
+        }
+        self.selected = usize::from(self.entries.len() > 1 && !query.is_empty());
+    }
+
+    fn label(&self, choice: &ProjectChoice, no_emoji: bool) -> String {
+        let (icon, text) = match choice {
+            ProjectChoice::Create if self.query.trim().is_empty() => {
+                ("➕ ", String::from("Create new project…"))
+            }
+            ProjectChoice::Create => ("➕ ", format!("Create project \"{}\"", self.query.trim())),
+            ProjectChoice::Existing(project) => ("📁 ", project.clone()),
+            ProjectChoice::Clear => ("", String::from("∅ No project")),
+        };
+        if no_emoji {
+            text
+        } else {
+            format!("{}{}", icon, text)
+        }
+    }
+}
+
+impl App {
+    pub(crate) fn open_project_picker(&mut self) -> Result<()> {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing to move");
+            return Ok(());
+        };
+        let task_id = task.id.clone();
+        let current = task.project.clone();
+        let projects = self
+            .database
+            .fetch_facets()?
+            .projects
+            .into_iter()
+            .map(|facet| facet.name)
+            .filter(|name| Some(name) != current.as_ref())
+            .collect();
+        self.project_picker = Some(ProjectPicker::new(task_id, projects));
+        self.input_mode = InputMode::MoveProject;
+        self.set_status_info("Type to search projects • Enter move • Esc cancel");
+        Ok(())
+    }
+
+    pub(crate) fn handle_project_picker_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(picker) = self.project_picker.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return Ok(());
+        };
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => {
+                self.project_picker = None;
+                self.input_mode = InputMode::Normal;
+                self.status = None;
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                picker.selected = min(picker.selected + 1, picker.entries.len() - 1);
+            }
+            KeyCode::Char('n') if ctrl => {
+                picker.selected = min(picker.selected + 1, picker.entries.len() - 1);
+            }
+            KeyCode::Up | KeyCode::BackTab => picker.selected = picker.selected.saturating_sub(1),
+            KeyCode::Char('p') if ctrl => picker.selected = picker.selected.saturating_sub(1),
+            KeyCode::Backspace => {
+                picker.query.pop();
+                picker.rebuild();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                picker.query.push(c);
+                picker.rebuild();
+            }
+            KeyCode::Enter => self.apply_project_choice()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn apply_project_choice(&mut self) -> Result<()> {
+        let Some(picker) = self.project_picker.as_ref() else {
+            return Ok(());
+        };
+        let project = match picker.entries.get(picker.selected) {
+            Some(ProjectChoice::Create) => {
+                let name = picker.query.trim().trim_start_matches('+').to_string();
+                if name.is_empty() {
+                    self.set_status_error("Type a name for the new project");
+                    return Ok(());
+                }
+                Some(name)
+            }
+            Some(ProjectChoice::Existing(project)) => Some(project.clone()),
+            Some(ProjectChoice::Clear) => None,
+            None => return Ok(()),
+        };
+        let task_id = picker.task_id.clone();
+        self.project_picker = None;
+        self.input_mode = InputMode::Normal;
+
+        let results = self
+            .database
+            .set_project(std::slice::from_ref(&task_id), project.as_deref())?;
+        self.refresh()?;
+        self.select_task_by_id(&task_id);
+        self.sync_board_selection();
+        if !results.iter().any(|r| r.changed) {
+            self.set_status_info("Task not found");
+        } else if let Some(project) = project {
+            self.set_status_info(format!("Moved task to +{}", project));
+        } else {
+            self.set_status_info("Removed task from its project");
+        }
+        Ok(())
+    }
+
+    pub(crate) fn draw_project_picker(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(picker) = &self.project_picker else {
+            return;
+        };
+        let width = min(area.width.saturating_sub(10), 60);
+        let height = min(
+            picker.entries.len() as u16 + 5,
+            area.height.saturating_sub(2),
+        );
+        let popup_area = centered_rect(width, height, area);
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&self.label("📁 Move to Project")))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
+        let inner = block.inner(popup_area);
+        f.render_widget(block, popup_area);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(1)])
+            .split(inner);
+
+        let query = Paragraph::new(Line::from(vec![
+            Span::styled("Search: ", self.theme.fg(self.theme.muted)),
+            Span::raw(format!("{}▏", picker.query)),
+        ]));
+        f.render_widget(query, rows[0]);
+
+        let items: Vec<ListItem> = picker
+            .entries
+            .iter()
+            .map(|choice| ListItem::new(picker.label(choice, self.no_emoji)))
+            .collect();
+        let list = List::new(items).highlight_style(self.theme.selected(self.theme.highlight));
+        let mut state = ListState::default();
+        state.select(Some(picker.selected));
+        f.render_stateful_widget(list, rows[1], &mut state);
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::model::Task;
+use crate::tui::helpers::fuzzy_score;
+
+use super::{App, InputMode};
+
+/// Type-ahead narrowing of the loaded rows; `source` keeps the unfiltered fetch so typing never
+/// goes back to the database.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct QuickFilter {
+    pub(crate) query: String,
+    pub(crate) source: Vec<Task>,
+}
+
+impl QuickFilter {
+    fn matching(&self) -> Vec<Task> {
+        self.source
+            .iter()
+            .filter(|task| fuzzy_score(&self.query, &task.title).is_some())
+            .cloned()
+            .collect()
+    }
+}
+
+impl App {
+    pub(crate) fn start_quick_filter(&mut self) {
+        if !self.ensure_task_view("Quick filter works on task views") {
+            return;
+        }
+        if self.quick_filter.is_none() {
+            self.quick_filter = Some(QuickFilter {
+                query: String::new(),
+                source: self.tasks.clone(),
+            });
+        }
+        self.input_mode = InputMode::QuickFilter;
+        self.status = None;
+    }
+
+    /// Moves freshly loaded tasks behind the active quick filter and shows only the matches.
+    pub(crate) fn narrow_tasks(&mut self) {
+        if let Some(filter) = self.quick_filter.as_mut() {
+            filter.source = std::mem::take(&mut self.tasks);
+            self.tasks = filter.matching();
+        }
+    }
+
+    pub(crate) fn clear_quick_filter(&mut self) {
+        if let Some(filter) = self.quick_filter.take() {
+            let selected_id = self.tasks.get(self.selected).map(|task| task.id.clone());
+            self.tasks = filter.source;
+            self.selected = 0;
+            if let Some(id) = selected_id {
+                self.select_task_by_id(&id);
+            }
+            self.clamp_selection();
+        }
+        self.input_mode = InputMode::Normal;
+        self.status = None;
+    }
+
+    fn update_quick_filter(&mut self, edit: impl FnOnce(&mut String)) {
+        let Some(filter) = self.quick_filter.as_mut() else {
+            return;
+        };
+        edit(&mut filter.query);
+        self.tasks = filter.matching();
+        self.selected = 0;
+        self.clamp_selection();
+    }
+
+    pub(crate) fn handle_quick_filter_mode(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => self.clear_quick_filter(),
+            KeyCode::Enter => {
+                self.input_mode = InputMode::Normal;
+                if self
+                    .quick_filter
+                    .as_ref()
+                    .is_some_and(|f| f.query.is_empty())
+                {
+                    self.clear_quick_filter();
+                }
+            }
+            KeyCode::Backspace => self.update_quick_filter(|query| {
+                query.pop();
+            }),
+            KeyCode::Down => self.move_selection(1)?,
+            KeyCode::Up => self.move_selection(-1)?,
+            KeyCode::Char(c) => self.update_quick_filter(|query| query.push(c)),
+            _ => {}
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use std::cmp::min;
+
+use anyhow::Result;
+use chrono::{Datelike, Local, Weekday};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::core::{RepeatRule, RepeatUnit};
+use crate::model::Task;
+use crate::tui::helpers::{centered_rect, repeat_summary};
+
+use super::{App, InputMode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RepeatAction {
+    Set(RepeatRule),
+    /// Opens the field for typing a rule such as `3d` or `2w:mon,thu`.
+    Custom,
+    Stop,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RepeatChoice {
+    pub(crate) key: char,
+    pub(crate) action: RepeatAction,
+}
+
+/// Overlay for choosing how a task repeats, each rule shown in words next to its `rep:` token.
+#[derive(Debug, Clone)]
+pub(crate) struct RecurrenceEditor {
+    pub(crate) task_id: String,
+    /// The task's rule as stored, such as `2w:fri`.
+    pub(crate) current: Option<String>,
+    pub(crate) choices: Vec<RepeatChoice>,
+    pub(crate) selected: usize,
+    /// The rule being typed while the custom field is open.
+    pub(crate) custom: Option<String>,
+}
+
+/// Common rules, the weekly ones on `weekday`, then a custom rule and, for a repeating task,
+/// stopping it.
+pub(crate) fn repeat_choices(weekday: Weekday, repeating: bool) -> Vec<RepeatChoice> {
+    let set = |key, rule| RepeatChoice {
+        key,
+        action: RepeatAction::Set(rule),
+    };
+    let mut choices = vec![
+        set('d', RepeatRule::new(1, RepeatUnit::Day)),
+        set(
+            'e',
+            RepeatRule::weekly_on(
+                1,
+                &[
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                ],
+            ),
+        ),
+        set('w', RepeatRule::weekly_on(1, &[weekday])),
+        set('2', RepeatRule::weekly_on(2, &[weekday])),
+        set('m', RepeatRule::new(1, RepeatUnit::Month)),
+        set('y', RepeatRule::new(1, RepeatUnit::Year)),
+        RepeatChoice {
+            key: 'c',
+            action: RepeatAction::Custom,
+        },
+    ];
+    if repeating {
+        choices.push(RepeatChoice {
+            key: 'x',
+            action: RepeatAction::Stop,
+        });
+    }
+    choices
+}
+
+/// Capitalized for a menu row: "Every 2 weeks on Friday".
+fn sentence(summary: &str) -> String {
+    let mut chars = summary.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+impl App {
+    /// Opens the editor for `task`; weekly choices fall on the weekday the task is due or
+    /// starts, or today's.
+    pub(crate) fn open_recurrence_editor(&mut self, task: &Task) {
+        let weekday = task
+            .due_at
+            .or(task.defer_until)
+            .map(|at| at.with_timezone(&Local).weekday())
+            .unwrap_or_else(|| Local::now().weekday());
+        self.recurrence = Some(RecurrenceEditor {
+            task_id: task.id.clone(),
+            current: task.repeat.clone(),
+            choices: repeat_choices(weekday, task.repeat.is_some()),
+            selected: 0,
+            custom: None,
+        });
+        self.input_mode = InputMode::Recurrence;
+        self.status = None;
+    }
+
+    fn close_recurrence_editor(&mut self) {
+        self.recurrence = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub(crate) fn handle_recurrence_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(editor) = self.recurrence.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return Ok(());
+        };
+        if let Some(custom) = editor.custom.as_mut() {
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Esc => editor.custom = None,
+                KeyCode::Backspace => {
+                    custom.pop();
+                }
+                KeyCode::Char(c) if !ctrl => custom.push(c),
+                KeyCode::Enter => {
+                    return match custom.trim().parse::<RepeatRule>() {
+                        Ok(rule) => self.apply_repeat(Some(rule)),
+                        Err(err) => {
+                            self.set_status_error(err.to_string());
+                            Ok(())
+                        }
+                    };
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let last = editor.choices.len().saturating_sub(1);
+        let chosen = match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                editor.selected = min(editor.selected + 1, last);
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                editor.selected = editor.selected.saturating_sub(1);
+                None
+            }
+            KeyCode::Enter => Some(editor.selected),
+            KeyCode::Char(c) => editor.choices.iter().position(|choice| choice.key == c),
+            KeyCode::Esc => {
+                self.close_recurrence_editor();
+                return Ok(());
+            }
+            _ => None,
+        };
+        let Some(action) =
+            chosen.and_then(|idx| editor.choices.get(idx).map(|choice| choice.action.clone()))
+        else {
+            return Ok(());
+        };
+        match action {
+            RepeatAction::Set(rule) => self.apply_repeat(Some(rule)),
+            RepeatAction::Stop => self.apply_repeat(None),
+            RepeatAction::Custom => {
+                editor.custom = Some(editor.current.clone().unwrap_or_default());
+                Ok(())
+            }
+        }
+    }
+
+    fn apply_repeat(&mut self, rule: Option<RepeatRule>) -> Result<()> {
+        let Some(editor) = self.recurrence.as_ref() else {
+            return Ok(());
+        };
+        let task_id = editor.task_id.clone();
+        let updated = self.database.set_repeat(&task_id, rule.as_ref())?;
+        self.close_recurrence_editor();
+        self.refresh()?;
+        self.select_task_by_id(&task_id);
+        self.sync_board_selection();
+        match (updated, rule) {
+            (None, _) => self.set_status_info("Task not found"),
+            (Some(_), Some(rule)) => {
+                self.set_status_info(format!("Repeats {} once done", rule.summary()))
+            }
+            (Some(_), None) => self.set_status_info("Stopped repeating"),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn draw_recurrence_editor(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(editor) = &self.recurrence else {
+            return;
+        };
+        let muted = self.theme.fg(self.theme.muted);
+        let mut lines = vec![
+            Line::from(match &editor.current {
+                Some(current) => Span::raw(format!("Repeats: {}", repeat_summary(current))),
+                None => Span::styled("Repeats: never", muted),
+            }),
+            Line::default(),
+        ];
+        for (idx, choice) in editor.choices.iter().enumerate() {
+            let (label, token) = match &choice.action {
+                RepeatAction::Set(rule) => (sentence(&rule.summary()), format!("rep:{rule}")),
+                RepeatAction::Custom => (String::from("Custom…"), String::new()),
+                RepeatAction::Stop => (String::from("Stop repeating"), String::new()),
+            };
+            let style = if idx == editor.selected && editor.custom.is_none() {
+                self.theme.selected(self.theme.highlight)
+            } else {
+                self.theme.fg(self.theme.text)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", choice.key), self.theme.fg(self.theme.info)),
+                Span::styled(format!("{:<28}", label), style),
+                Span::styled(token, muted),
+            ]));
+        }
+        if let Some(custom) = &editor.custom {
+            lines.push(Line::default());
+            lines.push(Line::from(vec![
+                Span::styled("Rule: ", muted),
+                Span::raw(format!("{custom}▏")),
+            ]));
+            lines.push(match custom.trim().parse::<RepeatRule>() {
+                Ok(rule) => Line::from(Span::styled(
+                    sentence(&rule.summary()),
+                    self.theme.fg(self.theme.success),
+                )),
+                Err(_) if custom.trim().is_empty() => {
+                    Line::from(Span::styled("e.g. 3d, weekdays, 2w:mon,thu, 1m", muted))
+                }
+                Err(err) => Line::from(Span::styled(
+                    err.to_string(),
+                    self.theme.fg(self.theme.danger),
+                )),
+            });
+        }
+
+        let width = min(area.width.saturating_sub(4), 56);
+        let height = min(lines.len() as u16 + 2, area.height.saturating_sub(2));
+        let popup_area = centered_rect(width, height, area);
+        f.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&self.label("🔁 Repeat")))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
+        f.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
@@ -1,8 +1,8 @@
 use std::cmp::min;
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::{Line, Span};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{
     Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, Tabs, Wrap,
 };
@@ -12,24 +12,27 @@ use crate::model::ListView;
 use crate::tui::constants::APP_VERSION;
 use crate::tui::filters::{FilterColumn, FilterOverlay, PRIORITY_LEVELS};
 use crate::tui::helpers::{
-    accent_title, build_help_lines, centered_rect, format_opt_datetime, format_task_detail_entries,
-    inset_rect, join_prefixed, short_id, BG_ACCENT, BG_BASE, BG_PANEL, FG_ACCENT,
+    build_help_lines, capture_chips, centered_rect, due_urgency, format_opt_datetime,
+    format_short_date, format_task_detail_entries, inset_rect, join_prefixed, short_id,
+    table_layout, ChipKind, DueUrgency, HelpLine, TableLayout,
 };
 
+use super::board::BOARD_COLUMNS;
+use super::sidebar::SIDEBAR_WIDTH;
 use super::{App, InputMode};
 
 impl App {
     pub(crate) fn draw(&mut self, f: &mut Frame<'_>) {
         let size = f.size();
         f.render_widget(Clear, size);
-        f.render_widget(Block::default().style(Style::default().bg(BG_BASE)), size);
+        f.render_widget(Block::default().style(self.theme.base_style()), size);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(1),
                 Constraint::Length(3),
                 Constraint::Min(5),
-                Constraint::Length(2),
+                Constraint::Length(self.status_bar.height()),
             ])
             .split(size);
 
@@ -37,16 +40,29 @@ impl App {
         self.draw_tabs(f, chunks[1]);
         self.draw_body(f, chunks[2]);
         self.draw_footer(f, chunks[3]);
+        if matches!(
+            self.input_mode,
+            InputMode::Normal | InputMode::QuickFilter | InputMode::Sidebar
+        ) {
+            self.draw_tutorial(f, chunks[2]);
+        }
 
         match self.input_mode {
             InputMode::Add | InputMode::Command | InputMode::Edit => {
                 self.draw_input_overlay(f, size)
             }
             InputMode::Filter => self.draw_filter_overlay(f, size),
+            InputMode::EditForm => self.draw_edit_form(f, size),
             InputMode::Inspect => self.draw_detail_overlay(f, size),
             InputMode::Help => self.draw_help_overlay(f, size),
             InputMode::ConfirmDelete => self.draw_confirm_overlay(f, size),
-            InputMode::Normal => {}
+            InputMode::LinkPicker => self.draw_link_picker(f, size),
+            InputMode::MoveProject => self.draw_project_picker(f, size),
+            InputMode::DatePicker => self.draw_date_picker(f, size),
+            InputMode::Recurrence => self.draw_recurrence_editor(f, size),
+            InputMode::Review => self.draw_review_overlay(f, size),
+            InputMode::Stats => self.draw_stats(f, size),
+            InputMode::Normal | InputMode::Sidebar | InputMode::QuickFilter => {}
         }
     }
 
@@ -56,79 +72,156 @@ impl App {
             .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
             .split(area);
 
-        let current = self
-            .tabs
-            .get(self.tab_index)
-            .map(|tab| tab.description)
-            .unwrap_or("Tasks");
+        let current = if self.board.is_some() {
+            String::from("Board")
+        } else if let Some(drill) = &self.project_drill {
+            format!("{} › {}", self.label("📂 Projects"), drill.project)
+        } else {
+            self.tabs
+                .get(self.tab_index)
+                .map(|tab| tab.description)
+                .unwrap_or("Tasks")
+                .to_string()
+        };
         let mut left_spans = vec![
             Span::styled(
-                format!(" cpt.run v{} ✅ ", APP_VERSION),
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
+                self.label(&format!(" cpt.run v{} ✅ ", APP_VERSION))
+                    .into_owned(),
+                self.theme.bold(self.theme.success),
             ),
             Span::raw(format!("— {}", current)),
             Span::raw("  "),
             Span::styled(
-                format!("💾 {}", self.config.db_path().display()),
-                Style::default().fg(Color::DarkGray),
+                format!(
+                    "{}{}",
+                    self.icon("💾 ", "DB: "),
+                    self.config.db_path().display()
+                ),
+                self.theme.fg(self.theme.muted),
             ),
         ];
 
+        if !self.showing_projects && !self.showing_calendar() {
+            left_spans.push(Span::raw("  "));
+            left_spans.push(Span::styled(
+                format!("⇅ {}", self.current_sort().label()),
+                self.theme.fg(self.theme.special),
+            ));
+        }
+
+        if let Some(filter) = self.quick_filter.as_ref().filter(|f| !f.query.is_empty()) {
+            left_spans.push(Span::raw("  "));
+            left_spans.push(Span::styled(
+                format!("{}{}", self.icon("⌕ ", "Search: "), filter.query),
+                self.theme.bold(self.theme.highlight),
+            ));
+        }
+
+        if let Some(preset) = self.active_preset() {
+            left_spans.push(Span::raw("  "));
+            left_spans.push(Span::styled(
+                format!(
+                    "{}{} {}",
+                    self.icon("★", "Preset "),
+                    preset.slot,
+                    preset.name
+                ),
+                self.theme.bold(self.theme.special),
+            ));
+        }
+
         if let Some(summary) = self.active_filters.summary() {
             left_spans.push(Span::raw("  "));
             left_spans.push(Span::styled(
-                format!("🔍 {}", summary),
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
+                format!("{}{}", self.icon("🔍 ", "Filters: "), summary),
+                self.theme.bold(self.theme.highlight),
             ));
         }
 
         let left_line = Line::from(left_spans);
         f.render_widget(
-            Paragraph::new(left_line).style(Style::default().bg(BG_BASE)),
+            Paragraph::new(left_line).style(self.theme.base_style()),
             cols[0],
         );
 
-        let right_line = Line::from(vec![
-            Span::styled("😺 /\\_/\\ ", Style::default().fg(Color::DarkGray)),
-            Span::styled(
-                "cpt",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]);
+        let mut right_spans = Vec::new();
+        if let Some(label) = self.timer_label() {
+            right_spans.push(Span::styled(
+                format!("{}  ", label),
+                self.theme.bold(self.theme.info),
+            ));
+        }
+        right_spans.push(Span::styled(
+            self.label("😺 /\\_/\\ "),
+            self.theme.fg(self.theme.muted),
+        ));
+        right_spans.push(Span::styled("cpt", self.theme.bold(self.theme.success)));
+        let right_line = Line::from(right_spans);
         let right_para = Paragraph::new(right_line)
             .alignment(ratatui::layout::Alignment::Right)
-            .style(Style::default().bg(BG_BASE));
+            .style(self.theme.base_style());
         f.render_widget(right_para, cols[1]);
     }
 
     fn draw_tabs(&self, f: &mut Frame<'_>, area: Rect) {
-        let titles: Vec<Line> = self.tabs.iter().map(|tab| Line::from(tab.label)).collect();
+        let titles: Vec<Line> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(idx, tab)| {
+                let count = self
+                    .tab_counts
+                    .get(idx)
+                    .copied()
+                    .flatten()
+                    .filter(|_| self.tab_badges);
+                let Some(count) = count else {
+                    return Line::from(self.label(tab.label).into_owned());
+                };
+                let mut spans = vec![Span::raw(format!(
+                    "{} ({}",
+                    self.label(tab.label),
+                    count.open
+                ))];
+                if count.overdue > 0 {
+                    spans.push(Span::styled(
+                        format!(" {}{}", self.icon("⏰", "!"), count.overdue),
+                        self.theme.bold(self.theme.danger),
+                    ));
+                }
+                spans.push(Span::raw(")"));
+                Line::from(spans)
+            })
+            .collect();
         let tabs = Tabs::new(titles)
             .select(self.tab_index)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(accent_title("Views"))
-                    .border_style(Style::default().fg(Color::DarkGray))
-                    .style(Style::default().bg(BG_PANEL)),
+                    .title(self.theme.title("Views"))
+                    .border_style(self.theme.border())
+                    .style(self.theme.panel_style()),
             )
-            .highlight_style(
-                Style::default()
-                    .fg(Color::Green)
-                    .bg(BG_ACCENT)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(self.theme.selected(self.theme.success));
         f.render_widget(tabs, area);
     }
 
     fn draw_body(&mut self, f: &mut Frame<'_>, area: Rect) {
-        if self.showing_projects {
+        let area = if self.sidebar.visible {
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(SIDEBAR_WIDTH), Constraint::Min(20)])
+                .split(area);
+            self.draw_sidebar(f, cols[0]);
+            cols[1]
+        } else {
+            area
+        };
+        if self.board.is_some() {
+            self.draw_board(f, area);
+        } else if self.showing_calendar() {
+            self.draw_calendar(f, area);
+        } else if self.showing_projects {
             self.draw_projects(f, area);
         } else {
             self.draw_tasks(f, area);
@@ -140,8 +233,8 @@ impl App {
             let lines = self.empty_task_state();
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
-                .style(Style::default().bg(BG_PANEL));
+                .border_style(self.theme.border())
+                .style(self.theme.panel_style());
             let inner = block.inner(area);
             f.render_widget(Clear, area);
             f.render_widget(block, area);
@@ -150,7 +243,7 @@ impl App {
                 return;
             }
 
-            let width = inner.width.min(80).max(1);
+            let width = inner.width.clamp(1, 80);
             let mut height = (lines.len() as u16).saturating_add(2).min(inner.height);
             if height < 3 && inner.height >= 3 {
                 height = 3;
@@ -161,77 +254,313 @@ impl App {
             let paragraph = Paragraph::new(lines)
                 .wrap(Wrap { trim: true })
                 .alignment(ratatui::layout::Alignment::Center)
-                .style(Style::default().bg(BG_PANEL));
+                .style(self.theme.panel_style());
             f.render_widget(paragraph, content_area);
             return;
         }
 
-        let header = Row::new(vec![
-            Cell::from("#️⃣ ID"),
-            Cell::from("📝 Title"),
-            Cell::from("🔖 Status"),
-            Cell::from("📁 Project"),
-            Cell::from("🧭 Contexts"),
-            Cell::from("# Tags"),
-            Cell::from("⏰ Due"),
-            Cell::from("⭐ Pri"),
-        ])
+        let showing_done = matches!(self.current_view(), Some(ListView::Done));
+        let layout = table_layout(area.width);
+        let when_label = if showing_done {
+            "✅ Completed"
+        } else {
+            "⏰ Due"
+        };
+        let heading = |text: &'static str| Cell::from(self.label(text));
+        let header = match layout {
+            TableLayout::Full => Row::new(vec![
+                heading("#️⃣ ID"),
+                heading("📝 Title"),
+                heading("☑ Steps"),
+                heading("🔖 Status"),
+                heading("📁 Project"),
+                heading("🧭 Contexts"),
+                heading("# Tags"),
+                heading(when_label),
+                heading("⭐ Pri"),
+            ]),
+            TableLayout::Compact => Row::new(vec![
+                heading("#️⃣ ID"),
+                heading("📝 Title"),
+                heading("🔖 Status"),
+                heading("📁 Project"),
+                heading(when_label),
+                Cell::from(self.icon("⭐", "Pri")),
+            ]),
+            TableLayout::Narrow => Row::new(vec![heading("📝 Task"), heading(when_label)]),
+        }
         .style(Style::default().add_modifier(Modifier::BOLD));
+        // Narrow rows lead with the title, so headings go in the first column there.
+        let spanning_row = |text: String| match layout {
+            TableLayout::Narrow => Row::new(vec![Cell::from(text)]),
+            _ => Row::new(vec![Cell::from(""), Cell::from(text)]),
+        };
 
-        let rows: Vec<Row> = self
-            .tasks
-            .iter()
-            .map(|task| {
-                Row::new(vec![
+        let now = chrono::Utc::now();
+        let mut rows: Vec<Row> = Vec::with_capacity(self.tasks.len());
+        let mut selected_row = None;
+        let mut current_group: Option<String> = None;
+        for (idx, task) in self.tasks.iter().enumerate() {
+            if let Some(label) = self.group_label(task) {
+                if current_group.as_deref() != Some(label.as_str()) {
+                    rows.push(
+                        spanning_row(label.clone()).style(self.theme.bold(self.theme.accent)),
+                    );
+                    current_group = Some(label);
+                }
+            }
+            if idx == self.selected {
+                selected_row = Some(rows.len());
+            }
+            let urgency = due_urgency(task, now).map(|urgency| self.urgency_style(urgency));
+            let when_at = if showing_done {
+                task.completed_at.as_ref()
+            } else {
+                task.due_at.as_ref()
+            };
+            let when = Cell::from(match layout {
+                TableLayout::Full => format_opt_datetime(when_at),
+                TableLayout::Compact | TableLayout::Narrow => format_short_date(when_at),
+            });
+            let when = match urgency {
+                Some(style) => when.style(style),
+                None => when,
+            };
+            let mut row = match layout {
+                TableLayout::Full => {
+                    let steps = match task.checklist() {
+                        Some(progress) if progress.done == progress.total => {
+                            Cell::from(progress.to_string())
+                                .style(self.theme.fg(self.theme.success))
+                        }
+                        Some(progress) => Cell::from(progress.to_string()),
+                        None => Cell::from(""),
+                    };
+                    Row::new(vec![
+                        Cell::from(short_id(&task.id)),
+                        Cell::from(task.title.clone()),
+                        steps,
+                        Cell::from(task.status.as_str()),
+                        Cell::from(task.project.clone().unwrap_or_default()),
+                        Cell::from(join_prefixed(&task.contexts, "@")),
+                        Cell::from(join_prefixed(&task.tags, "#")),
+                        when,
+                        Cell::from(task.priority.to_string()),
+                    ])
+                }
+                TableLayout::Compact => Row::new(vec![
                     Cell::from(short_id(&task.id)),
                     Cell::from(task.title.clone()),
                     Cell::from(task.status.as_str()),
                     Cell::from(task.project.clone().unwrap_or_default()),
-                    Cell::from(join_prefixed(&task.contexts, "@")),
-                    Cell::from(join_prefixed(&task.tags, "#")),
-                    Cell::from(format_opt_datetime(task.due_at.as_ref())),
+                    when,
                     Cell::from(task.priority.to_string()),
-                ])
-            })
-            .collect();
+                ]),
+                TableLayout::Narrow => {
+                    let mut meta = vec![short_id(&task.id), task.status.as_str().to_string()];
+                    if let Some(project) = &task.project {
+                        meta.push(format!("+{}", project));
+                    }
+                    if !task.contexts.is_empty() {
+                        meta.push(join_prefixed(&task.contexts, "@"));
+                    }
+                    if task.priority > 0 {
+                        meta.push(format!("p{}", task.priority));
+                    }
+                    if let Some(progress) = task.checklist() {
+                        meta.push(format!("{}{}", self.icon("☑ ", "steps "), progress));
+                    }
+                    let summary = Text::from(vec![
+                        Line::from(task.title.clone()),
+                        Line::from(Span::styled(
+                            meta.join(" · "),
+                            self.theme.fg(self.theme.muted),
+                        )),
+                    ]);
+                    Row::new(vec![Cell::from(summary), when]).height(2)
+                }
+            };
+            if let Some(style) = urgency.filter(|_| self.highlight_due_rows) {
+                row = row.style(style);
+            }
+            rows.push(row);
+        }
+        if showing_done && self.done_has_more {
+            rows.push(
+                spanning_row(String::from(
+                    "… more completed tasks — press j at the bottom to load",
+                ))
+                .style(self.theme.fg(self.theme.muted)),
+            );
+        }
+        // Group headings shift row positions, so map the task selection onto table rows.
+        self.table_state.select(selected_row);
+        // Borders plus the header row; narrow rows take two lines each.
+        let visible = area.height.saturating_sub(3) as usize;
+        self.page_rows = match layout {
+            TableLayout::Narrow => visible / 2,
+            _ => visible,
+        };
 
-        let widths = [
-            Constraint::Length(8),
-            Constraint::Percentage(35),
-            Constraint::Length(9),
-            Constraint::Percentage(15),
-            Constraint::Percentage(12),
-            Constraint::Percentage(12),
-            Constraint::Length(12),
-            Constraint::Length(4),
-        ];
+        let widths: Vec<Constraint> = match layout {
+            TableLayout::Full => vec![
+                Constraint::Length(8),
+                Constraint::Percentage(35),
+                Constraint::Length(8),
+                Constraint::Length(9),
+                Constraint::Percentage(15),
+                Constraint::Percentage(12),
+                Constraint::Percentage(12),
+                Constraint::Length(12),
+                Constraint::Length(4),
+            ],
+            TableLayout::Compact => vec![
+                Constraint::Length(8),
+                Constraint::Min(16),
+                Constraint::Length(9),
+                Constraint::Percentage(20),
+                Constraint::Length(7),
+                Constraint::Length(2),
+            ],
+            TableLayout::Narrow => vec![Constraint::Min(10), Constraint::Length(7)],
+        };
 
         let table = Table::new(rows, widths)
             .header(header)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::DarkGray))
-                    .style(Style::default().bg(BG_PANEL)),
-            )
-            .highlight_style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .bg(BG_ACCENT)
-                    .add_modifier(Modifier::BOLD),
+                    .border_style(self.theme.border())
+                    .style(self.theme.panel_style()),
             )
+            .highlight_style(self.theme.selected(self.theme.highlight))
             .highlight_symbol("▶ ");
 
         f.render_stateful_widget(table, area, &mut self.table_state);
     }
 
-    fn draw_projects(&self, f: &mut Frame<'_>, area: Rect) {
+    fn draw_board(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(board) = self.board.as_ref() else {
+            return;
+        };
+        let constraints = [Constraint::Ratio(1, BOARD_COLUMNS.len() as u32); BOARD_COLUMNS.len()];
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(area);
+
+        for (idx, (_, label)) in BOARD_COLUMNS.iter().enumerate() {
+            let entries = self.board_column(idx);
+            let is_active = board.column == idx;
+            let now = chrono::Utc::now();
+            let items: Vec<ListItem> = entries
+                .iter()
+                .map(|(_, task)| {
+                    let mut lines = vec![Line::from(task.title.clone())];
+                    let muted = self.theme.fg(self.theme.muted);
+                    let mut meta = Vec::new();
+                    if let Some(project) = &task.project {
+                        meta.push(Span::styled(format!("+{} ", project), muted));
+                    }
+                    if task.due_at.is_some() {
+                        let style = due_urgency(task, now)
+                            .map_or(muted, |urgency| self.urgency_style(urgency));
+                        meta.push(Span::styled(
+                            format!(
+                                "{}{}",
+                                self.icon("⏰ ", "due "),
+                                format_opt_datetime(task.due_at.as_ref())
+                            ),
+                            style,
+                        ));
+                    }
+                    if !meta.is_empty() {
+                        lines.push(Line::from(meta));
+                    }
+                    ListItem::new(lines)
+                })
+                .collect();
+
+            let mut state = ListState::default();
+            if is_active && !items.is_empty() {
+                state.select(Some(board.rows[idx].min(items.len() - 1)));
+            }
+
+            let title = format!("{} ({})", self.label(label), entries.len());
+            let border_style = if is_active {
+                self.theme.bold(self.theme.highlight)
+            } else {
+                self.theme.fg(self.theme.muted)
+            };
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(title)
+                        .border_style(border_style)
+                        .style(self.theme.panel_style()),
+                )
+                .highlight_style(self.theme.selected(self.theme.highlight))
+                .highlight_symbol("▶ ");
+            f.render_stateful_widget(list, columns[idx], &mut state);
+        }
+    }
+
+    /// Parsed tokens of the Add/Edit input as colored chips, or the parse error in their place.
+    fn capture_chip_line(&self) -> Line<'static> {
+        if self.input.as_str().trim().is_empty() {
+            return Line::from(Span::styled(
+                "Tokens you type show up here",
+                self.theme.fg(self.theme.muted),
+            ));
+        }
+        let chips = match capture_chips(self.input.as_str()) {
+            Ok(chips) => chips,
+            Err(err) => {
+                return Line::from(Span::styled(
+                    format!("{}{:#}", self.icon("⚠ ", "Error: "), err),
+                    self.theme.bold(self.theme.danger),
+                ))
+            }
+        };
+        if chips.is_empty() {
+            return Line::from(Span::styled(
+                "No tokens yet — plain title",
+                self.theme.fg(self.theme.muted),
+            ));
+        }
+        let mut spans = Vec::new();
+        for (kind, text) in chips {
+            let color = match kind {
+                ChipKind::Project | ChipKind::Area => self.theme.accent,
+                ChipKind::Context => self.theme.info,
+                ChipKind::Tag | ChipKind::Waiting => self.theme.special,
+                ChipKind::Due => self.theme.danger,
+                ChipKind::Defer | ChipKind::Estimate | ChipKind::Repeat => self.theme.subtle,
+                ChipKind::Priority => self.theme.highlight,
+                ChipKind::Energy => self.theme.success,
+            };
+            spans.push(Span::styled(format!(" {} ", text), self.theme.badge(color)));
+            spans.push(Span::raw(" "));
+        }
+        Line::from(spans)
+    }
+
+    /// Red for overdue, yellow for due within a day.
+    pub(crate) fn urgency_style(&self, urgency: DueUrgency) -> Style {
+        match urgency {
+            DueUrgency::Overdue => self.theme.bold(self.theme.danger),
+            DueUrgency::Soon => self.theme.fg(self.theme.highlight),
+        }
+    }
+
+    fn draw_projects(&mut self, f: &mut Frame<'_>, area: Rect) {
         if self.projects.is_empty() {
             let lines = self.empty_project_state();
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
-                .style(Style::default().bg(BG_PANEL));
+                .border_style(self.theme.border())
+                .style(self.theme.panel_style());
             let inner = block.inner(area);
             f.render_widget(Clear, area);
             f.render_widget(block, area);
@@ -240,7 +569,7 @@ impl App {
                 return;
             }
 
-            let width = inner.width.min(80).max(1);
+            let width = inner.width.clamp(1, 80);
             let mut height = (lines.len() as u16).saturating_add(2).min(inner.height);
             if height < 3 && inner.height >= 3 {
                 height = 3;
@@ -251,17 +580,17 @@ impl App {
             let paragraph = Paragraph::new(lines)
                 .wrap(Wrap { trim: true })
                 .alignment(ratatui::layout::Alignment::Center)
-                .style(Style::default().bg(BG_PANEL));
+                .style(self.theme.panel_style());
             f.render_widget(paragraph, content_area);
             return;
         }
 
         let header = Row::new(vec![
-            Cell::from("📁 Project"),
+            Cell::from(self.label("📁 Project")),
             Cell::from("∑ Total"),
-            Cell::from("⚡ Next"),
-            Cell::from("⏳ Waiting"),
-            Cell::from("🌱 Someday"),
+            Cell::from(self.label("⚡ Next")),
+            Cell::from(self.label("⏳ Waiting")),
+            Cell::from(self.label("🌱 Someday")),
         ])
         .style(Style::default().add_modifier(Modifier::BOLD));
 
@@ -287,21 +616,27 @@ impl App {
             Constraint::Length(10),
         ];
 
-        let table = Table::new(rows, widths).header(header).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(accent_title("Projects"))
-                .border_style(Style::default().fg(Color::DarkGray))
-                .style(Style::default().bg(BG_PANEL)),
-        );
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.theme.title("Projects"))
+                    .border_style(self.theme.border())
+                    .style(self.theme.panel_style()),
+            )
+            .highlight_style(self.theme.selected(self.theme.highlight))
+            .highlight_symbol("▶ ");
+        self.page_rows = area.height.saturating_sub(3) as usize;
 
-        f.render_widget(table, area);
+        f.render_stateful_widget(table, area, &mut self.project_state);
     }
 
     fn empty_task_state(&self) -> Vec<Line<'static>> {
         let view = self.current_view();
         let heading = match view {
             None => "All clear ✨",
+            Some(ListView::Today) => "Nothing pressing today 🔥",
             Some(ListView::Inbox) => "Inbox is quiet 📥",
             Some(ListView::Next) => "No next actions yet ⚡",
             Some(ListView::Waiting) => "Nothing pending ⏳",
@@ -318,6 +653,9 @@ impl App {
         ];
 
         let mut view_hints = Vec::new();
+        if matches!(view, Some(ListView::Today)) {
+            view_hints.push("Overdue work, items due today, and defers starting today land here.");
+        }
         if matches!(view, Some(ListView::Next)) {
             view_hints.push("Promote Inbox tasks with 'n' or run '/next'.");
         }
@@ -330,19 +668,15 @@ impl App {
 
         let mut lines: Vec<Line<'static>> = Vec::new();
         lines.push(Line::from(vec![Span::styled(
-            heading,
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD),
+            self.label(heading).into_owned(),
+            self.theme.bold(self.theme.success),
         )]));
         lines.push(Line::default());
 
         for hint in base_hints {
             lines.push(Line::from(vec![Span::styled(
                 hint,
-                Style::default()
-                    .fg(Color::Gray)
-                    .add_modifier(Modifier::BOLD),
+                self.theme.bold(self.theme.subtle),
             )]));
         }
 
@@ -351,9 +685,7 @@ impl App {
         }
 
         if !view_hints.is_empty() {
-            let hint_style = Style::default()
-                .fg(Color::Gray)
-                .add_modifier(Modifier::BOLD);
+            let hint_style = self.theme.bold(self.theme.subtle);
             for hint in view_hints {
                 lines.push(Line::from(vec![Span::styled(hint, hint_style)]));
             }
@@ -361,9 +693,7 @@ impl App {
 
         if self.first_run {
             lines.push(Line::default());
-            let meta_style = Style::default()
-                .fg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD);
+            let meta_style = self.theme.bold(self.theme.muted);
             lines.push(Line::from(vec![Span::styled(
                 format!(
                     "Your cpt.run data lives in `{}` (adjust with `--data-dir` or `CPT_DATA_DIR`).",
@@ -385,26 +715,21 @@ impl App {
 
         lines.push(Line::from(vec![Span::styled(
             "Projects overview",
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD),
+            self.theme.bold(self.theme.success),
         )]));
         lines.push(Line::default());
 
         if self.first_run {
             lines.push(Line::from(vec![Span::styled(
                 "Projects roll up tasks that share a `+Project` token.",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
+                self.theme.bold(self.theme.info),
             )]));
             lines.push(Line::default());
 
-            let help_intro_style = Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD);
-            let help_highlight_style = Style::default()
-                .fg(Color::Yellow)
+            let help_intro_style = self.theme.bold(self.theme.text);
+            let help_highlight_style = self
+                .theme
+                .fg(self.theme.highlight)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
             lines.push(Line::from(vec![
                 Span::styled("Need commands? ", help_intro_style),
@@ -420,9 +745,7 @@ impl App {
             "Press `Tab` to return to Inbox and add a few tasks per project to get started.",
         ];
 
-        let hint_style = Style::default()
-            .fg(Color::Gray)
-            .add_modifier(Modifier::BOLD);
+        let hint_style = self.theme.bold(self.theme.subtle);
         for hint in hints {
             lines.push(Line::from(vec![Span::styled(hint, hint_style)]));
         }
@@ -431,24 +754,42 @@ impl App {
     }
 
     fn draw_footer(&self, f: &mut Frame<'_>, area: Rect) {
-        let lines = Layout::default()
+        let lines = self.status_bar_lines();
+        let rows = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Length(1)])
+            .constraints(vec![Constraint::Length(1); lines.len()])
             .split(area);
+        for (line, row) in lines.into_iter().zip(rows.iter()) {
+            f.render_widget(Paragraph::new(line), *row);
+        }
+    }
 
-        let status_line = if let Some(status) = &self.status {
-            Line::from(vec![Span::styled(status.text.clone(), status.style())])
-        } else {
-            Line::from(vec![Span::raw("Ready")])
-        };
-
-        f.render_widget(Paragraph::new(status_line), lines[0]);
-
+    /// Key hints for the current mode, shown by the `keys` status bar segment.
+    pub(crate) fn key_hints(&self) -> String {
         let mut help = match self.input_mode {
-            InputMode::Normal => String::from(
-                "nav: tab/shift+tab views | j/k move | q quit | overlays: enter details ℹ️ | h help ❔ | actions: a add ✚ | e edit ✏️ | n next ⚡ | s someday 🌱 | i inbox 📥 | d done ✅ | x delete 🗑️ | tools: f filter 🔍 | / command ⌨️ | r refresh 🔄",
+            InputMode::Normal if self.board.is_some() => self.board_footer(),
+            InputMode::Normal if self.showing_calendar() => format!(
+                "calendar: ←/→ change range | j/k select | enter jump to task | v week/month | t today | {}/{} views | {} quit",
+                self.keymap.hint("next-tab"),
+                self.keymap.hint("prev-tab"),
+                self.keymap.hint("quit"),
+            ),
+            InputMode::Normal if self.showing_projects => format!(
+                "projects: j/k select | {} open project 📂 | {}/{} views | {} quit",
+                self.keymap.hint("details"),
+                self.keymap.hint("next-tab"),
+                self.keymap.hint("prev-tab"),
+                self.keymap.hint("quit"),
+            ),
+            InputMode::Normal if self.project_drill.is_some() => format!(
+                "{} back to Projects | {}",
+                self.keymap.hint("back"),
+                self.normal_footer()
+            ),
+            InputMode::Normal => self.normal_footer(),
+            InputMode::Add => String::from(
+                "Enter to capture ✍️ • Tab notes/title • Shift+Enter new line • Esc to cancel",
             ),
-            InputMode::Add => String::from("Enter to capture ✍️ • Esc to cancel"),
             InputMode::Command => {
                 String::from("Up/Down navigate • Tab/Right complete • Enter select/run • Esc cancel")
             }
@@ -456,22 +797,107 @@ impl App {
                 "←/→ column • ↑/↓ move • Space toggle • Enter apply • Esc cancel",
             ),
             InputMode::Edit => String::from("Enter to save ✏️ • Esc to cancel"),
-            InputMode::Inspect => String::from("Enter/Esc to close ℹ️"),
-            InputMode::Help => String::from("Enter/Esc to close ❔"),
+            InputMode::EditForm => String::from(
+                "Tab next field • ←/→ pick project/priority • Enter save ✏️ • Esc cancel",
+            ),
+            InputMode::Inspect => String::from("r repeat • Enter/Esc to close ℹ️"),
+            InputMode::Help if self.help.searching => {
+                String::from("Type to search • Enter keep • Esc clear")
+            }
+            InputMode::Help => {
+                String::from("j/k scroll • / search • Enter/Esc to close ❔")
+            }
             InputMode::ConfirmDelete => {
                 String::from("←/→ choose • Space toggle • Enter confirm • Esc cancel")
             }
+            InputMode::LinkPicker => {
+                String::from("j/k move • Enter or 1-9 open 🔗 • Esc cancel")
+            }
+            InputMode::DatePicker
+                if self
+                    .date_picker
+                    .as_ref()
+                    .is_some_and(|picker| picker.calendar.is_some()) =>
+            {
+                String::from("h/l day • j/k week • </> month • Enter pick • Esc back")
+            }
+            InputMode::DatePicker => {
+                String::from("j/k move • letter or Enter choose 📅 • Esc cancel")
+            }
+            InputMode::Recurrence
+                if self
+                    .recurrence
+                    .as_ref()
+                    .is_some_and(|editor| editor.custom.is_some()) =>
+            {
+                String::from("Type a rule • Enter save • Esc back")
+            }
+            InputMode::Recurrence => {
+                String::from("j/k move • letter or Enter choose 🔁 • Esc cancel")
+            }
+            InputMode::Stats => String::from("r reload • Esc/q close"),
+            InputMode::Review => String::from(
+                "n next • s someday • z defer a week • k keep • x delete • q stop the review",
+            ),
+            InputMode::MoveProject => {
+                String::from("Type to search • ↑/↓ move • Enter move 📁 • Esc cancel")
+            }
+            InputMode::QuickFilter => String::from(
+                "Type to narrow by title • ↑/↓ move • Enter keep • Esc clear",
+            ),
+            InputMode::Sidebar => format!(
+                "sidebar: j/k move | Enter apply/clear | Esc back to list | {} collapse",
+                self.keymap.hint("sidebar")
+            ),
         };
 
         if self.input_mode == InputMode::Normal && self.first_run {
             help.push_str(" • New here? Press `a` to capture or type `/help`");
         }
 
-        let help_line = Line::from(vec![Span::styled(
-            help,
-            Style::default().fg(Color::DarkGray),
-        )]);
-        f.render_widget(Paragraph::new(help_line), lines[1]);
+        self.label(&help).into_owned()
+    }
+
+    fn board_footer(&self) -> String {
+        let key = |name| self.keymap.hint(name);
+        format!(
+            "board: h/l column | j/k move | H/L move task ⇄ | {} back to list | {} details ℹ️ | {} edit ✏️ | {} done ✅ | {} delete 🗑️ | {} quit",
+            key("board"),
+            key("details"),
+            key("edit"),
+            key("done"),
+            key("delete"),
+            key("quit"),
+        )
+    }
+
+    /// Footer hints follow the active keymap so remapped keys show up here too.
+    fn normal_footer(&self) -> String {
+        let key = |name| self.keymap.hint(name);
+        format!(
+            "nav: {}/{} views | {}/{} move | {} quit | overlays: {} details ℹ️ | {} help ❔ | actions: {} add ✚ | {} edit ✏️ | {} notes 📝 | {} next ⚡ | {} someday 🌱 | {} inbox 📥 | {} done ✅ | {} delete 🗑️ | tools: {} filter 🔍 | {} sort ⇅ | {} board 🗂️ | {} sidebar 📚 | {} command ⌨️ | {} refresh 🔄",
+            key("next-tab"),
+            key("prev-tab"),
+            key("down"),
+            key("up"),
+            key("quit"),
+            key("details"),
+            key("help"),
+            key("add"),
+            key("edit"),
+            key("notes"),
+            key("next"),
+            key("someday"),
+            key("inbox"),
+            key("done"),
+            key("delete"),
+            key("filter"),
+            key("sort"),
+            key("board"),
+            key("sidebar"),
+            key("command"),
+            key("refresh"),
+        )
     }
 
     fn draw_input_overlay(&self, f: &mut Frame<'_>, area: Rect) {
@@ -479,7 +905,8 @@ impl App {
         let base_height: u16 = 5;
         let extra_height = match self.input_mode {
             InputMode::Command => self.suggestions.len().min(6) as u16,
-            InputMode::Add | InputMode::Edit => 8,
+            InputMode::Add => 16,
+            InputMode::Edit => 10,
             _ => 0,
         };
         let popup_area = centered_rect(width, base_height + extra_height, area);
@@ -490,25 +917,54 @@ impl App {
             InputMode::Edit => "✏️ Edit Task",
             InputMode::Normal
             | InputMode::Filter
+            | InputMode::EditForm
             | InputMode::Inspect
             | InputMode::Help
-            | InputMode::ConfirmDelete => "Input",
+            | InputMode::ConfirmDelete
+            | InputMode::Sidebar
+            | InputMode::QuickFilter
+            | InputMode::LinkPicker
+            | InputMode::MoveProject
+            | InputMode::DatePicker
+            | InputMode::Recurrence
+            | InputMode::Review
+            | InputMode::Stats => "Input",
         };
+        let editing = matches!(self.input_mode, InputMode::Add | InputMode::Edit);
         let inner = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(if self.input_mode == InputMode::Add {
+                    5
+                } else {
+                    0
+                }),
+                Constraint::Length(if editing { 2 } else { 0 }),
+                Constraint::Min(1),
+            ])
             .split(popup_area);
 
+        let notes_focus = self.input_mode == InputMode::Add && self.add_notes_focus;
+        let focus_border = |focused: bool| {
+            if focused {
+                self.theme.fg(self.theme.accent)
+            } else {
+                self.theme.border()
+            }
+        };
         f.render_widget(Clear, inner[0]);
         let input_block = Block::default()
             .borders(Borders::ALL)
-            .title(accent_title(title))
-            .border_style(Style::default().fg(Color::DarkGray))
-            .style(Style::default().bg(BG_PANEL));
+            .title(self.theme.title(&self.label(title)))
+            .border_style(focus_border(
+                self.input_mode == InputMode::Add && !notes_focus,
+            ))
+            .style(self.theme.panel_style());
         f.render_widget(input_block.clone(), inner[0]);
         let input_area = input_block.inner(inner[0]);
         let paragraph = Paragraph::new(self.input.as_str())
-            .style(Style::default().bg(BG_PANEL))
+            .style(self.theme.panel_style())
             .wrap(Wrap { trim: false });
         f.render_widget(paragraph, input_area);
 
@@ -516,44 +972,64 @@ impl App {
             let mut lines: Vec<Line> = Vec::new();
             lines.push(Line::from(vec![Span::styled(
                 "Suggestions",
-                Style::default()
-                    .fg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
+                self.theme.bold(self.theme.muted),
             )]));
             for (i, s) in self.suggestions.iter().enumerate() {
                 let style = if i == self.suggestion_index {
-                    Style::default().fg(Color::Yellow)
+                    self.theme.fg(self.theme.highlight)
                 } else {
                     Style::default()
                 };
                 lines.push(Line::from(vec![
                     Span::styled(s.fill.as_str(), style.add_modifier(Modifier::BOLD)),
                     Span::raw("  "),
-                    Span::styled(s.label.as_str(), Style::default().fg(Color::DarkGray)),
+                    Span::styled(s.label.as_str(), self.theme.fg(self.theme.muted)),
                 ]));
             }
-            f.render_widget(Clear, inner[1]);
-            let suggestion_block = Block::default().style(Style::default().bg(BG_PANEL));
-            f.render_widget(suggestion_block.clone(), inner[1]);
-            let suggestion_inner = suggestion_block.inner(inner[1]);
+            f.render_widget(Clear, inner[3]);
+            let suggestion_block = Block::default().style(self.theme.panel_style());
+            f.render_widget(suggestion_block.clone(), inner[3]);
+            let suggestion_inner = suggestion_block.inner(inner[3]);
             f.render_widget(
                 Paragraph::new(lines)
                     .wrap(Wrap { trim: true })
-                    .style(Style::default().bg(BG_PANEL)),
+                    .style(self.theme.panel_style()),
                 suggestion_inner,
             );
-        } else if matches!(self.input_mode, InputMode::Add | InputMode::Edit) {
+        } else if editing {
+            if self.input_mode == InputMode::Add {
+                f.render_widget(Clear, inner[1]);
+                let notes_block = Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.theme.title(&self.label("📝 Notes (Tab)")))
+                    .border_style(focus_border(notes_focus))
+                    .style(self.theme.panel_style());
+                let notes = if self.add_notes.as_str().is_empty() && !notes_focus {
+                    Paragraph::new(Span::styled(
+                        "Optional — Tab to write notes, Shift+Enter for new lines",
+                        self.theme.fg(self.theme.muted),
+                    ))
+                } else {
+                    Paragraph::new(self.add_notes.as_str()).wrap(Wrap { trim: false })
+                };
+                f.render_widget(notes.block(notes_block), inner[1]);
+            }
+
+            f.render_widget(Clear, inner[2]);
+            f.render_widget(
+                Paragraph::new(self.capture_chip_line())
+                    .wrap(Wrap { trim: true })
+                    .style(self.theme.panel_style()),
+                inner[2],
+            );
+
             let header = Row::new(vec![
                 Cell::from("Token"),
                 Cell::from("Example / Description"),
             ])
-            .style(
-                Style::default()
-                    .fg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .style(self.theme.bold(self.theme.muted));
 
-            let hints: [(&str, &str); 10] = [
+            let hints: [(&str, &str); 11] = [
                 ("@context", "Context label (@home, @phone)"),
                 ("+project", "Project name (+Website)"),
                 ("#tag", "Tag (#ops)"),
@@ -565,6 +1041,7 @@ impl App {
                 ("t:30m", "Time estimate (minutes or 2h)"),
                 ("e:low|med|high", "Energy level"),
                 ("p:0-3", "Priority (0=low … 3=high)"),
+                ("rep:RULE", "Repeat (daily, weekdays, 2w:fri, monthly)"),
                 ("wait:Name", "Waiting on person/contact"),
                 ("since:DATE", "Waiting since (today, +2d)"),
             ];
@@ -574,18 +1051,18 @@ impl App {
             for (tok, desc) in hints.iter() {
                 rows.push(
                     Row::new(vec![
-                        Cell::from(*tok).style(Style::default().fg(Color::Cyan)),
-                        Cell::from(*desc).style(Style::default().fg(Color::DarkGray)),
+                        Cell::from(*tok).style(self.theme.fg(self.theme.info)),
+                        Cell::from(*desc).style(self.theme.fg(self.theme.muted)),
                     ])
                     .height(1),
                 );
             }
 
             let widths = [Constraint::Length(16), Constraint::Min(10)];
-            f.render_widget(Clear, inner[1]);
-            let hint_block = Block::default().style(Style::default().bg(BG_PANEL));
-            let hint_inner = hint_block.inner(inner[1]);
-            f.render_widget(hint_block, inner[1]);
+            f.render_widget(Clear, inner[3]);
+            let hint_block = Block::default().style(self.theme.panel_style());
+            let hint_inner = hint_block.inner(inner[3]);
+            f.render_widget(hint_block, inner[3]);
             let table = Table::new(rows, widths).column_spacing(2);
             f.render_widget(table, hint_inner);
         }
@@ -623,16 +1100,14 @@ impl App {
 
         let hint_lines = Line::from(vec![Span::styled(
             "Space toggles selection • Enter applies • Esc cancels • C clears all",
-            Style::default()
-                .fg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
+            self.theme.bold(self.theme.muted),
         )]);
         let hint_area = inset_rect(hint_area, 1);
         f.render_widget(Clear, hint_area);
         f.render_widget(
             Paragraph::new(hint_lines)
                 .wrap(Wrap { trim: true })
-                .style(Style::default().bg(BG_PANEL)),
+                .style(self.theme.panel_style()),
             hint_area,
         );
     }
@@ -648,18 +1123,19 @@ impl App {
         let idx = column.index();
         let is_active = overlay.column == column;
         let selected_row = overlay.row_positions[idx];
+        let mark = if self.no_emoji { 'x' } else { '✓' };
 
         match column {
             FilterColumn::Projects => {
                 let checked = overlay.working.project.is_none();
                 items.push(ListItem::new(format!(
                     "[{}] {}",
-                    if checked { '✓' } else { ' ' },
+                    if checked { mark } else { ' ' },
                     column.clear_label()
                 )));
                 for project in &overlay.facets.projects {
                     let badge = if overlay.working.project.as_deref() == Some(project.as_str()) {
-                        '✓'
+                        mark
                     } else {
                         ' '
                     };
@@ -670,12 +1146,12 @@ impl App {
                 let checked = overlay.working.contexts.is_empty();
                 items.push(ListItem::new(format!(
                     "[{}] {}",
-                    if checked { '✓' } else { ' ' },
+                    if checked { mark } else { ' ' },
                     column.clear_label()
                 )));
                 for ctx in &overlay.facets.contexts {
                     let badge = if overlay.working.contexts.contains(ctx) {
-                        '✓'
+                        mark
                     } else {
                         ' '
                     };
@@ -686,12 +1162,12 @@ impl App {
                 let checked = overlay.working.tags.is_empty();
                 items.push(ListItem::new(format!(
                     "[{}] {}",
-                    if checked { '✓' } else { ' ' },
+                    if checked { mark } else { ' ' },
                     column.clear_label()
                 )));
                 for tag in &overlay.facets.tags {
                     let badge = if overlay.working.tags.contains(tag) {
-                        '✓'
+                        mark
                     } else {
                         ' '
                     };
@@ -702,12 +1178,12 @@ impl App {
                 let checked = overlay.working.priority_min.is_none();
                 items.push(ListItem::new(format!(
                     "[{}] {}",
-                    if checked { '✓' } else { ' ' },
+                    if checked { mark } else { ' ' },
                     column.clear_label()
                 )));
                 for priority in PRIORITY_LEVELS {
                     let badge = if overlay.working.priority_min == Some(priority) {
-                        '✓'
+                        mark
                     } else {
                         ' '
                     };
@@ -731,16 +1207,14 @@ impl App {
             column.title().to_string()
         };
         let border_style = if is_active {
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD)
+            self.theme.bold(self.theme.highlight)
         } else {
-            Style::default().fg(Color::DarkGray)
+            self.theme.fg(self.theme.muted)
         };
         let list_style = if is_active {
-            Style::default().bg(BG_PANEL)
+            self.theme.panel_style()
         } else {
-            Style::default().fg(Color::DarkGray).bg(BG_BASE)
+            self.theme.fg(self.theme.muted).bg(self.theme.base)
         };
 
         let list = List::new(items)
@@ -751,11 +1225,7 @@ impl App {
                     .border_style(border_style),
             )
             .style(list_style)
-            .highlight_style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .highlight_style(self.theme.bold(self.theme.info))
             .highlight_symbol("▶ ");
 
         f.render_widget(Clear, area);
@@ -783,9 +1253,9 @@ impl App {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(accent_title("🗒 Task Details"))
-            .border_style(Style::default().fg(Color::DarkGray))
-            .style(Style::default().bg(BG_PANEL));
+            .title(self.theme.title(&self.label("🗒 Task Details")))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
         let inner = block.inner(popup_area);
         f.render_widget(block, popup_area);
 
@@ -795,59 +1265,118 @@ impl App {
             .into_iter()
             .map(|(key, value)| {
                 Row::new(vec![
-                    Cell::from(key)
-                        .style(Style::default().fg(FG_ACCENT).add_modifier(Modifier::BOLD)),
+                    Cell::from(key).style(self.theme.bold(self.theme.accent)),
                     Cell::from(value),
                 ])
             })
             .collect();
 
         let table = Table::new(rows, [Constraint::Length(14), Constraint::Min(20)])
-            .block(Block::default().style(Style::default().bg(BG_PANEL)))
+            .block(Block::default().style(self.theme.panel_style()))
             .column_spacing(2);
         f.render_widget(table, detail_area);
     }
 
-    fn draw_help_overlay(&self, f: &mut Frame<'_>, area: Rect) {
-        let lines = build_help_lines();
+    fn draw_help_overlay(&mut self, f: &mut Frame<'_>, area: Rect) {
+        let lines = build_help_lines(self.keymap.help_sections(), &self.help.query);
         let width = min(area.width.saturating_sub(10), 100);
         let height = min(lines.len() as u16 + 4, area.height.saturating_sub(2)).max(10);
         let popup_area = centered_rect(width, height, area);
         f.render_widget(Clear, popup_area);
 
-        let block = Block::default()
+        let inner = inset_rect(Block::default().borders(Borders::ALL).inner(popup_area), 1);
+        let visible = inner.height as usize;
+        self.help.height = visible;
+        self.help.scroll = self.help.scroll.min(lines.len().saturating_sub(visible));
+
+        let mut title = self.label("⌨️ Keyboard Reference").into_owned();
+        if self.help.searching || !self.help.query.is_empty() {
+            title.push_str(&format!(" — /{}", self.help.query));
+            if self.help.searching {
+                title.push('▏');
+            }
+        }
+        let mut block = Block::default()
             .borders(Borders::ALL)
-            .title(accent_title("⌨️ Keyboard Reference"))
-            .border_style(Style::default().fg(Color::DarkGray))
-            .style(Style::default().bg(BG_PANEL));
-        let inner = block.inner(popup_area);
+            .title(self.theme.title(&title))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
+        if lines.len() > visible {
+            let last = (self.help.scroll + visible).min(lines.len());
+            block = block.title_bottom(Line::from(Span::styled(
+                format!(" {}–{} of {} ", self.help.scroll + 1, last, lines.len()),
+                self.theme.fg(self.theme.muted),
+            )));
+        }
         f.render_widget(block, popup_area);
 
-        let help_lines: Vec<Line> = lines
-            .into_iter()
-            .map(|(combo, desc)| {
-                Line::from(vec![
-                    Span::styled(combo, Style::default().fg(Color::Cyan)),
-                    Span::raw("  "),
-                    Span::raw(desc),
-                ])
-            })
-            .collect();
-
-        if inner.width < 3 || inner.height < 3 {
+        if inner.width < 3 || inner.height < 1 {
             return;
         }
 
-        let content = inset_rect(inner, 1);
-        f.render_widget(Clear, inner);
+        let help_lines: Vec<Line> = if lines.is_empty() {
+            vec![Line::from(Span::styled(
+                "No matching keys",
+                self.theme.fg(self.theme.muted),
+            ))]
+        } else {
+            lines
+                .into_iter()
+                .map(|line| match line {
+                    HelpLine::Section(section) => {
+                        Line::from(Span::styled(section, self.theme.bold(self.theme.accent)))
+                    }
+                    HelpLine::Binding(combo, desc) => Line::from(vec![
+                        Span::styled(combo, self.theme.fg(self.theme.info)),
+                        Span::raw("  "),
+                        Span::raw(desc),
+                    ]),
+                })
+                .collect()
+        };
+
         f.render_widget(
             Paragraph::new(help_lines)
-                .wrap(Wrap { trim: true })
-                .style(Style::default().bg(BG_PANEL)),
-            content,
+                .scroll((self.help.scroll as u16, 0))
+                .style(self.theme.panel_style()),
+            inner,
         );
     }
 
+    fn draw_link_picker(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(picker) = &self.link_picker else {
+            return;
+        };
+        let width = min(area.width.saturating_sub(10), 90);
+        let height = min(picker.urls.len() as u16 + 2, area.height.saturating_sub(2));
+        let popup_area = centered_rect(width, height, area);
+        f.render_widget(Clear, popup_area);
+
+        let items: Vec<ListItem> = picker
+            .urls
+            .iter()
+            .enumerate()
+            .map(|(idx, url)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{} ", idx + 1), self.theme.fg(self.theme.info)),
+                    Span::raw(url.clone()),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.theme.title(&self.label("🔗 Open Link")))
+                    .border_style(self.theme.border())
+                    .style(self.theme.panel_style()),
+            )
+            .highlight_style(self.theme.selected(self.theme.highlight));
+        let mut state = ListState::default();
+        state.select(Some(picker.selected));
+        f.render_stateful_widget(list, popup_area, &mut state);
+    }
+
     fn draw_confirm_overlay(&self, f: &mut Frame<'_>, area: Rect) {
         let width = min(area.width.saturating_sub(20), 60).max(40);
         let height = 8u16;
@@ -856,9 +1385,9 @@ impl App {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(accent_title("🗑 Confirm Deletion"))
-            .border_style(Style::default().fg(Color::Red))
-            .style(Style::default().bg(BG_PANEL));
+            .title(self.theme.title(&self.label("🗑 Confirm Deletion")))
+            .border_style(self.theme.fg(self.theme.danger))
+            .style(self.theme.panel_style());
         let inner = block.inner(popup_area);
         f.render_widget(block, popup_area);
 
@@ -871,29 +1400,23 @@ impl App {
         let mut lines = Vec::new();
         lines.push(Line::from(vec![Span::styled(
             "This action cannot be undone.",
-            Style::default().fg(Color::Red),
+            self.theme.fg(self.theme.danger),
         )]));
         lines.push(Line::from(vec![Span::styled(
             format!("Delete '{}'?", task_title),
-            Style::default().fg(Color::White),
+            self.theme.fg(self.theme.text),
         )]));
         lines.push(Line::default());
 
         let yes_style = if self.confirm_choice == super::ConfirmChoice::Yes {
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Red)
-                .add_modifier(Modifier::BOLD)
+            self.theme.badge(self.theme.danger)
         } else {
-            Style::default().fg(Color::Red)
+            self.theme.fg(self.theme.danger)
         };
         let no_style = if self.confirm_choice == super::ConfirmChoice::No {
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Gray)
-                .add_modifier(Modifier::BOLD)
+            self.theme.badge(self.theme.subtle)
         } else {
-            Style::default().fg(Color::Gray)
+            self.theme.fg(self.theme.subtle)
         };
 
         lines.push(Line::from(vec![
@@ -906,7 +1429,7 @@ impl App {
             Paragraph::new(lines)
                 .wrap(Wrap { trim: true })
                 .alignment(ratatui::layout::Alignment::Center)
-                .style(Style::default().bg(BG_PANEL)),
+                .style(self.theme.panel_style()),
             inset_rect(inner, 1),
         );
     }
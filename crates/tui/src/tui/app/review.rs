@@ -0,0 +1,194 @@
+use std::cmp::min;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::core::services::{Disposition, ReviewSession, ReviewStage};
+use crate::tui::helpers::{centered_rect, format_task_detail_entries};
+
+use super::{App, InputMode};
+
+/// A running `/review`; `delete_armed` holds the first `x` while delete confirmation is on.
+#[derive(Debug, Clone)]
+pub(crate) struct ReviewState {
+    pub(crate) session: ReviewSession,
+    pub(crate) delete_armed: bool,
+}
+
+impl App {
+    pub(crate) fn start_review(&mut self) -> Result<()> {
+        let session = ReviewSession::load(&self.database)?;
+        if session.is_finished() {
+            self.set_status_info(
+                "Nothing to review: the inbox is empty and no project has open tasks",
+            );
+            return Ok(());
+        }
+        self.review = Some(ReviewState {
+            session,
+            delete_armed: false,
+        });
+        self.input_mode = InputMode::Review;
+        self.status = None;
+        Ok(())
+    }
+
+    pub(crate) fn handle_review_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let confirm_delete = self.confirm_delete;
+        let Some(review) = self.review.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return Ok(());
+        };
+        if review.session.is_finished() {
+            if matches!(key.code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q')) {
+                return self.finish_review();
+            }
+            return Ok(());
+        }
+        let disposition = match key.code {
+            KeyCode::Char('n') => Disposition::Next,
+            KeyCode::Char('s') => Disposition::Someday,
+            KeyCode::Char('z') => Disposition::Defer(Utc::now() + Duration::days(7)),
+            KeyCode::Char('k') | KeyCode::Char(' ') => Disposition::Keep,
+            KeyCode::Char('x') if confirm_delete && !review.delete_armed => {
+                review.delete_armed = true;
+                return Ok(());
+            }
+            KeyCode::Char('x') => Disposition::Delete,
+            KeyCode::Esc | KeyCode::Char('q') => return self.finish_review(),
+            _ => {
+                review.delete_armed = false;
+                return Ok(());
+            }
+        };
+        review.delete_armed = false;
+        review.session.apply(&self.database, disposition)
+    }
+
+    /// Leaves the review (finished or not) and reports what changed.
+    fn finish_review(&mut self) -> Result<()> {
+        let Some(review) = self.review.take() else {
+            return Ok(());
+        };
+        self.input_mode = InputMode::Normal;
+        self.refresh()?;
+        let summary = review.session.summary();
+        self.set_status_info(format!(
+            "Review {}: {} reviewed — {} next, {} someday, {} deferred, {} deleted, {} kept",
+            if review.session.is_finished() {
+                "complete"
+            } else {
+                "stopped"
+            },
+            summary.reviewed(),
+            summary.next,
+            summary.someday,
+            summary.deferred,
+            summary.deleted,
+            summary.kept
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn draw_review_overlay(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(review) = &self.review else {
+            return;
+        };
+        let session = &review.session;
+        let muted = self.theme.fg(self.theme.muted);
+        let accent = self.theme.bold(self.theme.accent);
+        let (position, total) = session.progress();
+
+        let (title, lines) = match session.current() {
+            None => {
+                let summary = session.summary();
+                let row = |label: &str, count: usize| {
+                    Line::from(vec![
+                        Span::styled(format!("{:<12}", label), accent),
+                        Span::raw(count.to_string()),
+                    ])
+                };
+                let lines = vec![
+                    Line::from(format!("Reviewed {} items.", summary.reviewed())),
+                    Line::default(),
+                    row("Next", summary.next),
+                    row("Someday", summary.someday),
+                    row("Deferred", summary.deferred),
+                    row("Deleted", summary.deleted),
+                    row("Kept", summary.kept),
+                    Line::default(),
+                    Line::from(Span::styled("Enter to close", muted)),
+                ];
+                (String::from("🧭 Weekly Review — done"), lines)
+            }
+            Some(item) => {
+                let stage = match &item.stage {
+                    ReviewStage::Inbox => self.label("📥 Inbox triage").into_owned(),
+                    ReviewStage::Project(project) => {
+                        format!("{}{}", self.icon("📁 ", "Project: "), project)
+                    }
+                };
+                let mut lines = vec![
+                    Line::from(vec![
+                        Span::styled(stage, accent),
+                        Span::styled(
+                            format!("  ({} left here)", session.remaining_in_stage()),
+                            muted,
+                        ),
+                    ]),
+                    Line::default(),
+                ];
+                lines.extend(
+                    format_task_detail_entries(&item.task)
+                        .into_iter()
+                        .filter(|(key, _)| key != "ID")
+                        .map(|(key, value)| {
+                            Line::from(vec![
+                                Span::styled(format!("{:<12}", key), accent),
+                                Span::raw(value),
+                            ])
+                        }),
+                );
+                lines.push(Line::default());
+                let delete_hint = if review.delete_armed {
+                    Span::styled("x again to delete", self.theme.bold(self.theme.danger))
+                } else {
+                    Span::styled("x delete", muted)
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        "n next • s someday • z defer a week • k/Space keep • ",
+                        muted,
+                    ),
+                    delete_hint,
+                    Span::styled(" • q stop", muted),
+                ]));
+                (
+                    format!("🧭 Weekly Review — {} of {}", position + 1, total),
+                    lines,
+                )
+            }
+        };
+
+        let width = min(area.width.saturating_sub(10), 80);
+        let height = min(lines.len() as u16 + 4, area.height.saturating_sub(2));
+        let popup_area = centered_rect(width, height, area);
+        f.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&self.label(&title)))
+            .border_style(self.theme.fg(self.theme.accent))
+            .style(self.theme.panel_style());
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            popup_area,
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
+use ratatui::Frame;
+
+use crate::tui::filters::ActiveFilters;
+
+use super::input::NormalAction;
+use super::keymap::{KeyLookup, KeyPress};
+use super::{App, InputMode};
+
+pub(crate) const SIDEBAR_WIDTH: u16 = 28;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SidebarTarget {
+    Everything,
+    Project(String),
+    Context(String),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SidebarEntry {
+    pub(crate) target: SidebarTarget,
+    pub(crate) count: Option<usize>,
+}
+
+impl SidebarEntry {
+    fn label(&self) -> String {
+        let name = match &self.target {
+            SidebarTarget::Everything => String::from("Everything"),
+            SidebarTarget::Project(project) => project.clone(),
+            SidebarTarget::Context(context) => format!("@{}", context),
+        };
+        match self.count {
+            Some(count) => format!("{} ({})", name, count),
+            None => name,
+        }
+    }
+
+    fn is_active(&self, filters: &ActiveFilters) -> bool {
+        match &self.target {
+            SidebarTarget::Everything => filters.project.is_none() && filters.contexts.is_empty(),
+            SidebarTarget::Project(project) => filters.project.as_ref() == Some(project),
+            SidebarTarget::Context(context) => {
+                filters.contexts.len() == 1 && filters.contexts.contains(context)
+            }
+        }
+    }
+
+    fn section(&self) -> Option<&'static str> {
+        match self.target {
+            SidebarTarget::Everything => None,
+            SidebarTarget::Project(_) => Some("📁 Projects"),
+            SidebarTarget::Context(_) => Some("🧭 Contexts"),
+        }
+    }
+}
+
+/// Collapsible project/context list; choices feed `active_filters`, so they follow every tab.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SidebarState {
+    pub(crate) visible: bool,
+    pub(crate) entries: Vec<SidebarEntry>,
+    pub(crate) selected: usize,
+}
+
+impl App {
+    /// Shows and focuses the sidebar; the same key pressed inside it collapses it again.
+    pub(crate) fn open_sidebar(&mut self) -> Result<()> {
+        self.sidebar.visible = true;
+        self.load_sidebar()?;
+        self.input_mode = InputMode::Sidebar;
+        self.set_status_info("Sidebar — j/k move • Enter apply • Esc back to the list");
+        Ok(())
+    }
+
+    fn collapse_sidebar(&mut self) {
+        self.sidebar.visible = false;
+        self.input_mode = InputMode::Normal;
+        self.status = None;
+    }
+
+    /// Rebuilds entries from the facet counts, keeping the cursor on the same target.
+    pub(crate) fn load_sidebar(&mut self) -> Result<()> {
+        let facets = self.database.fetch_facets()?;
+        let current = self
+            .sidebar
+            .entries
+            .get(self.sidebar.selected)
+            .map(|entry| entry.target.clone());
+        let mut entries = vec![SidebarEntry {
+            target: SidebarTarget::Everything,
+            count: None,
+        }];
+        entries.extend(facets.projects.into_iter().map(|facet| SidebarEntry {
+            target: SidebarTarget::Project(facet.name),
+            count: Some(facet.count),
+        }));
+        entries.extend(facets.contexts.into_iter().map(|facet| SidebarEntry {
+            target: SidebarTarget::Context(facet.name),
+            count: Some(facet.count),
+        }));
+        self.sidebar.selected = current
+            .and_then(|target| entries.iter().position(|entry| entry.target == target))
+            .unwrap_or(0);
+        self.sidebar.entries = entries;
+        Ok(())
+    }
+
+    pub(crate) fn handle_sidebar_mode(&mut self, key: KeyEvent) -> Result<()> {
+        if self.keymap.lookup(&[KeyPress::from(&key)])
+            == KeyLookup::Action(NormalAction::ToggleSidebar)
+        {
+            self.collapse_sidebar();
+            return Ok(());
+        }
+        let last = self.sidebar.entries.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.sidebar.selected = (self.sidebar.selected + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.sidebar.selected = self.sidebar.selected.saturating_sub(1);
+            }
+            KeyCode::Home => self.sidebar.selected = 0,
+            KeyCode::End => self.sidebar.selected = last,
+            KeyCode::Enter | KeyCode::Char(' ') => self.apply_sidebar_entry()?,
+            KeyCode::Esc | KeyCode::Tab | KeyCode::Right => {
+                self.input_mode = InputMode::Normal;
+                self.status = None;
+            }
+            KeyCode::Char('q') => self.request_quit(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Applies the highlighted entry as a filter; choosing the active entry again clears it.
+    fn apply_sidebar_entry(&mut self) -> Result<()> {
+        let Some(entry) = self.sidebar.entries.get(self.sidebar.selected).cloned() else {
+            return Ok(());
+        };
+        let active = entry.is_active(&self.active_filters);
+        match entry.target {
+            SidebarTarget::Everything => {
+                self.active_filters.project = None;
+                self.active_filters.contexts.clear();
+            }
+            SidebarTarget::Project(project) => {
+                self.active_filters.project = (!active).then_some(project);
+            }
+            SidebarTarget::Context(context) => {
+                self.active_filters.contexts.clear();
+                if !active {
+                    self.active_filters.contexts.insert(context);
+                }
+            }
+        }
+        // The sidebar's project choice replaces a Projects-tab drill-down.
+        self.project_drill = None;
+        self.selected = 0;
+        self.refresh()?;
+        let status = match self.active_filters.summary() {
+            Some(summary) => format!("Applied filters: {summary}"),
+            None => String::from("Cleared filters"),
+        };
+        self.set_status_info(status);
+        Ok(())
+    }
+
+    pub(crate) fn draw_sidebar(&self, f: &mut Frame<'_>, area: Rect) {
+        let mut items = Vec::new();
+        let mut selected_row = None;
+        let mut section = None;
+        for (idx, entry) in self.sidebar.entries.iter().enumerate() {
+            if entry.section() != section {
+                section = entry.section();
+                if let Some(heading) = section {
+                    items.push(ListItem::new(Line::from(Span::styled(
+                        self.label(heading),
+                        self.theme.bold(self.theme.accent),
+                    ))));
+                }
+            }
+            if idx == self.sidebar.selected {
+                selected_row = Some(items.len());
+            }
+            let marker = if entry.is_active(&self.active_filters) {
+                "● "
+            } else {
+                "  "
+            };
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled(marker, self.theme.fg(self.theme.success)),
+                Span::raw(entry.label()),
+            ])));
+        }
+
+        let focused = self.input_mode == InputMode::Sidebar;
+        let border = if focused {
+            self.theme.fg(self.theme.accent)
+        } else {
+            self.theme.border()
+        };
+        let mut list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(self.theme.title("Browse"))
+                .border_style(border)
+                .style(self.theme.panel_style()),
+        );
+        if focused {
+            list = list.highlight_style(self.theme.selected(self.theme.highlight));
+        }
+        let mut state = ListState::default();
+        state.select(selected_row);
+        f.render_stateful_widget(list, area, &mut state);
+    }
+}
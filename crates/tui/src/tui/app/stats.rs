@@ -0,0 +1,187 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Clear, Paragraph, Sparkline};
+use ratatui::Frame;
+
+use crate::core::services::stats::format_age;
+use crate::core::services::StatsService;
+
+use super::{App, InputMode};
+
+/// Days of completion history on the sparkline.
+const HISTORY_DAYS: usize = 14;
+/// Projects shown on the bar chart; the rest are summed into "other".
+const TOP_PROJECTS: usize = 8;
+
+impl App {
+    pub(crate) fn open_stats(&mut self) -> Result<()> {
+        self.stats = Some(StatsService::new(&self.database).snapshot(HISTORY_DAYS)?);
+        self.input_mode = InputMode::Stats;
+        self.status = None;
+        Ok(())
+    }
+
+    pub(crate) fn handle_stats_mode(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.stats = None;
+                self.input_mode = InputMode::Normal;
+            }
+            KeyCode::Char('r') => {
+                self.stats = Some(StatsService::new(&self.database).snapshot(HISTORY_DAYS)?);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub(crate) fn draw_stats(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(stats) = &self.stats else {
+            return;
+        };
+        let popup_area = area.inner(&Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+        f.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&self.label("📊 Stats")))
+            .border_style(self.theme.border())
+            .style(self.theme.panel_style());
+        let inner = block.inner(popup_area);
+        f.render_widget(block, popup_area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(5),
+                Constraint::Min(8),
+                Constraint::Length(7),
+            ])
+            .split(inner);
+        let panel = |title: String| {
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(self.theme.border())
+        };
+
+        let sparkline = Sparkline::default()
+            .block(panel(format!(
+                " Completed — last {} days: {} (today {}) ",
+                HISTORY_DAYS,
+                stats.completed_total(),
+                stats.completions.last().copied().unwrap_or(0)
+            )))
+            .data(&stats.completions)
+            .style(self.theme.fg(self.theme.success));
+        f.render_widget(sparkline, rows[0]);
+
+        let charts = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[1]);
+        let status_bars: Vec<Bar> = stats
+            .by_status
+            .iter()
+            .map(|(status, count)| {
+                Bar::default()
+                    .value(*count as u64)
+                    .label(Line::from(status.as_str()))
+            })
+            .collect();
+        let by_status = BarChart::default()
+            .block(panel(String::from(" By status ")))
+            .data(BarGroup::default().bars(&status_bars))
+            .bar_width(9)
+            .bar_gap(1)
+            .bar_style(self.theme.fg(self.theme.accent))
+            .value_style(self.theme.badge(self.theme.accent))
+            .label_style(self.theme.fg(self.theme.muted));
+        f.render_widget(by_status, charts[0]);
+
+        let mut projects: Vec<(String, u64)> = stats
+            .by_project
+            .iter()
+            .take(TOP_PROJECTS)
+            .map(|(name, count)| (name.clone(), *count as u64))
+            .collect();
+        let other: usize = stats
+            .by_project
+            .iter()
+            .skip(TOP_PROJECTS)
+            .map(|(_, count)| count)
+            .sum();
+        if other > 0 {
+            projects.push((String::from("other"), other as u64));
+        }
+        let project_bars: Vec<Bar> = projects
+            .iter()
+            .map(|(name, count)| Bar::default().value(*count).label(Line::from(name.clone())))
+            .collect();
+        if project_bars.is_empty() {
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    "No open tasks belong to a project",
+                    self.theme.fg(self.theme.muted),
+                ))
+                .block(panel(String::from(" Open tasks by project "))),
+                charts[1],
+            );
+        } else {
+            let by_project = BarChart::default()
+                .block(panel(String::from(" Open tasks by project ")))
+                .data(BarGroup::default().bars(&project_bars))
+                .direction(Direction::Horizontal)
+                .bar_width(1)
+                .bar_gap(0)
+                .bar_style(self.theme.fg(self.theme.info))
+                .value_style(self.theme.badge(self.theme.info))
+                .label_style(self.theme.fg(self.theme.muted));
+            f.render_widget(by_project, charts[1]);
+        }
+
+        let label = self.theme.bold(self.theme.accent);
+        let muted = self.theme.fg(self.theme.muted);
+        let mut lines = vec![Line::from(vec![
+            Span::styled("Inbox        ", label),
+            Span::raw(match stats.average_inbox_age_secs {
+                Some(age) => format!(
+                    "{} tasks, average age {}",
+                    stats.inbox_count,
+                    format_age(age)
+                ),
+                None => String::from("empty"),
+            }),
+        ])];
+        lines.push(Line::from(vec![
+            Span::styled("Waiting      ", label),
+            Span::raw(match stats.average_waiting_secs() {
+                Some(wait) => format!(
+                    "{} tasks, average wait {}",
+                    stats.waiting.len(),
+                    format_age(wait)
+                ),
+                None => String::from("nothing pending"),
+            }),
+        ]));
+        for entry in stats.waiting.iter().take(3) {
+            let who = entry
+                .waiting_on
+                .as_deref()
+                .map(|who| format!(" (on {})", who))
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:>5}  ", format_age(entry.waited_secs)), muted),
+                Span::raw(format!("{}{}", entry.title, who)),
+            ]));
+        }
+        f.render_widget(
+            Paragraph::new(lines).block(panel(String::from(" Flow "))),
+            rows[2],
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::{anyhow, Result};
+use ratatui::text::{Line, Span};
+
+use crate::model::ListView;
+use crate::tui::settings::StatusBarSettings;
+
+use super::{App, InputMode, TabKind};
+
+pub(crate) const SEGMENTS: [&str; 7] = [
+    "status", "filters", "counts", "timer", "sort", "updated", "keys",
+];
+
+/// One piece of the footer; lines are built from these in the configured order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment {
+    /// The transient status message, or "Ready".
+    Status,
+    /// Active filters, preset, and quick-filter query.
+    Filters,
+    /// Open tasks per view under the current filters.
+    Counts,
+    /// The running timer and its elapsed time.
+    Timer,
+    Sort,
+    /// When the view was last loaded from the database.
+    Updated,
+    /// Key hints for the current mode.
+    Keys,
+}
+
+impl Segment {
+    fn parse(name: &str) -> Result<Self> {
+        Ok(match name.trim().to_ascii_lowercase().as_str() {
+            "status" => Segment::Status,
+            "filters" => Segment::Filters,
+            "counts" => Segment::Counts,
+            "timer" => Segment::Timer,
+            "sort" => Segment::Sort,
+            "updated" => Segment::Updated,
+            "keys" => Segment::Keys,
+            other => {
+                return Err(anyhow!(
+                    "unknown status bar segment '{}' (expected one of: {})",
+                    other,
+                    SEGMENTS.join(", ")
+                ))
+            }
+        })
+    }
+}
+
+/// Footer layout: each non-empty line is one terminal row.
+#[derive(Debug, Clone)]
+pub(crate) struct StatusBar {
+    lines: Vec<Vec<Segment>>,
+}
+
+impl Default for StatusBar {
+    fn default() -> Self {
+        Self {
+            lines: vec![vec![Segment::Status, Segment::Counts], vec![Segment::Keys]],
+        }
+    }
+}
+
+impl StatusBar {
+    pub(crate) fn from_settings(settings: &StatusBarSettings) -> Result<Self> {
+        let parse = |names: &[String]| -> Result<Vec<Segment>> {
+            names.iter().map(|name| Segment::parse(name)).collect()
+        };
+        let lines: Vec<Vec<Segment>> = vec![parse(&settings.top)?, parse(&settings.bottom)?];
+        Ok(Self {
+            lines: lines.into_iter().filter(|line| !line.is_empty()).collect(),
+        })
+    }
+
+    pub(crate) fn height(&self) -> u16 {
+        self.lines.len() as u16
+    }
+
+    pub(crate) fn shows(&self, segment: Segment) -> bool {
+        self.lines.iter().flatten().any(|shown| *shown == segment)
+    }
+}
+
+/// Open tasks on a tab and how many of them are past due.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct TabCount {
+    pub(crate) open: usize,
+    pub(crate) overdue: usize,
+}
+
+impl App {
+    /// Open and overdue counts for each list tab, honoring the active filters.
+    pub(crate) fn count_tabs(&self) -> Result<Vec<Option<TabCount>>> {
+        let now = chrono::Utc::now();
+        self.tabs
+            .iter()
+            .map(|tab| {
+                if tab.kind != TabKind::Tasks
+                    || matches!(tab.view, Some(ListView::Projects | ListView::Done))
+                {
+                    return Ok(None);
+                }
+                let mut filters = crate::model::ListFilters::for_view(tab.view.clone());
+                self.active_filters.apply_to(&mut filters);
+                let open = self.database.count_tasks(&filters)?;
+                filters.due_before = Some(now);
+                let overdue = if open == 0 {
+                    0
+                } else {
+                    self.database.count_tasks(&filters)?
+                };
+                Ok(Some(TabCount { open, overdue }))
+            })
+            .collect()
+    }
+
+    pub(crate) fn status_bar_lines(&self) -> Vec<Line<'static>> {
+        let separator = Span::styled(" │ ", self.theme.fg(self.theme.muted));
+        self.status_bar
+            .lines
+            .iter()
+            .map(|segments| {
+                let mut spans = Vec::new();
+                for segment in segments {
+                    let parts = self.segment_spans(*segment);
+                    if parts.is_empty() {
+                        continue;
+                    }
+                    if !spans.is_empty() {
+                        spans.push(separator.clone());
+                    }
+                    spans.extend(parts);
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    fn segment_spans(&self, segment: Segment) -> Vec<Span<'static>> {
+        let muted = self.theme.fg(self.theme.muted);
+        match segment {
+            Segment::Status => self.status_spans(),
+            Segment::Filters => {
+                let mut parts = Vec::new();
+                if let Some(preset) = self.active_preset() {
+                    parts.push(format!(
+                        "{}{} {}",
+                        self.icon("★", "Preset "),
+                        preset.slot,
+                        preset.name
+                    ));
+                } else if let Some(summary) = self.active_filters.summary() {
+                    parts.push(summary);
+                }
+                if let Some(filter) = self.quick_filter.as_ref().filter(|f| !f.query.is_empty()) {
+                    parts.push(format!("{}{}", self.icon("⌕ ", "Search: "), filter.query));
+                }
+                if parts.is_empty() {
+                    return Vec::new();
+                }
+                vec![Span::styled(
+                    format!("{}{}", self.icon("🔍 ", "Filters: "), parts.join(" ")),
+                    self.theme.fg(self.theme.highlight),
+                )]
+            }
+            Segment::Counts => {
+                let counts: Vec<String> = self
+                    .tabs
+                    .iter()
+                    .zip(&self.tab_counts)
+                    .filter_map(|(tab, count)| {
+                        count.map(|count| format!("{} {}", self.label(tab.label), count.open))
+                    })
+                    .collect();
+                if counts.is_empty() {
+                    return Vec::new();
+                }
+                vec![Span::styled(
+                    counts.join(" · "),
+                    self.theme.fg(self.theme.subtle),
+                )]
+            }
+            Segment::Timer => self
+                .timer_label()
+                .map(|label| vec![Span::styled(label, self.theme.bold(self.theme.info))])
+                .unwrap_or_default(),
+            Segment::Sort if self.showing_projects || self.showing_calendar() => Vec::new(),
+            Segment::Sort => vec![Span::styled(
+                format!("⇅ {}", self.current_sort().label()),
+                self.theme.fg(self.theme.special),
+            )],
+            Segment::Updated => vec![Span::styled(
+                format!("↻ {}", self.last_refresh.format("%H:%M:%S")),
+                muted,
+            )],
+            Segment::Keys => vec![Span::styled(self.key_hints(), muted)],
+        }
+    }
+
+    fn status_spans(&self) -> Vec<Span<'static>> {
+        let quick_query = self
+            .quick_filter
+            .as_ref()
+            .filter(|_| self.input_mode == InputMode::QuickFilter);
+        if let Some(filter) = quick_query {
+            vec![
+                Span::styled(
+                    format!("{} {}▏", self.keymap.hint("quick-filter"), filter.query),
+                    self.theme.bold(self.theme.highlight),
+                ),
+                Span::styled(
+                    format!("  {} of {} tasks", self.tasks.len(), filter.source.len()),
+                    self.theme.fg(self.theme.muted),
+                ),
+            ]
+        } else if let Some(status) = &self.status {
+            vec![Span::styled(status.text.clone(), status.style(&self.theme))]
+        } else {
+            vec![Span::raw("Ready")]
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use anyhow::{bail, Result};
+
+use crate::model::ListView;
+use crate::tui::settings::TabSettings;
+
+use super::{App, ViewTab};
+
+/// Every tab in its built-in order; `[tabs]` in `config.toml` reorders and hides these.
+pub(super) fn default_tabs() -> Vec<ViewTab> {
+    vec![
+        ViewTab::new("all", "📋 All", None, "All active tasks"),
+        ViewTab::new(
+            "today",
+            "🔥 Today",
+            Some(ListView::Today),
+            "Due and starting today",
+        ),
+        ViewTab::new("inbox", "📥 Inbox", Some(ListView::Inbox), "Inbox items"),
+        ViewTab::new("next", "⚡ Next", Some(ListView::Next), "Next actions"),
+        ViewTab::new(
+            "waiting",
+            "⏳ Waiting",
+            Some(ListView::Waiting),
+            "Waiting on others",
+        ),
+        ViewTab::new(
+            "scheduled",
+            "📅 Scheduled",
+            Some(ListView::Scheduled),
+            "Scheduled work",
+        ),
+        ViewTab::calendar("calendar", "🗓️ Calendar", "Due and deferred by date"),
+        ViewTab::new(
+            "someday",
+            "🌱 Someday",
+            Some(ListView::Someday),
+            "Someday/Maybe",
+        ),
+        ViewTab::new(
+            "projects",
+            "📂 Projects",
+            Some(ListView::Projects),
+            "Project health",
+        ),
+        ViewTab::new("done", "✅ Done", Some(ListView::Done), "Completed tasks"),
+    ]
+}
+
+/// Splits `tabs` into (shown, hidden): names in `order` lead, the rest keep their built-in
+/// order, and `hidden` names move to the second list.
+pub(super) fn arrange_tabs(
+    tabs: Vec<ViewTab>,
+    settings: &TabSettings,
+) -> Result<(Vec<ViewTab>, Vec<ViewTab>)> {
+    let known: Vec<&str> = tabs.iter().map(|tab| tab.name).collect();
+    for name in settings.order.iter().chain(&settings.hidden) {
+        if !known.contains(&name.as_str()) {
+            bail!(
+                "unknown tab \"{}\" in [tabs]; expected one of {}",
+                name,
+                known.join(", ")
+            );
+        }
+    }
+    let rank = |tab: &ViewTab| {
+        settings
+            .order
+            .iter()
+            .position(|name| name == tab.name)
+            .unwrap_or(usize::MAX)
+    };
+    let mut tabs = tabs;
+    // Stable sort: unlisted tabs share the same rank and keep their relative order.
+    tabs.sort_by_key(rank);
+    let (hidden, shown): (Vec<ViewTab>, Vec<ViewTab>) = tabs
+        .into_iter()
+        .partition(|tab| settings.hidden.iter().any(|name| name == tab.name));
+    if shown.is_empty() {
+        bail!("[tabs] hides every tab; at least one must stay visible");
+    }
+    Ok((shown, hidden))
+}
+
+impl App {
+    /// `/tabs [hide|show <name> | move <name> <position> | reset]`; changes last for the session.
+    pub(crate) fn run_tabs_command(&mut self, args: &[String]) -> Result<()> {
+        let current = self.tabs.get(self.tab_index).map(|tab| tab.name);
+        match args {
+            [] => {}
+            [verb, name] if verb == "hide" => {
+                let Some(idx) = self.tabs.iter().position(|tab| tab.name == name) else {
+                    return self.report_unknown_tab(name);
+                };
+                if self.tabs.len() == 1 {
+                    self.set_status_error("Can't hide the last visible tab");
+                    return Ok(());
+                }
+                let tab = self.tabs.remove(idx);
+                self.hidden_tabs.push(tab);
+            }
+            [verb, name] if verb == "show" => {
+                let Some(idx) = self.hidden_tabs.iter().position(|tab| tab.name == name) else {
+                    return self.report_unknown_tab(name);
+                };
+                let tab = self.hidden_tabs.remove(idx);
+                self.tabs.push(tab);
+            }
+            [verb, name, position] if verb == "move" => {
+                let Some(idx) = self.tabs.iter().position(|tab| tab.name == name) else {
+                    return self.report_unknown_tab(name);
+                };
+                let Ok(position) = position.parse::<usize>() else {
+                    self.set_status_error("Usage: /tabs move <name> <position>");
+                    return Ok(());
+                };
+                let tab = self.tabs.remove(idx);
+                let target = position.clamp(1, self.tabs.len() + 1) - 1;
+                self.tabs.insert(target, tab);
+            }
+            [verb] if verb == "reset" => {
+                let mut all = std::mem::take(&mut self.tabs);
+                all.append(&mut self.hidden_tabs);
+                let mut tabs = default_tabs();
+                // Keep per-tab sort choices made this session.
+                for tab in &mut tabs {
+                    if let Some(old) = all.iter().find(|old| old.name == tab.name) {
+                        tab.sort = old.sort;
+                    }
+                }
+                let (shown, hidden) = arrange_tabs(tabs, &self.tab_settings)?;
+                self.tabs = shown;
+                self.hidden_tabs = hidden;
+            }
+            _ => {
+                self.set_status_error(
+                    "Usage: /tabs [hide <name> | show <name> | move <name> <position> | reset]",
+                );
+                return Ok(());
+            }
+        }
+
+        match current.and_then(|name| self.tabs.iter().position(|tab| tab.name == name)) {
+            Some(idx) => self.tab_index = idx,
+            None => {
+                self.tab_index = 0;
+                self.board = None;
+                self.project_drill = None;
+            }
+        }
+        self.refresh()?;
+        let shown: Vec<&str> = self.tabs.iter().map(|tab| tab.name).collect();
+        let mut message = format!("Tabs: {}", shown.join(", "));
+        if !self.hidden_tabs.is_empty() {
+            let hidden: Vec<&str> = self.hidden_tabs.iter().map(|tab| tab.name).collect();
+            message.push_str(&format!(" • hidden: {}", hidden.join(", ")));
+        }
+        self.set_status_info(message);
+        Ok(())
+    }
+
+    fn report_unknown_tab(&mut self, name: &str) -> Result<()> {
+        let names: Vec<&str> = self
+            .tabs
+            .iter()
+            .chain(&self.hidden_tabs)
+            .map(|tab| tab.name)
+            .collect();
+        self.set_status_error(format!(
+            "No matching tab \"{}\"; tabs: {}",
+            name,
+            names.join(", ")
+        ));
+        Ok(())
+    }
+}
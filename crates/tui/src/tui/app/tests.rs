@@ -1,9 +1,27 @@
 use super::super::filters::{ActiveFilters, FilterColumn, FilterFacets, FilterOverlay};
-use crate::model::{EnergyLevel, ListFilters, Task, TaskStatus};
+use super::calendar::{CalendarSpan, CalendarState};
+use super::date_picker::{date_problem, due_choices, snooze_choices, QuickAction};
+use super::edit_form::EditField;
+use super::input::NormalAction;
+use super::keymap::{KeyLookup, KeyPress, Keymap, ACTIONS};
+use super::status_bar::{Segment, StatusBar, TabCount};
+use super::tutorial::TutorialStep;
+use super::{App, InputMode, SortChoice, SORT_CYCLE};
+use crate::capture::TaskInput;
+use crate::config::AppConfig;
+use crate::db::Database;
+use crate::model::{EnergyLevel, ListFilters, ListView, SortField, Task, TaskStatus};
 use crate::tui::helpers::{
-    centered_rect, compose_task_capture, format_task_detail_entries, join_prefixed, short_id,
+    build_help_lines, capture_chips, centered_rect, compose_task_capture, due_urgency,
+    format_task_detail_entries, fuzzy_score, is_emoji, join_prefixed, plain_label, schedule_bucket,
+    short_id, sort_waiting, table_layout, today_bucket, waiting_heading, ChipKind, DueUrgency,
+    HelpLine, ScheduleBucket, TableLayout, TodayBucket,
 };
+use crate::tui::settings::Settings;
+use crate::tui::theme::Theme;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::layout::Rect;
+use ratatui::style::Color;
 
 #[test]
 fn centered_rect_keeps_within_bounds() {
@@ -135,13 +153,41 @@ fn compose_task_capture_emits_key_tokens() {
     assert!(capture.contains("e:med"));
 }
 
+#[test]
+fn capture_chips_preview_tokens_and_parse_errors() {
+    let chips =
+        capture_chips("Review PR +Platform @office #infra p:2 t:45m due:2030-01-02").unwrap();
+    let kinds: Vec<ChipKind> = chips.iter().map(|(kind, _)| *kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ChipKind::Project,
+            ChipKind::Context,
+            ChipKind::Tag,
+            ChipKind::Due,
+            ChipKind::Priority,
+            ChipKind::Estimate,
+        ]
+    );
+    assert_eq!(chips[0].1, "+Platform");
+    assert_eq!(chips[4].1, "p2");
+    assert!(capture_chips("Just a title").unwrap().is_empty());
+    let repeat = capture_chips("Water plants rep:2w:fri").unwrap();
+    assert_eq!(
+        repeat,
+        vec![(ChipKind::Repeat, String::from("every 2 weeks on Friday"))]
+    );
+    assert!(capture_chips("Water plants rep:often").is_err());
+    assert!(capture_chips("Pay rent due:someday-ish").is_err());
+}
+
 #[test]
 fn format_task_detail_entries_surfaces_metadata() {
     let now = chrono::Utc::now();
     let task = Task {
         id: "task-1".into(),
         title: "Review PR".into(),
-        notes: Some("Line one\nLine two".into()),
+        notes: Some("Line one\nLine two\n- [x] Tests\n- [ ] Docs".into()),
         status: TaskStatus::Next,
         project: Some("Platform".into()),
         areas: vec!["eng".into()],
@@ -176,6 +222,297 @@ fn format_task_detail_entries_surfaces_metadata() {
     assert!(entries
         .iter()
         .any(|(k, v)| k == "Notes" && v.contains("Line two")));
+    assert!(entries
+        .iter()
+        .any(|(k, v)| k == "Checklist" && v == "1/2 done"));
+}
+
+#[test]
+fn sort_choice_cycles_through_fields_and_directions() {
+    let start = SortChoice::for_view(Some(ListView::Today));
+    assert_eq!(start.field, SortField::Due);
+    assert_eq!(start.label(), "due ↑");
+
+    let reversed = start.cycle();
+    assert_eq!(reversed.field, SortField::Due);
+    assert_eq!(reversed.label(), "due ↓");
+
+    let priority = reversed.cycle();
+    assert_eq!(priority.field, SortField::Priority);
+    assert_eq!(priority.label(), "priority ↓");
+
+    let mut choice = priority;
+    for _ in 0..5 {
+        choice = choice.cycle();
+    }
+    assert_eq!(choice.field, SortField::Urgency);
+    assert!(choice.reverse);
+
+    let title = choice.cycle();
+    assert_eq!(title.field, SortField::Title);
+    assert_eq!(title.label(), "title ↑");
+    assert_eq!(title.cycle().cycle(), start);
+
+    // Manual and completed sorts are left for /sort; `o` moves on to due.
+    for view in [ListView::Next, ListView::Done] {
+        let default = SortChoice::for_view(Some(view));
+        assert!(!SORT_CYCLE.contains(&default.field));
+        assert_eq!(default.cycle(), start);
+    }
+}
+
+#[test]
+fn today_bucket_prefers_due_over_defer() {
+    let today = chrono::Local::now().date_naive();
+    let now = chrono::Utc::now();
+    let mut task = dummy_task("1", None, vec![], vec![], 0);
+    assert_eq!(today_bucket(&task, today), None);
+
+    task.defer_until = Some(now);
+    assert_eq!(today_bucket(&task, today), Some(TodayBucket::StartingToday));
+
+    task.due_at = Some(now);
+    assert_eq!(today_bucket(&task, today), Some(TodayBucket::DueToday));
+
+    task.due_at = Some(now - chrono::Duration::days(3));
+    assert_eq!(today_bucket(&task, today), Some(TodayBucket::Overdue));
+}
+
+#[test]
+fn schedule_bucket_files_tasks_by_next_date() {
+    use chrono::{Local, NaiveDate, TimeZone, Utc};
+    // Wednesday; the week ends before Monday the 19th.
+    let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+    let at = |day| {
+        Local
+            .with_ymd_and_hms(2026, 10, day, 12, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    };
+    let mut task = dummy_task("1", None, vec![], vec![], 0);
+    assert_eq!(schedule_bucket(&task, today), ScheduleBucket::Undated);
+
+    task.defer_until = Some(at(10));
+    assert_eq!(schedule_bucket(&task, today), ScheduleBucket::Today);
+    task.defer_until = Some(at(15));
+    assert_eq!(schedule_bucket(&task, today), ScheduleBucket::Tomorrow);
+    task.defer_until = Some(at(18));
+    assert_eq!(schedule_bucket(&task, today), ScheduleBucket::ThisWeek);
+    task.defer_until = Some(at(19));
+    assert_eq!(schedule_bucket(&task, today), ScheduleBucket::Later);
+
+    task.due_at = Some(at(16));
+    assert_eq!(schedule_bucket(&task, today), ScheduleBucket::ThisWeek);
+    task.due_at = Some(at(13));
+    assert_eq!(schedule_bucket(&task, today), ScheduleBucket::Overdue);
+}
+
+#[test]
+fn due_urgency_flags_overdue_and_next_day_deadlines() {
+    let now = chrono::Utc::now();
+    let mut task = dummy_task("1", None, vec![], vec![], 0);
+    assert_eq!(due_urgency(&task, now), None);
+    task.due_at = Some(now - chrono::Duration::minutes(5));
+    assert_eq!(due_urgency(&task, now), Some(DueUrgency::Overdue));
+    task.due_at = Some(now + chrono::Duration::hours(23));
+    assert_eq!(due_urgency(&task, now), Some(DueUrgency::Soon));
+    task.due_at = Some(now + chrono::Duration::hours(25));
+    assert_eq!(due_urgency(&task, now), None);
+    task.due_at = Some(now - chrono::Duration::days(1));
+    task.status = TaskStatus::Done;
+    assert_eq!(due_urgency(&task, now), None);
+
+    let settings = Settings::parse("[display]\nhighlight_due_rows = true").unwrap();
+    assert!(settings.display.highlight_due_rows);
+    assert!(!Settings::default().display.highlight_due_rows);
+}
+
+#[test]
+fn theme_settings_apply_preset_and_hex_overrides() {
+    let settings = Settings::parse(
+        r##"
+[theme]
+preset = "light"
+
+[theme.colors]
+accent = "#ff8800"
+muted = "dark gray"
+"##,
+    )
+    .expect("parse settings");
+    let theme = Theme::resolve(
+        settings.theme.preset.as_deref(),
+        &settings.theme.colors,
+        false,
+    )
+    .expect("resolve theme");
+    assert_eq!(theme.accent, Color::Rgb(255, 136, 0));
+    assert_eq!(theme.muted, Color::DarkGray);
+    assert_eq!(theme.panel, Theme::light().panel);
+
+    let plain = Theme::resolve(None, &settings.theme.colors, true).expect("no-color theme");
+    assert_eq!(plain, Theme::no_color());
+
+    let mut bad = settings.theme.colors.clone();
+    bad.insert("accent".into(), "not-a-color".into());
+    assert!(Theme::resolve(None, &bad, false).is_err());
+    assert!(Theme::resolve(Some("neon"), &Default::default(), false).is_err());
+    assert!(Settings::parse("[theme]\nshade = \"dark\"").is_err());
+}
+
+#[test]
+fn no_emoji_mode_draws_plain_labels_with_high_contrast() {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let settings: Settings = toml::from_str("[display]\nno_emoji = true\n").unwrap();
+    assert_eq!(settings.theme().unwrap(), Theme::high_contrast());
+    assert_eq!(plain_label("#️⃣ ID"), "ID");
+    assert_eq!(plain_label("Enter to save ✏️ • Esc"), "Enter to save • Esc");
+    assert!(!is_emoji('│') && !is_emoji('•'));
+
+    let screen = |app: &mut App| {
+        let mut terminal = Terminal::new(TestBackend::new(140, 30)).unwrap();
+        terminal.draw(|f| app.draw(f)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content
+            .chunks(140)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+    };
+    let (mut app, _dir) = app_with_tasks(&["Ship the release due:2020-01-01"]);
+    assert!(
+        screen(&mut app).concat().chars().any(is_emoji),
+        "emoji by default"
+    );
+
+    app.no_emoji = true;
+    let rows = screen(&mut app);
+    assert!(!rows.concat().chars().any(is_emoji), "{}", rows.join("\n"));
+    assert_eq!(table_layout(140), TableLayout::Full);
+    let header = rows.iter().find(|row| row.contains("Title")).unwrap();
+    assert!(
+        header.contains("ID") && !header.contains("#ID"),
+        "{}",
+        header
+    );
+
+    // Task text is user data and keeps its emoji.
+    let (mut app, _dir) = app_with_tasks(&["Ship 🚀 release"]);
+    app.no_emoji = true;
+    assert!(screen(&mut app).concat().contains('🚀'));
+}
+
+#[test]
+fn task_table_drops_columns_as_the_terminal_narrows() {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    assert_eq!(table_layout(120), TableLayout::Full);
+    assert_eq!(table_layout(80), TableLayout::Compact);
+    assert_eq!(table_layout(50), TableLayout::Narrow);
+
+    let (mut app, _dir) = app_with_tasks(&["Call the plumber +Home @phone #chores"]);
+    let mut screen = |width: u16| {
+        let mut terminal = Terminal::new(TestBackend::new(width, 20)).unwrap();
+        terminal.draw(|f| app.draw(f)).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        let text: String = buffer
+            .content
+            .chunks(width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>() + "\n")
+            .collect();
+        (text, app.page_rows)
+    };
+
+    let (full, full_rows) = screen(140);
+    assert!(full.contains("Tags") && full.contains("#chores"));
+
+    let (compact, _) = screen(85);
+    assert!(!compact.contains("Tags") && !compact.contains("Contexts"));
+    assert!(compact.contains("Project"));
+
+    let (narrow, narrow_rows) = screen(60);
+    assert!(narrow.contains("Call the plumber"));
+    assert!(narrow.contains("+Home · @phone"), "{}", narrow);
+    assert!(narrow_rows < full_rows);
+}
+
+#[test]
+fn keymap_overrides_support_chords_and_layouts() {
+    let settings = Settings::parse(
+        r#"
+[keys]
+layout = "emacs"
+
+[keys.bindings]
+add = "g a"
+board = ["B", "ctrl+k"]
+"#,
+    )
+    .expect("parse settings");
+    let keymap = Keymap::from_settings(&settings.keys).expect("build keymap");
+    let press = |code| KeyPress::from(&KeyEvent::from(code));
+    let ctrl = |c| KeyPress::from(&KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+
+    assert_eq!(
+        keymap.lookup(&[press(KeyCode::Char('a'))]),
+        KeyLookup::Unbound
+    );
+    assert_eq!(
+        keymap.lookup(&[press(KeyCode::Char('g'))]),
+        KeyLookup::Pending
+    );
+    assert_eq!(
+        keymap.lookup(&[press(KeyCode::Char('g')), press(KeyCode::Char('a'))]),
+        KeyLookup::Action(NormalAction::EnterAdd)
+    );
+    assert_eq!(
+        keymap.lookup(&[ctrl('k')]),
+        KeyLookup::Action(NormalAction::ToggleBoard)
+    );
+    assert_eq!(
+        keymap.lookup(&[ctrl('n')]),
+        KeyLookup::Action(NormalAction::SelectNext)
+    );
+    assert_eq!(keymap.lookup(&[ctrl('x')]), KeyLookup::Pending);
+    assert_eq!(
+        keymap.lookup(&[ctrl('x'), ctrl('c')]),
+        KeyLookup::Action(NormalAction::Quit)
+    );
+    assert_eq!(keymap.hint("add"), "g a");
+    assert!(keymap
+        .help_sections()
+        .iter()
+        .flat_map(|(_, rows)| rows)
+        .any(|row| row
+            == &(
+                "B / ctrl+k".to_string(),
+                "Toggle the kanban board".to_string()
+            )));
+
+    let mut bad = settings.keys.clone();
+    bad.layout = Some("dvorak-ish".into());
+    assert!(Keymap::from_settings(&bad).is_err());
+}
+
+#[test]
+fn chorded_keys_drive_the_app() {
+    let (mut app, _dir) = app_with_tasks(&["first task"]);
+    app.keymap = Keymap::from_settings(
+        &Settings::parse("[keys.bindings]\nboard = \"g b\"")
+            .expect("parse")
+            .keys,
+    )
+    .expect("keymap");
+
+    app.on_key(KeyEvent::from(KeyCode::Char('b'))).unwrap();
+    assert!(app.board.is_none());
+    app.on_key(KeyEvent::from(KeyCode::Char('g'))).unwrap();
+    app.on_key(KeyEvent::from(KeyCode::Char('b'))).unwrap();
+    assert!(app.board.is_some());
 }
 
 fn dummy_task(
@@ -208,3 +545,1020 @@ fn dummy_task(
         waiting_since: None,
     }
 }
+
+fn app_with_tasks(captures: &[&str]) -> (App, tempfile::TempDir) {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    let config = AppConfig::from_data_dir(dir.path().to_path_buf()).expect("config");
+    let mut database = Database::initialize(&config).expect("init db");
+    for capture in captures {
+        database
+            .handle_add(&TaskInput {
+                text: capture.split_whitespace().map(|s| s.to_string()).collect(),
+                ..Default::default()
+            })
+            .expect("capture");
+    }
+    let app = App::new(config, database, false).expect("app");
+    (app, dir)
+}
+
+#[test]
+fn board_moves_tasks_across_statuses() {
+    let (mut app, _dir) = app_with_tasks(&["Plan trip", "Book flights"]);
+    app.on_key(KeyEvent::from(KeyCode::Char('b'))).unwrap();
+    assert!(app.board.is_some());
+    assert_eq!(app.board_column(0).len(), 2);
+
+    app.on_key(KeyEvent::from(KeyCode::Char('L'))).unwrap();
+    let board = app.board.as_ref().unwrap();
+    assert_eq!(board.column, 1);
+    assert_eq!(app.board_column(0).len(), 1);
+    assert_eq!(app.board_column(1).len(), 1);
+    assert_eq!(app.tasks[app.selected].status, TaskStatus::Next);
+
+    app.on_key(KeyEvent::from(KeyCode::Char('h'))).unwrap();
+    assert_eq!(app.board.as_ref().unwrap().column, 0);
+    assert_eq!(app.tasks[app.selected].status, TaskStatus::Inbox);
+
+    app.on_key(KeyEvent::from(KeyCode::Char('b'))).unwrap();
+    assert!(app.board.is_none());
+}
+
+#[test]
+fn calendar_state_ranges_start_on_monday() {
+    let wednesday = chrono::NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    let mut state = CalendarState::new(wednesday);
+    let (start, days) = state.range();
+    assert_eq!(start, chrono::NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+    assert_eq!(days, 7);
+
+    state.shift(true);
+    assert_eq!(
+        state.range().0,
+        chrono::NaiveDate::from_ymd_opt(2025, 1, 20).unwrap()
+    );
+
+    state.span = CalendarSpan::Month;
+    let (start, days) = state.range();
+    assert_eq!(
+        start,
+        chrono::NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()
+    );
+    assert_eq!(days, 42);
+}
+
+#[test]
+fn calendar_enter_jumps_to_task_view() {
+    let (mut app, _dir) = app_with_tasks(&["Call dentist due:today"]);
+    app.select_tab_by_name("calendar").expect("calendar tab");
+    app.refresh().unwrap();
+    assert_eq!(app.calendar_entries().len(), 1);
+
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(app.tabs[app.tab_index].name, "inbox");
+    assert_eq!(app.tasks[app.selected].title, "Call dentist");
+}
+
+#[test]
+fn vim_counts_and_motions_move_the_selection() {
+    let captures = ["one", "two", "three", "four", "five", "six"];
+    let (mut app, _dir) = app_with_tasks(&captures);
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+
+    app.on_key(key('3')).unwrap();
+    app.on_key(key('j')).unwrap();
+    assert_eq!(app.selected, 3);
+
+    app.on_key(key('G')).unwrap();
+    assert_eq!(app.selected, 5);
+    app.on_key(key('g')).unwrap();
+    app.on_key(key('g')).unwrap();
+    assert_eq!(app.selected, 0);
+    app.on_key(key('4')).unwrap();
+    app.on_key(key('G')).unwrap();
+    assert_eq!(app.selected, 3);
+
+    app.page_rows = 4;
+    app.on_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.selected, 1);
+    app.on_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.selected, 3);
+
+    app.on_key(key('d')).unwrap();
+    app.on_key(key('d')).unwrap();
+    assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+    app.on_key(KeyEvent::from(KeyCode::Esc)).unwrap();
+
+    // A lone `d` waits for the chord timeout, then marks the task done.
+    app.on_key(key('d')).unwrap();
+    assert_eq!(app.tasks.len(), captures.len());
+    app.pending_since -= crate::tui::constants::CHORD_TIMEOUT;
+    app.on_tick().unwrap();
+    assert_eq!(app.tasks.len(), captures.len() - 1);
+
+    // Any other key resolves it at once, then runs as usual.
+    app.on_key(key('d')).unwrap();
+    app.on_key(key('k')).unwrap();
+    assert_eq!(app.tasks.len(), captures.len() - 2);
+    assert!(app.pending_keys.is_empty());
+}
+
+#[test]
+fn command_history_recalls_with_up_and_down() {
+    let (mut app, dir) = app_with_tasks(&["first task"]);
+    let key = |code| KeyEvent::from(code);
+
+    for command in ["/view next", "/refresh"] {
+        app.on_key(key(KeyCode::Char('/'))).unwrap();
+        app.input.set(command);
+        app.run_command().unwrap();
+    }
+
+    app.on_key(key(KeyCode::Char('/'))).unwrap();
+    app.on_key(key(KeyCode::Up)).unwrap();
+    assert_eq!(app.input.as_str(), "/refresh");
+    app.on_key(key(KeyCode::Up)).unwrap();
+    assert_eq!(app.input.as_str(), "/view next");
+    app.on_key(key(KeyCode::Down)).unwrap();
+    app.on_key(key(KeyCode::Down)).unwrap();
+    assert_eq!(app.input.as_str(), "/");
+
+    let saved = std::fs::read_to_string(dir.path().join("command_history")).unwrap();
+    assert_eq!(saved, "/view next\n/refresh\n");
+}
+
+#[test]
+fn fuzzy_score_prefers_word_starts_and_runs() {
+    assert!(fuzzy_score("emal", "Email Alice").is_some());
+    assert!(fuzzy_score("xyz", "Email Alice").is_none());
+    assert!(fuzzy_score("rev", "Review meal plan") > fuzzy_score("rev", "Prevent overrun"));
+}
+
+#[test]
+fn command_suggestions_fuzzy_match_task_titles() {
+    let (mut app, _dir) = app_with_tasks(&["Review meal plan", "Email Alice"]);
+    let email = app
+        .tasks
+        .iter()
+        .find(|task| task.title == "Email Alice")
+        .expect("email task")
+        .id
+        .clone();
+
+    app.on_key(KeyEvent::from(KeyCode::Char('/'))).unwrap();
+    app.input.set("/done emal");
+    app.update_command_suggestions();
+    assert_eq!(app.suggestions[0].fill, format!("/done {}", email));
+
+    app.input.set("/srt");
+    app.update_command_suggestions();
+    assert_eq!(app.suggestions[0].fill, "/sort ");
+}
+
+#[test]
+fn projects_tab_drills_into_a_project_and_back() {
+    let (mut app, _dir) =
+        app_with_tasks(&["Fix sink +Home", "Email Alice +Work", "Plan offsite +Work"]);
+    let key = |code| KeyEvent::from(code);
+
+    app.select_tab_by_name("projects").unwrap();
+    app.refresh().unwrap();
+    assert!(app.showing_projects);
+    app.on_key(key(KeyCode::Char('j'))).unwrap();
+    app.on_key(key(KeyCode::Enter)).unwrap();
+
+    assert!(!app.showing_projects);
+    assert_eq!(app.tasks.len(), 2);
+    assert!(app
+        .tasks
+        .iter()
+        .all(|task| task.project.as_deref() == Some("Work")));
+
+    app.on_key(key(KeyCode::Esc)).unwrap();
+    assert!(app.showing_projects);
+    assert!(app.project_drill.is_none());
+    assert_eq!(app.project_state.selected(), Some(1));
+}
+
+#[test]
+fn sidebar_applies_contexts_across_tabs() {
+    let (mut app, _dir) =
+        app_with_tasks(&["Call plumber +Home @phone", "Email Alice +Work @computer"]);
+    let key = |code| KeyEvent::from(code);
+
+    app.on_key(key(KeyCode::Char('\\'))).unwrap();
+    assert_eq!(app.input_mode, InputMode::Sidebar);
+    // Everything, Home, Work, @computer, @phone
+    assert_eq!(app.sidebar.entries.len(), 5);
+    app.on_key(key(KeyCode::End)).unwrap();
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    assert_eq!(app.tasks.len(), 1);
+    assert_eq!(app.tasks[0].title, "Call plumber");
+
+    app.on_key(key(KeyCode::Esc)).unwrap();
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    assert!(app.active_filters.contexts.contains("phone"));
+
+    app.on_key(key(KeyCode::Char('\\'))).unwrap();
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    assert!(app.active_filters.is_empty());
+    app.on_key(key(KeyCode::Char('\\'))).unwrap();
+    assert!(!app.sidebar.visible);
+}
+
+#[test]
+fn quick_filter_narrows_rows_until_cleared() {
+    let (mut app, _dir) = app_with_tasks(&["Email Alice", "Review meal plan", "Water plants"]);
+    let key = |code| KeyEvent::from(code);
+
+    app.on_key(key(KeyCode::Char(','))).unwrap();
+    assert_eq!(app.input_mode, InputMode::QuickFilter);
+    for c in "alic".chars() {
+        app.on_key(key(KeyCode::Char(c))).unwrap();
+    }
+    let titles: Vec<&str> = app.tasks.iter().map(|task| task.title.as_str()).collect();
+    assert_eq!(titles, vec!["Email Alice"]);
+
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    app.refresh().unwrap();
+    assert_eq!(app.tasks.len(), 1);
+
+    app.on_key(key(KeyCode::Esc)).unwrap();
+    assert!(app.quick_filter.is_none());
+    assert_eq!(app.tasks.len(), 3);
+}
+
+#[test]
+fn filter_presets_save_and_reapply_by_number() {
+    let (mut app, dir) = app_with_tasks(&["Call plumber @phone", "Email Alice @computer"]);
+    let key = |code| KeyEvent::from(code);
+
+    app.active_filters.contexts.insert("computer".into());
+    app.on_key(key(KeyCode::Char('/'))).unwrap();
+    app.input.set("/savefilter 1 deep work");
+    app.run_command().unwrap();
+    assert_eq!(
+        app.active_preset().map(|p| p.name.as_str()),
+        Some("deep work")
+    );
+
+    app.active_filters = ActiveFilters::default();
+    app.refresh().unwrap();
+    assert_eq!(app.tasks.len(), 2);
+
+    // Bare digits are counts even when their slot holds a preset.
+    app.on_key(key(KeyCode::Char('1'))).unwrap();
+    assert_eq!(app.pending_count, Some(1));
+    assert_eq!(app.tasks.len(), 2);
+    app.on_key(key(KeyCode::Esc)).unwrap();
+
+    app.on_key(key(KeyCode::Char('\''))).unwrap();
+    app.on_key(key(KeyCode::Char('1'))).unwrap();
+    assert_eq!(app.tasks.len(), 1);
+    assert_eq!(app.tasks[0].title, "Email Alice");
+    assert_eq!(app.pending_count, None);
+
+    app.on_key(key(KeyCode::Char('\''))).unwrap();
+    app.on_key(key(KeyCode::Char('2'))).unwrap();
+    assert_eq!(app.pending_count, None);
+    assert_eq!(app.tasks.len(), 1);
+
+    let config = AppConfig::from_data_dir(dir.path().to_path_buf()).unwrap();
+    let database = Database::initialize(&config).unwrap();
+    let reopened = App::new(config, database, false).unwrap();
+    assert_eq!(reopened.presets.len(), 1);
+}
+
+#[test]
+fn open_link_lists_multiple_urls_in_a_picker() {
+    let (mut app, _dir) = app_with_tasks(&["Compare https://a.example/x and https://b.example/y"]);
+
+    app.on_key(KeyEvent::from(KeyCode::Char('O'))).unwrap();
+    assert_eq!(app.input_mode, InputMode::LinkPicker);
+    let picker = app.link_picker.as_ref().expect("picker");
+    assert_eq!(
+        picker.urls,
+        vec!["https://a.example/x", "https://b.example/y"]
+    );
+
+    app.on_key(KeyEvent::from(KeyCode::Esc)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert!(app.link_picker.is_none());
+}
+
+#[test]
+fn help_sections_cover_every_action_and_filter_by_query() {
+    let keymap = Keymap::default();
+    let sections = keymap.help_sections();
+    let listed: usize = sections.iter().map(|(_, rows)| rows.len()).sum();
+    assert_eq!(listed, ACTIONS.len());
+
+    let lines = build_help_lines(sections, "board");
+    assert!(lines.contains(&HelpLine::Section("Views & tools".into())));
+    assert!(lines.contains(&HelpLine::Binding(
+        "b".into(),
+        "Toggle the kanban board".into()
+    )));
+    assert!(!lines.contains(&HelpLine::Section("Task actions".into())));
+}
+
+#[test]
+fn help_overlay_scrolls_and_searches() {
+    let (mut app, _dir) = app_with_tasks(&[]);
+    let key = |code| KeyEvent::from(code);
+
+    app.on_key(key(KeyCode::Char('h'))).unwrap();
+    assert_eq!(app.input_mode, InputMode::Help);
+    app.on_key(key(KeyCode::Char('j'))).unwrap();
+    assert_eq!(app.help.scroll, 1);
+
+    app.on_key(key(KeyCode::Char('/'))).unwrap();
+    for c in "yank".chars() {
+        app.on_key(key(KeyCode::Char(c))).unwrap();
+    }
+    assert_eq!(app.help.query, "yank");
+    assert_eq!(app.help.scroll, 0);
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Help);
+    app.on_key(key(KeyCode::Esc)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+}
+
+#[test]
+fn external_writes_refresh_the_view() {
+    let (mut app, dir) = app_with_tasks(&["Existing task"]);
+    let config = AppConfig::from_data_dir(dir.path().to_path_buf()).unwrap();
+    let mut other = Database::initialize(&config).unwrap();
+    other
+        .handle_add(&TaskInput {
+            text: vec!["Added from the CLI".into()],
+            ..Default::default()
+        })
+        .unwrap();
+
+    app.check_external_changes().unwrap();
+    assert_eq!(app.tasks.len(), 2);
+}
+
+#[test]
+fn status_bar_segments_follow_settings() {
+    let settings =
+        Settings::parse("[status_bar]\ntop = [\"counts\", \"sort\"]\nbottom = []").unwrap();
+    let status_bar = StatusBar::from_settings(&settings.status_bar).unwrap();
+    assert_eq!(status_bar.height(), 1);
+    assert!(status_bar.shows(Segment::Counts));
+    assert!(!status_bar.shows(Segment::Keys));
+
+    let settings = Settings::parse("[status_bar]\ntop = [\"weather\"]").unwrap();
+    let err = StatusBar::from_settings(&settings.status_bar).unwrap_err();
+    assert!(err.to_string().contains("weather"));
+
+    let (mut app, _dir) = app_with_tasks(&["Write report", "Call Bob"]);
+    app.refresh().unwrap();
+    let lines = app.status_bar_lines();
+    assert_eq!(lines.len(), 2);
+    let top: String = lines[0]
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect();
+    assert!(top.starts_with("Ready"), "{top}");
+    assert!(top.contains("Inbox 2"), "{top}");
+}
+
+#[test]
+fn set_confirm_delete_off_skips_the_overlay_for_single_deletes() {
+    let (mut app, _dir) = app_with_tasks(&["Keep me", "Drop me"]);
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+
+    app.on_key(key('/')).unwrap();
+    app.input.set("/delete");
+    app.run_command().unwrap();
+    assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+    app.on_key(KeyEvent::from(KeyCode::Esc)).unwrap();
+
+    app.on_key(key('/')).unwrap();
+    app.input.set("/set confirm-delete off");
+    app.run_command().unwrap();
+    assert!(!app.confirm_delete);
+    assert!(app.delete_needs_confirmation(2));
+
+    // `d d` still asks; `x` deletes straight away.
+    app.on_key(key('d')).unwrap();
+    app.on_key(key('d')).unwrap();
+    assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+    app.on_key(KeyEvent::from(KeyCode::Esc)).unwrap();
+    app.on_key(key('x')).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.tasks.len(), 1);
+}
+
+#[test]
+fn move_to_project_picks_or_creates_projects() {
+    let (mut app, _dir) = app_with_tasks(&["Draft outline +Book", "Sketch cover"]);
+    let key = |code| KeyEvent::from(code);
+    app.select_task_by_id(
+        &app.tasks
+            .iter()
+            .find(|t| t.title == "Sketch cover")
+            .unwrap()
+            .id
+            .clone(),
+    );
+
+    app.on_key(key(KeyCode::Char('m'))).unwrap();
+    assert_eq!(app.input_mode, InputMode::MoveProject);
+    for c in "bk".chars() {
+        app.on_key(key(KeyCode::Char(c))).unwrap();
+    }
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.tasks[app.selected].title, "Sketch cover");
+    assert_eq!(app.tasks[app.selected].project.as_deref(), Some("Book"));
+
+    app.on_key(key(KeyCode::Char('m'))).unwrap();
+    for c in "Art".chars() {
+        app.on_key(key(KeyCode::Char(c))).unwrap();
+    }
+    let picker = app.project_picker.as_ref().unwrap();
+    assert_eq!(picker.selected, 0, "no match leaves create selected");
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    assert_eq!(app.tasks[app.selected].project.as_deref(), Some("Art"));
+}
+
+#[test]
+fn export_writes_the_filtered_view_and_echoes_the_destination() {
+    let (mut app, dir) = app_with_tasks(&["Pack bags +Trip", "Book hotel +Trip", "Pay rent"]);
+    app.on_key(KeyEvent::from(KeyCode::Char(','))).unwrap();
+    for c in "bag".chars() {
+        app.on_key(KeyEvent::from(KeyCode::Char(c))).unwrap();
+    }
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(app.tasks.len(), 1);
+
+    let path = dir.path().join("view.csv");
+    app.input.set(format!("/export {}", path.display()));
+    app.run_command().unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        written.lines().count(),
+        2,
+        "header plus the one filtered row"
+    );
+    assert!(written.contains("Pack bags"));
+    let status = app.status.as_ref().unwrap().text.clone();
+    assert!(status.contains(&path.display().to_string()));
+    assert!(status.contains("as csv"));
+
+    let path = dir.path().join("view.txt");
+    app.input.set(format!("/export md {}", path.display()));
+    app.run_command().unwrap();
+    assert!(std::fs::read_to_string(&path)
+        .unwrap()
+        .starts_with("- [ ] Pack bags"));
+}
+
+#[test]
+fn tutorial_advances_through_capture_promote_filter_and_complete() {
+    let (mut app, _dir) = app_with_tasks(&["Older task"]);
+    let key = |code| KeyEvent::from(code);
+    let typed = |app: &mut App, text: &str| {
+        for c in text.chars() {
+            app.on_key(key(KeyCode::Char(c))).unwrap();
+        }
+    };
+    app.start_tutorial();
+    let step = |app: &App| app.tutorial.as_ref().map(|tutorial| tutorial.step);
+    app.advance_tutorial().unwrap();
+    assert_eq!(
+        step(&app),
+        Some(TutorialStep::Capture),
+        "old tasks don't count"
+    );
+
+    app.on_key(key(KeyCode::Char('a'))).unwrap();
+    typed(&mut app, "Water plants");
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    assert_eq!(step(&app), Some(TutorialStep::Promote));
+    let id = app.tutorial.as_ref().unwrap().task_id.clone().unwrap();
+
+    app.select_task_by_id(&id);
+    app.on_key(key(KeyCode::Char('n'))).unwrap();
+    assert_eq!(step(&app), Some(TutorialStep::Filter));
+
+    app.on_key(key(KeyCode::Char(','))).unwrap();
+    typed(&mut app, "water");
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    assert_eq!(step(&app), Some(TutorialStep::Complete));
+
+    app.select_task_by_id(&id);
+    app.on_key(key(KeyCode::Char('d'))).unwrap();
+    app.pending_since -= crate::tui::constants::CHORD_TIMEOUT;
+    app.on_tick().unwrap();
+    assert_eq!(step(&app), Some(TutorialStep::Finished));
+
+    app.input.set("/tutorial skip");
+    app.run_command().unwrap();
+    assert!(app.tutorial.is_none());
+}
+
+#[test]
+fn add_overlay_tab_switches_to_a_notes_field() {
+    let (mut app, _dir) = app_with_tasks(&[]);
+    let key = |code| KeyEvent::from(code);
+    let typed = |app: &mut App, text: &str| {
+        for c in text.chars() {
+            app.on_key(key(KeyCode::Char(c))).unwrap();
+        }
+    };
+
+    app.on_key(key(KeyCode::Char('a'))).unwrap();
+    typed(&mut app, "Book flights +Trip");
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    typed(&mut app, "Window seat");
+    app.on_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT))
+        .unwrap();
+    typed(&mut app, "Under 400");
+    app.on_key(key(KeyCode::BackTab)).unwrap();
+    typed(&mut app, " @phone");
+    app.on_key(key(KeyCode::Enter)).unwrap();
+
+    assert_eq!(app.input_mode, InputMode::Normal);
+    let task = app
+        .tasks
+        .iter()
+        .find(|t| t.title == "Book flights")
+        .expect("captured");
+    assert_eq!(task.contexts, vec!["phone"]);
+    assert_eq!(task.notes.as_deref(), Some("Window seat\nUnder 400"));
+
+    app.on_key(key(KeyCode::Char('a'))).unwrap();
+    assert!(app.add_notes.as_str().is_empty());
+    assert!(!app.add_notes_focus);
+}
+
+#[test]
+fn edit_form_updates_fields_and_reports_errors_inline() {
+    let (mut app, _dir) = app_with_tasks(&["Draft outline +Book", "Sketch cover @desk"]);
+    let key = |code| KeyEvent::from(code);
+    let typed = |app: &mut App, text: &str| {
+        for c in text.chars() {
+            app.on_key(key(KeyCode::Char(c))).unwrap();
+        }
+    };
+    let id = app
+        .tasks
+        .iter()
+        .find(|t| t.title == "Sketch cover")
+        .unwrap()
+        .id
+        .clone();
+    app.select_task_by_id(&id);
+
+    app.on_key(key(KeyCode::Char('e'))).unwrap();
+    assert_eq!(app.input_mode, InputMode::EditForm);
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    app.on_key(key(KeyCode::Right)).unwrap();
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    typed(&mut app, " home");
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    typed(&mut app, "someday-ish");
+    app.on_key(key(KeyCode::Enter)).unwrap();
+    let form = app
+        .edit_form
+        .as_ref()
+        .expect("a bad date keeps the form open");
+    assert_eq!(form.field(), EditField::Due);
+    assert!(form.error.is_some());
+
+    for _ in "someday-ish".chars() {
+        app.on_key(key(KeyCode::Backspace)).unwrap();
+    }
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    app.on_key(key(KeyCode::Right)).unwrap();
+    app.on_key(key(KeyCode::Right)).unwrap();
+    app.on_key(key(KeyCode::Tab)).unwrap();
+    typed(&mut app, "Call the printer");
+    app.on_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT))
+        .unwrap();
+    typed(&mut app, "ask for proofs");
+    app.on_key(key(KeyCode::Enter)).unwrap();
+
+    assert_eq!(app.input_mode, InputMode::Normal);
+    let task = app.database.fetch_task(&id).unwrap().unwrap();
+    assert_eq!(task.title, "Sketch cover");
+    assert_eq!(task.project.as_deref(), Some("Book"));
+    assert_eq!(task.contexts, vec!["desk", "home"]);
+    assert_eq!(task.priority, 2);
+    assert_eq!(
+        task.notes.as_deref(),
+        Some("Call the printer\nask for proofs")
+    );
+    assert!(task.due_at.is_none());
+
+    app.on_key(key(KeyCode::Char('e'))).unwrap();
+    app.on_key(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.input_mode, InputMode::Edit);
+    assert!(app.input.as_str().contains("+Book"));
+}
+
+#[test]
+fn priority_keys_step_within_bounds() {
+    let (mut app, _dir) = app_with_tasks(&["Ship release"]);
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+
+    app.on_key(key('+')).unwrap();
+    assert_eq!(app.tasks[0].priority, 1);
+    app.on_key(key('5')).unwrap();
+    app.on_key(key('+')).unwrap();
+    assert_eq!(app.tasks[0].priority, 3);
+    app.on_key(key('-')).unwrap();
+    assert_eq!(app.tasks[0].priority, 2);
+}
+
+#[test]
+fn snooze_choices_resolve_relative_to_now() {
+    use chrono::{Local, NaiveDate, TimeZone};
+    let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+    // Friday morning: tonight is still offered.
+    let friday = Local.with_ymd_and_hms(2026, 10, 16, 10, 0, 0).unwrap();
+    let choices = snooze_choices(friday);
+    let dates: Vec<_> = choices
+        .iter()
+        .map(|choice| match choice.action {
+            QuickAction::At(at) => (choice.key, Some(at.date_naive())),
+            _ => (choice.key, None),
+        })
+        .collect();
+    assert_eq!(
+        dates,
+        vec![
+            ('t', Some(day(16))),
+            ('m', Some(day(17))),
+            ('w', Some(day(17))),
+            ('n', Some(day(19))),
+            ('p', None),
+        ]
+    );
+
+    let saturday_night = Local.with_ymd_and_hms(2026, 10, 17, 21, 0, 0).unwrap();
+    let choices = snooze_choices(saturday_night);
+    assert_eq!(choices[0].key, 'm');
+    assert!(matches!(choices[1].action, QuickAction::At(at) if at.date_naive() == day(18)));
+
+    let due = due_choices(friday, true);
+    let keys: String = due.iter().map(|choice| choice.key).collect();
+    assert_eq!(keys, "tmfnpc");
+    assert!(matches!(due[2].action, QuickAction::At(at) if at.date_naive() == day(16)));
+}
+
+#[test]
+fn snooze_defers_and_schedules_the_task() {
+    let (mut app, _dir) = app_with_tasks(&["Renew passport"]);
+    let id = app.tasks[0].id.clone();
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+
+    // A lone `z` opens the picker once the `z 1`/`z 7`/`z 3` chords time out.
+    app.on_key(key('z')).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    app.pending_since -= crate::tui::constants::CHORD_TIMEOUT;
+    app.on_tick().unwrap();
+    assert_eq!(app.input_mode, InputMode::DatePicker);
+    app.on_key(key('p')).unwrap();
+    assert!(app.date_picker.as_ref().unwrap().calendar.is_some());
+    app.on_key(key('l')).unwrap();
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+
+    let task = app.database.fetch_task(&id).unwrap().unwrap();
+    assert_eq!(task.status, TaskStatus::Scheduled);
+    let deferred = crate::tui::helpers::local_date(&task.defer_until.unwrap());
+    assert_eq!(
+        deferred,
+        chrono::Local::now().date_naive() + chrono::Duration::days(2)
+    );
+
+    for (chord, days) in [('1', 1), ('7', 7), ('3', 30)] {
+        app.on_key(key('z')).unwrap();
+        app.on_key(key(chord)).unwrap();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        let task = app.database.fetch_task(&id).unwrap().unwrap();
+        assert_eq!(
+            crate::tui::helpers::local_date(&task.defer_until.unwrap()),
+            chrono::Local::now().date_naive() + chrono::Duration::days(days)
+        );
+    }
+}
+
+#[test]
+fn due_picker_sets_validates_and_clears() {
+    let (mut app, _dir) = app_with_tasks(&["File taxes defer:+3d"]);
+    app.select_tab_by_name("all");
+    app.refresh().unwrap();
+    let id = app.tasks[0].id.clone();
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+    let due = |app: &App| app.database.fetch_task(&id).unwrap().unwrap().due_at;
+
+    app.on_key(key('D')).unwrap();
+    assert_eq!(app.input_mode, InputMode::DatePicker);
+    let picker = app.date_picker.as_ref().unwrap();
+    let tomorrow = chrono::Local::now() + chrono::Duration::days(1);
+    assert!(date_problem(picker, tomorrow, chrono::Local::now()).is_some());
+    let yesterday = chrono::Local::now() - chrono::Duration::days(1);
+    assert!(date_problem(picker, yesterday, chrono::Local::now()).is_some());
+
+    app.on_key(key('p')).unwrap();
+    for _ in 0..2 {
+        app.on_key(key('j')).unwrap();
+    }
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    let set = crate::tui::helpers::local_date(&due(&app).expect("due set"));
+    assert_eq!(
+        set,
+        chrono::Local::now().date_naive() + chrono::Duration::days(15)
+    );
+
+    app.select_task_by_id(&id);
+    app.on_key(key('D')).unwrap();
+    app.on_key(key('c')).unwrap();
+    assert_eq!(due(&app), None);
+}
+
+#[test]
+fn recurrence_editor_sets_custom_and_stops_repeats() {
+    let (mut app, _dir) = app_with_tasks(&["Water plants"]);
+    let id = app.tasks[0].id.clone();
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+    let repeat = |app: &App| app.database.fetch_task(&id).unwrap().unwrap().repeat;
+
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Inspect);
+    app.on_key(key('r')).unwrap();
+    assert_eq!(app.input_mode, InputMode::Recurrence);
+    let keys: String = app
+        .recurrence
+        .as_ref()
+        .unwrap()
+        .choices
+        .iter()
+        .map(|choice| choice.key)
+        .collect();
+    assert_eq!(keys, "dew2myc");
+    app.on_key(key('e')).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(repeat(&app).as_deref(), Some("1w:mon,tue,wed,thu,fri"));
+
+    // The edit form hands over with Ctrl+R; a typed rule is checked before it is saved.
+    app.on_key(key('e')).unwrap();
+    assert_eq!(app.input_mode, InputMode::EditForm);
+    app.on_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.input_mode, InputMode::Recurrence);
+    app.on_key(key('c')).unwrap();
+    for _ in 0.."1w:mon,tue,wed,thu,fri".len() {
+        app.on_key(KeyEvent::from(KeyCode::Backspace)).unwrap();
+    }
+    for c in "0d".chars() {
+        app.on_key(key(c)).unwrap();
+    }
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Recurrence);
+    app.on_key(KeyEvent::from(KeyCode::Backspace)).unwrap();
+    app.on_key(KeyEvent::from(KeyCode::Backspace)).unwrap();
+    for c in "w:thu,mon".chars() {
+        app.on_key(key(c)).unwrap();
+    }
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(repeat(&app).as_deref(), Some("1w:mon,thu"));
+
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    app.on_key(key('r')).unwrap();
+    app.on_key(key('x')).unwrap();
+    assert_eq!(repeat(&app), None);
+}
+
+#[test]
+fn timer_toggles_and_guards_quit() {
+    let (mut app, _dir) = app_with_tasks(&["Write report"]);
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+    assert_eq!(super::timer::format_elapsed(3725), "1:02:05");
+    assert_eq!(super::timer::format_elapsed(65), "01:05");
+
+    app.on_key(key('T')).unwrap();
+    assert!(app.timer_label().unwrap().ends_with("Write report"));
+    assert!(app.database.running_timer().unwrap().is_some());
+
+    app.on_key(key('q')).unwrap();
+    assert!(!app.should_quit());
+    app.on_key(key('T')).unwrap();
+    assert!(app.timer.is_none());
+    assert!(app.database.running_timer().unwrap().is_none());
+
+    app.on_key(key('T')).unwrap();
+    app.on_key(key('q')).unwrap();
+    app.on_key(key('q')).unwrap();
+    assert!(app.should_quit());
+}
+
+#[test]
+fn review_triages_items_and_reports_a_summary() {
+    let (mut app, _dir) = app_with_tasks(&["Sort receipts", "Call plumber", "Old idea"]);
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+
+    app.on_key(key('/')).unwrap();
+    app.input.set("/review");
+    app.run_command().unwrap();
+    assert_eq!(app.input_mode, InputMode::Review);
+    assert_eq!(app.review.as_ref().unwrap().session.progress(), (0, 3));
+
+    app.on_key(key('n')).unwrap();
+    app.on_key(key('s')).unwrap();
+    app.on_key(key('x')).unwrap();
+    assert!(app.review.as_ref().unwrap().delete_armed);
+    app.on_key(key('x')).unwrap();
+    assert!(app.review.as_ref().unwrap().session.is_finished());
+
+    app.on_key(KeyEvent::from(KeyCode::Enter)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert!(app.review.is_none());
+    let inbox = app
+        .database
+        .fetch_tasks(&ListFilters::for_view(Some(ListView::Inbox)))
+        .unwrap();
+    let remaining = app
+        .database
+        .fetch_tasks(&ListFilters::for_view(None))
+        .unwrap();
+    assert!(inbox.is_empty(), "inbox is triaged");
+    assert_eq!(remaining.len(), 2);
+}
+
+#[test]
+fn stats_screen_opens_with_a_snapshot_and_closes() {
+    let (mut app, _dir) = app_with_tasks(&["Sort receipts", "Draft chapter +Book"]);
+
+    app.on_key(KeyEvent::from(KeyCode::Char('/'))).unwrap();
+    app.input.set("/stats");
+    app.run_command().unwrap();
+    assert_eq!(app.input_mode, InputMode::Stats);
+    let stats = app.stats.as_ref().unwrap();
+    assert_eq!(stats.completions.len(), 14);
+    assert_eq!(stats.inbox_count, 2);
+    assert_eq!(stats.by_project, vec![("Book".to_string(), 1)]);
+
+    app.on_key(KeyEvent::from(KeyCode::Esc)).unwrap();
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert!(app.stats.is_none());
+}
+
+#[test]
+fn goto_switches_tabs_to_find_tasks_by_title_or_id() {
+    let (mut app, _dir) = app_with_tasks(&["Sort receipts", "Email Alice", "Ship release"]);
+    let id_of = |app: &App, title: &str| {
+        app.tasks
+            .iter()
+            .find(|t| t.title == title)
+            .unwrap()
+            .id
+            .clone()
+    };
+    app.select_tab_by_name("all");
+    app.refresh().unwrap();
+    let email = id_of(&app, "Email Alice");
+    let release = id_of(&app, "Ship release");
+    app.database
+        .mark_next(std::slice::from_ref(&email))
+        .unwrap();
+    app.database
+        .mark_done(std::slice::from_ref(&release))
+        .unwrap();
+    app.select_tab_by_name("inbox");
+    app.refresh().unwrap();
+    let goto = |app: &mut App, command: &str| {
+        app.on_key(KeyEvent::from(KeyCode::Char('/'))).unwrap();
+        app.input.set(command);
+        app.run_command().unwrap();
+    };
+
+    goto(&mut app, "/goto emal");
+    assert_eq!(app.tabs[app.tab_index].name, "next");
+    assert_eq!(app.tasks[app.selected].id, email);
+
+    // Ids created in the same millisecond share their leading characters.
+    let prefix = release[..release.len() - 2].to_ascii_lowercase();
+    goto(&mut app, &format!("/goto {}", prefix));
+    assert_eq!(app.tabs[app.tab_index].name, "done");
+    assert_eq!(app.tasks[app.selected].id, release);
+
+    goto(&mut app, &format!("/goto cpt://task/{}", email));
+    assert_eq!(app.tasks[app.selected].id, email);
+
+    goto(&mut app, "/goto nothing-like-this");
+    assert_eq!(app.tasks[app.selected].id, email);
+}
+
+#[test]
+fn tab_settings_hide_and_reorder_and_tabs_command_adjusts() {
+    use super::tabs::{arrange_tabs, default_tabs};
+    let settings = Settings::parse(
+        r#"
+        [tabs]
+        order = ["inbox", "next"]
+        hidden = ["someday", "calendar"]
+        "#,
+    )
+    .unwrap();
+    let (shown, hidden) = arrange_tabs(default_tabs(), &settings.tabs).unwrap();
+    let names: Vec<&str> = shown.iter().map(|tab| tab.name).collect();
+    assert_eq!(
+        names,
+        vec![
+            "inbox",
+            "next",
+            "all",
+            "today",
+            "waiting",
+            "scheduled",
+            "projects",
+            "done"
+        ]
+    );
+    assert_eq!(hidden.len(), 2);
+    let bad = Settings::parse("[tabs]\nhidden = [\"nope\"]").unwrap();
+    assert!(arrange_tabs(default_tabs(), &bad.tabs).is_err());
+
+    let (mut app, _dir) = app_with_tasks(&["Sort receipts"]);
+    let run = |app: &mut App, command: &str| {
+        app.on_key(KeyEvent::from(KeyCode::Char('/'))).unwrap();
+        app.input.set(command);
+        app.run_command().unwrap();
+    };
+    run(&mut app, "/tabs hide someday");
+    assert!(app.tabs.iter().all(|tab| tab.name != "someday"));
+    run(&mut app, "/tabs move done 1");
+    assert_eq!(app.tabs[0].name, "done");
+    assert_eq!(app.tabs[app.tab_index].name, "all", "current tab is kept");
+    run(&mut app, "/tabs show someday");
+    assert_eq!(app.tabs.last().unwrap().name, "someday");
+    run(&mut app, "/tabs reset");
+    assert_eq!(app.tabs[0].name, "all");
+    assert!(app.hidden_tabs.is_empty());
+}
+
+#[test]
+fn tab_counts_track_open_and_overdue_tasks() {
+    let (mut app, _dir) = app_with_tasks(&["Sort receipts due:2020-01-01", "Email Alice"]);
+    let count = |app: &App, name: &str| {
+        let idx = app.tabs.iter().position(|tab| tab.name == name).unwrap();
+        app.tab_counts[idx]
+    };
+    assert_eq!(
+        count(&app, "inbox"),
+        Some(TabCount {
+            open: 2,
+            overdue: 1
+        })
+    );
+    assert_eq!(count(&app, "next").map(|c| c.open), Some(0));
+    assert_eq!(count(&app, "done"), None);
+
+    app.on_key(KeyEvent::from(KeyCode::Char('n'))).unwrap();
+    assert_eq!(count(&app, "inbox").map(|c| c.open), Some(1));
+    assert_eq!(count(&app, "next").map(|c| c.open), Some(1));
+}
+
+#[test]
+fn waiting_tasks_group_by_person_longest_wait_first() {
+    let now = chrono::Utc::now();
+    let waiting = |id: &str, who: Option<&str>, days: i64| {
+        let mut task = dummy_task(id, None, vec![], vec![], 0);
+        task.status = TaskStatus::Waiting;
+        task.waiting_on = who.map(String::from);
+        task.waiting_since = Some(now - chrono::Duration::days(days));
+        task
+    };
+    let mut tasks = vec![
+        waiting("1", Some("Alice"), 1),
+        waiting("2", None, 9),
+        waiting("3", Some("sam "), 2),
+        waiting("4", Some("alice"), 5),
+        waiting("5", Some("Sam"), 3),
+    ];
+    sort_waiting(&mut tasks);
+    let ids: Vec<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "4", "3", "5", "2"]);
+    assert_eq!(
+        waiting_heading(&tasks, &tasks[1], now),
+        "👤 Alice · 2 tasks · waiting 5d"
+    );
+    assert_eq!(
+        waiting_heading(&tasks, &tasks[4], now),
+        "👤 No one named · 1 task · waiting 9d"
+    );
+}
@@ -0,0 +1,111 @@
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::model::TimeEntry;
+
+use super::App;
+
+/// The running timer with its task title cached for the header.
+#[derive(Debug, Clone)]
+pub(crate) struct ActiveTimer {
+    pub(crate) entry: TimeEntry,
+    pub(crate) title: String,
+}
+
+/// `h:mm:ss`, or `mm:ss` under an hour.
+pub(crate) fn format_elapsed(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+impl App {
+    /// Picks up a timer started or stopped elsewhere; the title is only looked up when it changes.
+    pub(crate) fn load_timer(&mut self) -> Result<()> {
+        let running = self.database.running_timer()?;
+        self.timer = match running {
+            Some(entry) => {
+                let title = match &self.timer {
+                    Some(timer) if timer.entry.task_id == entry.task_id => timer.title.clone(),
+                    _ => self
+                        .database
+                        .fetch_task(&entry.task_id)?
+                        .map_or_else(|| String::from("(deleted task)"), |task| task.title),
+                };
+                Some(ActiveTimer { entry, title })
+            }
+            None => None,
+        };
+        Ok(())
+    }
+
+    /// Stops the timer when it runs on the selected task, otherwise starts one there.
+    pub(crate) fn toggle_timer(&mut self) -> Result<()> {
+        let Some(task) = self.tasks.get(self.selected) else {
+            self.set_status_info("Nothing to time");
+            return Ok(());
+        };
+        let (id, title) = (task.id.clone(), task.title.clone());
+        self.quit_warned = false;
+        if self
+            .timer
+            .as_ref()
+            .is_some_and(|timer| timer.entry.task_id == id)
+        {
+            if let Some(entry) = self.database.stop_timer()? {
+                let elapsed = entry.ended_at.unwrap_or_else(Utc::now) - entry.started_at;
+                self.set_status_info(format!(
+                    "Stopped timer on {} after {}",
+                    title,
+                    format_elapsed(elapsed.num_seconds())
+                ));
+            }
+            self.timer = None;
+            return Ok(());
+        }
+        let switched = self.timer.take().map(|timer| timer.title);
+        let entry = self.database.start_timer(&id)?;
+        self.timer = Some(ActiveTimer {
+            entry,
+            title: title.clone(),
+        });
+        match switched {
+            Some(previous) => {
+                self.set_status_info(format!("Stopped {} and started timing {}", previous, title))
+            }
+            None => self.set_status_info(format!("Started timing {}", title)),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn timer_label(&self) -> Option<String> {
+        let timer = self.timer.as_ref()?;
+        let elapsed = Utc::now() - timer.entry.started_at;
+        Some(format!(
+            "{} {} {}",
+            self.icon("⏱", "Timer"),
+            format_elapsed(elapsed.num_seconds()),
+            timer.title
+        ))
+    }
+
+    /// Quitting with a running timer needs a second press; the timer keeps running in storage.
+    pub(crate) fn request_quit(&mut self) {
+        match &self.timer {
+            Some(timer) if !self.quit_warned => {
+                self.quit_warned = true;
+                let message = format!(
+                    "Timer still running on {} — quit again to leave it running, or {} to stop it",
+                    timer.title,
+                    self.keymap.hint("timer")
+                );
+                self.set_status_error(message);
+            }
+            _ => self.should_quit = true,
+        }
+    }
+}
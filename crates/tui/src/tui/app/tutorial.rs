@@ -0,0 +1,221 @@
+use std::cmp::min;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::model::{ListFilters, ListOutputItem, TaskStatus};
+
+use super::App;
+
+/// Steps of the first-run walkthrough, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TutorialStep {
+    Capture,
+    Promote,
+    Filter,
+    Complete,
+    Finished,
+}
+
+impl TutorialStep {
+    fn number(self) -> usize {
+        match self {
+            TutorialStep::Capture => 1,
+            TutorialStep::Promote => 2,
+            TutorialStep::Filter => 3,
+            TutorialStep::Complete => 4,
+            TutorialStep::Finished => 5,
+        }
+    }
+}
+
+/// A running walkthrough. Each step watches the app state rather than specific keys, so remapped
+/// bindings and commands complete it just as well.
+#[derive(Debug, Clone)]
+pub(crate) struct Tutorial {
+    pub(crate) step: TutorialStep,
+    /// Tasks captured before this moment don't count for the first step.
+    started_at: DateTime<Utc>,
+    /// The task captured in the first step, followed through the rest.
+    pub(crate) task_id: Option<String>,
+}
+
+const STEPS: usize = 4;
+
+impl App {
+    pub(crate) fn start_tutorial(&mut self) {
+        self.tutorial = Some(Tutorial {
+            step: TutorialStep::Capture,
+            started_at: Utc::now(),
+            task_id: None,
+        });
+    }
+
+    /// `/tutorial [skip]`: restarts the walkthrough, or dismisses it.
+    pub(crate) fn run_tutorial_command(&mut self, args: &[String]) {
+        match args {
+            [] => {
+                self.start_tutorial();
+                self.set_status_info("Tutorial started • /tutorial skip to dismiss");
+            }
+            [verb] if verb == "skip" => {
+                if self.tutorial.take().is_some() {
+                    self.set_status_info("Tutorial skipped • /tutorial starts it again");
+                } else {
+                    self.set_status_info("No tutorial running");
+                }
+            }
+            _ => self.set_status_error("Usage: /tutorial [skip]"),
+        }
+    }
+
+    /// Moves the walkthrough on once the current step's goal holds; called after every key and
+    /// tick.
+    pub(crate) fn advance_tutorial(&mut self) -> Result<()> {
+        let Some(tutorial) = self.tutorial.as_ref() else {
+            return Ok(());
+        };
+        let task = match &tutorial.task_id {
+            Some(id) => self.database.fetch_task(id)?,
+            None => None,
+        };
+        let next = match tutorial.step {
+            TutorialStep::Capture => {
+                let started_at = tutorial.started_at;
+                let captured = self
+                    .database
+                    .fetch_tasks(&ListFilters::for_view(None))?
+                    .into_iter()
+                    .filter_map(|item| match item {
+                        ListOutputItem::Task(task) => Some(*task),
+                        ListOutputItem::Project(_) => None,
+                    })
+                    .filter(|task| task.created_at >= started_at)
+                    .max_by_key(|task| task.created_at);
+                captured.map(|task| {
+                    let step = if task.status == TaskStatus::Next {
+                        TutorialStep::Filter
+                    } else {
+                        TutorialStep::Promote
+                    };
+                    (step, Some(task.id))
+                })
+            }
+            _ if tutorial.task_id.is_some() && task.is_none() => {
+                // The tutorial task was deleted; start over with a fresh capture.
+                Some((TutorialStep::Capture, None))
+            }
+            TutorialStep::Promote => task
+                .filter(|task| task.status == TaskStatus::Next)
+                .map(|_| (TutorialStep::Filter, tutorial.task_id.clone())),
+            TutorialStep::Filter => {
+                let filtered = !self.active_filters.is_empty()
+                    || self
+                        .quick_filter
+                        .as_ref()
+                        .is_some_and(|quick| !quick.query.is_empty());
+                filtered.then(|| (TutorialStep::Complete, tutorial.task_id.clone()))
+            }
+            TutorialStep::Complete => task
+                .filter(|task| task.status == TaskStatus::Done)
+                .map(|_| (TutorialStep::Finished, None)),
+            TutorialStep::Finished => None,
+        };
+        if let (Some((step, task_id)), Some(tutorial)) = (next, self.tutorial.as_mut()) {
+            tutorial.step = step;
+            tutorial.task_id = task_id;
+        }
+        Ok(())
+    }
+
+    fn tutorial_lines(&self, step: TutorialStep) -> Vec<Line<'static>> {
+        let key = |name: &str| {
+            Span::styled(
+                self.keymap.hint(name),
+                self.theme.bold(self.theme.highlight),
+            )
+        };
+        let text = |text: &str| Span::raw(text.to_string());
+        match step {
+            TutorialStep::Capture => vec![
+                Line::from(vec![
+                    text("Press "),
+                    key("add"),
+                    text(" and type a task. Tokens like "),
+                    Span::styled("+Home @phone due:fri", self.theme.fg(self.theme.info)),
+                    text(" fill in project, context, and due date."),
+                ]),
+                Line::from(vec![text("Enter captures it into the Inbox.")]),
+            ],
+            TutorialStep::Promote => vec![Line::from(vec![
+                text("Select your new task and press "),
+                key("next"),
+                text(" to promote it to a Next action — something you'll actually do soon."),
+            ])],
+            TutorialStep::Filter => vec![Line::from(vec![
+                text("Narrow the list: "),
+                key("quick-filter"),
+                text(" type-ahead filters by title, "),
+                key("filter"),
+                text(" picks projects, contexts, and tags."),
+            ])],
+            TutorialStep::Complete => vec![Line::from(vec![
+                text("Done with it? Select the task and press "),
+                key("done"),
+                text(" to complete it. "),
+                key("back"),
+                text(" clears a quick filter."),
+            ])],
+            TutorialStep::Finished => vec![
+                Line::from(vec![text(
+                    "That's the loop: capture, clarify, focus, finish. ",
+                )]),
+                Line::from(vec![
+                    key("help"),
+                    text(" lists every key and "),
+                    Span::styled("/help", self.theme.bold(self.theme.highlight)),
+                    text(" every command."),
+                ]),
+            ],
+        }
+    }
+
+    /// Docks the current step above the status bar, leaving the selected rows visible.
+    pub(crate) fn draw_tutorial(&self, f: &mut Frame<'_>, body: Rect) {
+        let Some(tutorial) = &self.tutorial else {
+            return;
+        };
+        let mut lines = self.tutorial_lines(tutorial.step);
+        lines.push(Line::from(Span::styled(
+            "/tutorial skip to dismiss",
+            self.theme.fg(self.theme.muted),
+        )));
+        let width = min(body.width.saturating_sub(4), 72);
+        let height = min(lines.len() as u16 + 3, body.height);
+        let area = Rect {
+            x: body.x + body.width.saturating_sub(width + 2),
+            y: body.y + body.height.saturating_sub(height),
+            width,
+            height,
+        };
+        let title = match tutorial.step {
+            TutorialStep::Finished => String::from("🎓 Tutorial complete"),
+            step => format!("🎓 Tutorial {}/{}", step.number(), STEPS),
+        };
+        let title = self.label(&title);
+        f.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.theme.title(&title))
+            .border_style(self.theme.fg(self.theme.accent))
+            .style(self.theme.panel_style());
+        f.render_widget(
+            Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+            area,
+        );
+    }
+}
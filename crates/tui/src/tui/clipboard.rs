@@ -0,0 +1,70 @@
+use std::io::{self, Write};
+
+use anyhow::{Context, Result};
+
+/// System clipboard with an OSC 52 fallback for SSH sessions and headless terminals.
+///
+/// The `arboard` handle is kept alive for the whole session because X11 and Wayland only serve
+/// the copied text while its owner exists.
+#[derive(Default)]
+pub(crate) struct Clipboard {
+    system: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    /// Copies `text`, returning a short label for how it was delivered.
+    pub(crate) fn copy(&mut self, text: &str) -> Result<&'static str> {
+        if self.system.is_none() {
+            self.system = arboard::Clipboard::new().ok();
+        }
+        if let Some(system) = self.system.as_mut() {
+            if system.set_text(text.to_owned()).is_ok() {
+                return Ok("clipboard");
+            }
+        }
+        let mut stdout = io::stdout();
+        stdout
+            .write_all(osc52_sequence(text).as_bytes())
+            .and_then(|_| stdout.flush())
+            .context("failed to write the OSC 52 clipboard sequence")?;
+        Ok("terminal clipboard")
+    }
+}
+
+/// Escape sequence asking the terminal to place `text` on the system clipboard.
+fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buf = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (buf[0] as u32) << 16 | (buf[1] as u32) << 8 | buf[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osc52_wraps_base64_payload() {
+        assert_eq!(base64(b"Ma"), "TWE=");
+        assert_eq!(base64(b"Man"), "TWFu");
+        assert_eq!(osc52_sequence("hi"), "\x1b]52;c;aGk=\x07");
+    }
+}
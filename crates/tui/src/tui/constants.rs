@@ -2,14 +2,21 @@ use std::time::Duration;
 
 pub(crate) const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub(crate) const TICK_RATE: Duration = Duration::from_millis(200);
+pub(crate) const DONE_PAGE_SIZE: usize = 50;
+pub(crate) const EXTERNAL_POLL: Duration = Duration::from_secs(1);
+pub(crate) const CHORD_TIMEOUT: Duration = Duration::from_millis(600);
+pub(crate) const MAX_COUNT: usize = 9_999;
+pub(crate) const HISTORY_LIMIT: usize = 500;
+pub(crate) const HISTORY_FILE: &str = "command_history";
+pub(crate) const MAX_TASK_SUGGESTIONS: usize = 20;
 
 pub(crate) const COMMAND_HELP: &str = concat!(
     "Commands: /help, /add <text>, /edit <id> [text], /next [id], /done [id], ",
-    "/delete [id], /filter (clear), /refresh, /view|/tab <name>, /quit"
+    "/delete [id], /filter (clear), /savefilter <1-9> [name], /sort <field> [asc|desc], /goto <id|text>, /tabs [hide|show|move|reset], /review, /stats, /export [json|csv|md|ics] [path], /tutorial [skip], /set confirm-delete <on|off>, /refresh, /view|/tab <name>, /quit"
 );
 
 pub(crate) const STATUS_ENTER_ADD: &str =
-    "Enter a task description, tokens allowed (Esc to cancel)";
+    "Enter a task description, tokens allowed (Tab for notes, Esc to cancel)";
 pub(crate) const STATUS_COMMAND_PALETTE: &str =
     "Type a /command • Up/Down: navigate • Tab/Right: complete • Enter: run • Esc: cancel";
 pub(crate) const STATUS_FILTER_PICKER: &str =
@@ -23,7 +30,9 @@ pub(crate) const STATUS_ENTER_EDIT: &str =
     "Edit task — adjust tokens, tokens apply immediately • Enter to save • Esc to cancel";
 pub(crate) const STATUS_PROJECT_INBOX: &str = "Select a task view to send items back to Inbox";
 pub(crate) const STATUS_PROJECT_SOMEDAY: &str = "Select a task view to move items into Someday";
+pub(crate) const STATUS_PROJECT_SORT: &str = "Select a task view to change the sort order";
 pub(crate) const STATUS_VIEW_DETAILS: &str = "Viewing task details • Enter/Esc to close";
-pub(crate) const STATUS_HELP: &str = "Keyboard reference — Enter/Esc to close";
+pub(crate) const STATUS_HELP: &str =
+    "Keyboard reference — j/k scroll • / search • Enter/Esc to close";
 pub(crate) const STATUS_CONFIRM_DELETE: &str =
     "Confirm deletion — arrows choose, Enter confirms, Esc cancels";
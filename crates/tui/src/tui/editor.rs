@@ -0,0 +1,104 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, Context, Result};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::Terminal;
+
+use super::Backend;
+
+/// Resolves the editor command from `$VISUAL`/`$EDITOR`, falling back to a platform default.
+pub(crate) fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|key| env::var(key).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                String::from("notepad")
+            } else {
+                String::from("vi")
+            }
+        })
+}
+
+/// Suspends the TUI, opens `initial` in the external editor, and returns the saved text.
+pub(crate) fn edit_in_external_editor(
+    terminal: &mut Terminal<Backend>,
+    label: &str,
+    initial: &str,
+) -> Result<String> {
+    let path = write_temp_file(label, initial)?;
+
+    suspend(terminal)?;
+    let status = launch(&editor_command(), &path);
+    resume(terminal)?;
+
+    let result = match status {
+        Ok(()) => {
+            fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))
+        }
+        Err(err) => Err(err),
+    };
+    // Dropping `path` removes the file.
+    result
+}
+
+/// Creates a new, uniquely named file holding `initial`, so nothing planted in the shared temp
+/// directory can redirect the write or the read-back.
+fn write_temp_file(label: &str, initial: &str) -> Result<tempfile::TempPath> {
+    let slug: String = label
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .take(12)
+        .collect();
+    let mut file = tempfile::Builder::new()
+        .prefix(&format!("cpt-notes-{slug}-"))
+        .suffix(".md")
+        .tempfile()
+        .context("failed to create a temp file for the editor")?;
+    file.write_all(initial.as_bytes())
+        .with_context(|| format!("failed to write {}", file.path().display()))?;
+    // Closes the handle so the editor can replace the file, even on Windows.
+    Ok(file.into_temp_path())
+}
+
+fn launch(editor: &str, path: &Path) -> Result<()> {
+    let mut parts = editor.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow!("no editor configured; set $EDITOR"))?;
+    let status = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .with_context(|| format!("failed to launch editor '{}'", program))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(anyhow!("editor '{}' exited with {}", program, status))
+    }
+}
+
+fn suspend(terminal: &mut Terminal<Backend>) -> Result<()> {
+    disable_raw_mode().context("failed to disable raw mode")?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)
+        .context("failed to leave alternate screen")?;
+    terminal.show_cursor().context("failed to show cursor")?;
+    Ok(())
+}
+
+fn resume(terminal: &mut Terminal<Backend>) -> Result<()> {
+    enable_raw_mode().context("failed to enable raw mode")?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)
+        .context("failed to enter alternate screen")?;
+    terminal.hide_cursor().context("failed to hide cursor")?;
+    terminal.clear().context("failed to redraw terminal")?;
+    Ok(())
+}
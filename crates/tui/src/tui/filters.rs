@@ -1,8 +1,8 @@
 use std::collections::BTreeSet;
 
-use crate::model::{ListFilters, Task};
+use crate::model::{ListFilters, SavedView, Task};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub(crate) struct ActiveFilters {
     pub(crate) project: Option<String>,
     pub(crate) contexts: BTreeSet<String>,
@@ -55,6 +55,26 @@ impl ActiveFilters {
         Some(parts.join(" | "))
     }
 
+    pub(crate) fn from_saved(view: &SavedView) -> Self {
+        Self {
+            project: view.project.clone(),
+            contexts: view.contexts.iter().cloned().collect(),
+            tags: view.tags.iter().cloned().collect(),
+            priority_min: view.priority_min,
+        }
+    }
+
+    pub(crate) fn to_saved(&self, slot: u8, name: String) -> SavedView {
+        SavedView {
+            slot,
+            name,
+            project: self.project.clone(),
+            contexts: self.contexts.iter().cloned().collect(),
+            tags: self.tags.iter().cloned().collect(),
+            priority_min: self.priority_min,
+        }
+    }
+
     pub(crate) fn apply_to(&self, filters: &mut ListFilters) {
         if let Some(project) = &self.project {
             filters.project = Some(project.clone());
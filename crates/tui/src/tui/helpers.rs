@@ -1,16 +1,255 @@
 use std::cmp::min;
+use std::collections::HashMap;
 
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::{Line, Span};
 
-use crate::model::Task;
+use crate::capture::TaskInput;
+use crate::core::services::stats::format_age;
+use crate::core::RepeatRule;
+use crate::model::{Task, TaskStatus};
+use crate::parser;
 
-pub const BG_BASE: Color = Color::Rgb(14, 17, 23);
-pub const BG_PANEL: Color = Color::Rgb(22, 26, 34);
-pub const BG_ACCENT: Color = Color::Rgb(32, 37, 47);
-pub const FG_ACCENT: Color = Color::Rgb(120, 161, 255);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TodayBucket {
+    Overdue,
+    DueToday,
+    StartingToday,
+}
+
+impl TodayBucket {
+    pub fn label(self) -> &'static str {
+        match self {
+            TodayBucket::Overdue => "⚠️ Overdue",
+            TodayBucket::DueToday => "📌 Due today",
+            TodayBucket::StartingToday => "🌅 Starting today",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompletionBucket {
+    Today,
+    Yesterday,
+    ThisWeek,
+    Earlier,
+}
+
+impl CompletionBucket {
+    pub fn label(self) -> &'static str {
+        match self {
+            CompletionBucket::Today => "Today",
+            CompletionBucket::Yesterday => "Yesterday",
+            CompletionBucket::ThisWeek => "This week",
+            CompletionBucket::Earlier => "Earlier",
+        }
+    }
+}
+
+pub fn completion_bucket(task: &Task, today: NaiveDate) -> CompletionBucket {
+    let Some(completed) = task.completed_at.as_ref().map(local_date) else {
+        return CompletionBucket::Earlier;
+    };
+    let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    if completed >= today {
+        CompletionBucket::Today
+    } else if completed == today - Duration::days(1) {
+        CompletionBucket::Yesterday
+    } else if completed >= week_start {
+        CompletionBucket::ThisWeek
+    } else {
+        CompletionBucket::Earlier
+    }
+}
+
+pub fn local_date(value: &DateTime<Utc>) -> NaiveDate {
+    let local: DateTime<Local> = (*value).into();
+    local.date_naive()
+}
+
+pub fn today_bucket(task: &Task, today: NaiveDate) -> Option<TodayBucket> {
+    if let Some(due) = task.due_at.as_ref().map(local_date) {
+        if due < today {
+            return Some(TodayBucket::Overdue);
+        }
+        if due == today {
+            return Some(TodayBucket::DueToday);
+        }
+    }
+    match task.defer_until.as_ref().map(local_date) {
+        Some(defer) if defer == today => Some(TodayBucket::StartingToday),
+        _ => None,
+    }
+}
+
+/// How close an open task is to its deadline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueUrgency {
+    Overdue,
+    /// Due within the next 24 hours.
+    Soon,
+}
+
+pub fn due_urgency(task: &Task, now: DateTime<Utc>) -> Option<DueUrgency> {
+    if matches!(task.status, TaskStatus::Done | TaskStatus::Canceled) {
+        return None;
+    }
+    let due = task.due_at?;
+    if due < now {
+        Some(DueUrgency::Overdue)
+    } else if due <= now + Duration::hours(24) {
+        Some(DueUrgency::Soon)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScheduleBucket {
+    Overdue,
+    Today,
+    Tomorrow,
+    ThisWeek,
+    Later,
+    Undated,
+}
+
+impl ScheduleBucket {
+    pub fn label(self) -> &'static str {
+        match self {
+            ScheduleBucket::Overdue => "⚠️ Overdue",
+            ScheduleBucket::Today => "📌 Today",
+            ScheduleBucket::Tomorrow => "🌄 Tomorrow",
+            ScheduleBucket::ThisWeek => "📆 This week",
+            ScheduleBucket::Later => "🔭 Later",
+            ScheduleBucket::Undated => "❔ No date",
+        }
+    }
+}
+
+/// Files a Scheduled task by its next date: past-due tasks are overdue, otherwise the earlier of
+/// the defer and due dates decides; a defer date already passed counts as today.
+pub fn schedule_bucket(task: &Task, today: NaiveDate) -> ScheduleBucket {
+    let due = task.due_at.as_ref().map(local_date);
+    if due.is_some_and(|due| due < today) {
+        return ScheduleBucket::Overdue;
+    }
+    let defer = task.defer_until.as_ref().map(local_date);
+    let Some(date) = due.into_iter().chain(defer).min() else {
+        return ScheduleBucket::Undated;
+    };
+    let week_end = today + Duration::days(7 - today.weekday().num_days_from_monday() as i64);
+    if date <= today {
+        ScheduleBucket::Today
+    } else if date == today + Duration::days(1) {
+        ScheduleBucket::Tomorrow
+    } else if date < week_end {
+        ScheduleBucket::ThisWeek
+    } else {
+        ScheduleBucket::Later
+    }
+}
+
+/// Who a waiting task is blocked on, normalized for grouping; `None` when nobody is named.
+pub fn waiting_key(task: &Task) -> Option<String> {
+    task.waiting_on
+        .as_deref()
+        .map(str::trim)
+        .filter(|who| !who.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// When a task started waiting; tasks without `waiting_since` count from their creation.
+pub fn waiting_start(task: &Task) -> DateTime<Utc> {
+    task.waiting_since.unwrap_or(task.created_at)
+}
+
+/// Orders waiting tasks into one run per person, the longest-waiting person first. Each
+/// run keeps the fetched order, so the chosen sort still applies; tasks with nobody named go last.
+pub fn sort_waiting(tasks: &mut [Task]) {
+    let mut oldest: HashMap<Option<String>, DateTime<Utc>> = HashMap::new();
+    for task in tasks.iter() {
+        let start = waiting_start(task);
+        oldest
+            .entry(waiting_key(task))
+            .and_modify(|at| *at = (*at).min(start))
+            .or_insert(start);
+    }
+    tasks.sort_by_cached_key(|task| {
+        let key = waiting_key(task);
+        (key.is_none(), oldest[&key], key)
+    });
+}
+
+/// Heading over a person's run in the Waiting view: the name as first written, the task count,
+/// and the longest wait in the run.
+pub fn waiting_heading(tasks: &[Task], task: &Task, now: DateTime<Utc>) -> String {
+    let key = waiting_key(task);
+    let group: Vec<&Task> = tasks
+        .iter()
+        .filter(|other| waiting_key(other) == key)
+        .collect();
+    let oldest = group
+        .iter()
+        .map(|other| waiting_start(other))
+        .min()
+        .unwrap_or(now);
+    let who = match key {
+        Some(_) => group
+            .first()
+            .and_then(|first| first.waiting_on.as_deref())
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+        None => String::from("No one named"),
+    };
+    format!(
+        "👤 {} · {} {} · waiting {}",
+        who,
+        group.len(),
+        if group.len() == 1 { "task" } else { "tasks" },
+        format_age((now - oldest).num_seconds())
+    )
+}
+
+/// Scores `query` as a case-insensitive subsequence of `candidate`; `None` when it doesn't match.
+///
+/// Matches at word starts and runs of consecutive characters score higher, gaps cost a little,
+/// so `emal` ranks "Email Alice" above "Review meal plan".
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let chars: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0i64;
+    let mut matched = 0;
+    let mut prev: Option<usize> = None;
+    for (idx, ch) in chars.iter().enumerate() {
+        if matched == query.len() {
+            break;
+        }
+        if *ch != query[matched] {
+            continue;
+        }
+        score += 1;
+        if idx == 0 || !chars[idx - 1].is_alphanumeric() {
+            score += 8;
+        }
+        score += match prev {
+            Some(p) if p + 1 == idx => 5,
+            Some(p) => -((idx - p - 1).min(5) as i64),
+            None => -((idx.min(10) / 2) as i64),
+        };
+        prev = Some(idx);
+        matched += 1;
+    }
+    (matched == query.len()).then_some(score)
+}
 
 pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     let w = min(width, area.width);
@@ -53,6 +292,76 @@ pub fn join_prefixed(values: &[String], prefix: &str) -> String {
         .join(" ")
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChipKind {
+    Project,
+    Area,
+    Context,
+    Tag,
+    Due,
+    Defer,
+    Priority,
+    Energy,
+    Estimate,
+    Repeat,
+    Waiting,
+}
+
+/// Parses capture text the way `add` will and lists what it found, so the Add and Edit overlays
+/// can preview tokens (or the parse error) before submitting.
+pub fn capture_chips(text: &str) -> anyhow::Result<Vec<(ChipKind, String)>> {
+    let input = TaskInput {
+        text: text.split_whitespace().map(String::from).collect(),
+        ..Default::default()
+    };
+    let task = parser::parse_capture(&input)?.task;
+    let date = |at: &DateTime<Utc>| {
+        let local: DateTime<Local> = (*at).into();
+        local.format("%a %b %-d").to_string()
+    };
+    let mut chips = Vec::new();
+    if let Some(project) = task.project {
+        chips.push((ChipKind::Project, format!("+{}", project)));
+    }
+    chips.extend(
+        task.areas
+            .iter()
+            .map(|area| (ChipKind::Area, format!("&{}", area))),
+    );
+    chips.extend(
+        task.contexts
+            .iter()
+            .map(|context| (ChipKind::Context, format!("@{}", context))),
+    );
+    chips.extend(
+        task.tags
+            .iter()
+            .map(|tag| (ChipKind::Tag, format!("#{}", tag))),
+    );
+    if let Some(due) = &task.due_at {
+        chips.push((ChipKind::Due, format!("due {}", date(due))));
+    }
+    if let Some(defer) = &task.defer_until {
+        chips.push((ChipKind::Defer, format!("start {}", date(defer))));
+    }
+    if task.priority > 0 {
+        chips.push((ChipKind::Priority, format!("p{}", task.priority)));
+    }
+    if let Some(energy) = task.energy {
+        chips.push((ChipKind::Energy, format!("{} energy", energy.as_str())));
+    }
+    if let Some(minutes) = task.time_estimate {
+        chips.push((ChipKind::Estimate, format!("{} min", minutes)));
+    }
+    if let Some(repeat) = &task.repeat {
+        chips.push((ChipKind::Repeat, repeat_summary(repeat)));
+    }
+    if let Some(who) = task.waiting_on {
+        chips.push((ChipKind::Waiting, format!("waiting on {}", who)));
+    }
+    Ok(chips)
+}
+
 pub fn compose_task_capture(task: &Task) -> String {
     let mut components = Vec::new();
     components.push(task.title.clone());
@@ -101,6 +410,10 @@ pub fn compose_task_capture(task: &Task) -> String {
         components.push(format!("defer:{}", defer_until.format("%Y-%m-%d")));
     }
 
+    if let Some(repeat) = &task.repeat {
+        components.push(format!("rep:{}", repeat));
+    }
+
     if let Some(waiting_on) = &task.waiting_on {
         if !waiting_on.is_empty() {
             components.push(format!("wait:{}", waiting_on));
@@ -114,6 +427,15 @@ pub fn compose_task_capture(task: &Task) -> String {
     }
 }
 
+/// A stored repeat rule in words, such as "every 2 weeks on Friday"; text that no longer parses
+/// is shown as stored.
+pub fn repeat_summary(repeat: &str) -> String {
+    repeat
+        .parse::<RepeatRule>()
+        .map(|rule| rule.summary())
+        .unwrap_or_else(|_| repeat.to_string())
+}
+
 pub fn format_task_detail_entries(task: &Task) -> Vec<(String, String)> {
     let mut entries = Vec::new();
     entries.push((String::from("Title"), task.title.clone()));
@@ -145,6 +467,9 @@ pub fn format_task_detail_entries(task: &Task) -> Vec<(String, String)> {
     if let Some(minutes) = task.time_estimate {
         entries.push((String::from("Estimate"), format!("{} min", minutes)));
     }
+    if let Some(progress) = task.checklist() {
+        entries.push((String::from("Checklist"), format!("{} done", progress)));
+    }
     let due = format_opt_datetime(task.due_at.as_ref());
     if !due.is_empty() {
         entries.push((String::from("Due"), due));
@@ -153,6 +478,9 @@ pub fn format_task_detail_entries(task: &Task) -> Vec<(String, String)> {
     if !defer.is_empty() {
         entries.push((String::from("Start"), defer));
     }
+    if let Some(repeat) = &task.repeat {
+        entries.push((String::from("Repeats"), repeat_summary(repeat)));
+    }
     if let Some(waiting_on) = &task.waiting_on {
         if !waiting_on.is_empty() {
             entries.push((String::from("Waiting on"), waiting_on.clone()));
@@ -180,34 +508,75 @@ pub fn format_task_detail_entries(task: &Task) -> Vec<(String, String)> {
     entries
 }
 
-pub fn build_help_lines() -> Vec<(&'static str, &'static str)> {
-    vec![
-        ("Tab / Shift+Tab", "Switch GTD views"),
-        ("j / k or ↓ / ↑", "Move selection"),
-        ("q", "Quit"),
-        ("Enter", "Toggle task detail overlay"),
-        ("h", "Toggle this help overlay"),
-        ("Shift+Enter", "Insert newline while adding or editing"),
-        ("a", "Capture a new task"),
-        ("e", "Edit selected task"),
-        ("n", "Promote to Next actions"),
-        ("s", "Move to Someday/Maybe"),
-        ("i", "Send back to Inbox"),
-        ("d", "Mark as Done"),
-        ("x / Delete", "Delete task (with confirmation)"),
-        ("f", "Open filter picker"),
-        ("/", "Command palette"),
-        ("C (in filter)", "Clear all filters"),
-        ("r", "Refresh from storage"),
-        ("Esc", "Cancel/close overlays"),
-    ]
-}
-
-pub fn accent_title(text: &str) -> Line<'static> {
-    Line::from(vec![Span::styled(
-        text.to_owned(),
-        Style::default().fg(FG_ACCENT).add_modifier(Modifier::BOLD),
-    )])
+/// Keys that only apply inside a particular mode and are not part of the keymap.
+const CONTEXT_HELP: [(&str, &str); 15] = [
+    ("Shift+Enter", "Insert newline while adding or editing"),
+    ("h / l (board)", "Move between board columns"),
+    ("H / L (board)", "Move task to the previous/next status"),
+    ("← / → (calendar)", "Previous/next week or month"),
+    ("v / t (calendar)", "Toggle week/month, jump to today"),
+    ("Enter (calendar)", "Jump to the task in its view"),
+    ("C (in filter)", "Clear all filters"),
+    ("r (details)", "Change how the task repeats"),
+    ("Ctrl+R (edit form)", "Change how the task repeats"),
+    (
+        "Enter (sidebar)",
+        "Apply or clear the highlighted project/context",
+    ),
+    ("↑ / ↓ (command)", "Recall previous commands"),
+    ("1-9", "Apply a saved filter preset"),
+    ("/ (help)", "Search this reference"),
+    ("j / k (help)", "Scroll this reference"),
+    ("Esc", "Cancel/close overlays"),
+];
+
+/// One row of the help overlay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HelpLine {
+    Section(String),
+    Binding(String, String),
+}
+
+/// Help-overlay rows: the keymap's sections, then mode-specific keys, narrowed to `query`.
+///
+/// The query matches keys or descriptions case-insensitively; sections left empty are dropped.
+pub fn build_help_lines(
+    sections: Vec<(String, Vec<(String, String)>)>,
+    query: &str,
+) -> Vec<HelpLine> {
+    let query = query.trim().to_lowercase();
+    let matches = |combo: &str, desc: &str| {
+        query.is_empty()
+            || combo.to_lowercase().contains(&query)
+            || desc.to_lowercase().contains(&query)
+    };
+    let context = CONTEXT_HELP
+        .iter()
+        .map(|(combo, desc)| (combo.to_string(), desc.to_string()))
+        .collect();
+
+    let mut lines = Vec::new();
+    for (section, rows) in sections
+        .into_iter()
+        .chain(std::iter::once((String::from("In other modes"), context)))
+    {
+        let rows: Vec<(String, String)> = rows
+            .into_iter()
+            .filter(|(combo, desc)| matches(combo, desc))
+            .collect();
+        if rows.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(HelpLine::Section(String::new()));
+        }
+        lines.push(HelpLine::Section(section));
+        lines.extend(
+            rows.into_iter()
+                .map(|(combo, desc)| HelpLine::Binding(combo, desc)),
+        );
+    }
+    lines
 }
 
 pub fn format_opt_datetime(value: Option<&DateTime<Utc>>) -> String {
@@ -218,3 +587,74 @@ pub fn format_opt_datetime(value: Option<&DateTime<Utc>>) -> String {
         })
         .unwrap_or_default()
 }
+
+/// `Jan 5`-style date for narrow tables; the year and time are dropped.
+pub fn format_short_date(value: Option<&DateTime<Utc>>) -> String {
+    value
+        .map(|dt| {
+            let local: DateTime<Local> = (*dt).into();
+            local.format("%b %-d").to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// Column set for the task table, picked from the frame width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableLayout {
+    /// Every column.
+    Full,
+    /// No Steps, Contexts, or Tags columns, and short dates.
+    Compact,
+    /// Two-line rows: the title over a one-line summary, plus the date.
+    Narrow,
+}
+
+pub const COMPACT_TABLE_WIDTH: u16 = 100;
+pub const NARROW_TABLE_WIDTH: u16 = 70;
+
+pub fn table_layout(width: u16) -> TableLayout {
+    if width >= COMPACT_TABLE_WIDTH {
+        TableLayout::Full
+    } else if width >= NARROW_TABLE_WIDTH {
+        TableLayout::Compact
+    } else {
+        TableLayout::Narrow
+    }
+}
+
+/// Emoji and pictograph codepoints (including the variation selector and keycap joiner) that
+/// `[display] no_emoji` keeps off screen; box drawing, arrows, and bullets are not emoji.
+pub fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF
+            | 0x2600..=0x27BF
+            | 0x2300..=0x23FF
+            | 0x2B00..=0x2BFF
+            | 0x2139
+            | 0x203C
+            | 0x2049
+            | 0xFE0F
+            | 0x20E3
+    )
+}
+
+/// Drops emoji from an interface label, along with the words they leave empty: `"📥 Inbox"`
+/// becomes `"Inbox"` and `"#️⃣ ID"` becomes `"ID"`. Only for built-in text, never task data.
+pub fn plain_label(label: &str) -> String {
+    label
+        .split(' ')
+        .filter_map(|word| {
+            let mut plain = String::with_capacity(word.len());
+            for c in word.chars() {
+                if c == '\u{20E3}' {
+                    plain.pop();
+                } else if !is_emoji(c) {
+                    plain.push(c);
+                }
+            }
+            (word.is_empty() || !plain.is_empty()).then_some(plain)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use super::constants::HISTORY_LIMIT;
+
+/// Previously run `/` commands, newest last, with a shell-style recall cursor.
+#[derive(Debug, Clone, Default)]
+pub struct CommandHistory {
+    entries: Vec<String>,
+    cursor: Option<usize>,
+    path: Option<PathBuf>,
+}
+
+impl CommandHistory {
+    /// History that lives only for this session.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// History backed by a file, one command per line; a missing file starts empty.
+    pub fn persisted(path: PathBuf) -> Result<Self> {
+        let entries = if path.exists() {
+            fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let mut history = Self {
+            entries,
+            cursor: None,
+            path: Some(path),
+        };
+        history.trim();
+        Ok(history)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn is_browsing(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    pub fn reset_cursor(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Appends a command (skipping immediate repeats) and writes the file when persisted.
+    pub fn record(&mut self, command: &str) -> Result<()> {
+        self.cursor = None;
+        let command = command.trim();
+        if command.is_empty() || command.contains('\n') {
+            return Ok(());
+        }
+        if self.entries.last().map(String::as_str) == Some(command) {
+            return Ok(());
+        }
+        self.entries.push(command.to_string());
+        self.trim();
+        self.save()
+    }
+
+    /// Steps back in time; stays on the oldest entry once reached.
+    pub fn older(&mut self) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let next = match self.cursor {
+            None => self.entries.len() - 1,
+            Some(idx) => idx.saturating_sub(1),
+        };
+        self.cursor = Some(next);
+        self.entries.get(next).map(String::as_str)
+    }
+
+    /// Steps forward; returns `None` after the newest entry so the caller can restore a blank prompt.
+    pub fn newer(&mut self) -> Option<&str> {
+        let idx = self.cursor?;
+        if idx + 1 >= self.entries.len() {
+            self.cursor = None;
+            return None;
+        }
+        self.cursor = Some(idx + 1);
+        self.entries.get(idx + 1).map(String::as_str)
+    }
+
+    fn trim(&mut self) {
+        if self.entries.len() > HISTORY_LIMIT {
+            let excess = self.entries.len() - HISTORY_LIMIT;
+            self.entries.drain(..excess);
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut contents = self.entries.join("\n");
+        contents.push('\n');
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recall_walks_back_and_returns_to_blank() {
+        let mut history = CommandHistory::in_memory();
+        history.record("/view next").unwrap();
+        history.record("/sort due").unwrap();
+        history.record("/sort due").unwrap();
+
+        assert_eq!(history.older(), Some("/sort due"));
+        assert_eq!(history.older(), Some("/view next"));
+        assert_eq!(history.older(), Some("/view next"));
+        assert_eq!(history.newer(), Some("/sort due"));
+        assert_eq!(history.newer(), None);
+        assert!(!history.is_browsing());
+    }
+
+    #[test]
+    fn persisted_history_survives_reload() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("command_history");
+        let mut history = CommandHistory::persisted(path.clone()).unwrap();
+        history.record("/done 01H").unwrap();
+        history.record("/refresh").unwrap();
+
+        let mut reloaded = CommandHistory::persisted(path).unwrap();
+        assert_eq!(reloaded.older(), Some("/refresh"));
+        assert_eq!(reloaded.older(), Some("/done 01H"));
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::{Context, Result};
+
+/// `http(s)://` links in `text`, in order of appearance, without duplicates or wrapping punctuation.
+pub(crate) fn extract_urls(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        let word = word.trim_start_matches(['(', '<', '[', '"', '\'']);
+        if !(word.starts_with("http://") || word.starts_with("https://")) {
+            continue;
+        }
+        let url = word.trim_end_matches([')', '>', ']', '"', '\'', '.', ',', ';', ':', '!', '?']);
+        if url.len() > "https://".len() && !urls.iter().any(|seen| seen == url) {
+            urls.push(url.to_string());
+        }
+    }
+    urls
+}
+
+/// Hands `url` to the browser (`$BROWSER` or the platform default). The URL comes from task
+/// text, so it never goes through a shell: `cmd /C start` would run whatever follows an `&`.
+pub(crate) fn open_in_browser(url: &str) -> Result<()> {
+    opener::open_browser(url).with_context(|| format!("failed to open {}", url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_urls_strips_punctuation_and_duplicates() {
+        let text =
+            "Read (https://example.com/a), then https://example.com/a and <http://x.io/b?c=1>.";
+        assert_eq!(
+            extract_urls(text),
+            vec!["https://example.com/a", "http://x.io/b?c=1"]
+        );
+        assert!(extract_urls("no links here, https:// alone").is_empty());
+    }
+}
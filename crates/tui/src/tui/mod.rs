@@ -15,9 +15,15 @@ use crate::db::Database;
 
 mod app;
 mod buffer;
+mod clipboard;
 mod constants;
+mod editor;
 mod filters;
 mod helpers;
+mod history;
+mod links;
+mod settings;
+mod theme;
 
 use app::App;
 use constants::TICK_RATE;
@@ -42,6 +48,7 @@ pub fn run(config: AppConfig) -> Result<()> {
 
     let mut app = App::new(config, database, first_run)?;
     if first_run {
+        app.start_tutorial();
         app.set_status_info(format!(
             "Initialized cpt.run data store\n  database file: {}",
             db_path_str
@@ -77,8 +84,14 @@ fn run_app(terminal: &mut Terminal<Backend>, app: &mut App) -> Result<()> {
             }
         }
 
+        if let Some(request) = app.take_notes_request() {
+            let outcome =
+                editor::edit_in_external_editor(terminal, &request.task_id, &request.notes);
+            app.finish_notes_edit(request, outcome)?;
+        }
+
         if last_tick.elapsed() >= TICK_RATE {
-            app.on_tick();
+            app.on_tick()?;
             last_tick = Instant::now();
         }
     }
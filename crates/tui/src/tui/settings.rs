@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use super::theme::Theme;
+
+/// TUI preferences read from `config.toml` in the data directory. Every section is optional.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Settings {
+    pub(crate) theme: ThemeSettings,
+    pub(crate) keys: KeySettings,
+    pub(crate) history: HistorySettings,
+    pub(crate) status_bar: StatusBarSettings,
+    pub(crate) confirm: ConfirmSettings,
+    pub(crate) tabs: TabSettings,
+    pub(crate) display: DisplaySettings,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct ThemeSettings {
+    pub(crate) preset: Option<String>,
+    pub(crate) colors: BTreeMap<String, String>,
+}
+
+/// `layout` picks a base keymap; `bindings` maps action names to one or more keys or chords.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct KeySettings {
+    pub(crate) layout: Option<String>,
+    pub(crate) bindings: BTreeMap<String, KeyList>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &String> {
+        match self {
+            KeyList::One(key) => std::slice::from_ref(key).iter(),
+            KeyList::Many(keys) => keys.iter(),
+        }
+    }
+}
+
+/// `persist = false` keeps `/` command history in memory for the session only.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct HistorySettings {
+    pub(crate) persist: bool,
+}
+
+impl Default for HistorySettings {
+    fn default() -> Self {
+        Self { persist: true }
+    }
+}
+
+/// `delete = false` deletes a single task without the confirmation overlay.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct ConfirmSettings {
+    pub(crate) delete: bool,
+}
+
+impl Default for ConfirmSettings {
+    fn default() -> Self {
+        Self { delete: true }
+    }
+}
+
+/// Tab names listed in `order` come first; `hidden` tabs are left out of the tab bar, and
+/// `counts = false` drops the task counts from the labels.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct TabSettings {
+    pub(crate) order: Vec<String>,
+    pub(crate) hidden: Vec<String>,
+    pub(crate) counts: bool,
+}
+
+impl Default for TabSettings {
+    fn default() -> Self {
+        Self {
+            order: Vec::new(),
+            hidden: Vec::new(),
+            counts: true,
+        }
+    }
+}
+
+/// `highlight_due_rows = true` colors whole rows of overdue and due-soon tasks, not just the
+/// Due column. `no_emoji = true` draws tabs, headings, and markers as plain text (task titles are
+/// left as typed) and, unless a theme preset is set, switches to the high-contrast theme.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct DisplaySettings {
+    pub(crate) highlight_due_rows: bool,
+    pub(crate) no_emoji: bool,
+}
+
+/// Footer segments per line, in display order; an empty list hides that line.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct StatusBarSettings {
+    pub(crate) top: Vec<String>,
+    pub(crate) bottom: Vec<String>,
+}
+
+impl Default for StatusBarSettings {
+    fn default() -> Self {
+        Self {
+            top: vec![String::from("status"), String::from("counts")],
+            bottom: vec![String::from("keys")],
+        }
+    }
+}
+
+impl Settings {
+    /// Reads the settings file; a missing file yields the defaults.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Self::parse(&raw).with_context(|| format!("invalid settings in {}", path.display()))
+    }
+
+    pub(crate) fn parse(raw: &str) -> Result<Self> {
+        Ok(toml::from_str(raw)?)
+    }
+
+    pub(crate) fn theme(&self) -> Result<Theme> {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let preset = self
+            .theme
+            .preset
+            .as_deref()
+            .or(self.display.no_emoji.then_some("high-contrast"));
+        Theme::resolve(preset, &self.theme.colors, no_color)
+    }
+}
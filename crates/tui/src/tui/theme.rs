@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+pub const PRESETS: [&str; 4] = ["dark", "light", "high-contrast", "no-color"];
+
+/// Colors used across the TUI, resolved once from the `[theme]` config section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub base: Color,
+    pub panel: Color,
+    pub selection: Color,
+    pub accent: Color,
+    pub text: Color,
+    pub subtle: Color,
+    pub muted: Color,
+    pub success: Color,
+    pub highlight: Color,
+    pub info: Color,
+    pub special: Color,
+    pub danger: Color,
+    pub contrast: Color,
+    /// Set by the `no-color` preset; selections fall back to reversed video.
+    pub monochrome: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            base: Color::Rgb(14, 17, 23),
+            panel: Color::Rgb(22, 26, 34),
+            selection: Color::Rgb(32, 37, 47),
+            accent: Color::Rgb(120, 161, 255),
+            text: Color::White,
+            subtle: Color::Gray,
+            muted: Color::DarkGray,
+            success: Color::Green,
+            highlight: Color::Yellow,
+            info: Color::Cyan,
+            special: Color::Magenta,
+            danger: Color::Red,
+            contrast: Color::Black,
+            monochrome: false,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            base: Color::Rgb(246, 247, 249),
+            panel: Color::Rgb(255, 255, 255),
+            selection: Color::Rgb(221, 228, 240),
+            accent: Color::Rgb(38, 84, 196),
+            text: Color::Rgb(24, 28, 36),
+            subtle: Color::Rgb(70, 76, 88),
+            muted: Color::Rgb(130, 136, 148),
+            success: Color::Rgb(24, 128, 56),
+            highlight: Color::Rgb(176, 96, 0),
+            info: Color::Rgb(0, 118, 140),
+            special: Color::Rgb(138, 43, 160),
+            danger: Color::Rgb(196, 30, 40),
+            contrast: Color::White,
+            monochrome: false,
+        }
+    }
+
+    /// Bright colors on black with no dim roles, for low-vision use and `[display] no_emoji`.
+    pub fn high_contrast() -> Self {
+        Self {
+            base: Color::Black,
+            panel: Color::Black,
+            selection: Color::Blue,
+            accent: Color::LightCyan,
+            text: Color::White,
+            subtle: Color::White,
+            muted: Color::Gray,
+            success: Color::LightGreen,
+            highlight: Color::LightYellow,
+            info: Color::LightCyan,
+            special: Color::LightMagenta,
+            danger: Color::LightRed,
+            contrast: Color::Black,
+            monochrome: false,
+        }
+    }
+
+    pub fn no_color() -> Self {
+        Self {
+            base: Color::Reset,
+            panel: Color::Reset,
+            selection: Color::Reset,
+            accent: Color::Reset,
+            text: Color::Reset,
+            subtle: Color::Reset,
+            muted: Color::Reset,
+            success: Color::Reset,
+            highlight: Color::Reset,
+            info: Color::Reset,
+            special: Color::Reset,
+            danger: Color::Reset,
+            contrast: Color::Reset,
+            monochrome: true,
+        }
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "dark" | "default" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" | "contrast" => Some(Self::high_contrast()),
+            "no-color" | "nocolor" | "none" | "mono" => Some(Self::no_color()),
+            _ => None,
+        }
+    }
+
+    /// Builds a theme from a preset name plus per-role overrides (`accent = "#ff8800"`).
+    ///
+    /// The `NO_COLOR` convention wins over everything so terminals that opt out stay plain.
+    pub fn resolve(
+        preset: Option<&str>,
+        overrides: &BTreeMap<String, String>,
+        no_color_env: bool,
+    ) -> Result<Self> {
+        if no_color_env {
+            return Ok(Self::no_color());
+        }
+        let mut theme = match preset {
+            Some(name) => Self::preset(name).ok_or_else(|| {
+                anyhow!(
+                    "unknown theme '{}' (expected one of: {})",
+                    name,
+                    PRESETS.join(", ")
+                )
+            })?,
+            None => Self::dark(),
+        };
+        if theme.monochrome {
+            return Ok(theme);
+        }
+        for (role, value) in overrides {
+            let color = Color::from_str(value.trim())
+                .map_err(|_| anyhow!("invalid color '{}' for theme.{}", value, role))?;
+            *theme.slot_mut(role)? = color;
+        }
+        Ok(theme)
+    }
+
+    fn slot_mut(&mut self, role: &str) -> Result<&mut Color> {
+        Ok(match role {
+            "base" => &mut self.base,
+            "panel" => &mut self.panel,
+            "selection" => &mut self.selection,
+            "accent" => &mut self.accent,
+            "text" => &mut self.text,
+            "subtle" => &mut self.subtle,
+            "muted" => &mut self.muted,
+            "success" => &mut self.success,
+            "highlight" => &mut self.highlight,
+            "info" => &mut self.info,
+            "special" => &mut self.special,
+            "danger" => &mut self.danger,
+            "contrast" => &mut self.contrast,
+            other => bail!("unknown theme color '{}'", other),
+        })
+    }
+
+    pub fn fg(&self, color: Color) -> Style {
+        Style::default().fg(color)
+    }
+
+    pub fn bold(&self, color: Color) -> Style {
+        self.fg(color).add_modifier(Modifier::BOLD)
+    }
+
+    pub fn panel_style(&self) -> Style {
+        Style::default().fg(self.text).bg(self.panel)
+    }
+
+    pub fn base_style(&self) -> Style {
+        Style::default().fg(self.text).bg(self.base)
+    }
+
+    pub fn border(&self) -> Style {
+        self.fg(self.muted)
+    }
+
+    /// Highlighted row/tab: `color` on the selection background, reversed when colorless.
+    pub fn selected(&self, color: Color) -> Style {
+        let style = self.bold(color).bg(self.selection);
+        if self.monochrome {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    }
+
+    /// Filled badge such as the confirm buttons: `contrast` text on a `fill` background.
+    pub fn badge(&self, fill: Color) -> Style {
+        let style = self.bold(self.contrast).bg(fill);
+        if self.monochrome {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    }
+
+    pub fn title(&self, text: &str) -> Line<'static> {
+        Line::from(vec![Span::styled(text.to_owned(), self.bold(self.accent))])
+    }
+}
@@ -12,3 +12,5 @@ pub use cpt_core::parser;
 
 pub use cpt_desktop as desktop;
 pub use cpt_desktop::DesktopOptions;
+
+pub use cpt_mcp as mcp;
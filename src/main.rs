@@ -7,13 +7,32 @@ fn main() -> Result<()> {
     let cli = cpt::cli::Cli::parse();
 
     match cli.command.clone() {
+        Some(cpt::cli::CliCommand::Desktop(args)) if args.register_url_scheme => {
+            println!("{}", cpt::desktop::register_url_scheme()?);
+        }
         Some(cpt::cli::CliCommand::Desktop(args)) => {
             let options = cpt::DesktopOptions {
                 data_dir: cli.data_dir.clone(),
                 refresh_interval: Duration::from_secs(args.refresh_interval),
+                close_to_tray: !args.exit_on_close,
+                capture_hotkey: match args.capture_hotkey.as_deref() {
+                    Some("off") => None,
+                    Some(keys) => Some(keys.to_string()),
+                    None => cpt::DesktopOptions::default().capture_hotkey,
+                },
+                open_url: args.open,
+                seed_sample_data: match (args.seed, args.no_seed) {
+                    (true, _) => Some(true),
+                    (_, true) => Some(false),
+                    _ => None,
+                },
             };
             cpt::desktop::run(options)?;
         }
+        Some(cpt::cli::CliCommand::Mcp) => {
+            let config = cpt::config::from_cli(&cli)?;
+            cpt::mcp::run(config)?;
+        }
         Some(cpt::cli::CliCommand::Tui) | None => {
             let config = cpt::config::from_cli(&cli)?;
             cpt::tui::run(config)?;